@@ -10,132 +10,32 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::mem::MaybeUninit;
-
 use clap::Parser;
+use iceoryx2::benchmark::Benchmark;
 use iceoryx2::prelude::*;
 use iceoryx2_bb_log::set_log_level;
-use iceoryx2_bb_posix::barrier::*;
-use iceoryx2_bb_posix::clock::Time;
-use iceoryx2_bb_posix::thread::ThreadBuilder;
 
 const ITERATIONS: u64 = 10000000;
+const WARMUP_ITERATIONS: u64 = 10000;
 
 fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
-    let service_name_a2b = ServiceName::new("a2b")?;
-    let service_name_b2a = ServiceName::new("b2a")?;
     let node = NodeBuilder::new().create::<T>()?;
 
-    let service_a2b = node
-        .service_builder(&service_name_a2b)
-        .publish_subscribe::<[u8]>()
-        .max_publishers(1)
-        .max_subscribers(1)
-        .history_size(0)
-        .subscriber_max_buffer_size(1)
-        .enable_safe_overflow(true)
-        .create()?;
-
-    let service_b2a = node
-        .service_builder(&service_name_b2a)
-        .publish_subscribe::<[u8]>()
-        .max_publishers(1)
-        .max_subscribers(1)
-        .history_size(0)
-        .subscriber_max_buffer_size(1)
-        .enable_safe_overflow(true)
-        .create()?;
-
-    let barrier_handle = BarrierHandle::new();
-    let barrier = BarrierBuilder::new(3).create(&barrier_handle).unwrap();
-
-    let t1 = ThreadBuilder::new()
-        .affinity(args.cpu_core_participant_1)
-        .priority(255)
-        .spawn(|| {
-            let sender_a2b = service_a2b
-                .publisher_builder()
-                .max_slice_len(args.payload_size)
-                .create()
-                .unwrap();
-            let receiver_b2a = service_b2a.subscriber_builder().create().unwrap();
-
-            barrier.wait();
-
-            let mut sample = if args.send_copy {
-                let mut sample = sender_a2b.loan_slice_uninit(args.payload_size).unwrap();
-                sample.payload_mut().fill(MaybeUninit::new(0));
-                unsafe { sample.assume_init() }
-            } else {
-                unsafe {
-                    sender_a2b
-                        .loan_slice_uninit(args.payload_size)
-                        .unwrap()
-                        .assume_init()
-                }
-            };
-
-            for _ in 0..args.iterations {
-                sample.send().unwrap();
-                sample = unsafe {
-                    sender_a2b
-                        .loan_slice_uninit(args.payload_size)
-                        .unwrap()
-                        .assume_init()
-                };
-                while receiver_b2a.receive().unwrap().is_none() {}
-            }
-        });
-
-    let t2 = ThreadBuilder::new()
-        .affinity(args.cpu_core_participant_2)
-        .priority(255)
-        .spawn(|| {
-            let sender_b2a = service_b2a
-                .publisher_builder()
-                .max_slice_len(args.payload_size)
-                .create()
-                .unwrap();
-            let receiver_a2b = service_a2b.subscriber_builder().create().unwrap();
+    let benchmark = Benchmark::new()
+        .iterations(args.iterations)
+        .warmup_iterations(args.warmup_iterations)
+        .payload_size(args.payload_size)
+        .send_copy(args.send_copy)
+        .cpu_core_participant_1(args.cpu_core_participant_1)
+        .cpu_core_participant_2(args.cpu_core_participant_2);
 
-            barrier.wait();
+    let latency = benchmark.latency(&node)?;
+    println!("{} ::: {}", std::any::type_name::<T>(), latency);
 
-            for _ in 0..args.iterations {
-                let sample = if args.send_copy {
-                    let mut sample = sender_b2a.loan_slice_uninit(args.payload_size).unwrap();
-                    sample.payload_mut().fill(MaybeUninit::new(0));
-                    unsafe { sample.assume_init() }
-                } else {
-                    unsafe {
-                        sender_b2a
-                            .loan_slice_uninit(args.payload_size)
-                            .unwrap()
-                            .assume_init()
-                    }
-                };
-
-                while receiver_a2b.receive().unwrap().is_none() {}
-
-                sample.send().unwrap();
-            }
-        });
-
-    std::thread::sleep(std::time::Duration::from_millis(100));
-    let start = Time::now().expect("failed to acquire time");
-    barrier.wait();
-
-    drop(t1);
-    drop(t2);
-
-    let stop = start.elapsed().expect("failed to measure time");
-    println!(
-        "{} ::: Iterations: {}, Time: {}, Latency: {} ns, Sample Size: {}",
-        std::any::type_name::<T>(),
-        args.iterations,
-        stop.as_secs_f64(),
-        stop.as_nanos() / (args.iterations as u128 * 2),
-        args.payload_size
-    );
+    if args.throughput {
+        let throughput = benchmark.throughput(&node)?;
+        println!("{} ::: {}", std::any::type_name::<T>(), throughput);
+    }
 
     Ok(())
 }
@@ -146,6 +46,9 @@ struct Args {
     /// Number of iterations the A --> B --> A communication is repeated
     #[clap(short, long, default_value_t = ITERATIONS)]
     iterations: u64,
+    /// Number of iterations that are performed before the measurement starts
+    #[clap(short, long, default_value_t = WARMUP_ITERATIONS)]
+    warmup_iterations: u64,
     /// Run benchmark for every service setup
     #[clap(short, long)]
     bench_all: bool,
@@ -167,8 +70,12 @@ struct Args {
     /// The size in bytes of the payload that shall be used
     #[clap(short, long, default_value_t = 8192)]
     payload_size: usize,
+    /// Write the whole payload before every sample is sent
     #[clap(long)]
     send_copy: bool,
+    /// Measure the throughput in addition to the latency
+    #[clap(short, long)]
+    throughput: bool,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
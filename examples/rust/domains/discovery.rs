@@ -18,12 +18,19 @@ use iceoryx2_bb_system_types::file_name::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = parse_args();
 
+    println!("\nDomains with running nodes:");
+    for domain in Node::<ipc::Service>::list_domains(Config::global_config())? {
+        println!("  {}", domain);
+    }
+
     // create a new config based on the global config
     let mut config = Config::global_config().clone();
 
-    // The domain name becomes the prefix for all resources.
+    // The domain defines the root path and the prefix for all resources.
     // Therefore, different domain names never share the same resources.
-    config.global.prefix = FileName::new(args.domain.as_bytes())?;
+    config
+        .global
+        .set_domain(&FileName::new(args.domain.as_bytes())?)?;
 
     println!("\nServices running in domain \"{}\":", args.domain);
 
@@ -22,17 +22,10 @@ const CYCLE_TIME: Duration = Duration::from_secs(1);
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = parse_args();
 
-    // create a new config based on the global config
-    let mut config = Config::global_config().clone();
-
-    // The domain name becomes the prefix for all resources.
-    // Therefore, different domain names never share the same resources.
-    config.global.prefix = FileName::new(args.domain.as_bytes())?;
-
     let node = NodeBuilder::new()
-        // use the custom config when creating the custom node
-        // every service constructed by the node will use this config
-        .config(&config)
+        // All resources of the node and of every service constructed by the node are
+        // placed in the domain. Therefore, different domains never share the same resources.
+        .domain(&FileName::new(args.domain.as_bytes())?)
         .create::<ipc::Service>()?;
 
     let service = node
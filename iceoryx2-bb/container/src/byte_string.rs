@@ -39,6 +39,8 @@ use std::{
 
 use iceoryx2_bb_derive_macros::PlacementDefault;
 use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::type_layout_hash::{TypeLayoutHash, TypeLayoutHasher};
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
@@ -127,6 +129,17 @@ impl<'de, const CAPACITY: usize> Deserialize<'de> for FixedSizeByteString<CAPACI
 
 unsafe impl<const CAPACITY: usize> Send for FixedSizeByteString<CAPACITY> {}
 
+unsafe impl<const CAPACITY: usize> ZeroCopySend for FixedSizeByteString<CAPACITY> {}
+
+impl<const CAPACITY: usize> TypeLayoutHash for FixedSizeByteString<CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+        .add_bytes(b"FixedSizeByteString")
+        .add_usize(CAPACITY)
+        .add_usize(core::mem::size_of::<Self>())
+        .add_usize(core::mem::align_of::<Self>())
+        .value();
+}
+
 impl<const CAPACITY: usize, const CAPACITY_OTHER: usize>
     PartialOrd<FixedSizeByteString<CAPACITY_OTHER>> for FixedSizeByteString<CAPACITY>
 {
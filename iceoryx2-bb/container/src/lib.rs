@@ -56,6 +56,32 @@
 //! # }
 //! ```
 //!
+//! All compile-time fixed size containers implement
+//! [`TypeLayoutHash`](iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash) when their
+//! elements do. Deriving it for a message type verifies at compile time that every nested
+//! field is self-contained, a field like [`std::string::String`] is rejected.
+//!
+//! ```
+//! use iceoryx2_bb_container::byte_string::*;
+//! use iceoryx2_bb_container::map::*;
+//! use iceoryx2_bb_container::vec::*;
+//! use iceoryx2_bb_derive_macros::TypeLayoutHash;
+//! use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+//!
+//! #[derive(TypeLayoutHash)]
+//! #[repr(C)]
+//! struct Measurement {
+//!     unit: FixedSizeByteString<8>,
+//!     samples: FixedSizeVec<f64, 32>,
+//! }
+//!
+//! #[derive(TypeLayoutHash)]
+//! #[repr(C)]
+//! struct MyMessageType {
+//!     measurements: FixedSizeMap<u32, Measurement, 4>,
+//! }
+//! ```
+//!
 //! ## 2. Shared Memory Compatible Run-Time FixedSize Containers
 //!
 //! Despite that the containers are already implemented, iceoryx2 itself does not yet support
@@ -87,6 +113,8 @@
 
 /// A byte string similar to [`std::string::String`] but it does not support UTF-8
 pub mod byte_string;
+/// A map similar to [`std::collections::HashMap`]
+pub mod map;
 /// A queue similar to [`std::collections::VecDeque`]
 pub mod queue;
 /// Extends the [ByteString](crate::byte_string) so that custom string types with a semantic
@@ -0,0 +1,285 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Relocatable (inter-process shared memory compatible) map implementation.
+//!
+//! The [`FixedSizeMap`](crate::map::FixedSizeMap) has a fixed capacity defined at compile time.
+//! The entries are stored unordered in a [`FixedSizeVec`], therefore lookups have a linear
+//! complexity and the map is intended for a small number of entries.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::map::*;
+//!
+//! const MAP_CAPACITY: usize = 8;
+//!
+//! let mut some_map = FixedSizeMap::<u32, u64, MAP_CAPACITY>::new();
+//! some_map.insert(1, 1024).unwrap();
+//! some_map.insert(2, 2048).unwrap();
+//!
+//! assert_eq!(some_map.get(&1), Some(&1024));
+//! assert_eq!(some_map.remove(&2), Some(2048));
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_bb_derive_macros::PlacementDefault;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::type_layout_hash::{TypeLayoutHash, TypeLayoutHasher};
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use serde::{de::Visitor, ser::SerializeMap, Deserialize, Serialize};
+
+use crate::vec::FixedSizeVec;
+
+/// Error which can occur when an entry is inserted into a [`FixedSizeMap`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FixedSizeMapInsertError {
+    /// The key does not exist yet and the [`FixedSizeMap`] has no space left for another entry.
+    ExceedsCapacity,
+}
+
+impl std::fmt::Display for FixedSizeMapInsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "FixedSizeMapInsertError::{:?}", self)
+    }
+}
+
+impl std::error::Error for FixedSizeMapInsertError {}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// Relocatable map with compile time fixed size capacity.
+#[repr(C)]
+#[derive(PlacementDefault)]
+pub struct FixedSizeMap<K, V, const CAPACITY: usize> {
+    entries: FixedSizeVec<Entry<K, V>, CAPACITY>,
+}
+
+impl<K: Serialize, V: Serialize, const CAPACITY: usize> Serialize for FixedSizeMap<K, V, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for entry in self.entries.iter() {
+            map.serialize_entry(&entry.key, &entry.value)?;
+        }
+        map.end()
+    }
+}
+
+struct FixedSizeMapVisitor<K, V, const CAPACITY: usize> {
+    _key: PhantomData<K>,
+    _value: PhantomData<V>,
+}
+
+impl<'de, K: Deserialize<'de> + Eq, V: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for FixedSizeMapVisitor<K, V, CAPACITY>
+{
+    type Value = FixedSizeMap<K, V, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let str = format!(
+            "a map of at most {} entries with keys of type {} and values of type {}",
+            CAPACITY,
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut new_map = Self::Value::new();
+
+        while let Some((key, value)) = map.next_entry()? {
+            if new_map.insert(key, value).is_err() {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the map can hold at most {} entries",
+                    CAPACITY
+                )));
+            }
+        }
+
+        Ok(new_map)
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Eq, V: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
+    for FixedSizeMap<K, V, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(FixedSizeMapVisitor::<K, V, CAPACITY> {
+            _key: PhantomData,
+            _value: PhantomData,
+        })
+    }
+}
+
+impl<K: TypeLayoutHash, V: TypeLayoutHash, const CAPACITY: usize> TypeLayoutHash
+    for FixedSizeMap<K, V, CAPACITY>
+{
+    const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+        .add_bytes(b"FixedSizeMap")
+        .add_usize(CAPACITY)
+        .add_usize(core::mem::size_of::<Self>())
+        .add_usize(core::mem::align_of::<Self>())
+        .add_u64(K::TYPE_LAYOUT_HASH)
+        .add_u64(V::TYPE_LAYOUT_HASH)
+        .value();
+}
+
+// the entries are stored inline in a FixedSizeVec
+unsafe impl<K: ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeMap<K, V, CAPACITY>
+{
+}
+
+impl<K, V, const CAPACITY: usize> Default for FixedSizeMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            entries: FixedSizeVec::new(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, const CAPACITY: usize> Debug for FixedSizeMap<K, V, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Eq, V: PartialEq, const CAPACITY: usize> PartialEq for FixedSizeMap<K, V, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Eq, V: Eq, const CAPACITY: usize> Eq for FixedSizeMap<K, V, CAPACITY> {}
+
+impl<K: Clone, V: Clone, const CAPACITY: usize> Clone for FixedSizeMap<K, V, CAPACITY> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K, V, const CAPACITY: usize> FixedSizeMap<K, V, CAPACITY> {
+    /// Creates a new empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the map is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if the map is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.entries.is_full()
+    }
+
+    /// Returns the capacity of the map
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of entries stored inside the map
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Removes all entries from the map
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+
+    /// Returns an iterator over all key-value pairs in an unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Returns an iterator over all keys in an unspecified order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|entry| &entry.key)
+    }
+
+    /// Returns an iterator over all values in an unspecified order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|entry| &entry.value)
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> FixedSizeMap<K, V, CAPACITY> {
+    fn position(&self, key: &K) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.key == *key)
+    }
+
+    /// Inserts the value for the provided key. If the key already exists, the old value is
+    /// replaced and returned. If the key does not exist and the map is full, it fails with
+    /// [`FixedSizeMapInsertError::ExceedsCapacity`].
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, FixedSizeMapInsertError> {
+        match self.position(&key) {
+            Some(index) => Ok(Some(core::mem::replace(
+                &mut self.entries[index].value,
+                value,
+            ))),
+            None => match self.entries.push(Entry { key, value }) {
+                true => Ok(None),
+                false => Err(FixedSizeMapInsertError::ExceedsCapacity),
+            },
+        }
+    }
+
+    /// Returns a reference to the value of the provided key. If the key does not exist it
+    /// returns [`None`].
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.position(key).map(|index| &self.entries[index].value)
+    }
+
+    /// Returns a mutable reference to the value of the provided key. If the key does not exist
+    /// it returns [`None`].
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.position(key)
+            .map(|index| &mut self.entries[index].value)
+    }
+
+    /// Returns true if the map contains the provided key, otherwise false.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Removes the entry of the provided key and returns its value. If the key does not exist
+    /// it returns [`None`]. The order of the remaining entries may change.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.position(key)?;
+        let last = self.entries.len() - 1;
+        self.entries.swap(index, last);
+        self.entries.pop().map(|entry| entry.value)
+    }
+}
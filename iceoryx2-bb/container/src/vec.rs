@@ -89,6 +89,8 @@ use iceoryx2_bb_elementary::{
     pointer_trait::PointerTrait,
     relocatable_container::RelocatableContainer,
     relocatable_ptr::RelocatablePointer,
+    type_layout_hash::{TypeLayoutHash, TypeLayoutHasher},
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
@@ -419,6 +421,19 @@ impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
     }
 }
 
+impl<T: TypeLayoutHash, const CAPACITY: usize> TypeLayoutHash for FixedSizeVec<T, CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+        .add_bytes(b"FixedSizeVec")
+        .add_usize(CAPACITY)
+        .add_usize(core::mem::size_of::<Self>())
+        .add_usize(core::mem::align_of::<Self>())
+        .add_u64(T::TYPE_LAYOUT_HASH)
+        .value();
+}
+
+// the elements are stored inline and the relocatable state does not point outside of the vec
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeVec<T, CAPACITY> {}
+
 impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeVec<T, CAPACITY> {
     unsafe fn placement_default(ptr: *mut Self) {
         let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
@@ -0,0 +1,189 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_map {
+    use iceoryx2_bb_container::map::*;
+    use iceoryx2_bb_container::vec::FixedSizeVec;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+    use iceoryx2_bb_testing::memory::RawMemory;
+    use serde_test::{assert_tokens, Token};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeMap<u32, u64, SUT_CAPACITY>;
+
+    #[test]
+    fn new_map_is_empty() {
+        let sut = Sut::new();
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.len(), eq 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.get(&0), is_none);
+    }
+
+    #[test]
+    fn insert_get_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY as u32 {
+            assert_that!(sut.is_full(), eq false);
+            assert_that!(sut.insert(i, i as u64 * 3 + 1), eq Ok(None));
+            assert_that!(sut.len(), eq i as usize + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..SUT_CAPACITY as u32 {
+            assert_that!(sut.contains_key(&i), eq true);
+            assert_that!(sut.get(&i), eq Some(&(i as u64 * 3 + 1)));
+        }
+        assert_that!(sut.contains_key(&(SUT_CAPACITY as u32)), eq false);
+    }
+
+    #[test]
+    fn insert_into_full_map_fails() {
+        let mut sut = FixedSizeMap::<u32, u64, 2>::new();
+
+        assert_that!(sut.insert(1, 1), eq Ok(None));
+        assert_that!(sut.insert(2, 2), eq Ok(None));
+        assert_that!(sut.insert(3, 3), eq Err(FixedSizeMapInsertError::ExceedsCapacity));
+        assert_that!(sut.insert(2, 5), eq Ok(Some(2)));
+        assert_that!(sut.get(&2), eq Some(&5));
+    }
+
+    #[test]
+    fn insert_existing_key_replaces_value() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.insert(12, 34), eq Ok(None));
+        assert_that!(sut.insert(12, 56), eq Ok(Some(34)));
+        assert_that!(sut.len(), eq 1);
+        assert_that!(sut.get(&12), eq Some(&56));
+
+        *sut.get_mut(&12).unwrap() = 78;
+        assert_that!(sut.get(&12), eq Some(&78));
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..10 {
+            sut.insert(i, i as u64 + 100).unwrap();
+        }
+
+        assert_that!(sut.remove(&3), eq Some(103));
+        assert_that!(sut.remove(&3), is_none);
+        assert_that!(sut.len(), eq 9);
+
+        for i in (0..10).filter(|i| *i != 3) {
+            assert_that!(sut.get(&i), eq Some(&(i as u64 + 100)));
+        }
+
+        sut.clear();
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn iterators_visit_all_entries() {
+        let mut sut = Sut::new();
+
+        for i in 0..5 {
+            sut.insert(i, i as u64 * 2).unwrap();
+        }
+
+        let mut keys: Vec<u32> = sut.keys().copied().collect();
+        keys.sort();
+        assert_that!(keys, eq vec![0, 1, 2, 3, 4]);
+
+        let mut values: Vec<u64> = sut.values().copied().collect();
+        values.sort();
+        assert_that!(values, eq vec![0, 2, 4, 6, 8]);
+
+        for (key, value) in sut.iter() {
+            assert_that!(*value, eq * key as u64 * 2);
+        }
+    }
+
+    #[test]
+    fn eq_does_not_depend_on_insertion_order() {
+        let mut sut1 = Sut::new();
+        let mut sut2 = Sut::new();
+
+        sut1.insert(1, 10).unwrap();
+        sut1.insert(2, 20).unwrap();
+        sut2.insert(2, 20).unwrap();
+        sut2.insert(1, 10).unwrap();
+
+        assert_that!(sut1, eq sut2);
+
+        sut2.insert(1, 11).unwrap();
+        assert_that!(sut1, ne sut2);
+
+        let sut3 = sut1.clone();
+        assert_that!(sut1, eq sut3);
+    }
+
+    #[test]
+    fn drops_all_objects_when_out_of_scope() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeMap::<u32, LifetimeTracker, SUT_CAPACITY>::new();
+
+        for i in 0..SUT_CAPACITY as u32 {
+            sut.insert(i, LifetimeTracker::new()).unwrap();
+        }
+
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY);
+        drop(sut);
+        assert_that!(state.number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init() }.is_empty(), eq true);
+        assert_that!(unsafe { sut.assume_init_mut() }.insert(1, 2), eq Ok(None));
+        assert_that!(unsafe { sut.assume_init() }.get(&1), eq Some(&2));
+    }
+
+    #[test]
+    fn type_layout_hash_depends_on_capacity_and_types() {
+        assert_that!(Sut::TYPE_LAYOUT_HASH, eq FixedSizeMap::<u32, u64, SUT_CAPACITY>::TYPE_LAYOUT_HASH);
+        assert_that!(Sut::TYPE_LAYOUT_HASH, ne FixedSizeMap::<u32, u64, 12>::TYPE_LAYOUT_HASH);
+        assert_that!(Sut::TYPE_LAYOUT_HASH, ne FixedSizeMap::<u32, i64, SUT_CAPACITY>::TYPE_LAYOUT_HASH);
+        assert_that!(Sut::TYPE_LAYOUT_HASH, ne FixedSizeVec::<u64, SUT_CAPACITY>::TYPE_LAYOUT_HASH);
+    }
+
+    #[test]
+    fn serialization_works() {
+        let mut sut = FixedSizeMap::<u32, u64, SUT_CAPACITY>::new();
+        sut.insert(12, 44617).unwrap();
+        sut.insert(7, 123123).unwrap();
+
+        assert_tokens(
+            &sut,
+            &[
+                Token::Map { len: Some(2) },
+                Token::U32(12),
+                Token::U64(44617),
+                Token::U32(7),
+                Token::U64(123123),
+                Token::MapEnd,
+            ],
+        );
+    }
+}
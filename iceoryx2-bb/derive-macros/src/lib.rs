@@ -14,6 +14,7 @@
 
 extern crate proc_macro;
 
+use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHasher;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, Data, DeriveInput, Fields};
@@ -99,3 +100,299 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Implements the [`iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash`] trait when all
+/// fields of the struct implement it. The digest covers the size and alignment of the struct
+/// and the name and digest of every field in declaration order.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::TypeLayoutHash;
+/// use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+///
+/// #[derive(TypeLayoutHash)]
+/// #[repr(C)]
+/// struct MyPayload {
+///     value: u64,
+///     flag: bool,
+/// }
+///
+/// #[derive(TypeLayoutHash)]
+/// #[repr(C)]
+/// struct MyChangedPayload {
+///     value: u64,
+///     flag: u8,
+/// }
+///
+/// assert_ne!(MyPayload::TYPE_LAYOUT_HASH, MyChangedPayload::TYPE_LAYOUT_HASH);
+/// ```
+///
+/// Enums and unions are rejected.
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::TypeLayoutHash;
+/// use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+///
+/// #[derive(TypeLayoutHash)]
+/// enum MyPayload {
+///     Value,
+/// }
+/// ```
+#[proc_macro_derive(TypeLayoutHash)]
+pub fn type_layout_hash_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // the field names are known at expansion time, the layout of the field types only when
+    // the impl is compiled
+    let (field_names, field_types): (Vec<String>, Vec<&syn::Type>) = match input.data {
+        Data::Struct(ref data_struct) => match data_struct.fields {
+            Fields::Named(ref fields_named) => fields_named
+                .named
+                .iter()
+                .map(|f| (f.ident.as_ref().unwrap().to_string(), &f.ty))
+                .unzip(),
+            Fields::Unnamed(ref fields_unnamed) => fields_unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, f)| (i.to_string(), &f.ty))
+                .unzip(),
+            Fields::Unit => (vec![], vec![]),
+        },
+        _ => {
+            return syn::Error::new_spanned(
+                name,
+                "TypeLayoutHash can only be derived for structs.",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let names_hash = field_names
+        .iter()
+        .fold(TypeLayoutHasher::new(), |hasher, name| {
+            hasher.add_bytes(name.as_bytes())
+        })
+        .value();
+
+    let expanded = quote! {
+        impl #impl_generics TypeLayoutHash for #name #ty_generics #where_clause {
+            const TYPE_LAYOUT_HASH: u64 = {
+                const PRIME: u64 = 0x100000001b3;
+                let mut hash: u64 = #names_hash;
+                hash = (hash ^ core::mem::size_of::<Self>() as u64).wrapping_mul(PRIME);
+                hash = (hash ^ core::mem::align_of::<Self>() as u64).wrapping_mul(PRIME);
+                #(hash = (hash ^ <#field_types as TypeLayoutHash>::TYPE_LAYOUT_HASH).wrapping_mul(PRIME);)*
+                hash
+            };
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Implements the [`iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend`] trait and verifies
+/// at compile time that the type can be shared via shared memory. The type must be
+/// `#[repr(C)]` or `#[repr(transparent)]`, enums may also use a primitive representation like
+/// `#[repr(u8)]`, and every field must implement
+/// [`iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend`].
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct MyPayload {
+///     value: u64,
+///     data: [u8; 16],
+/// }
+/// ```
+///
+/// A type without a defined memory layout is rejected.
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// struct MyPayload {
+///     value: u64,
+/// }
+/// ```
+///
+/// A type that points to memory outside of itself is rejected.
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct MyPayload {
+///     value: Box<u64>,
+/// }
+/// ```
+#[proc_macro_derive(ZeroCopySend)]
+pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let field_types: Vec<&syn::Type> = match input.data {
+        Data::Struct(ref data_struct) => data_struct.fields.iter().map(|f| &f.ty).collect(),
+        Data::Enum(ref data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|v| v.fields.iter().map(|f| &f.ty))
+            .collect(),
+        Data::Union(_) => {
+            return syn::Error::new_spanned(name, "ZeroCopySend cannot be derived for unions.")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let is_enum = matches!(input.data, Data::Enum(_));
+    let mut has_defined_layout = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            const PRIMITIVE_REPRESENTATIONS: [&str; 12] = [
+                "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+                "isize",
+            ];
+
+            if meta.path.is_ident("C")
+                || meta.path.is_ident("transparent")
+                || (is_enum
+                    && PRIMITIVE_REPRESENTATIONS
+                        .iter()
+                        .any(|repr| meta.path.is_ident(repr)))
+            {
+                has_defined_layout = true;
+            }
+
+            // skip the arguments of representations like align(8)
+            if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            return e.to_compile_error().into();
+        }
+    }
+
+    if !has_defined_layout {
+        return syn::Error::new_spanned(
+            name,
+            "ZeroCopySend requires a defined memory layout, add #[repr(C)] or #[repr(transparent)].",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // a field that does not implement ZeroCopySend turns the bound into an unsatisfied
+    // requirement, which fails the compilation for non-generic types
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field_type in field_types {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#field_type: ZeroCopySend));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let expanded = quote! {
+        unsafe impl #impl_generics ZeroCopySend for #name #ty_generics #where_clause {}
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Implements the `EventIdEnum` trait of iceoryx2 for an enum without fields. Every variant is
+/// mapped to the `EventId` of its discriminant, so that the events of a service can be named
+/// instead of using raw `EventId` values. Additionally, `From<Enum>` is implemented for
+/// `EventId`. Both, `EventIdEnum` and `EventId`, must be in scope, for instance with
+/// `use iceoryx2::prelude::*`.
+///
+/// An enum with fields is rejected at compile time.
+#[proc_macro_derive(EventIdEnum)]
+pub fn event_id_enum_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let data_enum = match input.data {
+        Data::Enum(ref data_enum) => data_enum,
+        _ => {
+            return syn::Error::new_spanned(name, "EventIdEnum can only be derived for enums.")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    if data_enum.variants.is_empty() {
+        return syn::Error::new_spanned(name, "EventIdEnum requires at least one variant.")
+            .to_compile_error()
+            .into();
+    }
+
+    if let Some(variant) = data_enum
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return syn::Error::new_spanned(
+            variant,
+            "EventIdEnum can only be derived for enums without fields.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let variants: Vec<&syn::Ident> = data_enum
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect();
+
+    let expanded = quote! {
+        impl EventIdEnum for #name {
+            const MAX_EVENT_ID: EventId = {
+                let mut max = 0;
+                #(if #name::#variants as usize > max {
+                    max = #name::#variants as usize;
+                })*
+                EventId::new(max)
+            };
+
+            fn event_id(&self) -> EventId {
+                match self {
+                    #(#name::#variants => EventId::new(#name::#variants as usize),)*
+                }
+            }
+
+            fn from_event_id(value: EventId) -> Option<Self> {
+                #(if value.as_value() == #name::#variants as usize {
+                    return Some(#name::#variants);
+                })*
+                None
+            }
+        }
+
+        impl From<#name> for EventId {
+            fn from(value: #name) -> Self {
+                <#name as EventIdEnum>::event_id(&value)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod type_layout_hash {
+    use iceoryx2_bb_derive_macros::TypeLayoutHash;
+    use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+    use iceoryx2_bb_testing::assert_that;
+
+    mod first {
+        use super::*;
+
+        #[derive(TypeLayoutHash)]
+        #[repr(C)]
+        pub struct Payload {
+            pub value: u64,
+            pub data: [u8; 16],
+        }
+    }
+
+    mod second {
+        use super::*;
+
+        #[derive(TypeLayoutHash)]
+        #[repr(C)]
+        pub struct Payload {
+            pub value: u64,
+            pub data: [u8; 16],
+        }
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct RenamedField {
+        value: u64,
+        other_data: [u8; 16],
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct ChangedFieldType {
+        value: u64,
+        data: [i8; 16],
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct ChangedArraySize {
+        value: u64,
+        data: [u8; 17],
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct SwappedFields {
+        data: [u8; 16],
+        value: u64,
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct Nested {
+        inner: first::Payload,
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct NestedChanged {
+        inner: RenamedField,
+    }
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct Unnamed(u32, u16);
+
+    #[derive(TypeLayoutHash)]
+    #[repr(C)]
+    struct UnnamedChanged(u32, i16);
+
+    #[test]
+    fn identical_definitions_have_the_same_hash() {
+        assert_that!(first::Payload::TYPE_LAYOUT_HASH, eq second::Payload::TYPE_LAYOUT_HASH);
+    }
+
+    #[test]
+    fn changed_definitions_have_a_different_hash() {
+        let hash = first::Payload::TYPE_LAYOUT_HASH;
+        assert_that!(RenamedField::TYPE_LAYOUT_HASH, ne hash);
+        assert_that!(ChangedFieldType::TYPE_LAYOUT_HASH, ne hash);
+        assert_that!(ChangedArraySize::TYPE_LAYOUT_HASH, ne hash);
+        assert_that!(SwappedFields::TYPE_LAYOUT_HASH, ne hash);
+    }
+
+    #[test]
+    fn changed_nested_definition_changes_the_hash() {
+        assert_that!(Nested::TYPE_LAYOUT_HASH, ne NestedChanged::TYPE_LAYOUT_HASH);
+    }
+
+    #[test]
+    fn changed_unnamed_field_type_changes_the_hash() {
+        assert_that!(Unnamed::TYPE_LAYOUT_HASH, ne UnnamedChanged::TYPE_LAYOUT_HASH);
+    }
+}
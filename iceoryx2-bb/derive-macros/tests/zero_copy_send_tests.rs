@@ -0,0 +1,92 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod zero_copy_send {
+    use iceoryx2_bb_derive_macros::ZeroCopySend;
+    use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn is_zero_copy_send<T: ZeroCopySend + ?Sized>() -> bool {
+        true
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct NamedFields {
+        value: u64,
+        data: [u8; 16],
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct UnnamedFields(u32, [i16; 4]);
+
+    #[derive(ZeroCopySend)]
+    #[repr(transparent)]
+    struct Transparent(u64);
+
+    #[derive(ZeroCopySend)]
+    #[repr(C, align(64))]
+    struct Aligned {
+        value: u64,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct Nested {
+        inner: NamedFields,
+        others: [UnnamedFields; 3],
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct Generic<T> {
+        value: T,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(u8)]
+    enum Enum {
+        Plain,
+        WithValue(u64),
+        WithFields { value: i32 },
+    }
+
+    #[test]
+    fn derive_works_for_structs() {
+        assert_that!(is_zero_copy_send::<NamedFields>(), eq true);
+        assert_that!(is_zero_copy_send::<UnnamedFields>(), eq true);
+        assert_that!(is_zero_copy_send::<Transparent>(), eq true);
+        assert_that!(is_zero_copy_send::<Aligned>(), eq true);
+        assert_that!(is_zero_copy_send::<Nested>(), eq true);
+        assert_that!(is_zero_copy_send::<[Nested]>(), eq true);
+    }
+
+    #[test]
+    fn derive_works_for_generic_structs() {
+        assert_that!(is_zero_copy_send::<Generic<u64>>(), eq true);
+        assert_that!(is_zero_copy_send::<Generic<[NamedFields; 2]>>(), eq true);
+    }
+
+    #[test]
+    fn derive_works_for_enums() {
+        assert_that!(is_zero_copy_send::<Enum>(), eq true);
+
+        // the variants are never constructed otherwise
+        let _ = [
+            Enum::Plain,
+            Enum::WithValue(0),
+            Enum::WithFields { value: 0 },
+        ];
+    }
+}
@@ -29,7 +29,9 @@ pub mod relocatable_container;
 pub mod relocatable_ptr;
 pub mod scope_guard;
 pub mod static_assert;
+pub mod type_layout_hash;
 pub mod unique_id;
+pub mod zero_copy_send;
 
 /// Defines how a callback based iteration shall progress after the calling the callback. Either
 /// stop the iteration with [`CallbackProgression::Stop`] or continue with
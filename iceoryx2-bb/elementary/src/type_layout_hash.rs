@@ -0,0 +1,163 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Trait that provides a compile-time digest of the memory layout of a type. Two processes
+//! that use a type with the same name but a different definition can detect the mismatch by
+//! comparing the digest. See [`TypeLayoutHash`] for an example.
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::*;
+
+/// Provides a digest of the memory layout of a type. It covers the size and alignment of the
+/// type and, for structs, the name and layout digest of every field in declaration order.
+///
+/// The digest is stable across processes and builds with the same compiler and target, it is
+/// not a cryptographic hash.
+///
+/// ```
+/// use iceoryx2_bb_elementary::type_layout_hash::{TypeLayoutHash, TypeLayoutHasher};
+///
+/// #[repr(C)]
+/// struct MyPayload {
+///     value: u64,
+///     flag: bool,
+/// }
+///
+/// impl TypeLayoutHash for MyPayload {
+///     const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+///         .add_usize(core::mem::size_of::<Self>())
+///         .add_usize(core::mem::align_of::<Self>())
+///         .add_bytes(b"value")
+///         .add_u64(u64::TYPE_LAYOUT_HASH)
+///         .add_bytes(b"flag")
+///         .add_u64(bool::TYPE_LAYOUT_HASH)
+///         .value();
+/// }
+///
+/// assert_ne!(MyPayload::TYPE_LAYOUT_HASH, u64::TYPE_LAYOUT_HASH);
+/// ```
+pub trait TypeLayoutHash {
+    /// The digest of the memory layout of the type.
+    const TYPE_LAYOUT_HASH: u64;
+}
+
+/// A `const` capable FNV-1a hasher to compute the [`TypeLayoutHash::TYPE_LAYOUT_HASH`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeLayoutHasher {
+    value: u64,
+}
+
+impl Default for TypeLayoutHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeLayoutHasher {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    /// Creates a new [`TypeLayoutHasher`]
+    pub const fn new() -> Self {
+        Self {
+            value: Self::OFFSET_BASIS,
+        }
+    }
+
+    /// Adds the bytes to the digest. The length is part of the digest, so that adjacent
+    /// byte sequences cannot be shifted into each other.
+    pub const fn add_bytes(self, bytes: &[u8]) -> Self {
+        self.add_raw(&(bytes.len() as u64).to_le_bytes())
+            .add_raw(bytes)
+    }
+
+    /// Adds the value to the digest.
+    pub const fn add_u64(self, value: u64) -> Self {
+        self.add_raw(&value.to_le_bytes())
+    }
+
+    /// Adds the value to the digest.
+    pub const fn add_usize(self, value: usize) -> Self {
+        self.add_u64(value as u64)
+    }
+
+    /// Returns the current digest.
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    const fn add_raw(self, bytes: &[u8]) -> Self {
+        let mut value = self.value;
+        let mut i = 0;
+        while i < bytes.len() {
+            value ^= bytes[i] as u64;
+            value = value.wrapping_mul(Self::PRIME);
+            i += 1;
+        }
+        Self { value }
+    }
+}
+
+macro_rules! Impl {
+    ($type:ty) => {
+        impl TypeLayoutHash for $type {
+            const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+                .add_bytes(stringify!($type).as_bytes())
+                .add_usize(core::mem::size_of::<$type>())
+                .add_usize(core::mem::align_of::<$type>())
+                .value();
+        }
+    };
+}
+
+Impl!(());
+Impl!(f32);
+Impl!(f64);
+Impl!(u8);
+Impl!(u16);
+Impl!(u32);
+Impl!(u64);
+Impl!(u128);
+Impl!(i8);
+Impl!(i16);
+Impl!(i32);
+Impl!(i64);
+Impl!(i128);
+Impl!(isize);
+Impl!(usize);
+Impl!(char);
+Impl!(bool);
+Impl!(IoxAtomicBool);
+Impl!(IoxAtomicU8);
+Impl!(IoxAtomicU16);
+Impl!(IoxAtomicU32);
+Impl!(IoxAtomicU64);
+Impl!(IoxAtomicI8);
+Impl!(IoxAtomicI16);
+Impl!(IoxAtomicI32);
+Impl!(IoxAtomicI64);
+Impl!(IoxAtomicIsize);
+Impl!(IoxAtomicUsize);
+
+impl<T: TypeLayoutHash> TypeLayoutHash for [T] {
+    const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+        .add_bytes(b"[T]")
+        .add_u64(T::TYPE_LAYOUT_HASH)
+        .value();
+}
+
+impl<T: TypeLayoutHash, const CAPACITY: usize> TypeLayoutHash for [T; CAPACITY] {
+    const TYPE_LAYOUT_HASH: u64 = TypeLayoutHasher::new()
+        .add_bytes(b"[T; CAPACITY]")
+        .add_usize(CAPACITY)
+        .add_u64(T::TYPE_LAYOUT_HASH)
+        .value();
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Marker trait for types that can be shared between processes via shared memory. See
+//! [`ZeroCopySend`] for details.
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::*;
+
+/// Marks types that can be transferred via shared memory to another process without
+/// serialization. Instead of implementing it manually, it shall be derived with
+/// `#[derive(ZeroCopySend)]`, which verifies the requirements at compile time.
+///
+/// ```
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// struct MyPayload {
+///     value: u64,
+///     data: [u8; 16],
+/// }
+///
+/// // SAFETY: MyPayload is repr(C) and all of its fields implement ZeroCopySend
+/// unsafe impl ZeroCopySend for MyPayload {}
+/// ```
+///
+/// # Safety
+///
+///  * The type must be self-contained, it must not contain pointers, references or any
+///    other handle to memory outside of itself, like heap allocated data.
+///  * The type must have a defined memory layout, like `#[repr(C)]`, so that every process
+///    interprets the memory in the same way.
+///  * All fields of the type must implement [`ZeroCopySend`].
+pub unsafe trait ZeroCopySend {}
+
+macro_rules! Impl {
+    ($type:ty) => {
+        unsafe impl ZeroCopySend for $type {}
+    };
+}
+
+Impl!(());
+Impl!(f32);
+Impl!(f64);
+Impl!(u8);
+Impl!(u16);
+Impl!(u32);
+Impl!(u64);
+Impl!(u128);
+Impl!(i8);
+Impl!(i16);
+Impl!(i32);
+Impl!(i64);
+Impl!(i128);
+Impl!(isize);
+Impl!(usize);
+Impl!(char);
+Impl!(bool);
+Impl!(IoxAtomicBool);
+Impl!(IoxAtomicU8);
+Impl!(IoxAtomicU16);
+Impl!(IoxAtomicU32);
+Impl!(IoxAtomicU64);
+Impl!(IoxAtomicI8);
+Impl!(IoxAtomicI16);
+Impl!(IoxAtomicI32);
+Impl!(IoxAtomicI64);
+Impl!(IoxAtomicIsize);
+Impl!(IoxAtomicUsize);
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for [T; CAPACITY] {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {}
@@ -44,7 +44,12 @@
 //! ```
 
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
-use std::{alloc::Layout, cell::UnsafeCell, fmt::Debug, sync::atomic::Ordering};
+use std::{
+    alloc::Layout,
+    cell::UnsafeCell,
+    fmt::Debug,
+    sync::atomic::{fence, Ordering},
+};
 
 use iceoryx2_bb_elementary::{
     math::align_to, owning_pointer::OwningPointer, pointer_trait::PointerTrait,
@@ -86,6 +91,24 @@ impl<PointerType: PointerTrait<UnsafeCell<usize>> + Debug> Consumer<'_, PointerT
     pub fn pop(&mut self) -> Option<usize> {
         unsafe { self.queue.pop() }
     }
+
+    /// Acquires up to `buffer.len()` indices with a single synchronization. Returns the number
+    /// of indices that were stored in the beginning of `buffer`.
+    pub fn pop_batch(&mut self, buffer: &mut [usize]) -> usize {
+        unsafe { self.queue.pop_batch(buffer) }
+    }
+
+    /// Returns the oldest value of the [`SafelyOverflowingIndexQueue`]/[`FixedSizeSafelyOverflowingIndexQueue`]
+    /// without removing it. If the queue is empty it returns [`None`].
+    pub fn peek(&self) -> Option<usize> {
+        unsafe { self.queue.peek() }
+    }
+
+    /// Returns the newest value of the [`SafelyOverflowingIndexQueue`]/[`FixedSizeSafelyOverflowingIndexQueue`]
+    /// without removing it. If the queue is empty it returns [`None`].
+    pub fn peek_last(&self) -> Option<usize> {
+        unsafe { self.queue.peek_last() }
+    }
 }
 
 impl<PointerType: PointerTrait<UnsafeCell<usize>>> Drop for Consumer<'_, PointerType> {
@@ -380,6 +403,113 @@ pub mod details {
             Some(value)
         }
 
+        /// Acquires up to `buffer.len()` indices from the [`SafelyOverflowingIndexQueue`] with
+        /// a single synchronization with the producer and stores them in order in the
+        /// beginning of `buffer`. Returns the number of acquired indices.
+        ///
+        /// # Safety
+        ///
+        ///  * [`SafelyOverflowingIndexQueue::pop_batch()`] and
+        ///    [`SafelyOverflowingIndexQueue::pop()`] cannot be called concurrently. The user has
+        ///    to ensure that at most one thread access these methods.
+        ///  * It has to be ensured that the memory is initialized with
+        ///    [`SafelyOverflowingIndexQueue::init()`].
+        pub unsafe fn pop_batch(&self, buffer: &mut [usize]) -> usize {
+            let mut read_position = self.read_position.load(Ordering::Relaxed);
+
+            loop {
+                ////////////////
+                // SYNC POINT W
+                ////////////////
+                let write_position = self.write_position.load(Ordering::Acquire);
+                let len = write_position
+                    .saturating_sub(read_position)
+                    .min(buffer.len());
+
+                if len == 0 {
+                    return 0;
+                }
+
+                for (n, value) in buffer.iter_mut().take(len).enumerate() {
+                    *value = unsafe { *self.at(read_position + n) };
+                }
+
+                // fails when the producer has overridden the oldest index in the meantime
+                match self.read_position.compare_exchange(
+                    read_position,
+                    read_position + len,
+                    Ordering::Relaxed,
+                    ////////////////
+                    // SYNC POINT R
+                    ////////////////
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => return len,
+                    Err(v) => read_position = v,
+                }
+            }
+        }
+
+        /// Returns the oldest index of the [`SafelyOverflowingIndexQueue`] without removing it.
+        /// If the queue is empty [`None`] is returned.
+        ///
+        /// # Safety
+        ///
+        ///  * [`SafelyOverflowingIndexQueue::peek()`] cannot be called concurrently with
+        ///    [`SafelyOverflowingIndexQueue::pop()`] or
+        ///    [`SafelyOverflowingIndexQueue::pop_batch()`].
+        ///  * It has to be ensured that the memory is initialized with
+        ///    [`SafelyOverflowingIndexQueue::init()`].
+        pub unsafe fn peek(&self) -> Option<usize> {
+            loop {
+                let read_position = self.read_position.load(Ordering::Acquire);
+                ////////////////
+                // SYNC POINT W
+                ////////////////
+                if read_position == self.write_position.load(Ordering::Acquire) {
+                    return None;
+                }
+
+                let value = unsafe { *self.at(read_position) };
+
+                // the producer may have overridden the oldest index in the meantime
+                fence(Ordering::Acquire);
+                if read_position == self.read_position.load(Ordering::Relaxed) {
+                    return Some(value);
+                }
+            }
+        }
+
+        /// Returns the newest index of the [`SafelyOverflowingIndexQueue`] without removing it.
+        /// If the queue is empty [`None`] is returned.
+        ///
+        /// # Safety
+        ///
+        ///  * [`SafelyOverflowingIndexQueue::peek_last()`] cannot be called concurrently with
+        ///    [`SafelyOverflowingIndexQueue::pop()`] or
+        ///    [`SafelyOverflowingIndexQueue::pop_batch()`].
+        ///  * It has to be ensured that the memory is initialized with
+        ///    [`SafelyOverflowingIndexQueue::init()`].
+        pub unsafe fn peek_last(&self) -> Option<usize> {
+            loop {
+                ////////////////
+                // SYNC POINT W
+                ////////////////
+                let write_position = self.write_position.load(Ordering::Acquire);
+                if write_position == self.read_position.load(Ordering::Acquire) {
+                    return None;
+                }
+
+                let value = unsafe { *self.at(write_position - 1) };
+
+                // the producer may have pushed and overridden the newest index in the meantime
+                fence(Ordering::Acquire);
+                if write_position == self.write_position.load(Ordering::Relaxed) {
+                    return Some(value);
+                }
+            }
+        }
+
         fn acquire_read_and_write_position(&self) -> (usize, usize) {
             loop {
                 let write_position = self.write_position.load(Ordering::Relaxed);
@@ -499,6 +629,39 @@ impl<const CAPACITY: usize> FixedSizeSafelyOverflowingIndexQueue<CAPACITY> {
         self.state.pop()
     }
 
+    /// See [`SafelyOverflowingIndexQueue::pop_batch()`]
+    ///
+    /// # Safety
+    ///
+    /// * It must be ensured that no other thread/process calls this method or
+    ///   [`FixedSizeSafelyOverflowingIndexQueue::pop()`] concurrently
+    ///
+    pub unsafe fn pop_batch(&self, buffer: &mut [usize]) -> usize {
+        self.state.pop_batch(buffer)
+    }
+
+    /// See [`SafelyOverflowingIndexQueue::peek()`]
+    ///
+    /// # Safety
+    ///
+    /// * It must be ensured that no other thread/process calls this method or
+    ///   [`FixedSizeSafelyOverflowingIndexQueue::pop()`] concurrently
+    ///
+    pub unsafe fn peek(&self) -> Option<usize> {
+        self.state.peek()
+    }
+
+    /// See [`SafelyOverflowingIndexQueue::peek_last()`]
+    ///
+    /// # Safety
+    ///
+    /// * It must be ensured that no other thread/process calls this method or
+    ///   [`FixedSizeSafelyOverflowingIndexQueue::pop()`] concurrently
+    ///
+    pub unsafe fn peek_last(&self) -> Option<usize> {
+        self.state.peek_last()
+    }
+
     /// See [`SafelyOverflowingIndexQueue::capacity()`]
     pub const fn capacity(&self) -> usize {
         self.state.capacity()
@@ -86,6 +86,65 @@ fn spsc_safely_overflowing_index_queue_push_pop_alteration_works() {
     }
 }
 
+#[test]
+fn spsc_safely_overflowing_index_queue_pop_batch_works() {
+    const CAPACITY: usize = 128;
+    const BATCH_SIZE: usize = 48;
+    let sut = FixedSizeSafelyOverflowingIndexQueue::<CAPACITY>::new();
+    let mut sut_producer = sut.acquire_producer().unwrap();
+    let mut sut_consumer = sut.acquire_consumer().unwrap();
+    let mut buffer = [0; BATCH_SIZE];
+
+    assert_that!(sut_consumer.pop_batch(&mut buffer), eq 0);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut_producer.push(i), is_none);
+    }
+
+    let mut expected_value = 0;
+    while expected_value < CAPACITY {
+        let len = sut_consumer.pop_batch(&mut buffer);
+        assert_that!(len, eq BATCH_SIZE.min(CAPACITY - expected_value));
+
+        for value in buffer.iter().take(len) {
+            assert_that!(*value, eq expected_value);
+            expected_value += 1;
+        }
+    }
+
+    assert_that!(sut_consumer.pop_batch(&mut buffer), eq 0);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+fn spsc_safely_overflowing_index_queue_peek_does_not_remove_values() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeSafelyOverflowingIndexQueue::<CAPACITY>::new();
+    let mut sut_producer = sut.acquire_producer().unwrap();
+    let mut sut_consumer = sut.acquire_consumer().unwrap();
+
+    assert_that!(sut_consumer.peek(), is_none);
+    assert_that!(sut_consumer.peek_last(), is_none);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut_producer.push(i), is_none);
+        assert_that!(sut_consumer.peek(), eq Some(0));
+        assert_that!(sut_consumer.peek_last(), eq Some(i));
+    }
+    assert_that!(sut, len CAPACITY);
+
+    assert_that!(sut_producer.push(CAPACITY), eq Some(0));
+    assert_that!(sut_consumer.peek(), eq Some(1));
+    assert_that!(sut_consumer.peek_last(), eq Some(CAPACITY));
+
+    for i in 1..CAPACITY + 1 {
+        assert_that!(sut_consumer.peek(), eq Some(i));
+        assert_that!(sut_consumer.pop(), eq Some(i));
+    }
+    assert_that!(sut_consumer.peek(), is_none);
+    assert_that!(sut_consumer.peek_last(), is_none);
+}
+
 #[test]
 fn spsc_safely_overflowing_index_queue_get_consumer_twice_fails() {
     let sut = FixedSizeSafelyOverflowingIndexQueue::<1024>::new();
@@ -0,0 +1,144 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`LogContext`] contains structured key-value fields that are attached to every log
+//! message that is emitted on the same thread while the context is entered. Optionally, it can
+//! redirect the messages into a custom [`Log`]ger.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use iceoryx2_bb_log::{info, context::LogContext};
+//!
+//! let context = Arc::new(LogContext::new().field("component", "camera"));
+//!
+//! {
+//!     let _guard = context.enter();
+//!     // is logged with the field component=camera
+//!     info!("hello world");
+//! }
+//!
+//! // is logged without any field
+//! info!("hello world");
+//! ```
+
+use std::{cell::Cell, fmt::Display, marker::PhantomData, sync::Arc};
+
+use crate::Log;
+
+thread_local! {
+    static CURRENT_CONTEXT: Cell<Option<Arc<LogContext>>> = const { Cell::new(None) };
+}
+
+/// Structured key-value fields and an optional custom [`Log`]ger that are used for every log
+/// message that is emitted while the [`LogContext`] is entered with [`LogContext::enter()`].
+#[derive(Default)]
+pub struct LogContext {
+    fields: Vec<(&'static str, String)>,
+    logger: Option<&'static dyn Log>,
+}
+
+impl std::fmt::Debug for LogContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LogContext {{ fields: {:?}, has_custom_logger: {} }}",
+            self.fields,
+            self.logger.is_some()
+        )
+    }
+}
+
+impl Display for LogContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (n, (key, value)) in self.fields.iter().enumerate() {
+            if n != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LogContext {
+    /// Creates a new [`LogContext`] without any field that uses the global [`Log`]ger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key-value field to the [`LogContext`].
+    pub fn field<T: Display>(mut self, key: &'static str, value: T) -> Self {
+        self.fields.push((key, value.to_string()));
+        self
+    }
+
+    /// Every log message emitted while the [`LogContext`] is entered is forwarded to the
+    /// provided [`Log`]ger instead of the global one, see [`crate::set_logger()`].
+    pub fn logger(mut self, value: &'static dyn Log) -> Self {
+        self.logger = Some(value);
+        self
+    }
+
+    /// Returns all key-value fields of the [`LogContext`].
+    pub fn fields(&self) -> &[(&'static str, String)] {
+        &self.fields
+    }
+
+    /// Returns the custom [`Log`]ger when one was set.
+    pub fn custom_logger(&self) -> Option<&'static dyn Log> {
+        self.logger
+    }
+
+    /// Enters the [`LogContext`] on the current thread. Until the returned
+    /// [`LogContextGuard`] goes out of scope, all log messages of the thread contain the
+    /// fields of the [`LogContext`]. When the guard is dropped the previously entered
+    /// [`LogContext`] is restored.
+    pub fn enter(self: &Arc<Self>) -> LogContextGuard {
+        let previous = CURRENT_CONTEXT
+            .try_with(|current| current.replace(Some(self.clone())))
+            .unwrap_or(None);
+
+        LogContextGuard {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Restores the previously entered [`LogContext`] when it goes out of scope. Acquired with
+/// [`LogContext::enter()`].
+#[derive(Debug)]
+pub struct LogContextGuard {
+    previous: Option<Arc<LogContext>>,
+    // the guard restores the context of the thread that entered it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for LogContextGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        let _ = CURRENT_CONTEXT.try_with(|current| current.set(previous));
+    }
+}
+
+pub(crate) fn with_current_context<F: FnOnce(Option<&LogContext>)>(callback: F) {
+    match CURRENT_CONTEXT.try_with(|current| current.take()) {
+        Ok(Some(context)) => {
+            callback(Some(&context));
+            let _ = CURRENT_CONTEXT.try_with(|current| current.set(Some(context)));
+        }
+        _ => callback(None),
+    }
+}
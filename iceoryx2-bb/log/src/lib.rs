@@ -137,11 +137,26 @@
 //!     println!("{:?} {} {}", entry.log_level, entry.origin, entry.message);
 //! }
 //! ```
+//!
+//! ## Attach structured fields to log messages
+//!
+//! A [`context::LogContext`] adds key-value fields to every log message that is emitted on the
+//! current thread while it is entered.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use iceoryx2_bb_log::{warn, context::LogContext};
+//!
+//! let context = Arc::new(LogContext::new().field("node_name", "my_node"));
+//! let _guard = context.enter();
+//! warn!("something happened");
+//! ```
 
 #[macro_use]
 pub mod log;
 #[macro_use]
 pub mod fail;
+pub mod context;
 pub mod logger;
 
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU8;
@@ -168,6 +183,28 @@ static INIT: Once = Once::new();
 pub trait Log: Send + Sync {
     /// logs a message
     fn log(&self, log_level: LogLevel, origin: Arguments, formatted_message: Arguments);
+
+    /// logs a message that was emitted while a [`context::LogContext`] was entered. The default
+    /// implementation prepends the fields of the context to the origin and calls [`Log::log()`].
+    fn log_with_context(
+        &self,
+        log_level: LogLevel,
+        origin: Arguments,
+        formatted_message: Arguments,
+        context: &context::LogContext,
+    ) {
+        self.log(
+            log_level,
+            format_args!("[{}] {}", context, origin),
+            formatted_message,
+        )
+    }
+}
+
+impl std::fmt::Debug for dyn Log {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Log")
+    }
 }
 
 /// Describes the log level.
@@ -230,5 +267,11 @@ pub fn get_logger() -> &'static dyn Log {
 
 #[doc(hidden)]
 pub fn __internal_print_log_msg(log_level: LogLevel, origin: Arguments, args: Arguments) {
-    get_logger().log(log_level, origin, args)
+    context::with_current_context(|context| match context {
+        Some(context) => context
+            .custom_logger()
+            .unwrap_or_else(get_logger)
+            .log_with_context(log_level, origin, args, context),
+        None => get_logger().log(log_level, origin, args),
+    })
 }
@@ -12,13 +12,15 @@
 
 use std::sync::Mutex;
 
-use crate::LogLevel;
+use crate::{context::LogContext, LogLevel};
 
 #[derive(Debug, Clone)]
 pub struct Entry {
     pub log_level: LogLevel,
     pub origin: String,
     pub message: String,
+    /// The fields of the [`LogContext`] that was entered when the message was logged.
+    pub context: Vec<(&'static str, String)>,
 }
 
 pub struct Logger {
@@ -98,6 +100,25 @@ impl crate::Log for Logger {
                 log_level,
                 origin: origin.to_string(),
                 message: formatted_message.to_string(),
+                context: vec![],
+            });
+    }
+
+    fn log_with_context(
+        &self,
+        log_level: LogLevel,
+        origin: std::fmt::Arguments,
+        formatted_message: std::fmt::Arguments,
+        context: &LogContext,
+    ) {
+        self.buffer
+            .lock()
+            .expect("Unable to log message since the lock of the log buffer failed.")
+            .push(Entry {
+                log_level,
+                origin: origin.to_string(),
+                message: formatted_message.to_string(),
+                context: context.fields().to_vec(),
             });
     }
 }
@@ -44,6 +44,8 @@ use std::sync::mpsc::channel;
 
 use crate::{get_log_level, LogLevel};
 
+use super::LogFormat;
+
 enum Message {
     Entry(Entry),
     Stop,
@@ -67,6 +69,17 @@ impl Debug for Entry {
     }
 }
 
+impl Entry {
+    fn format(&self, format: LogFormat) -> String {
+        match format {
+            LogFormat::Plain => format!("{:?}", self),
+            LogFormat::Json => {
+                format.format(self.timestamp, self.log_level, &self.origin, &self.message)
+            }
+        }
+    }
+}
+
 /// A logger that logs all messages into a file. It implements an active object pattern. A
 /// background thread waits on a queue of log messages and whenever a new message is added.
 pub struct Logger {
@@ -78,6 +91,11 @@ pub struct Logger {
 impl Logger {
     /// Creates a new file logger.
     pub fn new(file_name: &str) -> Self {
+        Self::with_format(file_name, LogFormat::Plain)
+    }
+
+    /// Creates a new file logger that writes every message in the provided [`LogFormat`].
+    pub fn with_format(file_name: &str, format: LogFormat) -> Self {
         let mut file = OpenOptions::new()
             .append(true)
             .create(true)
@@ -89,7 +107,7 @@ impl Logger {
         let write_buffer_to_file = move || loop {
             match receiver.recv() {
                 Ok(Message::Entry(entry)) => file
-                    .write_all(format!("{:?}\n", entry).as_bytes())
+                    .write_all(format!("{}\n", entry.format(format)).as_bytes())
                     .expect("Writing log message into log file."),
                 Ok(Message::Stop) => break,
                 Err(e) => file
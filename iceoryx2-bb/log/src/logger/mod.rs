@@ -18,9 +18,100 @@ pub mod console;
 pub mod file;
 #[cfg(feature = "logger_log")]
 pub mod log;
+pub mod stderr;
+#[cfg(unix)]
+pub mod syslog;
 #[cfg(feature = "logger_tracing")]
 pub mod tracing;
 
+use std::{fmt::Write, time::Duration};
+
+use crate::LogLevel;
+
+/// Defines how a log message is written by the loggers that support multiple formats, like the
+/// [`stderr::Logger`] or the [`file::Logger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human readable plain text.
+    #[default]
+    Plain,
+    /// One JSON object per log message, e.g.
+    /// `{"timestamp":1718192021.000000042,"level":"WARN","origin":"...","message":"..."}`.
+    Json,
+}
+
+impl LogFormat {
+    fn level_name(log_level: LogLevel) -> &'static str {
+        match log_level {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+            LogLevel::Fatal => "FATAL",
+        }
+    }
+
+    /// Formats a log message without a trailing newline. The timestamp is the duration since
+    /// the UNIX epoch.
+    pub(crate) fn format(
+        &self,
+        timestamp: Duration,
+        log_level: LogLevel,
+        origin: &str,
+        message: &str,
+    ) -> String {
+        match self {
+            LogFormat::Plain => match origin.is_empty() {
+                true => format!(
+                    "{}.{:0>9} [{}] {}",
+                    timestamp.as_secs(),
+                    timestamp.subsec_nanos(),
+                    Self::level_name(log_level),
+                    message
+                ),
+                false => format!(
+                    "{}.{:0>9} [{}] {} | {}",
+                    timestamp.as_secs(),
+                    timestamp.subsec_nanos(),
+                    Self::level_name(log_level),
+                    origin,
+                    message
+                ),
+            },
+            LogFormat::Json => {
+                let mut json = format!(
+                    "{{\"timestamp\":{}.{:0>9},\"level\":\"{}\",\"origin\":\"",
+                    timestamp.as_secs(),
+                    timestamp.subsec_nanos(),
+                    Self::level_name(log_level)
+                );
+                Self::push_json_escaped(&mut json, origin);
+                json.push_str("\",\"message\":\"");
+                Self::push_json_escaped(&mut json, message);
+                json.push_str("\"}");
+                json
+            }
+        }
+    }
+
+    fn push_json_escaped(output: &mut String, value: &str) {
+        for c in value.chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                c if (c as u32) < 0x20 => {
+                    let _ = write!(output, "\\u{:04x}", c as u32);
+                }
+                c => output.push(c),
+            }
+        }
+    }
+}
+
 /// Sets the [`console::Logger`] as default logger
 pub fn use_console_logger() -> bool {
     // LazyLock is only available from 1.80 but currently iceoryx2 has the minimum version 1.75.
@@ -37,3 +128,26 @@ pub fn use_file_logger(log_file_name: &str) -> bool {
 
     crate::set_logger(logger)
 }
+
+/// Sets the [`file::Logger`] that writes the messages in the provided [`LogFormat`] as default
+/// logger
+pub fn use_file_logger_with_format(log_file_name: &str, format: LogFormat) -> bool {
+    let logger = Box::leak(Box::new(file::Logger::with_format(log_file_name, format)));
+
+    crate::set_logger(logger)
+}
+
+/// Sets the [`stderr::Logger`] as default logger
+pub fn use_stderr_logger(format: LogFormat) -> bool {
+    let logger = Box::leak(Box::new(stderr::Logger::new(format)));
+
+    crate::set_logger(logger)
+}
+
+/// Sets the [`syslog::Logger`] as default logger
+#[cfg(unix)]
+pub fn use_syslog_logger(format: LogFormat) -> bool {
+    let logger = Box::leak(Box::new(syslog::Logger::new(format)));
+
+    crate::set_logger(logger)
+}
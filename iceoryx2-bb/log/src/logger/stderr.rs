@@ -0,0 +1,75 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! Using the stderr logger with JSON formatted messages.
+//!
+//! ```
+//! use iceoryx2_bb_log::{info, logger::{use_stderr_logger, LogFormat}};
+//!
+//! use_stderr_logger(LogFormat::Json);
+//!
+//! // written as JSON object into stderr
+//! info!("hello world");
+//! ```
+
+use std::{io::Write, time::SystemTime};
+
+use crate::{get_log_level, LogLevel};
+
+use super::LogFormat;
+
+/// A logger that writes every message as a single line into stderr.
+#[derive(Debug)]
+pub struct Logger {
+    format: LogFormat,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new(LogFormat::default())
+    }
+}
+
+impl Logger {
+    /// Creates a new stderr logger that writes every message in the provided [`LogFormat`].
+    pub const fn new(format: LogFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl crate::Log for Logger {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: std::fmt::Arguments,
+        formatted_message: std::fmt::Arguments,
+    ) {
+        if get_log_level() > log_level as u8 {
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let line = self.format.format(
+            timestamp,
+            log_level,
+            &origin.to_string(),
+            &formatted_message.to_string(),
+        );
+
+        // a single write call so that the lines of concurrent loggers are not interleaved
+        let _ = std::io::stderr().write_all(format!("{}\n", line).as_bytes());
+    }
+}
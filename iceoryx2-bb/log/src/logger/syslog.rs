@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! Sending all log messages to the local syslog daemon.
+//!
+//! ```no_run
+//! use iceoryx2_bb_log::{info, logger::{use_syslog_logger, LogFormat}};
+//!
+//! use_syslog_logger(LogFormat::Plain);
+//!
+//! // sent to the syslog daemon
+//! info!("hello world");
+//! ```
+
+use std::{os::unix::net::UnixDatagram, sync::Mutex, time::SystemTime};
+
+use crate::{get_log_level, LogLevel};
+
+use super::LogFormat;
+
+#[cfg(target_os = "macos")]
+const SYSLOG_SOCKETS: &[&str] = &["/var/run/syslog"];
+#[cfg(not(target_os = "macos"))]
+const SYSLOG_SOCKETS: &[&str] = &["/dev/log", "/var/run/syslog"];
+
+/// The syslog facility "user-level messages"
+const FACILITY_USER: u8 = 1;
+
+/// A logger that sends every message to the local syslog daemon. The connection is
+/// established with the first message and reestablished when the daemon was restarted.
+/// Messages are discarded when no syslog daemon is running.
+#[derive(Debug)]
+pub struct Logger {
+    format: LogFormat,
+    socket: Mutex<Option<UnixDatagram>>,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new(LogFormat::default())
+    }
+}
+
+impl Logger {
+    /// Creates a new syslog logger that sends every message in the provided [`LogFormat`].
+    pub const fn new(format: LogFormat) -> Self {
+        Self {
+            format,
+            socket: Mutex::new(None),
+        }
+    }
+
+    fn severity(log_level: LogLevel) -> u8 {
+        match log_level {
+            LogLevel::Trace | LogLevel::Debug => 7,
+            LogLevel::Info => 6,
+            LogLevel::Warn => 4,
+            LogLevel::Error => 3,
+            LogLevel::Fatal => 2,
+        }
+    }
+
+    fn connect() -> Option<UnixDatagram> {
+        let socket = UnixDatagram::unbound().ok()?;
+        SYSLOG_SOCKETS
+            .iter()
+            .any(|path| socket.connect(path).is_ok())
+            .then_some(socket)
+    }
+}
+
+impl crate::Log for Logger {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: std::fmt::Arguments,
+        formatted_message: std::fmt::Arguments,
+    ) {
+        if get_log_level() > log_level as u8 {
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let message = format!(
+            "<{}>iceoryx2[{}]: {}",
+            FACILITY_USER * 8 + Self::severity(log_level),
+            std::process::id(),
+            self.format.format(
+                timestamp,
+                log_level,
+                &origin.to_string(),
+                &formatted_message.to_string()
+            )
+        );
+
+        let mut socket = match self.socket.lock() {
+            Ok(socket) => socket,
+            Err(_) => return,
+        };
+
+        for _ in 0..2 {
+            if socket.is_none() {
+                *socket = Self::connect();
+            }
+
+            match &*socket {
+                Some(s) => match s.send(message.as_bytes()) {
+                    Ok(_) => return,
+                    // the syslog daemon may have been restarted, reconnect once
+                    Err(_) => *socket = None,
+                },
+                None => return,
+            }
+        }
+    }
+}
@@ -38,4 +38,21 @@ impl crate::Log for Logger {
             LogLevel::Fatal => tracing::error!(origin, "{}", formatted_message),
         }
     }
+
+    fn log_with_context(
+        &self,
+        log_level: crate::LogLevel,
+        origin: std::fmt::Arguments,
+        formatted_message: std::fmt::Arguments,
+        context: &crate::context::LogContext,
+    ) {
+        match log_level {
+            LogLevel::Trace => tracing::trace!(origin, context = %context, "{}", formatted_message),
+            LogLevel::Debug => tracing::debug!(origin, context = %context, "{}", formatted_message),
+            LogLevel::Info => tracing::info!(origin, context = %context, "{}", formatted_message),
+            LogLevel::Warn => tracing::warn!(origin, context = %context, "{}", formatted_message),
+            LogLevel::Error => tracing::error!(origin, context = %context, "{}", formatted_message),
+            LogLevel::Fatal => tracing::error!(origin, context = %context, "{}", formatted_message),
+        }
+    }
 }
@@ -67,6 +67,19 @@ enum_gen! { FileRemoveError
     UnknownError(i32)
 }
 
+enum_gen! { FileRenameError
+  entry:
+    InsufficientPermissions,
+    CurrentlyInUse,
+    FileDoesNotExist,
+    TargetIsDirectory,
+    LoopInSymbolicLinks,
+    MaxSupportedPathLengthExceeded,
+    PartOfReadOnlyFileSystem,
+    NotOnTheSameFileSystem,
+    UnknownError(i32)
+}
+
 enum_gen! { FileAccessError
   entry:
     LoopInSymbolicLinks,
@@ -210,7 +223,7 @@ enum_gen! {
     FileError
   generalization:
     Create <= FileCreationError,
-    Write <= FileSyncError; FileWriteError; FileTruncateError; FileRemoveError,
+    Write <= FileSyncError; FileWriteError; FileTruncateError; FileRemoveError; FileRenameError,
     Read <= FileOffsetError; FileReadError; FileOpenError; FileAccessError,
     Credentials <= FileSetOwnerError; FileSetPermissionError,
     Stat <= FileStatError
@@ -755,6 +768,30 @@ impl File {
         );
     }
 
+    /// Renames the file `from` into `to` in one atomic step. If `to` already exists it is
+    /// replaced, so that other processes either open the previous or the new file but never
+    /// observe a missing one. Both paths must be located on the same file system.
+    pub fn rename(from: &FilePath, to: &FilePath) -> Result<(), FileRenameError> {
+        let msg = "Unable to rename file";
+        if unsafe { posix::rename(from.as_c_str(), to.as_c_str()) } == 0 {
+            trace!(from "File::rename", "\"{}\" to \"{}\"", from, to);
+            return Ok(());
+        }
+
+        handle_errno!(FileRenameError, from "File::rename",
+            Errno::EACCES => (InsufficientPermissions, "{} \"{}\" to \"{}\" due to insufficient permissions.", msg, from, to),
+            Errno::EPERM => (InsufficientPermissions, "{} \"{}\" to \"{}\" due to insufficient permissions.", msg, from, to),
+            Errno::EBUSY => (CurrentlyInUse, "{} \"{}\" to \"{}\" since it is currently in use.", msg, from, to),
+            Errno::ENOENT => (FileDoesNotExist, "{} \"{}\" to \"{}\" since the file does not exist.", msg, from, to),
+            Errno::EISDIR => (TargetIsDirectory, "{} \"{}\" to \"{}\" since the target is a directory.", msg, from, to),
+            Errno::ELOOP => (LoopInSymbolicLinks, "{} \"{}\" to \"{}\" since a loop exists in the symbolic links.", msg, from, to),
+            Errno::ENAMETOOLONG => (MaxSupportedPathLengthExceeded, "{} \"{}\" to \"{}\" since it is longer than the maximum path name length.", msg, from, to),
+            Errno::EROFS => (PartOfReadOnlyFileSystem, "{} \"{}\" to \"{}\" since it is part of a read-only filesystem.", msg, from, to),
+            Errno::EXDEV => (NotOnTheSameFileSystem, "{} \"{}\" to \"{}\" since both are not located on the same file system.", msg, from, to),
+            v => (UnknownError(v as i32), "{} \"{}\" to \"{}\" since an unknown error occurred ({}).", msg, from, to, v)
+        );
+    }
+
     pub(crate) fn truncate<T: FileDescriptorBased + Debug>(
         this: &T,
         size: usize,
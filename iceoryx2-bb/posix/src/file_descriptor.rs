@@ -223,6 +223,21 @@ impl Drop for FileDescriptor {
     }
 }
 
+#[cfg(unix)]
+impl std::os::fd::AsRawFd for FileDescriptor {
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        self.value
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsFd for FileDescriptor {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        // the FileDescriptor is valid until it is dropped and the BorrowedFd cannot outlive it
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(self.value) }
+    }
+}
+
 /// Every construct which is based on some [`FileDescriptor`] can implement this trait to gain
 /// extended [`FileDescriptorManagement`] features.
 pub trait FileDescriptorBased {
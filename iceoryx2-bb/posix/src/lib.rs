@@ -21,6 +21,7 @@ use group::GroupError;
 use iceoryx2_bb_elementary::enum_gen;
 use memory_lock::MemoryLockError;
 use mutex::MutexError;
+use pipe::PipeError;
 use process::ProcessError;
 use read_write_mutex::ReadWriteMutexError;
 use semaphore::SemaphoreError;
@@ -50,12 +51,14 @@ pub mod file_type;
 pub mod group;
 pub mod ipc_capable;
 pub mod memory;
+pub mod memory_advice;
 pub mod memory_lock;
 pub mod message_queue;
 pub mod metadata;
 pub mod mutex;
 pub mod ownership;
 pub mod permission;
+pub mod pipe;
 pub mod process;
 pub mod process_state;
 pub mod read_write_mutex;
@@ -82,6 +85,7 @@ enum_gen! {Error
     Group <= GroupError,
     MemoryLock <= MemoryLockError,
     Mutex <= MutexError,
+    Pipe <= PipeError,
     Process <= ProcessError,
     ReadWriteMutex <= ReadWriteMutexError,
     Semaphore <= SemaphoreError,
@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides hints to the operating system how a memory region is going to be used, for instance
+//! that it shall be backed by huge pages to reduce the pressure on the translation lookaside
+//! buffer (TLB).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::memory_advice::*;
+//!
+//! let some_memory = [0u8; 8192];
+//!
+//! // fails on platforms that do not support transparent huge pages
+//! let _ = unsafe {
+//!     advise(some_memory.as_ptr().cast(), some_memory.len(), MemoryAdvice::HugePages)
+//! };
+//!
+//! // the advice is only a hint, the mapping of the process shows whether it was applied
+//! println!("uses huge pages: {}", is_mapped_with_huge_pages(some_memory.as_ptr().cast()));
+//! ```
+
+use crate::handle_errno;
+use crate::system_configuration::SystemInfo;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MemoryAdviceError {
+    InvalidAddressRange,
+    NotSupported,
+    InsufficientResources,
+    UnknownError(i32),
+}
+
+/// Defines how a memory region is going to be used.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(i32)]
+pub enum MemoryAdvice {
+    /// The memory region shall be backed by transparent huge pages. Only supported on Linux.
+    HugePages = posix::MADV_HUGEPAGE,
+}
+
+/// Applies the [`MemoryAdvice`] to the memory region. The region is extended to the page
+/// boundaries that surround it.
+///
+/// # Safety
+///   * the memory range [address, len] must be mapped into the address space of the process
+///
+pub unsafe fn advise(
+    address: *const posix::void,
+    len: usize,
+    advice: MemoryAdvice,
+) -> Result<(), MemoryAdviceError> {
+    let page_size = SystemInfo::PageSize.value();
+    let start = address as usize / page_size * page_size;
+    let len = address as usize + len - start;
+
+    if unsafe { posix::madvise(start as *mut posix::void, len, advice as _) } == 0 {
+        return Ok(());
+    }
+
+    let msg = "Unable to apply the memory advice";
+    handle_errno!(MemoryAdviceError, from "memory_advice::advise()",
+        Errno::ENOMEM => (InvalidAddressRange, "{} {:?} since the range beginning from {:#16X} with a length of {} is not mapped in the address space of the current process.", msg, advice, start, len),
+        Errno::EINVAL => (NotSupported, "{} {:?} since it is not supported by the system.", msg, advice),
+        Errno::ENOTSUP => (NotSupported, "{} {:?} since it is not supported by the platform.", msg, advice),
+        Errno::EAGAIN => (InsufficientResources, "{} {:?} due to insufficient kernel resources.", msg, advice),
+        v => (UnknownError(v as i32), "{} {:?} since an unknown error occurred ({}).", msg, advice, v)
+    );
+}
+
+/// Returns the size of a transparent huge page when the system supports them, otherwise
+/// [`None`].
+pub fn huge_page_size() -> Option<usize> {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/sys/kernel/mm/transparent_hugepage/hpage_pmd_size")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .filter(|v| *v > SystemInfo::PageSize.value())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Returns true when the memory mapping of the current process that contains the provided
+/// address is backed by huge pages. The system decides on the first access of a page whether
+/// it is backed by a huge page, therefore a mapping whose pages were never accessed is not
+/// backed by huge pages.
+pub fn is_mapped_with_huge_pages(address: *const posix::void) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        const HUGE_PAGE_FIELDS: [&str; 5] = [
+            "AnonHugePages:",
+            "ShmemPmdMapped:",
+            "FilePmdMapped:",
+            "Shared_Hugetlb:",
+            "Private_Hugetlb:",
+        ];
+
+        let smaps = match std::fs::read_to_string("/proc/self/smaps") {
+            Ok(smaps) => smaps,
+            Err(_) => return false,
+        };
+
+        let address = address as usize;
+        let mut is_in_mapping = false;
+        for line in smaps.lines() {
+            let mut tokens = line.split_whitespace();
+            let key = tokens.next().unwrap_or_default();
+
+            if let Some((start, end)) = key.split_once('-') {
+                if let (Ok(start), Ok(end)) = (
+                    usize::from_str_radix(start, 16),
+                    usize::from_str_radix(end, 16),
+                ) {
+                    is_in_mapping = start <= address && address < end;
+                    continue;
+                }
+            }
+
+            if is_in_mapping
+                && HUGE_PAGE_FIELDS.contains(&key)
+                && tokens.next().and_then(|v| v.parse::<usize>().ok()) > Some(0)
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = address;
+        false
+    }
+}
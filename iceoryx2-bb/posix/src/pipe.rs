@@ -0,0 +1,178 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An anonymous, unidirectional and non-blocking [`Pipe`]. It does not require any
+//! file system entry and is therefore suited to wake up an event loop from within the same
+//! process, for instance from a signal handler.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::pipe::*;
+//!
+//! let pipe = Pipe::new().expect("failed to create pipe");
+//!
+//! pipe.write(b"x").expect("failed to write");
+//!
+//! let mut buffer = [0u8; 16];
+//! let received_bytes = pipe.try_read(&mut buffer).expect("failed to read");
+//! assert_eq!(received_bytes, 1);
+//! ```
+
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::SynchronousMultiplexing;
+use crate::handle_errno;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipeCreationError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    NotSupported,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipeReadError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipeWriteError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+enum_gen! {
+    /// The PipeError enum is a generalization when one doesn't require the fine-grained error
+    /// handling enums. One can forward PipeError as more generic return value when a method
+    /// returns a Pipe***Error.
+    /// On a higher level it is again convertable to [`crate::Error`].
+    PipeError
+  generalization:
+    CreationFailed <= PipeCreationError,
+    TransmissionFailed <= PipeReadError; PipeWriteError
+}
+
+/// An anonymous, unidirectional and non-blocking pipe. The [`FileDescriptor`] of
+/// [`FileDescriptorBased`] is the read end, it becomes readable as soon as data was written
+/// into the write end.
+#[derive(Debug)]
+pub struct Pipe {
+    read_end: FileDescriptor,
+    write_end: FileDescriptor,
+}
+
+impl FileDescriptorBased for Pipe {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.read_end
+    }
+}
+
+impl SynchronousMultiplexing for Pipe {}
+
+impl Pipe {
+    /// Creates a new [`Pipe`] with a non-blocking read and write end.
+    pub fn new() -> Result<Self, PipeCreationError> {
+        let msg = "Unable to create pipe";
+        let origin = "Pipe::new()";
+        let mut fildes: [posix::int; 2] = [-1, -1];
+
+        if unsafe { posix::pipe(fildes.as_mut_ptr()) } != 0 {
+            handle_errno!(PipeCreationError, from origin,
+                Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+                Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+                Errno::ENOSYS => (NotSupported, "{} since pipes are not supported on this platform.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        // the file descriptors are owned as soon as they exist so that they are closed on
+        // every error path
+        let read_end = unsafe { FileDescriptor::new_unchecked(fildes[0]) };
+        let write_end = unsafe { FileDescriptor::new_unchecked(fildes[1]) };
+
+        for fd in [&read_end, &write_end] {
+            let handle = unsafe { fd.native_handle() };
+            let flags = unsafe { posix::fcntl_int(handle, posix::F_GETFL, 0) };
+            if flags < 0
+                || unsafe { posix::fcntl_int(handle, posix::F_SETFL, flags | posix::O_NONBLOCK) }
+                    < 0
+            {
+                let v = Errno::get();
+                fail!(from origin, with PipeCreationError::UnknownError(v as i32),
+                    "{} since the pipe could not be set into non-blocking mode ({}).", msg, v);
+            }
+        }
+
+        Ok(Self {
+            read_end,
+            write_end,
+        })
+    }
+
+    /// Returns the [`FileDescriptor`] of the write end. Writing into it from a signal handler
+    /// is async-signal-safe.
+    pub fn write_end(&self) -> &FileDescriptor {
+        &self.write_end
+    }
+
+    /// Writes the data into the [`Pipe`] and returns the number of written bytes. Returns 0
+    /// when the [`Pipe`] is full.
+    pub fn write(&self, data: &[u8]) -> Result<usize, PipeWriteError> {
+        let result = unsafe {
+            posix::write(
+                self.write_end.native_handle(),
+                data.as_ptr().cast(),
+                data.len(),
+            )
+        };
+
+        if result >= 0 {
+            return Ok(result as usize);
+        }
+
+        let msg = "Unable to write into pipe";
+        handle_errno!(PipeWriteError, from self,
+            success Errno::EAGAIN => 0,
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Reads the available data from the [`Pipe`] without blocking and returns the number of
+    /// read bytes. Returns 0 when no data is available.
+    pub fn try_read(&self, buffer: &mut [u8]) -> Result<usize, PipeReadError> {
+        let result = unsafe {
+            posix::read(
+                self.read_end.native_handle(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len(),
+            )
+        };
+
+        if result >= 0 {
+            return Ok(result as usize);
+        }
+
+        let msg = "Unable to read from pipe";
+        handle_errno!(PipeReadError, from self,
+            success Errno::EAGAIN => 0,
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
@@ -99,6 +99,11 @@ enum_gen! {
     FailedToSendSignal <= ProcessSendSignalError
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ProcessStartTimeError {
+    UnableToRead,
+}
+
 /// Trait to be able to convert integers into processes by interpreting their value as the
 /// process id
 pub trait ProcessExt {
@@ -189,6 +194,19 @@ impl Process {
         Ok(path)
     }
 
+    /// Returns the start time of the [`Process`]. The unit is platform specific, the value can
+    /// only be compared with another start time of the same [`ProcessId`], for instance to
+    /// detect that the [`ProcessId`] was reused by a new process.
+    pub fn start_time(&self) -> Result<u64, ProcessStartTimeError> {
+        let mut start_time = 0;
+        if unsafe { posix::proc_pidstarttime(self.pid.0, &mut start_time) } != 0 {
+            fail!(from self, with ProcessStartTimeError::UnableToRead,
+                "Unable to acquire the start time of the process.");
+        }
+
+        Ok(start_time)
+    }
+
     /// Sends a signal to the process.
     pub fn send_signal(&self, signal: Signal) -> Result<(), ProcessSendSignalError> {
         if unsafe { posix::kill(self.pid.0, signal as i32) } == 0 {
@@ -469,10 +469,17 @@ impl ProcessGuard {
         }
     }
 
-    pub(crate) fn staged_death(mut self) {
+    /// Releases the [`ProcessGuard`] without removing the underlying state files. Every
+    /// [`ProcessMonitor`] sees the process as [`ProcessState::Dead`] from then on, as if it had
+    /// crashed, and a [`ProcessCleaner`] can be acquired to remove the stale resources.
+    pub fn abandon(mut self) {
         self.file.release_ownership();
         self.owner_lock_file.release_ownership();
     }
+
+    pub(crate) fn staged_death(self) {
+        self.abandon();
+    }
 }
 
 /// Monitor processes that have created a [`ProcessGuard`]. If the process dies, shutdowns or is
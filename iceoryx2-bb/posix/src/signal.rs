@@ -60,13 +60,14 @@ use std::{
 use crate::{
     adaptive_wait::*,
     clock::{NanosleepError, Time, TimeError},
+    file_descriptor::FileDescriptor,
     file_lock::ClockType,
     mutex::*,
 };
 use enum_iterator::{all, Sequence};
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::{fail, fatal_panic};
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicI32, IoxAtomicUsize};
 use iceoryx2_pal_posix::posix::{Errno, Struct};
 use iceoryx2_pal_posix::*;
 use lazy_static::lazy_static;
@@ -220,6 +221,7 @@ define_signals! {
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum SignalRegisterError {
     AlreadyRegistered,
+    ExceedsMaxSupportedWakeups,
 }
 
 enum_gen! { SignalWaitError
@@ -275,6 +277,42 @@ impl Drop for SignalGuard {
 
 static LAST_SIGNAL: IoxAtomicUsize = IoxAtomicUsize::new(posix::MAX_SIGNAL_VALUE);
 
+/// The maximum number of file descriptors that can be registered with
+/// [`SignalHandler::register_termination_wakeup()`] at the same time.
+pub const MAX_TERMINATION_WAKEUPS: usize = 32;
+
+#[allow(clippy::declare_interior_mutable_const)]
+const NO_TERMINATION_WAKEUP: IoxAtomicI32 = IoxAtomicI32::new(-1);
+static TERMINATION_WAKEUPS: [IoxAtomicI32; MAX_TERMINATION_WAKEUPS] =
+    [NO_TERMINATION_WAKEUP; MAX_TERMINATION_WAKEUPS];
+// number of signal handlers that are currently writing into the file descriptor of a slot,
+// a slot is only released when no handler uses its file descriptor anymore so that the
+// handler never writes into a file descriptor that was closed and reused in the meantime
+#[allow(clippy::declare_interior_mutable_const)]
+const NO_TERMINATION_WAKEUP_WRITER: IoxAtomicUsize = IoxAtomicUsize::new(0);
+static TERMINATION_WAKEUP_WRITERS: [IoxAtomicUsize; MAX_TERMINATION_WAKEUPS] =
+    [NO_TERMINATION_WAKEUP_WRITER; MAX_TERMINATION_WAKEUPS];
+
+/// Guards a file descriptor that was registered with
+/// [`SignalHandler::register_termination_wakeup()`]. When it goes out of scope the file
+/// descriptor is deregistered. The drop waits until no signal handler writes into the file
+/// descriptor anymore, therefore it can be closed safely afterwards.
+#[derive(Debug)]
+pub struct TerminationWakeupGuard {
+    index: usize,
+}
+
+impl Drop for TerminationWakeupGuard {
+    fn drop(&mut self) {
+        TERMINATION_WAKEUPS[self.index].store(-1, Ordering::SeqCst);
+        // a signal handler that loaded the file descriptor before it was removed finishes
+        // its non-blocking write in a bounded time
+        while TERMINATION_WAKEUP_WRITERS[self.index].load(Ordering::SeqCst) != 0 {
+            std::hint::spin_loop();
+        }
+    }
+}
+
 /// Manages POSIX signal handling. It provides an interface to register custom callbacks for
 /// signals, to perform a blocking wait until a certain signal arrived (for instance like CTRL+c) and
 /// tracks signals which were received by the process.
@@ -319,6 +357,25 @@ extern "C" fn handler(signal: posix::int) {
 
 extern "C" fn capture_signal(signal: posix::int) {
     LAST_SIGNAL.store(signal as usize, Ordering::Relaxed);
+
+    if signal == posix::SIGINT || signal == posix::SIGTERM {
+        let wakeup_byte: u8 = 1;
+        for (wakeup, writers) in TERMINATION_WAKEUPS
+            .iter()
+            .zip(TERMINATION_WAKEUP_WRITERS.iter())
+        {
+            // announces the write before the file descriptor is loaded so that the guard
+            // cannot release the slot while the file descriptor is in use
+            writers.fetch_add(1, Ordering::SeqCst);
+            let fd = wakeup.load(Ordering::SeqCst);
+            if fd >= 0 {
+                // write is async-signal-safe, the result is irrelevant since a full buffer
+                // already wakes up the receiver
+                unsafe { posix::write(fd, (&wakeup_byte as *const u8).cast(), 1) };
+            }
+            writers.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
 }
 
 impl Drop for SignalHandler {
@@ -405,6 +462,32 @@ impl SignalHandler {
         }
     }
 
+    /// Registers a file descriptor into which a single byte is written whenever
+    /// [`NonFatalFetchableSignal::Interrupt`] or [`NonFatalFetchableSignal::Terminate`] is
+    /// received. It allows event loops that wait on file descriptors to wake up on a termination
+    /// request. The file descriptor must be non-blocking and must stay valid as long as the
+    /// returned [`TerminationWakeupGuard`] lives. A [`crate::pipe::Pipe`] is a suitable
+    /// wakeup, its write end is registered and its read end becomes readable.
+    pub fn register_termination_wakeup(
+        fd: &FileDescriptor,
+    ) -> Result<TerminationWakeupGuard, SignalRegisterError> {
+        // ensures that the signal handler is installed
+        Self::instance();
+
+        let fd = unsafe { fd.native_handle() };
+        for (index, wakeup) in TERMINATION_WAKEUPS.iter().enumerate() {
+            if wakeup
+                .compare_exchange(-1, fd, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Ok(TerminationWakeupGuard { index });
+            }
+        }
+
+        fail!(from "SignalHandler::register_termination_wakeup()", with SignalRegisterError::ExceedsMaxSupportedWakeups,
+            "Unable to register termination wakeup since more than {} wakeups are registered.", MAX_TERMINATION_WAKEUPS);
+    }
+
     /// Returns true if ([`NonFatalFetchableSignal::Interrupt`] or
     /// [`NonFatalFetchableSignal::Terminate`]) was emitted
     /// for instance by pressing CTRL+c, otherwise false
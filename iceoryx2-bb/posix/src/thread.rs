@@ -239,6 +239,33 @@ impl ThreadBuilder {
         self
     }
 
+    /// Sets the threads CPU affinity to multiple CPU cores. CPU cores that do not exist are
+    /// ignored. When none of the provided CPU cores is supported, the affinity remains
+    /// unchanged. See [`ThreadBuilder::affinity()`] for more details.
+    pub fn affinity_to_cores(mut self, cores: &[usize]) -> Self {
+        let number_of_cores = SystemInfo::NumberOfCpuCores.value();
+        let mut affinity = [false; posix::CPU_SETSIZE];
+        let mut has_supported_core = false;
+
+        for core in cores {
+            if *core >= number_of_cores {
+                warn!(from self, "The system has cpu cores in the range [0, {}]. Adding cpu core {} to the affinity will have no effect.", number_of_cores - 1, core);
+            }
+            if *core >= posix::CPU_SETSIZE || *core > MAX_SUPPORTED_CPUS_IN_SYSTEM {
+                warn!(from self, "Maximum range of supported CPUs is [0, {}]. Unable to add cpu core {} to the affinity.", number_of_cores - 1, core);
+                continue;
+            }
+
+            affinity[*core] = true;
+            has_supported_core = true;
+        }
+
+        if has_supported_core {
+            self.affinity = affinity;
+        }
+        self
+    }
+
     /// Sets the priority of the thread whereby `0` represents the lowest and `255` the highest
     /// priority. Since the underlying scheduler priority varies in range the values are mapped
     /// to the scheduler dependent priority.
@@ -754,6 +781,9 @@ pub struct Thread<'thread> {
     _stack: Option<&'thread mut [u8]>,
 }
 
+// a thread can be joined from any other thread, therefore its ownership can be transferred
+unsafe impl Send for Thread<'_> {}
+
 impl<'thread> Debug for Thread<'thread> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Thread {{ handle: {:?} }}", self.handle)
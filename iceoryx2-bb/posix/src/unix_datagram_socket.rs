@@ -569,7 +569,10 @@ impl UnixDatagramSender {
         &self.socket.name
     }
 
-    fn set_non_blocking(&self, value: bool) -> Result<(), UnixDatagramSetPropertyError> {
+    /// Defines if a send operation on the underlying [`FileDescriptor`] blocks. It is adjusted
+    /// by every send call of the [`UnixDatagramSender`] and is only required when the
+    /// [`FileDescriptor`] is written directly, for instance from inside a signal handler.
+    pub fn set_non_blocking(&self, value: bool) -> Result<(), UnixDatagramSetPropertyError> {
         self.socket.set_non_blocking(value)
     }
 
@@ -265,6 +265,35 @@ fn file_remove_returns_false_when_file_not_exists() -> Result<(), FileError> {
     Ok(())
 }
 
+#[test]
+fn file_rename_replaces_existing_file() -> Result<(), FileError> {
+    let test = TestFixture::new();
+    let source = generate_file_name();
+    let mut file = test.create_file(&source);
+    file.write(b"new content")?;
+    let mut file = test.create_file(test.file());
+    file.write(b"old")?;
+
+    File::rename(&source, test.file())?;
+
+    let mut content = String::new();
+    test.open_file(test.file()).read_to_string(&mut content)?;
+    assert_that!(content, eq "new content");
+    assert_that!(File::does_exist(&source)?, eq false);
+    Ok(())
+}
+
+#[test]
+fn file_rename_of_non_existing_file_fails() {
+    let test = TestFixture::new();
+    let source = generate_file_name();
+
+    let result = File::rename(&source, test.file());
+
+    assert_that!(result, is_err);
+    assert_that!(result.err().unwrap(), eq FileRenameError::FileDoesNotExist);
+}
+
 #[test]
 fn file_newly_created_file_is_removed_when_it_has_ownership() -> Result<(), FileError> {
     create_test_directory();
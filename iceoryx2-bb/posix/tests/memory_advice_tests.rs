@@ -0,0 +1,53 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::memory_advice::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_pal_posix::posix;
+
+#[test]
+fn memory_advice_huge_pages_succeeds_or_is_not_supported() {
+    let some_memory = vec![0u8; 4 * 1024 * 1024];
+
+    let result = unsafe {
+        advise(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+            MemoryAdvice::HugePages,
+        )
+    };
+
+    if let Err(e) = result {
+        assert_that!(e, eq MemoryAdviceError::NotSupported);
+    }
+}
+
+#[test]
+#[cfg(not(target_os = "linux"))]
+fn memory_advice_huge_pages_is_not_supported_on_non_linux_platforms() {
+    let some_memory = vec![0u8; 8192];
+
+    let result = unsafe {
+        advise(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+            MemoryAdvice::HugePages,
+        )
+    };
+
+    assert_that!(result, eq Err(MemoryAdviceError::NotSupported));
+}
+
+#[test]
+fn memory_that_is_not_mapped_is_not_mapped_with_huge_pages() {
+    assert_that!(is_mapped_with_huge_pages(core::ptr::null()), eq false);
+}
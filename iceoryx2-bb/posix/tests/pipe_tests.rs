@@ -0,0 +1,73 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::file_descriptor_set::*;
+use iceoryx2_bb_posix::pipe::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::test_requires;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PIPE;
+use std::time::Duration;
+
+static TIMEOUT: Duration = Duration::from_millis(10);
+
+#[test]
+fn pipe_try_read_without_data_does_not_block() {
+    test_requires!(POSIX_SUPPORT_PIPE);
+
+    let sut = Pipe::new().unwrap();
+
+    let mut buffer = [0u8; 8];
+    assert_that!(sut.try_read(&mut buffer), eq Ok(0));
+}
+
+#[test]
+fn pipe_written_data_can_be_read() {
+    test_requires!(POSIX_SUPPORT_PIPE);
+
+    let sut = Pipe::new().unwrap();
+
+    assert_that!(sut.write(b"hello"), eq Ok(5));
+
+    let mut buffer = [0u8; 8];
+    assert_that!(sut.try_read(&mut buffer), eq Ok(5));
+    assert_that!(&buffer[..5], eq b"hello");
+    assert_that!(sut.try_read(&mut buffer), eq Ok(0));
+}
+
+#[test]
+fn pipe_write_into_full_pipe_does_not_block() {
+    test_requires!(POSIX_SUPPORT_PIPE);
+
+    let sut = Pipe::new().unwrap();
+
+    let data = [0u8; 4096];
+    while sut.write(&data).unwrap() != 0 {}
+
+    assert_that!(sut.write(&data), eq Ok(0));
+}
+
+#[test]
+fn pipe_is_readable_in_file_descriptor_set_after_write() {
+    test_requires!(POSIX_SUPPORT_PIPE);
+
+    let sut = Pipe::new().unwrap();
+    let fd_set = FileDescriptorSet::new();
+    let _guard = fd_set.add(&sut).unwrap();
+
+    let number_of_triggers = fd_set.timed_wait(TIMEOUT, FileEvent::Read, |_| {}).unwrap();
+    assert_that!(number_of_triggers, eq 0);
+
+    assert_that!(sut.write(b"x"), eq Ok(1));
+
+    let number_of_triggers = fd_set.timed_wait(TIMEOUT, FileEvent::Read, |_| {}).unwrap();
+    assert_that!(number_of_triggers, eq 1);
+}
@@ -57,3 +57,17 @@ pub fn process_executable_path_works() {
     println!("{}", executable_file);
     assert_that!(executable_file.starts_with("process_tests"), eq true);
 }
+
+#[test]
+#[cfg(not(target_os = "nto"))]
+pub fn process_start_time_works() {
+    let process = Process::from_self();
+    let start_time = process.start_time();
+    assert_that!(start_time, is_ok);
+
+    let process2 = Process::from_pid(process.id());
+    assert_that!(process2.start_time(), eq start_time);
+
+    let process3 = Process::from_pid(ProcessId::new(posix::pid_t::MAX - 1));
+    assert_that!(process3.start_time(), is_err);
+}
@@ -10,9 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_posix::clock::*;
+use iceoryx2_bb_posix::config::test_directory;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::pipe::*;
 use iceoryx2_bb_posix::process::*;
 use iceoryx2_bb_posix::signal::*;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::test_requires;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
@@ -305,3 +314,80 @@ fn signal_termination_requested_with_interrupt_works() {
     );
     assert_that!(SignalHandler::termination_requested(), eq false);
 }
+
+#[test]
+fn signal_termination_wakeup_is_written_on_termination_request() {
+    test_requires!(POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING);
+
+    let _test = TestFixture::new();
+    create_test_directory();
+    let mut file = FileName::new(b"signal_tests_wakeup").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+    let socket_name = FilePath::from_path_and_file(&test_directory(), &file).unwrap();
+
+    let receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    let sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+    assert_that!(sender.set_non_blocking(true), is_ok);
+
+    let guard = SignalHandler::register_termination_wakeup(sender.file_descriptor());
+    assert_that!(guard, is_ok);
+
+    let mut buffer = [0u8; 8];
+    assert_that!(receiver.try_receive(&mut buffer), eq Ok(0));
+
+    assert_that!(Process::from_self().send_signal(Signal::Terminate), is_ok);
+    assert_that!(receiver.timed_receive(&mut buffer, TIMEOUT), eq Ok(1));
+    assert_that!(
+        || { SignalHandler::termination_requested() },
+        block_until true
+    );
+
+    drop(guard);
+    assert_that!(Process::from_self().send_signal(Signal::Terminate), is_ok);
+    assert_that!(
+        || { SignalHandler::termination_requested() },
+        block_until true
+    );
+    assert_that!(receiver.try_receive(&mut buffer), eq Ok(0));
+}
+
+#[test]
+fn signal_termination_wakeup_with_pipe_is_written_on_termination_request() {
+    test_requires!(POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING);
+
+    let _test = TestFixture::new();
+    let pipe = Pipe::new().unwrap();
+
+    let guard = SignalHandler::register_termination_wakeup(pipe.write_end());
+    assert_that!(guard, is_ok);
+
+    let mut buffer = [0u8; 8];
+    assert_that!(pipe.try_read(&mut buffer), eq Ok(0));
+
+    assert_that!(Process::from_self().send_signal(Signal::Terminate), is_ok);
+    assert_that!(
+        || { SignalHandler::termination_requested() },
+        block_until true
+    );
+    assert_that!(pipe.try_read(&mut buffer), eq Ok(1));
+
+    drop(guard);
+    assert_that!(Process::from_self().send_signal(Signal::Terminate), is_ok);
+    assert_that!(
+        || { SignalHandler::termination_requested() },
+        block_until true
+    );
+    assert_that!(pipe.try_read(&mut buffer), eq Ok(0));
+}
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_posix::thread::*;
 use iceoryx2_bb_testing::assert_that;
 use std::sync::Arc;
@@ -105,6 +106,33 @@ fn thread_set_affinity_on_creation_works() {
     assert_that!(affinity[0], eq 0);
 }
 
+#[test]
+fn thread_set_affinity_to_multiple_cores_on_creation_works() {
+    let number_of_cores = SystemInfo::NumberOfCpuCores.value();
+    let cores: Vec<usize> = (0..number_of_cores.min(2)).collect();
+
+    let barrier = Arc::new(Barrier::new(2));
+    let thread = {
+        let barrier = barrier.clone();
+        let cores = cores.clone();
+        ThreadBuilder::new()
+            .affinity_to_cores(&cores)
+            .spawn(move || {
+                barrier.wait();
+                let handle = ThreadHandle::from_self();
+                let affinity = handle.get_affinity().unwrap();
+                barrier.wait();
+                assert_that!(affinity, eq cores);
+            })
+            .unwrap()
+    };
+
+    barrier.wait();
+    let affinity = thread.get_affinity().unwrap();
+    barrier.wait();
+    assert_that!(affinity, eq cores);
+}
+
 #[test]
 fn thread_set_affinity_from_handle_works() {
     let barrier = Arc::new(Barrier::new(2));
@@ -162,7 +162,11 @@ impl NamedConcept for Token {
     }
 }
 
-impl MonitoringToken for Token {}
+impl MonitoringToken for Token {
+    fn abandon(self) {
+        self.guard.abandon()
+    }
+}
 
 impl __InternalMonitoringTokenTestable for Token {
     fn staged_death(self) {
@@ -80,6 +80,7 @@ pub use crate::{
 };
 
 pub mod file_lock;
+pub mod process_handle;
 pub mod process_local;
 #[doc(hidden)]
 pub mod testing;
@@ -130,7 +131,12 @@ pub enum MonitoringStateError {
 }
 
 /// The token enables a process to be monitored by another process.
-pub trait MonitoringToken: NamedConcept {}
+pub trait MonitoringToken: NamedConcept {
+    /// Releases the [`MonitoringToken`] without removing the underlying [`Monitoring`] concept.
+    /// Other processes see the process as [`State::Dead`] from then on, as if it had crashed,
+    /// and can acquire the [`MonitoringCleaner`] to remove its stale resources.
+    fn abandon(self);
+}
 
 /// The cleaner owns the remains of a dead process and is the only one that is allowed to clean up
 /// those resources.
@@ -0,0 +1,627 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    directory::{Directory, DirectoryCreateError, DirectoryOpenError, DirectoryReadError},
+    file::{
+        AccessMode, CreationMode, File, FileBuilder, FileCreationError, FileOpenError,
+        FileRemoveError, Permission,
+    },
+    file_descriptor::FileDescriptorManagement,
+    file_type::FileType,
+    process::{Process, ProcessId},
+};
+use iceoryx2_bb_system_types::{file_name::FileName, file_path::FilePath, path::Path};
+
+use crate::{
+    monitoring::{MonitoringCreateCleanerError, MonitoringCreateMonitorError, State},
+    named_concept::{
+        NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptDoesExistError,
+        NamedConceptListError, NamedConceptMgmt, NamedConceptRemoveError,
+    },
+};
+
+use super::{
+    testing::__InternalMonitoringTokenTestable, Monitoring, MonitoringBuilder, MonitoringCleaner,
+    MonitoringCreateTokenError, MonitoringMonitor, MonitoringStateError, MonitoringToken,
+};
+
+const INIT_PERMISSION: Permission = Permission::OWNER_WRITE;
+const FINAL_PERMISSION: Permission = Permission::OWNER_ALL;
+const OWNER_LOCK_SUFFIX: &[u8] = b"_owner_lock";
+// exists next to the token when the owner abandoned it, see MonitoringToken::abandon()
+const ABANDONED_SUFFIX: &[u8] = b"_abandoned";
+
+/// Monitors processes via their process id. The token is a file that contains the process id
+/// and the start time of its owner. A monitor checks if the process with this id is still
+/// running and was started at the same time, so that a reused process id is not mistaken for
+/// the owner. It does not rely on file locks and is therefore the monitoring of choice on
+/// platforms like Windows where the lock of a file is not reliably released when its owner dies.
+#[derive(Debug)]
+pub struct ProcessHandleMonitoring {}
+
+fn suffixed_path(path: &FilePath, suffix: &[u8]) -> Option<FilePath> {
+    let mut suffixed_path = *path;
+    match suffixed_path.push_bytes(suffix) {
+        Ok(()) => Some(suffixed_path),
+        Err(_) => None,
+    }
+}
+
+fn is_abandoned(token_path: &FilePath) -> bool {
+    match suffixed_path(token_path, ABANDONED_SUFFIX) {
+        Some(path) => File::does_exist(&path).unwrap_or(false),
+        None => false,
+    }
+}
+
+// the start time is omitted on platforms that cannot provide it
+fn write_own_process_id(file: &mut File) -> bool {
+    let process = Process::from_self();
+    let content = match process.start_time() {
+        Ok(start_time) => format!("{} {}", process.id().value(), start_time),
+        Err(_) => process.id().value().to_string(),
+    };
+    file.write_at(0, content.as_bytes()).is_ok()
+}
+
+struct ProcessIdentity {
+    process_id: ProcessId,
+    start_time: Option<u64>,
+}
+
+impl ProcessIdentity {
+    fn is_alive(&self) -> bool {
+        let process = Process::from_pid(self.process_id);
+        if !process.is_alive() {
+            return false;
+        }
+
+        // when the start time cannot be acquired the process is not known to be dead
+        match (self.start_time, process.start_time()) {
+            (Some(start_time), Ok(current_start_time)) => start_time == current_start_time,
+            _ => true,
+        }
+    }
+}
+
+enum ProcessIdState {
+    DoesNotExist,
+    InInitialization,
+    Available(ProcessIdentity),
+}
+
+impl ProcessIdState {
+    fn is_alive(&self) -> bool {
+        match self {
+            ProcessIdState::Available(identity) => identity.is_alive(),
+            _ => false,
+        }
+    }
+}
+
+fn read_process_id(path: &FilePath) -> Result<ProcessIdState, FileOpenError> {
+    let file = match FileBuilder::new(path).open_existing(AccessMode::Read) {
+        Ok(file) => file,
+        Err(FileOpenError::FileDoesNotExist) => return Ok(ProcessIdState::DoesNotExist),
+        // the owner did not yet write its process id and set the final permissions
+        Err(FileOpenError::InsufficientPermissions) => return Ok(ProcessIdState::InInitialization),
+        Err(e) => return Err(e),
+    };
+
+    let mut content = String::new();
+    if file.read_to_string(&mut content).is_err() {
+        return Ok(ProcessIdState::InInitialization);
+    }
+
+    let mut fields = content.split_whitespace();
+    let process_id = match fields.next().map(|v| v.parse()) {
+        Some(Ok(process_id)) => ProcessId::new(process_id),
+        _ => return Ok(ProcessIdState::InInitialization),
+    };
+    let start_time = match fields.next().map(|v| v.parse()) {
+        None => None,
+        Some(Ok(start_time)) => Some(start_time),
+        Some(Err(_)) => return Ok(ProcessIdState::InInitialization),
+    };
+
+    Ok(ProcessIdState::Available(ProcessIdentity {
+        process_id,
+        start_time,
+    }))
+}
+
+impl NamedConceptMgmt for ProcessHandleMonitoring {
+    type Configuration = Configuration;
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::named_concept::NamedConceptListError> {
+        let path = cfg.get_path_hint();
+        let origin = "ProcessHandleMonitoring::list_cfg()";
+        let msg = format!(
+            "Unable to list all ProcessHandleMonitoring instances in \"{}\"",
+            path
+        );
+        let directory = match Directory::new(path) {
+            Ok(directory) => directory,
+            Err(DirectoryOpenError::InsufficientPermissions) => {
+                fail!(from origin, with NamedConceptListError::InsufficientPermissions,
+                    "{} due to insufficient permissions to read the directory.", msg);
+            }
+            Err(DirectoryOpenError::DoesNotExist) => {
+                return Ok(vec![]);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptListError::InternalError,
+                    "{} due to failure ({:?}) while reading the directory.", msg, v);
+            }
+        };
+
+        let entries = fail!(from origin,
+                            when directory.contents(),
+                            map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                            unmatched NamedConceptListError::InternalError,
+                            "{} due to a failure while reading the directory contents.", msg);
+
+        Ok(entries
+            .iter()
+            .filter(|entry| {
+                let metadata = entry.metadata();
+                metadata.file_type() == FileType::File
+            })
+            .filter_map(|entry| cfg.extract_name_from_file(entry.name()))
+            .collect())
+    }
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::named_concept::NamedConceptDoesExistError> {
+        let token_path = cfg.path_for(name);
+        let msg = format!(
+            "Unable to check if the ProcessHandleMonitoring \"{}\" exists",
+            token_path
+        );
+        let origin = "ProcessHandleMonitoring::does_exist_cfg()";
+
+        match File::does_exist(&token_path) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from origin, with NamedConceptDoesExistError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::named_concept::NamedConceptRemoveError> {
+        let token_path = cfg.path_for(name);
+        let msg = format!(
+            "Unable to remove ProcessHandleMonitoring \"{}\"",
+            token_path
+        );
+        let origin = "ProcessHandleMonitoring::remove_cfg()";
+
+        for suffix in [OWNER_LOCK_SUFFIX, ABANDONED_SUFFIX] {
+            if let Some(path) = suffixed_path(&token_path, suffix) {
+                let _ = File::remove(&path);
+            }
+        }
+
+        match File::remove(&token_path) {
+            Ok(v) => Ok(v),
+            Err(FileRemoveError::InsufficientPermissions) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                        "{} due to an internal failure ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        crate::named_concept::remove_path_hint(value)
+    }
+}
+
+#[derive(Debug)]
+pub struct Cleaner {
+    token_file: File,
+    abandoned_file: Option<File>,
+    _owner_lock_file: File,
+    name: FileName,
+}
+
+impl NamedConcept for Cleaner {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringCleaner for Cleaner {
+    fn abandon(mut self) {
+        // the owner lock is still removed so that another instance can acquire the cleaner
+        self.token_file.release_ownership();
+        if let Some(abandoned_file) = &mut self.abandoned_file {
+            abandoned_file.release_ownership();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Token {
+    file: File,
+    token_path: FilePath,
+    name: FileName,
+}
+
+impl NamedConcept for Token {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringToken for Token {
+    fn abandon(mut self) {
+        // the process is still alive, the abandoned file makes the monitors report it as dead
+        if let Some(abandoned_path) = suffixed_path(&self.token_path, ABANDONED_SUFFIX) {
+            let _ = FileBuilder::new(&abandoned_path)
+                .creation_mode(CreationMode::OpenOrCreate)
+                .permission(FINAL_PERMISSION)
+                .create();
+        }
+        self.file.release_ownership();
+    }
+}
+
+impl __InternalMonitoringTokenTestable for Token {
+    fn staged_death(self) {
+        self.abandon();
+    }
+}
+
+#[derive(Debug)]
+pub struct Monitor {
+    token_path: FilePath,
+    name: FileName,
+}
+
+impl NamedConcept for Monitor {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringMonitor for Monitor {
+    fn state(&self) -> Result<super::State, MonitoringStateError> {
+        let msg = "Unable to acquire monitor state";
+
+        match read_process_id(&self.token_path) {
+            Ok(ProcessIdState::DoesNotExist) | Ok(ProcessIdState::InInitialization) => {
+                Ok(State::DoesNotExist)
+            }
+            Ok(process_id_state) => {
+                if process_id_state.is_alive() && !is_abandoned(&self.token_path) {
+                    Ok(State::Alive)
+                } else {
+                    Ok(State::Dead)
+                }
+            }
+            Err(FileOpenError::Interrupt) => {
+                fail!(from self, with MonitoringStateError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringStateError::InternalError,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<ProcessHandleMonitoring> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(
+        mut self,
+        config: &<ProcessHandleMonitoring as NamedConceptMgmt>::Configuration,
+    ) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl Builder {
+    fn create_directory(&self) -> Result<(), MonitoringCreateTokenError> {
+        let msg = "Unable to create the directory of the ProcessHandleMonitoring token";
+        let directory = self.config.get_path_hint();
+
+        if directory.is_empty() {
+            return Ok(());
+        }
+
+        match Directory::does_exist(directory) {
+            Ok(true) => Ok(()),
+            Ok(false) => match Directory::create(
+                directory,
+                Permission::OWNER_ALL
+                    | Permission::GROUP_READ
+                    | Permission::GROUP_EXEC
+                    | Permission::OTHERS_READ
+                    | Permission::OTHERS_EXEC,
+            ) {
+                Ok(_) | Err(DirectoryCreateError::DirectoryAlreadyExists) => Ok(()),
+                Err(DirectoryCreateError::InsufficientPermissions) => {
+                    fail!(from self, with MonitoringCreateTokenError::InsufficientPermissions,
+                        "{} \"{}\" due to insufficient permissions.", msg, directory);
+                }
+                Err(v) => {
+                    fail!(from self, with MonitoringCreateTokenError::InternalError,
+                        "{} \"{}\" due to an internal failure ({:?}).", msg, directory, v);
+                }
+            },
+            Err(v) => {
+                fail!(from self, with MonitoringCreateTokenError::InternalError,
+                    "{} \"{}\" since its existence could not be verified ({:?}).", msg, directory, v);
+            }
+        }
+    }
+
+    fn create_owner_lock(
+        &self,
+        owner_lock_path: &FilePath,
+    ) -> Result<File, MonitoringCreateCleanerError> {
+        let msg = "Unable to acquire the owner lock of the cleaner";
+
+        // a second attempt is only made when a stale owner lock of a dead cleaner was removed
+        for _ in 0..2 {
+            match FileBuilder::new(owner_lock_path)
+                .has_ownership(true)
+                .creation_mode(CreationMode::CreateExclusive)
+                .permission(FINAL_PERMISSION)
+                .create()
+            {
+                Ok(mut file) => {
+                    if !write_own_process_id(&mut file) {
+                        fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                            "{} since the process id could not be written into the owner lock.", msg);
+                    }
+                    return Ok(file);
+                }
+                Err(FileCreationError::FileAlreadyExists) => match read_process_id(owner_lock_path)
+                {
+                    Ok(ProcessIdState::DoesNotExist) => (),
+                    Ok(ProcessIdState::InInitialization) | Err(_) => break,
+                    Ok(owner) => {
+                        if owner.is_alive() {
+                            break;
+                        }
+                        // the previous owner died while cleaning up
+                        let _ = File::remove(owner_lock_path);
+                    }
+                },
+                Err(v) => {
+                    fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                        "{} due to an internal failure ({:?}).", msg, v);
+                }
+            }
+        }
+
+        fail!(from self, with MonitoringCreateCleanerError::AlreadyOwnedByAnotherInstance,
+            "{} since another instance already acquired the cleaner.", msg);
+    }
+}
+
+impl MonitoringBuilder<ProcessHandleMonitoring> for Builder {
+    fn token(
+        self,
+    ) -> Result<<ProcessHandleMonitoring as Monitoring>::Token, MonitoringCreateTokenError> {
+        let msg = "Unable to create ProcessHandleMonitoring token";
+        let token_path = self.config.path_for(&self.name);
+
+        self.create_directory()?;
+
+        let mut file = match FileBuilder::new(&token_path)
+            .has_ownership(true)
+            .creation_mode(CreationMode::CreateExclusive)
+            .permission(INIT_PERMISSION)
+            .create()
+        {
+            Ok(file) => file,
+            Err(FileCreationError::InsufficientPermissions) => {
+                fail!(from self, with MonitoringCreateTokenError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(FileCreationError::FileAlreadyExists) => {
+                fail!(from self, with MonitoringCreateTokenError::AlreadyExists,
+                    "{} since it already exists.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringCreateTokenError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, v);
+            }
+        };
+
+        // a previous owner with the same name abandoned its token and the cleaner removed
+        // the token but not yet the abandoned file
+        if let Some(abandoned_path) = suffixed_path(&token_path, ABANDONED_SUFFIX) {
+            let _ = File::remove(&abandoned_path);
+        }
+
+        if !write_own_process_id(&mut file) {
+            fail!(from self, with MonitoringCreateTokenError::InternalError,
+                "{} since the process id could not be written into the token.", msg);
+        }
+
+        if let Err(v) = file.set_permission(FINAL_PERMISSION) {
+            fail!(from self, with MonitoringCreateTokenError::InternalError,
+                "{} since the final permissions could not be applied ({:?}).", msg, v);
+        }
+
+        Ok(Token {
+            file,
+            token_path,
+            name: self.name,
+        })
+    }
+
+    fn monitor(
+        self,
+    ) -> Result<<ProcessHandleMonitoring as Monitoring>::Monitor, MonitoringCreateMonitorError>
+    {
+        Ok(Monitor {
+            token_path: self.config.path_for(&self.name),
+            name: self.name,
+        })
+    }
+
+    fn cleaner(
+        self,
+    ) -> Result<<ProcessHandleMonitoring as Monitoring>::Cleaner, MonitoringCreateCleanerError>
+    {
+        let msg = "Unable to acquire cleaner";
+        let token_path = self.config.path_for(&self.name);
+        let owner_lock_path = match suffixed_path(&token_path, OWNER_LOCK_SUFFIX) {
+            Some(path) => path,
+            None => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} since the owner lock path would be invalid.", msg);
+            }
+        };
+
+        match read_process_id(&token_path) {
+            Ok(ProcessIdState::DoesNotExist) => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since it does not exist.", msg);
+            }
+            Ok(ProcessIdState::InInitialization) => {
+                fail!(from self, with MonitoringCreateCleanerError::InstanceStillAlive,
+                    "{} since the instance is still in initialization.", msg);
+            }
+            Ok(process_id_state) => {
+                if process_id_state.is_alive() && !is_abandoned(&token_path) {
+                    fail!(from self, with MonitoringCreateCleanerError::InstanceStillAlive,
+                        "{} since the instance is still alive.", msg);
+                }
+            }
+            Err(FileOpenError::Interrupt) => {
+                fail!(from self, with MonitoringCreateCleanerError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, v);
+            }
+        }
+
+        let owner_lock_file = self.create_owner_lock(&owner_lock_path)?;
+        let abandoned_file = suffixed_path(&token_path, ABANDONED_SUFFIX).and_then(|path| {
+            FileBuilder::new(&path)
+                .has_ownership(true)
+                .open_existing(AccessMode::Read)
+                .ok()
+        });
+
+        match FileBuilder::new(&token_path)
+            .has_ownership(true)
+            .open_existing(AccessMode::Read)
+        {
+            Ok(token_file) => Ok(Cleaner {
+                token_file,
+                abandoned_file,
+                _owner_lock_file: owner_lock_file,
+                name: self.name,
+            }),
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since it was removed by another instance.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} since the token could not be opened ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+impl crate::monitoring::Monitoring for ProcessHandleMonitoring {
+    type Token = Token;
+    type Monitor = Monitor;
+    type Builder = Builder;
+    type Cleaner = Cleaner;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path_hint: Path,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            suffix: ProcessHandleMonitoring::default_suffix(),
+            prefix: ProcessHandleMonitoring::default_prefix(),
+            path_hint: ProcessHandleMonitoring::default_path_hint(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = *value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = *value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path_hint = *value;
+        self
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path_hint
+    }
+}
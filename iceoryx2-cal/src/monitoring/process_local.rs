@@ -187,7 +187,11 @@ impl NamedConcept for Token {
     }
 }
 
-impl MonitoringToken for Token {}
+impl MonitoringToken for Token {
+    fn abandon(self) {
+        // a process local token cannot outlive its process, therefore it is simply removed
+    }
+}
 
 impl Drop for Token {
     fn drop(&mut self) {
@@ -18,6 +18,10 @@ use crate::dynamic_storage::*;
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::memory_advice::{
+    advise, huge_page_size, is_mapped_with_huge_pages, MemoryAdvice,
+};
+use iceoryx2_bb_posix::memory_lock::{MemoryLock, MemoryLockCreationError};
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
@@ -139,6 +143,10 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        is_memory_locked: bool,
+        prefault_memory: bool,
+        use_huge_pages: bool,
+        payload_alignment: usize,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -151,6 +159,10 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                is_memory_locked: false,
+                prefault_memory: false,
+                use_huge_pages: false,
+                payload_alignment: 1,
             }
         }
 
@@ -170,8 +182,9 @@ pub mod details {
             init_allocator: &mut BumpAllocator,
         ) -> bool {
             let msg = "Unable to initialize shared memory";
-            let res =
-                init_allocator.allocate(unsafe { Layout::from_size_align_unchecked(self.size, 1) });
+            let res = init_allocator.allocate(unsafe {
+                Layout::from_size_align_unchecked(self.size, self.payload_alignment)
+            });
             let memory = match res {
                 Ok(m) => m,
                 Err(e) => {
@@ -215,8 +228,23 @@ pub mod details {
             self
         }
 
+        fn is_memory_locked(mut self, value: bool) -> Self {
+            self.is_memory_locked = value;
+            self
+        }
+
+        fn prefault_memory(mut self, value: bool) -> Self {
+            self.prefault_memory = value;
+            self
+        }
+
+        fn use_huge_pages(mut self, value: bool) -> Self {
+            self.use_huge_pages = value;
+            self
+        }
+
         fn create(
-            self,
+            mut self,
             allocator_config: &Allocator::Configuration,
         ) -> Result<Memory<Allocator, Storage>, SharedMemoryCreateError> {
             let msg = "Unable to create shared memory";
@@ -226,11 +254,26 @@ pub mod details {
                     "{} since the size is zero.", msg);
             }
 
+            // a huge page can only back a memory range that is aligned to and a multiple of the
+            // huge page size
+            if self.use_huge_pages {
+                match huge_page_size() {
+                    Some(huge_page_size) => {
+                        self.size = self.size.next_multiple_of(huge_page_size);
+                        self.payload_alignment = huge_page_size;
+                    }
+                    None => {
+                        fail!(from self, with SharedMemoryCreateError::UnableToUseHugePages,
+                            "{} since the system does not support huge pages.", msg);
+                    }
+                }
+            }
+
             let allocator_mgmt_size = Allocator::management_size(self.size, allocator_config);
 
             let storage = match Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
-                .supplementary_size(self.size + allocator_mgmt_size)
+                .supplementary_size(self.size + allocator_mgmt_size + self.payload_alignment - 1)
                 .has_ownership(self.has_ownership)
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(allocator_config, details, init_allocator)
@@ -261,12 +304,49 @@ pub mod details {
                 }
             };
 
-            Ok(Memory::<Allocator, Storage> {
+            let mut memory = Memory::<Allocator, Storage> {
+                memory_lock: None,
                 payload_start_address: get_payload_start_address(&storage),
                 storage,
                 name: self.name,
                 _phantom: PhantomData,
-            })
+            };
+
+            // must be applied before the memory is locked or prefaulted, otherwise the pages are
+            // already backed by regular pages
+            if self.use_huge_pages {
+                if let Err(e) = unsafe {
+                    advise(
+                        memory.payload_start_address as *const _,
+                        self.size,
+                        MemoryAdvice::HugePages,
+                    )
+                } {
+                    fail!(from self, with SharedMemoryCreateError::UnableToUseHugePages,
+                        "{} since the payload memory could not be backed by huge pages ({:?}).", msg, e);
+                }
+
+                // the advice is only a hint, the first access shows whether the system applies
+                // it, for instance a tmpfs mounted without the huge option ignores it
+                memory.prefault(1);
+                if !is_mapped_with_huge_pages(memory.payload_start_address as *const _) {
+                    fail!(from self, with SharedMemoryCreateError::UnableToUseHugePages,
+                        "{} since the system does not back the payload memory with huge pages.", msg);
+                }
+            }
+
+            if self.is_memory_locked {
+                if let Err(e) = memory.lock(self.size) {
+                    fail!(from self, with SharedMemoryCreateError::UnableToLockMemory,
+                        "{} since the payload memory could not be locked ({:?}).", msg, e);
+                }
+            }
+
+            if self.prefault_memory {
+                memory.prefault(self.size);
+            }
+
+            Ok(memory)
         }
 
         fn open(self) -> Result<Memory<Allocator, Storage>, SharedMemoryOpenError> {
@@ -310,17 +390,52 @@ pub mod details {
                     msg, self.size, payload_size);
             }
 
-            Ok(Memory::<Allocator, Storage> {
+            let mut memory = Memory::<Allocator, Storage> {
+                memory_lock: None,
                 payload_start_address: get_payload_start_address(&storage),
                 name: self.name,
                 storage,
                 _phantom: PhantomData,
-            })
+            };
+
+            if self.use_huge_pages {
+                if let Err(e) = unsafe {
+                    advise(
+                        memory.payload_start_address as *const _,
+                        payload_size,
+                        MemoryAdvice::HugePages,
+                    )
+                } {
+                    debug!(from self, "The payload memory is mapped with regular pages since huge pages are not available ({:?}).", e);
+                }
+            }
+
+            if self.is_memory_locked {
+                if let Err(e) = memory.lock(payload_size) {
+                    fail!(from self, with SharedMemoryOpenError::UnableToLockMemory,
+                        "{} since the payload memory could not be locked ({:?}).", msg, e);
+                }
+            }
+
+            if self.prefault_memory {
+                memory.prefault_read_only(payload_size);
+            }
+
+            Ok(memory)
         }
     }
 
+    #[derive(Debug)]
+    struct PayloadMemoryLock(MemoryLock);
+
+    // the lock only stores the immutable address range that is unlocked when it is dropped
+    unsafe impl Send for PayloadMemoryLock {}
+    unsafe impl Sync for PayloadMemoryLock {}
+
     #[derive(Debug)]
     pub struct Memory<Allocator: ShmAllocator, Storage: DynamicStorage<AllocatorDetails<Allocator>>> {
+        // must be dropped before the storage is unmapped
+        memory_lock: Option<PayloadMemoryLock>,
         storage: Storage,
         name: FileName,
         payload_start_address: usize,
@@ -337,6 +452,46 @@ pub mod details {
         payload_start_offset: usize,
     }
 
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        Memory<Allocator, Storage>
+    {
+        fn lock(&mut self, size: usize) -> Result<(), MemoryLockCreationError> {
+            let page_size = SystemInfo::PageSize.value();
+            let start = self.payload_start_address / page_size * page_size;
+            let len = self.payload_start_address + size - start;
+            self.memory_lock = Some(PayloadMemoryLock(unsafe {
+                MemoryLock::new(start as *const _, len)
+            }?));
+            Ok(())
+        }
+
+        fn prefault(&self, size: usize) {
+            self.for_each_page(size, |ptr| {
+                // the memory was just created, nobody else can access it concurrently
+                unsafe { ptr.write_volatile(ptr.read_volatile()) };
+            });
+        }
+
+        // the memory is already used by its creator and must therefore not be written, reading
+        // every page maps it into the address space of the process
+        fn prefault_read_only(&self, size: usize) {
+            self.for_each_page(size, |ptr| {
+                unsafe { ptr.read_volatile() };
+            });
+        }
+
+        fn for_each_page<F: FnMut(*mut u8)>(&self, size: usize, mut call: F) {
+            let page_size = SystemInfo::PageSize.value();
+            let end = self.payload_start_address + size;
+            let mut address = self.payload_start_address;
+
+            while address < end {
+                call(address as *mut u8);
+                address = (address / page_size + 1) * page_size;
+            }
+        }
+    }
+
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
         NamedConcept for Memory<Allocator, Storage>
     {
@@ -433,5 +588,9 @@ pub mod details {
         fn payload_start_address(&self) -> usize {
             self.payload_start_address
         }
+
+        fn uses_huge_pages(&self) -> bool {
+            is_mapped_with_huge_pages(self.payload_start_address as *const _)
+        }
     }
 }
@@ -70,6 +70,8 @@ pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
     InsufficientPermissions,
+    UnableToLockMemory,
+    UnableToUseHugePages,
     InternalError,
 }
 
@@ -83,6 +85,7 @@ pub enum SharedMemoryOpenError {
     WrongAllocatorSelected,
     InitializationNotYetFinalized,
     VersionMismatch,
+    UnableToLockMemory,
     InternalError,
 }
 
@@ -112,6 +115,25 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines if the payload memory of a created or opened [`SharedMemory`] is locked into the
+    /// RAM so that it is never swapped out. The lock is held as long as the created or opened
+    /// [`SharedMemory`] exists. By default it is set to false.
+    fn is_memory_locked(self, value: bool) -> Self;
+
+    /// Defines if every page of the payload memory of a created or opened [`SharedMemory`] is
+    /// touched when it is created or opened so that no page fault occurs when it is accessed
+    /// for the first time. An opened [`SharedMemory`] is only read so that the content is not
+    /// modified. By default it is set to false.
+    fn prefault_memory(self, value: bool) -> Self;
+
+    /// Defines if the payload memory of the [`SharedMemory`] shall be backed by huge pages to
+    /// reduce the pressure on the translation lookaside buffer. The payload size is rounded up
+    /// to a multiple of the huge page size. When the system does not back the payload memory
+    /// with huge pages, [`SharedMemoryBuilder::create()`] fails while
+    /// [`SharedMemoryBuilder::open()`] continues with regular pages. By default it is set to
+    /// false.
+    fn use_huge_pages(self, value: bool) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -140,6 +162,10 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// the actual memory position.
     fn payload_start_address(&self) -> usize;
 
+    /// Returns true when the payload memory is currently mapped with huge pages in the address
+    /// space of the process, see [`SharedMemoryBuilder::use_huge_pages()`].
+    fn uses_huge_pages(&self) -> bool;
+
     /// Allocates memory. The alignment in the layout must be smaller or equal
     /// [`SharedMemory::max_alignment()`] otherwise the method will fail.
     fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError>;
@@ -20,6 +20,7 @@ use iceoryx2_bb_elementary::{allocator::BaseAllocator, enum_gen};
 
 pub trait ShmAllocatorConfig: Copy + Default {}
 
+#[repr(transparent)]
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct PointerOffset(usize);
 
@@ -47,6 +47,7 @@
 pub use crate::named_concept::*;
 pub use crate::static_storage::*;
 
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, trace, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::{
@@ -104,6 +105,18 @@ impl crate::named_concept::NamedConceptConfiguration for Configuration {
 
 impl crate::static_storage::StaticStorageConfiguration for Configuration {}
 
+impl Configuration {
+    /// The configuration of the temporary file that [`Builder::create_or_replace()`] writes
+    /// before it replaces the static storage. Its suffix differs so that it is never listed.
+    fn temporary(&self) -> Option<Configuration> {
+        let mut suffix = self.suffix;
+        match suffix.push_bytes(b".tmp") {
+            Ok(()) => Some(self.clone().suffix(&suffix)),
+            Err(_) => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Locked {
     static_storage: Storage,
@@ -198,6 +211,11 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
                 with NamedConceptRemoveError::InternalError,
                 "{} since the permissions could not be adjusted.", msg);
 
+        // the temporary file of an interrupted Builder::create_or_replace()
+        if let Some(temporary_config) = config.temporary() {
+            let _ = File::remove(&temporary_config.path_for(storage_name));
+        }
+
         match File::remove(&file_path) {
             Ok(v) => Ok(v),
             Err(FileRemoveError::InsufficientPermissions)
@@ -416,6 +434,62 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
         })
     }
 
+    fn create_or_replace(self, contents: &[u8]) -> Result<Storage, StaticStorageCreateError> {
+        let msg = format!(
+            "Unable to create or replace static storage \"{}\"",
+            self.storage_name
+        );
+
+        let temporary_config = match self.config.temporary() {
+            Some(config) => config,
+            None => {
+                fail!(from self, with StaticStorageCreateError::Creation,
+                    "{} since the suffix of the temporary file would exceed the maximum supported length.", msg);
+            }
+        };
+
+        // a previous replacement that was interrupted may have left its temporary file behind
+        match File::remove(&temporary_config.path_for(&self.storage_name)) {
+            Ok(_) => (),
+            Err(FileRemoveError::InsufficientPermissions)
+            | Err(FileRemoveError::PartOfReadOnlyFileSystem) => {
+                fail!(from self, with StaticStorageCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions to remove a stale temporary file.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with StaticStorageCreateError::Creation,
+                    "{} since a stale temporary file could not be removed ({:?}).", msg, e);
+            }
+        }
+
+        // owns the temporary file so that it is removed when the rename fails
+        let mut storage = Builder::new(&self.storage_name)
+            .config(&temporary_config)
+            .has_ownership(true)
+            .create(contents)?;
+
+        match File::rename(
+            &temporary_config.path_for(&self.storage_name),
+            &self.config.path_for(&self.storage_name),
+        ) {
+            Ok(()) => (),
+            Err(FileRenameError::InsufficientPermissions)
+            | Err(FileRenameError::PartOfReadOnlyFileSystem) => {
+                fail!(from self, with StaticStorageCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions to replace the underlying file.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with StaticStorageCreateError::Creation,
+                    "{} since the underlying file could not be replaced ({:?}).", msg, e);
+            }
+        }
+
+        storage.config = self.config;
+        storage.has_ownership = self.has_ownership;
+
+        Ok(storage)
+    }
+
     fn open(self, timeout: Duration) -> Result<Storage, StaticStorageOpenError> {
         let msg = "Unable to open static storage";
         let origin = "static_storage::File::Builder::open()";
@@ -80,6 +80,13 @@ pub trait StaticStorageBuilder<T: StaticStorage>: Sized + NamedConceptBuilder<T>
         )
     }
 
+    /// Creates a [`StaticStorage`] with the provided contents. If a [`StaticStorage`] with the
+    /// same name already exists, it is replaced in one atomic step so that other processes
+    /// either open the previous or the new contents but never observe a missing
+    /// [`StaticStorage`]. The previous [`StaticStorage`] must not own the underlying resources
+    /// anymore, otherwise it removes the new one when it goes out of scope.
+    fn create_or_replace(self, contents: &[u8]) -> Result<T, StaticStorageCreateError>;
+
     /// Creates an owning [`StaticStorageLocked`]. When its lifetime ends the underlying resource
     /// will be removed. The contents must be set later with [`StaticStorageLocked::unlock()`].
     /// This is useful if the static storage name should be reserved and initialized later.
@@ -345,6 +345,33 @@ impl StaticStorageBuilder<Storage> for Builder {
         }
     }
 
+    fn create_or_replace(self, contents: &[u8]) -> Result<Storage, StaticStorageCreateError> {
+        let msg = "Failed to create or replace storage";
+
+        let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
+                with StaticStorageCreateError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        let content = Arc::new(StorageContent {
+            is_locked: false,
+            value: Vec::from(contents),
+        });
+
+        guard.insert(
+            self.config.path_for(&self.name),
+            StorageEntry {
+                content: content.clone(),
+            },
+        );
+
+        Ok(Storage {
+            name: self.name,
+            has_ownership: self.has_ownership,
+            config: self.config,
+            content,
+        })
+    }
+
     fn create_locked(self) -> Result<<Storage as StaticStorage>::Locked, StaticStorageCreateError> {
         let msg = "Failed to create storage";
 
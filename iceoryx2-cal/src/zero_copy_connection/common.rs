@@ -29,7 +29,7 @@ pub mod details {
         index_queue::RelocatableIndexQueue,
         safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
     };
-    use iceoryx2_bb_log::{fail, fatal_panic};
+    use iceoryx2_bb_log::{debug, fail, fatal_panic};
     use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 
     use self::used_chunk_list::RelocatableUsedChunkList;
@@ -160,6 +160,7 @@ pub mod details {
         state: IoxAtomicU8,
         init_state: IoxAtomicU64,
         enable_safe_overflow: bool,
+        number_of_dropped_samples: IoxAtomicU64,
     }
 
     impl SharedManagementData {
@@ -184,6 +185,7 @@ pub mod details {
                 state: IoxAtomicU8::new(State::None.value()),
                 init_state: IoxAtomicU64::new(0),
                 enable_safe_overflow,
+                number_of_dropped_samples: IoxAtomicU64::new(0),
                 sample_size,
                 max_borrowed_samples,
                 number_of_samples,
@@ -482,6 +484,17 @@ pub mod details {
             self.storage.get().state.load(Ordering::Relaxed)
                 == State::Sender.value() | State::Receiver.value()
         }
+
+        fn number_of_buffered_samples(&self) -> usize {
+            self.storage.get().submission_channel.len()
+        }
+
+        fn number_of_dropped_samples(&self) -> u64 {
+            self.storage
+                .get()
+                .number_of_dropped_samples
+                .load(Ordering::Relaxed)
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
@@ -491,6 +504,10 @@ pub mod details {
             if !self.storage.get().enable_safe_overflow
                 && self.storage.get().submission_channel.is_full()
             {
+                self.storage
+                    .get()
+                    .number_of_dropped_samples
+                    .fetch_add(1, Ordering::Relaxed);
                 fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
                              "{} since the receive buffer is full.", msg);
             }
@@ -517,6 +534,10 @@ pub mod details {
                         "{} since an invalid offset was returned on overflow.", msg);
                     }
 
+                    self.storage
+                        .get()
+                        .number_of_dropped_samples
+                        .fetch_add(1, Ordering::Relaxed);
                     Ok(Some(PointerOffset::new(v)))
                 }
                 None => Ok(None),
@@ -612,6 +633,17 @@ pub mod details {
             self.storage.get().state.load(Ordering::Relaxed)
                 == State::Sender.value() | State::Receiver.value()
         }
+
+        fn number_of_buffered_samples(&self) -> usize {
+            self.storage.get().submission_channel.len()
+        }
+
+        fn number_of_dropped_samples(&self) -> u64 {
+            self.storage
+                .get()
+                .number_of_dropped_samples
+                .load(Ordering::Relaxed)
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
@@ -635,6 +667,38 @@ pub mod details {
             }
         }
 
+        fn receive_batch(
+            &self,
+            buffer: &mut [PointerOffset],
+        ) -> Result<usize, ZeroCopyReceiveError> {
+            let max_borrowed_samples = self.storage.get().max_borrowed_samples;
+            if *self.borrow_counter() >= max_borrowed_samples {
+                fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
+                "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
+                    self.borrow_counter(), self.max_borrowed_samples());
+            }
+
+            let len = buffer
+                .len()
+                .min(max_borrowed_samples - *self.borrow_counter());
+            // PointerOffset is a transparent wrapper around usize
+            let buffer =
+                unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut usize, len) };
+
+            let number_of_samples =
+                unsafe { self.storage.get().submission_channel.pop_batch(buffer) };
+            *self.borrow_counter() += number_of_samples;
+            Ok(number_of_samples)
+        }
+
+        fn peek(&self) -> Option<PointerOffset> {
+            unsafe { self.storage.get().submission_channel.peek() }.map(PointerOffset::new)
+        }
+
+        fn peek_last(&self) -> Option<PointerOffset> {
+            unsafe { self.storage.get().submission_channel.peek_last() }.map(PointerOffset::new)
+        }
+
         fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
             match unsafe { self.storage.get().completion_channel.push(ptr.value()) } {
                 true => {
@@ -701,5 +765,31 @@ pub mod details {
         fn has_configurable_buffer_size() -> bool {
             true
         }
+
+        fn statistics_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Option<ZeroCopyConnectionStatistics> {
+            let storage = match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+            Storage,
+        >>::new(name)
+            .config(&cfg.dynamic_storage_config)
+            .open()
+            {
+                Ok(storage) => storage,
+                Err(e) => {
+                    debug!(from "ZeroCopyConnection::statistics_cfg()",
+                        "Unable to acquire the statistics of the connection \"{}\" ({:?}).", name, e);
+                    return None;
+                }
+            };
+
+            let data = storage.get();
+            Some(ZeroCopyConnectionStatistics {
+                number_of_buffered_samples: data.submission_channel.len(),
+                buffer_size: data.submission_channel.capacity(),
+                number_of_dropped_samples: data.number_of_dropped_samples.load(Ordering::Relaxed),
+            })
+        }
     }
 }
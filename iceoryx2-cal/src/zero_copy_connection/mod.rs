@@ -126,6 +126,11 @@ pub trait ZeroCopyPortDetails {
     fn has_enabled_safe_overflow(&self) -> bool;
     fn max_borrowed_samples(&self) -> usize;
     fn is_connected(&self) -> bool;
+    /// Returns the number of samples that are currently stored in the receive buffer.
+    fn number_of_buffered_samples(&self) -> usize;
+    /// Returns the number of samples that were dropped since the connection was created, either
+    /// because the receive buffer was full or because they were overwritten on overflow.
+    fn number_of_dropped_samples(&self) -> u64;
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
@@ -148,9 +153,31 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn has_data(&self) -> bool;
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
+    /// Receives up to `buffer.len()` samples with a single synchronization of the underlying
+    /// queue and returns the number of samples that were stored in the buffer. The number is
+    /// additionally limited by the remaining number of samples the receiver can borrow.
+    fn receive_batch(&self, buffer: &mut [PointerOffset]) -> Result<usize, ZeroCopyReceiveError>;
+    /// Returns the oldest sample of the receive buffer without removing it. It neither counts
+    /// as borrowed nor must it be released.
+    fn peek(&self) -> Option<PointerOffset>;
+    /// Returns the newest sample of the receive buffer without removing it. It neither counts
+    /// as borrowed nor must it be released.
+    fn peek_last(&self) -> Option<PointerOffset>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
 }
 
+/// A snapshot of the receive buffer of an existing [`ZeroCopyConnection`], see
+/// [`ZeroCopyConnection::statistics_cfg()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ZeroCopyConnectionStatistics {
+    /// The number of samples that are currently stored in the receive buffer.
+    pub number_of_buffered_samples: usize,
+    /// The capacity of the receive buffer.
+    pub buffer_size: usize,
+    /// The number of samples that were dropped since the connection was created.
+    pub number_of_dropped_samples: u64,
+}
+
 pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
     type Sender: ZeroCopySender;
     type Receiver: ZeroCopyReceiver;
@@ -166,6 +193,14 @@ pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
         false
     }
 
+    /// Acquires the [`ZeroCopyConnectionStatistics`] of an existing connection without
+    /// participating in it. Returns [`None`] when the connection does not exist or is not
+    /// yet initialized.
+    fn statistics_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Option<ZeroCopyConnectionStatistics>;
+
     /// The default suffix of every zero copy connection
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".rx") }
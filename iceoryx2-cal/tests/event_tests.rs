@@ -742,7 +742,7 @@ mod event {
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_process_local::Event>)]
     mod sem_bitset_process_local {}
 
-    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    #[cfg(not(target_os = "windows"))]
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_posix_shared_memory::Event>)]
     mod sem_bitset_posix_shared_memory {}
 }
@@ -0,0 +1,181 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod monitoring_process_handle {
+    use iceoryx2_bb_posix::file::*;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::monitoring::testing::__InternalMonitoringTokenTestable;
+    use iceoryx2_cal::monitoring::*;
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::testing::*;
+
+    type Sut = iceoryx2_cal::monitoring::process_handle::ProcessHandleMonitoring;
+
+    #[test]
+    fn monitor_detects_alive_token_of_own_process() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_monitor = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .monitor()
+            .unwrap();
+        assert_that!(sut_monitor.state().unwrap(), eq State::DoesNotExist);
+
+        let sut_token = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .token()
+            .unwrap();
+        assert_that!(sut_monitor.state().unwrap(), eq State::Alive);
+
+        drop(sut_token);
+        assert_that!(sut_monitor.state().unwrap(), eq State::DoesNotExist);
+    }
+
+    #[test]
+    fn cleaner_cannot_be_acquired_while_owner_is_alive() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_token = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .token()
+            .unwrap();
+
+        let sut_cleaner = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .cleaner();
+        assert_that!(sut_cleaner, is_err);
+        assert_that!(sut_cleaner.err().unwrap(), eq MonitoringCreateCleanerError::InstanceStillAlive);
+    }
+
+    #[test]
+    fn dead_token_is_detected_and_removed_by_one_cleaner() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_monitor = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .monitor()
+            .unwrap();
+        <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .token()
+            .unwrap()
+            .staged_death();
+        assert_that!(sut_monitor.state().unwrap(), eq State::Dead);
+
+        let sut_cleaner = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .cleaner();
+        assert_that!(sut_cleaner, is_ok);
+
+        let sut_cleaner_2 = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .cleaner();
+        assert_that!(sut_cleaner_2, is_err);
+        assert_that!(sut_cleaner_2.err().unwrap(), eq MonitoringCreateCleanerError::AlreadyOwnedByAnotherInstance);
+
+        drop(sut_cleaner);
+        assert_that!(sut_monitor.state().unwrap(), eq State::DoesNotExist);
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn abandoned_cleaner_can_be_acquired_again() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .token()
+            .unwrap()
+            .staged_death();
+
+        let sut_cleaner = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .cleaner()
+            .unwrap();
+        sut_cleaner.abandon();
+
+        let sut_cleaner = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .cleaner();
+        assert_that!(sut_cleaner, is_ok);
+    }
+
+    #[test]
+    fn token_with_reused_process_id_is_detected_as_dead() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let process = Process::from_self();
+        let start_time = match process.start_time() {
+            Ok(start_time) => start_time,
+            // the platform cannot distinguish a reused process id
+            Err(_) => return,
+        };
+
+        // the token of a dead process whose process id is now used by this process
+        let mut file = FileBuilder::new(&config.path_for(&name))
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .permission(Permission::OWNER_ALL)
+            .create()
+            .unwrap();
+        let content = format!("{} {}", process.id().value(), start_time.wrapping_add(1));
+        file.write(content.as_bytes()).unwrap();
+
+        let sut_monitor = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .monitor()
+            .unwrap();
+        assert_that!(sut_monitor.state().unwrap(), eq State::Dead);
+
+        let sut_cleaner = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .cleaner();
+        assert_that!(sut_cleaner, is_ok);
+
+        drop(sut_cleaner);
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist_cfg(&name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn new_token_is_alive_after_abandoned_token_was_cleaned_up() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_monitor = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .monitor()
+            .unwrap();
+        <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .token()
+            .unwrap()
+            .abandon();
+        assert_that!(sut_monitor.state().unwrap(), eq State::Dead);
+
+        drop(
+            <Sut as Monitoring>::Builder::new(&name)
+                .config(&config)
+                .cleaner()
+                .unwrap(),
+        );
+
+        let _sut_token = <Sut as Monitoring>::Builder::new(&name)
+            .config(&config)
+            .token()
+            .unwrap();
+        assert_that!(sut_monitor.state().unwrap(), eq State::Alive);
+    }
+}
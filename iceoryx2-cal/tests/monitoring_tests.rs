@@ -68,7 +68,24 @@ mod monitoring {
     }
 
     #[test]
-    #[cfg(not(any(target_os = "linux", target_os = "freebsd", target_os = "macos")))]
+    fn abandoned_token_is_no_longer_alive<Sut: Monitoring>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_token = Sut::Builder::new(&name).config(&config).token().unwrap();
+        let sut_monitor = Sut::Builder::new(&name).config(&config).monitor().unwrap();
+
+        sut_token.abandon();
+        assert_that!(sut_monitor.state().unwrap(), ne State::Alive);
+    }
+
+    #[test]
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "macos",
+        target_os = "nto"
+    )))]
     fn monitor_works<Sut: Monitoring>() {
         let name = generate_name();
         let config = generate_isolated_config::<Sut>();
@@ -182,6 +199,9 @@ mod monitoring {
     #[instantiate_tests(<iceoryx2_cal::monitoring::file_lock::FileLockMonitoring>)]
     mod file_lock {}
 
+    #[instantiate_tests(<iceoryx2_cal::monitoring::process_handle::ProcessHandleMonitoring>)]
+    mod process_handle {}
+
     #[instantiate_tests(<iceoryx2_cal::monitoring::process_local::ProcessLocalMonitoring>)]
     mod process_local {}
 }
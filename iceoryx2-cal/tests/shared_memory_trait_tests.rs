@@ -15,6 +15,7 @@ mod shared_memory {
     use std::alloc::Layout;
 
     use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::memory_advice::huge_page_size;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::named_concept::*;
@@ -202,6 +203,106 @@ mod shared_memory {
         assert_that!(chunk, is_ok);
     }
 
+    #[test]
+    fn prefaulted_memory_can_be_used_by_creator_and_client<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .prefault_memory(true)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write(91) };
+
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+        let data_ptr = (chunk.offset.value() + sut_open.payload_start_address()) as *const u8;
+        assert_that!(unsafe { data_ptr.read() }, eq 91);
+    }
+
+    #[test]
+    fn opened_memory_can_be_locked_and_prefaulted_without_modifying_it<
+        Sut: SharedMemory<DefaultAllocator>,
+    >() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(CHUNK_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write(73) };
+
+        let sut_open = Sut::Builder::new(&name)
+            .is_memory_locked(true)
+            .prefault_memory(true)
+            .config(&config)
+            .open()
+            .unwrap();
+        let data_ptr = (chunk.offset.value() + sut_open.payload_start_address()) as *const u8;
+        assert_that!(unsafe { data_ptr.read() }, eq 73);
+    }
+
+    #[test]
+    fn huge_page_memory_can_be_used_or_reports_missing_support<
+        Sut: SharedMemory<DefaultAllocator>,
+    >() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = match Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .use_huge_pages(true)
+            .config(&config)
+            .create(&SHM_CONFIG)
+        {
+            Ok(sut) => sut,
+            Err(e) => {
+                assert_that!(e, eq SharedMemoryCreateError::UnableToUseHugePages);
+                assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
+                return;
+            }
+        };
+
+        let huge_page_size = huge_page_size().unwrap();
+        assert_that!(sut_create.uses_huge_pages(), eq true);
+        assert_that!(sut_create.size() % huge_page_size, eq 0);
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write(73) };
+
+        let sut_open = Sut::Builder::new(&name)
+            .use_huge_pages(true)
+            .config(&config)
+            .open()
+            .unwrap();
+        let data_ptr = (chunk.offset.value() + sut_open.payload_start_address()) as *const u8;
+        assert_that!(unsafe { data_ptr.read() }, eq 73);
+    }
+
+    #[test]
+    fn locked_memory_can_be_used<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(CHUNK_SIZE)
+            .is_memory_locked(true)
+            .prefault_memory(true)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let chunk = sut.allocate(DEFAULT_LAYOUT);
+        assert_that!(chunk, is_ok);
+    }
+
     #[test]
     fn allocated_chunks_have_correct_alignment<Sut: SharedMemory<DefaultAllocator>>() {
         let name = generate_name();
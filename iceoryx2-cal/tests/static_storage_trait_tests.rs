@@ -464,6 +464,63 @@ mod static_storage {
         assert_that!(Sut::does_exist(&storage_name), eq Ok(false));
     }
 
+    #[test]
+    fn create_or_replace_creates_non_existing_storage<Sut: StaticStorage>() {
+        let _test_guard = TEST_MUTEX.lock();
+        let storage_name = generate_name();
+
+        let content = "the grand premiere".to_string();
+        let storage_guard = Sut::Builder::new(&storage_name)
+            .create_or_replace(content.as_bytes())
+            .unwrap();
+
+        let storage_reader = Sut::Builder::new(&storage_name)
+            .open(Duration::ZERO)
+            .unwrap();
+        let mut read_content = vec![b' '; content.len()];
+        storage_reader.read(read_content.as_mut_slice()).unwrap();
+        assert_that!(read_content, eq content.as_bytes());
+
+        drop(storage_guard);
+        assert_that!(Sut::does_exist(&storage_name), eq Ok(false));
+    }
+
+    #[test]
+    fn create_or_replace_replaces_existing_storage<Sut: StaticStorage>() {
+        let _test_guard = TEST_MUTEX.lock();
+        let storage_name = generate_name();
+
+        let old_content = "the old stuff".to_string();
+        let new_content = "the brand new stuff".to_string();
+        let mut old_storage = Sut::Builder::new(&storage_name)
+            .create(old_content.as_bytes())
+            .unwrap();
+        let old_reader = Sut::Builder::new(&storage_name)
+            .open(Duration::ZERO)
+            .unwrap();
+
+        let new_storage = Sut::Builder::new(&storage_name)
+            .create_or_replace(new_content.as_bytes())
+            .unwrap();
+        old_storage.release_ownership();
+        drop(old_storage);
+
+        assert_that!(Sut::list().unwrap(), len 1);
+        let new_reader = Sut::Builder::new(&storage_name)
+            .open(Duration::ZERO)
+            .unwrap();
+        let mut read_content = vec![b' '; new_content.len()];
+        new_reader.read(read_content.as_mut_slice()).unwrap();
+        assert_that!(read_content, eq new_content.as_bytes());
+
+        let mut read_content = vec![b' '; old_content.len()];
+        old_reader.read(read_content.as_mut_slice()).unwrap();
+        assert_that!(read_content, eq old_content.as_bytes());
+
+        drop(new_storage);
+        assert_that!(Sut::does_exist(&storage_name), eq Ok(false));
+    }
+
     #[test]
     fn acquire_ownership_works<Sut: StaticStorage>() {
         let _test_guard = TEST_MUTEX.lock();
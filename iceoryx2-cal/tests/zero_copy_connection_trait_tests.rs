@@ -444,6 +444,109 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn dropped_and_buffered_samples_are_counted<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 12;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        assert_that!(sut_receiver.number_of_buffered_samples(), eq 0);
+        assert_that!(sut_receiver.number_of_dropped_samples(), eq 0);
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i)),
+                is_ok
+            );
+            assert_that!(sut_receiver.number_of_buffered_samples(), eq i + 1);
+        }
+
+        for i in 0..3 {
+            let result = sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * (BUFFER_SIZE + i)));
+            assert_that!(result, is_err);
+            assert_that!(sut_receiver.number_of_dropped_samples(), eq i as u64 + 1);
+        }
+
+        assert_that!(sut_sender.number_of_dropped_samples(), eq 3);
+        assert_that!(sut_receiver.number_of_buffered_samples(), eq BUFFER_SIZE);
+
+        assert_that!(sut_receiver.receive().unwrap(), is_some);
+        assert_that!(sut_receiver.number_of_buffered_samples(), eq BUFFER_SIZE - 1);
+    }
+
+    #[test]
+    fn statistics_can_be_acquired_without_participating<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 5;
+
+        assert_that!(Sut::statistics_cfg(&name, &config), is_none);
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE + 2 {
+            let _ = sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i));
+        }
+
+        let statistics = Sut::statistics_cfg(&name, &config);
+        assert_that!(statistics, eq Some(ZeroCopyConnectionStatistics {
+            number_of_buffered_samples: BUFFER_SIZE,
+            buffer_size: BUFFER_SIZE,
+            number_of_dropped_samples: 2,
+        }));
+
+        // the statistics do not connect to the connection
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver(SAMPLE_SIZE);
+        assert_that!(sut_receiver, is_ok);
+    }
+
+    #[test]
+    fn overflowed_samples_are_counted_as_dropped<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 7;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE * 2 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i)),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_sender.number_of_dropped_samples(), eq BUFFER_SIZE as u64);
+        assert_that!(sut_sender.number_of_buffered_samples(), eq BUFFER_SIZE);
+    }
+
     #[test]
     fn receive_can_acquire_data_with_late_connection<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -551,6 +654,102 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn receive_batch_is_limited_by_buffer_and_max_borrow<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 10;
+        const MAX_BORROW: usize = 4;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROW)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROW)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut buffer = [PointerOffset::new(0); BUFFER_SIZE];
+        assert_that!(sut_receiver.receive_batch(&mut buffer), eq Ok(0));
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(i * SAMPLE_SIZE)),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_receiver.receive_batch(&mut buffer[..3]), eq Ok(3));
+        assert_that!(sut_receiver.receive_batch(&mut buffer[3..]), eq Ok(1));
+        for (i, offset) in buffer.iter().take(MAX_BORROW).enumerate() {
+            assert_that!(offset.value(), eq i * SAMPLE_SIZE);
+        }
+
+        let result = sut_receiver.receive_batch(&mut buffer);
+        assert_that!(result, is_err);
+        assert_that!(
+            result.err().unwrap(), eq
+            ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue
+        );
+
+        for offset in buffer.iter().take(MAX_BORROW) {
+            assert_that!(sut_receiver.release(*offset), is_ok);
+        }
+
+        let number_of_samples = sut_receiver.receive_batch(&mut buffer).unwrap();
+        assert_that!(number_of_samples, eq MAX_BORROW);
+        assert_that!(buffer[0].value(), eq MAX_BORROW * SAMPLE_SIZE);
+    }
+
+    #[test]
+    fn peek_does_not_receive_or_borrow_samples<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 10;
+        const MAX_BORROW: usize = 2;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROW)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROW)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        assert_that!(sut_receiver.peek(), is_none);
+        assert_that!(sut_receiver.peek_last(), is_none);
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(i * SAMPLE_SIZE)),
+                is_ok
+            );
+            assert_that!(sut_receiver.peek(), eq Some(PointerOffset::new(0)));
+            assert_that!(sut_receiver.peek_last(), eq Some(PointerOffset::new(i * SAMPLE_SIZE)));
+        }
+        assert_that!(sut_receiver.number_of_buffered_samples(), eq BUFFER_SIZE);
+
+        for i in 0..MAX_BORROW {
+            assert_that!(sut_receiver.receive(), eq Ok(Some(PointerOffset::new(i * SAMPLE_SIZE))));
+        }
+        assert_that!(sut_receiver.peek(), eq Some(PointerOffset::new(MAX_BORROW * SAMPLE_SIZE)));
+        assert_that!(sut_receiver.peek_last(), eq Some(PointerOffset::new((BUFFER_SIZE - 1) * SAMPLE_SIZE)));
+    }
+
     #[test]
     fn blocking_send_blocks<Sut: ZeroCopyConnection>() {
         let name = generate_name();
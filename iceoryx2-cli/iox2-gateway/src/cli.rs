@@ -0,0 +1,156 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::net::SocketAddr;
+
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+use clap::ValueEnum;
+
+use iceoryx2_cli::help_template;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2-gateway",
+    about = "Bridge iceoryx2 publish-subscribe services to remote hosts",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template("iox2 gateway", false),
+)]
+pub struct Cli {
+    #[clap(subcommand)]
+    pub action: Option<Action>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "UPPERCASE")]
+pub enum Protocol {
+    Udp,
+    Tcp,
+    #[cfg(feature = "zenoh")]
+    Zenoh,
+    #[cfg(feature = "mqtt")]
+    Mqtt,
+}
+
+/// Serialization of the samples that are exchanged via MQTT.
+#[cfg(feature = "mqtt")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "UPPERCASE")]
+pub enum Encoding {
+    Json,
+    Cbor,
+    Postcard,
+}
+
+#[derive(Args)]
+pub struct OutboundOptions {
+    #[clap(
+        short,
+        long = "service",
+        required = true,
+        help = "Name pattern of the services that shall be forwarded, '*' matches any sequence of characters e.g. \"sensor/*\""
+    )]
+    pub services: Vec<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Address of the remote inbound gateway, zenoh router or MQTT broker e.g. 192.168.0.10:7447"
+    )]
+    pub remote: SocketAddr,
+
+    #[clap(short, long, value_enum, default_value_t = Protocol::Udp)]
+    pub protocol: Protocol,
+
+    #[cfg(feature = "zenoh")]
+    #[clap(
+        long,
+        default_value = "iox2",
+        help = "Key expression prefix under which the services are published via zenoh"
+    )]
+    pub key_prefix: String,
+
+    #[cfg(feature = "mqtt")]
+    #[clap(
+        long,
+        default_value = "iox2",
+        help = "Topic prefix under which the services are published via MQTT"
+    )]
+    pub topic_prefix: String,
+
+    #[cfg(feature = "mqtt")]
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = Encoding::Json,
+        help = "Serialization of the samples that are exchanged via MQTT"
+    )]
+    pub encoding: Encoding,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 10,
+        help = "Cycle time in milliseconds in which new samples and services are discovered"
+    )]
+    pub cycle_time: u64,
+}
+
+#[derive(Args)]
+pub struct InboundOptions {
+    #[clap(
+        short,
+        long,
+        help = "Address the gateway listens on or the MQTT broker it connects to e.g. 0.0.0.0:7447"
+    )]
+    pub bind: SocketAddr,
+
+    #[clap(short, long, value_enum, default_value_t = Protocol::Udp)]
+    pub protocol: Protocol,
+
+    #[cfg(feature = "zenoh")]
+    #[clap(
+        long,
+        default_value = "iox2",
+        help = "Key expression prefix under which the services are received via zenoh"
+    )]
+    pub key_prefix: String,
+
+    #[cfg(feature = "mqtt")]
+    #[clap(
+        long,
+        default_value = "iox2",
+        help = "Topic prefix under which the services are received via MQTT"
+    )]
+    pub topic_prefix: String,
+
+    #[cfg(feature = "mqtt")]
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = Encoding::Json,
+        help = "Serialization of the samples that are exchanged via MQTT"
+    )]
+    pub encoding: Encoding,
+}
+
+#[derive(Subcommand)]
+pub enum Action {
+    #[clap(about = "Forward samples of local services to a remote host")]
+    Outbound(OutboundOptions),
+    #[clap(about = "Receive samples from remote hosts and publish them locally")]
+    Inbound(InboundOptions),
+}
@@ -0,0 +1,382 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use iceoryx2::node::NodeView;
+use iceoryx2::port::port_identifiers::UniquePublisherId;
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::port_factory::publish_subscribe;
+use iceoryx2::service::static_config::message_type_details::{
+    MessageTypeDetails, TypeDetail, TypeVariant,
+};
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+
+use crate::cli::{InboundOptions, OutboundOptions};
+use crate::frame::{Frame, MAX_FRAME_SIZE};
+use crate::transport::{FrameReceiver, FrameSender};
+
+type CustomSubscriber = Subscriber<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>;
+type CustomPublisher = Publisher<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>;
+
+const INBOUND_RECEIVE_TIMEOUT: Duration = Duration::from_millis(10);
+/// The name of every gateway node. It marks the ports that were created by a gateway, so that
+/// the outbound gateway does not forward samples that an inbound gateway received.
+const GATEWAY_NODE_NAME: &str = "iox2-gateway";
+
+fn create_gateway_node() -> Result<Node<ipc::Service>> {
+    let node_name: NodeName = GATEWAY_NODE_NAME
+        .try_into()
+        .map_err(|e| anyhow!("invalid node name \"{}\" ({:?})", GATEWAY_NODE_NAME, e))?;
+
+    NodeBuilder::new()
+        .name(&node_name)
+        .create::<ipc::Service>()
+        .context("failed to create node")
+}
+
+fn is_gateway_node(node: &NodeState<ipc::Service>) -> bool {
+    match node {
+        NodeState::Alive(view) | NodeState::Unresponsive(view) => view
+            .details()
+            .as_ref()
+            .is_some_and(|details| details.name() == GATEWAY_NODE_NAME),
+        _ => false,
+    }
+}
+
+/// Matches the service name against a pattern where '*' matches any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    if !name.starts_with(first) {
+        return false;
+    }
+
+    let mut remainder = &name[first.len()..];
+    let mut parts: Vec<&str> = parts.collect();
+    let last = match parts.pop() {
+        Some(last) => last,
+        // pattern contains no wildcard
+        None => return remainder.is_empty(),
+    };
+
+    for part in parts {
+        match remainder.find(part) {
+            Some(position) => remainder = &remainder[position + part.len()..],
+            None => return false,
+        }
+    }
+
+    remainder.ends_with(last)
+}
+
+fn open_service(
+    node: &Node<ipc::Service>,
+    service_name: &str,
+    type_details: &MessageTypeDetails,
+) -> Result<publish_subscribe::PortFactory<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>>
+{
+    let service_name: ServiceName = service_name
+        .try_into()
+        .map_err(|e| anyhow!("invalid service name \"{}\" ({:?})", service_name, e))?;
+
+    node.service_builder(&service_name)
+        .publish_subscribe_raw()
+        .message_type_details(type_details)
+        .open_or_create()
+        .map_err(|e| anyhow!("unable to open service \"{}\" ({})", service_name, e))
+}
+
+struct OutboundConnection {
+    service:
+        publish_subscribe::PortFactory<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
+    subscriber: CustomSubscriber,
+    type_details: MessageTypeDetails,
+}
+
+impl OutboundConnection {
+    fn create(
+        node: &Node<ipc::Service>,
+        service_name: &str,
+        type_details: &MessageTypeDetails,
+    ) -> Result<Self> {
+        let service = open_service(node, service_name, type_details)?;
+        let subscriber = service
+            .subscriber_builder()
+            .create()
+            .map_err(|e| anyhow!("unable to create subscriber ({})", e))?;
+
+        Ok(Self {
+            service,
+            subscriber,
+            type_details: type_details.clone(),
+        })
+    }
+
+    /// Returns the publishers of the service that belong to a gateway node.
+    fn gateway_publishers(&self) -> Result<HashSet<UniquePublisherId>> {
+        let mut gateway_nodes = HashSet::new();
+        self.service.nodes(|node| {
+            if is_gateway_node(&node) {
+                gateway_nodes.insert(*node.node_id());
+            }
+            CallbackProgression::Continue
+        })?;
+
+        let mut gateway_publishers = HashSet::new();
+        self.service
+            .dynamic_config()
+            .__internal_publishers(|publisher_id, node_id| {
+                if gateway_nodes.contains(node_id) {
+                    gateway_publishers.insert(*publisher_id);
+                }
+            });
+
+        Ok(gateway_publishers)
+    }
+
+    fn forward(&self, service_name: &str, sender: &mut FrameSender) -> Result<()> {
+        if !self.subscriber.has_samples()? {
+            return Ok(());
+        }
+
+        let gateway_publishers = self.gateway_publishers()?;
+        while let Some(sample) = self.subscriber.receive_raw()? {
+            // samples that a gateway received from a remote host are never sent back
+            if gateway_publishers.contains(&sample.origin()) {
+                continue;
+            }
+
+            let frame = Frame {
+                service_name: service_name.to_string(),
+                type_details: self.type_details.clone(),
+                number_of_elements: sample.header().number_of_elements(),
+                user_header: sample.user_header_bytes().to_vec(),
+                payload: sample.payload_bytes().to_vec(),
+            };
+
+            if let Err(e) = sender.send(&frame) {
+                eprintln!(
+                    "Failed to forward sample of service \"{}\": {}",
+                    service_name, e
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn outbound(options: OutboundOptions) -> Result<()> {
+    let node = create_gateway_node()?;
+    let mut sender =
+        FrameSender::new(&options).context("failed to create connection to remote gateway")?;
+    let mut connections = HashMap::<String, OutboundConnection>::new();
+    let cycle_time = Duration::from_millis(options.cycle_time);
+
+    while node.wait(cycle_time).is_ok() {
+        for (service_name, connection) in &connections {
+            if let Err(e) = connection.forward(service_name, &mut sender) {
+                eprintln!("Failed to forward service \"{}\": {}", service_name, e);
+            }
+        }
+
+        // services that are only used by gateways were created by an inbound gateway or are
+        // kept alive by this gateway alone and are therefore not forwarded
+        let mut discovered_services = HashMap::new();
+        ipc::Service::list(node.config(), |service| {
+            let service_name = service.static_details.name().to_string();
+            if let MessagingPattern::PublishSubscribe(details) =
+                service.static_details.messaging_pattern()
+            {
+                let has_local_nodes = match &service.dynamic_details {
+                    Some(dynamic_details) => {
+                        dynamic_details.nodes.iter().any(|n| !is_gateway_node(n))
+                    }
+                    None => false,
+                };
+
+                if has_local_nodes
+                    && options
+                        .services
+                        .iter()
+                        .any(|pattern| matches_pattern(pattern, &service_name))
+                {
+                    discovered_services
+                        .insert(service_name, details.message_type_details().clone());
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve services")?;
+
+        connections.retain(|service_name, _| discovered_services.contains_key(service_name));
+
+        for (service_name, type_details) in discovered_services {
+            if connections.contains_key(&service_name) {
+                continue;
+            }
+
+            match OutboundConnection::create(&node, &service_name, &type_details) {
+                Ok(connection) => {
+                    connections.insert(service_name, connection);
+                }
+                Err(e) => eprintln!("Failed to forward service \"{}\": {}", service_name, e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct InboundConnection {
+    publisher: CustomPublisher,
+    type_details: MessageTypeDetails,
+    max_slice_len: usize,
+}
+
+/// Verifies that a type detail received from a remote gateway describes a type that can be
+/// transferred in a single frame, before a service is created with it.
+fn verify_remote_type_detail(type_detail: &TypeDetail) -> Result<()> {
+    if !type_detail.alignment.is_power_of_two() || type_detail.size > MAX_FRAME_SIZE {
+        return Err(anyhow!(
+            "the received type \"{}\" has an invalid layout (size: {}, alignment: {})",
+            type_detail.type_name,
+            type_detail.size,
+            type_detail.alignment
+        ));
+    }
+
+    Ok(())
+}
+
+impl InboundConnection {
+    fn create(node: &Node<ipc::Service>, frame: &Frame, max_slice_len: usize) -> Result<Self> {
+        verify_remote_type_detail(&frame.type_details.user_header)?;
+        verify_remote_type_detail(&frame.type_details.payload)?;
+
+        let service = open_service(node, &frame.service_name, &frame.type_details)?;
+
+        // the local service may have been created with other types by a local process, only
+        // the types of the local service are trusted from now on
+        let local_type_details = service.static_config().message_type_details().clone();
+        if local_type_details.user_header != frame.type_details.user_header
+            || local_type_details.payload != frame.type_details.payload
+        {
+            return Err(anyhow!(
+                "the type of the received sample does not match the type of the local service"
+            ));
+        }
+
+        let publisher_builder = service.publisher_builder();
+        let publisher_builder = match local_type_details.payload.variant {
+            TypeVariant::Dynamic => publisher_builder.max_slice_len(max_slice_len),
+            TypeVariant::FixedSize => publisher_builder,
+        };
+
+        let publisher = publisher_builder
+            .create()
+            .map_err(|e| anyhow!("unable to create publisher ({})", e))?;
+
+        Ok(Self {
+            publisher,
+            type_details: local_type_details,
+            max_slice_len,
+        })
+    }
+
+    fn publish(&self, frame: &Frame) -> Result<()> {
+        if frame.type_details.user_header != self.type_details.user_header
+            || frame.type_details.payload != self.type_details.payload
+        {
+            return Err(anyhow!(
+                "the type of the received sample does not match the service type"
+            ));
+        }
+
+        if frame.user_header.len() != self.type_details.user_header.size
+            || frame
+                .verified_number_of_elements(self.type_details.payload.size)
+                .is_err()
+        {
+            return Err(anyhow!(
+                "the size of the received sample does not match the service type"
+            ));
+        }
+
+        self.publisher
+            .send_raw_copy(&frame.user_header, &frame.payload)?;
+        Ok(())
+    }
+}
+
+fn publish_frame(
+    node: &Node<ipc::Service>,
+    connections: &mut HashMap<String, InboundConnection>,
+    frame: Frame,
+) -> Result<()> {
+    let number_of_elements = usize::try_from(frame.number_of_elements)?;
+    let requires_new_publisher = match connections.get(&frame.service_name) {
+        None => true,
+        Some(connection) => {
+            connection.type_details.payload.variant == TypeVariant::Dynamic
+                && connection.max_slice_len < number_of_elements
+        }
+    };
+
+    if requires_new_publisher {
+        let max_slice_len = connections
+            .get(&frame.service_name)
+            .map_or(1, |c| c.max_slice_len.saturating_mul(2))
+            .max(number_of_elements);
+        // the old publisher must be released first since the service may not support
+        // an additional publisher
+        connections.remove(&frame.service_name);
+        let connection = InboundConnection::create(node, &frame, max_slice_len)?;
+        connections.insert(frame.service_name.clone(), connection);
+    }
+
+    match connections.get(&frame.service_name) {
+        Some(connection) => connection.publish(&frame),
+        None => Err(anyhow!("no publisher available")),
+    }
+}
+
+pub fn inbound(options: InboundOptions) -> Result<()> {
+    let node = create_gateway_node()?;
+    let mut receiver = FrameReceiver::new(&options, INBOUND_RECEIVE_TIMEOUT)
+        .context("failed to bind inbound gateway")?;
+    let mut connections = HashMap::<String, InboundConnection>::new();
+
+    while node.wait(Duration::ZERO).is_ok() {
+        receiver.receive(
+            |frame| {
+                let service_name = frame.service_name.clone();
+                if let Err(e) = publish_frame(&node, &mut connections, frame) {
+                    eprintln!(
+                        "Failed to publish sample of service \"{}\": {}",
+                        service_name, e
+                    );
+                }
+            },
+            |e| eprintln!("Received invalid frame: {}", e),
+        )?;
+    }
+
+    Ok(())
+}
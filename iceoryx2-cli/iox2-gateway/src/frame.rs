@@ -0,0 +1,353 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Wire format of a single sample that is exchanged between two gateways.
+//!
+//! All integers are encoded in big endian.
+//!
+//! ```text
+//! | u32 frame len | u16 name len | name | u32 type len | type (json) |
+//! | u64 number of elements | u32 user header len | user header | u32 payload len | payload |
+//! ```
+//!
+//! The frame len covers everything that follows the frame len field and is limited to
+//! [`MAX_FRAME_SIZE`].
+
+use anyhow::{anyhow, Result};
+use iceoryx2::service::static_config::message_type_details::MessageTypeDetails;
+#[cfg(feature = "mqtt")]
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+#[cfg(feature = "mqtt")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "mqtt")]
+use crate::cli::Encoding;
+
+pub const FRAME_LEN_SIZE: usize = core::mem::size_of::<u32>();
+
+/// The maximum size of a frame, without the frame len field. Larger frames are neither sent
+/// nor accepted, so that a remote gateway cannot exhaust the memory of the receiver.
+pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub service_name: String,
+    pub type_details: MessageTypeDetails,
+    pub number_of_elements: u64,
+    pub user_header: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+impl Frame {
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let type_details = serde_json::to_vec(&self.type_details)?;
+        let name_len = u16::try_from(self.service_name.len())?;
+
+        let mut buffer = vec![0u8; FRAME_LEN_SIZE];
+        buffer.extend_from_slice(&name_len.to_be_bytes());
+        buffer.extend_from_slice(self.service_name.as_bytes());
+        buffer.extend_from_slice(&u32::try_from(type_details.len())?.to_be_bytes());
+        buffer.extend_from_slice(&type_details);
+        buffer.extend_from_slice(&self.number_of_elements.to_be_bytes());
+        buffer.extend_from_slice(&u32::try_from(self.user_header.len())?.to_be_bytes());
+        buffer.extend_from_slice(&self.user_header);
+        buffer.extend_from_slice(&u32::try_from(self.payload.len())?.to_be_bytes());
+        buffer.extend_from_slice(&self.payload);
+
+        let frame_len = buffer.len() - FRAME_LEN_SIZE;
+        if frame_len > MAX_FRAME_SIZE {
+            return Err(anyhow!(
+                "frame with {} bytes exceeds the maximum frame size of {} bytes",
+                frame_len,
+                MAX_FRAME_SIZE
+            ));
+        }
+        buffer[..FRAME_LEN_SIZE].copy_from_slice(&u32::try_from(frame_len)?.to_be_bytes());
+
+        Ok(buffer)
+    }
+
+    /// Decodes the frame from the bytes that follow the frame len field.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() > MAX_FRAME_SIZE {
+            return Err(anyhow!(
+                "frame with {} bytes exceeds the maximum frame size of {} bytes",
+                bytes.len(),
+                MAX_FRAME_SIZE
+            ));
+        }
+
+        let mut reader = Reader { bytes };
+
+        let name_len = u16::from_be_bytes(reader.take_array()?).into();
+        let service_name = String::from_utf8(reader.take(name_len)?.to_vec())?;
+        let type_len = reader.take_len()?;
+        let type_details = serde_json::from_slice(reader.take(type_len)?)?;
+        let number_of_elements = u64::from_be_bytes(reader.take_array()?);
+        let user_header_len = reader.take_len()?;
+        let user_header = reader.take(user_header_len)?.to_vec();
+        let payload_len = reader.take_len()?;
+        let payload = reader.take(payload_len)?.to_vec();
+
+        if !reader.bytes.is_empty() {
+            return Err(anyhow!(
+                "frame contains {} unexpected trailing bytes",
+                reader.bytes.len()
+            ));
+        }
+
+        Ok(Self {
+            service_name,
+            type_details,
+            number_of_elements,
+            user_header,
+            payload,
+        })
+    }
+
+    /// Decodes the frame len field. Fails when the frame exceeds [`MAX_FRAME_SIZE`].
+    pub fn decode_frame_len(bytes: [u8; FRAME_LEN_SIZE]) -> Result<usize> {
+        let frame_len = usize::try_from(u32::from_be_bytes(bytes))?;
+        if frame_len > MAX_FRAME_SIZE {
+            return Err(anyhow!(
+                "frame with {} bytes exceeds the maximum frame size of {} bytes",
+                frame_len,
+                MAX_FRAME_SIZE
+            ));
+        }
+
+        Ok(frame_len)
+    }
+
+    /// Returns the number of elements as `usize` and verifies that the payload contains
+    /// exactly `number_of_elements` elements of `payload_size` bytes each.
+    pub fn verified_number_of_elements(&self, payload_size: usize) -> Result<usize> {
+        let number_of_elements = usize::try_from(self.number_of_elements)?;
+        match number_of_elements.checked_mul(payload_size) {
+            Some(len) if len == self.payload.len() => Ok(number_of_elements),
+            _ => Err(anyhow!(
+                "payload with {} bytes does not contain {} elements of {} bytes",
+                self.payload.len(),
+                self.number_of_elements,
+                payload_size
+            )),
+        }
+    }
+
+    /// Serializes the frame into a self-describing MQTT message, so that MQTT clients like
+    /// dashboards can consume it without knowing the binary frame layout.
+    #[cfg(feature = "mqtt")]
+    pub fn to_mqtt_message(&self, encoding: Encoding) -> Result<Vec<u8>> {
+        let message = MqttMessage {
+            service_name: self.service_name.clone(),
+            header_type: (&self.type_details.header).into(),
+            user_header_type: (&self.type_details.user_header).into(),
+            payload_type: (&self.type_details.payload).into(),
+            number_of_elements: self.number_of_elements,
+            user_header: self.user_header.clone(),
+            payload: self.payload.clone(),
+        };
+
+        match encoding {
+            Encoding::Json => Ok(serde_json::to_vec(&message)?),
+            Encoding::Cbor => {
+                let mut buffer = vec![];
+                ciborium::into_writer(&message, &mut buffer)?;
+                Ok(buffer)
+            }
+            Encoding::Postcard => Ok(postcard::to_allocvec(&message)?),
+        }
+    }
+
+    #[cfg(feature = "mqtt")]
+    pub fn from_mqtt_message(bytes: &[u8], encoding: Encoding) -> Result<Self> {
+        let message: MqttMessage = match encoding {
+            Encoding::Json => serde_json::from_slice(bytes)?,
+            Encoding::Cbor => ciborium::from_reader(bytes)?,
+            Encoding::Postcard => postcard::from_bytes(bytes)?,
+        };
+
+        Ok(Self {
+            service_name: message.service_name,
+            type_details: MessageTypeDetails {
+                header: message.header_type.into(),
+                user_header: message.user_header_type.into(),
+                payload: message.payload_type.into(),
+            },
+            number_of_elements: message.number_of_elements,
+            user_header: message.user_header,
+            payload: message.payload,
+        })
+    }
+}
+
+/// Mirrors [`TypeDetail`] since its serde representation is tailored to TOML and cannot be
+/// used with non-self-describing formats like postcard.
+#[cfg(feature = "mqtt")]
+#[derive(Debug, Serialize, Deserialize)]
+struct MqttTypeDetail {
+    variant: TypeVariant,
+    type_name: String,
+    size: usize,
+    alignment: usize,
+    layout_hash: Option<u64>,
+}
+
+#[cfg(feature = "mqtt")]
+impl From<&TypeDetail> for MqttTypeDetail {
+    fn from(value: &TypeDetail) -> Self {
+        Self {
+            variant: value.variant,
+            type_name: value.type_name.clone(),
+            size: value.size,
+            alignment: value.alignment,
+            layout_hash: value.layout_hash,
+        }
+    }
+}
+
+#[cfg(feature = "mqtt")]
+impl From<MqttTypeDetail> for TypeDetail {
+    fn from(value: MqttTypeDetail) -> Self {
+        Self {
+            variant: value.variant,
+            type_name: value.type_name,
+            size: value.size,
+            alignment: value.alignment,
+            layout_hash: value.layout_hash,
+        }
+    }
+}
+
+#[cfg(feature = "mqtt")]
+#[derive(Debug, Serialize, Deserialize)]
+struct MqttMessage {
+    service_name: String,
+    header_type: MqttTypeDetail,
+    user_header_type: MqttTypeDetail,
+    payload_type: MqttTypeDetail,
+    number_of_elements: u64,
+    user_header: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(anyhow!(
+                "frame is truncated, expected {} more bytes but only {} are available",
+                len,
+                self.bytes.len()
+            ));
+        }
+
+        let (value, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(value)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut value = [0u8; N];
+        value.copy_from_slice(self.take(N)?);
+        Ok(value)
+    }
+
+    fn take_len(&mut self) -> Result<usize> {
+        Ok(usize::try_from(u32::from_be_bytes(self.take_array()?))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2_bb_testing::assert_that;
+
+    fn create_type_detail(variant: TypeVariant, size: usize) -> TypeDetail {
+        TypeDetail {
+            variant,
+            type_name: format!("u{}", size * 8),
+            size,
+            alignment: size,
+            layout_hash: None,
+        }
+    }
+
+    fn create_frame(payload_len: usize) -> Frame {
+        Frame {
+            service_name: "some/service".to_string(),
+            type_details: MessageTypeDetails {
+                header: create_type_detail(TypeVariant::FixedSize, 8),
+                user_header: create_type_detail(TypeVariant::FixedSize, 2),
+                payload: create_type_detail(TypeVariant::Dynamic, 1),
+            },
+            number_of_elements: payload_len as u64,
+            user_header: vec![7u8; 2],
+            payload: vec![3u8; payload_len],
+        }
+    }
+
+    #[test]
+    fn encoded_frame_can_be_decoded() {
+        let sut = create_frame(128);
+
+        let encoded = sut.encode().unwrap();
+        let frame_len =
+            Frame::decode_frame_len(encoded[..FRAME_LEN_SIZE].try_into().unwrap()).unwrap();
+        assert_that!(frame_len, eq encoded.len() - FRAME_LEN_SIZE);
+
+        let decoded = Frame::decode(&encoded[FRAME_LEN_SIZE..]).unwrap();
+        assert_that!(decoded, eq sut);
+        assert_that!(decoded.verified_number_of_elements(1).unwrap(), eq 128);
+    }
+
+    #[test]
+    fn truncated_frame_cannot_be_decoded() {
+        let encoded = create_frame(128).encode().unwrap();
+
+        for len in FRAME_LEN_SIZE..encoded.len() {
+            assert_that!(Frame::decode(&encoded[FRAME_LEN_SIZE..len]), is_err);
+        }
+    }
+
+    #[test]
+    fn frame_with_trailing_bytes_cannot_be_decoded() {
+        let mut encoded = create_frame(128).encode().unwrap();
+        encoded.push(0);
+
+        assert_that!(Frame::decode(&encoded[FRAME_LEN_SIZE..]), is_err);
+    }
+
+    #[test]
+    fn oversized_frame_is_rejected() {
+        let sut = create_frame(MAX_FRAME_SIZE);
+        assert_that!(sut.encode(), is_err);
+
+        let frame_len = u32::try_from(MAX_FRAME_SIZE + 1).unwrap().to_be_bytes();
+        assert_that!(Frame::decode_frame_len(frame_len), is_err);
+        assert_that!(Frame::decode_frame_len(u32::MAX.to_be_bytes()), is_err);
+        assert_that!(Frame::decode(&vec![0u8; MAX_FRAME_SIZE + 1]), is_err);
+    }
+
+    #[test]
+    fn number_of_elements_that_does_not_match_the_payload_is_rejected() {
+        let mut sut = create_frame(128);
+        assert_that!(sut.verified_number_of_elements(2), is_err);
+
+        sut.number_of_elements = u64::MAX;
+        assert_that!(sut.verified_number_of_elements(2), is_err);
+    }
+}
@@ -0,0 +1,602 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+#[cfg(feature = "mqtt")]
+use iceoryx2::prelude::CallbackProgression;
+#[cfg(feature = "zenoh")]
+use zenoh::Wait;
+
+#[cfg(feature = "mqtt")]
+use crate::cli::Encoding;
+use crate::cli::{InboundOptions, OutboundOptions, Protocol};
+use crate::frame::{Frame, FRAME_LEN_SIZE, MAX_FRAME_SIZE};
+
+const MAX_UDP_DATAGRAM_SIZE: usize = 65507;
+/// A TCP connection buffers at most one frame of maximum size, further data remains in the
+/// socket until the buffered frames are processed.
+const MAX_TCP_BUFFER_SIZE: usize = FRAME_LEN_SIZE + MAX_FRAME_SIZE;
+const TCP_READ_CHUNK_SIZE: usize = 4096;
+/// Limits how long the outbound gateway is blocked when the remote gateway is unreachable.
+const TCP_CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+#[cfg(feature = "mqtt")]
+const MQTT_REQUEST_CAPACITY: usize = 64;
+#[cfg(feature = "mqtt")]
+const MQTT_MAX_PACKET_SIZE: usize = 1024 * 1024;
+#[cfg(feature = "mqtt")]
+const MQTT_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Opens a zenoh session that either connects to or listens on the TCP endpoint with the
+/// provided address, `endpoints` is the corresponding zenoh config key.
+#[cfg(feature = "zenoh")]
+fn open_zenoh_session(endpoints: &str, address: SocketAddr) -> Result<zenoh::Session> {
+    let mut config = zenoh::Config::default();
+    config
+        .insert_json5(endpoints, &format!("[\"tcp/{}\"]", address))
+        .map_err(|e| anyhow!("invalid zenoh endpoint {} ({})", address, e))?;
+
+    zenoh::open(config)
+        .wait()
+        .map_err(|e| anyhow!("unable to open zenoh session ({})", e))
+}
+
+/// Returns `<prefix>/<encoded service name>`. Service names may contain characters that are
+/// wildcards or separators in zenoh key expressions and MQTT topics like `*`, `$`, `?`, `+`,
+/// `#` or `/`. Therefore, every byte that is not alphanumeric, `-`, `_` or `.` is
+/// percent-encoded so that the service name becomes a single, literal level of the key
+/// expression or topic.
+#[cfg(any(feature = "zenoh", feature = "mqtt"))]
+fn encode_service_name(prefix: &str, service_name: &str) -> String {
+    let mut encoded = String::with_capacity(prefix.len() + 1 + service_name.len());
+    encoded.push_str(prefix);
+    encoded.push('/');
+    for byte in service_name.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+/// Returns the key expression `<key prefix>/<encoded service name>` under which the samples of
+/// the service are published, see [`encode_service_name()`].
+#[cfg(feature = "zenoh")]
+fn zenoh_key_expr(key_prefix: &str, service_name: &str) -> String {
+    encode_service_name(key_prefix, service_name)
+}
+
+/// Returns the topic `<topic prefix>/<encoded service name>` under which the samples of the
+/// service are published, see [`encode_service_name()`].
+#[cfg(feature = "mqtt")]
+fn mqtt_topic(topic_prefix: &str, service_name: &str) -> String {
+    encode_service_name(topic_prefix, service_name)
+}
+
+/// Creates a client that connects to the MQTT broker with the provided address. The returned
+/// connection must be polled to make progress, see [`spawn_mqtt_connection()`].
+#[cfg(feature = "mqtt")]
+fn create_mqtt_client(role: &str, broker: SocketAddr) -> (rumqttc::Client, rumqttc::Connection) {
+    let mut options = rumqttc::MqttOptions::new(
+        format!("iox2-gateway-{}-{}", role, std::process::id()),
+        broker.ip().to_string(),
+        broker.port(),
+    );
+    options.set_max_packet_size(MQTT_MAX_PACKET_SIZE, MQTT_MAX_PACKET_SIZE);
+
+    rumqttc::Client::new(options, MQTT_REQUEST_CAPACITY)
+}
+
+/// Polls the MQTT connection in a background thread, reconnects on failure and calls
+/// `on_event` for every event until it returns [`CallbackProgression::Stop`] or all clients
+/// are dropped.
+#[cfg(feature = "mqtt")]
+fn spawn_mqtt_connection<F: FnMut(rumqttc::Event) -> CallbackProgression + Send + 'static>(
+    mut connection: rumqttc::Connection,
+    mut on_event: F,
+) -> Result<()> {
+    std::thread::Builder::new()
+        .name("iox2-gateway-mqtt".to_string())
+        .spawn(move || {
+            for event in connection.iter() {
+                match event {
+                    Ok(event) => {
+                        if on_event(event) == CallbackProgression::Stop {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("MQTT connection failure: {}", e);
+                        std::thread::sleep(MQTT_RECONNECT_DELAY);
+                    }
+                }
+            }
+        })?;
+
+    Ok(())
+}
+
+pub enum FrameSender {
+    Udp {
+        socket: UdpSocket,
+        remote: SocketAddr,
+    },
+    Tcp {
+        stream: Option<TcpStream>,
+        remote: SocketAddr,
+    },
+    /// Publishes every sample under the key expression `<key prefix>/<encoded service name>`,
+    /// so that zenoh applications can subscribe to single services, see [`zenoh_key_expr()`].
+    #[cfg(feature = "zenoh")]
+    Zenoh {
+        session: zenoh::Session,
+        key_prefix: String,
+    },
+    /// Publishes every sample under the topic `<topic prefix>/<encoded service name>`, so that
+    /// MQTT clients like dashboards can subscribe to single services, see [`mqtt_topic()`].
+    #[cfg(feature = "mqtt")]
+    Mqtt {
+        client: rumqttc::Client,
+        topic_prefix: String,
+        encoding: Encoding,
+    },
+}
+
+impl FrameSender {
+    pub fn new(options: &OutboundOptions) -> Result<Self> {
+        let remote = options.remote;
+        match options.protocol {
+            Protocol::Udp => {
+                let local: SocketAddr = if remote.is_ipv4() {
+                    ([0, 0, 0, 0], 0).into()
+                } else {
+                    ([0u16; 8], 0).into()
+                };
+                Ok(Self::Udp {
+                    socket: UdpSocket::bind(local)?,
+                    remote,
+                })
+            }
+            Protocol::Tcp => Ok(Self::Tcp {
+                stream: None,
+                remote,
+            }),
+            #[cfg(feature = "zenoh")]
+            Protocol::Zenoh => Ok(Self::Zenoh {
+                session: open_zenoh_session("connect/endpoints", remote)?,
+                key_prefix: options.key_prefix.clone(),
+            }),
+            #[cfg(feature = "mqtt")]
+            Protocol::Mqtt => {
+                let (client, connection) = create_mqtt_client("outbound", remote);
+                spawn_mqtt_connection(connection, |_| CallbackProgression::Continue)?;
+                Ok(Self::Mqtt {
+                    client,
+                    topic_prefix: options.topic_prefix.clone(),
+                    encoding: options.encoding,
+                })
+            }
+        }
+    }
+
+    pub fn send(&mut self, frame: &Frame) -> Result<()> {
+        match self {
+            Self::Udp { socket, remote } => {
+                let encoded_frame = frame.encode()?;
+                if encoded_frame.len() > MAX_UDP_DATAGRAM_SIZE {
+                    return Err(anyhow!(
+                        "frame with {} bytes exceeds the maximum UDP datagram size of {} bytes",
+                        encoded_frame.len(),
+                        MAX_UDP_DATAGRAM_SIZE
+                    ));
+                }
+                socket.send_to(&encoded_frame, *remote)?;
+                Ok(())
+            }
+            Self::Tcp { stream, remote } => {
+                let encoded_frame = frame.encode()?;
+                if stream.is_none() {
+                    let new_stream = TcpStream::connect_timeout(remote, TCP_CONNECT_TIMEOUT)?;
+                    new_stream.set_nodelay(true)?;
+                    *stream = Some(new_stream);
+                }
+
+                let result = stream
+                    .as_mut()
+                    .map_or(Ok(()), |s| s.write_all(&encoded_frame));
+                if result.is_err() {
+                    // reconnect with the next frame
+                    *stream = None;
+                }
+                Ok(result?)
+            }
+            #[cfg(feature = "zenoh")]
+            Self::Zenoh {
+                session,
+                key_prefix,
+            } => session
+                .put(
+                    zenoh_key_expr(key_prefix, &frame.service_name),
+                    frame.encode()?,
+                )
+                .wait()
+                .map_err(|e| anyhow!("unable to publish frame via zenoh ({})", e)),
+            // drops the sample instead of blocking the gateway when the broker is unreachable
+            #[cfg(feature = "mqtt")]
+            Self::Mqtt {
+                client,
+                topic_prefix,
+                encoding,
+            } => client
+                .try_publish(
+                    mqtt_topic(topic_prefix, &frame.service_name),
+                    rumqttc::QoS::AtMostOnce,
+                    false,
+                    frame.to_mqtt_message(*encoding)?,
+                )
+                .map_err(|e| anyhow!("unable to publish frame via MQTT ({})", e)),
+        }
+    }
+}
+
+pub struct TcpConnection {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+}
+
+pub enum FrameReceiver {
+    Udp {
+        socket: UdpSocket,
+        buffer: Vec<u8>,
+    },
+    Tcp {
+        listener: TcpListener,
+        connections: Vec<TcpConnection>,
+        timeout: Duration,
+    },
+    /// Receives the samples of all services that are published under the key prefix.
+    #[cfg(feature = "zenoh")]
+    Zenoh {
+        subscriber:
+            zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>,
+        // keeps the session alive as long as the subscriber is in use
+        _session: zenoh::Session,
+        timeout: Duration,
+    },
+    /// Receives the samples of all services that are published under the topic prefix.
+    #[cfg(feature = "mqtt")]
+    Mqtt {
+        messages: std::sync::mpsc::Receiver<Vec<u8>>,
+        encoding: Encoding,
+        timeout: Duration,
+    },
+}
+
+impl FrameReceiver {
+    pub fn new(options: &InboundOptions, timeout: Duration) -> Result<Self> {
+        let bind = options.bind;
+        match options.protocol {
+            Protocol::Udp => {
+                let socket = UdpSocket::bind(bind)?;
+                socket.set_read_timeout(Some(timeout))?;
+                Ok(Self::Udp {
+                    socket,
+                    buffer: vec![0u8; MAX_UDP_DATAGRAM_SIZE],
+                })
+            }
+            Protocol::Tcp => {
+                let listener = TcpListener::bind(bind)?;
+                listener.set_nonblocking(true)?;
+                Ok(Self::Tcp {
+                    listener,
+                    connections: vec![],
+                    timeout,
+                })
+            }
+            #[cfg(feature = "zenoh")]
+            Protocol::Zenoh => {
+                let session = open_zenoh_session("listen/endpoints", bind)?;
+                let subscriber = session
+                    .declare_subscriber(format!("{}/**", options.key_prefix))
+                    .wait()
+                    .map_err(|e| anyhow!("unable to create zenoh subscriber ({})", e))?;
+                Ok(Self::Zenoh {
+                    subscriber,
+                    _session: session,
+                    timeout,
+                })
+            }
+            #[cfg(feature = "mqtt")]
+            Protocol::Mqtt => {
+                let (client, connection) = create_mqtt_client("inbound", bind);
+                let (sender, messages) = std::sync::mpsc::channel();
+                let topic = format!("{}/#", options.topic_prefix);
+                spawn_mqtt_connection(connection, move |event| match event {
+                    // the broker discards the subscription when the connection is lost,
+                    // therefore it is renewed with every (re-)connect
+                    rumqttc::Event::Incoming(rumqttc::Packet::ConnAck(_)) => {
+                        if let Err(e) =
+                            client.try_subscribe(topic.as_str(), rumqttc::QoS::AtMostOnce)
+                        {
+                            eprintln!("Failed to subscribe to MQTT topic \"{}\": {}", topic, e);
+                        }
+                        CallbackProgression::Continue
+                    }
+                    rumqttc::Event::Incoming(rumqttc::Packet::Publish(publish)) => {
+                        match sender.send(publish.payload.to_vec()) {
+                            Ok(()) => CallbackProgression::Continue,
+                            Err(_) => CallbackProgression::Stop,
+                        }
+                    }
+                    _ => CallbackProgression::Continue,
+                })?;
+                Ok(Self::Mqtt {
+                    messages,
+                    encoding: options.encoding,
+                    timeout,
+                })
+            }
+        }
+    }
+
+    /// Waits at most for the timeout provided on construction and calls `on_frame` for
+    /// every received frame. Invalid frames are reported via `on_error` and skipped.
+    pub fn receive<F: FnMut(Frame), E: FnMut(anyhow::Error)>(
+        &mut self,
+        mut on_frame: F,
+        mut on_error: E,
+    ) -> Result<()> {
+        match self {
+            Self::Udp { socket, buffer } => {
+                let received_bytes = match socket.recv(buffer) {
+                    Ok(n) => n,
+                    Err(e)
+                        if matches!(
+                            e.kind(),
+                            ErrorKind::WouldBlock | ErrorKind::TimedOut | ErrorKind::Interrupted
+                        ) =>
+                    {
+                        return Ok(())
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+
+                match Self::decode_datagram(&buffer[..received_bytes]) {
+                    Ok(frame) => on_frame(frame),
+                    Err(e) => on_error(e),
+                }
+                Ok(())
+            }
+            Self::Tcp {
+                listener,
+                connections,
+                timeout,
+            } => {
+                loop {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            stream.set_nonblocking(true)?;
+                            connections.push(TcpConnection {
+                                stream,
+                                buffer: vec![],
+                            });
+                        }
+                        Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+
+                let mut received_data = false;
+                connections.retain_mut(|connection| {
+                    let (is_connected, has_data) = Self::read_available(connection);
+                    received_data |= has_data;
+
+                    loop {
+                        match Self::extract_frame(&mut connection.buffer) {
+                            Ok(Some(Ok(frame))) => on_frame(frame),
+                            Ok(Some(Err(e))) => on_error(e),
+                            Ok(None) => break,
+                            // the stream cannot be resynchronized after an invalid frame len
+                            Err(e) => {
+                                on_error(e.context("dropping connection"));
+                                return false;
+                            }
+                        }
+                    }
+
+                    is_connected
+                });
+
+                if !received_data {
+                    std::thread::sleep(*timeout);
+                }
+                Ok(())
+            }
+            #[cfg(feature = "zenoh")]
+            Self::Zenoh {
+                subscriber,
+                timeout,
+                ..
+            } => {
+                let sample = match subscriber
+                    .recv_timeout(*timeout)
+                    .map_err(|e| anyhow!("unable to receive from zenoh ({})", e))?
+                {
+                    Some(sample) => sample,
+                    None => return Ok(()),
+                };
+
+                match Self::decode_datagram(&sample.payload().to_bytes()) {
+                    Ok(frame) => on_frame(frame),
+                    Err(e) => on_error(e),
+                }
+                Ok(())
+            }
+            #[cfg(feature = "mqtt")]
+            Self::Mqtt {
+                messages,
+                encoding,
+                timeout,
+            } => {
+                let message = match messages.recv_timeout(*timeout) {
+                    Ok(message) => message,
+                    Err(std::sync::mpsc::RecvTimeoutError::Timeout) => return Ok(()),
+                    Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                        return Err(anyhow!("the MQTT connection was closed"))
+                    }
+                };
+
+                match Frame::from_mqtt_message(&message, *encoding) {
+                    Ok(frame) => on_frame(frame),
+                    Err(e) => on_error(e),
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn decode_datagram(datagram: &[u8]) -> Result<Frame> {
+        if datagram.len() < FRAME_LEN_SIZE {
+            return Err(anyhow!("datagram is too small to contain a frame"));
+        }
+
+        let (frame_len, frame) = datagram.split_at(FRAME_LEN_SIZE);
+        let frame_len = Frame::decode_frame_len(frame_len.try_into()?)?;
+        if frame_len != frame.len() {
+            return Err(anyhow!(
+                "datagram contains {} bytes but the frame requires {} bytes",
+                frame.len(),
+                frame_len
+            ));
+        }
+
+        Frame::decode(frame)
+    }
+
+    /// Reads the available data until [`MAX_TCP_BUFFER_SIZE`] is reached. Returns if the
+    /// connection is still established and if data was received.
+    fn read_available(connection: &mut TcpConnection) -> (bool, bool) {
+        let mut read_buffer = [0u8; TCP_READ_CHUNK_SIZE];
+        let mut has_data = false;
+        loop {
+            let capacity = MAX_TCP_BUFFER_SIZE.saturating_sub(connection.buffer.len());
+            if capacity == 0 {
+                return (true, has_data);
+            }
+
+            let chunk = &mut read_buffer[..capacity.min(TCP_READ_CHUNK_SIZE)];
+            match connection.stream.read(chunk) {
+                Ok(0) => return (false, has_data),
+                Ok(n) => {
+                    connection.buffer.extend_from_slice(&chunk[..n]);
+                    has_data = true;
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return (true, has_data),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => return (false, has_data),
+            }
+        }
+    }
+
+    /// Removes the next complete frame from the buffer and decodes it. Returns [`None`] when
+    /// the frame is incomplete and fails when the frame len exceeds [`MAX_FRAME_SIZE`].
+    fn extract_frame(buffer: &mut Vec<u8>) -> Result<Option<Result<Frame>>> {
+        if buffer.len() < FRAME_LEN_SIZE {
+            return Ok(None);
+        }
+
+        let mut frame_len = [0u8; FRAME_LEN_SIZE];
+        frame_len.copy_from_slice(&buffer[..FRAME_LEN_SIZE]);
+        let frame_end = Frame::decode_frame_len(frame_len)?
+            .checked_add(FRAME_LEN_SIZE)
+            .ok_or_else(|| anyhow!("frame len exceeds the address space"))?;
+        if buffer.len() < frame_end {
+            return Ok(None);
+        }
+
+        let frame = Frame::decode(&buffer[FRAME_LEN_SIZE..frame_end]);
+        buffer.drain(..frame_end);
+        Ok(Some(frame))
+    }
+}
+
+#[cfg(all(test, any(feature = "zenoh", feature = "mqtt")))]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+    #[cfg(feature = "zenoh")]
+    use zenoh::key_expr::KeyExpr;
+
+    #[cfg(feature = "zenoh")]
+    #[test]
+    fn zenoh_key_expr_keeps_plain_service_names() {
+        assert_that!(zenoh_key_expr("iox2", "My-Service_1.0"), eq "iox2/My-Service_1.0");
+    }
+
+    #[cfg(feature = "zenoh")]
+    #[test]
+    fn zenoh_key_expr_encodes_separators_and_wildcards() {
+        assert_that!(zenoh_key_expr("iox2", "a/b"), eq "iox2/a%2Fb");
+        assert_that!(zenoh_key_expr("iox2", "**"), eq "iox2/%2A%2A");
+        assert_that!(zenoh_key_expr("iox2", "$*?#"), eq "iox2/%24%2A%3F%23");
+        assert_that!(zenoh_key_expr("iox2", "a%b"), eq "iox2/a%25b");
+    }
+
+    #[cfg(feature = "zenoh")]
+    #[test]
+    fn zenoh_key_expr_of_arbitrary_service_name_is_a_valid_single_chunk() {
+        for service_name in ["a/b", "/a//b/", "**", "a/*/b", "$*", "?#", "a b", "ü"] {
+            let key_expr = zenoh_key_expr("iox2", service_name);
+            assert_that!(KeyExpr::try_from(key_expr.as_str()), is_ok);
+            assert_that!(key_expr.matches('/').count(), eq 1);
+        }
+    }
+
+    #[cfg(feature = "zenoh")]
+    #[test]
+    fn zenoh_key_exprs_of_different_service_names_differ() {
+        assert_that!(zenoh_key_expr("iox2", "a/b"), ne zenoh_key_expr("iox2", "a%2Fb"));
+        assert_that!(zenoh_key_expr("iox2", "a/b"), ne zenoh_key_expr("iox2", "a_b"));
+    }
+
+    #[cfg(feature = "mqtt")]
+    #[test]
+    fn mqtt_topic_keeps_plain_service_names() {
+        assert_that!(mqtt_topic("iox2", "My-Service_1.0"), eq "iox2/My-Service_1.0");
+    }
+
+    #[cfg(feature = "mqtt")]
+    #[test]
+    fn mqtt_topic_encodes_separators_and_wildcards() {
+        assert_that!(mqtt_topic("iox2", "a/b"), eq "iox2/a%2Fb");
+        assert_that!(mqtt_topic("iox2", "+"), eq "iox2/%2B");
+        assert_that!(mqtt_topic("iox2", "#"), eq "iox2/%23");
+        assert_that!(mqtt_topic("iox2", "a%b"), eq "iox2/a%25b");
+    }
+
+    #[cfg(feature = "mqtt")]
+    #[test]
+    fn mqtt_topic_of_arbitrary_service_name_is_a_single_level_without_wildcards() {
+        for service_name in ["a/b", "/a//b/", "+", "a/+/b", "#", "a/#", "a b", "ü"] {
+            let topic = mqtt_topic("iox2", service_name);
+            assert_that!(topic.contains(['+', '#']), eq false);
+            assert_that!(topic.matches('/').count(), eq 1);
+        }
+    }
+}
@@ -58,10 +58,18 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Args)]
+pub struct CleanupOptions {
+    #[clap(help = "Name, ID or PID of the dead node, all dead nodes when omitted")]
+    pub node: Option<NodeIdentifier>,
+}
+
 #[derive(Subcommand)]
 pub enum Action {
     #[clap(about = "List all nodes")]
     List(ListOptions),
     #[clap(about = "Show node details")]
     Details(DetailsOptions),
+    #[clap(about = "Remove the stale resources of dead nodes")]
+    Cleanup(CleanupOptions),
 }
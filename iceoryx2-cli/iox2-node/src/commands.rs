@@ -11,11 +11,14 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::{Context, Error, Result};
+use iceoryx2::node::NodeView;
 use iceoryx2::prelude::*;
 use iceoryx2_cli::filter::Filter;
 use iceoryx2_cli::filter::NodeIdentifier;
+use iceoryx2_cli::output::NodeCleanupReport;
 use iceoryx2_cli::output::NodeDescription;
 use iceoryx2_cli::output::NodeDescriptor;
+use iceoryx2_cli::output::NodeIdString;
 use iceoryx2_cli::output::NodeList;
 use iceoryx2_cli::Format;
 
@@ -60,5 +63,35 @@ pub fn details(identifier: NodeIdentifier, filter: OutputFilter, format: Format)
     })
     .context("failed to retrieve nodes")?;
 
+    if let Some(err) = error {
+        return Err(err);
+    }
+    Ok(())
+}
+
+pub fn cleanup(identifier: Option<NodeIdentifier>, format: Format) -> Result<()> {
+    let mut report = NodeCleanupReport::default();
+
+    Node::<ipc::Service>::list(Config::global_config(), |node| {
+        if identifier
+            .as_ref()
+            .map_or(true, |identifier| identifier.matches(&node))
+        {
+            if let NodeState::Dead(view) = node {
+                let id = NodeIdString::from(view.id());
+                match view.remove_stale_resources() {
+                    Ok(true) => report.removed.push(id),
+                    // another process is already cleaning up the node
+                    Ok(false) => report.skipped.push(id),
+                    Err(_) => report.failed.push(id),
+                }
+            }
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve nodes")?;
+
+    print!("{}", format.as_string(&report)?);
+
     Ok(())
 }
@@ -56,6 +56,11 @@ fn main() {
                             eprintln!("Failed to retrieve node details: {}", e);
                         }
                     }
+                    Action::Cleanup(options) => {
+                        if let Err(e) = commands::cleanup(options.node, cli.format) {
+                            eprintln!("Failed to clean up nodes: {}", e);
+                        }
+                    }
                 }
             } else {
                 Cli::command().print_help().expect("Failed to print help");
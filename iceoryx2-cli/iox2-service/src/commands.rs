@@ -33,7 +33,8 @@ pub fn list(filter: OutputFilter, format: Format) -> Result<()> {
     services.sort_by_key(|pattern| match pattern {
         ServiceDescriptor::PublishSubscribe(name) => (name.clone(), 0),
         ServiceDescriptor::Event(name) => (name.clone(), 1),
-        ServiceDescriptor::Undefined(name) => (name.to_string(), 2),
+        ServiceDescriptor::Blackboard(name) => (name.clone(), 2),
+        ServiceDescriptor::Undefined(name) => (name.to_string(), 3),
     });
 
     print!("{}", format.as_string(&services)?);
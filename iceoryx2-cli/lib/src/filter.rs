@@ -57,6 +57,7 @@ impl FromStr for NodeIdentifier {
 #[derive(Default)]
 pub enum StateFilter {
     Alive,
+    Unresponsive,
     Dead,
     Inaccessible,
     Undefined,
@@ -68,7 +69,7 @@ impl Filter<NodeState<Service>> for NodeIdentifier {
     fn matches(&self, node: &NodeState<Service>) -> bool {
         match self {
             NodeIdentifier::Name(ref name) => match node {
-                NodeState::Alive(view) => view
+                NodeState::Alive(view) | NodeState::Unresponsive(view) => view
                     .details()
                     .as_ref()
                     .map(|details| details.name().as_str() == name)
@@ -81,13 +82,17 @@ impl Filter<NodeState<Service>> for NodeIdentifier {
                 NodeState::Inaccessible(_) | NodeState::Undefined(_) => false,
             },
             NodeIdentifier::Id(ref id) => match node {
-                NodeState::Alive(view) => NodeIdString::from(view.id()) == **id,
+                NodeState::Alive(view) | NodeState::Unresponsive(view) => {
+                    NodeIdString::from(view.id()) == **id
+                }
                 NodeState::Dead(view) => NodeIdString::from(view.id()) == **id,
                 NodeState::Inaccessible(node_id) => NodeIdString::from(node_id) == **id,
                 NodeState::Undefined(node_id) => NodeIdString::from(node_id) == **id,
             },
             NodeIdentifier::Pid(pid) => match node {
-                NodeState::Alive(view) => view.id().pid().value() == *pid,
+                NodeState::Alive(view) | NodeState::Unresponsive(view) => {
+                    view.id().pid().value() == *pid
+                }
                 NodeState::Dead(view) => view.id().pid().value() == *pid,
                 NodeState::Inaccessible(node_id) => node_id.pid().value() == *pid,
                 NodeState::Undefined(node_id) => node_id.pid().value() == *pid,
@@ -101,6 +106,7 @@ impl Filter<NodeState<Service>> for StateFilter {
         matches!(
             (self, node),
             (StateFilter::Alive, NodeState::Alive(_))
+                | (StateFilter::Unresponsive, NodeState::Unresponsive(_))
                 | (StateFilter::Dead, NodeState::Dead(_))
                 | (StateFilter::Inaccessible, NodeState::Inaccessible(_))
                 | (StateFilter::Undefined, NodeState::Undefined(_))
@@ -115,6 +121,7 @@ impl Filter<NodeState<Service>> for StateFilter {
 pub enum MessagingPatternFilter {
     PublishSubscribe,
     Event,
+    Blackboard,
     #[default]
     All,
 }
@@ -127,6 +134,10 @@ impl Filter<ServiceDetails<Service>> for MessagingPatternFilter {
                 MessagingPatternFilter::PublishSubscribe,
                 MessagingPattern::PublishSubscribe(_)
             ) | (MessagingPatternFilter::Event, MessagingPattern::Event(_))
+                | (
+                    MessagingPatternFilter::Blackboard,
+                    MessagingPattern::Blackboard(_)
+                )
                 | (MessagingPatternFilter::All, _)
         )
     }
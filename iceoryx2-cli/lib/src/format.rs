@@ -13,6 +13,7 @@
 use anyhow::{anyhow, Context, Error, Result};
 use clap::ValueEnum;
 use serde::Serialize;
+use serde_json::Value;
 use std::str::FromStr;
 
 #[derive(Clone, Copy, ValueEnum)]
@@ -21,6 +22,7 @@ pub enum Format {
     Ron,
     Json,
     Yaml,
+    Table,
 }
 
 impl Format {
@@ -35,6 +37,131 @@ impl Format {
                 serde_json::to_string_pretty(data).context("failed to serialize to JSON format")
             }
             Format::Yaml => serde_yaml::to_string(data).context("failed to serialize to YAML"),
+            Format::Table => {
+                let value = serde_json::to_value(data).context("failed to serialize to table")?;
+                let mut output = String::new();
+                render_table(&value, &mut output);
+                Ok(output)
+            }
+        }
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+// scalars and lists of scalars fit into a single cell
+fn is_inline(value: &Value) -> bool {
+    match value {
+        Value::Array(items) => items.iter().all(is_scalar),
+        Value::Object(_) => false,
+        _ => true,
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if is_inline(value) => {
+            items.iter().map(cell).collect::<Vec<_>>().join(", ")
+        }
+        _ => value.to_string(),
+    }
+}
+
+// A row is a list of (column, cell) pairs. Objects with a single scalar entry are
+// externally tagged enums like `{"Event": "My/Service"}` and become a type and a value column.
+fn row(value: &Value) -> Vec<(String, String)> {
+    match value {
+        Value::Object(map) if map.len() == 1 && map.values().all(is_scalar) => {
+            let (key, value) = map.iter().next().expect("map has exactly one entry");
+            vec![
+                ("type".to_string(), key.clone()),
+                ("value".to_string(), cell(value)),
+            ]
+        }
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), cell(v))).collect(),
+        _ => vec![("value".to_string(), cell(value))],
+    }
+}
+
+fn write_rows(rows: &[Vec<(String, String)>], output: &mut String) {
+    let mut columns: Vec<String> = vec![];
+    for row in rows {
+        for (column, _) in row {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+            }
+        }
+    }
+
+    let lines: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| {
+                    row.iter()
+                        .find(|(c, _)| c == column)
+                        .map(|(_, v)| v.clone())
+                        .unwrap_or_else(|| "-".to_string())
+                })
+                .collect()
+        })
+        .collect();
+
+    let header: Vec<String> = columns.iter().map(|c| c.to_uppercase()).collect();
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(n, h)| {
+            lines
+                .iter()
+                .map(|line| line[n].chars().count())
+                .chain(std::iter::once(h.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for line in std::iter::once(&header).chain(lines.iter()) {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(widths.iter())
+            .map(|(c, w)| format!("{:<width$}", c, width = *w))
+            .collect();
+        output.push_str(cells.join("  ").trim_end());
+        output.push('\n');
+    }
+}
+
+fn render_table(value: &Value, output: &mut String) {
+    match value {
+        Value::Array(items) => {
+            if !items.is_empty() {
+                write_rows(&items.iter().map(row).collect::<Vec<_>>(), output);
+            }
+        }
+        Value::Object(map) => {
+            let (scalars, nested): (Vec<_>, Vec<_>) = map.iter().partition(|(_, v)| is_inline(v));
+
+            let width = scalars.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+            for (key, value) in scalars {
+                let line = format!("{:<width$}  {}", key, cell(value), width = width);
+                output.push_str(line.trim_end());
+                output.push('\n');
+            }
+
+            for (key, value) in nested {
+                output.push_str(&format!("\n{}:\n", key));
+                render_table(value, output);
+            }
+        }
+        _ => {
+            output.push_str(&cell(value));
+            output.push('\n');
         }
     }
 }
@@ -47,6 +174,7 @@ impl FromStr for Format {
             "RON" => Ok(Format::Ron),
             "JSON" => Ok(Format::Json),
             "YAML" => Ok(Format::Yaml),
+            "TABLE" => Ok(Format::Table),
             _ => Err(anyhow!("unsupported output format '{}'", s)),
         }
     }
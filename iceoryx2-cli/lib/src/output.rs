@@ -27,6 +27,7 @@ use iceoryx2_pal_posix::posix::pid_t;
 pub enum ServiceDescriptor {
     PublishSubscribe(String),
     Event(String),
+    Blackboard(String),
     Undefined(String),
 }
 
@@ -42,6 +43,9 @@ where
             IceoryxMessagingPattern::Event(_) => {
                 ServiceDescriptor::Event(service.static_details.name().to_string())
             }
+            IceoryxMessagingPattern::Blackboard(_) => {
+                ServiceDescriptor::Blackboard(service.static_details.name().to_string())
+            }
             _ => ServiceDescriptor::Undefined("Undefined".to_string()),
         }
     }
@@ -111,6 +115,7 @@ impl PartialEq<&str> for NodeIdString {
 #[derive(serde::Serialize)]
 pub enum NodeState {
     Alive,
+    Unresponsive,
     Dead,
     Inaccessible,
     Undefined,
@@ -144,6 +149,19 @@ where
                     .as_ref()
                     .map(|details| details.name().as_str().to_string()),
             },
+            IceoryxNodeState::Unresponsive(view) => NodeDescriptor {
+                state: NodeState::Unresponsive,
+                id: NodeIdString::from(view.id()),
+                pid: view.id().pid().value(),
+                executable: view
+                    .details()
+                    .as_ref()
+                    .map(|details| details.executable().to_string()),
+                name: view
+                    .details()
+                    .as_ref()
+                    .map(|details| details.name().as_str().to_string()),
+            },
             IceoryxNodeState::Dead(view) => NodeDescriptor {
                 state: NodeState::Dead,
                 id: NodeIdString::from(view.id()),
@@ -196,6 +214,12 @@ where
                 pid: view.id().pid().value(),
                 details: view.details().clone(),
             },
+            IceoryxNodeState::Unresponsive(view) => NodeDescription {
+                state: NodeState::Unresponsive,
+                id: NodeIdString::from(view.id()),
+                pid: view.id().pid().value(),
+                details: view.details().clone(),
+            },
             IceoryxNodeState::Dead(view) => NodeDescription {
                 state: NodeState::Dead,
                 id: NodeIdString::from(view.id()),
@@ -218,6 +242,13 @@ where
     }
 }
 
+#[derive(serde::Serialize, Default)]
+pub struct NodeCleanupReport {
+    pub removed: Vec<NodeIdString>,
+    pub skipped: Vec<NodeIdString>,
+    pub failed: Vec<NodeIdString>,
+}
+
 #[derive(serde::Serialize)]
 pub struct NodeList {
     pub num: usize,
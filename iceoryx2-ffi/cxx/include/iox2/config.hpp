@@ -46,6 +46,14 @@ class Node {
     auto service_tag_suffix() && -> const char*;
     /// Set the suffix of the service tags.
     void set_service_tag_suffix(const iox::FileName& value) &&;
+    /// The suffix of the heartbeat storage of a node with a liveness deadline.
+    auto heartbeat_suffix() && -> const char*;
+    /// Set the suffix of the heartbeat storage of a node with a liveness deadline.
+    void set_heartbeat_suffix(const iox::FileName& value) &&;
+    /// The suffix of the marker that identifies the instance that owns the prefix.
+    auto instance_suffix() && -> const char*;
+    /// Set the suffix of the marker that identifies the instance that owns the prefix.
+    void set_instance_suffix(const iox::FileName& value) &&;
     /// When true, the [`NodeBuilder`](NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](Node) is
     /// created.
@@ -98,6 +106,10 @@ class Service {
     auto event_connection_suffix() && -> const char*;
     /// Set the suffix of a one-to-one connection
     void set_event_connection_suffix(const iox::FileName& value) &&;
+    /// The suffix of the queue that transfers the payload of an event
+    auto event_payload_suffix() && -> const char*;
+    /// Set the suffix of the queue that transfers the payload of an event
+    void set_event_payload_suffix(const iox::FileName& value) &&;
 
   private:
     friend class Global;
@@ -84,6 +84,8 @@ constexpr auto from<int, iox2::NodeCreationFailure>(const int value) noexcept ->
         return iox2::NodeCreationFailure::InsufficientPermissions;
     case iox2_node_creation_failure_e_INTERNAL_ERROR:
         return iox2::NodeCreationFailure::InternalError;
+    case iox2_node_creation_failure_e_PREFIX_COLLISION:
+        return iox2::NodeCreationFailure::PrefixCollision;
     }
 
     IOX_UNREACHABLE();
@@ -138,6 +140,8 @@ constexpr auto from<int, iox2::NodeWaitFailure>(const int value) noexcept -> iox
         return iox2::NodeWaitFailure::TerminationRequest;
     case iox2_node_wait_failure_e_INTERRUPT:
         return iox2::NodeWaitFailure::Interrupt;
+    case iox2_node_wait_failure_e_INTERNAL_ERROR:
+        return iox2::NodeWaitFailure::InternalError;
     }
 
     IOX_UNREACHABLE();
@@ -209,6 +213,12 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_PAYLOAD_SIZE:
+        return iox2::EventOpenOrCreateError::OpenIncompatiblePayloadSize;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleDeadline;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_PLATFORM:
+        return iox2::EventOpenOrCreateError::OpenIncompatiblePlatform;
 
     case iox2_event_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::EventOpenOrCreateError::CreateServiceInCorruptedState;
@@ -259,6 +269,12 @@ constexpr auto from<int, iox2::EventOpenError>(const int value) noexcept -> iox2
         return iox2::EventOpenError::ExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenError::IsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_PAYLOAD_SIZE:
+        return iox2::EventOpenError::IncompatiblePayloadSize;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::EventOpenError::IncompatibleDeadline;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_PLATFORM:
+        return iox2::EventOpenError::IncompatiblePlatform;
     default:
         IOX_UNREACHABLE();
     }
@@ -326,6 +342,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PLATFORM:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePlatform;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
@@ -343,6 +361,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION:
         return iox2::PublishSubscribeOpenOrCreateError::CreateHangsInCreation;
+    case iox2_pub_sub_open_or_create_error_e_C_UNSUPPORTED_PAYLOAD_ALIGNMENT:
+        return iox2::PublishSubscribeOpenOrCreateError::CreateUnsupportedPayloadAlignment;
     }
 
     IOX_UNREACHABLE();
@@ -386,6 +406,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PLATFORM:
+        return iox2::PublishSubscribeOpenError::IncompatiblePlatform;
     default:
         IOX_UNREACHABLE();
     }
@@ -412,6 +434,8 @@ from<int, iox2::PublishSubscribeCreateError>(const int value) noexcept -> iox2::
         return iox2::PublishSubscribeCreateError::OldConnectionsStillActive;
     case iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION:
         return iox2::PublishSubscribeCreateError::HangsInCreation;
+    case iox2_pub_sub_open_or_create_error_e_C_UNSUPPORTED_PAYLOAD_ALIGNMENT:
+        return iox2::PublishSubscribeCreateError::UnsupportedPayloadAlignment;
     default:
         IOX_UNREACHABLE();
     }
@@ -447,6 +471,8 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
     switch (error) {
     case iox2_notifier_notify_error_e_EVENT_ID_OUT_OF_BOUNDS:
         return iox2::NotifierNotifyError::EventIdOutOfBounds;
+    case iox2_notifier_notify_error_e_INCOMPATIBLE_PAYLOAD_SIZE:
+        return iox2::NotifierNotifyError::IncompatiblePayloadSize;
     }
 
     IOX_UNREACHABLE();
@@ -488,6 +514,8 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService;
     case iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS:
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
+    case iox2_subscriber_create_error_e_UNABLE_TO_CREATE_BLOCKING_RECEIVE_EVENT:
+        return iox2::SubscriberCreateError::UnableToCreateBlockingReceiveEvent;
     }
 
     IOX_UNREACHABLE();
@@ -511,6 +539,8 @@ constexpr auto from<int, iox2::PublisherSendError>(const int value) noexcept ->
         return iox2::PublisherSendError::LoanErrorInternalFailure;
     case iox2_publisher_send_error_e_CONNECTION_ERROR:
         return iox2::PublisherSendError::ConnectionError;
+    case iox2_publisher_send_error_e_INCOMPATIBLE_RAW_SAMPLE_SIZE:
+        return iox2::PublisherSendError::IncompatibleRawSampleSize;
     }
 
     IOX_UNREACHABLE();
@@ -526,6 +556,10 @@ constexpr auto from<int, iox2::SubscriberReceiveError>(const int value) noexcept
         return iox2::SubscriberReceiveError::UnableToMapPublishersDataSegment;
     case iox2_subscriber_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES:
         return iox2::SubscriberReceiveError::ExceedsMaxBorrowedSamples;
+    case iox2_subscriber_receive_error_e_BLOCKING_RECEIVE_NOT_ENABLED:
+        return iox2::SubscriberReceiveError::BlockingReceiveNotEnabled;
+    case iox2_subscriber_receive_error_e_WAIT_FAILURE:
+        return iox2::SubscriberReceiveError::WaitFailure;
     }
 
     IOX_UNREACHABLE();
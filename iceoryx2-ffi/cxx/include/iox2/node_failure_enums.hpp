@@ -33,7 +33,11 @@ enum class NodeCreationFailure : uint8_t {
     InsufficientPermissions,
     /// Maybe the configuration/system is broken since someone has
     /// removed/modified internal resources
-    InternalError
+    InternalError,
+    /// Another iceoryx2 instance with a different root path uses the same
+    /// prefix and shares the resources without a path, like POSIX shared
+    /// memory, with this instance.
+    PrefixCollision
 };
 
 enum class NodeCleanupFailure : uint8_t {
@@ -87,11 +87,16 @@ class NodeState {
     explicit NodeState(const AliveNodeView<T>& view);
     explicit NodeState(const DeadNodeView<T>& view);
     NodeState(iox2_node_state_e node_state, const NodeId& node_id);
+    NodeState(iox2_node_state_e node_state, const AliveNodeView<T>& view);
 
     /// If the [`Node`] is alive the provided callback is called with an [`AliveNodeView`]
     /// as argument.
     auto alive(const iox::function<void(AliveNodeView<T>&)>& callback) -> NodeState&;
 
+    /// If the [`Node`]s process is alive but the [`Node`] missed its liveness deadline the
+    /// provided callback is called with an [`AliveNodeView`] as argument.
+    auto unresponsive(const iox::function<void(AliveNodeView<T>&)>& callback) -> NodeState&;
+
     /// If the [`Node`] is dead the provided callback is called with a [`DeadNodeView`] as
     /// argument.
     auto dead(const iox::function<void(DeadNodeView<T>&)>& callback) -> NodeState&;
@@ -105,7 +110,7 @@ class NodeState {
     auto undefined(const iox::function<void(NodeId&)>& callback) -> NodeState&;
 
   private:
-    iox::variant<AliveNodeView<T>, DeadNodeView<T>, NodeId, NodeId> m_state;
+    iox::variant<AliveNodeView<T>, DeadNodeView<T>, NodeId, NodeId, AliveNodeView<T>> m_state;
 };
 } // namespace iox2
 
@@ -22,6 +22,8 @@ enum class NodeWaitFailure : uint8_t {
     TerminationRequest,
     /// SIGINT signal was received
     Interrupt,
+    /// The resources that are required to wait could not be acquired
+    InternalError,
 };
 } // namespace iox2
 
@@ -34,6 +34,9 @@ enum class NotifierNotifyError : uint8_t {
     /// is greater than the maximum supported [`EventId`] by the
     /// [`Service`]
     EventIdOutOfBounds,
+    /// The size of the payload differs from the payload size of the
+    /// [`Service`]
+    IncompatiblePayloadSize,
 };
 } // namespace iox2
 
@@ -81,6 +81,9 @@ enum class PublisherSendError : uint8_t {
     /// A failure occurred while establishing a connection to a
     /// [`Subscriber`]
     ConnectionError,
+    /// The user header or payload bytes of a raw sample do not match the type details of the
+    /// [`Service`]
+    IncompatibleRawSampleSize,
 };
 } // namespace iox2
 
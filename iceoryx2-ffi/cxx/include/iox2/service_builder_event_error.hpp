@@ -59,6 +59,15 @@ enum class EventOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The notifications of the [`Service`] carry a payload of a different
+    /// size than requested.
+    IncompatiblePayloadSize,
+    /// The [`Service`] expects the notifications with a different deadline
+    /// than requested.
+    IncompatibleDeadline,
+    /// The [`Service`] was created on a platform with a different endianness,
+    /// pointer width or alignment of the primitive types.
+    IncompatiblePlatform,
 };
 
 /// Failures that can occur when a new [`MessagingPattern::Event`] [`Service`] shall be created.
@@ -132,6 +141,15 @@ enum class EventOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The notifications of the [`Service`] carry a payload of a different
+    /// size than requested.
+    OpenIncompatiblePayloadSize,
+    /// The [`Service`] expects the notifications with a different deadline
+    /// than requested.
+    OpenIncompatibleDeadline,
+    /// The [`Service`] was created on a platform with a different endianness,
+    /// pointer width or alignment of the primitive types.
+    OpenIncompatiblePlatform,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
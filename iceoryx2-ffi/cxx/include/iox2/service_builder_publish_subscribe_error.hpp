@@ -66,6 +66,9 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was created on a platform with a different endianness,
+    /// pointer width or alignment of the primitive types.
+    IncompatiblePlatform,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -98,6 +101,10 @@ enum class PublishSubscribeCreateError : uint8_t {
     /// initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The payload alignment is greater than the page size. The alignment of
+    /// the payload can then not be guaranteed in every process that maps the
+    /// data segment.
+    UnsupportedPayloadAlignment,
 };
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -151,6 +158,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] was created on a platform with a different endianness,
+    /// pointer width or alignment of the primitive types.
+    OpenIncompatiblePlatform,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -180,6 +190,10 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     CreateHangsInCreation,
+    /// The payload alignment is greater than the page size. The alignment of
+    /// the payload can then not be guaranteed in every process that maps the
+    /// data segment.
+    CreateUnsupportedPayloadAlignment,
 };
 } // namespace iox2
 
@@ -27,7 +27,14 @@ enum class SubscriberReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapPublishersDataSegment
+    UnableToMapPublishersDataSegment,
+
+    /// A blocking receive was requested from a [`Subscriber`] that was created
+    /// without blocking receive support
+    BlockingReceiveNotEnabled,
+
+    /// The blocking receive was unable to wait for the delivery of a sample
+    WaitFailure
 };
 
 /// Describes the failures when a new [`Subscriber`] is created via the
@@ -42,6 +49,9 @@ enum class SubscriberCreateError : uint8_t {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`] offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+
+    /// The event that is required for a blocking receive could not be created.
+    UnableToCreateBlockingReceiveEvent,
 };
 } // namespace iox2
 
@@ -364,6 +364,14 @@ auto Service::event_connection_suffix() && -> const char* {
 void Service::set_event_connection_suffix(const iox::FileName& value) && {
     iox2_config_global_service_set_event_connection_suffix(m_config, value.as_string().c_str());
 }
+
+auto Service::event_payload_suffix() && -> const char* {
+    return iox2_config_global_service_event_payload_suffix(m_config);
+}
+
+void Service::set_event_payload_suffix(const iox::FileName& value) && {
+    iox2_config_global_service_set_event_payload_suffix(m_config, value.as_string().c_str());
+}
 /////////////////////////
 // END: Service
 /////////////////////////
@@ -407,6 +415,22 @@ void Node::set_service_tag_suffix(const iox::FileName& value) && {
     iox2_config_global_node_set_service_tag_suffix(m_config, value.as_string().c_str());
 }
 
+auto Node::heartbeat_suffix() && -> const char* {
+    return iox2_config_global_node_heartbeat_suffix(m_config);
+}
+
+void Node::set_heartbeat_suffix(const iox::FileName& value) && {
+    iox2_config_global_node_set_heartbeat_suffix(m_config, value.as_string().c_str());
+}
+
+auto Node::instance_suffix() && -> const char* {
+    return iox2_config_global_node_instance_suffix(m_config);
+}
+
+void Node::set_instance_suffix(const iox::FileName& value) && {
+    iox2_config_global_node_set_instance_suffix(m_config, value.as_string().c_str());
+}
+
 auto Node::cleanup_dead_nodes_on_creation() && -> bool {
     return iox2_config_global_node_cleanup_dead_nodes_on_creation(m_config);
 }
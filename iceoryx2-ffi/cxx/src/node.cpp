@@ -88,6 +88,8 @@ auto list_callback(iox2_node_state_e node_state,
         switch (node_state) {
         case iox2_node_state_e_ALIVE:
             return NodeState<T> { AliveNodeView<T> { NodeId {}, node_details } };
+        case iox2_node_state_e_UNRESPONSIVE:
+            return NodeState<T> { iox2_node_state_e_UNRESPONSIVE, AliveNodeView<T> { NodeId {}, node_details } };
         case iox2_node_state_e_DEAD:
             return NodeState<T> { DeadNodeView<T> { AliveNodeView<T> { NodeId {}, node_details } } };
         case iox2_node_state_e_UNDEFINED:
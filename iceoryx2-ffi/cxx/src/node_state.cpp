@@ -18,6 +18,7 @@ constexpr uint64_t ALIVE_STATE = 0;
 constexpr uint64_t DEAD_STATE = 1;
 constexpr uint64_t INACCESSIBLE_STATE = 2;
 constexpr uint64_t UNDEFINED_STATE = 3;
+constexpr uint64_t UNRESPONSIVE_STATE = 4;
 
 template <ServiceType T>
 AliveNodeView<T>::AliveNodeView(const NodeId& node_id, const iox::optional<NodeDetails>& details)
@@ -79,6 +80,17 @@ NodeState<T>::NodeState(iox2_node_state_e node_state, const NodeId& node_id) {
     }
 }
 
+template <ServiceType T>
+NodeState<T>::NodeState(iox2_node_state_e node_state, const AliveNodeView<T>& view) {
+    switch (node_state) {
+    case iox2_node_state_e_UNRESPONSIVE:
+        m_state.template emplace_at_index<UNRESPONSIVE_STATE>(view);
+        break;
+    default:
+        IOX_UNREACHABLE();
+    }
+}
+
 template <ServiceType T>
 auto NodeState<T>::alive(const iox::function<void(AliveNodeView<T>&)>& callback) -> NodeState& {
     if (m_state.index() == ALIVE_STATE) {
@@ -88,6 +100,15 @@ auto NodeState<T>::alive(const iox::function<void(AliveNodeView<T>&)>& callback)
     return *this;
 }
 
+template <ServiceType T>
+auto NodeState<T>::unresponsive(const iox::function<void(AliveNodeView<T>&)>& callback) -> NodeState& {
+    if (m_state.index() == UNRESPONSIVE_STATE) {
+        callback(*m_state.template get_at_index<UNRESPONSIVE_STATE>());
+    }
+
+    return *this;
+}
+
 template <ServiceType T>
 auto NodeState<T>::dead(const iox::function<void(DeadNodeView<T>&)>& callback) -> NodeState& {
     if (m_state.index() == DEAD_STATE) {
@@ -204,6 +204,14 @@ TEST(Config, global_service_event_connection_suffix) {
     ASSERT_THAT(config.global().service().event_connection_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_service_event_payload_suffix) {
+    const auto test_value = iox::FileName::create("a_pigeon_carries_the_note").expect("");
+    auto config = Config();
+
+    config.global().service().set_event_payload_suffix(test_value);
+    ASSERT_THAT(config.global().service().event_payload_suffix(), StrEq(test_value.as_string().c_str()));
+}
+
 TEST(Config, global_node_directory) {
     const auto test_value = iox::Path::create("eat/the/carrototier").expect("");
     auto config = Config();
@@ -236,6 +244,22 @@ TEST(Config, global_node_service_tag_suffix) {
     ASSERT_THAT(config.global().node().service_tag_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_node_heartbeat_suffix) {
+    const auto test_value = iox::FileName::create("who_is_hypnotoad").expect("");
+    auto config = Config();
+
+    config.global().node().set_heartbeat_suffix(test_value);
+    ASSERT_THAT(config.global().node().heartbeat_suffix(), StrEq(test_value.as_string().c_str()));
+}
+
+TEST(Config, global_node_instance_suffix) {
+    const auto test_value = iox::FileName::create("all_glory_to_hypnotoad").expect("");
+    auto config = Config();
+
+    config.global().node().set_instance_suffix(test_value);
+    ASSERT_THAT(config.global().node().instance_suffix(), StrEq(test_value.as_string().c_str()));
+}
+
 TEST(Config, global_node_cleanup_dead_nodes_on_creation) {
     auto config = Config();
 
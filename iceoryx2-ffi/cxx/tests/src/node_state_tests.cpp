@@ -36,6 +36,29 @@ TYPED_TEST(NodeStateTest, alive_node_works) {
     iox::optional<NodeName> test_name;
     bool entered_wrong_callback = false;
     sut.alive([&](auto& view) { test_name = view.details()->name(); });
+    sut.unresponsive([&](auto& view) { entered_wrong_callback = true; });
+    sut.dead([&](auto& view) { entered_wrong_callback = true; });
+    sut.undefined([&](auto& view) { entered_wrong_callback = true; });
+    sut.inaccessible([&](auto& view) { entered_wrong_callback = true; });
+
+    ASSERT_FALSE(entered_wrong_callback);
+    ASSERT_TRUE(test_name.has_value());
+    ASSERT_THAT(test_name->to_string().c_str(), StrEq(valid_name));
+}
+
+TYPED_TEST(NodeStateTest, unresponsive_node_works) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    const auto* valid_name = "The hypnotoad fell asleep while staring at the moon.";
+    auto node_name = NodeName::create(valid_name).expect("");
+    auto sut = NodeState<SERVICE_TYPE>(
+        iox2_node_state_e_UNRESPONSIVE,
+        AliveNodeView<SERVICE_TYPE>(NodeId {}, NodeDetails { node_name, Config::global_config().to_owned() }));
+
+    iox::optional<NodeName> test_name;
+    bool entered_wrong_callback = false;
+    sut.alive([&](auto& view) { entered_wrong_callback = true; });
+    sut.unresponsive([&](auto& view) { test_name = view.details()->name(); });
     sut.dead([&](auto& view) { entered_wrong_callback = true; });
     sut.undefined([&](auto& view) { entered_wrong_callback = true; });
     sut.inaccessible([&](auto& view) { entered_wrong_callback = true; });
@@ -56,6 +79,7 @@ TYPED_TEST(NodeStateTest, dead_node_works) {
     iox::optional<NodeName> test_name;
     bool entered_wrong_callback = false;
     sut.alive([&](auto& view) { entered_wrong_callback = true; });
+    sut.unresponsive([&](auto& view) { entered_wrong_callback = true; });
     sut.dead([&](auto& view) { test_name = view.details()->name(); });
     sut.undefined([&](auto& view) { entered_wrong_callback = true; });
     sut.inaccessible([&](auto& view) { entered_wrong_callback = true; });
@@ -73,6 +97,7 @@ TYPED_TEST(NodeStateTest, inaccessible_node_works) {
     bool entered_right_callback = false;
     bool entered_wrong_callback = false;
     sut.alive([&](auto& view) { entered_wrong_callback = true; });
+    sut.unresponsive([&](auto& view) { entered_wrong_callback = true; });
     sut.dead([&](auto& view) { entered_wrong_callback = true; });
     sut.undefined([&](auto& view) { entered_wrong_callback = true; });
     sut.inaccessible([&](auto& view) { entered_right_callback = true; });
@@ -89,6 +114,7 @@ TYPED_TEST(NodeStateTest, undefined_node_works) {
     bool entered_right_callback = false;
     bool entered_wrong_callback = false;
     sut.alive([&](auto& view) { entered_wrong_callback = true; });
+    sut.unresponsive([&](auto& view) { entered_wrong_callback = true; });
     sut.dead([&](auto& view) { entered_wrong_callback = true; });
     sut.undefined([&](auto& view) { entered_right_callback = true; });
     sut.inaccessible([&](auto& view) { entered_wrong_callback = true; });
@@ -74,7 +74,7 @@ pub(super) struct ConfigOwner {
 #[repr(C)]
 #[repr(align(8))] // align_of<ConfigOwner>()
 pub struct iox2_config_storage_t {
-    internal: [u8; 3560], // size_of<ConfigOwner>()
+    internal: [u8; 3824], // size_of<ConfigOwner>()
 }
 
 /// Contains the iceoryx2 config
@@ -587,6 +587,102 @@ pub unsafe extern "C" fn iox2_config_global_node_set_service_tag_suffix(
     }
 }
 
+/// Returns the suffix of the node heartbeat storage.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_heartbeat_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .node
+        .heartbeat_suffix
+        .as_c_str()
+}
+
+/// Sets the suffix of the node heartbeat storage.
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_set_heartbeat_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    match FileName::from_c_str(value) {
+        Ok(n) => {
+            config.value.as_mut().value.global.node.heartbeat_suffix = n;
+            IOX2_OK as _
+        }
+        Err(e) => e as c_int,
+    }
+}
+
+/// Returns the suffix of the marker that identifies the instance that owns the prefix.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_instance_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .node
+        .instance_suffix
+        .as_c_str()
+}
+
+/// Sets the suffix of the marker that identifies the instance that owns the prefix.
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_node_set_instance_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    match FileName::from_c_str(value) {
+        Ok(n) => {
+            config.value.as_mut().value.global.node.instance_suffix = n;
+            IOX2_OK as _
+        }
+        Err(e) => e as c_int,
+    }
+}
+
 /// When true, [`iox2_node_builder_create()`](crate::api::iox2_node_builder_create) checks for dead
 /// nodes and cleans up all their stale resources whenever a new
 /// [`iox2_node_h`](crate::api::iox2_node_h) is created.
@@ -1043,6 +1139,60 @@ pub unsafe extern "C" fn iox2_config_global_service_set_event_connection_suffix(
         Err(e) => e as c_int,
     }
 }
+
+/// Returns the suffix of the queue that transfers the payload of an event
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_event_payload_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .event_payload_suffix
+        .as_c_str()
+}
+
+/// Sets the suffix of the queue that transfers the payload of an event
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_event_payload_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    match FileName::from_c_str(value) {
+        Ok(n) => {
+            config
+                .value
+                .as_mut()
+                .value
+                .global
+                .service
+                .event_payload_suffix = n;
+            IOX2_OK as _
+        }
+        Err(e) => e as c_int,
+    }
+}
 /////////////////
 // END: service
 /////////////////
@@ -54,6 +54,7 @@ impl IntoCInt for NodeListFailure {
 pub enum iox2_node_wait_failure_e {
     INTERRUPT = IOX2_OK as isize + 1,
     TERMINATION_REQUEST,
+    INTERNAL_ERROR,
 }
 
 impl IntoCInt for NodeWaitFailure {
@@ -61,6 +62,7 @@ impl IntoCInt for NodeWaitFailure {
         (match self {
             NodeWaitFailure::TerminationRequest => iox2_node_wait_failure_e::TERMINATION_REQUEST,
             NodeWaitFailure::Interrupt => iox2_node_wait_failure_e::INTERRUPT,
+            NodeWaitFailure::InternalError => iox2_node_wait_failure_e::INTERNAL_ERROR,
         }) as c_int
     }
 }
@@ -86,7 +88,7 @@ impl NodeUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<NodeUnion>
 pub struct iox2_node_storage_t {
-    internal: [u8; 16], // magic number obtained with size_of::<Option<NodeUnion>>()
+    internal: [u8; 48], // magic number obtained with size_of::<Option<NodeUnion>>()
 }
 
 #[repr(C)]
@@ -154,6 +156,7 @@ pub enum iox2_node_state_e {
     DEAD,
     INACCESSIBLE,
     UNDEFINED,
+    UNRESPONSIVE,
 }
 
 // NOTE check the README.md for using opaque types with renaming
@@ -280,6 +283,21 @@ fn iox2_node_list_impl<S: Service>(
             )
             .into()
         }
+        NodeState::Unresponsive(unresponsive_node_view) => {
+            let (node_name, config) = unresponsive_node_view
+                .details()
+                .as_ref()
+                .map(|view| (view.name() as _, view.config() as _))
+                .unwrap_or((std::ptr::null(), std::ptr::null()));
+            callback(
+                iox2_node_state_e::UNRESPONSIVE,
+                unresponsive_node_view.id(),
+                node_name,
+                config,
+                callback_ctx,
+            )
+            .into()
+        }
         NodeState::Dead(dead_node_view) => {
             let (node_name, config) = dead_node_view
                 .details()
@@ -32,6 +32,7 @@ use core::ffi::c_int;
 pub enum iox2_node_creation_failure_e {
     INSUFFICIENT_PERMISSIONS = IOX2_OK as isize + 1,
     INTERNAL_ERROR,
+    PREFIX_COLLISION,
 }
 
 impl IntoCInt for NodeCreationFailure {
@@ -41,6 +42,7 @@ impl IntoCInt for NodeCreationFailure {
                 iox2_node_creation_failure_e::INSUFFICIENT_PERMISSIONS
             }
             NodeCreationFailure::InternalError => iox2_node_creation_failure_e::INTERNAL_ERROR,
+            NodeCreationFailure::PrefixCollision => iox2_node_creation_failure_e::PREFIX_COLLISION,
         }) as c_int
     }
 }
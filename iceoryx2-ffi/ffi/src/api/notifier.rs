@@ -31,6 +31,7 @@ use core::mem::ManuallyDrop;
 #[derive(Copy, Clone)]
 pub enum iox2_notifier_notify_error_e {
     EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
+    INCOMPATIBLE_PAYLOAD_SIZE,
 }
 
 impl IntoCInt for NotifierNotifyError {
@@ -39,6 +40,9 @@ impl IntoCInt for NotifierNotifyError {
             NotifierNotifyError::EventIdOutOfBounds => {
                 iox2_notifier_notify_error_e::EVENT_ID_OUT_OF_BOUNDS
             }
+            NotifierNotifyError::IncompatiblePayloadSize => {
+                iox2_notifier_notify_error_e::INCOMPATIBLE_PAYLOAD_SIZE
+            }
         }) as c_int
     }
 }
@@ -51,7 +51,7 @@ impl PortFactoryEventUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<PortFactoryEventUnion>
 pub struct iox2_port_factory_event_storage_t {
-    internal: [u8; 1656], // magic number obtained with size_of::<Option<PortFactoryEventUnion>>()
+    internal: [u8; 1704], // magic number obtained with size_of::<Option<PortFactoryEventUnion>>()
 }
 
 #[repr(C)]
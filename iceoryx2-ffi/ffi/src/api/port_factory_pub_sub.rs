@@ -53,7 +53,7 @@ impl PortFactoryPubSubUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<PortFactoryPubSubUnion>
 pub struct iox2_port_factory_pub_sub_storage_t {
-    internal: [u8; 1656], // magic number obtained with size_of::<Option<PortFactoryPubSubUnion>>()
+    internal: [u8; 1704], // magic number obtained with size_of::<Option<PortFactoryPubSubUnion>>()
 }
 
 #[repr(C)]
@@ -33,6 +33,7 @@ use core::mem::ManuallyDrop;
 pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
+    UNABLE_TO_CREATE_BLOCKING_RECEIVE_EVENT,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -44,6 +45,9 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService => {
                 iox2_subscriber_create_error_e::BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE
             }
+            SubscriberCreateError::UnableToCreateBlockingReceiveEvent => {
+                iox2_subscriber_create_error_e::UNABLE_TO_CREATE_BLOCKING_RECEIVE_EVENT
+            }
         }) as c_int
     }
 }
@@ -27,9 +27,19 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 32], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 72], // core::mem::size_of::<Option<Header>>()
 }
 
+// fails the build when the header outgrows the storage, e.g. when a field is added
+const _: () = assert!(
+    core::mem::size_of::<Option<Header>>()
+        <= core::mem::size_of::<iox2_publish_subscribe_header_storage_t>()
+);
+const _: () = assert!(
+    core::mem::align_of::<Option<Header>>()
+        <= core::mem::align_of::<iox2_publish_subscribe_header_storage_t>()
+);
+
 #[repr(C)]
 #[iceoryx2_ffi(Header)]
 pub struct iox2_publish_subscribe_header_t {
@@ -41,6 +41,7 @@ pub enum iox2_publisher_send_error_e {
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
+    INCOMPATIBLE_RAW_SAMPLE_SIZE,
 }
 
 impl IntoCInt for PublisherSendError {
@@ -65,6 +66,9 @@ impl IntoCInt for PublisherSendError {
                 iox2_publisher_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             PublisherSendError::ConnectionError(_) => iox2_publisher_send_error_e::CONNECTION_ERROR,
+            PublisherSendError::IncompatibleRawSampleSize => {
+                iox2_publisher_send_error_e::INCOMPATIBLE_RAW_SAMPLE_SIZE
+            }
         }) as c_int
     }
 }
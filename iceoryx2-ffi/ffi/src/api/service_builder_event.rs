@@ -45,6 +45,9 @@ pub enum iox2_event_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     O_IS_MARKED_FOR_DESTRUCTION,
+    O_INCOMPATIBLE_PAYLOAD_SIZE,
+    O_INCOMPATIBLE_DEADLINE,
+    O_INCOMPATIBLE_PLATFORM,
     C_SERVICE_IN_CORRUPTED_STATE,
     C_INTERNAL_FAILURE,
     C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
@@ -94,6 +97,15 @@ impl IntoCInt for EventOpenError {
             EventOpenError::IsMarkedForDestruction => {
                 iox2_event_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
             }
+            EventOpenError::IncompatiblePayloadSize => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_PAYLOAD_SIZE
+            }
+            EventOpenError::IncompatibleDeadline => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
+            }
+            EventOpenError::IncompatiblePlatform => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_PLATFORM
+            }
         }) as c_int
     }
 }
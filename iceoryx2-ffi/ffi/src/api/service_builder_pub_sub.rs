@@ -55,6 +55,7 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_HANGS_IN_CREATION,
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     O_IS_MARKED_FOR_DESTRUCTION,
+    O_INCOMPATIBLE_PLATFORM,
     C_SERVICE_IN_CORRUPTED_STATE,
     C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
     C_ALREADY_EXISTS,
@@ -63,6 +64,7 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
     C_OLD_CONNECTION_STILL_ACTIVE,
     C_HANGS_IN_CREATION,
+    C_UNSUPPORTED_PAYLOAD_ALIGNMENT,
 }
 
 impl IntoCInt for PublishSubscribeOpenError {
@@ -117,6 +119,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::IncompatiblePlatform => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_PLATFORM
+         }
         }) as c_int
     }
 }
@@ -143,6 +148,9 @@ impl IntoCInt for PublishSubscribeCreateError {
          PublishSubscribeCreateError::HangsInCreation => {
              iox2_pub_sub_open_or_create_error_e::C_HANGS_IN_CREATION
          }
+            PublishSubscribeCreateError::UnsupportedPayloadAlignment => {
+                iox2_pub_sub_open_or_create_error_e::C_UNSUPPORTED_PAYLOAD_ALIGNMENT
+            }
         }) as c_int
     }
 }
@@ -249,6 +257,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_user_header_type_detai
         type_name,
         size,
         alignment,
+        layout_hash: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -326,6 +335,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_details(
         type_name,
         size,
         alignment,
+        layout_hash: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
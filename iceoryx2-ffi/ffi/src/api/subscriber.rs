@@ -35,6 +35,8 @@ pub enum iox2_subscriber_receive_error_e {
     EXCEEDS_MAX_BORROWED_SAMPLES = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+    BLOCKING_RECEIVE_NOT_ENABLED,
+    WAIT_FAILURE,
 }
 
 impl IntoCInt for SubscriberReceiveError {
@@ -49,6 +51,10 @@ impl IntoCInt for SubscriberReceiveError {
             SubscriberReceiveError::ConnectionFailure(
                 ConnectionFailure::UnableToMapPublishersDataSegment(_),
             ) => iox2_subscriber_receive_error_e::UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+            SubscriberReceiveError::BlockingReceiveNotEnabled => {
+                iox2_subscriber_receive_error_e::BLOCKING_RECEIVE_NOT_ENABLED
+            }
+            SubscriberReceiveError::WaitFailure => iox2_subscriber_receive_error_e::WAIT_FAILURE,
         }) as c_int
     }
 }
@@ -96,7 +102,7 @@ impl SubscriberUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<SubscriberUnion>
 pub struct iox2_subscriber_storage_t {
-    internal: [u8; 816], // magic number obtained with size_of::<Option<SubscriberUnion>>()
+    internal: [u8; 864], // magic number obtained with size_of::<Option<SubscriberUnion>>()
 }
 
 #[repr(C)]
@@ -67,6 +67,7 @@ mod node {
     #[derive(Default)]
     struct NodeListCtx {
         alive: u64,
+        unresponsive: u64,
         dead: u64,
         inaccessible: u64,
         undefined: u64,
@@ -85,6 +86,9 @@ mod node {
             iox2_node_state_e::ALIVE => {
                 ctx.alive += 1;
             }
+            iox2_node_state_e::UNRESPONSIVE => {
+                ctx.unresponsive += 1;
+            }
             iox2_node_state_e::DEAD => {
                 ctx.dead += 1;
             }
@@ -118,6 +122,7 @@ mod node {
             assert_that!(ret_val, eq(IOX2_OK));
 
             assert_that!(ctx.alive, eq(1));
+            assert_that!(ctx.unresponsive, eq(0));
             assert_that!(ctx.dead, eq(0));
             assert_that!(ctx.inaccessible, eq(0));
             assert_that!(ctx.undefined, eq(0));
@@ -0,0 +1,42 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::PyErr;
+
+create_exception!(
+    _iceoryx2,
+    Iceoryx2Error,
+    PyException,
+    "Raised when an iceoryx2 operation fails, the message contains the underlying failure."
+);
+
+pub(crate) fn to_py_err<E: Debug>(msg: &str, error: E) -> PyErr {
+    Iceoryx2Error::new_err(format!("{} ({:?}).", msg, error))
+}
+
+pub(crate) fn consumed_builder_err() -> PyErr {
+    Iceoryx2Error::new_err("The builder was already consumed by a previous call.")
+}
+
+pub(crate) fn to_duration(seconds: f64) -> Result<Duration, PyErr> {
+    Duration::try_from_secs_f64(seconds).map_err(|e| {
+        PyValueError::new_err(format!(
+            "{} is not a valid duration in seconds ({}).",
+            seconds, e
+        ))
+    })
+}
@@ -0,0 +1,297 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+
+use iceoryx2::port::{listener, notifier};
+use iceoryx2::prelude::{ipc, local, EventId, PortFactory};
+use iceoryx2::service::builder::event::Builder;
+use iceoryx2::service::port_factory::event;
+use pyo3::prelude::*;
+
+use crate::error::{consumed_builder_err, to_duration, to_py_err};
+
+pub(crate) enum EventBuilderType {
+    Ipc(Builder<ipc::Service>),
+    Local(Builder<local::Service>),
+}
+
+macro_rules! update_builder {
+    ($slf:ident, $builder:ident => $update:expr) => {{
+        $slf.0 = Some(match $slf.0.take().ok_or_else(consumed_builder_err)? {
+            EventBuilderType::Ipc($builder) => EventBuilderType::Ipc($update),
+            EventBuilderType::Local($builder) => EventBuilderType::Local($update),
+        });
+        Ok($slf)
+    }};
+}
+
+/// Creates or opens an event service. The settings are only applied when the service is
+/// created.
+#[pyclass(unsendable)]
+pub struct EventServiceBuilder(Option<EventBuilderType>);
+
+impl EventServiceBuilder {
+    pub(crate) fn new(builder: EventBuilderType) -> Self {
+        Self(Some(builder))
+    }
+}
+
+#[pymethods]
+impl EventServiceBuilder {
+    /// Defines the maximum number of notifiers.
+    fn max_notifiers(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.max_notifiers(value))
+    }
+
+    /// Defines the maximum number of listeners.
+    fn max_listeners(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.max_listeners(value))
+    }
+
+    /// Defines how many nodes can open the service in parallel. Opening the service fails
+    /// when the limit is reached.
+    fn max_nodes(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.max_nodes(value))
+    }
+
+    /// Defines the largest event id that can be used to notify a listener.
+    fn event_id_max_value(
+        mut slf: PyRefMut<'_, Self>,
+        value: usize,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.event_id_max_value(value))
+    }
+
+    /// Opens the service or creates it when it does not exist.
+    fn open_or_create(&mut self) -> PyResult<PortFactoryEvent> {
+        let msg = "Unable to open or create event service";
+        Ok(PortFactoryEvent(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                EventBuilderType::Ipc(builder) => {
+                    EventType::Ipc(builder.open_or_create().map_err(|e| to_py_err(msg, e))?)
+                }
+                EventBuilderType::Local(builder) => {
+                    EventType::Local(builder.open_or_create().map_err(|e| to_py_err(msg, e))?)
+                }
+            },
+        ))
+    }
+
+    /// Opens an existing service.
+    fn open(&mut self) -> PyResult<PortFactoryEvent> {
+        let msg = "Unable to open event service";
+        Ok(PortFactoryEvent(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                EventBuilderType::Ipc(builder) => {
+                    EventType::Ipc(builder.open().map_err(|e| to_py_err(msg, e))?)
+                }
+                EventBuilderType::Local(builder) => {
+                    EventType::Local(builder.open().map_err(|e| to_py_err(msg, e))?)
+                }
+            },
+        ))
+    }
+
+    /// Creates a new service.
+    fn create(&mut self) -> PyResult<PortFactoryEvent> {
+        let msg = "Unable to create event service";
+        Ok(PortFactoryEvent(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                EventBuilderType::Ipc(builder) => {
+                    EventType::Ipc(builder.create().map_err(|e| to_py_err(msg, e))?)
+                }
+                EventBuilderType::Local(builder) => {
+                    EventType::Local(builder.create().map_err(|e| to_py_err(msg, e))?)
+                }
+            },
+        ))
+    }
+}
+
+enum EventType {
+    Ipc(event::PortFactory<ipc::Service>),
+    Local(event::PortFactory<local::Service>),
+}
+
+/// Represents an event service and creates its notifiers and listeners.
+#[pyclass(unsendable)]
+pub struct PortFactoryEvent(EventType);
+
+#[pymethods]
+impl PortFactoryEvent {
+    /// Returns the name of the service.
+    #[getter]
+    fn name(&self) -> String {
+        match &self.0 {
+            EventType::Ipc(factory) => factory.name().as_str().to_string(),
+            EventType::Local(factory) => factory.name().as_str().to_string(),
+        }
+    }
+
+    /// Returns a builder to create a `Notifier`.
+    fn notifier_builder(slf: Py<Self>) -> PortFactoryNotifier {
+        PortFactoryNotifier {
+            factory: slf,
+            default_event_id: None,
+        }
+    }
+
+    /// Returns a builder to create a `Listener`.
+    fn listener_builder(slf: Py<Self>) -> PortFactoryListener {
+        PortFactoryListener { factory: slf }
+    }
+}
+
+/// Creates a `Notifier` of an event service.
+#[pyclass]
+pub struct PortFactoryNotifier {
+    factory: Py<PortFactoryEvent>,
+    default_event_id: Option<usize>,
+}
+
+#[pymethods]
+impl PortFactoryNotifier {
+    /// Defines the event id that is used when `Notifier.notify()` is called without one.
+    fn default_event_id(mut slf: PyRefMut<'_, Self>, value: usize) -> PyRefMut<'_, Self> {
+        slf.default_event_id = Some(value);
+        slf
+    }
+
+    /// Creates the `Notifier`.
+    fn create(&self, py: Python<'_>) -> PyResult<Notifier> {
+        macro_rules! create {
+            ($factory:expr) => {{
+                let mut builder = $factory.notifier_builder();
+                if let Some(value) = self.default_event_id {
+                    builder = builder.default_event_id(EventId::new(value));
+                }
+                builder
+                    .create()
+                    .map_err(|e| to_py_err("Unable to create notifier", e))?
+            }};
+        }
+
+        Ok(Notifier(match &self.factory.borrow(py).0 {
+            EventType::Ipc(factory) => NotifierType::Ipc(create!(factory)),
+            EventType::Local(factory) => NotifierType::Local(create!(factory)),
+        }))
+    }
+}
+
+/// Creates a `Listener` of an event service.
+#[pyclass]
+pub struct PortFactoryListener {
+    factory: Py<PortFactoryEvent>,
+}
+
+#[pymethods]
+impl PortFactoryListener {
+    /// Creates the `Listener`.
+    fn create(&self, py: Python<'_>) -> PyResult<Listener> {
+        let msg = "Unable to create listener";
+        Ok(Listener(match &self.factory.borrow(py).0 {
+            EventType::Ipc(factory) => ListenerType::Ipc(
+                factory
+                    .listener_builder()
+                    .create()
+                    .map_err(|e| to_py_err(msg, e))?,
+            ),
+            EventType::Local(factory) => ListenerType::Local(
+                factory
+                    .listener_builder()
+                    .create()
+                    .map_err(|e| to_py_err(msg, e))?,
+            ),
+        }))
+    }
+}
+
+enum NotifierType {
+    Ipc(notifier::Notifier<ipc::Service>),
+    Local(notifier::Notifier<local::Service>),
+}
+
+/// Wakes up all connected listeners.
+#[pyclass(unsendable)]
+pub struct Notifier(NotifierType);
+
+#[pymethods]
+impl Notifier {
+    /// Notifies all connected listeners with the provided `event_id` or the default event id
+    /// when none is provided. Returns the number of notified listeners.
+    #[pyo3(signature = (event_id = None))]
+    fn notify(&self, event_id: Option<usize>) -> PyResult<usize> {
+        let msg = "Unable to notify listeners";
+        match (&self.0, event_id) {
+            (NotifierType::Ipc(notifier), None) => notifier.notify(),
+            (NotifierType::Local(notifier), None) => notifier.notify(),
+            (NotifierType::Ipc(notifier), Some(id)) => {
+                notifier.notify_with_custom_event_id(EventId::new(id))
+            }
+            (NotifierType::Local(notifier), Some(id)) => {
+                notifier.notify_with_custom_event_id(EventId::new(id))
+            }
+        }
+        .map_err(|e| to_py_err(msg, e))
+    }
+}
+
+enum ListenerType {
+    Ipc(listener::Listener<ipc::Service>),
+    Local(listener::Listener<local::Service>),
+}
+
+/// Waits for the notifications of all connected notifiers.
+#[pyclass(unsendable)]
+pub struct Listener(ListenerType);
+
+#[pymethods]
+impl Listener {
+    /// Returns the next received event id or `None` when no event was received.
+    fn try_wait_one(&self) -> PyResult<Option<usize>> {
+        let event_id = match &self.0 {
+            ListenerType::Ipc(listener) => listener.try_wait_one(),
+            ListenerType::Local(listener) => listener.try_wait_one(),
+        };
+        Self::to_event_id(event_id)
+    }
+
+    /// Waits at most `timeout` seconds for the next event id. Returns `None` when the timeout
+    /// passed without an event.
+    fn timed_wait_one(&self, timeout: f64) -> PyResult<Option<usize>> {
+        let timeout = to_duration(timeout)?;
+        let event_id = match &self.0 {
+            ListenerType::Ipc(listener) => listener.timed_wait_one(timeout),
+            ListenerType::Local(listener) => listener.timed_wait_one(timeout),
+        };
+        Self::to_event_id(event_id)
+    }
+
+    /// Blocks until the next event id was received. Returns `None` when the wait was
+    /// interrupted by a signal.
+    fn blocking_wait_one(&self) -> PyResult<Option<usize>> {
+        let event_id = match &self.0 {
+            ListenerType::Ipc(listener) => listener.blocking_wait_one(),
+            ListenerType::Local(listener) => listener.blocking_wait_one(),
+        };
+        Self::to_event_id(event_id)
+    }
+}
+
+impl Listener {
+    fn to_event_id<E: Debug>(event_id: Result<Option<EventId>, E>) -> PyResult<Option<usize>> {
+        event_id
+            .map(|id| id.map(|id| id.as_value()))
+            .map_err(|e| to_py_err("Unable to wait for events", e))
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Python bindings of iceoryx2. The payload and user header of publish-subscribe services are
+//! transmitted as bytes, so that Python processes can attach to services of every payload type.
+
+mod error;
+mod event;
+mod node;
+mod publish_subscribe;
+mod service_builder;
+mod service_type;
+
+use pyo3::prelude::*;
+
+#[pymodule]
+fn _iceoryx2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("Iceoryx2Error", m.py().get_type::<error::Iceoryx2Error>())?;
+    m.add_class::<service_type::ServiceType>()?;
+
+    m.add_class::<node::NodeBuilder>()?;
+    m.add_class::<node::Node>()?;
+    m.add_class::<service_builder::ServiceBuilder>()?;
+
+    m.add_class::<publish_subscribe::PublishSubscribeServiceBuilder>()?;
+    m.add_class::<publish_subscribe::PortFactoryPublishSubscribe>()?;
+    m.add_class::<publish_subscribe::PortFactoryPublisher>()?;
+    m.add_class::<publish_subscribe::PortFactorySubscriber>()?;
+    m.add_class::<publish_subscribe::Publisher>()?;
+    m.add_class::<publish_subscribe::Subscriber>()?;
+    m.add_class::<publish_subscribe::Sample>()?;
+
+    m.add_class::<event::EventServiceBuilder>()?;
+    m.add_class::<event::PortFactoryEvent>()?;
+    m.add_class::<event::PortFactoryNotifier>()?;
+    m.add_class::<event::PortFactoryListener>()?;
+    m.add_class::<event::Notifier>()?;
+    m.add_class::<event::Listener>()?;
+
+    Ok(())
+}
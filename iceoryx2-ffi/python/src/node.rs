@@ -0,0 +1,137 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{ipc, local, NodeName, ServiceName};
+use iceoryx2_bb_system_types::file_name::FileName;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::error::{to_duration, to_py_err};
+use crate::service_builder::{ServiceBuilder, ServiceBuilderType};
+use crate::service_type::ServiceType;
+
+pub(crate) enum NodeType {
+    Ipc(iceoryx2::node::Node<ipc::Service>),
+    Local(iceoryx2::node::Node<local::Service>),
+}
+
+/// Creates a `Node` with the provided settings.
+#[pyclass]
+#[derive(Default)]
+pub struct NodeBuilder {
+    name: Option<NodeName>,
+    domain: Option<FileName>,
+}
+
+#[pymethods]
+impl NodeBuilder {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the `Node`. Multiple nodes can have the same name.
+    fn name(mut slf: PyRefMut<'_, Self>, value: &str) -> PyResult<PyRefMut<'_, Self>> {
+        let name = NodeName::new(value).map_err(|e| {
+            PyValueError::new_err(format!("\"{}\" is not a valid node name ({:?}).", value, e))
+        })?;
+        slf.name = Some(name);
+        Ok(slf)
+    }
+
+    /// Places the `Node` and all of its services into the provided domain.
+    fn domain(mut slf: PyRefMut<'_, Self>, value: &str) -> PyResult<PyRefMut<'_, Self>> {
+        let domain = FileName::try_from(value).map_err(|e| {
+            PyValueError::new_err(format!("\"{}\" is not a valid domain ({:?}).", value, e))
+        })?;
+        slf.domain = Some(domain);
+        Ok(slf)
+    }
+
+    /// Creates a new `Node` for the provided `ServiceType`.
+    fn create(&self, service_type: ServiceType) -> PyResult<Node> {
+        let mut builder = iceoryx2::node::NodeBuilder::new();
+        if let Some(name) = &self.name {
+            builder = builder.name(name);
+        }
+        if let Some(domain) = &self.domain {
+            builder = builder.domain(domain);
+        }
+
+        let msg = "Unable to create node";
+        let node = match service_type {
+            ServiceType::Ipc => NodeType::Ipc(
+                builder
+                    .create::<ipc::Service>()
+                    .map_err(|e| to_py_err(msg, e))?,
+            ),
+            ServiceType::Local => NodeType::Local(
+                builder
+                    .create::<local::Service>()
+                    .map_err(|e| to_py_err(msg, e))?,
+            ),
+        };
+
+        Ok(Node(node))
+    }
+}
+
+/// The central entry point of iceoryx2. All services are created or opened with it.
+#[pyclass(unsendable)]
+pub struct Node(pub(crate) NodeType);
+
+#[pymethods]
+impl Node {
+    /// Returns the name of the `Node`.
+    #[getter]
+    fn name(&self) -> String {
+        match &self.0 {
+            NodeType::Ipc(node) => node.name().as_str().to_string(),
+            NodeType::Local(node) => node.name().as_str().to_string(),
+        }
+    }
+
+    /// Returns the system wide unique id of the `Node`.
+    #[getter]
+    fn id(&self) -> u128 {
+        match &self.0 {
+            NodeType::Ipc(node) => node.id().value(),
+            NodeType::Local(node) => node.id().value(),
+        }
+    }
+
+    /// Waits for `cycle_time` seconds. Raises an `Iceoryx2Error` when a termination or
+    /// interrupt signal was received.
+    fn wait(&self, cycle_time: f64) -> PyResult<()> {
+        let cycle_time = to_duration(cycle_time)?;
+        let msg = "Unable to wait on node";
+        match &self.0 {
+            NodeType::Ipc(node) => node.wait(cycle_time).map_err(|e| to_py_err(msg, e)),
+            NodeType::Local(node) => node.wait(cycle_time).map_err(|e| to_py_err(msg, e)),
+        }
+    }
+
+    /// Returns a `ServiceBuilder` to create or open the service with the provided name.
+    fn service_builder(&self, name: &str) -> PyResult<ServiceBuilder> {
+        let name = ServiceName::new(name).map_err(|e| {
+            PyValueError::new_err(format!(
+                "\"{}\" is not a valid service name ({:?}).",
+                name, e
+            ))
+        })?;
+
+        Ok(ServiceBuilder::new(match &self.0 {
+            NodeType::Ipc(node) => ServiceBuilderType::Ipc(node.service_builder(&name)),
+            NodeType::Local(node) => ServiceBuilderType::Local(node.service_builder(&name)),
+        }))
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::{publisher, subscriber};
+use iceoryx2::prelude::{ipc, local, PortFactory};
+use iceoryx2::service::builder::publish_subscribe::{
+    Builder, CustomHeaderMarker, CustomPayloadMarker,
+};
+use iceoryx2::service::port_factory::publish_subscribe;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::error::{consumed_builder_err, to_py_err};
+
+type Payload = [CustomPayloadMarker];
+type UserHeader = CustomHeaderMarker;
+
+pub(crate) enum PublishSubscribeBuilderType {
+    Ipc(Builder<Payload, UserHeader, ipc::Service>),
+    Local(Builder<Payload, UserHeader, local::Service>),
+}
+
+macro_rules! update_builder {
+    ($slf:ident, $builder:ident => $update:expr) => {{
+        $slf.0 = Some(match $slf.0.take().ok_or_else(consumed_builder_err)? {
+            PublishSubscribeBuilderType::Ipc($builder) => PublishSubscribeBuilderType::Ipc($update),
+            PublishSubscribeBuilderType::Local($builder) => {
+                PublishSubscribeBuilderType::Local($update)
+            }
+        });
+        Ok($slf)
+    }};
+}
+
+/// Creates or opens a publish-subscribe service. The settings are only applied when the
+/// service is created.
+#[pyclass(unsendable)]
+pub struct PublishSubscribeServiceBuilder(Option<PublishSubscribeBuilderType>);
+
+impl PublishSubscribeServiceBuilder {
+    pub(crate) fn new(builder: PublishSubscribeBuilderType) -> Self {
+        Self(Some(builder))
+    }
+}
+
+#[pymethods]
+impl PublishSubscribeServiceBuilder {
+    /// Defines how many samples a late joining subscriber receives from the history.
+    fn history_size(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.history_size(value))
+    }
+
+    /// Defines the maximum buffer size of a subscriber.
+    fn subscriber_max_buffer_size(
+        mut slf: PyRefMut<'_, Self>,
+        value: usize,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.subscriber_max_buffer_size(value))
+    }
+
+    /// Defines the maximum number of publishers.
+    fn max_publishers(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.max_publishers(value))
+    }
+
+    /// Defines the maximum number of subscribers.
+    fn max_subscribers(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.max_subscribers(value))
+    }
+
+    /// Defines how many nodes can open the service in parallel. Opening the service fails
+    /// when the limit is reached.
+    fn max_nodes(mut slf: PyRefMut<'_, Self>, value: usize) -> PyResult<PyRefMut<'_, Self>> {
+        update_builder!(slf, builder => builder.max_nodes(value))
+    }
+
+    /// Opens the service or creates it when it does not exist.
+    fn open_or_create(&mut self) -> PyResult<PortFactoryPublishSubscribe> {
+        let msg = "Unable to open or create publish-subscribe service";
+        Ok(PortFactoryPublishSubscribe(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                PublishSubscribeBuilderType::Ipc(builder) => PublishSubscribeType::Ipc(
+                    builder.open_or_create().map_err(|e| to_py_err(msg, e))?,
+                ),
+                PublishSubscribeBuilderType::Local(builder) => PublishSubscribeType::Local(
+                    builder.open_or_create().map_err(|e| to_py_err(msg, e))?,
+                ),
+            },
+        ))
+    }
+
+    /// Opens an existing service.
+    fn open(&mut self) -> PyResult<PortFactoryPublishSubscribe> {
+        let msg = "Unable to open publish-subscribe service";
+        Ok(PortFactoryPublishSubscribe(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                PublishSubscribeBuilderType::Ipc(builder) => {
+                    PublishSubscribeType::Ipc(builder.open().map_err(|e| to_py_err(msg, e))?)
+                }
+                PublishSubscribeBuilderType::Local(builder) => {
+                    PublishSubscribeType::Local(builder.open().map_err(|e| to_py_err(msg, e))?)
+                }
+            },
+        ))
+    }
+
+    /// Creates a new service.
+    fn create(&mut self) -> PyResult<PortFactoryPublishSubscribe> {
+        let msg = "Unable to create publish-subscribe service";
+        Ok(PortFactoryPublishSubscribe(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                PublishSubscribeBuilderType::Ipc(builder) => {
+                    PublishSubscribeType::Ipc(builder.create().map_err(|e| to_py_err(msg, e))?)
+                }
+                PublishSubscribeBuilderType::Local(builder) => {
+                    PublishSubscribeType::Local(builder.create().map_err(|e| to_py_err(msg, e))?)
+                }
+            },
+        ))
+    }
+}
+
+pub(crate) enum PublishSubscribeType {
+    Ipc(publish_subscribe::PortFactory<ipc::Service, Payload, UserHeader>),
+    Local(publish_subscribe::PortFactory<local::Service, Payload, UserHeader>),
+}
+
+/// Represents a publish-subscribe service and creates its publishers and subscribers.
+#[pyclass(unsendable)]
+pub struct PortFactoryPublishSubscribe(PublishSubscribeType);
+
+#[pymethods]
+impl PortFactoryPublishSubscribe {
+    /// Returns the name of the service.
+    #[getter]
+    fn name(&self) -> String {
+        match &self.0 {
+            PublishSubscribeType::Ipc(factory) => factory.name().as_str().to_string(),
+            PublishSubscribeType::Local(factory) => factory.name().as_str().to_string(),
+        }
+    }
+
+    /// Returns a builder to create a `Publisher`.
+    fn publisher_builder(slf: Py<Self>) -> PortFactoryPublisher {
+        PortFactoryPublisher {
+            factory: slf,
+            max_slice_len: None,
+            max_loaned_samples: None,
+        }
+    }
+
+    /// Returns a builder to create a `Subscriber`.
+    fn subscriber_builder(slf: Py<Self>) -> PortFactorySubscriber {
+        PortFactorySubscriber {
+            factory: slf,
+            buffer_size: None,
+        }
+    }
+}
+
+/// Creates a `Publisher` of a publish-subscribe service.
+#[pyclass]
+pub struct PortFactoryPublisher {
+    factory: Py<PortFactoryPublishSubscribe>,
+    max_slice_len: Option<usize>,
+    max_loaned_samples: Option<usize>,
+}
+
+#[pymethods]
+impl PortFactoryPublisher {
+    /// Defines the maximum number of payload elements a sample can contain. For services
+    /// created from Python, a payload element is one byte.
+    fn max_slice_len(mut slf: PyRefMut<'_, Self>, value: usize) -> PyRefMut<'_, Self> {
+        slf.max_slice_len = Some(value);
+        slf
+    }
+
+    /// Defines how many samples the `Publisher` can loan at the same time.
+    fn max_loaned_samples(mut slf: PyRefMut<'_, Self>, value: usize) -> PyRefMut<'_, Self> {
+        slf.max_loaned_samples = Some(value);
+        slf
+    }
+
+    /// Creates the `Publisher`.
+    fn create(&self, py: Python<'_>) -> PyResult<Publisher> {
+        macro_rules! create {
+            ($factory:expr) => {{
+                let mut builder = $factory.publisher_builder();
+                if let Some(value) = self.max_slice_len {
+                    builder = builder.max_slice_len(value);
+                }
+                if let Some(value) = self.max_loaned_samples {
+                    builder = builder.max_loaned_samples(value);
+                }
+                builder
+                    .create()
+                    .map_err(|e| to_py_err("Unable to create publisher", e))?
+            }};
+        }
+
+        Ok(Publisher(match &self.factory.borrow(py).0 {
+            PublishSubscribeType::Ipc(factory) => PublisherType::Ipc(create!(factory)),
+            PublishSubscribeType::Local(factory) => PublisherType::Local(create!(factory)),
+        }))
+    }
+}
+
+/// Creates a `Subscriber` of a publish-subscribe service.
+#[pyclass]
+pub struct PortFactorySubscriber {
+    factory: Py<PortFactoryPublishSubscribe>,
+    buffer_size: Option<usize>,
+}
+
+#[pymethods]
+impl PortFactorySubscriber {
+    /// Defines how many samples the `Subscriber` can hold in its buffer.
+    fn buffer_size(mut slf: PyRefMut<'_, Self>, value: usize) -> PyRefMut<'_, Self> {
+        slf.buffer_size = Some(value);
+        slf
+    }
+
+    /// Creates the `Subscriber`.
+    fn create(&self, py: Python<'_>) -> PyResult<Subscriber> {
+        macro_rules! create {
+            ($factory:expr) => {{
+                let mut builder = $factory.subscriber_builder();
+                if let Some(value) = self.buffer_size {
+                    builder = builder.buffer_size(value);
+                }
+                builder
+                    .create()
+                    .map_err(|e| to_py_err("Unable to create subscriber", e))?
+            }};
+        }
+
+        Ok(Subscriber(match &self.factory.borrow(py).0 {
+            PublishSubscribeType::Ipc(factory) => SubscriberType::Ipc(create!(factory)),
+            PublishSubscribeType::Local(factory) => SubscriberType::Local(create!(factory)),
+        }))
+    }
+}
+
+enum PublisherType {
+    Ipc(publisher::Publisher<ipc::Service, Payload, UserHeader>),
+    Local(publisher::Publisher<local::Service, Payload, UserHeader>),
+}
+
+/// Sends bytes to all connected subscribers.
+#[pyclass(unsendable)]
+pub struct Publisher(PublisherType);
+
+#[pymethods]
+impl Publisher {
+    /// Copies the `payload` and the `user_header` bytes into a sample and delivers it. The
+    /// `payload` must consist of complete payload elements and the `user_header` must have the
+    /// size of the user header of the service. Returns the number of subscribers that received
+    /// the sample.
+    #[pyo3(signature = (payload, user_header = None))]
+    fn send_copy(&self, payload: &[u8], user_header: Option<&[u8]>) -> PyResult<usize> {
+        let user_header = user_header.unwrap_or(&[]);
+        let msg = "Unable to send sample";
+        match &self.0 {
+            PublisherType::Ipc(publisher) => publisher
+                .send_raw_copy(user_header, payload)
+                .map_err(|e| to_py_err(msg, e)),
+            PublisherType::Local(publisher) => publisher
+                .send_raw_copy(user_header, payload)
+                .map_err(|e| to_py_err(msg, e)),
+        }
+    }
+}
+
+enum SubscriberType {
+    Ipc(subscriber::Subscriber<ipc::Service, Payload, UserHeader>),
+    Local(subscriber::Subscriber<local::Service, Payload, UserHeader>),
+}
+
+/// Receives the samples of all connected publishers.
+#[pyclass(unsendable)]
+pub struct Subscriber(SubscriberType);
+
+#[pymethods]
+impl Subscriber {
+    /// Returns the next received `Sample` or `None` when no sample is available.
+    fn receive(&self, py: Python<'_>) -> PyResult<Option<Sample>> {
+        macro_rules! receive {
+            ($subscriber:expr) => {
+                $subscriber
+                    .receive_raw()
+                    .map_err(|e| to_py_err("Unable to receive sample", e))?
+                    .map(|sample| Sample {
+                        payload: PyBytes::new(py, sample.payload_bytes()).unbind(),
+                        user_header: PyBytes::new(py, sample.user_header_bytes()).unbind(),
+                    })
+            };
+        }
+
+        Ok(match &self.0 {
+            SubscriberType::Ipc(subscriber) => receive!(subscriber),
+            SubscriberType::Local(subscriber) => receive!(subscriber),
+        })
+    }
+}
+
+/// A copy of a received sample. The underlying shared memory is released when it was received.
+#[pyclass(frozen)]
+pub struct Sample {
+    /// The payload bytes of the sample.
+    #[pyo3(get)]
+    payload: Py<PyBytes>,
+    /// The user header bytes of the sample.
+    #[pyo3(get)]
+    user_header: Py<PyBytes>,
+}
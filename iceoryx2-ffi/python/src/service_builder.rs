@@ -0,0 +1,62 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{ipc, local};
+use iceoryx2::service::builder::Builder;
+use pyo3::prelude::*;
+
+use crate::error::consumed_builder_err;
+use crate::event::{EventBuilderType, EventServiceBuilder};
+use crate::publish_subscribe::{PublishSubscribeBuilderType, PublishSubscribeServiceBuilder};
+
+pub(crate) enum ServiceBuilderType {
+    Ipc(Builder<ipc::Service>),
+    Local(Builder<local::Service>),
+}
+
+/// Selects the messaging pattern of the service that shall be created or opened.
+#[pyclass(unsendable)]
+pub struct ServiceBuilder(Option<ServiceBuilderType>);
+
+impl ServiceBuilder {
+    pub(crate) fn new(builder: ServiceBuilderType) -> Self {
+        Self(Some(builder))
+    }
+}
+
+#[pymethods]
+impl ServiceBuilder {
+    /// Returns a builder for a publish-subscribe service. The payload and user header are
+    /// transmitted as bytes, an opened service adopts the type details of the existing service.
+    fn publish_subscribe(&mut self) -> PyResult<PublishSubscribeServiceBuilder> {
+        Ok(PublishSubscribeServiceBuilder::new(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                ServiceBuilderType::Ipc(builder) => {
+                    PublishSubscribeBuilderType::Ipc(builder.publish_subscribe_raw())
+                }
+                ServiceBuilderType::Local(builder) => {
+                    PublishSubscribeBuilderType::Local(builder.publish_subscribe_raw())
+                }
+            },
+        ))
+    }
+
+    /// Returns a builder for an event service.
+    fn event(&mut self) -> PyResult<EventServiceBuilder> {
+        Ok(EventServiceBuilder::new(
+            match self.0.take().ok_or_else(consumed_builder_err)? {
+                ServiceBuilderType::Ipc(builder) => EventBuilderType::Ipc(builder.event()),
+                ServiceBuilderType::Local(builder) => EventBuilderType::Local(builder.event()),
+            },
+        ))
+    }
+}
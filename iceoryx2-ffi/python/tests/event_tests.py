@@ -0,0 +1,62 @@
+# Copyright (c) 2024 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import uuid
+
+import pytest
+
+import iceoryx2 as iox2
+
+SERVICE_TYPES = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+def generate_name():
+    return "tests/" + uuid.uuid4().hex
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_listener_receives_event_ids(service_type):
+    node = iox2.NodeBuilder().create(service_type)
+    service = node.service_builder(generate_name()).event().create()
+    notifier = service.notifier_builder().default_event_id(3).create()
+    listener = service.listener_builder().create()
+
+    assert listener.try_wait_one() is None
+
+    assert notifier.notify() == 1
+    assert listener.try_wait_one() == 3
+
+    notifier.notify(7)
+    assert listener.timed_wait_one(1.0) == 7
+    assert listener.timed_wait_one(0.001) is None
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_open_or_create_opens_existing_event_service(service_type):
+    service_name = generate_name()
+    node = iox2.NodeBuilder().create(service_type)
+    service_1 = node.service_builder(service_name).event().open_or_create()
+    service_2 = node.service_builder(service_name).event().open_or_create()
+
+    notifier = service_1.notifier_builder().create()
+    listener = service_2.listener_builder().create()
+
+    notifier.notify(1)
+    assert listener.blocking_wait_one() == 1
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_open_of_non_existing_event_service_fails(service_type):
+    node = iox2.NodeBuilder().create(service_type)
+
+    with pytest.raises(iox2.Iceoryx2Error):
+        node.service_builder(generate_name()).event().open()
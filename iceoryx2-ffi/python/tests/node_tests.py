@@ -0,0 +1,64 @@
+# Copyright (c) 2024 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import uuid
+
+import pytest
+
+import iceoryx2 as iox2
+
+SERVICE_TYPES = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+def generate_name():
+    return "tests/" + uuid.uuid4().hex
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_node_can_be_created_with_name(service_type):
+    sut = iox2.NodeBuilder().name("hypnotoad").create(service_type)
+
+    assert sut.name == "hypnotoad"
+    assert sut.id != 0
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_node_ids_are_unique(service_type):
+    sut_1 = iox2.NodeBuilder().create(service_type)
+    sut_2 = iox2.NodeBuilder().create(service_type)
+
+    assert sut_1.id != sut_2.id
+
+
+def test_invalid_domain_is_rejected():
+    with pytest.raises(ValueError):
+        iox2.NodeBuilder().domain("no/valid/domain")
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_node_wait_returns_after_cycle_time(service_type):
+    sut = iox2.NodeBuilder().create(service_type)
+
+    sut.wait(0.001)
+
+    with pytest.raises(ValueError):
+        sut.wait(-1.0)
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_consumed_service_builder_raises(service_type):
+    node = iox2.NodeBuilder().create(service_type)
+    sut = node.service_builder(generate_name())
+
+    sut.event()
+    with pytest.raises(iox2.Iceoryx2Error):
+        sut.event()
@@ -0,0 +1,92 @@
+# Copyright (c) 2024 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import uuid
+
+import pytest
+
+import iceoryx2 as iox2
+
+SERVICE_TYPES = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+def generate_name():
+    return "tests/" + uuid.uuid4().hex
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_samples_are_delivered_as_bytes(service_type):
+    node = iox2.NodeBuilder().create(service_type)
+    service = node.service_builder(generate_name()).publish_subscribe().create()
+    publisher = service.publisher_builder().max_slice_len(64).create()
+    subscriber = service.subscriber_builder().create()
+
+    assert subscriber.receive() is None
+
+    assert publisher.send_copy(b"all glory to the hypnotoad") == 1
+    sample = subscriber.receive()
+
+    assert sample.payload == b"all glory to the hypnotoad"
+    assert sample.user_header == b""
+    assert subscriber.receive() is None
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_opened_service_shares_samples(service_type):
+    service_name = generate_name()
+    node = iox2.NodeBuilder().create(service_type)
+    service_1 = node.service_builder(service_name).publish_subscribe().create()
+    service_2 = node.service_builder(service_name).publish_subscribe().open()
+
+    assert service_1.name == service_name
+    assert service_2.name == service_name
+
+    publisher = service_1.publisher_builder().max_slice_len(8).create()
+    subscriber = service_2.subscriber_builder().create()
+
+    publisher.send_copy(b"\x01\x02\x03")
+    assert subscriber.receive().payload == b"\x01\x02\x03"
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_create_of_existing_service_fails(service_type):
+    service_name = generate_name()
+    node = iox2.NodeBuilder().create(service_type)
+    _service = node.service_builder(service_name).publish_subscribe().create()
+
+    with pytest.raises(iox2.Iceoryx2Error):
+        node.service_builder(service_name).publish_subscribe().create()
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_sending_payload_larger_than_max_slice_len_fails(service_type):
+    node = iox2.NodeBuilder().create(service_type)
+    service = node.service_builder(generate_name()).publish_subscribe().create()
+    publisher = service.publisher_builder().max_slice_len(4).create()
+
+    with pytest.raises(iox2.Iceoryx2Error):
+        publisher.send_copy(b"12345")
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_open_fails_when_max_nodes_is_reached(service_type):
+    service_name = generate_name()
+    node_1 = iox2.NodeBuilder().create(service_type)
+    node_2 = iox2.NodeBuilder().create(service_type)
+    node_3 = iox2.NodeBuilder().create(service_type)
+    _service_1 = (
+        node_1.service_builder(service_name).publish_subscribe().max_nodes(2).create()
+    )
+    _service_2 = node_2.service_builder(service_name).publish_subscribe().open()
+
+    with pytest.raises(iox2.Iceoryx2Error):
+        node_3.service_builder(service_name).publish_subscribe().open()
@@ -56,13 +56,27 @@ pub type IoxAtomicI16 = core::sync::atomic::AtomicI16;
 pub type IoxAtomicI32 = core::sync::atomic::AtomicI32;
 
 /// Behaves like [`core::sync::atomic::AtomicI64`]
+#[cfg(target_has_atomic = "64")]
 #[allow(clippy::disallowed_types)]
 pub type IoxAtomicI64 = core::sync::atomic::AtomicI64;
 
+/// Behaves like [`core::sync::atomic::AtomicI64`]. The target does not support 64-bit
+/// atomics, therefore the operations are not lock-free and must not be shared between
+/// processes, see [`IoxAtomic`].
+#[cfg(not(target_has_atomic = "64"))]
+pub type IoxAtomicI64 = IoxAtomic<i64>;
+
 /// Behaves like [`core::sync::atomic::AtomicU64`]
+#[cfg(target_has_atomic = "64")]
 #[allow(clippy::disallowed_types)]
 pub type IoxAtomicU64 = core::sync::atomic::AtomicU64;
 
+/// Behaves like [`core::sync::atomic::AtomicU64`]. The target does not support 64-bit
+/// atomics, therefore the operations are not lock-free and must not be shared between
+/// processes, see [`IoxAtomic`].
+#[cfg(not(target_has_atomic = "64"))]
+pub type IoxAtomicU64 = IoxAtomic<u64>;
+
 type LockType = RwLockWriterPreference;
 
 #[doc(hidden)]
@@ -134,6 +148,10 @@ pub mod internal {
 /// iceoryx2 implementation of an atomic that has an internal [`RwLockWriterPreference`].
 /// It enables atomic operations on platforms that do not support them with the restriction that
 /// those operations are no longer lock-free.
+///
+/// The lock is not robust. When a process dies while it holds the lock, every other process
+/// that accesses the atomic blocks forever. Therefore, it must only be used within a single
+/// process, iceoryx2 supports only process local services on targets without 64-bit atomics.
 #[derive(Default)]
 #[repr(C)]
 pub struct IoxAtomic<T: internal::AtomicInteger> {
@@ -21,6 +21,12 @@ fn main() {
     #[cfg(any(target_os = "linux", target_os = "freebsd"))]
     println!("cargo:rustc-link-lib=pthread");
 
+    // the socket functions are not part of the libc on QNX, the target os is acquired from the
+    // environment since QNX targets are always cross compiled
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("nto") {
+        println!("cargo:rustc-link-lib=socket");
+    }
+
     println!("cargo:rerun-if-changed=src/c/posix.h");
 
     let bindings = if std::env::var("DOCS_RS").is_ok() {
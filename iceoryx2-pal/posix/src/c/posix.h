@@ -31,6 +31,12 @@
 #include <mqueue.h>
 #endif
 
+#ifdef __QNXNTO__
+#include <limits.h>
+#include <mqueue.h>
+#include <sys/neutrino.h>
+#endif
+
 #if !(defined(_WIN64) || defined(_WIN32))
 #include <arpa/inet.h>
 #include <dirent.h>
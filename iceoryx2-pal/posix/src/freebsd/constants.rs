@@ -47,6 +47,8 @@ pub const PROT_EXEC: int = crate::internal::PROT_EXEC as _;
 pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
+// transparent huge pages are not supported, see madvise()
+pub const MADV_HUGEPAGE: int = -1;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
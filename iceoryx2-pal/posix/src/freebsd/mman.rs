@@ -73,6 +73,8 @@ unsafe fn shm_file_path(name: *const c_char, suffix: &[u8]) -> [u8; MAX_PATH_LEN
     state_file_path
 }
 
+// the state file contains the pid of the creator so that a stale state file of a dead creator
+// can be distinguished from a state file of a creator that did not yet create the shared memory
 unsafe fn create_shm_state_file(name: *const c_char) -> bool {
     let shm_file_path = shm_file_path(name, SHM_STATE_SUFFIX);
     let shm_state_fd = open_with_mode(
@@ -85,17 +87,71 @@ unsafe fn create_shm_state_file(name: *const c_char) -> bool {
         return false;
     }
 
+    let creator = getpid().to_string();
+    if write(shm_state_fd, creator.as_ptr().cast(), creator.len()) != creator.len() as _ {
+        remove(shm_file_path.as_ptr().cast());
+        close(shm_state_fd);
+        return false;
+    }
+
     close(shm_state_fd);
     true
 }
 
+unsafe fn read_shm_creator(name: *const c_char) -> pid_t {
+    let shm_file_path = shm_file_path(name, SHM_STATE_SUFFIX);
+    let shm_state_fd = open_with_mode(shm_file_path.as_ptr().cast(), O_RDONLY, 0);
+    if shm_state_fd == -1 {
+        return 0;
+    }
+
+    let mut buffer = [0u8; 16];
+    let len = read(shm_state_fd, buffer.as_mut_ptr().cast(), buffer.len());
+    close(shm_state_fd);
+
+    if len <= 0 {
+        return 0;
+    }
+
+    buffer[..len as usize]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .fold(0 as pid_t, |pid, c| pid * 10 + (c - b'0') as pid_t)
+}
+
 unsafe fn does_shm_exist(name: *const c_char) -> bool {
     let shm_file_path = shm_file_path(name, SHM_STATE_SUFFIX);
     access(shm_file_path.as_ptr().cast(), F_OK) == 0
 }
 
+unsafe fn is_stale_shm_state_file(name: *const c_char) -> bool {
+    let fd = crate::internal::shm_open(name.cast(), O_RDONLY, 0);
+    if fd != -1 {
+        close(fd);
+        return false;
+    }
+
+    if Errno::get() != Errno::ENOENT {
+        return false;
+    }
+
+    // when the creator is still alive it may be between creating the state file and the
+    // shared memory, a state file without a creator is never considered stale
+    let creator = read_shm_creator(name);
+    creator != 0 && kill(creator, 0) == -1 && Errno::get() == Errno::ESRCH
+}
+
 pub unsafe fn shm_open(name: *const c_char, oflag: int, mode: mode_t) -> int {
-    let shm_exists = does_shm_exist(name);
+    let mut shm_exists = does_shm_exist(name);
+
+    // a process that died between creating the state file and the shared memory leaves a
+    // stale state file behind, it is removed so that the name can be reused. The creation of
+    // the shared memory itself stays atomic since it uses the same name.
+    if shm_exists && oflag & O_CREAT != 0 && is_stale_shm_state_file(name) {
+        remove(shm_file_path(name, SHM_STATE_SUFFIX).as_ptr().cast());
+        shm_exists = false;
+    }
+
     if oflag & O_EXCL != 0 && shm_exists {
         Errno::set(Errno::EEXIST);
         return -1;
@@ -147,6 +203,15 @@ pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    if advice == MADV_HUGEPAGE {
+        Errno::set(Errno::ENOTSUP);
+        return -1;
+    }
+
+    crate::internal::madvise(addr, len, advice)
+}
+
 unsafe fn trim_ascii(value: &[i8]) -> &[u8] {
     let length = value.iter().position(|&c| c == 0).unwrap_or(value.len());
     core::slice::from_raw_parts(value.as_ptr().cast(), length)
@@ -27,6 +27,17 @@ pub unsafe fn proc_pidpath(pid: pid_t, buffer: *mut c_char, buffer_len: size_t)
     -1
 }
 
+pub unsafe fn proc_pidstarttime(pid: pid_t, start_time: *mut u64) -> int {
+    let proc = crate::internal::kinfo_getproc(pid);
+    if proc.is_null() {
+        return -1;
+    }
+
+    *start_time = (*proc).ki_start.tv_sec as u64 * 1_000_000 + (*proc).ki_start.tv_usec as u64;
+    crate::internal::free(proc.cast());
+    0
+}
+
 pub unsafe fn sysconf(name: int) -> long {
     crate::internal::sysconf(name)
 }
@@ -47,6 +58,10 @@ pub unsafe fn dup(fildes: int) -> int {
     crate::internal::dup(fildes)
 }
 
+pub unsafe fn pipe(fildes: *mut int) -> int {
+    crate::internal::pipe(fildes)
+}
+
 pub unsafe fn close(fd: int) -> int {
     crate::internal::close(fd)
 }
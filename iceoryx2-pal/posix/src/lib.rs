@@ -29,12 +29,23 @@ pub(crate) mod internal {
     pub const ESUCCES: u32 = 0;
 }
 
+#[cfg(not(any(
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "nto",
+    target_os = "windows"
+)))]
+compile_error!("the target operating system is not supported by the iceoryx2 posix platform layer");
+
 #[cfg(target_os = "freebsd")]
 mod freebsd;
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "macos")]
 mod macos;
+#[cfg(target_os = "nto")]
+mod qnx;
 #[cfg(target_os = "windows")]
 mod windows;
 
@@ -52,6 +63,8 @@ pub mod posix {
     pub use crate::linux::*;
     #[cfg(target_os = "macos")]
     pub use crate::macos::*;
+    #[cfg(target_os = "nto")]
+    pub use crate::qnx::*;
     #[cfg(target_os = "windows")]
     pub use crate::windows::*;
 
@@ -14,6 +14,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::posix::types::*;
+use crate::posix::O_RDONLY;
 
 pub unsafe fn proc_pidpath(pid: pid_t, buffer: *mut c_char, buffer_len: size_t) -> isize {
     let path = if pid == crate::internal::getpid() {
@@ -24,6 +25,40 @@ pub unsafe fn proc_pidpath(pid: pid_t, buffer: *mut c_char, buffer_len: size_t)
     crate::internal::readlink(path.as_bytes().as_ptr().cast(), buffer.cast(), buffer_len)
 }
 
+pub unsafe fn proc_pidstarttime(pid: pid_t, start_time: *mut u64) -> int {
+    let path = "/proc/".to_owned() + &pid.to_string() + "/stat\0";
+    let fd = crate::internal::open(path.as_bytes().as_ptr().cast(), O_RDONLY);
+    if fd == -1 {
+        return -1;
+    }
+
+    let mut buffer = [0u8; 1024];
+    let len = crate::internal::read(fd, buffer.as_mut_ptr().cast(), buffer.len());
+    crate::internal::close(fd);
+    if len <= 0 {
+        return -1;
+    }
+
+    // the executable name in the second field can contain spaces and parentheses, the start
+    // time is the 22nd field and the 20th after the name
+    let content = &buffer[..len as usize];
+    let value = match content.iter().rposition(|c| *c == b')') {
+        Some(name_end) => core::str::from_utf8(&content[name_end + 1..])
+            .ok()
+            .and_then(|fields| fields.split_whitespace().nth(19))
+            .and_then(|field| field.parse::<u64>().ok()),
+        None => None,
+    };
+
+    match value {
+        Some(value) => {
+            *start_time = value;
+            0
+        }
+        None => -1,
+    }
+}
+
 pub unsafe fn sysconf(name: int) -> long {
     crate::internal::sysconf(name)
 }
@@ -44,6 +79,10 @@ pub unsafe fn dup(fildes: int) -> int {
     crate::internal::dup(fildes)
 }
 
+pub unsafe fn pipe(fildes: *mut int) -> int {
+    crate::internal::pipe(fildes)
+}
+
 pub unsafe fn close(fd: int) -> int {
     crate::internal::close(fd)
 }
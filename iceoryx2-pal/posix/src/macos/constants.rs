@@ -49,6 +49,8 @@ pub const PROT_EXEC: int = crate::internal::PROT_EXEC as _;
 pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
+// transparent huge pages are not supported, see madvise()
+pub const MADV_HUGEPAGE: int = -1;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = int::MAX;
@@ -216,7 +218,8 @@ pub const S_ISGID: mode_t = crate::internal::S_ISGID as _;
 pub const S_ISVTX: mode_t = crate::internal::S_ISVTX as _;
 
 pub const CLOCK_REALTIME: clockid_t = 0;
-pub const CLOCK_MONOTONIC: clockid_t = 1;
+// the clock ids of darwin are not consecutive, see _CLOCK_MONOTONIC in time.h
+pub const CLOCK_MONOTONIC: clockid_t = 6;
 pub const CLOCK_TIMER_ABSTIME: int = 1;
 
 pub const F_OK: int = crate::internal::F_OK as _;
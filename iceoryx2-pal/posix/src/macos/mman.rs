@@ -143,8 +143,39 @@ unsafe fn generate_real_shm_name() -> [u8; SHM_MAX_NAME_LEN] {
     buffer
 }
 
+unsafe fn is_stale_real_shm_name(real_name: &[u8; SHM_MAX_NAME_LEN]) -> bool {
+    let fd = crate::internal::shm_open(real_name.as_ptr().cast(), O_RDONLY, 0);
+    if fd != -1 {
+        close(fd);
+        return false;
+    }
+
+    if Errno::get() != Errno::ENOENT {
+        return false;
+    }
+
+    // the real name starts with the pid of the creator, when it is still alive it may be
+    // between writing the state file and creating the shared memory
+    let creator = real_name
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .fold(0 as pid_t, |pid, c| pid * 10 + (c - b'0') as pid_t);
+
+    creator != 0 && kill(creator, 0) == -1 && Errno::get() == Errno::ESRCH
+}
+
 pub unsafe fn shm_open(name: *const c_char, oflag: int, _mode: mode_t) -> int {
-    let real_name = get_real_shm_name(name);
+    let mut real_name = get_real_shm_name(name);
+
+    // a process that died between writing the state file and creating the shared memory
+    // leaves a stale state file behind, it is removed so that the name can be reused
+    if let Some(ref stale_name) = real_name {
+        if oflag & O_CREAT != 0 && is_stale_real_shm_name(stale_name) {
+            remove(shm_file_path(name, SHM_STATE_SUFFIX).as_ptr().cast());
+            real_name = None;
+        }
+    }
+
     if oflag & O_EXCL != 0 && real_name.is_some() {
         Errno::set(Errno::EEXIST);
         return -1;
@@ -206,6 +237,15 @@ pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    if advice == MADV_HUGEPAGE {
+        Errno::set(Errno::ENOTSUP);
+        return -1;
+    }
+
+    crate::internal::madvise(addr, len, advice)
+}
+
 unsafe fn trim_ascii(value: &[i8]) -> &[u8] {
     for i in 0..value.len() {
         if value[i] == 0 {
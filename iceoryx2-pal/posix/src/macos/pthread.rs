@@ -146,35 +146,83 @@ impl ThreadStates {
     }
 }
 
-#[link(name = "c++")]
+// The wait and wake operations of libc++ are restricted to the address space of one process.
+// The ulock operations of libSystem (available since macOS 10.12) support waiting on an
+// address in shared memory, which is required for the inter-process synchronization
+// primitives.
 extern "C" {
-    #[link_name = "_ZNSt3__123__libcpp_atomic_monitorEPVKv"]
-    fn __libcpp_atomic_monitor(ptr: *const void) -> i64;
+    fn __ulock_wait(operation: u32, addr: *mut void, value: u64, timeout_us: u32) -> int;
+    fn __ulock_wake(operation: u32, addr: *mut void, wake_value: u64) -> int;
+}
+
+const UL_COMPARE_AND_WAIT_SHARED: u32 = 3;
+const ULF_WAKE_ALL: u32 = 0x00000100;
+const ULF_NO_ERRNO: u32 = 0x01000000;
+
+// when the ulock operations fail, e.g. because they are not supported by the kernel, the
+// waiter polls the value in this interval instead
+const FALLBACK_POLL_INTERVAL_US: u32 = 100;
+
+enum UlockWaitResult {
+    WokenUp,
+    TimedOut,
+    Failed,
+}
+
+fn ulock_wait(atomic: &IoxAtomicU32, expected: &u32, timeout_us: u32) -> UlockWaitResult {
+    let ptr = (atomic as *const IoxAtomicU32) as *mut void;
+    // returns immediately when the value has already changed, spurious wake ups are handled
+    // by the caller
+    let ret = unsafe {
+        __ulock_wait(
+            UL_COMPARE_AND_WAIT_SHARED | ULF_NO_ERRNO,
+            ptr,
+            *expected as u64,
+            timeout_us,
+        )
+    };
 
-    #[link_name = "_ZNSt3__120__libcpp_atomic_waitEPVKvx"]
-    fn __libcpp_atomic_wait(ptr: *const void, monitor: i64);
+    // with ULF_NO_ERRNO the error is returned as negative errno value
+    match -ret {
+        v if v <= 0 => UlockWaitResult::WokenUp,
+        // an interrupt is handled like a spurious wake up
+        v if v == Errno::EINTR as int => UlockWaitResult::WokenUp,
+        v if v == Errno::ETIMEDOUT as int => UlockWaitResult::TimedOut,
+        // EFAULT, ENOSYS or EINVAL, the value is polled instead
+        _ => UlockWaitResult::Failed,
+    }
+}
 
-    #[link_name = "_ZNSt3__123__cxx_atomic_notify_oneEPVKv"]
-    fn __cxx_atomic_notify_one(ptr: *const void);
+fn fallback_wait(timeout_us: u32) {
+    let interval = match timeout_us {
+        0 => FALLBACK_POLL_INTERVAL_US,
+        v => v.min(FALLBACK_POLL_INTERVAL_US),
+    };
+    std::thread::sleep(std::time::Duration::from_micros(interval as u64));
+}
+
+fn ulock_wake(atomic: &IoxAtomicU32, flags: u32) {
+    let ptr = (atomic as *const IoxAtomicU32) as *mut void;
+    loop {
+        let ret =
+            unsafe { __ulock_wake(UL_COMPARE_AND_WAIT_SHARED | ULF_NO_ERRNO | flags, ptr, 0) };
 
-    #[link_name = "_ZNSt3__123__cxx_atomic_notify_allEPVKv"]
-    fn __cxx_atomic_notify_all(ptr: *const void);
+        // ENOENT states that there is no waiter, on any other failure the waiters fall back
+        // to polling the value and do not require a wake up
+        if -ret != Errno::EINTR as int {
+            return;
+        }
+    }
 }
 
 pub fn wait(atomic: &IoxAtomicU32, expected: &u32) {
-    let ptr = (atomic as *const IoxAtomicU32) as *const void;
-    let monitor = unsafe { __libcpp_atomic_monitor(ptr) };
-    if atomic.load(Ordering::Relaxed) != *expected {
-        return;
+    // a timeout of zero waits until the value changes
+    if let UlockWaitResult::Failed = ulock_wait(atomic, expected, 0) {
+        fallback_wait(0);
     }
-    unsafe { __libcpp_atomic_wait(ptr, monitor) };
 }
 
 pub fn timed_wait(atomic: &IoxAtomicU32, expected: &u32, timeout: timespec) {
-    let sleep_time = timespec {
-        tv_sec: 0,
-        tv_nsec: 1000000,
-    };
     let mut now = timespec::new();
     loop {
         if atomic.load(Ordering::Relaxed) != *expected {
@@ -188,25 +236,25 @@ pub fn timed_wait(atomic: &IoxAtomicU32, expected: &u32, timeout: timespec) {
             return;
         }
 
-        unsafe {
-            clock_nanosleep(
-                CLOCK_REALTIME,
-                0,
-                &sleep_time,
-                core::ptr::null_mut::<timespec>().cast(),
-            )
-        };
+        let remaining_us =
+            (timeout.tv_sec - now.tv_sec) * 1_000_000 + (timeout.tv_nsec - now.tv_nsec) / 1000;
+        // zero would wait without timeout
+        let remaining_us = remaining_us.clamp(1, u32::MAX as _) as u32;
+
+        match ulock_wait(atomic, expected, remaining_us) {
+            UlockWaitResult::TimedOut => return,
+            UlockWaitResult::Failed => fallback_wait(remaining_us),
+            UlockWaitResult::WokenUp => (),
+        }
     }
 }
 
 pub fn wake_one(atomic: &IoxAtomicU32) {
-    let ptr = (atomic as *const IoxAtomicU32) as *const void;
-    unsafe { __cxx_atomic_notify_one(ptr) };
+    ulock_wake(atomic, 0);
 }
 
 pub fn wake_all(atomic: &IoxAtomicU32) {
-    let ptr = (atomic as *const IoxAtomicU32) as *const void;
-    unsafe { __cxx_atomic_notify_all(ptr) };
+    ulock_wake(atomic, ULF_WAKE_ALL);
 }
 
 pub unsafe fn pthread_barrier_wait(barrier: *mut pthread_barrier_t) -> int {
@@ -19,6 +19,7 @@ pub const POSIX_SUPPORT_PERMISSIONS: bool = false;
 pub const POSIX_SUPPORT_FILE_LOCK: bool = false;
 pub const POSIX_SUPPORT_MEMORY_LOCK: bool = false;
 pub const POSIX_SUPPORT_MESSAGE_QUEUE: bool = false;
+pub const POSIX_SUPPORT_PIPE: bool = true;
 pub const POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING: bool = false;
 pub const POSIX_SUPPORT_CONSOLE_SIGNAL_HANDLING: bool = false;
 pub const POSIX_SUPPORT_SCHEDULER: bool = false;
@@ -31,7 +31,7 @@ pub unsafe fn clock_nanosleep(
     rqtp: *const timespec,
     rmtp: *mut timespec,
 ) -> int {
-    if clock_id != crate::posix::CLOCK_REALTIME {
+    if clock_id != crate::posix::CLOCK_REALTIME && clock_id != crate::posix::CLOCK_MONOTONIC {
         return Errno::ENOTSUP as _;
     }
 
@@ -35,6 +35,24 @@ pub unsafe fn proc_pidpath(pid: pid_t, buffer: *mut c_char, buffer_len: size_t)
     crate::internal::strnlen(buffer.cast(), buffer_len) as _
 }
 
+pub unsafe fn proc_pidstarttime(pid: pid_t, start_time: *mut u64) -> int {
+    let mut info = core::mem::zeroed::<crate::internal::proc_bsdinfo>();
+    let size = core::mem::size_of::<crate::internal::proc_bsdinfo>() as int;
+    let ret_val = crate::internal::proc_pidinfo(
+        pid as _,
+        crate::internal::PROC_PIDTBSDINFO as _,
+        0,
+        (&mut info as *mut crate::internal::proc_bsdinfo).cast(),
+        size,
+    );
+    if ret_val != size {
+        return -1;
+    }
+
+    *start_time = info.pbi_start_tvsec * 1_000_000 + info.pbi_start_tvusec;
+    0
+}
+
 pub unsafe fn sysconf(name: int) -> long {
     crate::internal::sysconf(name)
 }
@@ -59,6 +77,10 @@ pub unsafe fn dup(fildes: int) -> int {
     crate::internal::dup(fildes)
 }
 
+pub unsafe fn pipe(fildes: *mut int) -> int {
+    crate::internal::pipe(fildes)
+}
+
 pub unsafe fn close(fd: int) -> int {
     crate::internal::close(fd)
 }
@@ -24,7 +24,12 @@ pub unsafe fn scandir_impl(
 
     *namelist = core::ptr::null_mut::<*mut dirent>();
     let mut entries = vec![];
+    #[cfg(not(target_os = "nto"))]
     const DIRENT_SIZE: usize = core::mem::size_of::<dirent>();
+    // the name is a flexible array member on QNX, readdir_r() requires space for NAME_MAX
+    #[cfg(target_os = "nto")]
+    const DIRENT_SIZE: usize =
+        core::mem::size_of::<dirent>() + crate::internal::NAME_MAX as usize + 1;
 
     let cleanup = |entries: &mut Vec<*mut void>, namelist: *mut *mut *mut dirent| {
         entries.drain(..).for_each(|entry| {
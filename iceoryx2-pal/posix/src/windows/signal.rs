@@ -17,13 +17,15 @@
 use iceoryx2_pal_concurrency_sync::mutex::Mutex;
 use iceoryx2_pal_concurrency_sync::WaitAction;
 use windows_sys::Win32::{
-    Foundation::{FALSE, TRUE},
+    Foundation::{
+        CloseHandle, ERROR_ACCESS_DENIED, ERROR_INVALID_PARAMETER, FALSE, STILL_ACTIVE, TRUE,
+    },
     System::{
         Console::{
             GenerateConsoleCtrlEvent, SetConsoleCtrlHandler, CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT,
             CTRL_C_EVENT,
         },
-        Threading::{GetExitCodeProcess, OpenProcess, PROCESS_ALL_ACCESS},
+        Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION},
     },
 };
 
@@ -117,10 +119,25 @@ pub unsafe fn sigaction(sig: int, act: *const sigaction_t, oact: *mut sigaction_
 
 pub unsafe fn kill(pid: pid_t, sig: int) -> int {
     if sig == 0 {
+        let (handle, last_error) = win32call! { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, FALSE, pid),
+        ignore ERROR_INVALID_PARAMETER, ERROR_ACCESS_DENIED };
+        if handle == 0 {
+            // the process exists but is owned by another user
+            if last_error == ERROR_ACCESS_DENIED {
+                return 0;
+            }
+
+            Errno::set(Errno::ESRCH);
+            return -1;
+        }
+
+        // the process object can outlive the process as long as a handle to it exists,
+        // therefore the exit code has to be checked as well
         let mut exit_code = 0;
-        let (handle, _) = win32call! { OpenProcess(PROCESS_ALL_ACCESS, TRUE, pid) };
         let (has_exit_code, _) = win32call! { GetExitCodeProcess(handle, &mut exit_code) };
-        return if has_exit_code == TRUE {
+        win32call! { CloseHandle(handle) };
+
+        return if has_exit_code == TRUE && exit_code == STILL_ACTIVE as u32 {
             0
         } else {
             Errno::set(Errno::ESRCH);
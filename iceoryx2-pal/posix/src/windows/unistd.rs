@@ -16,7 +16,8 @@
 
 use windows_sys::Win32::{
     Foundation::{
-        CloseHandle, ERROR_FILE_NOT_FOUND, ERROR_NO_MORE_FILES, FALSE, INVALID_HANDLE_VALUE, TRUE,
+        CloseHandle, ERROR_FILE_NOT_FOUND, ERROR_NO_MORE_FILES, FALSE, FILETIME,
+        INVALID_HANDLE_VALUE, TRUE,
     },
     Networking::WinSock::closesocket,
     Storage::FileSystem::{
@@ -31,7 +32,10 @@ use windows_sys::Win32::{
         },
         ProcessStatus::GetModuleFileNameExA,
         SystemInformation::{GetSystemInfo, SYSTEM_INFO},
-        Threading::{GetCurrentProcessId, OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ},
+        Threading::{
+            GetCurrentProcessId, GetProcessTimes, OpenProcess, PROCESS_QUERY_INFORMATION,
+            PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
+        },
         IO::OVERLAPPED,
     },
 };
@@ -46,6 +50,7 @@ use super::{settings::MAX_PATH_LENGTH, win32_handle_translator::HandleTranslator
 use crate::win32call;
 
 impl Struct for SYSTEM_INFO {}
+impl Struct for FILETIME {}
 
 pub unsafe fn proc_pidpath(pid: pid_t, buffer: *mut c_char, buffer_len: size_t) -> isize {
     let process_handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, FALSE, pid as _);
@@ -64,6 +69,35 @@ pub unsafe fn proc_pidpath(pid: pid_t, buffer: *mut c_char, buffer_len: size_t)
     ret_val as _
 }
 
+pub unsafe fn proc_pidstarttime(pid: pid_t, start_time: *mut u64) -> int {
+    let process_handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, FALSE, pid as _);
+
+    if process_handle == 0 {
+        return -1;
+    }
+
+    let mut creation_time = FILETIME::new();
+    let mut exit_time = FILETIME::new();
+    let mut kernel_time = FILETIME::new();
+    let mut user_time = FILETIME::new();
+    let ret_val = GetProcessTimes(
+        process_handle,
+        &mut creation_time,
+        &mut exit_time,
+        &mut kernel_time,
+        &mut user_time,
+    );
+    CloseHandle(process_handle);
+
+    if ret_val == FALSE {
+        return -1;
+    }
+
+    *start_time =
+        ((creation_time.dwHighDateTime as u64) << 32) | creation_time.dwLowDateTime as u64;
+    0
+}
+
 pub unsafe fn sysconf(name: int) -> long {
     let mut system_info = SYSTEM_INFO::new();
     win32call! { GetSystemInfo(&mut system_info)};
@@ -146,6 +180,11 @@ pub unsafe fn dup(fildes: int) -> int {
     -1
 }
 
+pub unsafe fn pipe(fildes: *mut int) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
 pub unsafe fn close(fd: int) -> int {
     match HandleTranslator::get_instance().get(fd) {
         Some(FdHandleEntry::SharedMemory(handle)) => {
@@ -0,0 +1,97 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "freebsd")]
+mod freebsd_mman {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_pal_configuration::TEMP_DIRECTORY;
+    use iceoryx2_pal_posix::posix::*;
+
+    fn generate_name() -> String {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        format!(
+            "freebsd_mman_tests_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    }
+
+    fn state_file_path(name: &str) -> String {
+        String::from_utf8_lossy(TEMP_DIRECTORY).to_string() + name + ".shm_state"
+    }
+
+    fn dead_process_id() -> u32 {
+        let mut process = std::process::Command::new("true").spawn().unwrap();
+        let pid = process.id();
+        process.wait().unwrap();
+        pid
+    }
+
+    #[test]
+    fn shm_open_removes_stale_state_file_of_dead_creator() {
+        let name = generate_name();
+        let shm_name = format!("/{}\0", name);
+        std::fs::write(state_file_path(&name), dead_process_id().to_string()).unwrap();
+
+        let fd = unsafe {
+            shm_open(
+                shm_name.as_ptr().cast(),
+                O_CREAT | O_EXCL | O_RDWR,
+                S_IRUSR | S_IWUSR,
+            )
+        };
+        assert_that!(fd, ne - 1);
+
+        unsafe { close(fd) };
+        assert_that!(unsafe { shm_unlink(shm_name.as_ptr().cast()) }, eq 0);
+    }
+
+    #[test]
+    fn shm_open_does_not_remove_state_file_of_living_creator() {
+        let name = generate_name();
+        let shm_name = format!("/{}\0", name);
+        std::fs::write(state_file_path(&name), std::process::id().to_string()).unwrap();
+
+        let fd = unsafe {
+            shm_open(
+                shm_name.as_ptr().cast(),
+                O_CREAT | O_EXCL | O_RDWR,
+                S_IRUSR | S_IWUSR,
+            )
+        };
+        assert_that!(fd, eq - 1);
+        assert_that!(Errno::get() == Errno::EEXIST, eq true);
+
+        std::fs::remove_file(state_file_path(&name)).unwrap();
+    }
+
+    #[test]
+    fn shm_open_does_not_remove_state_file_without_creator() {
+        let name = generate_name();
+        let shm_name = format!("/{}\0", name);
+        std::fs::write(state_file_path(&name), "").unwrap();
+
+        let fd = unsafe {
+            shm_open(
+                shm_name.as_ptr().cast(),
+                O_CREAT | O_EXCL | O_RDWR,
+                S_IRUSR | S_IWUSR,
+            )
+        };
+        assert_that!(fd, eq - 1);
+        assert_that!(Errno::get() == Errno::EEXIST, eq true);
+
+        std::fs::remove_file(state_file_path(&name)).unwrap();
+    }
+}
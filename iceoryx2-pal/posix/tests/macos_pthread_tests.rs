@@ -0,0 +1,82 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "macos")]
+mod macos_pthread {
+    use std::sync::atomic::Ordering;
+    use std::time::{Duration, Instant};
+
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+    use iceoryx2_pal_posix::posix::*;
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+
+    #[test]
+    fn timed_wait_returns_after_timeout() {
+        let sut = IoxAtomicU32::new(0);
+        let mut timeout = timespec::new();
+        unsafe { clock_gettime(CLOCK_REALTIME, &mut timeout) };
+        timeout.tv_nsec += TIMEOUT.as_nanos() as i64;
+        if timeout.tv_nsec >= 1_000_000_000 {
+            timeout.tv_sec += 1;
+            timeout.tv_nsec -= 1_000_000_000;
+        }
+
+        let start = Instant::now();
+        timed_wait(&sut, &0, timeout);
+
+        assert_that!(start.elapsed(), ge TIMEOUT - Duration::from_millis(1));
+    }
+
+    #[test]
+    fn timed_wait_returns_immediately_when_value_differs() {
+        let sut = IoxAtomicU32::new(1);
+        let mut timeout = timespec::new();
+        unsafe { clock_gettime(CLOCK_REALTIME, &mut timeout) };
+        timeout.tv_sec += 10;
+
+        let start = Instant::now();
+        timed_wait(&sut, &0, timeout);
+
+        assert_that!(start.elapsed(), lt Duration::from_secs(10));
+    }
+
+    #[test]
+    fn wait_returns_when_value_is_changed_and_woken_up() {
+        let sut = IoxAtomicU32::new(0);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                std::thread::sleep(TIMEOUT);
+                sut.store(1, Ordering::Relaxed);
+                wake_all(&sut);
+            });
+
+            while sut.load(Ordering::Relaxed) == 0 {
+                wait(&sut, &0);
+            }
+        });
+
+        assert_that!(sut.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn wake_without_waiter_returns() {
+        let sut = IoxAtomicU32::new(0);
+
+        wake_one(&sut);
+        wake_all(&sut);
+
+        assert_that!(sut.load(Ordering::Relaxed), eq 0);
+    }
+}
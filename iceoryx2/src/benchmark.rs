@@ -0,0 +1,764 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measures the latency and the throughput between a
+//! [`Publisher`](crate::port::publisher::Publisher) and a
+//! [`Subscriber`](crate::port::subscriber::Subscriber) so that the performance numbers of
+//! iceoryx2 can be reproduced on the target system and regressions can be detected in
+//! integration tests.
+//!
+//! The [`Benchmark`] creates its own uniquely named publish-subscribe services with the
+//! provided [`Node`] and runs every participant in a dedicated thread that can be pinned to a
+//! CPU core.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::benchmark::Benchmark;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let benchmark = Benchmark::new()
+//!     .iterations(1000)
+//!     .warmup_iterations(100)
+//!     .payload_size(64);
+//!
+//! let latency = benchmark.latency(&node)?;
+//! println!("median latency: {:?}", latency.percentile(50.0));
+//! println!("99.9th percentile: {:?}", latency.percentile(99.9));
+//!
+//! let throughput = benchmark.throughput(&node)?;
+//! println!("{} samples/s", throughput.samples_per_second());
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Display;
+use core::time::Duration;
+use std::mem::MaybeUninit;
+use std::sync::atomic::Ordering;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Instant;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::thread::ThreadBuilder;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU32, IoxAtomicU64};
+
+use crate::node::Node;
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+const DEFAULT_ITERATIONS: u64 = 10000;
+const DEFAULT_WARMUP_ITERATIONS: u64 = 1000;
+const DEFAULT_PAYLOAD_SIZE: usize = 8192;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+// number of polls after which a waiting participant checks the timeout, reading the clock on
+// every poll would distort the latency measurement
+const POLLS_PER_TIMEOUT_CHECK: u64 = 1024;
+const THROUGHPUT_SUBSCRIBER_BUFFER_SIZE: usize = 16;
+// both participants and the thread that runs the benchmark
+const NUMBER_OF_PARTICIPANTS: u32 = 3;
+// latencies below 2^HISTOGRAM_SUB_BUCKET_BITS ns are recorded exactly, larger latencies with
+// a relative resolution of 2^-(HISTOGRAM_SUB_BUCKET_BITS - 1)
+const HISTOGRAM_SUB_BUCKET_BITS: u32 = 7;
+const HISTOGRAM_SUB_BUCKETS: u64 = 1 << HISTOGRAM_SUB_BUCKET_BITS;
+const HISTOGRAM_HALF_SUB_BUCKETS: u64 = HISTOGRAM_SUB_BUCKETS / 2;
+const HISTOGRAM_BUCKETS: usize = (HISTOGRAM_SUB_BUCKETS
+    + (u64::BITS - HISTOGRAM_SUB_BUCKET_BITS) as u64 * HISTOGRAM_HALF_SUB_BUCKETS)
+    as usize;
+
+/// Synchronizes the start of the participants and records the first failure. A participant
+/// that failed to create its ports or a failed thread creation releases all waiting
+/// participants.
+struct StartCondition {
+    ready: IoxAtomicU32,
+    has_failed: IoxAtomicBool,
+    failure: Mutex<Option<BenchmarkError>>,
+}
+
+impl StartCondition {
+    fn new() -> Self {
+        Self {
+            ready: IoxAtomicU32::new(0),
+            has_failed: IoxAtomicBool::new(false),
+            failure: Mutex::new(None),
+        }
+    }
+
+    fn fail(&self, error: BenchmarkError) {
+        lock(&self.failure).get_or_insert(error);
+        self.has_failed.store(true, Ordering::Relaxed);
+    }
+
+    fn failure(&self) -> Option<BenchmarkError> {
+        *lock(&self.failure)
+    }
+
+    fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::Relaxed)
+    }
+
+    fn wait(&self) {
+        self.ready.fetch_add(1, Ordering::Release);
+        while self.ready.load(Ordering::Acquire) < NUMBER_OF_PARTICIPANTS && !self.has_failed() {
+            std::thread::yield_now();
+        }
+    }
+}
+
+// a participant that panicked while holding the lock cannot leave an inconsistent value behind
+// since all values are replaced as a whole
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(e) => e.into_inner(),
+    }
+}
+
+fn into_inner<T>(mutex: Mutex<T>) -> T {
+    match mutex.into_inner() {
+        Ok(value) => value,
+        Err(e) => e.into_inner(),
+    }
+}
+
+/// Failures that can occur when a [`Benchmark`] is executed.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum BenchmarkError {
+    /// The publish-subscribe services of the benchmark could not be created.
+    ServiceCreationFailed,
+    /// The publisher or subscriber of a participant could not be created.
+    PortCreationFailed,
+    /// The thread of a participant could not be spawned.
+    ThreadCreationFailed,
+    /// A sample could not be loaned, sent or received while the benchmark was running.
+    CommunicationFailure,
+    /// A participant did not make any progress within the timeout defined with
+    /// [`Benchmark::timeout()`].
+    Timeout,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for BenchmarkError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "BenchmarkError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BenchmarkError {}
+
+/// Records the latencies of a measurement in constant memory, independent of the number of
+/// iterations. The minimum, maximum and mean are exact, the percentiles are acquired from
+/// buckets whose width grows with the latency.
+#[derive(Clone)]
+struct LatencyHistogram {
+    count: u64,
+    min: u64,
+    max: u64,
+    sum: u128,
+    buckets: Vec<u64>,
+}
+
+impl core::fmt::Debug for LatencyHistogram {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "LatencyHistogram {{ count: {}, min: {}, max: {}, sum: {} }}",
+            self.count, self.min, self.max, self.sum
+        )
+    }
+}
+
+impl LatencyHistogram {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            min: u64::MAX,
+            max: 0,
+            sum: 0,
+            buckets: vec![0; HISTOGRAM_BUCKETS],
+        }
+    }
+
+    fn bucket_index(nanos: u64) -> usize {
+        if nanos < HISTOGRAM_SUB_BUCKETS {
+            return nanos as usize;
+        }
+
+        // keeps the HISTOGRAM_SUB_BUCKET_BITS most significant bits
+        let shift = u64::BITS - nanos.leading_zeros() - HISTOGRAM_SUB_BUCKET_BITS;
+        let mantissa = nanos >> shift;
+        (HISTOGRAM_SUB_BUCKETS
+            + (shift - 1) as u64 * HISTOGRAM_HALF_SUB_BUCKETS
+            + (mantissa - HISTOGRAM_HALF_SUB_BUCKETS)) as usize
+    }
+
+    fn bucket_upper_bound(index: usize) -> u64 {
+        let index = index as u64;
+        if index < HISTOGRAM_SUB_BUCKETS {
+            return index;
+        }
+
+        let shift = (index - HISTOGRAM_SUB_BUCKETS) / HISTOGRAM_HALF_SUB_BUCKETS + 1;
+        let mantissa = (index - HISTOGRAM_SUB_BUCKETS) % HISTOGRAM_HALF_SUB_BUCKETS
+            + HISTOGRAM_HALF_SUB_BUCKETS;
+        (mantissa << shift).saturating_add((1 << shift) - 1)
+    }
+
+    fn record(&mut self, latency: Duration) {
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.count += 1;
+        self.min = self.min.min(nanos);
+        self.max = self.max.max(nanos);
+        self.sum += nanos as u128;
+        self.buckets[Self::bucket_index(nanos)] += 1;
+    }
+}
+
+/// The result of [`Benchmark::latency()`]. All values are the one-way latency, the half of
+/// the measured round trip between the two participants.
+#[derive(Debug, Clone)]
+pub struct LatencyReport {
+    payload_size: usize,
+    histogram: LatencyHistogram,
+}
+
+impl LatencyReport {
+    /// Returns the number of measured iterations, the warmup is not included.
+    pub fn iterations(&self) -> u64 {
+        self.histogram.count
+    }
+
+    /// Returns the payload size in bytes of the samples that were exchanged.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns the smallest measured latency.
+    pub fn min(&self) -> Duration {
+        match self.histogram.count {
+            0 => Duration::ZERO,
+            _ => Duration::from_nanos(self.histogram.min),
+        }
+    }
+
+    /// Returns the largest measured latency.
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.histogram.max)
+    }
+
+    /// Returns the average of all measured latencies.
+    pub fn mean(&self) -> Duration {
+        if self.histogram.count == 0 {
+            return Duration::ZERO;
+        }
+
+        Duration::from_nanos((self.histogram.sum / self.histogram.count as u128) as u64)
+    }
+
+    /// Returns the latency below which the provided percentage of all measurements lies,
+    /// e.g. `percentile(99.9)`. The percentage is clamped to the range `[0.0, 100.0]`.
+    /// Latencies from 128ns on are reported with a resolution of 1/64 (about 1.6%) of their
+    /// value, the smallest and the largest latency are exact.
+    pub fn percentile(&self, percentage: f64) -> Duration {
+        let count = self.histogram.count;
+        if count == 0 {
+            return Duration::ZERO;
+        }
+
+        let percentage = percentage.clamp(0.0, 100.0);
+        let rank = ((percentage / 100.0 * count as f64).ceil() as u64).clamp(1, count);
+        if rank == 1 {
+            return self.min();
+        }
+        if rank == count {
+            return self.max();
+        }
+
+        let mut number_of_latencies = 0;
+        for (index, bucket) in self.histogram.buckets.iter().enumerate() {
+            number_of_latencies += bucket;
+            if rank <= number_of_latencies {
+                let nanos = LatencyHistogram::bucket_upper_bound(index)
+                    .clamp(self.histogram.min, self.histogram.max);
+                return Duration::from_nanos(nanos);
+            }
+        }
+
+        self.max()
+    }
+}
+
+impl Display for LatencyReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Iterations: {}, Sample Size: {}, Latency [ns]: min {}, mean {}, p50 {}, p90 {}, p99 {}, p99.9 {}, max {}",
+            self.iterations(),
+            self.payload_size,
+            self.min().as_nanos(),
+            self.mean().as_nanos(),
+            self.percentile(50.0).as_nanos(),
+            self.percentile(90.0).as_nanos(),
+            self.percentile(99.0).as_nanos(),
+            self.percentile(99.9).as_nanos(),
+            self.max().as_nanos()
+        )
+    }
+}
+
+/// The result of [`Benchmark::throughput()`].
+#[derive(Debug, Clone, Copy)]
+pub struct ThroughputReport {
+    iterations: u64,
+    payload_size: usize,
+    duration: Duration,
+}
+
+impl ThroughputReport {
+    /// Returns the number of samples that were received, the warmup is not included.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Returns the payload size in bytes of the samples that were exchanged.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns the time it took to send and receive all samples.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the number of samples that were received per second. When no sample was
+    /// measured or the duration was too short to be measured, it returns 0.
+    pub fn samples_per_second(&self) -> f64 {
+        if self.iterations == 0 || self.duration.is_zero() {
+            return 0.0;
+        }
+
+        self.iterations as f64 / self.duration.as_secs_f64()
+    }
+
+    /// Returns the number of payload bytes that were received per second.
+    pub fn bytes_per_second(&self) -> f64 {
+        self.samples_per_second() * self.payload_size as f64
+    }
+}
+
+impl Display for ThroughputReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Iterations: {}, Sample Size: {}, Time: {}, Throughput: {:.0} samples/s, {:.0} bytes/s",
+            self.iterations,
+            self.payload_size,
+            self.duration.as_secs_f64(),
+            self.samples_per_second(),
+            self.bytes_per_second()
+        )
+    }
+}
+
+/// Configures and executes the latency and throughput measurements between a
+/// [`Publisher`] and a [`Subscriber`](crate::port::subscriber::Subscriber).
+#[derive(Debug, Clone)]
+pub struct Benchmark {
+    iterations: u64,
+    warmup_iterations: u64,
+    payload_size: usize,
+    send_copy: bool,
+    cpu_core_participant_1: Option<usize>,
+    cpu_core_participant_2: Option<usize>,
+    priority: u8,
+    timeout: Duration,
+}
+
+impl Default for Benchmark {
+    fn default() -> Self {
+        Self {
+            iterations: DEFAULT_ITERATIONS,
+            warmup_iterations: DEFAULT_WARMUP_ITERATIONS,
+            payload_size: DEFAULT_PAYLOAD_SIZE,
+            send_copy: false,
+            cpu_core_participant_1: None,
+            cpu_core_participant_2: None,
+            priority: 255,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl Benchmark {
+    /// Creates a new [`Benchmark`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines how many samples are measured.
+    pub fn iterations(mut self, value: u64) -> Self {
+        self.iterations = value;
+        self
+    }
+
+    /// Defines how many samples are exchanged before the measurement starts to warm up the
+    /// caches and the data segment.
+    pub fn warmup_iterations(mut self, value: u64) -> Self {
+        self.warmup_iterations = value;
+        self
+    }
+
+    /// Defines the payload size in bytes of every sample.
+    pub fn payload_size(mut self, value: usize) -> Self {
+        self.payload_size = value;
+        self
+    }
+
+    /// When enabled, the whole payload is written before a sample is sent so that the
+    /// measurement includes the cost of producing the data.
+    pub fn send_copy(mut self, value: bool) -> Self {
+        self.send_copy = value;
+        self
+    }
+
+    /// Pins the thread of the first participant, the one that starts the communication, to
+    /// the provided CPU core.
+    pub fn cpu_core_participant_1(mut self, value: usize) -> Self {
+        self.cpu_core_participant_1 = Some(value);
+        self
+    }
+
+    /// Pins the thread of the second participant to the provided CPU core.
+    pub fn cpu_core_participant_2(mut self, value: usize) -> Self {
+        self.cpu_core_participant_2 = Some(value);
+        self
+    }
+
+    /// Defines the thread priority of both participants, 0 is the lowest and 255 the highest
+    /// priority of the scheduler.
+    pub fn priority(mut self, value: u8) -> Self {
+        self.priority = value;
+        self
+    }
+
+    /// Defines how long a participant waits for the other participant before the benchmark
+    /// fails with [`BenchmarkError::Timeout`]. It prevents the benchmark from blocking forever
+    /// when a participant stops responding.
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    /// Measures the latency of every iteration with a ping-pong between two participants.
+    /// The first participant sends a sample to the second one which replies with a sample
+    /// of its own.
+    pub fn latency<S: Service>(&self, node: &Node<S>) -> Result<LatencyReport, BenchmarkError> {
+        let service_a2b = self.create_service(node, "a2b", 1, true)?;
+        let service_b2a = self.create_service(node, "b2a", 1, true)?;
+        let start = StartCondition::new();
+        let histogram = Mutex::new(LatencyHistogram::new());
+        let total_iterations = self.warmup_iterations + self.iterations;
+
+        let participant_1 = || {
+            let ports = (
+                service_a2b
+                    .publisher_builder()
+                    .max_slice_len(self.payload_size)
+                    .create(),
+                service_b2a.subscriber_builder().create(),
+            );
+            if ports.0.is_err() || ports.1.is_err() {
+                start.fail(BenchmarkError::PortCreationFailed);
+            }
+            start.wait();
+
+            let (Ok(publisher), Ok(subscriber)) = ports else {
+                return;
+            };
+            if start.has_failed() {
+                return;
+            }
+
+            let mut measurements = LatencyHistogram::new();
+            for n in 0..total_iterations {
+                let round_trip_start = Instant::now();
+                if let Err(e) = self
+                    .send(&publisher)
+                    .and_then(|_| self.receive(&start, &subscriber))
+                {
+                    start.fail(e);
+                    return;
+                }
+
+                if n >= self.warmup_iterations {
+                    measurements.record(round_trip_start.elapsed() / 2);
+                }
+            }
+
+            *lock(&histogram) = measurements;
+        };
+
+        let participant_2 = || {
+            let ports = (
+                service_b2a
+                    .publisher_builder()
+                    .max_slice_len(self.payload_size)
+                    .create(),
+                service_a2b.subscriber_builder().create(),
+            );
+            if ports.0.is_err() || ports.1.is_err() {
+                start.fail(BenchmarkError::PortCreationFailed);
+            }
+            start.wait();
+
+            let (Ok(publisher), Ok(subscriber)) = ports else {
+                return;
+            };
+            if start.has_failed() {
+                return;
+            }
+
+            for _ in 0..total_iterations {
+                if let Err(e) = self
+                    .receive(&start, &subscriber)
+                    .and_then(|_| self.send(&publisher))
+                {
+                    start.fail(e);
+                    return;
+                }
+            }
+        };
+
+        self.run(&start, participant_1, participant_2)?;
+
+        Ok(LatencyReport {
+            payload_size: self.payload_size,
+            histogram: into_inner(histogram),
+        })
+    }
+
+    /// Measures how many samples the first participant can send to the second participant
+    /// when the subscriber buffer is full and the publisher has to wait until the
+    /// subscriber consumed a sample.
+    ///
+    /// The publisher does not use [`UnableToDeliverStrategy::Block`] since it would block
+    /// forever when the subscriber fails with a full buffer. Instead, it waits until the
+    /// subscriber has consumed a sample and stops when the subscriber failed or the timeout
+    /// has passed.
+    pub fn throughput<S: Service>(
+        &self,
+        node: &Node<S>,
+    ) -> Result<ThroughputReport, BenchmarkError> {
+        let service =
+            self.create_service(node, "throughput", THROUGHPUT_SUBSCRIBER_BUFFER_SIZE, false)?;
+        let start = StartCondition::new();
+        let duration = Mutex::new(Duration::ZERO);
+        let received_samples = IoxAtomicU64::new(0);
+        let total_iterations = self.warmup_iterations + self.iterations;
+
+        let participant_1 = || {
+            let publisher = service
+                .publisher_builder()
+                .max_slice_len(self.payload_size)
+                .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+                .create();
+            if publisher.is_err() {
+                start.fail(BenchmarkError::PortCreationFailed);
+            }
+            start.wait();
+
+            let Ok(publisher) = publisher else {
+                return;
+            };
+            if start.has_failed() {
+                return;
+            }
+
+            for n in 0..total_iterations {
+                // blocks while the subscriber buffer is full
+                let has_free_buffer_slot = || {
+                    Ok(n - received_samples.load(Ordering::Acquire)
+                        < THROUGHPUT_SUBSCRIBER_BUFFER_SIZE as u64)
+                };
+                if let Err(e) = self
+                    .wait_until(&start, has_free_buffer_slot)
+                    .and_then(|_| self.send(&publisher))
+                {
+                    start.fail(e);
+                    return;
+                }
+            }
+        };
+
+        let participant_2 = || {
+            let subscriber = service
+                .subscriber_builder()
+                .buffer_size(THROUGHPUT_SUBSCRIBER_BUFFER_SIZE)
+                .create();
+            if subscriber.is_err() {
+                start.fail(BenchmarkError::PortCreationFailed);
+            }
+            start.wait();
+
+            let Ok(subscriber) = subscriber else {
+                return;
+            };
+            if start.has_failed() {
+                return;
+            }
+
+            let mut measurement_start = Instant::now();
+            for n in 0..total_iterations {
+                if n == self.warmup_iterations {
+                    measurement_start = Instant::now();
+                }
+
+                if let Err(e) = self.receive(&start, &subscriber) {
+                    start.fail(e);
+                    return;
+                }
+                received_samples.fetch_add(1, Ordering::Release);
+            }
+
+            *lock(&duration) = measurement_start.elapsed();
+        };
+
+        self.run(&start, participant_1, participant_2)?;
+
+        Ok(ThroughputReport {
+            iterations: self.iterations,
+            payload_size: self.payload_size,
+            duration: into_inner(duration),
+        })
+    }
+
+    fn create_service<S: Service>(
+        &self,
+        node: &Node<S>,
+        suffix: &str,
+        buffer_size: usize,
+        enable_safe_overflow: bool,
+    ) -> Result<PortFactory<S, [u8], ()>, BenchmarkError> {
+        let msg = "Unable to create benchmark service";
+        let unique_id = fail!(from self, when UniqueSystemId::new(),
+                            with BenchmarkError::InternalError,
+                            "{} since a unique service name could not be generated.", msg);
+        let service_name = fail!(from self,
+                            when ServiceName::new(&format!("iox2_benchmark_{}_{}", unique_id.value(), suffix)),
+                            with BenchmarkError::InternalError,
+                            "{} since the service name is invalid.", msg);
+
+        Ok(fail!(from self, when node
+                .service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .max_publishers(1)
+                .max_subscribers(1)
+                .history_size(0)
+                .subscriber_max_buffer_size(buffer_size)
+                .enable_safe_overflow(enable_safe_overflow)
+                .create(),
+            with BenchmarkError::ServiceCreationFailed,
+            "{} since the service \"{}\" could not be created.", msg, service_name))
+    }
+
+    fn send<S: Service>(&self, publisher: &Publisher<S, [u8], ()>) -> Result<(), BenchmarkError> {
+        let mut sample = publisher
+            .loan_slice_uninit(self.payload_size)
+            .map_err(|_| BenchmarkError::CommunicationFailure)?;
+        if self.send_copy {
+            sample.payload_mut().fill(MaybeUninit::new(0));
+        }
+
+        unsafe { sample.assume_init() }
+            .send()
+            .map_err(|_| BenchmarkError::CommunicationFailure)?;
+        Ok(())
+    }
+
+    fn receive<S: Service>(
+        &self,
+        start: &StartCondition,
+        subscriber: &Subscriber<S, [u8], ()>,
+    ) -> Result<(), BenchmarkError> {
+        self.wait_until(start, || match subscriber.receive() {
+            Ok(sample) => Ok(sample.is_some()),
+            Err(_) => Err(BenchmarkError::CommunicationFailure),
+        })
+    }
+
+    /// Polls the condition until it is satisfied. Fails when the other participant failed or
+    /// when the condition was not satisfied within the timeout.
+    fn wait_until<F: FnMut() -> Result<bool, BenchmarkError>>(
+        &self,
+        start: &StartCondition,
+        mut condition: F,
+    ) -> Result<(), BenchmarkError> {
+        let wait_start = Instant::now();
+        let mut polls: u64 = 0;
+        while !condition()? {
+            if start.has_failed() {
+                return Err(BenchmarkError::CommunicationFailure);
+            }
+
+            polls += 1;
+            if polls % POLLS_PER_TIMEOUT_CHECK == 0 && wait_start.elapsed() >= self.timeout {
+                return Err(BenchmarkError::Timeout);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run<P1: FnOnce() + Send, P2: FnOnce() + Send>(
+        &self,
+        start: &StartCondition,
+        participant_1: P1,
+        participant_2: P2,
+    ) -> Result<(), BenchmarkError> {
+        let msg = "Unable to run benchmark";
+        let thread_builder = |cpu_core: Option<usize>| {
+            let builder = ThreadBuilder::new().priority(self.priority);
+            match cpu_core {
+                Some(cpu_core) => builder.affinity(cpu_core),
+                None => builder,
+            }
+        };
+
+        let t1 = thread_builder(self.cpu_core_participant_1).spawn(participant_1);
+        let t2 = thread_builder(self.cpu_core_participant_2).spawn(participant_2);
+
+        if t1.is_err() || t2.is_err() {
+            // releases the participant that was spawned successfully
+            start.fail(BenchmarkError::ThreadCreationFailed);
+        } else {
+            start.wait();
+        }
+        drop(t1);
+        drop(t2);
+
+        if let Some(failure) = start.failure() {
+            fail!(from self, with failure,
+                "{} since a participant failed with {:?}.", msg, failure);
+        }
+
+        Ok(())
+    }
+}
@@ -53,3 +53,22 @@ fn sending_uninitialized_sample_fails_to_compile() {}
 /// ```
 #[cfg(doctest)]
 fn loan_with_type_not_implementing_default_fails_to_compile() {}
+
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Wrapper(Box<u64>);
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let node = NodeBuilder::new().create::<ipc::Service>()?;
+///
+/// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+///     .publish_subscribe::<Wrapper>() // should fail to compile since 'Wrapper' does not implement 'ZeroCopySend'
+///     .open_or_create()?;
+///
+/// Ok(())
+/// }
+/// ```
+#[cfg(doctest)]
+fn publish_subscribe_with_payload_not_implementing_zero_copy_send_fails_to_compile() {}
@@ -36,6 +36,34 @@
 //! # }
 //! ```
 //!
+//! ## Multiple Instances On One Host
+//!
+//! Every iceoryx2 instance is defined by its [`Global::root_path()`] and its [`Global::prefix`].
+//! Resources without a path, like POSIX shared memory, are only separated by the prefix,
+//! therefore instances that share them, like different users on the same host or containers
+//! that share `/dev/shm`, require a unique prefix each. [`Global::set_domain()`] adjusts both
+//! at once. When two instances with different root paths use the same prefix, the
+//! [`NodeBuilder`](crate::node::NodeBuilder) fails with
+//! [`NodeCreationFailure::PrefixCollision`](crate::node::NodeCreationFailure::PrefixCollision)
+//! and reports the root path and owner of the other instance. The prefix stays owned by the
+//! other instance even when it has no more nodes, its resources must be removed before another
+//! instance can use the prefix.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut config = Config::default();
+//! config.global.set_domain(&"container_a".try_into()?)?;
+//!
+//! let node = NodeBuilder::new()
+//!     .config(&config)
+//!     .create::<ipc::Service>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Set Global Config From Custom File
 //!
 //! The [`crate::config::Config::setup_global_config_from_file()`] call must be the first
@@ -68,23 +96,78 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Environment Overrides
+//!
+//! Every setting can be overridden with an environment variable that starts with
+//! [`ENV_VAR_PREFIX`] followed by the upper case path of the setting, where `.` and `-` are
+//! replaced with `_`, e.g. `IOX2_GLOBAL_PREFIX` overrides `global.prefix` and
+//! `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS` overrides
+//! `defaults.publish-subscribe.max-publishers`. The settings are taken in the following
+//! order, the latter overrides the former:
+//!
+//!  1. the built-in [`Config::default()`]
+//!  2. the config file, either [`DEFAULT_CONFIG_FILE`] or the one provided to
+//!     [`Config::setup_global_config_from_file()`]
+//!  3. the environment variables
+//!
+//! The global config applies the environment overrides automatically, a custom [`Config`]
+//! applies them with [`Config::apply_env_overrides()`]. The [`Log`] settings are overridden
+//! when they are applied, see [`LOG_LEVEL_ENV_VAR`].
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut config = Config::from_file(&FilePath::new(b"my/custom/config/file.toml")?)?;
+//! config.apply_env_overrides();
+//!
+//! // store the effective config for the next deployment
+//! config.save(&FilePath::new(b"my/effective/config/file.toml")?)?;
+//! # Ok(())
+//! # }
+//! ```
 
-use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_container::semantic_string::{SemanticString, SemanticStringError};
 use iceoryx2_bb_elementary::lazy_singleton::*;
-use iceoryx2_bb_posix::{file::FileBuilder, shared_memory::AccessMode};
+use iceoryx2_bb_posix::file::{CreationMode, FileBuilder, Permission};
+use iceoryx2_bb_posix::shared_memory::AccessMode;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
-use iceoryx2_bb_log::{debug, fail, trace, warn};
+use iceoryx2_bb_log::{debug, fail, logger, set_log_level, trace, warn};
 
-use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::port_factory::publisher::{AllocationStrategy, UnableToDeliverStrategy};
+use crate::service::port_factory::subscriber::{SampleOrdering, SubscriberOverflowStrategy};
 
 /// Path to the default config file
 pub const DEFAULT_CONFIG_FILE: &[u8] = b"config/iceoryx2.toml";
 
+/// The directory below the root path that contains the root paths of all domains, see
+/// [`Global::set_domain()`].
+pub const DOMAIN_DIRECTORY: &[u8] = b"domains";
+
+/// The prefix of the environment variables that override the settings of a [`Config`], see
+/// [`Config::apply_env_overrides()`].
+pub const ENV_VAR_PREFIX: &str = "IOX2_";
+
+/// Environment variable that overrides [`Log::level`], e.g. `IOX2_LOG_LEVEL=debug`
+pub const LOG_LEVEL_ENV_VAR: &str = "IOX2_LOG_LEVEL";
+
+/// Environment variable that overrides [`Log::format`], e.g. `IOX2_LOG_FORMAT=json`
+pub const LOG_FORMAT_ENV_VAR: &str = "IOX2_LOG_FORMAT";
+
+/// Environment variable that overrides [`Log::target`], e.g. `IOX2_LOG_TARGET=stderr`
+pub const LOG_TARGET_ENV_VAR: &str = "IOX2_LOG_TARGET";
+
+/// Environment variable that overrides [`Log::file`], e.g. `IOX2_LOG_FILE=/var/log/app.log`
+pub const LOG_FILE_ENV_VAR: &str = "IOX2_LOG_FILE";
+
 /// Failures occurring while creating a new [`Config`] object with [`Config::from_file()`] or
 /// [`Config::setup_global_config_from_file()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -105,6 +188,105 @@ impl std::fmt::Display for ConfigCreationError {
 
 impl std::error::Error for ConfigCreationError {}
 
+/// Failures occurring while storing a [`Config`] with [`Config::save()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigSaveError {
+    /// The config file could not be created.
+    FailedToCreateConfigFile,
+    /// The contents could not be written into the config file.
+    FailedToWriteConfigFileContents,
+    /// The [`Config`] could not be serialized.
+    UnableToSerializeContents,
+}
+
+impl std::fmt::Display for ConfigSaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ConfigSaveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ConfigSaveError {}
+
+/// The severity of a [`ConfigDiagnostic`] that is reported by [`Config::validate()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ConfigDiagnosticSeverity {
+    /// The setting is not supported and is adjusted or ignored when it is used.
+    Warning,
+    /// The setting lets the creation of a [`Node`](crate::node::Node), a
+    /// [`Service`](crate::service::Service) or a port fail or leads to ports that cannot be used.
+    Error,
+}
+
+/// Categorizes the finding of a [`ConfigDiagnostic`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigDiagnosticKind {
+    /// The value is outside of the supported range.
+    OutOfRange,
+    /// The resources cannot be named with the configured prefix, paths or suffixes.
+    InvalidName,
+    /// The value contradicts another setting.
+    ConflictingSettings,
+}
+
+/// A finding of [`Config::validate()`]. It names the setting in the notation of the config
+/// file, e.g. `defaults.publish-subscribe.max-publishers`.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ConfigDiagnostic {
+    severity: ConfigDiagnosticSeverity,
+    kind: ConfigDiagnosticKind,
+    setting: &'static str,
+    message: String,
+}
+
+impl ConfigDiagnostic {
+    fn new(
+        severity: ConfigDiagnosticSeverity,
+        kind: ConfigDiagnosticKind,
+        setting: &'static str,
+        message: String,
+    ) -> Self {
+        Self {
+            severity,
+            kind,
+            setting,
+            message,
+        }
+    }
+
+    /// Returns the [`ConfigDiagnosticSeverity`]
+    pub fn severity(&self) -> ConfigDiagnosticSeverity {
+        self.severity
+    }
+
+    /// Returns the [`ConfigDiagnosticKind`]
+    pub fn kind(&self) -> ConfigDiagnosticKind {
+        self.kind
+    }
+
+    /// Returns the setting in the notation of the config file
+    pub fn setting(&self) -> &str {
+        self.setting
+    }
+
+    /// Returns a human readable description of the finding
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "{:?}: {} ({:?}): {}",
+            self.severity,
+            self.setting,
+            self.kind,
+            self.message
+        )
+    }
+}
+
 /// All configurable settings of a [`crate::service::Service`].
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -125,6 +307,10 @@ pub struct Service {
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// The suffix of the queue that transfers the payload of an event from a
+    /// [`crate::port::notifier::Notifier`] to a [`crate::port::listener::Listener`]
+    #[serde(default = "serde_defaults::event_payload_suffix")]
+    pub event_payload_suffix: FileName,
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -140,6 +326,14 @@ pub struct Node {
     pub static_config_suffix: FileName,
     /// The suffix of the service tags.
     pub service_tag_suffix: FileName,
+    /// The suffix of the heartbeat storage of a [`Node`](crate::node::Node) that was created
+    /// with a liveness deadline.
+    #[serde(default = "serde_defaults::heartbeat_suffix")]
+    pub heartbeat_suffix: FileName,
+    /// The suffix of the marker that identifies the iceoryx2 instance that owns the
+    /// [`Global::prefix`], see [`NodeCreationFailure::PrefixCollision`](crate::node::NodeCreationFailure::PrefixCollision).
+    #[serde(default = "serde_defaults::instance_suffix")]
+    pub instance_suffix: FileName,
     /// When true, the [`NodeBuilder`](crate::node::NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](crate::node::Node) is
     /// created.
@@ -180,6 +374,33 @@ impl Global {
         path
     }
 
+    /// The absolute path to the directory that contains the root paths of all domains
+    pub fn domain_dir(&self) -> Path {
+        let mut path = *self.root_path();
+        path.add_path_entry(&Path::new(DOMAIN_DIRECTORY).unwrap())
+            .unwrap();
+        path
+    }
+
+    /// Moves all resources into the provided domain. The root path becomes a subdirectory of
+    /// [`Global::domain_dir()`] and the domain is appended to the prefix, so that resources
+    /// without a path, like POSIX shared memory, are separated as well. Entities of different
+    /// domains cannot discover or communicate with each other.
+    ///
+    /// Fails when the resulting root path or prefix exceeds the maximum supported length.
+    pub fn set_domain(&mut self, domain: &FileName) -> Result<(), SemanticStringError> {
+        let mut root_path = self.domain_dir();
+        root_path.add_path_entry(&domain.into())?;
+
+        let mut prefix = self.prefix;
+        prefix.push_bytes(domain.as_bytes())?;
+        prefix.push(b'_')?;
+
+        self.set_root_path(&root_path);
+        self.prefix = prefix;
+        Ok(())
+    }
+
     /// The path under which all other directories or files will be created
     pub fn root_path(&self) -> &Path {
         #[cfg(target_os = "windows")]
@@ -215,6 +436,9 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern blackboard
+    #[serde(default)]
+    pub blackboard: Blackboard,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -255,6 +479,91 @@ pub struct PublishSubscribe {
     /// disconnected from a service and the connection
     /// still contains unconsumed [`Sample`](crate::sample::Sample)s.
     pub subscriber_expired_connection_buffer: usize,
+    /// The [`MemoryPool`]s a [`crate::port::publisher::Publisher`] with a slice payload uses
+    /// instead of a single data segment that is sized for the maximum slice length. When it is
+    /// empty every sample occupies the memory of the largest possible sample.
+    #[serde(default)]
+    pub publisher_memory_pools: Vec<MemoryPool>,
+    /// When enabled, the data segment of every [`crate::port::publisher::Publisher`] is locked
+    /// into the RAM so that it is never swapped out. It is locked by the publisher and by every
+    /// [`crate::port::subscriber::Subscriber`] that maps it.
+    #[serde(default)]
+    pub publisher_memory_lock: bool,
+    /// When enabled, every page of the data segment of a [`crate::port::publisher::Publisher`]
+    /// is touched when the publisher creates it and when a
+    /// [`crate::port::subscriber::Subscriber`] maps it to avoid page faults in the real-time
+    /// path.
+    #[serde(default)]
+    pub publisher_prefault_memory: bool,
+    /// The default of [`crate::service::builder::publish_subscribe::Builder::huge_pages()`].
+    /// When enabled, the data segments of the [`crate::port::publisher::Publisher`]s of a newly
+    /// created service are backed by transparent huge pages to reduce the TLB pressure of large
+    /// payloads.
+    #[serde(default)]
+    pub huge_pages: bool,
+    /// Defines what happens when a [`crate::port::publisher::Publisher`] of a service with
+    /// huge pages is created on a system that does not support them. When enabled, the data
+    /// segment falls back to regular pages, otherwise the creation of the
+    /// [`crate::port::publisher::Publisher`] fails.
+    #[serde(default = "serde_defaults::huge_pages_fallback")]
+    pub huge_pages_fallback: bool,
+    /// The default of
+    /// [`crate::service::builder::publish_subscribe::Builder::publisher_max_loaned_samples_ceiling()`].
+    /// Defines up to which value a [`crate::port::publisher::Publisher`] can raise its maximum
+    /// number of loaned samples at runtime.
+    #[serde(default = "serde_defaults::publisher_max_loaned_samples_ceiling")]
+    pub publisher_max_loaned_samples_ceiling: usize,
+    /// The default of
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::buffer_size()`]. When
+    /// it is not set, a [`crate::port::subscriber::Subscriber`] uses the maximum buffer size of
+    /// the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_buffer_size: Option<usize>,
+    /// The default of
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::overflow_strategy()`].
+    /// When it is not set, the safe overflow setting of the service and the
+    /// [`UnableToDeliverStrategy`] of the [`crate::port::publisher::Publisher`]s decide.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_overflow_strategy: Option<SubscriberOverflowStrategy>,
+    /// The default of
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::sample_ordering()`].
+    #[serde(default = "serde_defaults::subscriber_sample_ordering")]
+    pub subscriber_sample_ordering: SampleOrdering,
+    /// The default of
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::allocation_strategy()`].
+    #[serde(default = "serde_defaults::publisher_allocation_strategy")]
+    pub publisher_allocation_strategy: AllocationStrategy,
+    /// The default of
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::statistics()`]. When
+    /// enabled, every [`crate::port::publisher::Publisher`] counts its published and dropped
+    /// samples in the [`ServiceStatistics`](crate::service::statistics::ServiceStatistics).
+    #[serde(default)]
+    pub publisher_statistics: bool,
+}
+
+/// Defines a memory pool of the data segment of a [`crate::port::publisher::Publisher`]. A
+/// sample is always loaned from the smallest memory pool that can hold its payload and that has
+/// free samples left.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MemoryPool {
+    /// The maximum payload size in bytes a sample of the memory pool can hold.
+    pub payload_size: usize,
+    /// The number of samples the memory pool provides. It is increased to the number of samples
+    /// the [`crate::port::publisher::Publisher`] can have in flight when it is smaller.
+    pub number_of_samples: usize,
+}
+
+impl MemoryPool {
+    /// Creates a new [`MemoryPool`] with `number_of_samples` samples that can each hold a
+    /// payload of up to `payload_size` bytes.
+    pub fn new(payload_size: usize, number_of_samples: usize) -> Self {
+        Self {
+            payload_size,
+            number_of_samples,
+        }
+    }
 }
 
 /// Default settings for the event messaging pattern. These settings are used unless
@@ -274,22 +583,277 @@ pub struct Event {
     pub event_id_max_value: usize,
 }
 
-/// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+}
+
+/// The minimum level of the messages that are logged, see [`iceoryx2_bb_log::LogLevel`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LogLevel {
+    /// Logs everything, including the internal control flow.
+    Trace,
+    /// Logs messages that help to debug an application.
+    Debug,
+    /// Logs informational messages.
+    Info,
+    /// Logs only warnings and errors.
+    Warn,
+    /// Logs only errors.
+    Error,
+    /// Logs only fatal errors that terminate the process.
+    Fatal,
+}
+
+impl From<LogLevel> for iceoryx2_bb_log::LogLevel {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Trace => iceoryx2_bb_log::LogLevel::Trace,
+            LogLevel::Debug => iceoryx2_bb_log::LogLevel::Debug,
+            LogLevel::Info => iceoryx2_bb_log::LogLevel::Info,
+            LogLevel::Warn => iceoryx2_bb_log::LogLevel::Warn,
+            LogLevel::Error => iceoryx2_bb_log::LogLevel::Error,
+            LogLevel::Fatal => iceoryx2_bb_log::LogLevel::Fatal,
+        }
+    }
+}
+
+impl LogLevel {
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            "fatal" => Some(LogLevel::Fatal),
+            _ => None,
+        }
+    }
+}
+
+/// Defines how the messages are formatted when they are written by the [`LogTarget::Stderr`],
+/// [`LogTarget::File`] or [`LogTarget::Syslog`] logger.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LogFormat {
+    /// Human readable plain text.
+    Plain,
+    /// One JSON object per message.
+    Json,
+}
+
+impl From<LogFormat> for logger::LogFormat {
+    fn from(value: LogFormat) -> Self {
+        match value {
+            LogFormat::Plain => logger::LogFormat::Plain,
+            LogFormat::Json => logger::LogFormat::Json,
+        }
+    }
+}
+
+impl LogFormat {
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "plain" => Some(LogFormat::Plain),
+            "json" => Some(LogFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Defines where the log messages are written to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LogTarget {
+    /// Keeps the logger that is set in the code or the default logger of iceoryx2. The default
+    /// logger writes to the console unless iceoryx2 was built with the `logger_log` or the
+    /// `logger_tracing` feature.
+    Default,
+    /// Writes every message as single line into stderr.
+    Stderr,
+    /// Appends every message to [`Log::file`].
+    File,
+    /// Sends every message to the syslog daemon. Only supported on unix platforms.
+    Syslog,
+}
+
+impl LogTarget {
+    fn from_env_value(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "default" => Some(LogTarget::Default),
+            "stderr" => Some(LogTarget::Stderr),
+            "file" => Some(LogTarget::File),
+            "syslog" => Some(LogTarget::Syslog),
+            _ => None,
+        }
+    }
+}
+
+/// The settings of the logger. They are applied when the global config is set up, see
+/// [`Config::global_config()`], and every setting can be overridden with an environment
+/// variable, see [`LOG_LEVEL_ENV_VAR`], [`LOG_FORMAT_ENV_VAR`], [`LOG_TARGET_ENV_VAR`] and
+/// [`LOG_FILE_ENV_VAR`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Log {
+    /// The log level. When it is not set, the log level that is set in the code is kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<LogLevel>,
+    /// The format of the log messages
+    pub format: LogFormat,
+    /// The destination of the log messages. The logger can be set only once per process,
+    /// therefore it is ignored when a logger was already set in the code.
+    pub target: LogTarget,
+    /// The log file that is used with [`LogTarget::File`]
+    pub file: FilePath,
+}
+
+impl Log {
+    fn from_env<T>(name: &str, parse: fn(&str) -> Option<T>) -> Option<T> {
+        let value = std::env::var(name).ok()?;
+        let parsed_value = parse(&value);
+        if parsed_value.is_none() {
+            warn!(from "Log::from_env()",
+                "The environment variable {}={} contains an invalid value and is ignored.", name, value);
+        }
+        parsed_value
+    }
+
+    /// Applies only the log level, overridden by [`LOG_LEVEL_ENV_VAR`], to the process. In
+    /// contrast to the logger, the log level can be changed at any time.
+    pub(crate) fn apply_level(&self) {
+        let level = Self::from_env(LOG_LEVEL_ENV_VAR, LogLevel::from_env_value).or(self.level);
+        if let Some(level) = level {
+            set_log_level(level.into());
+        }
+    }
+
+    /// Applies the settings, overridden by the environment variables, to the logger of the
+    /// process. It is called automatically when the global config is set up and must be called
+    /// explicitly when only custom [`Config`]s are used.
+    pub fn apply(&self) {
+        let format = Self::from_env(LOG_FORMAT_ENV_VAR, LogFormat::from_env_value)
+            .unwrap_or(self.format)
+            .into();
+        let target =
+            Self::from_env(LOG_TARGET_ENV_VAR, LogTarget::from_env_value).unwrap_or(self.target);
+        let file = Self::from_env(LOG_FILE_ENV_VAR, |v| FilePath::new(v.as_bytes()).ok())
+            .unwrap_or(self.file);
+
+        self.apply_level();
+
+        let is_logger_set = match target {
+            LogTarget::Default => return,
+            LogTarget::Stderr => logger::use_stderr_logger(format),
+            LogTarget::File => {
+                logger::use_file_logger_with_format(&file.as_string().to_string(), format)
+            }
+            #[cfg(unix)]
+            LogTarget::Syslog => logger::use_syslog_logger(format),
+            #[cfg(not(unix))]
+            LogTarget::Syslog => {
+                warn!(from self, "Unable to use the syslog logger since it is not supported on this platform.");
+                return;
+            }
+        };
+
+        if !is_logger_set {
+            warn!(from self,
+                "Unable to use the {:?} logger since a logger was already set.", target);
+        }
+    }
+}
+
+/// Represents the configuration that iceoryx2 will utilize. It is divided into three sections:
 /// the [Global] settings, which must align with the iceoryx2 instance the application intends to
-/// join, and the [Defaults] for communication within that iceoryx2 instance. The user has the
-/// flexibility to override both sections.
+/// join, the [Defaults] for communication within that iceoryx2 instance and the [Log] settings.
+/// The user has the flexibility to override all sections.
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub struct Config {
-    /// Global settings for the iceoryx2 instance
+    /// Global settings for the iceoryx2 instance. They are fixed for the lifetime of a
+    /// [`Node`](crate::node::Node) and cannot be changed with
+    /// [`Node::update_config()`](crate::node::Node::update_config()).
     pub global: Global,
-    /// Default settings
+    /// Default settings. [`Node::update_config()`](crate::node::Node::update_config())
+    /// applies them to all services and ports that are created afterwards.
     pub defaults: Defaults,
+    /// Settings of the logger. [`Node::update_config()`](crate::node::Node::update_config())
+    /// applies only a changed log level, the other settings cannot be changed.
+    #[serde(default)]
+    pub log: Log,
 }
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
 
+// The settings that were added after the first release of the config file are optional, so
+// that existing config files stay valid. A missing setting takes the value of
+// [`Config::default()`].
+mod serde_defaults {
+    use super::*;
+
+    pub(super) fn event_payload_suffix() -> FileName {
+        Config::default().global.service.event_payload_suffix
+    }
+
+    pub(super) fn heartbeat_suffix() -> FileName {
+        Config::default().global.node.heartbeat_suffix
+    }
+
+    pub(super) fn instance_suffix() -> FileName {
+        Config::default().global.node.instance_suffix
+    }
+
+    pub(super) fn huge_pages_fallback() -> bool {
+        Config::default()
+            .defaults
+            .publish_subscribe
+            .huge_pages_fallback
+    }
+
+    pub(super) fn publisher_max_loaned_samples_ceiling() -> usize {
+        Config::default()
+            .defaults
+            .publish_subscribe
+            .publisher_max_loaned_samples_ceiling
+    }
+
+    pub(super) fn subscriber_sample_ordering() -> SampleOrdering {
+        Config::default()
+            .defaults
+            .publish_subscribe
+            .subscriber_sample_ordering
+    }
+
+    pub(super) fn publisher_allocation_strategy() -> AllocationStrategy {
+        Config::default()
+            .defaults
+            .publish_subscribe
+            .publisher_allocation_strategy
+    }
+}
+
+impl Default for Blackboard {
+    fn default() -> Self {
+        Config::default().defaults.blackboard
+    }
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Config::default().log
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -305,12 +869,15 @@ impl Default for Config {
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    event_payload_suffix: FileName::new(b".event_payload").unwrap(),
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
                     monitor_suffix: FileName::new(b".node_monitor").unwrap(),
                     static_config_suffix: FileName::new(b".details").unwrap(),
                     service_tag_suffix: FileName::new(b".service_tag").unwrap(),
+                    heartbeat_suffix: FileName::new(b".heartbeat").unwrap(),
+                    instance_suffix: FileName::new(b".instance").unwrap(),
                     cleanup_dead_nodes_on_creation: true,
                     cleanup_dead_nodes_on_destruction: true,
                 },
@@ -327,6 +894,17 @@ impl Default for Config {
                     enable_safe_overflow: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
                     subscriber_expired_connection_buffer: 128,
+                    publisher_memory_pools: vec![],
+                    publisher_memory_lock: false,
+                    publisher_prefault_memory: false,
+                    huge_pages: false,
+                    huge_pages_fallback: true,
+                    publisher_max_loaned_samples_ceiling: 2,
+                    subscriber_buffer_size: None,
+                    subscriber_overflow_strategy: None,
+                    subscriber_sample_ordering: SampleOrdering::PerPublisherFifo,
+                    publisher_allocation_strategy: AllocationStrategy::Static,
+                    publisher_statistics: false,
                 },
                 event: Event {
                     max_listeners: 16,
@@ -334,6 +912,16 @@ impl Default for Config {
                     max_nodes: 36,
                     event_id_max_value: 4294967295,
                 },
+                blackboard: Blackboard {
+                    max_readers: 8,
+                    max_nodes: 20,
+                },
+            },
+            log: Log {
+                level: None,
+                format: LogFormat::Plain,
+                target: LogTarget::Default,
+                file: FilePath::new(b"iceoryx2.log").unwrap(),
             },
         }
     }
@@ -367,6 +955,287 @@ impl Config {
         Ok(new_config)
     }
 
+    /// Stores the configuration in a file that can be loaded with [`Config::from_file()`]. An
+    /// existing file is overwritten.
+    pub fn save(&self, config_file: &FilePath) -> Result<(), ConfigSaveError> {
+        let msg = "Failed to save config";
+
+        let contents = fail!(from self, when toml::to_string_pretty(self),
+                with ConfigSaveError::UnableToSerializeContents,
+                "{} since the contents could not be serialized.", msg);
+
+        let mut file = fail!(from self, when FileBuilder::new(config_file)
+                    .creation_mode(CreationMode::PurgeAndCreate)
+                    .permission(Permission::OWNER_READ | Permission::OWNER_WRITE | Permission::GROUP_READ | Permission::OTHERS_READ)
+                    .create(),
+                with ConfigSaveError::FailedToCreateConfigFile,
+                "{} since the config file could not be created.", msg);
+
+        fail!(from self, when file.write(contents.as_bytes()),
+                with ConfigSaveError::FailedToWriteConfigFileContents,
+                "{} since the contents could not be written into the config file.", msg);
+
+        trace!(from self, "Saved.");
+        Ok(())
+    }
+
+    /// Overrides the settings with the values of the environment variables that start with
+    /// [`ENV_VAR_PREFIX`], see the [module documentation](crate::config) for the naming scheme
+    /// and the precedence. The variables of the [`Log`] settings are applied in
+    /// [`Log::apply()`]. Variables that do not correspond to a setting or contain an invalid
+    /// value are ignored with a warning.
+    pub fn apply_env_overrides(&mut self) {
+        self.apply_overrides(std::env::vars().filter_map(|(name, value)| {
+            name.strip_prefix(ENV_VAR_PREFIX)
+                .map(|key| (name.clone(), key.to_string(), value))
+        }));
+    }
+
+    fn apply_overrides<I: Iterator<Item = (String, String, String)>>(&mut self, overrides: I) {
+        let origin = "Config::apply_env_overrides()";
+        let mut config = match toml::Value::try_from(&*self) {
+            Ok(config) => config,
+            Err(e) => {
+                warn!(from origin, "Unable to apply the environment overrides since the config could not be serialized ({}).", e);
+                return;
+            }
+        };
+
+        for (name, key, value) in overrides {
+            if key.starts_with("LOG_") {
+                continue;
+            }
+
+            let mut candidate = config.clone();
+            let entry = match Self::find_entry(&mut candidate, &key) {
+                Some(entry) => entry,
+                None => {
+                    warn!(from origin, "The environment variable {} does not correspond to a setting and is ignored.", name);
+                    continue;
+                }
+            };
+
+            *entry = match entry {
+                toml::Value::String(_) => toml::Value::String(value.clone()),
+                _ => match toml::from_str::<toml::Table>(&format!("value = {}", value))
+                    .ok()
+                    .and_then(|mut table| table.remove("value"))
+                {
+                    Some(v) => v,
+                    None => {
+                        warn!(from origin, "The environment variable {}={} contains an invalid value and is ignored.", name, value);
+                        continue;
+                    }
+                },
+            };
+
+            match candidate.clone().try_into::<Config>() {
+                Ok(_) => config = candidate,
+                Err(e) => {
+                    warn!(from origin, "The environment variable {}={} contains an invalid value and is ignored ({}).", name, value, e);
+                }
+            }
+        }
+
+        match config.try_into::<Config>() {
+            Ok(config) => *self = config,
+            Err(e) => {
+                warn!(from origin, "Unable to apply the environment overrides since the config could not be deserialized ({}).", e);
+            }
+        }
+    }
+
+    // Finds the setting whose path, in upper case with `.` and `-` replaced by `_`, is `key`.
+    fn find_entry<'a>(value: &'a mut toml::Value, key: &str) -> Option<&'a mut toml::Value> {
+        let table = value.as_table_mut()?;
+        let name_of = |entry: &str| entry.to_uppercase().replace('-', "_");
+
+        if let Some(entry) = table
+            .iter()
+            .find(|(entry, v)| !v.is_table() && name_of(entry) == key)
+            .map(|(entry, _)| entry.clone())
+        {
+            return table.get_mut(&entry);
+        }
+
+        let sub_tables: Vec<(String, String)> = table
+            .iter()
+            .filter(|(_, v)| v.is_table())
+            .filter_map(|(entry, _)| {
+                key.strip_prefix(&(name_of(entry) + "_"))
+                    .map(|rest| (entry.clone(), rest.to_string()))
+            })
+            .collect();
+
+        // a section may be a prefix of another one, therefore every candidate is tried
+        for (entry, rest) in sub_tables {
+            if Self::find_entry(table.get_mut(&entry)?, &rest).is_some() {
+                return Self::find_entry(table.get_mut(&entry)?, &rest);
+            }
+        }
+
+        None
+    }
+
+    /// Checks the settings for unsupported values, names that exceed the limits of the system
+    /// and settings that contradict each other. Otherwise, such settings are only detected when
+    /// a [`Node`](crate::node::Node), a [`Service`](crate::service::Service) or a port is
+    /// created, where they are either adjusted with a warning or let the creation fail.
+    /// The settings of the [`Defaults`] are checked as if a [`Service`](crate::service::Service)
+    /// uses them without any customization. Returns an empty [`Vec`] when no issue was found.
+    ///
+    /// ```
+    /// use iceoryx2::config::{Config, ConfigDiagnosticSeverity};
+    ///
+    /// let mut config = Config::default();
+    /// config.defaults.publish_subscribe.subscriber_buffer_size = Some(1000);
+    ///
+    /// for diagnostic in config.validate() {
+    ///     if diagnostic.severity() == ConfigDiagnosticSeverity::Error {
+    ///         println!("{}", diagnostic);
+    ///     }
+    /// }
+    /// ```
+    pub fn validate(&self) -> Vec<ConfigDiagnostic> {
+        use ConfigDiagnosticKind::*;
+        use ConfigDiagnosticSeverity::*;
+
+        // the longest name of a resource is the connection name, two u128 in decimal
+        // representation separated by an underscore
+        const MAX_RESOURCE_NAME_LEN: usize = 2 * 39 + 1;
+
+        let mut diagnostics = vec![];
+        let mut report = |severity, kind, setting: &'static str, message: String| {
+            diagnostics.push(ConfigDiagnostic::new(severity, kind, setting, message))
+        };
+
+        let global = &self.global;
+        let suffixes = [
+            &global.service.publisher_data_segment_suffix,
+            &global.service.static_config_storage_suffix,
+            &global.service.dynamic_config_storage_suffix,
+            &global.service.connection_suffix,
+            &global.service.event_connection_suffix,
+            &global.service.event_payload_suffix,
+            &global.node.monitor_suffix,
+            &global.node.static_config_suffix,
+            &global.node.service_tag_suffix,
+            &global.node.heartbeat_suffix,
+            &global.node.instance_suffix,
+        ];
+        let max_suffix_len = suffixes.iter().map(|s| s.len()).max().unwrap_or(0);
+        // the service variants may prepend a marker to the prefix
+        let max_prefix_len =
+            global.prefix.len() + crate::service::external_memory::RESOURCE_PREFIX.len();
+        if FileName::max_len() < max_prefix_len + MAX_RESOURCE_NAME_LEN + max_suffix_len {
+            report(Error, InvalidName, "global.prefix",
+                format!("The prefix \"{}\" is too long, together with the longest suffix and resource name it exceeds the maximum file name length of {}.",
+                    global.prefix, FileName::max_len()));
+        }
+
+        for (setting, directory) in [
+            ("global.service.directory", &global.service.directory),
+            ("global.node.directory", &global.node.directory),
+        ] {
+            let mut path = *global.root_path();
+            if path.add_path_entry(directory).is_err() {
+                report(Error, InvalidName, setting,
+                    format!("The directory \"{}\" together with the root path \"{}\" exceeds the maximum path length of {}.",
+                        directory, global.root_path(), Path::max_len()));
+            }
+        }
+
+        let pubsub = &self.defaults.publish_subscribe;
+        let event = &self.defaults.event;
+        let blackboard = &self.defaults.blackboard;
+        for (setting, value) in [
+            (
+                "defaults.publish-subscribe.max-subscribers",
+                pubsub.max_subscribers,
+            ),
+            (
+                "defaults.publish-subscribe.max-publishers",
+                pubsub.max_publishers,
+            ),
+            ("defaults.publish-subscribe.max-nodes", pubsub.max_nodes),
+            (
+                "defaults.publish-subscribe.subscriber-max-buffer-size",
+                pubsub.subscriber_max_buffer_size,
+            ),
+            (
+                "defaults.publish-subscribe.subscriber-max-borrowed-samples",
+                pubsub.subscriber_max_borrowed_samples,
+            ),
+            ("defaults.event.max-listeners", event.max_listeners),
+            ("defaults.event.max-notifiers", event.max_notifiers),
+            ("defaults.event.max-nodes", event.max_nodes),
+            ("defaults.blackboard.max-readers", blackboard.max_readers),
+            ("defaults.blackboard.max-nodes", blackboard.max_nodes),
+        ] {
+            if value == 0 {
+                report(Warning, OutOfRange, setting,
+                    "The value 0 is not supported and is adjusted to 1, the smallest supported value.".to_string());
+            }
+        }
+
+        if pubsub.publisher_max_loaned_samples == 0 {
+            report(
+                Error,
+                OutOfRange,
+                "defaults.publish-subscribe.publisher-max-loaned-samples",
+                "The value 0 does not allow a publisher to loan any sample.".to_string(),
+            );
+        }
+
+        if pubsub.subscriber_buffer_size == Some(0) {
+            report(
+                Warning,
+                OutOfRange,
+                "defaults.publish-subscribe.subscriber-buffer-size",
+                "The value 0 is not supported and is adjusted to 1, the smallest supported value."
+                    .to_string(),
+            );
+        }
+
+        let subscriber_max_buffer_size = pubsub.subscriber_max_buffer_size.max(1);
+        if let Some(buffer_size) = pubsub.subscriber_buffer_size {
+            if subscriber_max_buffer_size < buffer_size {
+                report(Error, ConflictingSettings, "defaults.publish-subscribe.subscriber-buffer-size",
+                    format!("The buffer size {} exceeds the subscriber-max-buffer-size {}, the creation of a subscriber fails.",
+                        buffer_size, subscriber_max_buffer_size));
+            }
+        }
+
+        if !pubsub.enable_safe_overflow
+            && subscriber_max_buffer_size < pubsub.publisher_history_size
+        {
+            report(Error, ConflictingSettings, "defaults.publish-subscribe.publisher-history-size",
+                format!("The history size {} exceeds the subscriber-max-buffer-size {} without safe overflow, the creation of a service fails.",
+                    pubsub.publisher_history_size, subscriber_max_buffer_size));
+        }
+
+        if pubsub.publisher_max_loaned_samples_ceiling < pubsub.publisher_max_loaned_samples {
+            report(Warning, ConflictingSettings, "defaults.publish-subscribe.publisher-max-loaned-samples-ceiling",
+                format!("The ceiling {} is less than the publisher-max-loaned-samples {} and is raised to it.",
+                    pubsub.publisher_max_loaned_samples_ceiling, pubsub.publisher_max_loaned_samples));
+        }
+
+        if pubsub
+            .publisher_memory_pools
+            .iter()
+            .any(|pool| pool.payload_size == 0 || pool.number_of_samples == 0)
+        {
+            report(
+                Warning,
+                OutOfRange,
+                "defaults.publish-subscribe.publisher-memory-pools",
+                "Memory pools without payload size or samples are ignored.".to_string(),
+            );
+        }
+
+        diagnostics
+    }
+
     /// Sets up the global configuration from a file. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
@@ -377,7 +1246,10 @@ impl Config {
             return Ok(ICEORYX2_CONFIG.get());
         }
 
-        if !ICEORYX2_CONFIG.set_value(Config::from_file(config_file)?) {
+        let mut config = Config::from_file(config_file)?;
+        config.apply_env_overrides();
+
+        if !Self::set_global_config(config) {
             warn!(
                 from ICEORYX2_CONFIG.get(),
                 "Configuration already loaded and set up, cannot load another one. This may happen when this function is called from multiple threads."
@@ -402,19 +1274,34 @@ impl Config {
                 Ok(_) => (),
                 Err(ConfigCreationError::FailedToOpenConfigFile) => {
                     debug!(from "Config::global_config()", "Default config file not found, populate config with default values.");
-                    ICEORYX2_CONFIG.set_value(Config::default());
+                    Self::set_global_config(Self::default_with_env_overrides());
                 }
                 Err(ConfigCreationError::FailedToReadConfigFileContents) => {
                     warn!(from "Config::global_config()", "Default config file found but unable to read content, populate config with default values.");
-                    ICEORYX2_CONFIG.set_value(Config::default());
+                    Self::set_global_config(Self::default_with_env_overrides());
                 }
                 Err(ConfigCreationError::UnableToDeserializeContents) => {
                     warn!(from "Config::global_config()", "Default config file found but unable to load data, populate config with default values.");
-                    ICEORYX2_CONFIG.set_value(Config::default());
+                    Self::set_global_config(Self::default_with_env_overrides());
                 }
             }
         }
 
         ICEORYX2_CONFIG.get()
     }
+
+    fn default_with_env_overrides() -> Config {
+        let mut config = Config::default();
+        config.apply_env_overrides();
+        config
+    }
+
+    fn set_global_config(config: Config) -> bool {
+        if !ICEORYX2_CONFIG.set_value(config) {
+            return false;
+        }
+
+        ICEORYX2_CONFIG.get().log.apply();
+        true
+    }
 }
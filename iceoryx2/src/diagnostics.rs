@@ -0,0 +1,316 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Verifies that iceoryx2 works on the current host. [`self_test()`] creates a throwaway
+//! [`Node`], a publish-subscribe and an event service with all their ports, exchanges a
+//! sample and an event, removes everything again and returns a [`SelfTestReport`] that
+//! states which [`SelfTestConcept`]s work.
+//!
+//! The [`SelfTestReport`] implements [`serde::Serialize`] so that it can be handed over to
+//! installers or health checks in a machine-readable format.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::diagnostics::self_test;
+//!
+//! let report = self_test::<ipc::Service>(Config::global_config());
+//!
+//! if !report.has_passed() {
+//!     println!("iceoryx2 is not operational on this host:\n{}", report);
+//! }
+//! ```
+
+use core::fmt::Display;
+
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::node::node_name::NodeName;
+use crate::node::{Node, NodeBuilder};
+use crate::port::event_id::EventId;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::PortFactory;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+const SELF_TEST_NODE_NAME: &str = "iox2_self_test";
+const SELF_TEST_PAYLOAD: u64 = 0x1ceb_0a12_5e1f_7e57;
+const SELF_TEST_EVENT_ID: usize = 3;
+
+/// The iceoryx2 concepts that are verified by [`self_test()`], in the order in which they
+/// are verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SelfTestConcept {
+    /// A [`Node`] can be created.
+    Node,
+    /// A publish-subscribe service can be created.
+    PublishSubscribeService,
+    /// A [`Publisher`](crate::port::publisher::Publisher) can be created.
+    Publisher,
+    /// A [`Subscriber`](crate::port::subscriber::Subscriber) can be created.
+    Subscriber,
+    /// A sample that is sent by the publisher is received by the subscriber.
+    PublishSubscribeTransmission,
+    /// An event service can be created.
+    EventService,
+    /// A [`Notifier`](crate::port::notifier::Notifier) can be created.
+    Notifier,
+    /// A [`Listener`](crate::port::listener::Listener) can be created.
+    Listener,
+    /// An event that is emitted by the notifier is received by the listener.
+    EventTransmission,
+    /// All resources of the node and the services are removed when they go out of scope.
+    Cleanup,
+}
+
+impl SelfTestConcept {
+    /// All [`SelfTestConcept`]s in the order in which they are verified.
+    pub const ALL: [SelfTestConcept; 10] = [
+        SelfTestConcept::Node,
+        SelfTestConcept::PublishSubscribeService,
+        SelfTestConcept::Publisher,
+        SelfTestConcept::Subscriber,
+        SelfTestConcept::PublishSubscribeTransmission,
+        SelfTestConcept::EventService,
+        SelfTestConcept::Notifier,
+        SelfTestConcept::Listener,
+        SelfTestConcept::EventTransmission,
+        SelfTestConcept::Cleanup,
+    ];
+}
+
+/// The outcome of the verification of a single [`SelfTestConcept`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum SelfTestOutcome {
+    /// The concept works.
+    Passed,
+    /// The concept does not work, contains the reason.
+    Failed(String),
+    /// The concept could not be verified since a concept it depends on failed.
+    Skipped,
+}
+
+impl Display for SelfTestOutcome {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SelfTestOutcome::Passed => write!(f, "passed"),
+            SelfTestOutcome::Failed(reason) => write!(f, "failed ({})", reason),
+            SelfTestOutcome::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+/// The [`SelfTestOutcome`] of a single [`SelfTestConcept`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct SelfTestResult {
+    concept: SelfTestConcept,
+    outcome: SelfTestOutcome,
+}
+
+impl SelfTestResult {
+    /// Returns the verified [`SelfTestConcept`].
+    pub fn concept(&self) -> SelfTestConcept {
+        self.concept
+    }
+
+    /// Returns the [`SelfTestOutcome`] of the verification.
+    pub fn outcome(&self) -> &SelfTestOutcome {
+        &self.outcome
+    }
+}
+
+/// The result of [`self_test()`]. It contains a [`SelfTestResult`] for every
+/// [`SelfTestConcept`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SelfTestReport {
+    results: Vec<SelfTestResult>,
+}
+
+impl SelfTestReport {
+    /// Returns the [`SelfTestResult`]s in the order of [`SelfTestConcept::ALL`].
+    pub fn results(&self) -> &[SelfTestResult] {
+        &self.results
+    }
+
+    /// Returns the [`SelfTestOutcome`] of the provided [`SelfTestConcept`].
+    pub fn outcome(&self, concept: SelfTestConcept) -> &SelfTestOutcome {
+        self.results
+            .iter()
+            .find(|result| result.concept == concept)
+            .map_or(&SelfTestOutcome::Skipped, |result| &result.outcome)
+    }
+
+    /// Returns true when every [`SelfTestConcept`] has passed.
+    pub fn has_passed(&self) -> bool {
+        self.results
+            .iter()
+            .all(|result| result.outcome == SelfTestOutcome::Passed)
+    }
+
+    fn verify<T, F: FnOnce() -> Result<T, String>>(
+        &mut self,
+        concept: SelfTestConcept,
+        verification: F,
+    ) -> Option<T> {
+        let (outcome, value) = match verification() {
+            Ok(value) => (SelfTestOutcome::Passed, Some(value)),
+            Err(reason) => (SelfTestOutcome::Failed(reason), None),
+        };
+
+        self.results.push(SelfTestResult { concept, outcome });
+        value
+    }
+
+    fn verify_publish_subscribe<S: Service>(
+        &mut self,
+        node: &Node<S>,
+        created_services: &mut Vec<(ServiceName, MessagingPattern)>,
+    ) -> Option<()> {
+        let service = self.verify(SelfTestConcept::PublishSubscribeService, || {
+            node.service_builder(&generate_service_name("publish_subscribe")?)
+                .publish_subscribe::<u64>()
+                .create()
+                .map_err(|e| e.to_string())
+        })?;
+        created_services.push((service.name().clone(), MessagingPattern::PublishSubscribe));
+
+        let publisher = self.verify(SelfTestConcept::Publisher, || {
+            service
+                .publisher_builder()
+                .create()
+                .map_err(|e| e.to_string())
+        })?;
+        let subscriber = self.verify(SelfTestConcept::Subscriber, || {
+            service
+                .subscriber_builder()
+                .create()
+                .map_err(|e| e.to_string())
+        })?;
+
+        self.verify(SelfTestConcept::PublishSubscribeTransmission, || {
+            publisher
+                .send_copy(SELF_TEST_PAYLOAD)
+                .map_err(|e| e.to_string())?;
+            match subscriber.receive().map_err(|e| e.to_string())? {
+                Some(sample) if *sample == SELF_TEST_PAYLOAD => Ok(()),
+                Some(_) => Err("the received sample is corrupted".to_string()),
+                None => Err("the sent sample was not received".to_string()),
+            }
+        })
+    }
+
+    fn verify_event<S: Service>(
+        &mut self,
+        node: &Node<S>,
+        created_services: &mut Vec<(ServiceName, MessagingPattern)>,
+    ) -> Option<()> {
+        let service = self.verify(SelfTestConcept::EventService, || {
+            node.service_builder(&generate_service_name("event")?)
+                .event()
+                .create()
+                .map_err(|e| e.to_string())
+        })?;
+        created_services.push((service.name().clone(), MessagingPattern::Event));
+
+        let notifier = self.verify(SelfTestConcept::Notifier, || {
+            service
+                .notifier_builder()
+                .create()
+                .map_err(|e| e.to_string())
+        })?;
+        let listener = self.verify(SelfTestConcept::Listener, || {
+            service
+                .listener_builder()
+                .create()
+                .map_err(|e| e.to_string())
+        })?;
+
+        self.verify(SelfTestConcept::EventTransmission, || {
+            notifier
+                .notify_with_custom_event_id(EventId::new(SELF_TEST_EVENT_ID))
+                .map_err(|e| e.to_string())?;
+            match listener.try_wait_one().map_err(|e| e.to_string())? {
+                Some(event_id) if event_id.as_value() == SELF_TEST_EVENT_ID => Ok(()),
+                Some(_) => Err("the received event id is corrupted".to_string()),
+                None => Err("the emitted event was not received".to_string()),
+            }
+        })
+    }
+}
+
+impl Display for SelfTestReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for result in &self.results {
+            writeln!(f, "{:?}: {}", result.concept, result.outcome)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn generate_service_name(suffix: &str) -> Result<ServiceName, String> {
+    let unique_id = UniqueSystemId::new().map_err(|e| format!("{:?}", e))?;
+    ServiceName::new(&format!("iox2_self_test_{}_{}", unique_id.value(), suffix))
+        .map_err(|e| e.to_string())
+}
+
+/// Creates a throwaway [`Node`] with the provided [`Config`], verifies the publish-subscribe
+/// and the event messaging pattern with it and removes all resources again. Returns a
+/// [`SelfTestReport`] with the [`SelfTestOutcome`] of every [`SelfTestConcept`].
+pub fn self_test<S: Service>(config: &Config) -> SelfTestReport {
+    let mut report = SelfTestReport { results: vec![] };
+    let mut created_services = vec![];
+
+    let node = report.verify(SelfTestConcept::Node, || {
+        let node_name = NodeName::new(SELF_TEST_NODE_NAME).map_err(|e| e.to_string())?;
+        NodeBuilder::new()
+            .name(&node_name)
+            .config(config)
+            .create::<S>()
+            .map_err(|e| e.to_string())
+    });
+
+    if let Some(node) = node {
+        report.verify_publish_subscribe(&node, &mut created_services);
+        report.verify_event(&node, &mut created_services);
+        drop(node);
+
+        report.verify(SelfTestConcept::Cleanup, || {
+            for (service_name, messaging_pattern) in created_services {
+                if S::does_exist(&service_name, config, messaging_pattern)
+                    .map_err(|e| e.to_string())?
+                {
+                    return Err(format!(
+                        "the service \"{}\" still exists after it went out of scope",
+                        service_name
+                    ));
+                }
+            }
+            Ok(())
+        });
+    }
+
+    // concepts that could not be verified are reported as skipped
+    let results = SelfTestConcept::ALL
+        .iter()
+        .map(|concept| SelfTestResult {
+            concept: *concept,
+            outcome: report.outcome(*concept).clone(),
+        })
+        .collect();
+    report.results = results;
+
+    report
+}
@@ -285,9 +285,19 @@
 #[cfg(doctest)]
 mod compiletests;
 
+/// Reproducible latency and throughput measurements between a publisher and a subscriber
+pub mod benchmark;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// Self-test that verifies which iceoryx2 concepts work on the current host
+pub mod diagnostics;
+
+/// Gauges about the health of all nodes and services that can be exposed to Prometheus
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
@@ -0,0 +1,482 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Acquires a snapshot of the health of all [`Node`]s and [`Service`]s that share the same
+//! [`Config`] and provides it as gauges. The snapshot can either be iterated with a callback
+//! or rendered in the
+//! [OpenMetrics text format](https://github.com/OpenObservability/OpenMetrics/blob/main/specification/OpenMetrics.md)
+//! so that it can be served by any HTTP endpoint that is scraped by Prometheus.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::metrics::Metrics;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let metrics = Metrics::scrape::<ipc::Service>(Config::global_config())?;
+//!
+//! metrics.list(|metric| {
+//!     println!("{} {:?} = {}", metric.name(), metric.labels(), metric.value());
+//!     CallbackProgression::Continue
+//! });
+//!
+//! // the body of a Prometheus pull endpoint
+//! let exposition = metrics.to_string();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
+
+use crate::config::Config;
+use crate::node::{Node, NodeListFailure, NodeState};
+use crate::service::config_scheme::connection_config;
+use crate::service::naming_scheme::connection_name;
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::{self, Service, ServiceListError};
+
+/// Failures that can occur when the [`Metrics`] are acquired with [`Metrics::scrape()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MetricsScrapeError {
+    /// The process has insufficient permissions to list all [`Node`]s or [`Service`]s.
+    InsufficientPermissions,
+    /// The process received an interrupt signal while acquiring the [`Metrics`].
+    Interrupt,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for MetricsScrapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "MetricsScrapeError::{:?}", self)
+    }
+}
+
+impl std::error::Error for MetricsScrapeError {}
+
+impl From<NodeListFailure> for MetricsScrapeError {
+    fn from(value: NodeListFailure) -> Self {
+        match value {
+            NodeListFailure::InsufficientPermissions => MetricsScrapeError::InsufficientPermissions,
+            NodeListFailure::Interrupt => MetricsScrapeError::Interrupt,
+            NodeListFailure::InternalError => MetricsScrapeError::InternalError,
+        }
+    }
+}
+
+impl From<ServiceListError> for MetricsScrapeError {
+    fn from(value: ServiceListError) -> Self {
+        match value {
+            ServiceListError::InsufficientPermissions => {
+                MetricsScrapeError::InsufficientPermissions
+            }
+            ServiceListError::InternalError => MetricsScrapeError::InternalError,
+        }
+    }
+}
+
+type Labels = Vec<(&'static str, String)>;
+
+/// Defines what a [`Metric`] measures. Every [`Metric`] is a gauge.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub enum MetricFamily {
+    /// The number of [`Node`]s in a specific [`NodeState`], labeled with `state`.
+    Nodes,
+    /// The number of [`Service`]s, labeled with `messaging_pattern`.
+    Services,
+    /// The number of [`Node`]s that opened a [`Service`].
+    ServiceNodes,
+    /// The number of [`Publisher`](crate::port::publisher::Publisher)s of a [`Service`].
+    ServicePublishers,
+    /// The number of [`Subscriber`](crate::port::subscriber::Subscriber)s of a [`Service`].
+    ServiceSubscribers,
+    /// The number of [`Notifier`](crate::port::notifier::Notifier)s of a [`Service`].
+    ServiceNotifiers,
+    /// The number of [`Listener`](crate::port::listener::Listener)s of a [`Service`].
+    ServiceListeners,
+    /// The number of [`Writer`](crate::port::writer::Writer)s of a [`Service`].
+    ServiceWriters,
+    /// The number of [`Reader`](crate::port::reader::Reader)s of a [`Service`].
+    ServiceReaders,
+    /// The number of samples of a [`Service`] that were not delivered to a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) since its buffer was full or that
+    /// were overwritten on overflow, see
+    /// [`ServiceStatistics::samples_dropped()`](crate::service::statistics::ServiceStatistics::samples_dropped()).
+    ServiceDroppedSamples,
+    /// The number of samples that are stored in the buffer of a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber), labeled with `subscriber_id`.
+    SubscriberBufferedSamples,
+    /// The capacity of the buffer of a [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// per connected [`Publisher`](crate::port::publisher::Publisher), labeled with
+    /// `subscriber_id`.
+    SubscriberBufferSize,
+}
+
+impl MetricFamily {
+    /// Returns the name under which the [`MetricFamily`] is exposed.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetricFamily::Nodes => "iceoryx2_nodes",
+            MetricFamily::Services => "iceoryx2_services",
+            MetricFamily::ServiceNodes => "iceoryx2_service_nodes",
+            MetricFamily::ServicePublishers => "iceoryx2_service_publishers",
+            MetricFamily::ServiceSubscribers => "iceoryx2_service_subscribers",
+            MetricFamily::ServiceNotifiers => "iceoryx2_service_notifiers",
+            MetricFamily::ServiceListeners => "iceoryx2_service_listeners",
+            MetricFamily::ServiceWriters => "iceoryx2_service_writers",
+            MetricFamily::ServiceReaders => "iceoryx2_service_readers",
+            MetricFamily::ServiceDroppedSamples => "iceoryx2_service_dropped_samples",
+            MetricFamily::SubscriberBufferedSamples => "iceoryx2_subscriber_buffered_samples",
+            MetricFamily::SubscriberBufferSize => "iceoryx2_subscriber_buffer_size",
+        }
+    }
+
+    /// Returns a human readable description of the [`MetricFamily`].
+    pub fn help(&self) -> &'static str {
+        match self {
+            MetricFamily::Nodes => "Number of nodes per state.",
+            MetricFamily::Services => "Number of services per messaging pattern.",
+            MetricFamily::ServiceNodes => "Number of nodes that opened the service.",
+            MetricFamily::ServicePublishers => "Number of publishers of the service.",
+            MetricFamily::ServiceSubscribers => "Number of subscribers of the service.",
+            MetricFamily::ServiceNotifiers => "Number of notifiers of the service.",
+            MetricFamily::ServiceListeners => "Number of listeners of the service.",
+            MetricFamily::ServiceWriters => "Number of writers of the service.",
+            MetricFamily::ServiceReaders => "Number of readers of the service.",
+            MetricFamily::ServiceDroppedSamples => {
+                "Number of samples of the service that were dropped since a subscriber buffer was full."
+            }
+            MetricFamily::SubscriberBufferedSamples => {
+                "Number of samples that are stored in the buffer of the subscriber."
+            }
+            MetricFamily::SubscriberBufferSize => {
+                "Capacity of the buffer of the subscriber per connected publisher."
+            }
+        }
+    }
+}
+
+/// A single gauge of the [`Metrics`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Metric {
+    family: MetricFamily,
+    labels: Vec<(&'static str, String)>,
+    value: u64,
+}
+
+impl Metric {
+    /// Returns the [`MetricFamily`] of the [`Metric`].
+    pub fn family(&self) -> MetricFamily {
+        self.family
+    }
+
+    /// Returns the name of the [`Metric`].
+    pub fn name(&self) -> &'static str {
+        self.family.name()
+    }
+
+    /// Returns the labels that identify the [`Metric`] within its [`MetricFamily`].
+    pub fn labels(&self) -> &[(&'static str, String)] {
+        &self.labels
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+/// A snapshot of all [`Metric`]s of the [`Node`]s and [`Service`]s that share the same
+/// [`Config`].
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    metrics: Vec<Metric>,
+}
+
+impl Metrics {
+    /// Acquires a new snapshot of all [`Node`]s and [`Service`]s under the provided
+    /// [`Config`].
+    pub fn scrape<S: Service>(config: &Config) -> Result<Self, MetricsScrapeError> {
+        let msg = "Unable to scrape metrics";
+        let origin = "Metrics::scrape()";
+        let mut new_self = Self::default();
+
+        let mut nodes = BTreeMap::from([
+            ("alive", 0),
+            ("unresponsive", 0),
+            ("dead", 0),
+            ("inaccessible", 0),
+            ("undefined", 0),
+        ]);
+        fail!(from origin, when Node::<S>::list(config, |node_state| {
+                let state = match node_state {
+                    NodeState::Alive(_) => "alive",
+                    NodeState::Unresponsive(_) => "unresponsive",
+                    NodeState::Dead(_) => "dead",
+                    NodeState::Inaccessible(_) => "inaccessible",
+                    NodeState::Undefined(_) => "undefined",
+                };
+                *nodes.entry(state).or_default() += 1;
+                CallbackProgression::Continue
+            }),
+            "{} since the nodes could not be listed.", msg);
+
+        for (state, value) in nodes {
+            new_self.add(
+                MetricFamily::Nodes,
+                vec![("state", state.to_string())],
+                value,
+            );
+        }
+
+        let mut services = BTreeMap::<String, u64>::new();
+        let mut service_metrics = vec![];
+        fail!(from origin, when S::list(config, |service| {
+                let messaging_pattern = service.static_details.messaging_pattern().to_string();
+                *services.entry(messaging_pattern.clone()).or_default() += 1;
+
+                let labels = vec![
+                    ("service_name", service.static_details.name().to_string()),
+                    ("messaging_pattern", messaging_pattern),
+                ];
+
+                if let Some(dynamic_details) = &service.dynamic_details {
+                    service_metrics.push(Metric {
+                        family: MetricFamily::ServiceNodes,
+                        labels: labels.clone(),
+                        value: dynamic_details.nodes.len() as u64,
+                    });
+
+                    if let MessagingPattern::PublishSubscribe(_) =
+                        service.static_details.messaging_pattern()
+                    {
+                        service_metrics.push(Metric {
+                            family: MetricFamily::ServiceDroppedSamples,
+                            labels: labels.clone(),
+                            value: dynamic_details.statistics.samples_dropped(),
+                        });
+                    }
+                }
+
+                for (family, port_labels, value) in
+                    Self::port_metrics::<S>(config, &service.static_details)
+                {
+                    let mut labels = labels.clone();
+                    labels.extend(port_labels);
+                    service_metrics.push(Metric {
+                        family,
+                        labels,
+                        value,
+                    });
+                }
+
+                CallbackProgression::Continue
+            }),
+            "{} since the services could not be listed.", msg);
+
+        for (messaging_pattern, value) in services {
+            new_self.add(
+                MetricFamily::Services,
+                vec![("messaging_pattern", messaging_pattern)],
+                value,
+            );
+        }
+        new_self.metrics.append(&mut service_metrics);
+
+        // metrics of the same family must be grouped in the exposition format
+        new_self.metrics.sort_by_key(|metric| metric.family);
+
+        Ok(new_self)
+    }
+
+    fn add(&mut self, family: MetricFamily, labels: Vec<(&'static str, String)>, value: u64) {
+        self.metrics.push(Metric {
+            family,
+            labels,
+            value,
+        });
+    }
+
+    fn subscriber_buffers<S: Service>(
+        config: &Config,
+        details: &service::dynamic_config::publish_subscribe::DynamicConfig,
+    ) -> Vec<(MetricFamily, Labels, u64)> {
+        let connection_config = connection_config::<S>(config);
+        let mut publisher_ids = vec![];
+        unsafe { details.publishers.get_state() }.for_each(|_, publisher| {
+            publisher_ids.push(publisher.publisher_id);
+            CallbackProgression::Continue
+        });
+
+        let mut metrics = vec![];
+        unsafe { details.subscribers.get_state() }.for_each(|_, subscriber| {
+            let mut buffered_samples = 0;
+            for publisher_id in &publisher_ids {
+                let connection = connection_name(*publisher_id, subscriber.subscriber_id);
+                if let Some(statistics) = <S::Connection as ZeroCopyConnection>::statistics_cfg(
+                    &connection,
+                    &connection_config,
+                ) {
+                    buffered_samples += statistics.number_of_buffered_samples as u64;
+                }
+            }
+
+            let labels = vec![(
+                "subscriber_id",
+                subscriber.subscriber_id.0.value().to_string(),
+            )];
+            metrics.push((
+                MetricFamily::SubscriberBufferedSamples,
+                labels.clone(),
+                buffered_samples,
+            ));
+            metrics.push((
+                MetricFamily::SubscriberBufferSize,
+                labels,
+                subscriber.buffer_size as u64,
+            ));
+            CallbackProgression::Continue
+        });
+
+        metrics
+    }
+
+    fn port_metrics<S: Service>(
+        config: &Config,
+        static_config: &service::static_config::StaticConfig,
+    ) -> Vec<(MetricFamily, Labels, u64)> {
+        let dynamic_config =
+            match service::open_dynamic_config::<S>(config, static_config.service_id()) {
+                Ok(Some(dynamic_config)) => dynamic_config,
+                Ok(None) => return vec![],
+                Err(e) => {
+                    debug!(from "Metrics::scrape()",
+                        "Unable to acquire the ports of the service \"{}\" ({:?}).",
+                        static_config.name(), e);
+                    return vec![];
+                }
+            };
+
+        let dynamic_config = dynamic_config.get();
+        match static_config.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(_) => {
+                let details = dynamic_config.publish_subscribe();
+                let mut metrics = vec![
+                    (
+                        MetricFamily::ServicePublishers,
+                        vec![],
+                        details.number_of_publishers() as u64,
+                    ),
+                    (
+                        MetricFamily::ServiceSubscribers,
+                        vec![],
+                        details.number_of_subscribers() as u64,
+                    ),
+                ];
+                metrics.append(&mut Self::subscriber_buffers::<S>(config, details));
+                metrics
+            }
+            MessagingPattern::Event(_) => {
+                let details = dynamic_config.event();
+                vec![
+                    (
+                        MetricFamily::ServiceNotifiers,
+                        vec![],
+                        details.number_of_notifiers() as u64,
+                    ),
+                    (
+                        MetricFamily::ServiceListeners,
+                        vec![],
+                        details.number_of_listeners() as u64,
+                    ),
+                ]
+            }
+            MessagingPattern::Blackboard(_) => {
+                let details = dynamic_config.blackboard();
+                vec![
+                    (
+                        MetricFamily::ServiceWriters,
+                        vec![],
+                        details.number_of_writers() as u64,
+                    ),
+                    (
+                        MetricFamily::ServiceReaders,
+                        vec![],
+                        details.number_of_readers() as u64,
+                    ),
+                ]
+            }
+        }
+    }
+
+    /// Calls the provided callback for every [`Metric`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list<F: FnMut(&Metric) -> CallbackProgression>(&self, mut callback: F) {
+        for metric in &self.metrics {
+            if callback(metric) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    /// Returns the number of acquired [`Metric`]s.
+    pub fn len(&self) -> usize {
+        self.metrics.len()
+    }
+
+    /// Returns true if no [`Metric`] was acquired.
+    pub fn is_empty(&self) -> bool {
+        self.metrics.is_empty()
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Renders the [`Metrics`] in the OpenMetrics text format.
+impl Display for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut current_family = None;
+        for metric in &self.metrics {
+            if current_family != Some(metric.family) {
+                current_family = Some(metric.family);
+                writeln!(f, "# TYPE {} gauge", metric.name())?;
+                writeln!(f, "# HELP {} {}", metric.name(), metric.family.help())?;
+            }
+
+            write!(f, "{}", metric.name())?;
+            if !metric.labels.is_empty() {
+                let labels = metric
+                    .labels
+                    .iter()
+                    .map(|(key, value)| format!("{}=\"{}\"", key, escape_label_value(value)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "{{{}}}", labels)?;
+            }
+            writeln!(f, " {}", metric.value)?;
+        }
+
+        writeln!(f, "# EOF")
+    }
+}
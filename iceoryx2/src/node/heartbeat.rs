@@ -0,0 +1,134 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The heartbeat of a [`Node`](crate::node::Node) that was created with a
+//! [`NodeBuilder::liveness_deadline()`](crate::node::NodeBuilder::liveness_deadline()).
+//! It is stored in a [`DynamicStorage`](iceoryx2_cal::dynamic_storage::DynamicStorage) so
+//! that every process can detect a hung [`Node`](crate::node::Node) whose process is still
+//! alive but which did not send a heartbeat within its liveness deadline.
+//!
+//! Additionally, it contains the slots of the [`NodeWatchdog`](crate::node::watchdog::NodeWatchdog)s
+//! of the [`Node`](crate::node::Node). When one of them is not fed within its deadline, the
+//! [`Node`](crate::node::Node) is considered unresponsive as well.
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+
+/// The maximum number of [`NodeWatchdog`](crate::node::watchdog::NodeWatchdog)s that can be
+/// registered at a [`Node`](crate::node::Node) at the same time.
+pub const MAX_NUMBER_OF_WATCHDOGS: usize = 32;
+
+#[repr(C)]
+#[derive(Debug)]
+struct WatchdogSlot {
+    is_in_use: IoxAtomicBool,
+    deadline: IoxAtomicU64,
+    last_feed: IoxAtomicU64,
+}
+
+impl WatchdogSlot {
+    fn new() -> Self {
+        Self {
+            is_in_use: IoxAtomicBool::new(false),
+            deadline: IoxAtomicU64::new(u64::MAX),
+            last_feed: IoxAtomicU64::new(0),
+        }
+    }
+
+    // an unused slot has the deadline u64::MAX and can never miss it
+    fn has_missed_deadline(&self, now: u64) -> bool {
+        let deadline = self.deadline.load(Ordering::Acquire);
+        now.saturating_sub(self.last_feed.load(Ordering::Relaxed)) > deadline
+    }
+}
+
+/// Contains the timestamp of the last heartbeat of a [`Node`](crate::node::Node) and the
+/// deadline in which the next heartbeat is expected.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NodeHeartbeat {
+    last_heartbeat: IoxAtomicU64,
+    liveness_deadline: u64,
+    watchdogs: [WatchdogSlot; MAX_NUMBER_OF_WATCHDOGS],
+}
+
+impl NodeHeartbeat {
+    pub(crate) fn new(liveness_deadline: Duration) -> Self {
+        Self {
+            last_heartbeat: IoxAtomicU64::new(Self::now()),
+            liveness_deadline: liveness_deadline.as_nanos() as u64,
+            watchdogs: core::array::from_fn(|_| WatchdogSlot::new()),
+        }
+    }
+
+    fn now() -> u64 {
+        let now = fatal_panic!(from "NodeHeartbeat::now()",
+                    when Time::now(),
+                    "This should never happen! Unable to acquire the current time.");
+        now.as_duration().as_nanos() as u64
+    }
+
+    pub(crate) fn beat(&self) {
+        self.last_heartbeat.store(Self::now(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn has_missed_deadline(&self) -> bool {
+        let now = Self::now();
+        let last_heartbeat = self.last_heartbeat.load(Ordering::Relaxed);
+        now.saturating_sub(last_heartbeat) > self.liveness_deadline
+            || self
+                .watchdogs
+                .iter()
+                .any(|watchdog| watchdog.has_missed_deadline(now))
+    }
+
+    /// Acquires a free watchdog slot and returns its index. If all slots are in use, it
+    /// returns [`None`].
+    pub(crate) fn acquire_watchdog(&self, deadline: Duration) -> Option<usize> {
+        for (index, watchdog) in self.watchdogs.iter().enumerate() {
+            if watchdog
+                .is_in_use
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                // the last feed must be visible before the deadline becomes active
+                watchdog.last_feed.store(Self::now(), Ordering::Relaxed);
+                watchdog
+                    .deadline
+                    .store(deadline.as_nanos() as u64, Ordering::Release);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn feed_watchdog(&self, index: usize) {
+        self.watchdogs[index]
+            .last_feed
+            .store(Self::now(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn has_watchdog_missed_deadline(&self, index: usize) -> bool {
+        self.watchdogs[index].has_missed_deadline(Self::now())
+    }
+
+    pub(crate) fn release_watchdog(&self, index: usize) {
+        let watchdog = &self.watchdogs[index];
+        watchdog.deadline.store(u64::MAX, Ordering::Relaxed);
+        watchdog.is_in_use.store(false, Ordering::Release);
+    }
+}
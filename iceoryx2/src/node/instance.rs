@@ -0,0 +1,69 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The marker of the iceoryx2 instance that owns a [`Global::prefix`](crate::config::Global::prefix).
+//! Resources without a path, like POSIX shared memory, are shared by all instances on the same
+//! host that use the same prefix. The marker is stored in such a resource so that a
+//! [`Node`](crate::node::Node) detects when another instance, with a different root path, uses
+//! the same prefix.
+//!
+//! The marker outlives all [`Node`](crate::node::Node)s of the instance and therefore also
+//! holds the counter from which every new [`Node`](crate::node::Node) acquires its generation,
+//! see [`NodeDetails::generation()`](crate::node::NodeDetails::generation()).
+
+use std::sync::atomic::Ordering;
+
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+/// Identifies the iceoryx2 instance that owns a
+/// [`Global::prefix`](crate::config::Global::prefix).
+#[repr(C)]
+#[derive(Debug)]
+pub struct InstanceMarker {
+    instance_id: u128,
+    owner_uid: u32,
+    root_path: Path,
+    node_generation: IoxAtomicU64,
+}
+
+impl InstanceMarker {
+    pub(crate) fn new(instance_id: u128, owner_uid: u32, root_path: Path) -> Self {
+        Self {
+            instance_id,
+            owner_uid,
+            root_path,
+            node_generation: IoxAtomicU64::new(0),
+        }
+    }
+
+    /// Returns the id that is stored in the root path of the instance.
+    pub(crate) fn instance_id(&self) -> u128 {
+        self.instance_id
+    }
+
+    /// Returns the uid of the owner of the root path of the instance.
+    pub(crate) fn owner_uid(&self) -> u32 {
+        self.owner_uid
+    }
+
+    /// Returns the root path of the instance.
+    pub(crate) fn root_path(&self) -> &Path {
+        &self.root_path
+    }
+
+    /// Returns the generation of the next [`Node`](crate::node::Node) of the instance,
+    /// starting with 1.
+    pub(crate) fn next_node_generation(&self) -> u64 {
+        self.node_generation.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
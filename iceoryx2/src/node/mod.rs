@@ -106,47 +106,91 @@
 //!         Err(NodeWaitFailure::Interrupt) => {
 //!             println!("Someone send an interrupt signal ...");
 //!         }
+//!         Err(NodeWaitFailure::InternalError) => {
+//!             println!("Unable to wait");
+//!             break;
+//!         }
 //!     }
 //! }
 //! # Ok(())
 //! # }
 //! ```
 
+/// The heartbeat of a node with a liveness deadline.
+pub mod heartbeat;
+
+/// The marker of the iceoryx2 instance that owns a prefix.
+pub mod instance;
+
 /// The name for a node.
 pub mod node_name;
 
+/// A file descriptor that wakes up external event loops on a termination request.
+pub mod termination_wakeup;
+
 #[doc(hidden)]
 pub mod testing;
 
+/// The scheduling settings of the threads a node uses internally.
+pub mod thread_settings;
+
+/// Watchdogs that monitor the liveness of individual threads of a node.
+pub mod watchdog;
+
+/// Runtime agnostic async support for the [`Node`] event loop.
+#[cfg(feature = "async")]
+pub mod wait_async;
+
+use crate::node::heartbeat::NodeHeartbeat;
+use crate::node::instance::InstanceMarker;
 use crate::node::node_name::NodeName;
+use crate::node::termination_wakeup::{TerminationWakeup, TerminationWakeupCreateError};
+use crate::node::thread_settings::ThreadSettings;
+use crate::node::watchdog::{NodeWatchdog, NodeWatchdogCreateError};
+use crate::port::subscriber::Subscriber;
+use crate::service::attribute::{AttributeSet, AttributeSpecifier};
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
-    node_details_path, node_monitoring_config, service_tag_config,
+    instance_identity_config, instance_marker_config, node_details_path, node_heartbeat_config,
+    node_monitoring_config, service_tag_config,
+};
+use crate::service::discovery::{
+    ServiceDiscoveryCreateError, ServiceDiscoveryEvent, ServiceDiscoveryPublisher,
 };
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
-use crate::service::{self, remove_service_tag};
+use crate::service::{self, discovery, remove_service_tag};
 use crate::{config::Config, service::config_scheme::node_details_config};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
-use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_log::context::{LogContext, LogContextGuard};
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn, Log};
 use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError, Time};
+use iceoryx2_bb_posix::directory::{
+    Directory, DirectoryCreateError, DirectoryOpenError, DirectoryReadError,
+};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::file_type::FileType;
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::scheduler::Scheduler;
 use iceoryx2_bb_posix::signal::SignalHandler;
+use iceoryx2_bb_posix::thread::{ThreadName, ThreadSpawnError};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
 use iceoryx2_cal::{
-    monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
+    dynamic_storage::*, monitoring::*, named_concept::NamedConceptListError, serialize::*,
+    static_storage::*,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
-use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::sync::{Arc, Mutex, MutexGuard, Once, OnceLock, Weak};
+use std::time::{Duration, Instant};
 
 /// The system-wide unique id of a [`Node`]
 #[derive(
@@ -183,6 +227,11 @@ pub enum NodeCreationFailure {
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalError,
+    /// Another iceoryx2 instance with a different root path uses the same
+    /// [`Global::prefix`](crate::config::Global::prefix) and shares the resources without a
+    /// path, like POSIX shared memory, with this instance. Every instance requires a unique
+    /// prefix, for instance by using [`NodeBuilder::domain()`].
+    PrefixCollision,
 }
 
 impl std::fmt::Display for NodeCreationFailure {
@@ -193,6 +242,50 @@ impl std::fmt::Display for NodeCreationFailure {
 
 impl std::error::Error for NodeCreationFailure {}
 
+/// The failures that can occur when a [`Node`] is renamed with [`Node::rename()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeRenameFailure {
+    /// The node details could not be replaced since the process does not have sufficient
+    /// permissions.
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for NodeRenameFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "NodeRenameFailure::{:?}", self)
+    }
+}
+
+impl std::error::Error for NodeRenameFailure {}
+
+/// The failures that can occur when the [`Config`] of a [`Node`] is replaced with
+/// [`Node::update_config()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeUpdateConfigFailure {
+    /// The new [`Config`] changes [`Config::global`]. The global settings define where the
+    /// resources of the [`Node`] and its services are located and can only be set when the
+    /// [`Node`] is created.
+    GlobalSettingsChanged,
+    /// The new [`Config`] changes the format, the target or the file of [`Config::log`]. The
+    /// logger of a process can be set only once, only the log level can be changed.
+    LogSettingsChanged,
+    /// The node details could not be replaced since the process does not have sufficient
+    /// permissions.
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for NodeUpdateConfigFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "NodeUpdateConfigFailure::{:?}", self)
+    }
+}
+
+impl std::error::Error for NodeUpdateConfigFailure {}
+
 /// The failures that can occur when a list of [`NodeState`]s is created with [`Node::list()`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum NodeWaitFailure {
@@ -200,6 +293,9 @@ pub enum NodeWaitFailure {
     Interrupt,
     /// A termination signal `SIGTERM` was received.
     TerminationRequest,
+    /// The resources that are required to wait could not be acquired, for instance the timer
+    /// thread of [`Node::wait_async()`] could not be spawned.
+    InternalError,
 }
 
 impl std::fmt::Display for NodeWaitFailure {
@@ -262,6 +358,12 @@ enum NodeReadServiceTagsFailure {
     InternalError,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum NodeReplaceDetailsFailure {
+    InsufficientPermissions,
+    InternalError,
+}
+
 /// Optional detailed informations that a [`Node`] can have. They can only be obtained when the
 /// process has sufficient access permissions.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -269,10 +371,19 @@ pub struct NodeDetails {
     executable: FileName,
     name: NodeName,
     config: Config,
+    #[serde(default = "AttributeSet::new")]
+    attributes: AttributeSet,
+    #[serde(default)]
+    generation: u64,
 }
 
 impl NodeDetails {
-    fn new(node_name: &Option<NodeName>, config: &Config) -> Self {
+    fn new(
+        node_name: &Option<NodeName>,
+        config: &Config,
+        attributes: &Option<AttributeSet>,
+        generation: u64,
+    ) -> Self {
         let executable = match Process::from_self().executable() {
             Ok(n) => n.file_name(),
             Err(e) => {
@@ -290,6 +401,8 @@ impl NodeDetails {
                 NodeName::new("").expect("An empty NodeName is always valid.")
             },
             config: config.clone(),
+            attributes: attributes.clone().unwrap_or_else(AttributeSet::new),
+            generation,
         }
     }
 
@@ -308,6 +421,21 @@ impl NodeDetails {
     pub fn config(&self) -> &Config {
         &self.config
     }
+
+    /// Returns the user defined [`AttributeSet`] of the [`Node`] that was set with
+    /// [`NodeBuilder::attributes()`].
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
+    /// Returns the generation of the [`Node`]. Every [`Node`] acquires a generation that is
+    /// higher than the one of all previously created [`Node`]s of the same iceoryx2 instance,
+    /// also when they were created by another process. A restarted process can therefore be
+    /// distinguished from its predecessor with the same [`NodeName`]. Is `0` when the [`Node`]
+    /// was created by a version that did not support generations.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 /// The current state of the [`Node`]. If the [`Node`] is dead all of its resources can be removed
@@ -316,6 +444,10 @@ impl NodeDetails {
 pub enum NodeState<Service: service::Service> {
     /// The [`Node`]s process is still alive.
     Alive(AliveNodeView<Service>),
+    /// The [`Node`]s process is still alive but the [`Node`] did not send a heartbeat within
+    /// its liveness deadline, see [`NodeBuilder::liveness_deadline()`]. This usually means
+    /// that the process hangs.
+    Unresponsive(AliveNodeView<Service>),
     /// The [`Node`]s process died without cleaning up the [`Node`]s resources. Another process has
     /// now the responsibility to cleanup all the stale resources.
     Dead(DeadNodeView<Service>),
@@ -331,6 +463,7 @@ impl<Service: service::Service> Clone for NodeState<Service> {
     fn clone(&self) -> Self {
         match self {
             NodeState::Alive(n) => NodeState::Alive(n.clone()),
+            NodeState::Unresponsive(n) => NodeState::Unresponsive(n.clone()),
             NodeState::Dead(n) => NodeState::Dead(n.clone()),
             NodeState::Inaccessible(n) => NodeState::Inaccessible(*n),
             NodeState::Undefined(n) => NodeState::Undefined(*n),
@@ -350,7 +483,13 @@ impl<Service: service::Service> NodeState<Service> {
 
         match Node::<Service>::get_node_state(config, node_id) {
             Ok(State::DoesNotExist) => Ok(None),
-            Ok(State::Alive) => Ok(Some(NodeState::Alive(node_view))),
+            Ok(State::Alive) => {
+                if Node::<Service>::has_missed_liveness_deadline(config, node_id) {
+                    Ok(Some(NodeState::Unresponsive(node_view)))
+                } else {
+                    Ok(Some(NodeState::Alive(node_view)))
+                }
+            }
             Ok(State::Dead) => Ok(Some(NodeState::Dead(DeadNodeView(node_view)))),
             Err(NodeListFailure::InsufficientPermissions) => {
                 Ok(Some(NodeState::Inaccessible(*node_id)))
@@ -365,6 +504,7 @@ impl<Service: service::Service> NodeState<Service> {
         match self {
             NodeState::Dead(node) => node.id(),
             NodeState::Alive(node) => node.id(),
+            NodeState::Unresponsive(node) => node.id(),
             NodeState::Inaccessible(ref node_id) => node_id,
             NodeState::Undefined(ref node_id) => node_id,
         }
@@ -390,6 +530,17 @@ pub trait NodeView {
     fn id(&self) -> &NodeId;
     /// Returns the [`NodeDetails`].
     fn details(&self) -> &Option<NodeDetails>;
+
+    /// Returns the generation of the [`Node`] when its [`NodeDetails`] are available, see
+    /// [`NodeDetails::generation()`].
+    fn generation(&self) -> Option<u64> {
+        self.details().as_ref().map(|details| details.generation())
+    }
+
+    /// Returns the point in time when the [`Node`] was created.
+    fn creation_time(&self) -> Time {
+        self.id().creation_time()
+    }
 }
 
 /// All the informations of a [`Node`] that is alive.
@@ -538,6 +689,26 @@ impl<Service: service::Service> DeadNodeView<Service> {
     }
 }
 
+// Removes a node that no longer exists from a service it is still registered at. The monitoring
+// token of the node is created as inter-process lock, so that concurrent cleanups never remove
+// the same ports twice.
+pub(crate) fn remove_vanished_node_from_service<Service: service::Service>(
+    node_id: &NodeId,
+    service_id: &ServiceId,
+    config: &Config,
+) -> bool {
+    let token = <Service::Monitoring as Monitoring>::Builder::new(&node_id.as_file_name())
+        .config(&node_monitoring_config::<Service>(config))
+        .token();
+
+    match token {
+        Ok(_token) => {
+            Service::__internal_remove_node_from_service(node_id, service_id, config).is_ok()
+        }
+        Err(_) => false,
+    }
+}
+
 fn acquire_all_node_detail_storages<Service: service::Service>(
     origin: &str,
     config: &<Service::StaticStorage as NamedConceptMgmt>::Configuration,
@@ -599,6 +770,65 @@ fn remove_node_details_directory<Service: service::Service>(
     }
 }
 
+fn remove_heartbeat_storage<Service: service::Service>(
+    origin: &str,
+    config: &Config,
+    node_id: &NodeId,
+) -> Result<(), NodeCleanupFailure> {
+    let msg = "Unable to remove node heartbeat storage";
+    match unsafe {
+        <Service::HeartbeatStorage as NamedConceptMgmt>::remove_cfg(
+            &node_id.as_file_name(),
+            &node_heartbeat_config::<Service>(config),
+        )
+    } {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} due to an internal failure.", msg);
+        }
+    }
+}
+
+fn node_log_context(
+    name: &NodeName,
+    node_id: &NodeId,
+    logger: Option<&'static dyn Log>,
+) -> LogContext {
+    let log_context = LogContext::new()
+        .field("node_name", name)
+        .field("node_id", node_id.value());
+    match logger {
+        Some(logger) => log_context.logger(logger),
+        None => log_context,
+    }
+}
+
+/// Invokes one of the [`iceoryx2_bb_log`] macros while the log context of the owning [`Node`]
+/// is entered, so that the message contains the [`NodeName`] and [`NodeId`]. The context is
+/// acquired with `enter_log_context()` of the origin, or of the explicitly provided entity, and
+/// is only entered when the message is actually logged since the ports are on the hot path.
+///
+/// ```ignore
+/// node_log!(fail!(from self, with MyError::Failure, "Unable to do something."));
+/// node_log!(in self.data_segment, warn!(from "custom origin", "Something went wrong."));
+/// ```
+macro_rules! node_log {
+    (in $context:expr, $log:ident!($($args:tt)*)) => {{
+        let _log_context = $context.enter_log_context();
+        $log!($($args)*);
+    }};
+    ($log:ident!(from $origin:expr, $($args:tt)*)) => {{
+        let _log_context = $origin.enter_log_context();
+        $log!(from $origin, $($args)*);
+    }};
+}
+pub(crate) use node_log;
+
 fn remove_node<Service: service::Service>(
     id: NodeId,
     config: &Config,
@@ -613,10 +843,140 @@ fn remove_node<Service: service::Service>(
     let detail_storages = acquire_all_node_detail_storages::<Service>(&origin, &details_config)?;
     remove_detail_storages::<Service>(&origin, detail_storages, &details_config)?;
     remove_node_details_directory::<Service>(config, &id)?;
+    remove_heartbeat_storage::<Service>(&origin, config, &id)?;
 
     Ok(true)
 }
 
+type TerminationCallback = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+struct TerminationCallbacks {
+    data: Mutex<Vec<TerminationCallback>>,
+}
+
+impl std::fmt::Debug for TerminationCallbacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TerminationCallbacks {{ number_of_callbacks: {} }}",
+            self.lock().len()
+        )
+    }
+}
+
+impl TerminationCallbacks {
+    // the callbacks are called while the process terminates, therefore a poisoned lock must
+    // not prevent the remaining ones from being called
+    fn lock(&self) -> MutexGuard<'_, Vec<TerminationCallback>> {
+        match self.data.lock() {
+            Ok(data) => data,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    fn add(&self, callback: TerminationCallback) {
+        self.lock().push(callback);
+    }
+
+    fn call_all(&self) {
+        let callbacks = std::mem::take(&mut *self.lock());
+        for callback in callbacks {
+            callback();
+        }
+    }
+}
+
+trait MarkDeadOnPanic: Send + Sync {
+    fn mark_dead(&self);
+}
+
+type NodesToMarkDeadOnPanic = Vec<(NodeId, Weak<dyn MarkDeadOnPanic>)>;
+
+// the nodes that were created with NodeBuilder::mark_dead_on_panic()
+// An iceoryx2 instance, defined by the service variant, the root path and the prefix, whose
+// ownership of the prefix was already verified by this process, see
+// `NodeBuilder::verify_prefix_ownership()`.
+struct VerifiedInstance {
+    service_variant: &'static str,
+    root_path: Path,
+    prefix: FileName,
+    instance_id: u128,
+}
+
+static VERIFIED_INSTANCES: Mutex<Vec<VerifiedInstance>> = Mutex::new(Vec::new());
+
+impl VerifiedInstance {
+    fn verified_instances() -> MutexGuard<'static, Vec<VerifiedInstance>> {
+        match VERIFIED_INSTANCES.lock() {
+            Ok(v) => v,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    fn is_instance_of<Service: service::Service>(&self, config: &Config) -> bool {
+        self.service_variant == core::any::type_name::<Service>()
+            && self.root_path == *config.global.root_path()
+            && self.prefix == config.global.prefix
+    }
+
+    fn instance_id<Service: service::Service>(config: &Config) -> Option<u128> {
+        Self::verified_instances()
+            .iter()
+            .find(|instance| instance.is_instance_of::<Service>(config))
+            .map(|instance| instance.instance_id)
+    }
+
+    fn add<Service: service::Service>(config: &Config, instance_id: u128) {
+        let mut verified_instances = Self::verified_instances();
+        verified_instances.retain(|instance| !instance.is_instance_of::<Service>(config));
+        verified_instances.push(VerifiedInstance {
+            service_variant: core::any::type_name::<Service>(),
+            root_path: *config.global.root_path(),
+            prefix: config.global.prefix,
+            instance_id,
+        });
+    }
+}
+
+static NODES_TO_MARK_DEAD_ON_PANIC: Mutex<NodesToMarkDeadOnPanic> = Mutex::new(Vec::new());
+static INSTALL_MARK_DEAD_PANIC_HOOK: Once = Once::new();
+
+fn nodes_to_mark_dead_on_panic() -> MutexGuard<'static, NodesToMarkDeadOnPanic> {
+    match NODES_TO_MARK_DEAD_ON_PANIC.lock() {
+        Ok(v) => v,
+        Err(e) => e.into_inner(),
+    }
+}
+
+// The panic hook is installed once and chains the previously installed hook, so that the
+// panic message is still reported after the nodes were marked as dead.
+fn mark_dead_on_panic(node_id: NodeId, node: Weak<dyn MarkDeadOnPanic>) {
+    INSTALL_MARK_DEAD_PANIC_HOOK.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            // the nodes are upgraded while the lock is held but marked and released afterwards,
+            // since releasing the last reference removes the node from the list
+            let nodes: Vec<_> = nodes_to_mark_dead_on_panic()
+                .iter()
+                .filter_map(|(_, node)| node.upgrade())
+                .collect();
+            for node in &nodes {
+                node.mark_dead();
+            }
+            drop(nodes);
+
+            previous_hook(info);
+        }));
+    });
+
+    nodes_to_mark_dead_on_panic().push((node_id, node));
+}
+
+fn do_not_mark_dead_on_panic(node_id: &NodeId) {
+    nodes_to_mark_dead_on_panic().retain(|(id, _)| id != node_id);
+}
+
 #[derive(Debug)]
 pub(crate) struct RegisteredServices {
     data: Mutex<HashMap<ServiceId, (ContainerHandle, u64)>>,
@@ -677,45 +1037,231 @@ impl RegisteredServices {
     }
 }
 
+/// A value that can be replaced while other threads still use the previous one, like the
+/// [`NodeDetails`] when the [`Node`] is renamed. Readers acquire a shared reference to the
+/// current value, therefore a replaced value is released as soon as the last reader drops it.
+#[derive(Debug)]
+struct Replaceable<T> {
+    current: Mutex<Arc<T>>,
+}
+
+impl<T> Replaceable<T> {
+    fn new(value: T) -> Self {
+        Self {
+            current: Mutex::new(Arc::new(value)),
+        }
+    }
+
+    fn get(&self) -> Arc<T> {
+        match self.current.lock() {
+            Ok(current) => current.clone(),
+            Err(e) => e.into_inner().clone(),
+        }
+    }
+
+    fn replace(&self, value: T) {
+        let value = Arc::new(value);
+        let previous = match self.current.lock() {
+            Ok(mut current) => core::mem::replace(&mut *current, value),
+            Err(e) => core::mem::replace(&mut *e.into_inner(), value),
+        };
+        // the previous value is released outside of the lock when it has no more readers
+        drop(previous);
+    }
+}
+
+/// The [`Config`] of a [`SharedNode`]. It stays valid when the [`Config`] is replaced
+/// concurrently with [`Node::update_config()`].
+pub(crate) struct NodeConfig(Arc<NodeDetails>);
+
+impl core::ops::Deref for NodeConfig {
+    type Target = Config;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.config
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedNode<Service: service::Service> {
     id: NodeId,
-    details: NodeDetails,
-    monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
+    details: Replaceable<NodeDetails>,
+    monitoring_token: Mutex<Option<<Service::Monitoring as Monitoring>::Token>>,
+    is_marked_dead_on_panic: bool,
     registered_services: RegisteredServices,
-    _details_storage: Service::StaticStorage,
+    heartbeat: Option<(Service::HeartbeatStorage, Duration)>,
+    watchdogs: Mutex<Vec<(usize, String)>>,
+    service_discovery_publisher: ServiceDiscoveryPublisher<Service>,
+    log_context: Replaceable<LogContext>,
+    details_storage: Mutex<Service::StaticStorage>,
+    thread_settings: ThreadSettings,
 }
 
 unsafe impl<Service: service::Service> Send for SharedNode<Service> {}
 unsafe impl<Service: service::Service> Sync for SharedNode<Service> {}
 
 impl<Service: service::Service> SharedNode<Service> {
-    pub(crate) fn config(&self) -> &Config {
-        &self.details.config
+    /// Returns the current [`NodeDetails`], see [`Node::rename()`] and
+    /// [`Node::update_config()`].
+    pub(crate) fn details(&self) -> Arc<NodeDetails> {
+        self.details.get()
+    }
+
+    /// Returns the [`Config`] that was last set with [`Node::update_config()`]. Its global
+    /// settings never change.
+    pub(crate) fn config(&self) -> NodeConfig {
+        NodeConfig(self.details())
     }
 
     pub(crate) fn id(&self) -> &NodeId {
         &self.id
     }
 
+    pub(crate) fn thread_settings(&self) -> &ThreadSettings {
+        &self.thread_settings
+    }
+
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn service_discovery_publisher(&self) -> &ServiceDiscoveryPublisher<Service> {
+        &self.service_discovery_publisher
+    }
+
+    /// Attaches the [`NodeName`] and [`NodeId`] to every log message of the current thread
+    /// until the returned guard goes out of scope.
+    pub(crate) fn enter_log_context(&self) -> LogContextGuard {
+        self.log_context.get().enter()
+    }
+
+    fn heartbeat(&self) {
+        if let Some((ref storage, _)) = self.heartbeat {
+            storage.get().beat();
+        }
+    }
+
+    pub(crate) fn heartbeat_storage(&self) -> Option<&NodeHeartbeat> {
+        self.heartbeat.as_ref().map(|(storage, _)| storage.get())
+    }
+
+    pub(crate) fn register_watchdog(&self, index: usize, name: &str) {
+        self.watchdogs
+            .lock()
+            .unwrap()
+            .push((index, name.to_string()));
+    }
+
+    pub(crate) fn release_watchdog(&self, index: usize) {
+        self.watchdogs
+            .lock()
+            .unwrap()
+            .retain(|(watchdog_index, _)| *watchdog_index != index);
+
+        if let Some(heartbeat) = self.heartbeat_storage() {
+            heartbeat.release_watchdog(index);
+        }
+    }
+
+    fn take_monitoring_token(&self) -> Option<<Service::Monitoring as Monitoring>::Token> {
+        match self.monitoring_token.lock() {
+            Ok(mut v) => v.take(),
+            Err(e) => e.into_inner().take(),
+        }
+    }
+
+    fn starving_watchdogs(&self) -> Vec<String> {
+        let heartbeat = match self.heartbeat_storage() {
+            Some(heartbeat) => heartbeat,
+            None => return Vec::new(),
+        };
+
+        self.watchdogs
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(index, _)| heartbeat.has_watchdog_missed_deadline(*index))
+            .map(|(_, name)| name.clone())
+            .collect()
+    }
+}
+
+impl<Service: service::Service> MarkDeadOnPanic for SharedNode<Service> {
+    fn mark_dead(&self) {
+        if let Some(token) = self.take_monitoring_token() {
+            token.abandon();
+        }
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
     fn drop(&mut self) {
-        if self.monitoring_token.get_mut().is_some() {
-            if self.config().global.node.cleanup_dead_nodes_on_destruction {
-                Node::<Service>::cleanup_dead_nodes(self.config());
+        let _log_context = self.enter_log_context();
+        if self.is_marked_dead_on_panic {
+            do_not_mark_dead_on_panic(&self.id);
+        }
+
+        let has_monitoring_token = match self.monitoring_token.get_mut() {
+            Ok(v) => v.is_some(),
+            Err(e) => e.into_inner().is_some(),
+        };
+
+        // a node that was marked as dead keeps its resources, they are removed by the dead
+        // node cleanup of another process
+        if has_monitoring_token {
+            let config = self.config();
+            if config.global.node.cleanup_dead_nodes_on_destruction {
+                Node::<Service>::cleanup_dead_nodes(&config);
             }
 
-            warn!(from self, when remove_node::<Service>(self.id, self.details.config()),
+            warn!(from self, when remove_node::<Service>(self.id, &config),
                 "Unable to remove node resources.");
         }
     }
 }
 
+/// The default minimum time between two scans for dead [`Node`]s in [`Node::wait()`], see
+/// [`NodeBuilder::cleanup_dead_nodes_on_wait()`].
+pub const DEFAULT_DEAD_NODE_CLEANUP_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+struct DeadNodeCleanup {
+    interval: Duration,
+    last_cleanup: Mutex<Option<Instant>>,
+}
+
+#[derive(Debug)]
+struct CyclicDeadline {
+    period: Duration,
+    next_deadline: Mutex<Option<Instant>>,
+}
+
+impl CyclicDeadline {
+    // Returns the time until the next deadline and advances the schedule by one period. When
+    // deadlines were already missed, they are skipped so that the schedule stays aligned.
+    fn time_until_next_deadline(&self) -> Duration {
+        let mut next_deadline = match self.next_deadline.lock() {
+            Ok(v) => v,
+            Err(e) => e.into_inner(),
+        };
+
+        let now = Instant::now();
+        let deadline = next_deadline.unwrap_or(now + self.period);
+        if now < deadline {
+            *next_deadline = Some(deadline + self.period);
+            return deadline - now;
+        }
+
+        let missed_periods = match self.period.as_nanos() {
+            0 => 0,
+            period => (now - deadline).as_nanos() / period + 1,
+        };
+        *next_deadline =
+            Some(deadline + Duration::from_nanos((self.period.as_nanos() * missed_periods) as u64));
+        Duration::ZERO
+    }
+}
+
 /// The [`Node`] is the entry point to the whole iceoryx2 infrastructure and owns all entities.
 ///
 /// As soon as a process crashes other processes can detect dead [`Node`]s via [`Node::list()`]
@@ -726,6 +1272,22 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 #[derive(Debug)]
 pub struct Node<Service: service::Service> {
     shared: Arc<SharedNode<Service>>,
+    // the node can only be renamed or reconfigured mutably, therefore the getters can return
+    // references into its own copy of the shared details
+    details: Arc<NodeDetails>,
+    termination_callbacks: TerminationCallbacks,
+    dead_node_cleanup: Option<DeadNodeCleanup>,
+    cyclic_deadline: Option<CyclicDeadline>,
+    termination_wakeup: OnceLock<TerminationWakeup>,
+    #[cfg(feature = "async")]
+    wait_timer: OnceLock<wait_async::WaitTimer>,
+}
+
+impl<Service: service::Service> Drop for Node<Service> {
+    fn drop(&mut self) {
+        self.termination_callbacks.call_all();
+        self.shared.service_discovery_publisher.close();
+    }
 }
 
 unsafe impl<Service: service::Service> Send for Node<Service> {}
@@ -733,12 +1295,17 @@ unsafe impl<Service: service::Service> Send for Node<Service> {}
 impl<Service: service::Service> Node<Service> {
     /// Returns the [`NodeName`].
     pub fn name(&self) -> &NodeName {
-        &self.shared.details.name
+        self.details.name()
     }
 
     /// Returns the [`Config`] that the [`Node`] will use to create any iceoryx2 entity.
     pub fn config(&self) -> &Config {
-        &self.shared.details.config
+        &self.details.config
+    }
+
+    /// Returns the user defined [`AttributeSet`] of the [`Node`].
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.details.attributes
     }
 
     /// Returns the [`NodeId`] of the [`Node`].
@@ -746,77 +1313,482 @@ impl<Service: service::Service> Node<Service> {
         &self.shared.id
     }
 
-    /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
-    pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
-        Builder::new(name, self.shared.clone())
+    pub(crate) fn shared_node(&self) -> &Arc<SharedNode<Service>> {
+        &self.shared
     }
 
-    /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
-    /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
-    /// return [`CallbackProgression::Continue`] to perform the next iteration or
-    /// [`CallbackProgression::Stop`] to stop the iteration immediately.
+    /// Returns the generation of the [`Node`], see [`NodeDetails::generation()`].
+    pub fn generation(&self) -> u64 {
+        self.details.generation()
+    }
+
+    /// Returns the [`ThreadSettings`] of the threads the [`Node`] uses internally, see
+    /// [`thread_settings`](crate::node::thread_settings) for a list of those threads.
+    pub fn thread_settings(&self) -> &ThreadSettings {
+        &self.shared.thread_settings
+    }
+
+    /// Changes the [`NodeName`] of the [`Node`] at runtime, for instance to append a role after
+    /// a leader election. The new [`NodeName`] replaces the one in the [`NodeDetails`] so that
+    /// other processes see it in [`Node::list()`], and it is attached to all log messages the
+    /// [`Node`] and its entities emit from now on.
+    ///
+    /// The [`NodeDetails`] are replaced atomically, another process that lists the [`Node`]
+    /// either sees the previous or the new [`NodeName`].
+    ///
     /// ```
-    /// # use iceoryx2::prelude::*;
-    /// Node::<ipc::Service>::list(Config::global_config(), |node_state| {
-    ///     println!("found node {:?}", node_state);
-    ///     CallbackProgression::Continue
-    /// });
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut node = NodeBuilder::new()
+    ///                 .name(&"controller".try_into()?)
+    ///                 .create::<ipc::Service>()?;
+    ///
+    /// node.rename(&"controller_leader".try_into()?)?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn list<F: FnMut(NodeState<Service>) -> CallbackProgression>(
-        config: &Config,
-        mut callback: F,
-    ) -> Result<(), NodeListFailure> {
-        let msg = "Unable to iterate over Node list";
-        let origin = "Node::list()";
-        let monitoring_config = node_monitoring_config::<Service>(config);
+    pub fn rename(&mut self, name: &NodeName) -> Result<(), NodeRenameFailure> {
+        let msg = format!("Unable to rename node to \"{}\"", name);
+        let mut details = self.current_details();
+        details.name = name.clone();
 
-        match Self::list_all_nodes(&monitoring_config) {
-            Ok(node_list) => {
-                for node_name in node_list {
-                    let node_id = core::str::from_utf8(node_name.as_bytes()).unwrap();
-                    let node_id = NodeId(node_id.parse::<u128>().unwrap().into());
-
-                    match NodeState::new(&node_id, config) {
-                        Ok(Some(node_state)) => {
-                            if callback(node_state) == CallbackProgression::Stop {
-                                break;
-                            }
-                        }
-                        Ok(None) => (),
-                        Err(e) => {
-                            fail!(from origin, with e,
-                                "{msg} since the following error occurred ({:?}).", e);
-                        }
-                    }
-                }
+        match self.replace_details(&details, &msg) {
+            Ok(()) => (),
+            Err(NodeReplaceDetailsFailure::InsufficientPermissions) => {
+                fail!(from self, with NodeRenameFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions.");
             }
-            Err(e) => {
-                fail!(from origin, with e,
-                    "{msg} since the node list could not be acquired ({:?}).", e);
+            Err(NodeReplaceDetailsFailure::InternalError) => {
+                fail!(from self, with NodeRenameFailure::InternalError,
+                    "{msg} due to an internal failure.");
             }
         }
 
+        let custom_logger = self.shared.log_context.get().custom_logger();
+        self.shared
+            .log_context
+            .replace(node_log_context(name, self.id(), custom_logger));
+
         Ok(())
     }
 
-    pub(crate) unsafe fn staged_death(&mut self) -> <Service::Monitoring as Monitoring>::Token {
-        (*self.shared.monitoring_token.get()).take().unwrap()
-    }
+    /// Replaces the [`Config`] of the [`Node`] at runtime. All services that are created or
+    /// opened afterwards and all ports that are created afterwards use the [`Config::defaults`]
+    /// of the new [`Config`].
+    ///
+    /// The settings of already existing services, like the maximum number of
+    /// [`Publisher`](crate::port::publisher::Publisher)s or the buffer size of a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber), are defined when the service is
+    /// created and stay unchanged. [`Config::global`] defines where the resources of the
+    /// [`Node`] and its services are located and must not differ from the current [`Config`],
+    /// otherwise [`NodeUpdateConfigFailure::GlobalSettingsChanged`] is returned.
+    ///
+    /// A changed log level in [`Config::log`] is applied to the whole process. The format, the
+    /// target and the file of the logger can be set only once per process, when they differ
+    /// [`NodeUpdateConfigFailure::LogSettingsChanged`] is returned.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// let mut config = node.config().clone();
+    /// config.defaults.publish_subscribe.subscriber_max_buffer_size = 16;
+    /// node.update_config(&config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_config(&mut self, config: &Config) -> Result<(), NodeUpdateConfigFailure> {
+        let msg = "Unable to update the config of the node";
+        if config.global != self.config().global {
+            fail!(from self, with NodeUpdateConfigFailure::GlobalSettingsChanged,
+                "{msg} since the global settings can only be defined when the node is created.");
+        }
 
-    /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
-    /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
-    /// signal was received.
-    pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
-        let msg = "Unable to wait on node";
-        if SignalHandler::termination_requested() {
-            fail!(from self, with NodeWaitFailure::TerminationRequest,
-                "{msg} since a termination request was received.");
+        let log = &self.config().log;
+        if config.log.format != log.format
+            || config.log.target != log.target
+            || config.log.file != log.file
+        {
+            fail!(from self, with NodeUpdateConfigFailure::LogSettingsChanged,
+                "{msg} since the format, the target and the file of the logger can be set only once per process.");
         }
+        let previous_log_level = log.level;
 
-        match nanosleep(cycle_time) {
-            Ok(()) => {
-                if SignalHandler::termination_requested() {
-                    fail!(from self, with NodeWaitFailure::TerminationRequest,
+        let mut details = self.current_details();
+        details.config = config.clone();
+
+        match self.replace_details(&details, msg) {
+            Ok(()) => (),
+            Err(NodeReplaceDetailsFailure::InsufficientPermissions) => {
+                fail!(from self, with NodeUpdateConfigFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions.");
+            }
+            Err(NodeReplaceDetailsFailure::InternalError) => {
+                fail!(from self, with NodeUpdateConfigFailure::InternalError,
+                    "{msg} due to an internal failure.");
+            }
+        }
+
+        if config.log.level != previous_log_level {
+            config.log.apply_level();
+        }
+
+        Ok(())
+    }
+
+    fn current_details(&self) -> NodeDetails {
+        (*self.details).clone()
+    }
+
+    fn replace_details(
+        &mut self,
+        details: &NodeDetails,
+        msg: &str,
+    ) -> Result<(), NodeReplaceDetailsFailure> {
+        let serialized_details = match <Service::ConfigSerializer>::serialize(details) {
+            Ok(serialized_details) => serialized_details,
+            Err(SerializeError::InternalError) => {
+                fail!(from self, with NodeReplaceDetailsFailure::InternalError,
+                    "{msg} since the node details could not be serialized.");
+            }
+        };
+
+        let details_config = node_details_config::<Service>(self.config(), self.id());
+        let mut details_storage = match self.shared.details_storage.lock() {
+            Ok(details_storage) => details_storage,
+            Err(e) => e.into_inner(),
+        };
+
+        // replaces the previous node details in one step so that the node remains discoverable
+        match <Service::StaticStorage as StaticStorage>::Builder::new(
+            &FileName::new(b"node").unwrap(),
+        )
+        .config(&details_config)
+        .has_ownership(false)
+        .create_or_replace(&serialized_details)
+        {
+            Ok(storage) => *details_storage = storage,
+            Err(StaticStorageCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeReplaceDetailsFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to replace the node details.");
+            }
+            Err(e) => {
+                fail!(from self, with NodeReplaceDetailsFailure::InternalError,
+                    "{msg} since the node details could not be replaced ({:?}).", e);
+            }
+        }
+        self.shared.details.replace(details.clone());
+        self.details = self.shared.details();
+
+        Ok(())
+    }
+
+    /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
+    pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
+        Builder::new(name, self.shared.clone())
+    }
+
+    /// Creates a [`Subscriber`] for the built-in service discovery. It receives a
+    /// [`ServiceDiscoveryEvent`] whenever a [`Service`](crate::service::Service) is created or
+    /// removed by any [`Node`] in the system that uses the same [`Config`], so that the current
+    /// set of services can be tracked without polling
+    /// [`Service::list()`](crate::service::Service::list()).
+    ///
+    /// Only events that occur after the [`Subscriber`] was created are received. A late-joining
+    /// process acquires the initial set of services once with
+    /// [`Service::list()`](crate::service::Service::list()) after the [`Subscriber`] was created.
+    /// The underlying service discovery service is created with the first [`Subscriber`], until
+    /// then no [`Node`] publishes any [`ServiceDiscoveryEvent`]s.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let discovery = node.service_discovery()?;
+    ///
+    /// while let Some(event) = discovery.receive()? {
+    ///     println!("{:?} {:?}", event.kind(), event.service_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn service_discovery(
+        &self,
+    ) -> Result<Subscriber<Service, ServiceDiscoveryEvent, ()>, ServiceDiscoveryCreateError> {
+        let service = fail!(from self, when discovery::open_or_create_service(&self.shared),
+            "Unable to create service discovery subscriber since the service discovery service could not be opened or created.");
+        let subscriber = fail!(from self, when service.subscriber_builder().create(),
+            "Unable to create service discovery subscriber since the subscriber could not be created.");
+
+        Ok(subscriber)
+    }
+
+    /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
+    /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
+    /// return [`CallbackProgression::Continue`] to perform the next iteration or
+    /// [`CallbackProgression::Stop`] to stop the iteration immediately.
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// Node::<ipc::Service>::list(Config::global_config(), |node_state| {
+    ///     println!("found node {:?}", node_state);
+    ///     CallbackProgression::Continue
+    /// });
+    /// ```
+    pub fn list<F: FnMut(NodeState<Service>) -> CallbackProgression>(
+        config: &Config,
+        mut callback: F,
+    ) -> Result<(), NodeListFailure> {
+        let msg = "Unable to iterate over Node list";
+        let origin = "Node::list()";
+
+        let node_states = fail!(from origin, when Self::node_states(config),
+                "{msg} since the node list could not be acquired.");
+
+        for node_state in node_states {
+            match node_state {
+                Ok(node_state) => {
+                    if callback(node_state) == CallbackProgression::Stop {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    fail!(from origin, with e,
+                        "{msg} since the following error occurred ({:?}).", e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the [`NodeState<Service>`]s of all [`Node`]s in the system under
+    /// a given [`Config`]. In contrast to [`Node::list()`], the states of all [`Node`]s are
+    /// acquired before the iterator is returned, so that every failure is reported by this call
+    /// and not in the middle of the iteration.
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// use iceoryx2::node::NodeView;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut alive_nodes: Vec<_> = Node::<ipc::Service>::list_iter(Config::global_config())?
+    ///     .filter_map(|node_state| match node_state {
+    ///         NodeState::Alive(view) => Some(*view.id()),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// alive_nodes.sort_by_key(|id| id.value());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_iter(
+        config: &Config,
+    ) -> Result<impl Iterator<Item = NodeState<Service>>, NodeListFailure> {
+        let msg = "Unable to acquire Node list";
+        let origin = "Node::list_iter()";
+
+        let node_states = fail!(from origin, when Self::node_states(config),
+                "{msg} since the node list could not be acquired.");
+        let node_states = fail!(from origin,
+                when node_states.collect::<Result<Vec<_>, _>>(),
+                "{msg} since the state of a node could not be acquired.");
+
+        Ok(node_states.into_iter())
+    }
+
+    // Lazily acquires the state of every node, nodes that were removed in the meantime are
+    // skipped.
+    fn node_states(
+        config: &Config,
+    ) -> Result<
+        impl Iterator<Item = Result<NodeState<Service>, NodeListFailure>> + '_,
+        NodeListFailure,
+    > {
+        let monitoring_config = node_monitoring_config::<Service>(config);
+        let node_list = Self::list_all_nodes(&monitoring_config)?;
+
+        Ok(node_list.into_iter().filter_map(move |node_name| {
+            let node_id = core::str::from_utf8(node_name.as_bytes()).unwrap();
+            let node_id = NodeId(node_id.parse::<u128>().unwrap().into());
+
+            NodeState::new(&node_id, config).transpose()
+        }))
+    }
+
+    /// Returns the names of all domains that contain at least one [`Node`], see
+    /// [`NodeBuilder::domain()`]. The provided [`Config`] is the one the [`Node`]s were created
+    /// with, before the domain was applied.
+    pub fn list_domains(config: &Config) -> Result<Vec<FileName>, NodeListFailure> {
+        let msg = "Unable to list all domains";
+        let origin = "Node::list_domains()";
+        let domain_dir = config.global.domain_dir();
+
+        let directory = match Directory::new(&domain_dir) {
+            Ok(directory) => directory,
+            Err(DirectoryOpenError::DoesNotExist) => return Ok(vec![]),
+            Err(DirectoryOpenError::InsufficientPermissions) => {
+                fail!(from origin, with NodeListFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to open the domain directory \"{}\".", domain_dir);
+            }
+            Err(e) => {
+                fail!(from origin, with NodeListFailure::InternalError,
+                    "{msg} since the domain directory \"{}\" could not be opened ({:?}).", domain_dir, e);
+            }
+        };
+
+        let entries = fail!(from origin, when directory.contents(),
+            map DirectoryReadError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+            unmatched NodeListFailure::InternalError,
+            "{msg} since the contents of the domain directory \"{}\" could not be read.", domain_dir);
+
+        let mut domains = vec![];
+        for entry in entries
+            .iter()
+            .filter(|entry| entry.metadata().file_type() == FileType::Directory)
+        {
+            let domain = *entry.name();
+            let mut domain_config = config.clone();
+            if domain_config.global.set_domain(&domain).is_err() {
+                continue;
+            }
+
+            let mut has_nodes = false;
+            Self::list(&domain_config, |_| {
+                has_nodes = true;
+                CallbackProgression::Stop
+            })?;
+
+            if has_nodes {
+                domains.push(domain);
+            }
+        }
+        domains.sort_by(|lhs, rhs| lhs.as_bytes().cmp(rhs.as_bytes()));
+
+        Ok(domains)
+    }
+
+    pub(crate) unsafe fn staged_death(&mut self) -> <Service::Monitoring as Monitoring>::Token {
+        self.shared.take_monitoring_token().unwrap()
+    }
+
+    /// Registers a callback that is called exactly once, either when [`Node::wait()`] or
+    /// [`Node::wait_async()`] observes a `SIGINT` or `SIGTERM` signal or when the [`Node`] goes
+    /// out of scope, whatever happens first. The callbacks are called in the order they were registered.
+    ///
+    /// It can be used to inform the rest of the application that the process is about to
+    /// terminate, for instance to send a final "offline" sample or to detach ports
+    /// deterministically.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let keep_running = Arc::new(AtomicBool::new(true));
+    ///
+    /// let keep_running_hook = keep_running.clone();
+    /// node.on_termination(move || keep_running_hook.store(false, Ordering::Relaxed));
+    ///
+    /// while keep_running.load(Ordering::Relaxed) {
+    ///     let _ = node.wait(Duration::from_millis(100));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_termination<F: FnOnce() + Send + 'static>(&self, callback: F) {
+        self.termination_callbacks.add(Box::new(callback));
+    }
+
+    /// Sends a heartbeat to signal that the [`Node`] is still responsive. It is only required
+    /// when the [`Node`] was created with a [`NodeBuilder::liveness_deadline()`] and the event
+    /// loop does not use [`Node::wait()`], which sends heartbeats automatically.
+    pub fn heartbeat(&self) {
+        self.shared.heartbeat();
+    }
+
+    /// Registers a [`NodeWatchdog`] with the provided name that must be fed at least once
+    /// within the deadline. It is intended to monitor the liveness of a single thread, see
+    /// [`watchdog`] for an example. When any [`NodeWatchdog`] misses its deadline, the [`Node`]
+    /// is reported as [`NodeState::Unresponsive`].
+    ///
+    /// Requires that the [`Node`] was created with a [`NodeBuilder::liveness_deadline()`].
+    pub fn watchdog(
+        &self,
+        name: &str,
+        deadline: Duration,
+    ) -> Result<NodeWatchdog<Service>, NodeWatchdogCreateError> {
+        NodeWatchdog::new(&self.shared, name, deadline)
+    }
+
+    /// Returns the names of all [`NodeWatchdog`]s of the [`Node`] that missed their deadline.
+    pub fn starving_watchdogs(&self) -> Vec<String> {
+        self.shared.starving_watchdogs()
+    }
+
+    /// Returns the [`TerminationWakeup`] of the [`Node`]. Its file descriptor becomes readable
+    /// when a `SIGINT` or `SIGTERM` signal is received, so that the [`Node`] can be registered
+    /// in an external event loop like `epoll`, `kqueue` or `mio` next to the file descriptors
+    /// of [`Listener`](crate::port::listener::Listener)s and sockets. When it becomes readable,
+    /// a non-blocking [`Node::wait()`] with [`Duration::ZERO`] reports the termination request
+    /// and resets the [`TerminationWakeup`].
+    ///
+    /// The [`TerminationWakeup`] is created on the first call and lives as long as the [`Node`].
+    pub fn termination_wakeup(&self) -> Result<&TerminationWakeup, TerminationWakeupCreateError> {
+        if let Some(wakeup) = self.termination_wakeup.get() {
+            return Ok(wakeup);
+        }
+
+        let wakeup = fail!(from self, when TerminationWakeup::new(),
+            "Unable to acquire the termination wakeup of the node.");
+        let _ = self.termination_wakeup.set(wakeup);
+
+        Ok(self.termination_wakeup.get().unwrap())
+    }
+
+    /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
+    /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
+    /// signal was received. In both cases all callbacks registered with
+    /// [`Node::on_termination()`] are called before it returns.
+    ///
+    /// If the [`Node`] was created with a [`NodeBuilder::liveness_deadline()`] it sends a
+    /// heartbeat at least every [`NodeBuilder::heartbeat_interval()`] while waiting.
+    ///
+    /// If the [`Node`] was created with [`NodeBuilder::cleanup_dead_nodes_on_wait()`] it removes
+    /// the stale resources of all dead [`Node`]s before it waits, at most once per
+    /// [`NodeBuilder::dead_node_cleanup_interval()`].
+    ///
+    /// If the [`Node`] was created with a [`NodeBuilder::cyclic_deadline()`] the provided
+    /// `cycle_time` is ignored and it waits until the next deadline of the cyclic schedule.
+    pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
+        let _log_context = self.shared.enter_log_context();
+        let result = self.wait_impl(cycle_time);
+        self.call_termination_callbacks_on_request(&result);
+
+        result
+    }
+
+    // every wait of [`Node::wait()`] and [`Node::wait_async()`] that observes a termination
+    // request must call the termination callbacks
+    fn call_termination_callbacks_on_request(&self, result: &Result<(), NodeWaitFailure>) {
+        if let Err(NodeWaitFailure::Interrupt | NodeWaitFailure::TerminationRequest) = result {
+            self.termination_callbacks.call_all();
+        }
+    }
+
+    fn wait_impl(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
+        let msg = "Unable to wait on node";
+        let cycle_time = self.prepare_wait(cycle_time)?;
+
+        match self.sleep_with_heartbeat(cycle_time) {
+            Ok(()) => {
+                if SignalHandler::termination_requested() {
+                    fail!(from self, with NodeWaitFailure::TerminationRequest,
                         "{msg} since a termination request was received.");
                 } else {
                     Ok(())
@@ -834,6 +1806,115 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
+    // performs the steps that precede every wait of [`Node::wait()`] and
+    // [`Node::wait_async()`] and returns the time that shall be waited
+    fn prepare_wait(&self, cycle_time: Duration) -> Result<Duration, NodeWaitFailure> {
+        if let Some(wakeup) = self.termination_wakeup.get() {
+            wakeup.reset();
+        }
+
+        if SignalHandler::termination_requested() {
+            fail!(from self, with NodeWaitFailure::TerminationRequest,
+                "Unable to wait on node since a termination request was received.");
+        }
+
+        self.cleanup_dead_nodes_when_due();
+
+        Ok(match &self.cyclic_deadline {
+            Some(cyclic_deadline) => cyclic_deadline.time_until_next_deadline(),
+            None => cycle_time,
+        })
+    }
+
+    fn heartbeat_interval(&self) -> Option<Duration> {
+        self.shared
+            .heartbeat
+            .as_ref()
+            .map(|(_, heartbeat_interval)| *heartbeat_interval)
+    }
+
+    #[cfg(feature = "async")]
+    fn wait_timer(&self) -> Result<&wait_async::WaitTimer, ThreadSpawnError> {
+        if let Some(timer) = self.wait_timer.get() {
+            return Ok(timer);
+        }
+
+        let timer = wait_async::WaitTimer::new(&self.shared.thread_settings)?;
+        let _ = self.wait_timer.set(timer);
+
+        Ok(self.wait_timer.get().unwrap())
+    }
+
+    fn cleanup_dead_nodes_when_due(&self) {
+        let dead_node_cleanup = match &self.dead_node_cleanup {
+            Some(v) => v,
+            None => return,
+        };
+
+        // when another thread is already cleaning up, this thread does not have to
+        let mut last_cleanup = match dead_node_cleanup.last_cleanup.try_lock() {
+            Ok(v) => v,
+            Err(_) => return,
+        };
+
+        if let Some(last_cleanup) = *last_cleanup {
+            if last_cleanup.elapsed() < dead_node_cleanup.interval {
+                return;
+            }
+        }
+
+        let cleanup_state = Node::<Service>::cleanup_dead_nodes(self.config());
+        if cleanup_state.cleanups != 0 || cleanup_state.failed_cleanups != 0 {
+            debug!(from self, "Cleaned up dead nodes while waiting ({:?}).", cleanup_state);
+        }
+        *last_cleanup = Some(Instant::now());
+    }
+
+    fn sleep_with_heartbeat(&self, cycle_time: Duration) -> Result<(), NanosleepError> {
+        let heartbeat_interval = match self.heartbeat_interval() {
+            Some(heartbeat_interval) => heartbeat_interval,
+            None => return nanosleep(cycle_time),
+        };
+
+        self.shared.heartbeat();
+        let mut remaining_time = cycle_time;
+        while !remaining_time.is_zero() {
+            let sleep_time = remaining_time.min(heartbeat_interval);
+            nanosleep(sleep_time)?;
+            self.shared.heartbeat();
+            remaining_time -= sleep_time;
+        }
+
+        Ok(())
+    }
+
+    /// Async variant of [`Node::wait()`]. The returned [`Future`](std::future::Future) becomes
+    /// ready when the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
+    /// when a `SIGINT` or `SIGTERM` signal was received, after all callbacks registered with
+    /// [`Node::on_termination()`] were called.
+    ///
+    /// Like [`Node::wait()`], it removes dead [`Node`]s, sends heartbeats and follows the
+    /// [`NodeBuilder::cyclic_deadline()`]. The future does not depend on a specific async
+    /// runtime and does not block the executor.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// while node.wait_async(Duration::from_millis(100)).await.is_ok() {
+    ///     // do some work
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn wait_async(&self, cycle_time: Duration) -> wait_async::NodeWaitFuture<'_, Service> {
+        wait_async::NodeWaitFuture::new(self, cycle_time)
+    }
+
     /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
     /// removed from all registered [`Service`](crate::service::Service)s.
     ///
@@ -924,6 +2005,24 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
+    fn has_missed_liveness_deadline(config: &Config, node_id: &NodeId) -> bool {
+        match <Service::HeartbeatStorage as DynamicStorage<NodeHeartbeat>>::Builder::new(
+            &node_id.as_file_name(),
+        )
+        .config(&node_heartbeat_config::<Service>(config))
+        .has_ownership(false)
+        .open()
+        {
+            Ok(storage) => storage.get().has_missed_deadline(),
+            Err(DynamicStorageOpenError::DoesNotExist) => false,
+            Err(e) => {
+                debug!(from "Node::has_missed_liveness_deadline()",
+                    "Unable to open the heartbeat storage of the node {:?} ({:?}).", node_id, e);
+                false
+            }
+        }
+    }
+
     fn get_node_state(config: &Config, node_id: &NodeId) -> Result<State, NodeListFailure> {
         let my_pid = Process::from_self().id();
         let node_pid = node_id.0.pid();
@@ -1082,6 +2181,16 @@ impl<Service: service::Service> Node<Service> {
 pub struct NodeBuilder {
     name: Option<NodeName>,
     config: Option<Config>,
+    attributes: Option<AttributeSet>,
+    heartbeat_interval: Option<Duration>,
+    liveness_deadline: Option<Duration>,
+    logger: Option<&'static dyn Log>,
+    domain: Option<FileName>,
+    cleanup_dead_nodes_on_wait: bool,
+    dead_node_cleanup_interval: Option<Duration>,
+    cyclic_deadline: Option<Duration>,
+    mark_dead_on_panic: bool,
+    thread_settings: ThreadSettings,
 }
 
 impl NodeBuilder {
@@ -1103,6 +2212,231 @@ impl NodeBuilder {
         self
     }
 
+    /// Places the [`Node`] and all entities it creates in the provided domain. Entities of
+    /// different domains are completely isolated from each other, as if every domain used its
+    /// own [`Config`] with a distinct root path and prefix, see
+    /// [`Global::set_domain()`](crate::config::Global::set_domain()).
+    /// All domains can be listed with [`Node::list_domains()`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .domain(&"robot_7".try_into()?)
+    ///                 .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn domain(mut self, value: &FileName) -> Self {
+        self.domain = Some(*value);
+        self
+    }
+
+    /// Sets user defined key-value [`Attribute`](crate::service::attribute::Attribute)s of the
+    /// [`Node`]. They are stored in the [`NodeDetails`] and can be acquired by every process
+    /// via [`NodeView::details()`], for instance when iterating over all nodes with
+    /// [`Node::list()`].
+    pub fn attributes(mut self, value: &AttributeSpecifier) -> Self {
+        self.attributes = Some(value.attributes().clone());
+        self
+    }
+
+    /// Sets the interval in which [`Node::wait()`] sends heartbeats. It has only an effect when
+    /// a [`NodeBuilder::liveness_deadline()`] is set and defaults to half of the liveness
+    /// deadline.
+    pub fn heartbeat_interval(mut self, value: Duration) -> Self {
+        self.heartbeat_interval = Some(value);
+        self
+    }
+
+    /// Enables the heartbeat of the [`Node`]. When the [`Node`] does not send a heartbeat, either
+    /// via [`Node::wait()`] or [`Node::heartbeat()`], within the provided deadline, other
+    /// processes see it as [`NodeState::Unresponsive`] even though its process is still alive.
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .liveness_deadline(Duration::from_secs(1))
+    ///                 .heartbeat_interval(Duration::from_millis(250))
+    ///                 .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn liveness_deadline(mut self, value: Duration) -> Self {
+        self.liveness_deadline = Some(value);
+        self
+    }
+
+    /// When enabled, [`Node::wait()`] periodically removes the stale resources of all dead
+    /// [`Node`]s, like [`Node::cleanup_dead_nodes()`], so that a long running process does not
+    /// require a separate cleanup loop. The scans are rate limited by the
+    /// [`NodeBuilder::dead_node_cleanup_interval()`]. Disabled by default.
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .cleanup_dead_nodes_on_wait(true)
+    ///                 .dead_node_cleanup_interval(Duration::from_secs(5))
+    ///                 .create::<ipc::Service>()?;
+    ///
+    /// while node.wait(Duration::from_millis(100)).is_ok() {
+    ///     // do some work
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cleanup_dead_nodes_on_wait(mut self, value: bool) -> Self {
+        self.cleanup_dead_nodes_on_wait = value;
+        self
+    }
+
+    /// Sets the minimum time between two scans for dead [`Node`]s in [`Node::wait()`]. It has
+    /// only an effect when [`NodeBuilder::cleanup_dead_nodes_on_wait()`] is enabled and
+    /// defaults to [`DEFAULT_DEAD_NODE_CLEANUP_INTERVAL`].
+    pub fn dead_node_cleanup_interval(mut self, value: Duration) -> Self {
+        self.dead_node_cleanup_interval = Some(value);
+        self
+    }
+
+    /// Aligns the wakeups of [`Node::wait()`] to an absolute monotonic schedule with the
+    /// provided period, starting with the first call. In contrast to the cycle time of
+    /// [`Node::wait()`], the time spent between two calls does not delay the schedule. When
+    /// a call comes too late, it returns immediately and the missed deadlines are skipped.
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .cyclic_deadline(Duration::from_millis(10))
+    ///                 .create::<ipc::Service>()?;
+    ///
+    /// // wakes up every 10ms, independent of the duration of the work
+    /// while node.wait(Duration::ZERO).is_ok() {
+    ///     // do some work
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cyclic_deadline(mut self, period: Duration) -> Self {
+        self.cyclic_deadline = Some(period);
+        self
+    }
+
+    /// When enabled, a panic in any thread of the process marks the [`Node`] immediately as
+    /// dead, as if the process had crashed. Other processes see it as [`NodeState::Dead`]
+    /// without waiting for the process to terminate and can remove its stale resources with
+    /// [`Node::cleanup_dead_nodes()`]. The [`Node`] does not remove its resources when it goes
+    /// out of scope afterwards, even when the panic was caught. Disabled by default.
+    ///
+    /// The panic hook is installed with the creation of the first [`Node`] that enables it
+    /// and calls the previously installed panic hook after the [`Node`]s were marked as dead.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .mark_dead_on_panic(true)
+    ///                 .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mark_dead_on_panic(mut self, value: bool) -> Self {
+        self.mark_dead_on_panic = value;
+        self
+    }
+
+    /// Pins the threads the [`Node`] uses internally to the provided CPU cores, for instance to
+    /// keep them off CPU cores that are isolated for real-time work. CPU cores that do not
+    /// exist are ignored. When not set, the internal threads inherit the CPU affinity of the
+    /// thread that spawns them. See [`thread_settings`](crate::node::thread_settings) for a
+    /// list of the internal threads.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .thread_cpu_affinity(&[0])
+    ///                 .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn thread_cpu_affinity(mut self, cores: &[usize]) -> Self {
+        self.thread_settings.cpu_affinity = Some(cores.to_vec());
+        self
+    }
+
+    /// Sets the [`Scheduler`] of the threads the [`Node`] uses internally. The real-time
+    /// schedulers [`Scheduler::Fifo`] and [`Scheduler::RoundRobin`] usually require elevated
+    /// privileges, otherwise [`NodeBuilder::create()`] fails with
+    /// [`NodeCreationFailure::InsufficientPermissions`]. When neither the [`Scheduler`] nor the
+    /// [`NodeBuilder::thread_priority()`] is set, the internal threads inherit the scheduling
+    /// attributes of the thread that spawns them.
+    pub fn thread_scheduler(mut self, value: Scheduler) -> Self {
+        self.thread_settings.scheduler = Some(value);
+        self
+    }
+
+    /// Sets the priority of the threads the [`Node`] uses internally, whereby `0` represents the
+    /// lowest and `255` the highest priority. It is mapped to the priority range of the
+    /// [`NodeBuilder::thread_scheduler()`], which defaults to [`Scheduler::Other`] that has no
+    /// priorities on most platforms.
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::scheduler::Scheduler;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .thread_cpu_affinity(&[0, 1])
+    ///                 .thread_scheduler(Scheduler::Fifo)
+    ///                 .thread_priority(10)
+    ///                 .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn thread_priority(mut self, value: u8) -> Self {
+        self.thread_settings.priority = Some(value);
+        self
+    }
+
+    /// Sets a custom [`Log`]ger for the [`Node`]. Every message that is logged by the [`Node`]
+    /// or by an entity created from it, like a [`Service`](crate::service::Service) or a port,
+    /// is forwarded to this [`Log`]ger instead of the global one. Independent of the
+    /// [`Log`]ger, all those messages carry the [`NodeName`] and [`NodeId`] as structured
+    /// fields, see [`Log::log_with_context()`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// static LOGGER: iceoryx2_bb_log::logger::buffer::Logger =
+    ///     iceoryx2_bb_log::logger::buffer::Logger::new();
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///                 .name(&"camera_driver".try_into()?)
+    ///                 .logger(&LOGGER)
+    ///                 .create::<ipc::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn logger<T: Log + 'static>(mut self, value: &'static T) -> Self {
+        self.logger = Some(value);
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1118,36 +2452,288 @@ impl NodeBuilder {
         self,
         node_id: UniqueSystemId,
     ) -> Result<Node<Service>, NodeCreationFailure> {
-        let config = if let Some(ref config) = self.config {
+        let mut config = if let Some(ref config) = self.config {
             config.clone()
         } else {
             Config::global_config().clone()
         };
 
+        let msg = "Unable to create node";
+        // the lock based fallback of the 64-bit atomics is not robust, a process that dies
+        // while holding the lock of an atomic in shared memory blocks every other process
+        #[cfg(not(target_has_atomic = "64"))]
+        if <Service as service::internal::ServiceInternal<Service>>::__internal_is_inter_process() {
+            fail!(from self, with NodeCreationFailure::InternalError,
+                "{msg} since the target does not support 64-bit atomics which are required for inter-process communication. Only process local services, like local::Service, are supported.");
+        }
+
+        if let Some(domain) = self.domain {
+            if let Err(e) = config.global.set_domain(&domain) {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since the domain \"{}\" leads to an invalid root path or prefix ({:?}).", domain, e);
+            }
+            self.create_domain_directory(&config)?;
+        }
+
         if config.global.node.cleanup_dead_nodes_on_creation {
             Node::<Service>::cleanup_dead_nodes(&config);
         }
 
-        let msg = "Unable to create node";
+        let instance_marker = self.verify_prefix_ownership::<Service>(&config)?;
+        self.verify_thread_settings()?;
+
         let monitor_name = fatal_panic!(from self, when FileName::new(node_id.value().to_string().as_bytes()),
                                 "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
-        let (details_storage, details) =
-            self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
+        let (details_storage, details) = self.create_node_details_storage::<Service>(
+            &config,
+            &NodeId(node_id),
+            instance_marker.get().next_node_generation(),
+        )?;
+        let heartbeat = self.create_heartbeat_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
 
+        let log_context = node_log_context(details.name(), &NodeId(node_id), self.logger);
+
+        let dead_node_cleanup = if self.cleanup_dead_nodes_on_wait {
+            Some(DeadNodeCleanup {
+                interval: self
+                    .dead_node_cleanup_interval
+                    .unwrap_or(DEFAULT_DEAD_NODE_CLEANUP_INTERVAL),
+                last_cleanup: Mutex::new(None),
+            })
+        } else {
+            None
+        };
+
+        let cyclic_deadline = self.cyclic_deadline.map(|period| CyclicDeadline {
+            period,
+            next_deadline: Mutex::new(None),
+        });
+
+        let shared = Arc::new(SharedNode {
+            id: NodeId(node_id),
+            monitoring_token: Mutex::new(Some(monitoring_token)),
+            is_marked_dead_on_panic: self.mark_dead_on_panic,
+            registered_services: RegisteredServices {
+                data: Mutex::new(HashMap::new()),
+            },
+            heartbeat,
+            watchdogs: Mutex::new(Vec::new()),
+            service_discovery_publisher: ServiceDiscoveryPublisher::new(),
+            log_context: Replaceable::new(log_context),
+            details_storage: Mutex::new(details_storage),
+            thread_settings: self.thread_settings.clone(),
+            details: Replaceable::new(details),
+        });
+
+        if self.mark_dead_on_panic {
+            mark_dead_on_panic(NodeId(node_id), Arc::downgrade(&shared));
+        }
+
         Ok(Node {
-            shared: Arc::new(SharedNode {
-                id: NodeId(node_id),
-                monitoring_token: UnsafeCell::new(Some(monitoring_token)),
-                registered_services: RegisteredServices {
-                    data: Mutex::new(HashMap::new()),
-                },
-                _details_storage: details_storage,
-                details,
-            }),
+            termination_callbacks: TerminationCallbacks::default(),
+            dead_node_cleanup,
+            cyclic_deadline,
+            termination_wakeup: OnceLock::new(),
+            #[cfg(feature = "async")]
+            wait_timer: OnceLock::new(),
+            details: shared.details(),
+            shared,
         })
     }
 
+    fn verify_thread_settings(&self) -> Result<(), NodeCreationFailure> {
+        if self.thread_settings.is_inherited() {
+            return Ok(());
+        }
+
+        let msg = "Unable to create node";
+        // the internal threads are spawned on demand, therefore a probe thread verifies
+        // upfront that the system permits the settings
+        match self
+            .thread_settings
+            .spawn(&ThreadName::from(b"iox2-probe"), || {})
+        {
+            Ok(_) => Ok(()),
+            Err(ThreadSpawnError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} since the process has insufficient permissions to spawn threads with the settings {:?}.", self.thread_settings);
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} since no thread can be spawned with the settings {:?} ({:?}).", self.thread_settings, e);
+            }
+        }
+    }
+
+    fn create_domain_directory(&self, config: &Config) -> Result<(), NodeCreationFailure> {
+        let msg = "Unable to create the domain directory";
+        // the domain directory is created for every service type so that all domains can be
+        // discovered with Node::list_domains()
+        match Directory::create(
+            config.global.root_path(),
+            Permission::OWNER_ALL | Permission::GROUP_ALL,
+        ) {
+            Ok(_) | Err(DirectoryCreateError::DirectoryAlreadyExists) => Ok(()),
+            Err(DirectoryCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} \"{}\" due to insufficient permissions.", config.global.root_path());
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} \"{}\" due to an internal failure ({:?}).", config.global.root_path(), e);
+            }
+        }
+    }
+
+    fn acquire_instance_id<Service: service::Service>(
+        &self,
+        config: &Config,
+    ) -> Result<u128, NodeCreationFailure> {
+        let msg = "Unable to acquire the id of the iceoryx2 instance";
+        let storage_name = FileName::new(b"identity").unwrap();
+        let identity_config = instance_identity_config::<Service>(config);
+
+        // the storage is never removed so that the id identifies the instance as long as its
+        // root path exists
+        loop {
+            match <Service::StaticStorage as StaticStorage>::Builder::new(&storage_name)
+                .config(&identity_config)
+                .has_ownership(false)
+                .open(config.global.service.creation_timeout)
+            {
+                Ok(storage) => {
+                    let mut content = vec![0u8; storage.len() as usize];
+                    let instance_id = storage
+                        .read(&mut content)
+                        .ok()
+                        .and_then(|_| String::from_utf8(content).ok())
+                        .and_then(|content| content.parse::<u128>().ok());
+
+                    match instance_id {
+                        Some(instance_id) => return Ok(instance_id),
+                        None => {
+                            fail!(from self, with NodeCreationFailure::InternalError,
+                                "{msg} since the content of the instance id storage is corrupted.");
+                        }
+                    }
+                }
+                Err(StaticStorageOpenError::DoesNotExist) => (),
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::InternalError,
+                        "{msg} since the instance id storage could not be opened ({:?}).", e);
+                }
+            }
+
+            let instance_id = fail!(from self, when UniqueSystemId::new(),
+                with NodeCreationFailure::InternalError,
+                "{msg} since a new instance id could not be generated.")
+            .value();
+
+            match <Service::StaticStorage as StaticStorage>::Builder::new(&storage_name)
+                .config(&identity_config)
+                .has_ownership(false)
+                .create(instance_id.to_string().as_bytes())
+            {
+                Ok(_) => return Ok(instance_id),
+                Err(StaticStorageCreateError::AlreadyExists) => (),
+                Err(StaticStorageCreateError::InsufficientPermissions) => {
+                    fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                        "{msg} due to insufficient permissions to create the instance id storage.");
+                }
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::InternalError,
+                        "{msg} due to an unknown failure while creating the instance id storage ({:?}).", e);
+                }
+            }
+        }
+    }
+
+    // Resources without a path, like POSIX shared memory, are shared by all instances with the
+    // same prefix. The first instance creates the instance marker exclusively and stores its id
+    // in it, every other instance that finds a different id collides with it. Once verified,
+    // further nodes of the process only open the marker to acquire their generation.
+    fn verify_prefix_ownership<Service: service::Service>(
+        &self,
+        config: &Config,
+    ) -> Result<Service::InstanceStorage, NodeCreationFailure> {
+        let msg = "Unable to create node";
+        let marker_name = FileName::new(b"identity").unwrap();
+        let marker_config = instance_marker_config::<Service>(config);
+        let prefix = config.global.prefix;
+        let open_marker = || {
+            <Service::InstanceStorage as DynamicStorage<InstanceMarker>>::Builder::new(&marker_name)
+                .config(&marker_config)
+                .has_ownership(false)
+                .timeout(config.global.service.creation_timeout)
+                .open()
+        };
+
+        if let Some(instance_id) = VerifiedInstance::instance_id::<Service>(config) {
+            if let Ok(storage) = open_marker() {
+                if storage.get().instance_id() == instance_id {
+                    return Ok(storage);
+                }
+            }
+        }
+
+        let instance_id = self.acquire_instance_id::<Service>(config)?;
+        // the uid is only required to identify the owner of a colliding instance, it is
+        // unknown when the root path is not backed by a directory
+        let owner_uid = Directory::new(config.global.root_path())
+            .ok()
+            .and_then(|directory| directory.ownership().ok())
+            .map_or(u32::MAX, |ownership| ownership.uid());
+
+        loop {
+            match <Service::InstanceStorage as DynamicStorage<InstanceMarker>>::Builder::new(
+                &marker_name,
+            )
+            .config(&marker_config)
+            .has_ownership(false)
+            .create(InstanceMarker::new(
+                instance_id,
+                owner_uid,
+                *config.global.root_path(),
+            )) {
+                Ok(storage) => {
+                    VerifiedInstance::add::<Service>(config, instance_id);
+                    return Ok(storage);
+                }
+                Err(DynamicStorageCreateError::AlreadyExists) => (),
+                Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                    fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                        "{msg} due to insufficient permissions to create the instance marker.");
+                }
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::InternalError,
+                        "{msg} due to an unknown failure while creating the instance marker ({:?}).", e);
+                }
+            }
+
+            match open_marker() {
+                Ok(storage) => {
+                    let marker = storage.get();
+                    if marker.instance_id() == instance_id {
+                        VerifiedInstance::add::<Service>(config, instance_id);
+                        return Ok(storage);
+                    }
+
+                    fail!(from self, with NodeCreationFailure::PrefixCollision,
+                        "{msg} since the prefix \"{}\" is already used by the iceoryx2 instance with the root path \"{}\" owned by the uid {}, which shares the resources without a path, like POSIX shared memory, with this instance. Configure a unique prefix for every instance or remove the stale resources of the other instance, including its instance marker.",
+                        prefix, marker.root_path(), marker.owner_uid());
+                }
+                // the marker was removed in between, for instance by a manual cleanup
+                Err(DynamicStorageOpenError::DoesNotExist) => (),
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::PrefixCollision,
+                        "{msg} since the marker of the iceoryx2 instance that uses the prefix \"{}\" could not be opened ({:?}), most likely it belongs to an instance of another user. Configure a unique prefix for every instance.",
+                        prefix, e);
+                }
+            }
+        }
+    }
+
     fn create_token<Service: service::Service>(
         &self,
         config: &Config,
@@ -1175,13 +2761,53 @@ impl NodeBuilder {
         }
     }
 
+    fn create_heartbeat_storage<Service: service::Service>(
+        &self,
+        config: &Config,
+        node_id: &NodeId,
+    ) -> Result<Option<(Service::HeartbeatStorage, Duration)>, NodeCreationFailure> {
+        let msg = "Unable to create heartbeat storage for new node";
+        let liveness_deadline = match self.liveness_deadline {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        const MIN_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(1);
+        let heartbeat_interval = self
+            .heartbeat_interval
+            .unwrap_or(liveness_deadline / 2)
+            .max(MIN_HEARTBEAT_INTERVAL);
+
+        match <Service::HeartbeatStorage as DynamicStorage<NodeHeartbeat>>::Builder::new(
+            &node_id.as_file_name(),
+        )
+        .config(&node_heartbeat_config::<Service>(config))
+        .has_ownership(false)
+        .create(NodeHeartbeat::new(liveness_deadline))
+        {
+            Ok(storage) => Ok(Some((storage, heartbeat_interval))),
+            Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to create the heartbeat storage.");
+            }
+            Err(DynamicStorageCreateError::AlreadyExists) => {
+                fatal_panic!(from self,
+                    "This should never happen! {msg} since the heartbeat storage already exists.");
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} due to an unknown failure while creating the heartbeat storage {:?}.", e);
+            }
+        }
+    }
+
     fn create_node_details_storage<Service: service::Service>(
         &self,
         config: &Config,
         node_id: &NodeId,
+        generation: u64,
     ) -> Result<(Service::StaticStorage, NodeDetails), NodeCreationFailure> {
         let msg = "Unable to create node details storage";
-        let details = NodeDetails::new(&self.name, config);
+        let details = NodeDetails::new(&self.name, config, &self.attributes, generation);
 
         let details_config = node_details_config::<Service>(&details.config, node_id);
         let serialized_details = match <Service::ConfigSerializer>::serialize(&details) {
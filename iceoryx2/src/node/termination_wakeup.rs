@@ -0,0 +1,141 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`TerminationWakeup`] of a [`Node`](crate::node::Node), acquired with
+//! [`Node::termination_wakeup()`](crate::node::Node::termination_wakeup()). Its file descriptor
+//! becomes readable when the process receives `SIGINT` or `SIGTERM`, so that the
+//! [`Node`](crate::node::Node) can be integrated into an external event loop like `epoll`,
+//! `kqueue` or `mio` that must not block in [`Node::wait()`](crate::node::Node::wait()).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::NodeWaitFailure;
+//! use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+//! # use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let wakeup = node.termination_wakeup()?;
+//!
+//! // register the file descriptor in the event loop, when it becomes readable the
+//! // non-blocking Node::wait() reports the termination request
+//! let _fd = wakeup.file_descriptor();
+//!
+//! match node.wait(Duration::ZERO) {
+//!     Ok(()) => println!("no termination request"),
+//!     Err(NodeWaitFailure::TerminationRequest) => println!("terminating"),
+//!     Err(NodeWaitFailure::Interrupt) => println!("interrupted"),
+//!     Err(NodeWaitFailure::InternalError) => println!("unable to wait"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_bb_posix::pipe::Pipe;
+use iceoryx2_bb_posix::signal::{SignalHandler, SignalRegisterError, TerminationWakeupGuard};
+
+/// Defines the failures that can occur when a [`TerminationWakeup`] is created with
+/// [`Node::termination_wakeup()`](crate::node::Node::termination_wakeup()).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TerminationWakeupCreateError {
+    /// More file descriptors are registered for termination signals than the process supports.
+    ExceedsMaxSupportedWakeups,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for TerminationWakeupCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "TerminationWakeupCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for TerminationWakeupCreateError {}
+
+/// Provides a [`FileDescriptor`] that becomes readable when the process receives `SIGINT` or
+/// `SIGTERM`. It stays readable until [`TerminationWakeup::reset()`] is called, which is done
+/// by [`Node::wait()`](crate::node::Node::wait()).
+///
+/// It is based on an anonymous pipe, therefore it does not create any file system entry.
+/// On platforms without pipes, like Windows, its creation fails with
+/// [`TerminationWakeupCreateError::InternalError`].
+#[derive(Debug)]
+pub struct TerminationWakeup {
+    // must be dropped first so that the signal handler never writes into a closed pipe
+    _guard: TerminationWakeupGuard,
+    pipe: Pipe,
+}
+
+impl FileDescriptorBased for TerminationWakeup {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.pipe.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for TerminationWakeup {}
+
+#[cfg(unix)]
+impl std::os::fd::AsRawFd for TerminationWakeup {
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        std::os::fd::AsRawFd::as_raw_fd(self.file_descriptor())
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsFd for TerminationWakeup {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        std::os::fd::AsFd::as_fd(self.file_descriptor())
+    }
+}
+
+impl TerminationWakeup {
+    pub(crate) fn new() -> Result<Self, TerminationWakeupCreateError> {
+        let msg = "Unable to create termination wakeup";
+        let origin = "TerminationWakeup::new()";
+
+        let pipe = fail!(from origin, when Pipe::new(),
+            with TerminationWakeupCreateError::InternalError,
+            "{msg} since the underlying pipe could not be created.");
+
+        let guard = match SignalHandler::register_termination_wakeup(pipe.write_end()) {
+            Ok(guard) => guard,
+            Err(SignalRegisterError::ExceedsMaxSupportedWakeups) => {
+                fail!(from origin, with TerminationWakeupCreateError::ExceedsMaxSupportedWakeups,
+                    "{msg} since the maximum number of termination wakeups is already registered.");
+            }
+            Err(e) => {
+                fail!(from origin, with TerminationWakeupCreateError::InternalError,
+                    "{msg} since the wakeup could not be registered in the signal handler ({:?}).", e);
+            }
+        };
+
+        Ok(Self {
+            _guard: guard,
+            pipe,
+        })
+    }
+
+    /// Consumes all pending wakeups so that the [`FileDescriptor`] is no longer readable.
+    pub fn reset(&self) {
+        let mut buffer = [0u8; 16];
+        while let Ok(received_bytes) = self.pipe.try_read(&mut buffer) {
+            if received_bytes == 0 {
+                break;
+            }
+        }
+    }
+}
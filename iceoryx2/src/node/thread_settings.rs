@@ -0,0 +1,147 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`ThreadSettings`] of the threads that a [`Node`](crate::node::Node) uses internally.
+//! They are configured with [`NodeBuilder::thread_cpu_affinity()`](crate::node::NodeBuilder::thread_cpu_affinity()),
+//! [`NodeBuilder::thread_scheduler()`](crate::node::NodeBuilder::thread_scheduler()) and
+//! [`NodeBuilder::thread_priority()`](crate::node::NodeBuilder::thread_priority()) so that
+//! the housekeeping of iceoryx2 can be kept off isolated real-time CPU cores.
+//!
+//! # Internal Threads
+//!
+//! A [`Node`](crate::node::Node) does not own any permanent background thread. The heartbeat,
+//! the dead node cleanup and the cyclic deadline are handled in the thread that calls or polls
+//! [`Node::wait()`](crate::node::Node::wait()) or
+//! [`Node::wait_async()`](crate::node::Node::wait_async()). The only threads that are spawned
+//! internally are the helper threads of the `async` feature:
+//!
+//!  * `iox2-node-wait` - spawned once per [`Node`](crate::node::Node) by the first pending
+//!    future of [`Node::wait_async()`](crate::node::Node::wait_async()), wakes up the futures
+//!    when the cycle time has passed or the next heartbeat is due. It lives as long as the
+//!    [`Node`](crate::node::Node).
+//!  * `iox2-listener` - spawned by a future of
+//!    [`Listener::wait_async()`](crate::port::listener::Listener::wait_async()) when no event
+//!    is available, waits until the [`Listener`](crate::port::listener::Listener) becomes
+//!    readable.
+//!
+//! Both apply the [`ThreadSettings`] of the [`Node`](crate::node::Node) that owns them.
+//! Additionally, when any setting is configured,
+//! [`NodeBuilder::create()`](crate::node::NodeBuilder::create()) spawns and joins one
+//! short-lived thread to verify that the settings are permitted, for instance
+//! [`Scheduler::Fifo`] requires the `CAP_SYS_NICE` capability on Linux.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_posix::scheduler::Scheduler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // keep the housekeeping on core 0, the cores 2 and 3 are isolated for the real-time work
+//! let node = NodeBuilder::new()
+//!                 .thread_cpu_affinity(&[0])
+//!                 .thread_scheduler(Scheduler::Other)
+//!                 .create::<ipc::Service>()?;
+//!
+//! println!("internal threads run on {:?}", node.thread_settings().cpu_affinity());
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::scheduler::Scheduler;
+use iceoryx2_bb_posix::thread::{
+    Thread, ThreadBuilder, ThreadHandle, ThreadName, ThreadProperties, ThreadSpawnError,
+};
+
+/// The scheduling settings of the threads that a [`Node`](crate::node::Node) uses internally.
+/// Settings that are not configured are inherited from the thread that spawns the internal
+/// thread.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadSettings {
+    pub(crate) cpu_affinity: Option<Vec<usize>>,
+    pub(crate) scheduler: Option<Scheduler>,
+    pub(crate) priority: Option<u8>,
+}
+
+impl ThreadSettings {
+    /// Returns the CPU cores the internal threads are pinned to, if configured.
+    pub fn cpu_affinity(&self) -> Option<&[usize]> {
+        self.cpu_affinity.as_deref()
+    }
+
+    /// Returns the [`Scheduler`] of the internal threads, if configured.
+    pub fn scheduler(&self) -> Option<Scheduler> {
+        self.scheduler
+    }
+
+    /// Returns the priority of the internal threads, if configured. `0` represents the lowest
+    /// and `255` the highest priority of the [`Scheduler`].
+    pub fn priority(&self) -> Option<u8> {
+        self.priority
+    }
+
+    /// Returns true when no setting is configured.
+    pub fn is_inherited(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Spawns an internal thread with the settings. When no setting is configured, the thread
+    /// is detached and [`InternalThread`] does not join it.
+    pub(crate) fn spawn<F: FnOnce() + Send + 'static>(
+        &self,
+        name: &ThreadName,
+        f: F,
+    ) -> Result<InternalThread, ThreadSpawnError> {
+        if self.is_inherited() {
+            std::thread::spawn(f);
+            return Ok(InternalThread { _thread: None });
+        }
+
+        let mut builder = ThreadBuilder::new().name(name);
+
+        // the ThreadBuilder pins the thread to all cores by default, so the affinity of the
+        // spawning thread is applied explicitly when no affinity is configured
+        let cores = match &self.cpu_affinity {
+            Some(cores) => Some(cores.clone()),
+            None => match ThreadHandle::from_self().get_affinity() {
+                Ok(cores) => Some(cores),
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to acquire the cpu affinity of the calling thread ({:?}). The internal thread may run on all cpu cores.", e);
+                    None
+                }
+            },
+        };
+        if let Some(cores) = cores {
+            builder = builder.affinity_to_cores(&cores);
+        }
+
+        if self.scheduler.is_some() || self.priority.is_some() {
+            builder = builder
+                .inherit_scheduling_attributes(false)
+                .scheduler(self.scheduler.unwrap_or_default())
+                .priority(self.priority.unwrap_or(0));
+        }
+
+        Ok(InternalThread {
+            _thread: Some(builder.spawn(f)?),
+        })
+    }
+}
+
+/// An internal thread of a [`Node`](crate::node::Node). It is joined when it goes out of scope,
+/// unless it was spawned without any [`ThreadSettings`].
+#[derive(Debug)]
+pub(crate) struct InternalThread {
+    _thread: Option<Thread<'static>>,
+}
@@ -0,0 +1,288 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Runtime agnostic [`Future`] that is returned by
+//! [`Node::wait_async()`](crate::node::Node::wait_async()). It does not depend on a specific
+//! async executor and can therefore be awaited in tokio, async-std or any other runtime.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_log::{debug, warn};
+use iceoryx2_bb_posix::signal::SignalHandler;
+use iceoryx2_bb_posix::thread::{ThreadName, ThreadSpawnError};
+
+use super::thread_settings::{InternalThread, ThreadSettings};
+use super::{Node, NodeWaitFailure};
+use crate::service;
+
+// interval in which the timer thread checks for a termination request while futures are
+// pending
+const TERMINATION_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Default)]
+struct TimerState {
+    wakeups: HashMap<u64, (Instant, Waker)>,
+    // registrations that were woken up due to a termination request, the request is consumed
+    // by the timer thread and must therefore be forwarded to the futures
+    terminated: HashSet<u64>,
+    next_id: u64,
+    is_shutdown: bool,
+}
+
+#[derive(Debug, Default)]
+struct TimerShared {
+    state: Mutex<TimerState>,
+    has_changed: Condvar,
+}
+
+/// Wakes the pending [`NodeWaitFuture`]s of a [`Node`] when their wake-up time has passed or
+/// when a termination request was received. All futures of a [`Node`] share the one thread of
+/// the timer, it is spawned with the first pending future and stopped when the [`Node`] goes
+/// out of scope.
+#[derive(Debug)]
+pub(crate) struct WaitTimer {
+    shared: Arc<TimerShared>,
+    _thread: InternalThread,
+}
+
+impl WaitTimer {
+    pub(crate) fn new(thread_settings: &ThreadSettings) -> Result<Self, ThreadSpawnError> {
+        let shared = Arc::new(TimerShared::default());
+        let thread = {
+            let shared = shared.clone();
+            thread_settings.spawn(&ThreadName::from(b"iox2-node-wait"), move || {
+                Self::run(&shared)
+            })?
+        };
+
+        Ok(Self {
+            shared,
+            _thread: thread,
+        })
+    }
+
+    // registers the waker or replaces the previous registration with the provided id,
+    // returns the id of the registration
+    fn register(&self, id: Option<u64>, wake_time: Instant, waker: Waker) -> u64 {
+        let mut state = lock(&self.shared.state);
+        let id = match id {
+            Some(id) => id,
+            None => {
+                state.next_id += 1;
+                state.next_id
+            }
+        };
+        state.wakeups.insert(id, (wake_time, waker));
+        drop(state);
+        self.shared.has_changed.notify_one();
+
+        id
+    }
+
+    fn deregister(&self, id: u64) {
+        let mut state = lock(&self.shared.state);
+        state.wakeups.remove(&id);
+        state.terminated.remove(&id);
+    }
+
+    // returns true when the registration was woken up due to a termination request
+    fn take_termination_request(&self, id: u64) -> bool {
+        lock(&self.shared.state).terminated.remove(&id)
+    }
+
+    fn run(shared: &TimerShared) {
+        let mut state = lock(&shared.state);
+        loop {
+            if state.is_shutdown {
+                return;
+            }
+
+            let now = Instant::now();
+            // the termination request is only consumed when a future can report it
+            let is_termination_requested =
+                !state.wakeups.is_empty() && SignalHandler::termination_requested();
+            let mut due_wakers = vec![];
+            let mut terminated = vec![];
+            state.wakeups.retain(|id, (wake_time, waker)| {
+                if is_termination_requested {
+                    terminated.push(*id);
+                } else if now < *wake_time {
+                    return true;
+                }
+                due_wakers.push(waker.clone());
+                false
+            });
+            state.terminated.extend(terminated);
+
+            // the wakers are called without holding the lock since an executor may poll the
+            // future right away
+            if !due_wakers.is_empty() {
+                drop(state);
+                due_wakers.into_iter().for_each(Waker::wake);
+                state = lock(&shared.state);
+                continue;
+            }
+
+            let next_wake_time = state
+                .wakeups
+                .values()
+                .map(|(wake_time, _)| *wake_time)
+                .min();
+            state = match next_wake_time {
+                None => match shared.has_changed.wait(state) {
+                    Ok(state) => state,
+                    Err(e) => e.into_inner(),
+                },
+                Some(wake_time) => {
+                    let timeout = wake_time
+                        .saturating_duration_since(now)
+                        .min(TERMINATION_CHECK_INTERVAL);
+                    match shared.has_changed.wait_timeout(state, timeout) {
+                        Ok((state, _)) => state,
+                        Err(e) => e.into_inner().0,
+                    }
+                }
+            };
+        }
+    }
+}
+
+impl Drop for WaitTimer {
+    fn drop(&mut self) {
+        lock(&self.shared.state).is_shutdown = true;
+        self.shared.has_changed.notify_one();
+    }
+}
+
+fn lock(state: &Mutex<TimerState>) -> MutexGuard<'_, TimerState> {
+    match state.lock() {
+        Ok(guard) => guard,
+        Err(e) => e.into_inner(),
+    }
+}
+
+/// The [`Future`] returned by [`Node::wait_async()`](crate::node::Node::wait_async()). It
+/// becomes ready when the cycle time has passed or when a `SIGINT` or `SIGTERM` signal was
+/// received.
+///
+/// It performs the same steps as [`Node::wait()`](crate::node::Node::wait()), it removes dead
+/// nodes, sends heartbeats, follows the cyclic deadline and calls the termination callbacks.
+/// Instead of blocking, it registers itself at the timer thread of the
+/// [`Node`](crate::node::Node) that wakes it up when the next heartbeat is due or the cycle
+/// time has passed, so that the executor thread is never blocked.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NodeWaitFuture<'node, Service: service::Service> {
+    node: &'node Node<Service>,
+    cycle_time: Duration,
+    deadline: Option<Instant>,
+    registration: Option<u64>,
+}
+
+impl<'node, Service: service::Service> NodeWaitFuture<'node, Service> {
+    pub(crate) fn new(node: &'node Node<Service>, cycle_time: Duration) -> Self {
+        Self {
+            node,
+            cycle_time,
+            deadline: None,
+            registration: None,
+        }
+    }
+
+    fn poll_wait(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), NodeWaitFailure>> {
+        let deadline = match self.deadline {
+            Some(deadline) => {
+                if self.is_termination_requested() {
+                    debug!(from self.node,
+                        "Unable to wait asynchronously on node since a termination request was received.");
+                    return Poll::Ready(Err(NodeWaitFailure::TerminationRequest));
+                }
+                deadline
+            }
+            None => match self.node.prepare_wait(self.cycle_time) {
+                Ok(wait_time) => {
+                    let deadline = Instant::now() + wait_time;
+                    self.deadline = Some(deadline);
+                    deadline
+                }
+                Err(e) => return Poll::Ready(Err(e)),
+            },
+        };
+
+        self.node.shared.heartbeat();
+        let now = Instant::now();
+        if deadline <= now {
+            return Poll::Ready(Ok(()));
+        }
+
+        let wake_time = match self.node.heartbeat_interval() {
+            Some(heartbeat_interval) => deadline.min(now + heartbeat_interval),
+            None => deadline,
+        };
+
+        let timer = match self.node.wait_timer() {
+            Ok(timer) => timer,
+            Err(e) => {
+                warn!(from self.node,
+                    "Unable to spawn the timer thread that wakes up the asynchronous waits of the node with the settings {:?} ({:?}).",
+                    self.node.thread_settings(), e);
+                return Poll::Ready(Err(NodeWaitFailure::InternalError));
+            }
+        };
+        self.registration = Some(timer.register(self.registration, wake_time, cx.waker().clone()));
+
+        Poll::Pending
+    }
+
+    fn is_termination_requested(&self) -> bool {
+        let is_forwarded_by_timer = match (self.registration, self.node.wait_timer.get()) {
+            (Some(id), Some(timer)) => timer.take_termination_request(id),
+            _ => false,
+        };
+
+        is_forwarded_by_timer || SignalHandler::termination_requested()
+    }
+
+    fn deregister(&mut self) {
+        if let Some(id) = self.registration.take() {
+            if let Some(timer) = self.node.wait_timer.get() {
+                timer.deregister(id);
+            }
+        }
+    }
+}
+
+impl<Service: service::Service> Future for NodeWaitFuture<'_, Service> {
+    type Output = Result<(), NodeWaitFailure>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _log_context = self.node.shared.enter_log_context();
+        let result = self.poll_wait(cx);
+        if let Poll::Ready(ref result) = result {
+            self.deregister();
+            self.node.call_termination_callbacks_on_request(result);
+        }
+
+        result
+    }
+}
+
+impl<Service: service::Service> Drop for NodeWaitFuture<'_, Service> {
+    fn drop(&mut self) {
+        self.deregister();
+    }
+}
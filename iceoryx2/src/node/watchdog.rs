@@ -0,0 +1,160 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`NodeWatchdog`] of a [`Node`](crate::node::Node), acquired with
+//! [`Node::watchdog()`](crate::node::Node::watchdog()). It monitors the liveness of a single
+//! thread of a multi-threaded process. Every critical thread registers its own
+//! [`NodeWatchdog`] and feeds it periodically. When one of them is not fed within its deadline,
+//! the [`Node`](crate::node::Node) is reported as
+//! [`NodeState::Unresponsive`](crate::node::NodeState::Unresponsive) to all processes, even
+//! when the [`Node`](crate::node::Node) itself still sends heartbeats.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new()
+//!                 .liveness_deadline(Duration::from_secs(1))
+//!                 .create::<ipc::Service>()?;
+//!
+//! let watchdog = node.watchdog("executor-1", Duration::from_millis(500))?;
+//! let executor = std::thread::spawn(move || {
+//!     for _ in 0..3 {
+//!         // process the work of this cycle
+//!         watchdog.feed();
+//!     }
+//! });
+//!
+//! executor.join().unwrap();
+//!
+//! // the event loop of the main thread can emit an event for every starving thread
+//! for name in node.starving_watchdogs() {
+//!     println!("thread {} is unresponsive", name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use iceoryx2_bb_log::fail;
+
+use crate::node::heartbeat::MAX_NUMBER_OF_WATCHDOGS;
+use crate::node::SharedNode;
+use crate::service;
+
+/// Defines the failures that can occur when a [`NodeWatchdog`] is created with
+/// [`Node::watchdog()`](crate::node::Node::watchdog()).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeWatchdogCreateError {
+    /// The [`Node`](crate::node::Node) was created without a
+    /// [`NodeBuilder::liveness_deadline()`](crate::node::NodeBuilder::liveness_deadline()),
+    /// therefore its liveness is not monitored.
+    LivenessMonitoringDisabled,
+    /// The [`Node`](crate::node::Node) has already registered
+    /// [`MAX_NUMBER_OF_WATCHDOGS`] [`NodeWatchdog`]s.
+    ExceedsMaxNumberOfWatchdogs,
+}
+
+impl std::fmt::Display for NodeWatchdogCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "NodeWatchdogCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for NodeWatchdogCreateError {}
+
+/// A named watchdog that must be fed with [`NodeWatchdog::feed()`] within its deadline,
+/// otherwise the [`Node`](crate::node::Node) is considered unresponsive. It can be moved into
+/// the thread it monitors and is released when it goes out of scope.
+#[derive(Debug)]
+pub struct NodeWatchdog<Service: service::Service> {
+    name: String,
+    deadline: Duration,
+    index: usize,
+    node: Arc<SharedNode<Service>>,
+}
+
+impl<Service: service::Service> Drop for NodeWatchdog<Service> {
+    fn drop(&mut self) {
+        self.node.release_watchdog(self.index);
+    }
+}
+
+impl<Service: service::Service> NodeWatchdog<Service> {
+    pub(crate) fn new(
+        node: &Arc<SharedNode<Service>>,
+        name: &str,
+        deadline: Duration,
+    ) -> Result<Self, NodeWatchdogCreateError> {
+        let msg = "Unable to create node watchdog";
+        let origin = format!(
+            "NodeWatchdog::new(name: {}, deadline: {:?})",
+            name, deadline
+        );
+
+        let heartbeat = match node.heartbeat_storage() {
+            Some(heartbeat) => heartbeat,
+            None => {
+                fail!(from origin, with NodeWatchdogCreateError::LivenessMonitoringDisabled,
+                    "{msg} since the node was created without a liveness deadline.");
+            }
+        };
+
+        let index = match heartbeat.acquire_watchdog(deadline) {
+            Some(index) => index,
+            None => {
+                fail!(from origin, with NodeWatchdogCreateError::ExceedsMaxNumberOfWatchdogs,
+                    "{msg} since the node has already registered the maximum number of {} watchdogs.",
+                    MAX_NUMBER_OF_WATCHDOGS);
+            }
+        };
+
+        node.register_watchdog(index, name);
+
+        Ok(Self {
+            name: name.to_string(),
+            deadline,
+            index,
+            node: node.clone(),
+        })
+    }
+
+    /// Returns the name of the [`NodeWatchdog`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the deadline in which the [`NodeWatchdog`] must be fed.
+    pub fn deadline(&self) -> Duration {
+        self.deadline
+    }
+
+    /// Feeds the [`NodeWatchdog`] to signal that the monitored thread is still responsive.
+    pub fn feed(&self) {
+        if let Some(heartbeat) = self.node.heartbeat_storage() {
+            heartbeat.feed_watchdog(self.index);
+        }
+    }
+
+    /// Returns true when the [`NodeWatchdog`] was not fed within its deadline, otherwise false.
+    pub fn has_missed_deadline(&self) -> bool {
+        match self.node.heartbeat_storage() {
+            Some(heartbeat) => heartbeat.has_watchdog_missed_deadline(self.index),
+            None => false,
+        }
+    }
+}
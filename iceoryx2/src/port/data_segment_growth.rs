@@ -0,0 +1,57 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The generations of the data segments that a [`Publisher`](crate::port::publisher::Publisher)
+//! adds when it grows according to its
+//! [`AllocationStrategy`](crate::service::port_factory::publisher::AllocationStrategy). It is
+//! stored in a [`DynamicStorage`](iceoryx2_cal::dynamic_storage::DynamicStorage) that is owned
+//! by the [`Publisher`](crate::port::publisher::Publisher).
+//!
+//! An added data segment is released as soon as it is empty and a larger one was added. Its
+//! slot can then be reused by the next data segment. Every reuse increases the generation of
+//! the slot so that the [`Subscriber`](crate::port::subscriber::Subscriber)s can detect that
+//! the data segment they have mapped was replaced.
+
+use std::sync::atomic::Ordering;
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+/// The maximum number of data segments a [`Publisher`](crate::port::publisher::Publisher) can
+/// have added at the same time.
+pub(crate) const MAX_NUMBER_OF_GROWTH_SEGMENTS: usize = 8;
+
+/// Contains the generation of every slot of the added data segments of a
+/// [`Publisher`](crate::port::publisher::Publisher).
+#[repr(C)]
+#[derive(Debug)]
+pub struct DataSegmentGrowth {
+    generations: [IoxAtomicU64; MAX_NUMBER_OF_GROWTH_SEGMENTS],
+}
+
+impl DataSegmentGrowth {
+    pub(crate) fn new() -> Self {
+        Self {
+            generations: core::array::from_fn(|_| IoxAtomicU64::new(0)),
+        }
+    }
+
+    /// Returns the generation of the data segment in the provided slot.
+    pub(crate) fn generation(&self, slot: usize) -> u64 {
+        self.generations[slot].load(Ordering::Acquire)
+    }
+
+    /// Sets the generation of the data segment in the provided slot. Must be called before
+    /// the first sample of the data segment is delivered.
+    pub(crate) fn set_generation(&self, slot: usize, generation: u64) {
+        self.generations[slot].store(generation, Ordering::Release);
+    }
+}
@@ -0,0 +1,817 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::sync::atomic::Ordering;
+use std::sync::{Mutex, MutexGuard};
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_cal::shared_memory::{
+    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, SharedMemoryOpenError, ShmPointer,
+};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+use crate::config::{self, MemoryPool};
+use crate::port::data_segment_growth::{DataSegmentGrowth, MAX_NUMBER_OF_GROWTH_SEGMENTS};
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::port::publisher::MemoryPoolStatistics;
+use crate::service::{
+    self,
+    config_scheme::{data_segment_config, data_segment_growth_config},
+    naming_scheme::{
+        data_segment_growth_name, data_segment_growth_storage_name, data_segment_name,
+    },
+    static_config::message_type_details::{MessageTypeDetails, TypeVariant},
+};
+
+/// The layout of a single memory pool of a publishers data segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MemoryPoolLayout {
+    pub(crate) bucket_layout: Layout,
+    pub(crate) number_of_samples: usize,
+}
+
+/// Calculates the memory pool layouts of a publisher, ordered from the smallest to the largest
+/// bucket. Without configured memory pools or when the payload is not a slice, it is a single
+/// pool where every bucket can hold a sample with `max_slice_len` elements. Every pool provides
+/// at least `number_of_samples` buckets, the amount of samples a publisher can have in flight,
+/// so that every sample in flight can be of the same size class.
+///
+/// The bucket size of every pool is a multiple of the bucket size of the smallest pool, so that
+/// the offset of every sample is a multiple of it and can be transferred via a
+/// [`iceoryx2_cal::zero_copy_connection::ZeroCopyConnection`] with the smallest bucket size as
+/// sample size.
+pub(crate) fn memory_pool_layouts(
+    message_type_details: &MessageTypeDetails,
+    max_slice_len: usize,
+    number_of_samples: usize,
+    memory_pools: &[MemoryPool],
+) -> Vec<MemoryPoolLayout> {
+    let max_layout = MemoryPoolLayout {
+        bucket_layout: message_type_details.sample_layout(max_slice_len),
+        number_of_samples,
+    };
+
+    if message_type_details.payload.variant != TypeVariant::Dynamic
+        || message_type_details.payload.size == 0
+        || memory_pools.is_empty()
+    {
+        return vec![max_layout];
+    }
+
+    let mut pools: Vec<(usize, usize)> = memory_pools
+        .iter()
+        .map(|pool| {
+            (
+                (pool.payload_size / message_type_details.payload.size).min(max_slice_len),
+                pool.number_of_samples,
+            )
+        })
+        .filter(|(slice_len, number_of_samples)| *slice_len != 0 && *number_of_samples != 0)
+        .collect();
+
+    pools.sort_by_key(|(slice_len, _)| *slice_len);
+    pools.dedup_by(|current, previous| {
+        if current.0 == previous.0 {
+            previous.1 += current.1;
+            true
+        } else {
+            false
+        }
+    });
+    for pool in pools.iter_mut() {
+        pool.1 = pool.1.max(number_of_samples);
+    }
+
+    // the largest pool must always be able to hold a sample with max_slice_len elements
+    if pools
+        .last()
+        .map_or(true, |(slice_len, _)| *slice_len < max_slice_len)
+    {
+        pools.push((max_slice_len, number_of_samples));
+    }
+
+    let chunk_size = message_type_details.sample_layout(pools[0].0).size();
+    pools
+        .iter()
+        .map(|(slice_len, number_of_samples)| {
+            let layout = message_type_details.sample_layout(*slice_len);
+            MemoryPoolLayout {
+                bucket_layout: unsafe {
+                    Layout::from_size_align_unchecked(
+                        layout.size().div_ceil(chunk_size) * chunk_size,
+                        layout.align(),
+                    )
+                },
+                number_of_samples: *number_of_samples,
+            }
+        })
+        .collect()
+}
+
+/// Defines how many segments a publisher can add to its data segment when a sample exceeds the
+/// largest memory pool, see
+/// [`AllocationStrategy`](crate::service::port_factory::publisher::AllocationStrategy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct GrowthLayout {
+    /// The maximum number of segments that can be added at the same time, at most
+    /// [`MAX_NUMBER_OF_GROWTH_SEGMENTS`].
+    pub(crate) max_number_of_segments: usize,
+    /// The number of samples of every added segment.
+    pub(crate) number_of_samples: usize,
+}
+
+#[derive(Debug)]
+struct Segment<Service: service::Service> {
+    memory: Service::SharedMemory,
+    offset: usize,
+    // set for the segments that were added with DataSegmentMemory::grow(), each of their
+    // buckets occupies a single chunk of the offset range
+    compacted_bucket_size: Option<usize>,
+    number_of_used_buckets: IoxAtomicUsize,
+}
+
+impl<Service: service::Service> Segment<Service> {
+    fn new(
+        memory: Service::SharedMemory,
+        offset: usize,
+        compacted_bucket_size: Option<usize>,
+    ) -> Self {
+        Self {
+            memory,
+            offset,
+            compacted_bucket_size,
+            number_of_used_buckets: IoxAtomicUsize::new(0),
+        }
+    }
+
+    fn local_offset(&self, offset: usize, chunk_size: usize) -> usize {
+        match self.compacted_bucket_size {
+            None => offset - self.offset,
+            Some(bucket_size) => (offset - self.offset) / chunk_size * bucket_size,
+        }
+    }
+
+    fn global_offset(&self, local_offset: usize, chunk_size: usize) -> usize {
+        match self.compacted_bucket_size {
+            None => local_offset + self.offset,
+            Some(bucket_size) => local_offset / bucket_size * chunk_size + self.offset,
+        }
+    }
+
+    fn bucket_size(&self) -> usize {
+        self.compacted_bucket_size.unwrap_or(0)
+    }
+}
+
+/// A slot of the reserved area for the segments that are added with
+/// [`DataSegmentMemory::grow()`].
+#[derive(Debug)]
+struct GrowthSlot<Service: service::Service> {
+    segment: Option<Segment<Service>>,
+    // the generation of the segment, see DataSegmentGrowth
+    generation: u64,
+}
+
+/// The data segment of a publisher. It consists of one shared memory per memory pool. All
+/// segments are mapped into one continuous offset range, the offset of a sample is the sum of
+/// the offset of its segment and the offset of the sample inside the segment. Therefore, the
+/// offset can be shared via a
+/// [`iceoryx2_cal::zero_copy_connection::ZeroCopyConnection`] with a sample size of
+/// [`DataSegmentMemory::chunk_size()`].
+///
+/// When the publisher can grow, the offset range contains additionally a reserved area of
+/// slots for the segments that are added with [`DataSegmentMemory::grow()`]. Since their
+/// buckets can be arbitrarily large, each of their buckets is mapped to a single chunk so that
+/// the size of the offset range stays independent of the size of the added segments. An added
+/// segment is released when it is empty and a larger one was added, its slot is reused by the
+/// next added segment with a new [`DataSegmentGrowth`] generation. The subscriber opens an added
+/// segment when it receives the first sample from it and reopens it when the generation of its
+/// slot changed.
+#[derive(Debug)]
+pub(crate) struct DataSegmentMemory<Service: service::Service> {
+    publisher_id: UniquePublisherId,
+    is_owner: bool,
+    segments: Vec<Segment<Service>>,
+    bucket_sizes: Vec<usize>,
+    numbers_of_buckets: Vec<usize>,
+    growth_slots: Mutex<Vec<GrowthSlot<Service>>>,
+    growth_storage: Option<Service::GrowthStorage>,
+    growth: GrowthLayout,
+    growth_offset: usize,
+    sample_alignment: usize,
+    shm_config: <Service::SharedMemory as NamedConceptMgmt>::Configuration,
+    global_config: config::Config,
+    memory_lock: bool,
+    prefault_memory: bool,
+    huge_pages: bool,
+    chunk_size: usize,
+    number_of_chunks: usize,
+    number_of_samples: IoxAtomicUsize,
+}
+
+impl<Service: service::Service> DataSegmentMemory<Service> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create(
+        publisher_id: &UniquePublisherId,
+        global_config: &config::Config,
+        pools: &[MemoryPoolLayout],
+        growth: GrowthLayout,
+        memory_lock: bool,
+        prefault_memory: bool,
+        huge_pages: bool,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let origin = "DataSegmentMemory::create()";
+        let chunk_size = pools[0].bucket_layout.size();
+        let mut new_self = Self::new(
+            publisher_id,
+            global_config,
+            pools.len(),
+            growth,
+            pools[0].bucket_layout.align(),
+            chunk_size,
+            huge_pages,
+        );
+        new_self.is_owner = true;
+        new_self.memory_lock = memory_lock;
+        new_self.prefault_memory = prefault_memory;
+
+        for (n, pool) in pools.iter().enumerate() {
+            let memory = fail!(from origin,
+                when new_self.create_segment(&data_segment_name(publisher_id, n), pool),
+                "Unable to create the data segment of the memory pool with a bucket size of {}.", pool.bucket_layout.size());
+
+            new_self.add_segment(memory);
+            new_self.bucket_sizes.push(pool.bucket_layout.size());
+            new_self.numbers_of_buckets.push(pool.number_of_samples);
+            new_self
+                .number_of_samples
+                .fetch_add(pool.number_of_samples, Ordering::Relaxed);
+        }
+
+        if growth.max_number_of_segments != 0 {
+            match <Service::GrowthStorage as DynamicStorage<DataSegmentGrowth>>::Builder::new(
+                &data_segment_growth_storage_name(publisher_id),
+            )
+            .config(&data_segment_growth_config::<Service>(global_config))
+            .create(DataSegmentGrowth::new())
+            {
+                Ok(storage) => new_self.growth_storage = Some(storage),
+                Err(e) => {
+                    fail!(from origin, with SharedMemoryCreateError::InternalError,
+                        "Unable to create the data segment growth storage ({:?}).", e);
+                }
+            }
+        }
+        new_self.reserve_growth_slots();
+
+        Ok(new_self)
+    }
+
+    pub(crate) fn open(
+        publisher_id: &UniquePublisherId,
+        global_config: &config::Config,
+        number_of_segments: usize,
+        growth: GrowthLayout,
+        sample_alignment: usize,
+        chunk_size: usize,
+        huge_pages: bool,
+    ) -> Result<Self, SharedMemoryOpenError> {
+        let origin = "DataSegmentMemory::open()";
+        let mut new_self = Self::new(
+            publisher_id,
+            global_config,
+            number_of_segments,
+            growth,
+            sample_alignment,
+            chunk_size,
+            huge_pages,
+        );
+        // the publisher may apply it for itself only, the subscriber follows the configuration
+        // that enforces it for every data segment
+        new_self.memory_lock = global_config
+            .defaults
+            .publish_subscribe
+            .publisher_memory_lock;
+        new_self.prefault_memory = global_config
+            .defaults
+            .publish_subscribe
+            .publisher_prefault_memory;
+
+        for n in 0..number_of_segments {
+            let memory = fail!(from origin,
+                when new_self.open_segment(&data_segment_name(publisher_id, n)),
+                "Unable to open the data segment {} of the publisher {:?}.", n, publisher_id);
+
+            new_self.add_segment(memory);
+        }
+
+        if growth.max_number_of_segments != 0 {
+            match <Service::GrowthStorage as DynamicStorage<DataSegmentGrowth>>::Builder::new(
+                &data_segment_growth_storage_name(publisher_id),
+            )
+            .config(&data_segment_growth_config::<Service>(global_config))
+            .timeout(global_config.global.service.creation_timeout)
+            .open()
+            {
+                Ok(storage) => new_self.growth_storage = Some(storage),
+                Err(e) => {
+                    fail!(from origin, with SharedMemoryOpenError::DoesNotExist,
+                        "Unable to open the data segment growth storage of the publisher {:?} ({:?}).", publisher_id, e);
+                }
+            }
+        }
+        new_self.reserve_growth_slots();
+
+        Ok(new_self)
+    }
+
+    fn new(
+        publisher_id: &UniquePublisherId,
+        global_config: &config::Config,
+        number_of_segments: usize,
+        growth: GrowthLayout,
+        sample_alignment: usize,
+        chunk_size: usize,
+        huge_pages: bool,
+    ) -> Self {
+        let growth = GrowthLayout {
+            max_number_of_segments: growth
+                .max_number_of_segments
+                .min(MAX_NUMBER_OF_GROWTH_SEGMENTS),
+            ..growth
+        };
+
+        Self {
+            publisher_id: *publisher_id,
+            is_owner: false,
+            segments: Vec::with_capacity(number_of_segments),
+            bucket_sizes: Vec::with_capacity(number_of_segments),
+            numbers_of_buckets: Vec::with_capacity(number_of_segments),
+            growth_slots: Mutex::new(
+                (0..growth.max_number_of_segments)
+                    .map(|_| GrowthSlot {
+                        segment: None,
+                        generation: 0,
+                    })
+                    .collect(),
+            ),
+            growth_storage: None,
+            growth,
+            growth_offset: 0,
+            sample_alignment,
+            shm_config: data_segment_config::<Service>(global_config),
+            global_config: global_config.clone(),
+            memory_lock: false,
+            prefault_memory: false,
+            huge_pages,
+            chunk_size,
+            number_of_chunks: 0,
+            number_of_samples: IoxAtomicUsize::new(0),
+        }
+    }
+
+    fn create_segment(
+        &self,
+        name: &FileName,
+        pool: &MemoryPoolLayout,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let layout = pool.bucket_layout;
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: layout,
+        };
+
+        let create_segment = |use_huge_pages| {
+            <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(name)
+                .config(&self.shm_config)
+                .size(layout.size() * pool.number_of_samples + layout.align() - 1)
+                .is_memory_locked(self.memory_lock)
+                .prefault_memory(self.prefault_memory)
+                .use_huge_pages(use_huge_pages)
+                .create(&allocator_config)
+        };
+
+        match create_segment(self.huge_pages) {
+            Err(SharedMemoryCreateError::UnableToUseHugePages)
+                if self
+                    .global_config
+                    .defaults
+                    .publish_subscribe
+                    .huge_pages_fallback =>
+            {
+                warn!(from "DataSegmentMemory::create()",
+                    "Huge pages are not available, the data segment of the memory pool with a bucket size of {} falls back to regular pages.",
+                    layout.size());
+                create_segment(false)
+            }
+            v => v,
+        }
+    }
+
+    fn open_segment(
+        &self,
+        name: &FileName,
+    ) -> Result<Service::SharedMemory, SharedMemoryOpenError> {
+        <Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder::new(name)
+            .config(&self.shm_config)
+            .timeout(self.global_config.global.service.creation_timeout)
+            .is_memory_locked(self.memory_lock)
+            .prefault_memory(self.prefault_memory)
+            .use_huge_pages(self.huge_pages)
+            .open()
+    }
+
+    fn add_segment(&mut self, memory: Service::SharedMemory) {
+        // the size contains the additional alignment bytes which are never part of a bucket,
+        // they are excluded so that publisher and subscriber derive the same offsets
+        let usable_size = memory.size() / self.chunk_size * self.chunk_size;
+        self.segments.push(Segment::new(
+            memory,
+            self.number_of_chunks * self.chunk_size,
+            None,
+        ));
+        self.number_of_chunks += usable_size / self.chunk_size;
+    }
+
+    fn reserve_growth_slots(&mut self) {
+        self.growth_offset = self.number_of_chunks * self.chunk_size;
+        self.number_of_chunks += self.growth.max_number_of_segments * self.growth.number_of_samples;
+    }
+
+    fn growth_slot_index(&self, offset: usize) -> Option<usize> {
+        if offset < self.growth_offset {
+            return None;
+        }
+
+        Some((offset - self.growth_offset) / (self.growth.number_of_samples * self.chunk_size))
+    }
+
+    fn growth_slot_offset(&self, slot: usize) -> usize {
+        self.growth_offset + slot * self.growth.number_of_samples * self.chunk_size
+    }
+
+    fn growth_slots(&self) -> MutexGuard<'_, Vec<GrowthSlot<Service>>> {
+        // the slots are consistent at any time, a panic while holding the lock cannot
+        // corrupt them
+        match self.growth_slots.lock() {
+            Ok(guard) => guard,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    /// The granularity of all sample offsets, the bucket size of the smallest memory pool.
+    pub(crate) fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// The number of [`DataSegmentMemory::chunk_size()`] sized chunks that cover all segments,
+    /// including the reserved area for the segments that can be added with
+    /// [`DataSegmentMemory::grow()`].
+    pub(crate) fn number_of_chunks(&self) -> usize {
+        self.number_of_chunks
+    }
+
+    /// The number of samples that can be allocated from all memory pools together.
+    pub(crate) fn number_of_samples(&self) -> usize {
+        self.number_of_samples.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn number_of_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the [`MemoryPoolStatistics`] of all memory pools, ordered from the smallest to
+    /// the largest bucket, followed by the segments that were added with
+    /// [`DataSegmentMemory::grow()`] and are not yet released.
+    pub(crate) fn memory_pool_statistics(&self) -> Vec<MemoryPoolStatistics> {
+        let mut statistics: Vec<MemoryPoolStatistics> = self
+            .segments
+            .iter()
+            .zip(self.bucket_sizes.iter().copied())
+            .zip(self.numbers_of_buckets.iter().copied())
+            .map(
+                |((segment, bucket_size), number_of_buckets)| MemoryPoolStatistics {
+                    bucket_size,
+                    number_of_buckets,
+                    number_of_used_buckets: segment.number_of_used_buckets.load(Ordering::Relaxed),
+                    uses_huge_pages: segment.memory.uses_huge_pages(),
+                },
+            )
+            .collect();
+
+        let mut growth_statistics: Vec<MemoryPoolStatistics> = self
+            .growth_slots()
+            .iter()
+            .filter_map(|slot| slot.segment.as_ref())
+            .map(|segment| MemoryPoolStatistics {
+                bucket_size: segment.bucket_size(),
+                number_of_buckets: self.growth.number_of_samples,
+                number_of_used_buckets: segment.number_of_used_buckets.load(Ordering::Relaxed),
+                uses_huge_pages: segment.memory.uses_huge_pages(),
+            })
+            .collect();
+        growth_statistics.sort_by_key(|pool| pool.bucket_size);
+        statistics.append(&mut growth_statistics);
+
+        statistics
+    }
+
+    /// Returns the [`GrowthLayout`] of the data segment.
+    pub(crate) fn growth(&self) -> GrowthLayout {
+        self.growth
+    }
+
+    /// Adds a new segment that can hold samples with the provided layout and releases all empty
+    /// segments with a smaller bucket size. Returns false when all slots are occupied by
+    /// segments that are still in use.
+    pub(crate) fn grow(&self, layout: Layout) -> Result<bool, SharedMemoryCreateError> {
+        let origin = "DataSegmentMemory::grow()";
+        let growth_storage = match &self.growth_storage {
+            Some(storage) => storage,
+            None => return Ok(false),
+        };
+
+        let pool = MemoryPoolLayout {
+            bucket_layout: layout.pad_to_align(),
+            number_of_samples: self.growth.number_of_samples,
+        };
+
+        // an empty segment with a smaller bucket size is superseded by the new segment
+        let bucket_size = pool.bucket_layout.size();
+        let is_superseded = |slot: &GrowthSlot<Service>| {
+            slot.segment.as_ref().map_or(false, |segment| {
+                segment.number_of_used_buckets.load(Ordering::Relaxed) == 0
+                    && segment.bucket_size() < bucket_size
+            })
+        };
+
+        let mut slots = self.growth_slots();
+        let index = match slots
+            .iter()
+            .position(|slot| slot.segment.is_none())
+            .or_else(|| slots.iter().position(is_superseded))
+        {
+            Some(index) => index,
+            None => return Ok(false),
+        };
+
+        let generation = slots[index].generation + 1;
+        let memory = fail!(from origin,
+            when self.create_segment(&data_segment_growth_name(&self.publisher_id, index, generation), &pool),
+            "Unable to add a data segment with a bucket size of {}.", bucket_size);
+
+        self.release_growth_segment(&mut slots[index]);
+        slots[index] = GrowthSlot {
+            segment: Some(Segment::new(
+                memory,
+                self.growth_slot_offset(index),
+                Some(bucket_size),
+            )),
+            generation,
+        };
+        growth_storage.get().set_generation(index, generation);
+        self.number_of_samples
+            .fetch_add(pool.number_of_samples, Ordering::Relaxed);
+
+        for slot in slots.iter_mut().filter(|slot| is_superseded(slot)) {
+            self.release_growth_segment(slot);
+        }
+
+        Ok(true)
+    }
+
+    fn release_growth_segment(&self, slot: &mut GrowthSlot<Service>) {
+        if slot.segment.take().is_some() {
+            self.number_of_samples
+                .fetch_sub(self.growth.number_of_samples, Ordering::Relaxed);
+        }
+    }
+
+    /// Releases the added segment when it is empty and a segment with a larger bucket size
+    /// exists, since the larger one can serve all of its samples.
+    fn release_growth_segment_when_superseded(
+        &self,
+        slots: &mut [GrowthSlot<Service>],
+        index: usize,
+    ) {
+        let bucket_size = match &slots[index].segment {
+            Some(segment) if segment.number_of_used_buckets.load(Ordering::Relaxed) == 0 => {
+                segment.bucket_size()
+            }
+            _ => return,
+        };
+
+        if slots
+            .iter()
+            .filter_map(|slot| slot.segment.as_ref())
+            .any(|segment| segment.bucket_size() > bucket_size)
+        {
+            self.release_growth_segment(&mut slots[index]);
+        }
+    }
+
+    /// Calls the provided function with the added segment of the slot. When the data segment
+    /// was opened, the segment is opened on first use and reopened when it was replaced by the
+    /// publisher.
+    fn with_growth_segment<R, F: FnOnce(&Segment<Service>) -> R>(
+        &self,
+        index: usize,
+        f: F,
+    ) -> Result<R, SharedMemoryOpenError> {
+        let origin = "DataSegmentMemory::with_growth_segment()";
+        let mut slots = self.growth_slots();
+        let slot = match slots.get_mut(index) {
+            Some(slot) => slot,
+            None => {
+                fail!(from origin, with SharedMemoryOpenError::DoesNotExist,
+                    "The offset refers to the data segment slot {} which exceeds the {} slots of the publisher {:?}.",
+                    index, self.growth.max_number_of_segments, self.publisher_id);
+            }
+        };
+
+        if !self.is_owner {
+            let generation = match &self.growth_storage {
+                Some(storage) => storage.get().generation(index),
+                None => 0,
+            };
+
+            if slot.segment.is_none() || slot.generation != generation {
+                let memory = fail!(from origin,
+                    when self.open_segment(&data_segment_growth_name(&self.publisher_id, index, generation)),
+                    "Unable to open the added data segment {} of the publisher {:?}.", index, self.publisher_id);
+
+                // the size of an added segment is a multiple of the bucket size plus the
+                // additional alignment bytes which are always smaller than the bucket size
+                let alignment = self.sample_alignment;
+                let bucket_size =
+                    memory.size() / (alignment * self.growth.number_of_samples) * alignment;
+                *slot = GrowthSlot {
+                    segment: Some(Segment::new(
+                        memory,
+                        self.growth_slot_offset(index),
+                        Some(bucket_size),
+                    )),
+                    generation,
+                };
+            }
+        }
+
+        match &slot.segment {
+            Some(segment) => Ok(f(segment)),
+            None => {
+                fail!(from origin, with SharedMemoryOpenError::DoesNotExist,
+                    "The offset refers to the data segment slot {} of the publisher {:?} which contains no data segment.",
+                    index, self.publisher_id);
+            }
+        }
+    }
+
+    fn with_segment<R, F: FnOnce(&Segment<Service>) -> R>(
+        &self,
+        offset: usize,
+        f: F,
+    ) -> Result<R, SharedMemoryOpenError> {
+        match self.growth_slot_index(offset) {
+            Some(index) => self.with_growth_segment(index, f),
+            None => {
+                let index = self
+                    .segments
+                    .partition_point(|segment| segment.offset <= offset);
+                Ok(f(&self.segments[index - 1]))
+            }
+        }
+    }
+
+    fn allocate_from(
+        &self,
+        segment: &Segment<Service>,
+        layout: Layout,
+    ) -> Result<ShmPointer, ShmAllocationError> {
+        let ptr = segment.memory.allocate(layout)?;
+        segment
+            .number_of_used_buckets
+            .fetch_add(1, Ordering::Relaxed);
+        Ok(ShmPointer {
+            offset: PointerOffset::new(segment.global_offset(ptr.offset.value(), self.chunk_size)),
+            data_ptr: ptr.data_ptr,
+        })
+    }
+
+    /// Allocates the sample from the smallest memory pool that can hold the layout and has
+    /// still free buckets left.
+    pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
+        debug_assert!(self.bucket_sizes.len() == self.segments.len());
+
+        let mut result = Err(ShmAllocationError::AllocationError(
+            AllocationError::SizeTooLarge,
+        ));
+
+        let mut track_failure = |e: ShmAllocationError| match e {
+            ShmAllocationError::AllocationError(AllocationError::OutOfMemory) => {
+                result = Err(e);
+                Ok(())
+            }
+            e => Err(e),
+        };
+
+        for (segment, bucket_size) in self.segments.iter().zip(self.bucket_sizes.iter().copied()) {
+            if layout.size() > bucket_size {
+                continue;
+            }
+
+            match self.allocate_from(segment, layout) {
+                Ok(ptr) => return Ok(ptr),
+                Err(e) => track_failure(e)?,
+            }
+        }
+
+        if self.growth.max_number_of_segments == 0 {
+            return result;
+        }
+
+        let slots = self.growth_slots();
+        let mut growth_segments: Vec<&Segment<Service>> = slots
+            .iter()
+            .filter_map(|slot| slot.segment.as_ref())
+            .filter(|segment| layout.size() <= segment.bucket_size())
+            .collect();
+        growth_segments.sort_by_key(|segment| segment.bucket_size());
+
+        for segment in growth_segments {
+            match self.allocate_from(segment, layout) {
+                Ok(ptr) => return Ok(ptr),
+                Err(e) => track_failure(e)?,
+            }
+        }
+
+        result
+    }
+
+    /// Returns the sample to its memory pool. An added segment that becomes empty is released
+    /// when a larger one exists. Fails when the offset does not belong to a segment of the
+    /// data segment.
+    ///
+    /// # Safety
+    ///
+    ///  * the offset must be acquired with [`DataSegmentMemory::allocate()`]
+    pub(crate) unsafe fn deallocate(
+        &self,
+        offset: PointerOffset,
+        layout: Layout,
+    ) -> Result<(), SharedMemoryOpenError> {
+        let deallocate = |segment: &Segment<Service>| {
+            segment.memory.deallocate(
+                PointerOffset::new(segment.local_offset(offset.value(), self.chunk_size)),
+                layout,
+            );
+            segment
+                .number_of_used_buckets
+                .fetch_sub(1, Ordering::Relaxed);
+        };
+
+        match self.growth_slot_index(offset.value()) {
+            None => self.with_segment(offset.value(), deallocate),
+            Some(index) => {
+                let mut slots = self.growth_slots();
+                match slots.get(index).and_then(|slot| slot.segment.as_ref()) {
+                    Some(segment) => deallocate(segment),
+                    None => {
+                        fail!(from "DataSegmentMemory::deallocate()", with SharedMemoryOpenError::DoesNotExist,
+                            "Unable to deallocate the sample since its data segment slot {} contains no data segment.", index);
+                    }
+                }
+                self.release_growth_segment_when_superseded(&mut slots, index);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the absolute address of the sample with the provided offset. Fails when the
+    /// sample is stored in an added segment that could not be opened.
+    pub(crate) fn payload_address(
+        &self,
+        offset: PointerOffset,
+    ) -> Result<usize, SharedMemoryOpenError> {
+        self.with_segment(offset.value(), |segment| {
+            segment.local_offset(offset.value(), self.chunk_size)
+                + segment.memory.payload_start_address()
+        })
+    }
+}
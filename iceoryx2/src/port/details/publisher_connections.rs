@@ -15,28 +15,26 @@ use std::{cell::UnsafeCell, sync::Arc};
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service::{
-        self,
-        config_scheme::{connection_config, data_segment_config},
-        dynamic_config::publish_subscribe::PublisherDetails,
-        naming_scheme::{connection_name, data_segment_name},
-        static_config::publish_subscribe::StaticConfig,
+        self, config_scheme::connection_config,
+        dynamic_config::publish_subscribe::PublisherDetails, naming_scheme::connection_name,
+        port_factory::publisher::PublisherPriority, static_config::publish_subscribe::StaticConfig,
         ServiceState,
     },
 };
 
+use crate::port::details::data_segment::DataSegmentMemory;
 use crate::port::update_connections::ConnectionFailure;
 use iceoryx2_bb_log::fail;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
-use iceoryx2_cal::{
-    shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
-    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
-};
+use iceoryx2_cal::zero_copy_connection::*;
 
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) data_segment: DataSegmentMemory<Service>,
     pub(crate) publisher_id: UniquePublisherId,
+    pub(crate) user_header_size: usize,
+    pub(crate) priority: PublisherPriority,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -52,37 +50,44 @@ impl<Service: service::Service> Connection<Service> {
         let receiver = fail!(from this,
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(details.publisher_id, this.subscriber_id))
-                                    .config(&connection_config::<Service>(this.service_state.shared_node.config()))
+                                    .config(&connection_config::<Service>(&this.service_state.shared_node.config()))
                                     .buffer_size(this.buffer_size)
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                    .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                    .enable_safe_overflow(this.enable_safe_overflow)
                                     .number_of_samples(details.number_of_samples)
                                     .timeout(this.service_state.shared_node.config().global.service.creation_timeout)
-                                    .create_receiver(this.static_config.message_type_details().sample_layout(details.max_slice_len).size()),
+                                    .create_receiver(details.chunk_size),
                         "{} since the zero copy connection could not be established.", msg);
 
         let data_segment = fail!(from this,
-                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&data_segment_name(&details.publisher_id))
-                                .config(&data_segment_config::<Service>(this.service_state.shared_node.config()))
-                                .timeout(this.service_state.shared_node.config().global.service.creation_timeout)
-                                .open(),
+                            when DataSegmentMemory::open(&details.publisher_id,
+                                &this.service_state.shared_node.config(),
+                                details.number_of_data_segments,
+                                details.growth,
+                                this.static_config.message_type_details.sample_layout(1).align(),
+                                details.chunk_size,
+                                this.static_config.huge_pages),
                             "{} since the publishers data segment could not be opened.", msg);
 
         Ok(Self {
             receiver,
             data_segment,
             publisher_id: details.publisher_id,
+            user_header_size: this.static_config.message_type_details.user_header.size,
+            priority: details.priority,
         })
     }
 }
 #[derive(Debug)]
 pub(crate) struct PublisherConnections<Service: service::Service> {
     connections: Vec<UnsafeCell<Option<Arc<Connection<Service>>>>>,
+    // the indices of the established connections, the ones with the highest priority first
+    receive_order: UnsafeCell<Vec<usize>>,
     subscriber_id: UniqueSubscriberId,
     pub(crate) service_state: Arc<ServiceState<Service>>,
     pub(crate) static_config: StaticConfig,
     pub(crate) buffer_size: usize,
+    pub(crate) enable_safe_overflow: bool,
 }
 
 impl<Service: service::Service> PublisherConnections<Service> {
@@ -92,13 +97,16 @@ impl<Service: service::Service> PublisherConnections<Service> {
         service_state: Arc<ServiceState<Service>>,
         static_config: &StaticConfig,
         buffer_size: usize,
+        enable_safe_overflow: bool,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            receive_order: UnsafeCell::new(Vec::with_capacity(capacity)),
             subscriber_id,
             service_state,
             static_config: static_config.clone(),
             buffer_size,
+            enable_safe_overflow,
         }
     }
 
@@ -125,12 +133,35 @@ impl<Service: service::Service> PublisherConnections<Service> {
         details: &PublisherDetails,
     ) -> Result<(), ConnectionFailure> {
         *self.get_mut(index) = Some(Arc::new(Connection::new(self, details)?));
+        self.update_receive_order();
 
         Ok(())
     }
 
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None;
+        if self.get_mut(index).take().is_some() {
+            self.update_receive_order();
+        }
+    }
+
+    /// Returns the indices of the established connections ordered by the
+    /// [`PublisherPriority`] of the publishers, the highest priority first. Connections with
+    /// the same priority are ordered by their index.
+    pub(crate) fn receive_order(&self) -> &[usize] {
+        unsafe { &*self.receive_order.get() }
+    }
+
+    fn update_receive_order(&self) {
+        let receive_order = unsafe { &mut *self.receive_order.get() };
+        receive_order.clear();
+        receive_order.extend((0..self.len()).filter(|index| self.get(*index).is_some()));
+        receive_order.sort_by_key(|index| {
+            core::cmp::Reverse(
+                self.get(*index)
+                    .as_ref()
+                    .map(|connection| connection.priority),
+            )
+        });
     }
 
     pub(crate) fn len(&self) -> usize {
@@ -13,25 +13,32 @@
 use std::cell::UnsafeCell;
 use std::sync::Arc;
 
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::event::{Event, Notifier, NotifierBuilder, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
 use crate::node::SharedNode;
-use crate::service::config_scheme::connection_config;
+use crate::service::config_scheme::{connection_config, event_config};
 use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
+use crate::service::port_factory::subscriber::SubscriberOverflowStrategy;
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service,
-    service::{naming_scheme::connection_name, static_config::publish_subscribe::StaticConfig},
+    service::{
+        naming_scheme::{connection_name, subscriber_event_concept_name},
+        static_config::publish_subscribe::StaticConfig,
+    },
 };
 
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) overflow_strategy: Option<SubscriberOverflowStrategy>,
+    notifier: Option<<Service::Event as Event>::Notifier>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -39,7 +46,7 @@ impl<Service: service::Service> Connection<Service> {
         this: &SubscriberConnections<Service>,
         subscriber_details: SubscriberDetails,
         number_of_samples: usize,
-        max_slice_len: usize,
+        chunk_size: usize,
     ) -> Result<Self, ZeroCopyCreationError> {
         let msg = format!(
             "Unable to establish connection to subscriber {:?} from publisher {:?}",
@@ -53,20 +60,52 @@ impl<Service: service::Service> Connection<Service> {
 
         let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.port_id, subscriber_details.subscriber_id))
-                                .config(&connection_config::<Service>(this.shared_node.config()))
+                                .config(&connection_config::<Service>(&this.shared_node.config()))
                                 .buffer_size(subscriber_details.buffer_size)
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                .enable_safe_overflow(SubscriberOverflowStrategy::enable_safe_overflow(
+                                    subscriber_details.overflow_strategy,
+                                    this.static_config.enable_safe_overflow))
                                 .number_of_samples(number_of_samples)
                                 .timeout(this.shared_node.config().global.service.creation_timeout)
-                                .create_sender(this.static_config.message_type_details().sample_layout(max_slice_len).size()),
+                                .create_sender(chunk_size),
                         "{}.", msg);
 
+        let notifier = if subscriber_details.blocking_receive {
+            match <Service::Event as Event>::NotifierBuilder::new(&subscriber_event_concept_name(
+                &subscriber_details.subscriber_id,
+            ))
+            .config(&event_config::<Service>(&this.shared_node.config()))
+            .open()
+            {
+                Ok(notifier) => Some(notifier),
+                Err(e) => {
+                    warn!(from this, "{} with blocking receive support since the event could not be opened ({:?}). The subscriber will not be woken up on delivery.",
+                        msg, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(Self {
             sender,
             subscriber_id: subscriber_details.subscriber_id,
+            overflow_strategy: subscriber_details.overflow_strategy,
+            notifier,
         })
     }
+
+    /// Wakes up the [`crate::port::subscriber::Subscriber`] when it waits in
+    /// [`crate::port::subscriber::Subscriber::receive_blocking()`].
+    pub(crate) fn notify_delivery(&self) {
+        if let Some(notifier) = &self.notifier {
+            // a failure means that the subscriber has still pending notifications or is gone,
+            // in both cases it is not waiting for this one
+            let _ = notifier.notify(TriggerId::new(0));
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -76,6 +115,7 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
     shared_node: Arc<SharedNode<Service>>,
     pub(crate) static_config: StaticConfig,
     number_of_samples: usize,
+    chunk_size: usize,
 }
 
 impl<Service: service::Service> SubscriberConnections<Service> {
@@ -85,6 +125,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
         number_of_samples: usize,
+        chunk_size: usize,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
@@ -92,6 +133,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
             port_id,
             static_config: static_config.clone(),
             number_of_samples,
+            chunk_size,
         }
     }
 
@@ -116,13 +158,12 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         &self,
         index: usize,
         subscriber_details: SubscriberDetails,
-        max_slice_len: usize,
     ) -> Result<(), ZeroCopyCreationError> {
         *self.get_mut(index) = Some(Connection::new(
             self,
             subscriber_details,
             self.number_of_samples,
-            max_slice_len,
+            self.chunk_size,
         )?);
 
         Ok(())
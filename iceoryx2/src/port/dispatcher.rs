@@ -0,0 +1,371 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Dispatcher`] attaches callbacks to [`Listener`]s and [`Subscriber`]s and calls them
+//! whenever an event or a [`Sample`] arrives. It is a thin layer on top of the
+//! [`WaitSet`](crate::port::waitset::WaitSet) that takes care of waiting, of draining all
+//! pending events and [`Sample`]s of an attachment and of the termination on `SIGINT` or
+//! `SIGTERM`.
+//!
+//! A [`Subscriber`] can only be attached when it was created with
+//! [`PortFactorySubscriber::enable_blocking_receive()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_blocking_receive()),
+//! since the [`Dispatcher`] waits on the event that the
+//! [`Publisher`](crate::port::publisher::Publisher)s notify on every delivery.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//! let pubsub = node.service_builder(&"MyServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let subscriber = pubsub.subscriber_builder()
+//!     .enable_blocking_receive(true)
+//!     .create()?;
+//!
+//! let mut dispatcher = DispatcherBuilder::new().create::<ipc::Service>()?;
+//! dispatcher.on_event(&listener, |event_id| {
+//!     println!("received notification {:?}", event_id);
+//!     CallbackProgression::Continue
+//! })?;
+//! dispatcher.on_sample(&subscriber, |sample| {
+//!     println!("received sample {}", *sample);
+//!     CallbackProgression::Continue
+//! })?;
+//!
+//! // returns when a callback returns CallbackProgression::Stop or on SIGINT or SIGTERM
+//! dispatcher.dispatch_loop()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::{Event, Listener as EventListener};
+
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::subscriber::{Subscriber, SubscriberReceiveError};
+use crate::port::waitset::{WaitSet, WaitSetBuilder, WaitSetRunResult};
+use crate::sample::Sample;
+use crate::service;
+
+/// Defines the failures that can occur when a callback is attached with
+/// [`Dispatcher::on_event()`], [`Dispatcher::on_sample()`] or
+/// [`Dispatcher::on_slice_sample()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DispatcherAttachmentError {
+    /// The [`Subscriber`] was created without
+    /// [`PortFactorySubscriber::enable_blocking_receive()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_blocking_receive()).
+    BlockingReceiveNotEnabled,
+    /// The capacity of the underlying [`WaitSet`] is exceeded.
+    InsufficientCapacity,
+}
+
+impl std::fmt::Display for DispatcherAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "DispatcherAttachmentError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DispatcherAttachmentError {}
+
+/// Defines the failures that can occur when calling [`Dispatcher::dispatch_loop()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DispatcherRunError {
+    /// A [`Listener`] or [`Subscriber`] could not be attached to the underlying [`WaitSet`].
+    AttachmentFailure,
+    /// The underlying [`WaitSet`] failed to wait for the attachments.
+    WaitFailure,
+    /// The events or [`Sample`]s of an attachment could not be acquired.
+    ReceiveFailure,
+}
+
+impl std::fmt::Display for DispatcherRunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "DispatcherRunError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DispatcherRunError {}
+
+/// Defines the failures that can occur when calling [`DispatcherBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DispatcherCreateError {
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl std::fmt::Display for DispatcherCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "DispatcherCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DispatcherCreateError {}
+
+enum AttachmentSource<'attachment, Service: service::Service> {
+    Listener(&'attachment Listener<Service>),
+    Delivery(&'attachment <Service::Event as Event>::Listener),
+}
+
+type DispatchCall<'attachment> =
+    Box<dyn FnMut() -> Result<CallbackProgression, DispatcherRunError> + 'attachment>;
+
+struct Attachment<'attachment, Service: service::Service> {
+    source: AttachmentSource<'attachment, Service>,
+    dispatch: DispatchCall<'attachment>,
+}
+
+/// The builder for the [`Dispatcher`].
+#[derive(Debug)]
+pub struct DispatcherBuilder {}
+
+impl Default for DispatcherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DispatcherBuilder {
+    /// Creates a new [`DispatcherBuilder`].
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Creates the [`Dispatcher`].
+    pub fn create<'attachment, Service: service::Service>(
+        self,
+    ) -> Result<Dispatcher<'attachment, Service>, DispatcherCreateError> {
+        let waitset = fail!(from self, when WaitSetBuilder::new().create::<Service>(),
+                with DispatcherCreateError::InternalError,
+                "Unable to create Dispatcher since the underlying WaitSet could not be created.");
+
+        Ok(Dispatcher {
+            waitset,
+            attachments: vec![],
+        })
+    }
+}
+
+/// Calls the attached callbacks whenever an event or a [`Sample`] arrives, see the
+/// [module documentation](crate::port::dispatcher) for an example. Can be created via the
+/// [`DispatcherBuilder`].
+pub struct Dispatcher<'attachment, Service: service::Service> {
+    waitset: WaitSet<Service>,
+    attachments: Vec<Attachment<'attachment, Service>>,
+}
+
+impl<Service: service::Service> Debug for Dispatcher<'_, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Dispatcher<{}> {{ waitset: {:?}, number_of_attachments: {} }}",
+            core::any::type_name::<Service>(),
+            self.waitset,
+            self.attachments.len()
+        )
+    }
+}
+
+impl<'attachment, Service: service::Service + 'attachment> Dispatcher<'attachment, Service>
+where
+    <Service::Event as Event>::Listener: SynchronousMultiplexing,
+{
+    fn attach(
+        &mut self,
+        source: AttachmentSource<'attachment, Service>,
+        dispatch: DispatchCall<'attachment>,
+    ) -> Result<(), DispatcherAttachmentError> {
+        if self.attachments.len() >= self.waitset.capacity() {
+            fail!(from self, with DispatcherAttachmentError::InsufficientCapacity,
+                "Unable to attach callback since it would exceed the capacity of {}.", self.waitset.capacity());
+        }
+
+        self.attachments.push(Attachment { source, dispatch });
+        Ok(())
+    }
+
+    fn attach_subscriber<T: 'attachment>(
+        &mut self,
+        delivery_listener: Option<&'attachment <Service::Event as Event>::Listener>,
+        mut receive: impl FnMut() -> Result<Option<T>, SubscriberReceiveError> + 'attachment,
+        mut callback: impl FnMut(T) -> CallbackProgression + 'attachment,
+    ) -> Result<(), DispatcherAttachmentError> {
+        let delivery_listener = match delivery_listener {
+            Some(listener) => listener,
+            None => {
+                fail!(from self, with DispatcherAttachmentError::BlockingReceiveNotEnabled,
+                    "Unable to attach subscriber since it was created without blocking receive support.");
+            }
+        };
+
+        let origin = format!("{:?}", self);
+        self.attach(
+            AttachmentSource::Delivery(delivery_listener),
+            Box::new(move || {
+                let msg = "Unable to dispatch samples";
+                // notifications of samples that are received in the next step are obsolete
+                fail!(from origin, when delivery_listener.try_wait_all(|_| {}),
+                    with DispatcherRunError::ReceiveFailure,
+                    "{} since the pending delivery notifications could not be acquired.", msg);
+
+                while let Some(sample) = fail!(from origin, when receive(),
+                        with DispatcherRunError::ReceiveFailure,
+                        "{} since the sample could not be received.", msg)
+                {
+                    if let CallbackProgression::Stop = callback(sample) {
+                        return Ok(CallbackProgression::Stop);
+                    }
+                }
+
+                Ok(CallbackProgression::Continue)
+            }),
+        )
+    }
+
+    /// Attaches a callback to the [`Listener`]. It is called for every received [`EventId`].
+    /// When it returns [`CallbackProgression::Stop`], [`Dispatcher::dispatch_loop()`] returns
+    /// and the pending events remain in the [`Listener`].
+    pub fn on_event<F: FnMut(EventId) -> CallbackProgression + 'attachment>(
+        &mut self,
+        listener: &'attachment Listener<Service>,
+        mut callback: F,
+    ) -> Result<(), DispatcherAttachmentError> {
+        let origin = format!("{:?}", self);
+        self.attach(
+            AttachmentSource::Listener(listener),
+            Box::new(move || {
+                while let Some(event_id) = fail!(from origin, when listener.try_wait_one(),
+                        with DispatcherRunError::ReceiveFailure,
+                        "Unable to dispatch events since the event could not be acquired.")
+                {
+                    if let CallbackProgression::Stop = callback(event_id) {
+                        return Ok(CallbackProgression::Stop);
+                    }
+                }
+
+                Ok(CallbackProgression::Continue)
+            }),
+        )
+    }
+
+    /// Attaches a callback to the [`Subscriber`]. It is called for every received [`Sample`].
+    /// When it returns [`CallbackProgression::Stop`], [`Dispatcher::dispatch_loop()`] returns
+    /// and the pending [`Sample`]s remain in the [`Subscriber`].
+    pub fn on_sample<
+        Payload: Debug + 'static,
+        UserHeader: Debug + 'attachment,
+        F: FnMut(Sample<Service, Payload, UserHeader>) -> CallbackProgression + 'attachment,
+    >(
+        &mut self,
+        subscriber: &'attachment Subscriber<Service, Payload, UserHeader>,
+        callback: F,
+    ) -> Result<(), DispatcherAttachmentError> {
+        self.attach_subscriber(
+            subscriber.delivery_listener(),
+            move || subscriber.receive(),
+            callback,
+        )
+    }
+
+    /// Attaches a callback to the [`Subscriber`] with a slice payload. It is called for every
+    /// received [`Sample`]. When it returns [`CallbackProgression::Stop`],
+    /// [`Dispatcher::dispatch_loop()`] returns and the pending [`Sample`]s remain in the
+    /// [`Subscriber`].
+    pub fn on_slice_sample<
+        Payload: Debug + 'static,
+        UserHeader: Debug + 'attachment,
+        F: FnMut(Sample<Service, [Payload], UserHeader>) -> CallbackProgression + 'attachment,
+    >(
+        &mut self,
+        subscriber: &'attachment Subscriber<Service, [Payload], UserHeader>,
+        callback: F,
+    ) -> Result<(), DispatcherAttachmentError> {
+        self.attach_subscriber(
+            subscriber.delivery_listener(),
+            move || subscriber.receive(),
+            callback,
+        )
+    }
+
+    /// Waits for events and [`Sample`]s and calls the attached callbacks until a callback
+    /// returns [`CallbackProgression::Stop`] or a signal is received. All events and
+    /// [`Sample`]s that arrived are handled in one iteration, so that the callbacks are never
+    /// called in a busy loop.
+    ///
+    /// Returns [`WaitSetRunResult::StopRequest`] when a callback requested the stop,
+    /// [`WaitSetRunResult::Interrupt`] on `SIGINT` and
+    /// [`WaitSetRunResult::TerminationRequest`] on `SIGTERM`.
+    pub fn dispatch_loop(&mut self) -> Result<WaitSetRunResult, DispatcherRunError> {
+        let msg = "Unable to run the dispatch loop";
+
+        let mut guards = Vec::with_capacity(self.attachments.len());
+        for attachment in &self.attachments {
+            let guard = match attachment.source {
+                AttachmentSource::Listener(listener) => self.waitset.attach_notification(listener),
+                AttachmentSource::Delivery(listener) => self.waitset.attach_notification(listener),
+            };
+            guards.push(fail!(from self, when guard,
+                with DispatcherRunError::AttachmentFailure,
+                "{} since an attachment could not be attached to the WaitSet.", msg));
+        }
+
+        let attachments = &mut self.attachments;
+        let mut dispatch_error = None;
+        let result = self.waitset.wait_and_process(|attachment_id| {
+            for (attachment, guard) in attachments.iter_mut().zip(guards.iter()) {
+                if attachment_id.has_event_from(guard) {
+                    return match (attachment.dispatch)() {
+                        Ok(progression) => progression,
+                        Err(e) => {
+                            dispatch_error = Some(e);
+                            CallbackProgression::Stop
+                        }
+                    };
+                }
+            }
+
+            CallbackProgression::Continue
+        });
+
+        if let Some(e) = dispatch_error {
+            fail!(from self, with e,
+                "{} since the events of an attachment could not be dispatched.", msg);
+        }
+
+        Ok(fail!(from self, when result,
+            with DispatcherRunError::WaitFailure,
+            "{} since the WaitSet failed to wait for the attachments.", msg))
+    }
+
+    /// Returns the number of attached callbacks.
+    pub fn len(&self) -> usize {
+        self.attachments.len()
+    }
+
+    /// Returns true when no callback is attached.
+    pub fn is_empty(&self) -> bool {
+        self.attachments.is_empty()
+    }
+}
@@ -37,6 +37,239 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Listen To A Subset Of Events
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let event = node.service_builder(&"MyEventName".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! let filter = EventIdFilter::new()
+//!     .with_range(EventId::new(10)..=EventId::new(19))?
+//!     .with_id(EventId::new(42))?;
+//! let listener = event.listener_builder().event_id_filter(filter).create()?;
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! // is filtered out by the notifier, the listener does not wake up
+//! notifier.notify_with_custom_event_id(EventId::new(5))?;
+//! notifier.notify_with_custom_event_id(EventId::new(42))?;
+//!
+//! assert_eq!(listener.try_wait_one()?, Some(EventId::new(42)));
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Named Events
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, EventIdEnum)]
+//! enum MyEvents {
+//!     Start,
+//!     Stop,
+//!     Error = 10,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyNamedEvents".try_into()?)
+//!     .event()
+//!     .event_id_max_value(MyEvents::MAX_EVENT_ID.as_value())
+//!     .open_or_create()?;
+//! let listener = event.listener_builder().create()?;
+//! let notifier = event.notifier_builder()
+//!     .default_event_id(MyEvents::Start.into())
+//!     .create()?;
+//!
+//! notifier.notify_with_custom_event_id(MyEvents::Stop.into())?;
+//!
+//! while let Some(event_id) = listener.try_wait_one()? {
+//!     match MyEvents::from_event_id(event_id) {
+//!         Some(MyEvents::Start) => println!("start"),
+//!         Some(MyEvents::Stop) => println!("stop"),
+//!         Some(MyEvents::Error) => println!("error"),
+//!         None => println!("unknown event: {:?}", event_id),
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::ops::RangeInclusive;
 
 /// Type that allows to identify an event uniquely.
 pub type EventId = iceoryx2_cal::event::TriggerId;
+
+/// Maps the variants of an enum without fields to [`EventId`]s, so that the events of a
+/// service can be named instead of using raw [`EventId`] values. It is implemented with
+/// `#[derive(EventIdEnum)]`, which maps every variant to the [`EventId`] of its discriminant and
+/// implements `From<Enum>` for [`EventId`].
+pub trait EventIdEnum: Sized {
+    /// The largest [`EventId`] of all variants. It can be used as
+    /// [`event_id_max_value()`](crate::service::builder::event::Builder::event_id_max_value())
+    /// of the service.
+    const MAX_EVENT_ID: EventId;
+
+    /// Returns the [`EventId`] of the variant.
+    fn event_id(&self) -> EventId;
+
+    /// Returns the variant that corresponds to the [`EventId`] or [`None`] when there is none.
+    fn from_event_id(value: EventId) -> Option<Self>;
+}
+
+/// The maximum number of distinct [`EventId`] ranges an [`EventIdFilter`] can contain.
+/// Adjacent and overlapping ranges are merged and a single [`EventId`] occupies one range.
+pub const MAX_EVENT_ID_FILTER_RANGES: usize = 32;
+
+/// Failures that can occur when an [`EventIdFilter`] is extended.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EventIdFilterError {
+    /// The [`EventIdFilter`] would contain more than [`MAX_EVENT_ID_FILTER_RANGES`] distinct
+    /// ranges.
+    ExceedsMaxSupportedRanges,
+}
+
+impl std::fmt::Display for EventIdFilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EventIdFilterError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EventIdFilterError {}
+
+/// Defines the [`EventId`]s a [`Listener`](crate::port::listener::Listener) wants to receive.
+/// The filter is applied by the [`Notifier`](crate::port::notifier::Notifier), a
+/// [`Listener`](crate::port::listener::Listener) is not woken up by [`EventId`]s that are
+/// not part of its filter. The default filter accepts every [`EventId`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventIdFilter {
+    accepts_all: bool,
+    // sorted, non-overlapping and non-adjacent inclusive ranges
+    ranges: [(usize, usize); MAX_EVENT_ID_FILTER_RANGES],
+    len: usize,
+}
+
+impl Default for EventIdFilter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl EventIdFilter {
+    /// Creates a filter that accepts every [`EventId`].
+    pub fn all() -> Self {
+        Self {
+            accepts_all: true,
+            ranges: [(0, 0); MAX_EVENT_ID_FILTER_RANGES],
+            len: 0,
+        }
+    }
+
+    /// Creates an empty filter that accepts no [`EventId`]. Use [`EventIdFilter::with_id()`]
+    /// and [`EventIdFilter::with_range()`] to add the [`EventId`]s of interest.
+    pub fn new() -> Self {
+        Self {
+            accepts_all: false,
+            ..Self::all()
+        }
+    }
+
+    /// Adds a single [`EventId`] to the filter.
+    pub fn with_id(self, id: EventId) -> Result<Self, EventIdFilterError> {
+        self.with_range(id..=id)
+    }
+
+    /// Adds all [`EventId`]s of the inclusive range to the filter. An empty range is ignored.
+    pub fn with_range(
+        mut self,
+        range: RangeInclusive<EventId>,
+    ) -> Result<Self, EventIdFilterError> {
+        if self.accepts_all || range.is_empty() {
+            return Ok(self);
+        }
+
+        let mut start = range.start().as_value();
+        let mut end = range.end().as_value();
+
+        // ranges that overlap or are adjacent to the new range are merged into it
+        let mut ranges = [(0, 0); MAX_EVENT_ID_FILTER_RANGES];
+        let mut len = 0;
+        let mut push = |range: (usize, usize)| -> Result<(), EventIdFilterError> {
+            if len == MAX_EVENT_ID_FILTER_RANGES {
+                return Err(EventIdFilterError::ExceedsMaxSupportedRanges);
+            }
+            ranges[len] = range;
+            len += 1;
+            Ok(())
+        };
+
+        for &(s, e) in self.ranges[..self.len].iter() {
+            if e.saturating_add(1) >= start && end.saturating_add(1) >= s {
+                start = start.min(s);
+                end = end.max(e);
+            }
+        }
+
+        let mut is_inserted = false;
+        for &(s, e) in self.ranges[..self.len].iter() {
+            if e < start {
+                push((s, e))?;
+            } else if end < s {
+                if !is_inserted {
+                    push((start, end))?;
+                    is_inserted = true;
+                }
+                push((s, e))?;
+            }
+        }
+
+        if !is_inserted {
+            push((start, end))?;
+        }
+
+        self.ranges = ranges;
+        self.len = len;
+        Ok(self)
+    }
+
+    /// Returns true when the filter accepts every [`EventId`].
+    pub fn accepts_all(&self) -> bool {
+        self.accepts_all
+    }
+
+    /// Returns true when the [`EventId`] passes the filter.
+    pub fn contains(&self, id: EventId) -> bool {
+        if self.accepts_all {
+            return true;
+        }
+
+        let id = id.as_value();
+        self.ranges[..self.len]
+            .binary_search_by(|&(s, e)| {
+                if e < id {
+                    core::cmp::Ordering::Less
+                } else if id < s {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns an iterator over the inclusive [`EventId`] ranges of the filter in ascending
+    /// order. It is empty when the filter accepts every [`EventId`].
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<EventId>> + '_ {
+        self.ranges[..self.len]
+            .iter()
+            .map(|&(s, e)| EventId::new(s)..=EventId::new(e))
+    }
+}
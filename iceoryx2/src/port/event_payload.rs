@@ -0,0 +1,132 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .payload_size(4)
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! notifier.notify_with_payload(&42u32.to_ne_bytes())?;
+//!
+//! if let Some(payload) = listener.timed_wait_one_with_payload(Duration::from_millis(10))? {
+//!     println!("event {:?} carried the payload {:?}", payload.event_id(), payload.data());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_lock_free::spsc::queue::Queue;
+
+use super::event_id::EventId;
+
+/// The maximum size in bytes of the payload a [`Notifier`](crate::port::notifier::Notifier)
+/// can attach to an event.
+pub const MAX_EVENT_PAYLOAD_SIZE: usize = 64;
+
+/// The number of event payloads a [`Notifier`](crate::port::notifier::Notifier) can send to a
+/// [`Listener`](crate::port::listener::Listener) before the listener has to consume them.
+pub const EVENT_PAYLOAD_QUEUE_CAPACITY: usize = 32;
+
+/// The small inline payload of an event together with the [`EventId`] it was sent with. Both
+/// are delivered together, a [`Listener`](crate::port::listener::Listener) never sees the
+/// payload of one notification with the [`EventId`] of another.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventPayload {
+    event_id: EventId,
+    len: usize,
+    data: [u8; MAX_EVENT_PAYLOAD_SIZE],
+}
+
+impl EventPayload {
+    pub(crate) fn new(event_id: EventId, value: &[u8]) -> Self {
+        debug_assert!(value.len() <= MAX_EVENT_PAYLOAD_SIZE);
+
+        let mut new_self = Self {
+            event_id,
+            len: value.len(),
+            data: [0; MAX_EVENT_PAYLOAD_SIZE],
+        };
+        new_self.data[..value.len()].copy_from_slice(value);
+        new_self
+    }
+
+    /// Returns the [`EventId`] the payload was sent with.
+    pub fn event_id(&self) -> EventId {
+        self.event_id
+    }
+
+    /// Returns the payload bytes.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+}
+
+/// The queue that transfers the [`EventPayload`]s of one
+/// [`Notifier`](crate::port::notifier::Notifier) to one
+/// [`Listener`](crate::port::listener::Listener). It is stored in a
+/// [`DynamicStorage`](iceoryx2_cal::dynamic_storage::DynamicStorage) that is owned by the
+/// [`Notifier`](crate::port::notifier::Notifier).
+#[repr(C)]
+pub struct EventPayloadQueue {
+    queue: Queue<EventPayload, EVENT_PAYLOAD_QUEUE_CAPACITY>,
+}
+
+impl Debug for EventPayloadQueue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "EventPayloadQueue {{ len: {}, capacity: {} }}",
+            self.queue.len(),
+            self.queue.capacity()
+        )
+    }
+}
+
+impl EventPayloadQueue {
+    pub(crate) fn new() -> Self {
+        Self {
+            queue: Queue::new(),
+        }
+    }
+
+    /// Returns false when the queue is full.
+    ///
+    /// # Safety
+    ///
+    ///  * must be called only by the [`Notifier`](crate::port::notifier::Notifier) that owns
+    ///    the queue
+    pub(crate) unsafe fn push(&self, payload: &EventPayload) -> bool {
+        self.queue.push(payload)
+    }
+
+    /// # Safety
+    ///
+    ///  * must be called only by the [`Listener`](crate::port::listener::Listener) the queue
+    ///    belongs to
+    pub(crate) unsafe fn pop(&self) -> Option<EventPayload> {
+        self.queue.pop()
+    }
+}
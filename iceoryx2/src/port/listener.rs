@@ -57,26 +57,61 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Process Set Of Distinct Events
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let mut listener = event.listener_builder().create()?;
+//!
+//! let batch = listener.timed_wait_batch(Duration::from_millis(10))?;
+//! for (id, count) in batch.iter() {
+//!     println!("event was triggered {} times with id: {:?}", count, id);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`event_payload`](crate::port::event_payload) for notifications that carry a payload.
+
+/// Runtime agnostic async support for the [`Listener`].
+#[cfg(feature = "async")]
+pub mod wait_async;
 
-use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::context::LogContextGuard;
+use iceoryx2_bb_log::{debug, fail};
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
 use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
-use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::dynamic_storage::{
+    DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
+};
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId};
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
 
 use crate::config::Config;
-use crate::service::config_scheme::event_config;
-use crate::service::dynamic_config::event::ListenerDetails;
-use crate::service::naming_scheme::event_concept_name;
+use crate::node::node_log;
+use crate::port::port_identifiers::UniqueNotifierId;
+use crate::service::config_scheme::{event_config, event_payload_config};
+use crate::service::dynamic_config::event::{ListenerDetails, NotifierDetails};
+use crate::service::naming_scheme::{event_concept_name, event_payload_name};
 use crate::service::ServiceState;
 use crate::{port::port_identifiers::UniqueListenerId, service};
 use std::sync::atomic::Ordering;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use super::event_id::EventId;
+use super::event_id::{EventId, EventIdFilter};
+use super::event_payload::{EventPayload, EventPayloadQueue};
 
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
@@ -99,6 +134,181 @@ impl std::fmt::Display for ListenerCreateError {
 
 impl std::error::Error for ListenerCreateError {}
 
+/// The set of distinct [`EventId`]s that were received by one call of
+/// [`Listener::try_wait_batch()`], [`Listener::timed_wait_batch()`] or
+/// [`Listener::blocking_wait_batch()`] together with the number of times every [`EventId`] was
+/// received. The [`EventId`]s are ordered by their value.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventIdBatch {
+    entries: Vec<(EventId, usize)>,
+}
+
+impl EventIdBatch {
+    fn position(&self, id: EventId) -> Result<usize, usize> {
+        self.entries
+            .binary_search_by_key(&id, |(entry_id, _)| *entry_id)
+    }
+
+    fn add(&mut self, id: EventId) {
+        match self.position(id) {
+            Ok(index) => self.entries[index].1 += 1,
+            Err(index) => self.entries.insert(index, (id, 1)),
+        }
+    }
+
+    /// Returns true when no [`EventId`] was received.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of distinct [`EventId`]s that were received.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true when the [`EventId`] was received at least once.
+    pub fn contains(&self, id: EventId) -> bool {
+        self.count(id) != 0
+    }
+
+    /// Returns how often the [`EventId`] was received, 0 when it was not received.
+    pub fn count(&self, id: EventId) -> usize {
+        match self.position(id) {
+            Ok(index) => self.entries[index].1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Returns an iterator over all received [`EventId`]s and how often they were received.
+    pub fn iter(&self) -> impl Iterator<Item = (EventId, usize)> + '_ {
+        self.entries.iter().copied()
+    }
+}
+
+#[derive(Debug)]
+struct PayloadConnection<Service: service::Service> {
+    notifier_id: UniqueNotifierId,
+    payload_queue: Option<Service::EventPayloadStorage>,
+}
+
+/// The payload queues of all [`Notifier`](crate::port::notifier::Notifier)s, indexed like the
+/// notifiers in the dynamic config of the service.
+#[derive(Debug)]
+struct PayloadConnections<Service: service::Service> {
+    notifier_list_state: ContainerState<NotifierDetails>,
+    connections: Vec<Option<PayloadConnection<Service>>>,
+    next_connection: usize,
+}
+
+impl<Service: service::Service> PayloadConnections<Service> {
+    fn new(service_state: &ServiceState<Service>) -> Self {
+        let notifier_list = &service_state.dynamic_storage.get().event().notifiers;
+        let mut new_self = Self {
+            notifier_list_state: unsafe { notifier_list.get_state() },
+            connections: (0..notifier_list.capacity()).map(|_| None).collect(),
+            next_connection: 0,
+        };
+        new_self.populate_notifier_connections();
+        new_self
+    }
+
+    fn update_connections(&mut self, service_state: &ServiceState<Service>) {
+        if unsafe {
+            service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .notifiers
+                .update_state(&mut self.notifier_list_state)
+        } {
+            self.populate_notifier_connections();
+        }
+    }
+
+    fn populate_notifier_connections(&mut self) {
+        let mut visited_indices = vec![None; self.connections.len()];
+        self.notifier_list_state.for_each(|h, details| {
+            visited_indices[h.index() as usize] = Some(details.notifier_id);
+            CallbackProgression::Continue
+        });
+
+        for (connection, notifier_id) in self.connections.iter_mut().zip(visited_indices) {
+            match notifier_id {
+                Some(notifier_id) => {
+                    if connection
+                        .as_ref()
+                        .map_or(true, |c| c.notifier_id != notifier_id)
+                    {
+                        // the payload queue is created by the notifier and opened on demand
+                        *connection = Some(PayloadConnection {
+                            notifier_id,
+                            payload_queue: None,
+                        });
+                    }
+                }
+                None => *connection = None,
+            }
+        }
+    }
+
+    fn receive(
+        &mut self,
+        listener_id: &UniqueListenerId,
+        service_state: &ServiceState<Service>,
+    ) -> Option<EventPayload> {
+        let number_of_connections = self.connections.len();
+        for n in 0..number_of_connections {
+            let index = (self.next_connection + n) % number_of_connections;
+            let connection = match &mut self.connections[index] {
+                Some(connection) => connection,
+                None => continue,
+            };
+
+            if connection.payload_queue.is_none() {
+                connection.payload_queue =
+                    Self::open_payload_queue(&connection.notifier_id, listener_id, service_state);
+            }
+
+            if let Some(queue) = &connection.payload_queue {
+                if let Some(payload) = unsafe { queue.get().pop() } {
+                    // continue with the next notifier so that no notifier can starve the others
+                    self.next_connection = (index + 1) % number_of_connections;
+                    return Some(payload);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn open_payload_queue(
+        notifier_id: &UniqueNotifierId,
+        listener_id: &UniqueListenerId,
+        service_state: &ServiceState<Service>,
+    ) -> Option<Service::EventPayloadStorage> {
+        match <Service::EventPayloadStorage as DynamicStorage<EventPayloadQueue>>::Builder::new(
+            &event_payload_name(notifier_id, listener_id),
+        )
+        .config(&event_payload_config::<Service>(
+            &service_state.shared_node.config(),
+        ))
+        .open()
+        {
+            Ok(queue) => Some(queue),
+            Err(
+                DynamicStorageOpenError::DoesNotExist
+                | DynamicStorageOpenError::InitializationNotYetFinalized,
+            ) => None,
+            Err(e) => {
+                let _log_context = service_state.shared_node.enter_log_context();
+                debug!(from "PayloadConnections::open_payload_queue()",
+                    "Unable to open the payload queue of the notifier {:?} ({:?}).", notifier_id, e);
+                None
+            }
+        }
+    }
+}
+
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
@@ -106,6 +316,9 @@ pub struct Listener<Service: service::Service> {
     listener: <Service::Event as iceoryx2_cal::event::Event>::Listener,
     service_state: Arc<ServiceState<Service>>,
     listener_id: UniqueListenerId,
+    payload_connections: Option<Mutex<PayloadConnections<Service>>>,
+    deadline: Option<Duration>,
+    missed_deadlines_at_creation: u64,
 }
 
 impl<Service: service::Service> FileDescriptorBased for Listener<Service>
@@ -122,26 +335,87 @@ impl<Service: service::Service> SynchronousMultiplexing for Listener<Service> wh
 {
 }
 
+/// Allows to register the [`Listener`] in an external event loop like `epoll`, `kqueue` or
+/// `mio`. When the file descriptor becomes readable, the events can be acquired with
+/// [`Listener::try_wait_all()`] without blocking.
+#[cfg(unix)]
+impl<Service: service::Service> std::os::fd::AsRawFd for Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        std::os::fd::AsRawFd::as_raw_fd(self.file_descriptor())
+    }
+}
+
+#[cfg(unix)]
+impl<Service: service::Service> std::os::fd::AsFd for Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        std::os::fd::AsFd::as_fd(self.file_descriptor())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service> Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Returns a [`Future`](core::future::Future) that becomes ready with the next received
+    /// [`EventId`]. It does not depend on a specific async runtime and does not block the
+    /// executor. When an [`EventId`] is already available it becomes ready without involving
+    /// a thread, otherwise a short-lived background thread waits for the next notification.
+    ///
+    /// Runtimes with their own reactor can avoid the background thread completely by
+    /// registering the file descriptor of the [`Listener`] and calling
+    /// [`Listener::try_wait_one()`] when it becomes readable, e.g. with tokio's `AsyncFd`.
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let event = node.service_builder(&"MyEventName".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    /// let listener = event.listener_builder().create()?;
+    ///
+    /// while let Ok(event_id) = listener.wait_async().await {
+    ///     println!("event was triggered with id: {:?}", event_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_async(&self) -> wait_async::ListenerWaitFuture<'_, Service> {
+        wait_async::ListenerWaitFuture::new(self)
+    }
+}
+
 impl<Service: service::Service> Drop for Listener<Service> {
     fn drop(&mut self) {
         if let Some(handle) = self.dynamic_listener_handle {
-            self.service_state
-                .dynamic_storage
-                .get()
-                .event()
-                .release_listener_handle(handle)
+            let dynamic_config = self.service_state.dynamic_storage.get();
+            dynamic_config.event().release_listener_handle(handle);
+            dynamic_config.statistics().add_disconnected_port();
         }
     }
 }
 
 impl<Service: service::Service> Listener<Service> {
-    pub(crate) fn new(service: &Service) -> Result<Self, ListenerCreateError> {
+    pub(crate) fn new(
+        service: &Service,
+        event_id_filter: EventIdFilter,
+    ) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
+        let _log_context = service.__internal_state().shared_node.enter_log_context();
         let listener_id = UniqueListenerId::new();
 
         let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(service.__internal_state().shared_node.config());
+        let event_config =
+            event_config::<Service>(&service.__internal_state().shared_node.config());
 
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name).config(&event_config)
@@ -150,11 +424,37 @@ impl<Service: service::Service> Listener<Service> {
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
 
+        let payload_connections = match service
+            .__internal_state()
+            .static_config
+            .event()
+            .payload_size
+        {
+            0 => None,
+            _ => Some(Mutex::new(PayloadConnections::new(
+                service.__internal_state(),
+            ))),
+        };
+
+        let deadline = service.__internal_state().static_config.event().deadline;
+        let missed_deadlines_at_creation = match deadline {
+            Some(deadline) => service
+                .__internal_state()
+                .dynamic_storage
+                .get()
+                .event()
+                .missed_deadlines(deadline),
+            None => 0,
+        };
+
         let mut new_self = Self {
             service_state: service.__internal_state().clone(),
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            payload_connections,
+            deadline,
+            missed_deadlines_at_creation,
         };
 
         std::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -169,6 +469,7 @@ impl<Service: service::Service> Listener<Service> {
             .add_listener_id(ListenerDetails {
                 listener_id,
                 node_id: *service.__internal_state().shared_node.id(),
+                event_id_filter,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -179,16 +480,33 @@ impl<Service: service::Service> Listener<Service> {
         };
 
         new_self.dynamic_listener_handle = Some(dynamic_listener_handle);
+        service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .add_connected_port();
 
         Ok(new_self)
     }
 
+    // Waiting is on the hot path, the log context of the owning node is entered only right
+    // before a failure is logged.
+    fn enter_log_context(&self) -> LogContextGuard {
+        self.service_state.shared_node.enter_log_context()
+    }
+
     /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn try_wait_all<F: FnMut(EventId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.try_wait_all(callback),
-            "Failed to while calling try_wait on underlying event::Listener"))
+        match self.listener.try_wait_all(callback) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                node_log!(fail!(from self, with e,
+                    "Failed to while calling try_wait on underlying event::Listener"));
+            }
+        }
     }
 
     /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Unblocks as soon
@@ -200,10 +518,13 @@ impl<Service: service::Service> Listener<Service> {
         timeout: Duration,
     ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(
-            fail!(from self, when self.listener.timed_wait_all(callback, timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout),
-        )
+        match self.listener.timed_wait_all(callback, timeout) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                node_log!(fail!(from self, with e,
+                    "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout));
+            }
+        }
     }
 
     /// Blocking wait for new [`EventId`]s. Unblocks as soon
@@ -214,10 +535,43 @@ impl<Service: service::Service> Listener<Service> {
         callback: F,
     ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(
-            fail!(from self, when self.listener.blocking_wait_all(callback),
-            "Failed to while calling blocking_wait on underlying event::Listener"),
-        )
+        match self.listener.blocking_wait_all(callback) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                node_log!(fail!(from self, with e,
+                    "Failed to while calling blocking_wait on underlying event::Listener"));
+            }
+        }
+    }
+
+    /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received
+    /// and returns them as [`EventIdBatch`] where every [`EventId`] is contained once together
+    /// with the number of times it was received.
+    pub fn try_wait_batch(&self) -> Result<EventIdBatch, ListenerWaitError> {
+        let mut batch = EventIdBatch::default();
+        self.try_wait_all(|id| batch.add(id))?;
+        Ok(batch)
+    }
+
+    /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Unblocks as soon
+    /// as an [`EventId`] was received and then collects all [`EventId`]s that were received
+    /// and returns them as [`EventIdBatch`] where every [`EventId`] is contained once together
+    /// with the number of times it was received. When the timeout passed without any
+    /// [`EventId`] the [`EventIdBatch`] is empty.
+    pub fn timed_wait_batch(&self, timeout: Duration) -> Result<EventIdBatch, ListenerWaitError> {
+        let mut batch = EventIdBatch::default();
+        self.timed_wait_all(|id| batch.add(id), timeout)?;
+        Ok(batch)
+    }
+
+    /// Blocking wait for new [`EventId`]s. Unblocks as soon as an [`EventId`] was received and
+    /// then collects all [`EventId`]s that were received and returns them as [`EventIdBatch`]
+    /// where every [`EventId`] is contained once together with the number of times it was
+    /// received. Sporadic wakeups can occur and then the [`EventIdBatch`] is empty.
+    pub fn blocking_wait_batch(&self) -> Result<EventIdBatch, ListenerWaitError> {
+        let mut batch = EventIdBatch::default();
+        self.blocking_wait_all(|id| batch.add(id))?;
+        Ok(batch)
     }
 
     /// Non-blocking wait for a new [`EventId`]. If no [`EventId`] was notified it returns [`None`].
@@ -225,8 +579,13 @@ impl<Service: service::Service> Listener<Service> {
     /// in detail.
     pub fn try_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.try_wait_one(),
-            "Failed to while calling try_wait on underlying event::Listener"))
+        match self.listener.try_wait_one() {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                node_log!(fail!(from self, with e,
+                    "Failed to while calling try_wait on underlying event::Listener"));
+            }
+        }
     }
 
     /// Blocking wait for a new [`EventId`] until either an [`EventId`] was received or the timeout
@@ -235,8 +594,13 @@ impl<Service: service::Service> Listener<Service> {
     /// in detail.
     pub fn timed_wait_one(&self, timeout: Duration) -> Result<Option<EventId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.timed_wait_one(timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout))
+        match self.listener.timed_wait_one(timeout) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                node_log!(fail!(from self, with e,
+                    "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout));
+            }
+        }
     }
 
     /// Blocking wait for a new [`EventId`].
@@ -245,14 +609,128 @@ impl<Service: service::Service> Listener<Service> {
     /// in detail.
     pub fn blocking_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.blocking_wait_one(),
-            "Failed to while calling blocking_wait on underlying event::Listener"))
+        match self.listener.blocking_wait_one() {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                node_log!(fail!(from self, with e,
+                    "Failed to while calling blocking_wait on underlying event::Listener"));
+            }
+        }
+    }
+
+    fn receive_payload(&self) -> Result<Option<EventPayload>, ListenerWaitError> {
+        let msg = "Unable to receive an event payload";
+        let payload_connections = match &self.payload_connections {
+            Some(payload_connections) => payload_connections,
+            None => {
+                node_log!(fail!(from self, with ListenerWaitError::ContractViolation,
+                    "{} since the notifications of the service do not carry a payload.", msg));
+            }
+        };
+
+        let mut payload_connections = match payload_connections.lock() {
+            Ok(payload_connections) => payload_connections,
+            Err(_) => {
+                node_log!(fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} since the payload connections are poisoned.", msg));
+            }
+        };
+
+        payload_connections.update_connections(&self.service_state);
+        Ok(payload_connections.receive(&self.listener_id, &self.service_state))
+    }
+
+    /// Non-blocking wait for a new [`EventPayload`] that was sent with
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+    /// If no [`EventPayload`] was received it returns [`None`]. The payloads of every
+    /// [`Notifier`](crate::port::notifier::Notifier) are received in the order they were sent.
+    /// When the notifications of the service do not carry a payload, it fails with
+    /// [`ListenerWaitError::ContractViolation`].
+    pub fn try_wait_one_with_payload(&self) -> Result<Option<EventPayload>, ListenerWaitError> {
+        self.receive_payload()
+    }
+
+    /// Blocking wait for a new [`EventPayload`] until either an [`EventPayload`] was received or
+    /// the timeout has passed. If no [`EventPayload`] was received it returns [`None`].
+    /// The [`EventId`]s of the notifications are consumed while waiting, therefore it shall not
+    /// be mixed with the [`EventId`] based wait calls.
+    /// See [`Listener::try_wait_one_with_payload()`].
+    pub fn timed_wait_one_with_payload(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<EventPayload>, ListenerWaitError> {
+        let start = Instant::now();
+        loop {
+            // the notifications of payloads that are received in the next step are obsolete
+            self.try_wait_all(|_| {})?;
+
+            if let Some(payload) = self.receive_payload()? {
+                return Ok(Some(payload));
+            }
+
+            let elapsed = start.elapsed();
+            if timeout <= elapsed {
+                return Ok(None);
+            }
+
+            self.timed_wait_all(|_| {}, timeout - elapsed)?;
+        }
+    }
+
+    /// Blocking wait for a new [`EventPayload`].
+    /// Sporadic wakeups can occur and if no [`EventPayload`] was received it returns [`None`].
+    /// The [`EventId`]s of the notifications are consumed while waiting, therefore it shall not
+    /// be mixed with the [`EventId`] based wait calls.
+    /// See [`Listener::try_wait_one_with_payload()`].
+    pub fn blocking_wait_one_with_payload(
+        &self,
+    ) -> Result<Option<EventPayload>, ListenerWaitError> {
+        // the notifications of payloads that are received in the next step are obsolete
+        self.try_wait_all(|_| {})?;
+
+        if let Some(payload) = self.receive_payload()? {
+            return Ok(Some(payload));
+        }
+
+        self.blocking_wait_all(|_| {})?;
+        self.receive_payload()
     }
 
     /// Returns the [`UniqueListenerId`] of the [`Listener`]
     pub fn id(&self) -> UniqueListenerId {
         self.listener_id
     }
+
+    /// Returns the human-readable name of the [`EventId`] when one was registered with
+    /// [`crate::service::builder::event::Builder::event_id_name()`], otherwise [`None`].
+    pub fn event_id_name(&self, id: EventId) -> Option<&str> {
+        self.service_state.static_config.event().event_id_name(id)
+    }
+
+    /// Returns the deadline of the [`Service`](crate::service::Service) in which a notification
+    /// is expected, see [`crate::service::builder::event::Builder::deadline()`]. When it is
+    /// [`None`], no deadline is monitored.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns how many deadline periods passed without a notification since the [`Listener`]
+    /// was created. Every notification of any [`Notifier`](crate::port::notifier::Notifier)
+    /// starts a new period, therefore the count does not depend on how often the [`Listener`]
+    /// waits for events. When the [`Service`](crate::service::Service) has no deadline, it
+    /// returns 0.
+    pub fn missed_deadline_count(&self) -> u64 {
+        match self.deadline {
+            Some(deadline) => self
+                .service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .missed_deadlines(deadline)
+                .saturating_sub(self.missed_deadlines_at_creation),
+            None => 0,
+        }
+    }
 }
 
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
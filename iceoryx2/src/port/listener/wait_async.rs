@@ -0,0 +1,211 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Runtime agnostic [`Future`] that is returned by
+//! [`Listener::wait_async()`](crate::port::listener::Listener::wait_async()). It does not
+//! depend on a specific async executor and can therefore be awaited in tokio, async-std or
+//! any other runtime.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+};
+use iceoryx2_bb_posix::thread::ThreadName;
+use iceoryx2_cal::event::ListenerWaitError;
+
+use super::Listener;
+use crate::node::thread_settings::InternalThread;
+use crate::port::event_id::EventId;
+use crate::service;
+
+// interval in which a waiting thread checks if its future was dropped
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+// owns a duplicate of the listeners file descriptor so that the waiting thread never
+// operates on a closed file descriptor when the listener is dropped in the meantime
+struct ListenerFileDescriptor(FileDescriptor);
+
+impl FileDescriptorBased for ListenerFileDescriptor {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+impl SynchronousMultiplexing for ListenerFileDescriptor {}
+
+#[derive(Debug, Default)]
+struct State {
+    result: Option<Result<(), ListenerWaitError>>,
+    waker: Option<Waker>,
+    is_cancelled: bool,
+}
+
+/// The [`Future`] returned by
+/// [`Listener::wait_async()`](crate::port::listener::Listener::wait_async()). It becomes
+/// ready with the next [`EventId`] that is received by the [`Listener`].
+///
+/// When an [`EventId`] is already available no thread is involved. Otherwise, a short-lived
+/// background thread waits until the file descriptor of the [`Listener`] becomes readable so
+/// that the executor thread is never blocked. The thread applies the
+/// [`ThreadSettings`](crate::node::thread_settings::ThreadSettings) of the
+/// [`Node`](crate::node::Node) that created the [`Listener`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ListenerWaitFuture<'listener, Service: service::Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    listener: &'listener Listener<Service>,
+    state: Option<Arc<Mutex<State>>>,
+    thread: Option<InternalThread>,
+}
+
+impl<'listener, Service: service::Service> ListenerWaitFuture<'listener, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    pub(crate) fn new(listener: &'listener Listener<Service>) -> Self {
+        Self {
+            listener,
+            state: None,
+            thread: None,
+        }
+    }
+
+    fn start(&mut self, waker: Waker) -> Result<(), ListenerWaitError> {
+        let state = Arc::new(Mutex::new(State {
+            waker: Some(waker),
+            ..Default::default()
+        }));
+        self.state = Some(state.clone());
+        let file_descriptor = ListenerFileDescriptor(self.listener.file_descriptor().clone());
+
+        let thread_settings = self.listener.service_state.shared_node.thread_settings();
+        let thread = thread_settings.spawn(&ThreadName::from(b"iox2-listener"), move || {
+            if wait_until_readable(file_descriptor, &state) {
+                if let Some(waker) = lock(&state).waker.take() {
+                    waker.wake();
+                }
+            }
+        });
+
+        match thread {
+            Ok(thread) => {
+                self.thread = Some(thread);
+                Ok(())
+            }
+            Err(e) => {
+                self.state = None;
+                warn!(from "ListenerWaitFuture::start()",
+                    "Unable to spawn the thread that waits on the listener with the settings {:?} ({:?}).",
+                    thread_settings, e);
+                Err(ListenerWaitError::InternalFailure)
+            }
+        }
+    }
+}
+
+fn wait_until_readable(file_descriptor: ListenerFileDescriptor, state: &Mutex<State>) -> bool {
+    let fd_set = FileDescriptorSet::new();
+    let _guard = match fd_set.add(&file_descriptor) {
+        Ok(guard) => guard,
+        Err(_) => {
+            lock(state).result = Some(Err(ListenerWaitError::InternalFailure));
+            return true;
+        }
+    };
+
+    loop {
+        let wait_result = fd_set.timed_wait(CANCELLATION_CHECK_INTERVAL, FileEvent::Read, |_| {});
+        let result = match wait_result {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(())),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                Some(Err(ListenerWaitError::InterruptSignal))
+            }
+            Err(_) => Some(Err(ListenerWaitError::InternalFailure)),
+        };
+
+        let mut state = lock(state);
+        if state.is_cancelled {
+            return false;
+        }
+
+        if result.is_some() {
+            state.result = result;
+            return true;
+        }
+    }
+}
+
+fn lock(state: &Mutex<State>) -> std::sync::MutexGuard<'_, State> {
+    match state.lock() {
+        Ok(guard) => guard,
+        Err(e) => e.into_inner(),
+    }
+}
+
+impl<Service: service::Service> Future for ListenerWaitFuture<'_, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    type Output = Result<EventId, ListenerWaitError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(state) = &self.state {
+            let mut state = lock(state);
+            match state.result.take() {
+                // the file descriptor became readable, acquire the event below
+                Some(Ok(())) => (),
+                Some(Err(e)) => return Poll::Ready(Err(e)),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+        }
+        self.state = None;
+
+        match self.listener.try_wait_one() {
+            Ok(Some(event_id)) => Poll::Ready(Ok(event_id)),
+            Ok(None) => {
+                // the file descriptor may have become readable due to an event that
+                // was already consumed by someone else, therefore the wait is restarted
+                match self.start(cx.waker().clone()) {
+                    Ok(()) => Poll::Pending,
+                    Err(e) => Poll::Ready(Err(e)),
+                }
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+impl<Service: service::Service> Drop for ListenerWaitFuture<'_, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn drop(&mut self) {
+        if let Some(state) = &self.state {
+            let mut state = lock(state);
+            state.is_cancelled = true;
+            state.waker = None;
+        }
+    }
+}
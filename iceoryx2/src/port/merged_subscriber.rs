@@ -0,0 +1,198 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`MergedSubscriber`] owns the [`Subscriber`]s of multiple publish-subscribe services with
+//! the same payload type and receives their [`Sample`]s in one call. Every [`Sample`] is
+//! returned together with the [`MergedSubscriberSource`] of the [`Subscriber`] it was received
+//! from. The [`Subscriber`]s are served in a round-robin fashion so that a busy service cannot
+//! starve the others.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::merged_subscriber::MergedSubscriber;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let mut merged_subscriber = MergedSubscriber::new();
+//! for sensor in ["Sensor/Front", "Sensor/Rear"] {
+//!     let service = node.service_builder(&sensor.try_into()?)
+//!         .publish_subscribe::<f32>()
+//!         .open_or_create()?;
+//!     merged_subscriber.add(service.subscriber_builder().create()?);
+//! }
+//!
+//! while let Some((source, sample)) = merged_subscriber.receive()? {
+//!     println!("{}: {}", merged_subscriber.service_name(source).unwrap(), *sample);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::Cell;
+use std::fmt::Debug;
+
+use crate::port::subscriber::{Subscriber, SubscriberReceiveError};
+use crate::port::update_connections::ConnectionFailure;
+use crate::sample::Sample;
+use crate::service;
+use crate::service::service_name::ServiceName;
+
+/// Identifies the [`Subscriber`] of a [`MergedSubscriber`] a [`Sample`] was received from.
+/// It is returned by [`MergedSubscriber::add()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MergedSubscriberSource(usize);
+
+impl MergedSubscriberSource {
+    /// Returns the position of the [`Subscriber`] in the order it was added to the
+    /// [`MergedSubscriber`].
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Receives the [`Sample`]s of multiple [`Subscriber`]s with the same payload type in one
+/// call, see the [module documentation](crate::port::merged_subscriber).
+#[derive(Debug)]
+pub struct MergedSubscriber<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    subscribers: Vec<Subscriber<Service, Payload, UserHeader>>,
+    next_subscriber: Cell<usize>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Default
+    for MergedSubscriber<Service, Payload, UserHeader>
+{
+    fn default() -> Self {
+        Self {
+            subscribers: vec![],
+            next_subscriber: Cell::new(0),
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    MergedSubscriber<Service, Payload, UserHeader>
+{
+    /// Creates an empty [`MergedSubscriber`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`Subscriber`] and returns the [`MergedSubscriberSource`] that identifies the
+    /// [`Sample`]s that are received from it.
+    pub fn add(
+        &mut self,
+        subscriber: Subscriber<Service, Payload, UserHeader>,
+    ) -> MergedSubscriberSource {
+        self.subscribers.push(subscriber);
+        MergedSubscriberSource(self.subscribers.len() - 1)
+    }
+
+    /// Returns the number of [`Subscriber`]s.
+    pub fn len(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Returns true when no [`Subscriber`] was added.
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+
+    /// Returns the [`Subscriber`] of the [`MergedSubscriberSource`].
+    pub fn subscriber(
+        &self,
+        source: MergedSubscriberSource,
+    ) -> Option<&Subscriber<Service, Payload, UserHeader>> {
+        self.subscribers.get(source.0)
+    }
+
+    /// Returns the [`ServiceName`] of the [`Subscriber`] of the [`MergedSubscriberSource`].
+    pub fn service_name(&self, source: MergedSubscriberSource) -> Option<&ServiceName> {
+        self.subscriber(source)
+            .map(|subscriber| subscriber.service_name())
+    }
+
+    /// Returns true when any [`Subscriber`] has samples that can be received.
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        for subscriber in &self.subscribers {
+            if subscriber.has_samples()? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn receive_impl<
+        T,
+        F: Fn(&Subscriber<Service, Payload, UserHeader>) -> Result<Option<T>, SubscriberReceiveError>,
+    >(
+        &self,
+        receive: F,
+    ) -> Result<Option<(MergedSubscriberSource, T)>, SubscriberReceiveError> {
+        let number_of_subscribers = self.subscribers.len();
+        let start = self.next_subscriber.get();
+
+        for n in 0..number_of_subscribers {
+            let index = (start + n) % number_of_subscribers;
+            if let Some(sample) = receive(&self.subscribers[index])? {
+                self.next_subscriber
+                    .set((index + 1) % number_of_subscribers);
+                return Ok(Some((MergedSubscriberSource(index), sample)));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
+    MergedSubscriber<Service, Payload, UserHeader>
+{
+    /// Receives a [`Sample`] from the next [`Subscriber`] that has one, starting after the
+    /// [`Subscriber`] of the previously received [`Sample`]. Returns [`None`] when no
+    /// [`Subscriber`] has a [`Sample`].
+    pub fn receive(
+        &self,
+    ) -> Result<
+        Option<(MergedSubscriberSource, Sample<Service, Payload, UserHeader>)>,
+        SubscriberReceiveError,
+    > {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
+    MergedSubscriber<Service, [Payload], UserHeader>
+{
+    /// Receives a [`Sample`] from the next [`Subscriber`] that has one, starting after the
+    /// [`Subscriber`] of the previously received [`Sample`]. Returns [`None`] when no
+    /// [`Subscriber`] has a [`Sample`].
+    pub fn receive(
+        &self,
+    ) -> Result<
+        Option<(
+            MergedSubscriberSource,
+            Sample<Service, [Payload], UserHeader>,
+        )>,
+        SubscriberReceiveError,
+    > {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
@@ -16,16 +16,34 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// The generations of the data segments a [`Publisher`](crate::port::publisher::Publisher) adds
+/// when it grows.
+pub mod data_segment_growth;
+/// Calls user-defined callbacks whenever a [`Listener`](crate::port::listener::Listener)
+/// receives an event or a [`Subscriber`](crate::port::subscriber::Subscriber) receives a
+/// [`Sample`](crate::sample::Sample).
+pub mod dispatcher;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
+/// The small inline payload a [`Notifier`](crate::port::notifier::Notifier) can attach to an
+/// event.
+pub mod event_payload;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Receives the [`Sample`](crate::sample::Sample)s of multiple publish-subscribe services with
+/// the same payload type in one call.
+pub mod merged_subscriber;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Receiving endpoint (port) for blackboard based communication
+pub mod reader;
+/// Detects lost [`Sample`](crate::sample::Sample)s with the sequence numbers of the
+/// publishers.
+pub mod sample_gap_detector;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
@@ -34,6 +52,8 @@ pub mod update_connections;
 /// Event handling mechanism to wait on multiple [`Listener`](crate::port::listener::Listener)s
 /// in one call, realizing the reactor pattern. (Event multiplexer)
 pub mod waitset;
+/// Sending endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
 use crate::service;
@@ -61,3 +81,54 @@ impl<'a> Debug for DegrationCallback<'a> {
         write!(f, "")
     }
 }
+
+tiny_fn! {
+    /// Called by a [`Subscriber`](crate::port::subscriber::Subscriber) when the last
+    /// [`Publisher`](crate::port::publisher::Publisher) of the service disconnected. It is
+    /// called with the id of the last [`Publisher`](crate::port::publisher::Publisher).
+    pub struct PublisherDisconnectCallback = Fn(publisher_id: UniquePublisherId);
+}
+
+impl<'a> Debug for PublisherDisconnectCallback<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// Describes a change of the connections of a [`Publisher`](crate::port::publisher::Publisher)
+/// that is reported to the [`SubscriberConnectionCallback`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SubscriberConnectionEvent {
+    /// The connection to the [`Subscriber`](crate::port::subscriber::Subscriber) with the
+    /// provided id was established.
+    Connected(UniqueSubscriberId),
+    /// The [`Subscriber`](crate::port::subscriber::Subscriber) with the provided id
+    /// disconnected.
+    Disconnected(UniqueSubscriberId),
+}
+
+tiny_fn! {
+    /// Called by a [`Publisher`](crate::port::publisher::Publisher) whenever a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) connected or disconnected.
+    pub struct SubscriberConnectionCallback = Fn(event: SubscriberConnectionEvent);
+}
+
+impl<'a> Debug for SubscriberConnectionCallback<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+tiny_fn! {
+    /// Called by a [`Subscriber`](crate::port::subscriber::Subscriber) whenever it discards a
+    /// sample that exceeded the maximum sample age. It is called with the id of the
+    /// [`Publisher`](crate::port::publisher::Publisher) that sent the sample and the age of the
+    /// sample.
+    pub struct ExpiredSampleCallback = Fn(publisher_id: UniquePublisherId, age: core::time::Duration);
+}
+
+impl<'a> Debug for ExpiredSampleCallback<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
@@ -34,22 +34,37 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! See [`event_payload`](crate::port::event_payload) for notifications that carry a payload.
 
-use super::{event_id::EventId, port_identifiers::UniqueListenerId};
+use super::{
+    event_id::{EventId, EventIdFilter},
+    event_payload::{EventPayload, EventPayloadQueue},
+    port_identifiers::UniqueListenerId,
+};
 use crate::{
+    config::Config,
+    node::node_log,
     port::port_identifiers::UniqueNotifierId,
     service::{
         self,
-        config_scheme::event_config,
+        config_scheme::{event_config, event_payload_config},
         dynamic_config::event::{ListenerDetails, NotifierDetails},
-        naming_scheme::event_concept_name,
+        naming_scheme::{
+            event_concept_name, event_payload_name, extract_notifier_id_from_event_payload,
+        },
         ServiceState,
     },
 };
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::context::LogContextGuard;
 use iceoryx2_bb_log::{debug, fail, warn};
-use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
+use iceoryx2_cal::{
+    dynamic_storage::{DynamicStorage, DynamicStorageBuilder},
+    event::NotifierBuilder,
+    named_concept::{NamedConceptListError, NamedConceptMgmt, NamedConceptRemoveError},
+};
 use iceoryx2_cal::{event::Event, named_concept::NamedConceptBuilder};
 use std::{
     cell::UnsafeCell,
@@ -82,6 +97,9 @@ pub enum NotifierNotifyError {
     /// is greater than the maximum supported [`EventId`] by the
     /// [`Service`](crate::service::Service)
     EventIdOutOfBounds,
+    /// A [`Notifier::notify_with_payload()`] was called and the size of the provided payload
+    /// differs from the payload size of the [`Service`](crate::service::Service).
+    IncompatiblePayloadSize,
 }
 
 impl std::fmt::Display for NotifierNotifyError {
@@ -95,7 +113,9 @@ impl std::error::Error for NotifierNotifyError {}
 #[derive(Debug)]
 struct Connection<Service: service::Service> {
     notifier: <Service::Event as Event>::Notifier,
+    payload_queue: Option<Service::EventPayloadStorage>,
     listener_id: UniqueListenerId,
+    event_id_filter: EventIdFilter,
 }
 
 #[derive(Debug)]
@@ -103,13 +123,22 @@ struct ListenerConnections<Service: service::Service> {
     #[allow(clippy::type_complexity)]
     connections: Vec<UnsafeCell<Option<Connection<Service>>>>,
     service_state: Arc<ServiceState<Service>>,
+    notifier_id: UniqueNotifierId,
+    payload_size: usize,
 }
 
 impl<Service: service::Service> ListenerConnections<Service> {
-    fn new(size: usize, service_state: Arc<ServiceState<Service>>) -> Self {
+    fn new(
+        size: usize,
+        service_state: Arc<ServiceState<Service>>,
+        notifier_id: UniqueNotifierId,
+    ) -> Self {
+        let payload_size = service_state.static_config.event().payload_size;
         let mut new_self = Self {
             connections: vec![],
             service_state,
+            notifier_id,
+            payload_size,
         };
 
         new_self.connections.reserve(size);
@@ -120,19 +149,37 @@ impl<Service: service::Service> ListenerConnections<Service> {
         new_self
     }
 
-    fn create(&self, index: usize, listener_id: UniqueListenerId) {
+    /// Enters the log context of the owning node. Only the error paths of
+    /// [`Notifier::notify()`] enter it, so that a notification does not touch the thread local.
+    fn enter_log_context(&self) -> LogContextGuard {
+        self.service_state.shared_node.enter_log_context()
+    }
+
+    fn create(&self, index: usize, details: &ListenerDetails) {
+        let listener_id = details.listener_id;
         let msg = "Unable to establish connection to listener";
         let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(self.service_state.shared_node.config());
+        let event_config = event_config::<Service>(&self.service_state.shared_node.config());
         if self.get(index).is_none() {
             match <Service::Event as iceoryx2_cal::event::Event>::NotifierBuilder::new(&event_name)
                 .config(&event_config)
                 .open()
             {
                 Ok(notifier) => {
+                    let payload_queue = if self.payload_size != 0 {
+                        match self.create_payload_queue(listener_id) {
+                            Some(queue) => Some(queue),
+                            None => return,
+                        }
+                    } else {
+                        None
+                    };
+
                     *self.get_mut(index) = Some(Connection {
                         notifier,
+                        payload_queue,
                         listener_id,
+                        event_id_filter: details.event_id_filter,
                     });
                 }
                 Err(
@@ -140,20 +187,44 @@ impl<Service: service::Service> ListenerConnections<Service> {
                     | iceoryx2_cal::event::NotifierCreateError::InitializationNotYetFinalized,
                 ) => (),
                 Err(iceoryx2_cal::event::NotifierCreateError::VersionMismatch) => {
-                    warn!(from self,
+                    node_log!(warn!(from self,
                         "{} since a version mismatch was detected! All entities must use the same iceoryx2 version!",
-                        msg);
+                        msg));
                 }
                 Err(iceoryx2_cal::event::NotifierCreateError::InsufficientPermissions) => {
-                    warn!(from self, "{} since the permissions do not match. The service or the participants are maybe misconfigured.", msg);
+                    node_log!(
+                        warn!(from self, "{} since the permissions do not match. The service or the participants are maybe misconfigured.", msg)
+                    );
                 }
                 Err(iceoryx2_cal::event::NotifierCreateError::InternalFailure) => {
-                    debug!(from self, "{} due to an internal failure.", msg);
+                    node_log!(debug!(from self, "{} due to an internal failure.", msg));
                 }
             }
         }
     }
 
+    fn create_payload_queue(
+        &self,
+        listener_id: UniqueListenerId,
+    ) -> Option<Service::EventPayloadStorage> {
+        match <Service::EventPayloadStorage as DynamicStorage<EventPayloadQueue>>::Builder::new(
+            &event_payload_name(&self.notifier_id, &listener_id),
+        )
+        .config(&event_payload_config::<Service>(
+            &self.service_state.shared_node.config(),
+        ))
+        .create(EventPayloadQueue::new())
+        {
+            Ok(queue) => Some(queue),
+            Err(e) => {
+                node_log!(
+                    warn!(from self, "Unable to establish connection to listener since the payload queue could not be created ({:?}).", e)
+                );
+                None
+            }
+        }
+    }
+
     fn get(&self, index: usize) -> &Option<Connection<Service>> {
         unsafe { &(*self.connections[index].get()) }
     }
@@ -186,12 +257,13 @@ pub struct Notifier<Service: service::Service> {
 impl<Service: service::Service> Drop for Notifier<Service> {
     fn drop(&mut self) {
         if let Some(handle) = self.dynamic_notifier_handle {
-            self.listener_connections
+            let dynamic_config = self
+                .listener_connections
                 .service_state
                 .dynamic_storage
-                .get()
-                .event()
-                .release_notifier_handle(handle)
+                .get();
+            dynamic_config.event().release_notifier_handle(handle);
+            dynamic_config.statistics().add_disconnected_port();
         }
     }
 }
@@ -203,6 +275,7 @@ impl<Service: service::Service> Notifier<Service> {
     ) -> Result<Self, NotifierCreateError> {
         let msg = "Unable to create Notifier port";
         let origin = "Notifier::new()";
+        let _log_context = service.__internal_state().shared_node.enter_log_context();
         let notifier_id = UniqueNotifierId::new();
 
         let listener_list = &service
@@ -216,6 +289,7 @@ impl<Service: service::Service> Notifier<Service> {
             listener_connections: ListenerConnections::new(
                 listener_list.capacity(),
                 service.__internal_state().clone(),
+                notifier_id,
             ),
             default_event_id,
             listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
@@ -252,10 +326,21 @@ impl<Service: service::Service> Notifier<Service> {
             }
         };
         new_self.dynamic_notifier_handle = Some(dynamic_notifier_handle);
+        service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .add_connected_port();
 
         Ok(new_self)
     }
 
+    /// Enters the log context of the owning node, see [`ListenerConnections::enter_log_context()`].
+    fn enter_log_context(&self) -> LogContextGuard {
+        self.listener_connections.enter_log_context()
+    }
+
     fn update_connections(&self) {
         if unsafe {
             self.listener_connections
@@ -296,7 +381,7 @@ impl<Service: service::Service> Notifier<Service> {
                     };
 
                     if create_connection {
-                        self.listener_connections.create(i, details.listener_id);
+                        self.listener_connections.create(i, details);
                     }
                 }
                 None => self.listener_connections.remove(i),
@@ -319,13 +404,56 @@ impl<Service: service::Service> Notifier<Service> {
     }
 
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
-    /// [`EventId`].
+    /// [`EventId`]. [`crate::port::listener::Listener`]s whose
+    /// [`EventIdFilter`] does not contain the [`EventId`] are not notified.
     /// On success the number of
     /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
     /// [`NotifierNotifyError`].
     pub fn notify_with_custom_event_id(
         &self,
         value: EventId,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(value, None)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with the
+    /// default event id provided on creation and attaches the payload to the notification.
+    /// The size of the payload must be equal to
+    /// [`crate::service::static_config::event::StaticConfig::payload_size()`].
+    /// A [`crate::port::listener::Listener`] that still holds
+    /// [`EVENT_PAYLOAD_QUEUE_CAPACITY`](crate::port::event_payload::EVENT_PAYLOAD_QUEUE_CAPACITY)
+    /// unconsumed payloads of this [`Notifier`] is not notified.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_payload(&self, payload: &[u8]) -> Result<usize, NotifierNotifyError> {
+        self.notify_with_custom_event_id_and_payload(self.default_event_id, payload)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
+    /// [`EventId`] and attaches the payload to the notification. See
+    /// [`Notifier::notify_with_payload()`].
+    pub fn notify_with_custom_event_id_and_payload(
+        &self,
+        value: EventId,
+        payload: &[u8],
+    ) -> Result<usize, NotifierNotifyError> {
+        let payload_size = self.listener_connections.payload_size;
+        if payload.len() != payload_size {
+            node_log!(
+                fail!(from self, with NotifierNotifyError::IncompatiblePayloadSize,
+                    "Unable to notify event since the payload has a size of {} bytes but the service requires a payload size of {} bytes.",
+                    payload.len(), payload_size)
+            );
+        }
+
+        self.notify_impl(value, Some(EventPayload::new(value, payload)))
+    }
+
+    fn notify_impl(
+        &self,
+        value: EventId,
+        payload: Option<EventPayload>,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
         self.update_connections();
@@ -334,20 +462,38 @@ impl<Service: service::Service> Notifier<Service> {
         let mut number_of_triggered_listeners = 0;
 
         if self.event_id_max_value < value.as_value() {
-            fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
+            node_log!(
+                fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
                             "{} since the EventId {:?} exceeds the maximum supported EventId value of {}.",
-                            msg, value, self.event_id_max_value);
+                            msg, value, self.event_id_max_value)
+            );
         }
 
         for i in 0..self.listener_connections.len() {
             if let Some(ref connection) = self.listener_connections.get(i) {
+                if !connection.event_id_filter.contains(value) {
+                    continue;
+                }
+
+                if let (Some(payload), Some(queue)) = (&payload, &connection.payload_queue) {
+                    if !unsafe { queue.get().push(payload) } {
+                        node_log!(
+                            warn!(from self, "Unable to send notification via connection {:?} since the payload queue is full.",
+                                connection)
+                        );
+                        continue;
+                    }
+                }
+
                 match connection.notifier.notify(value) {
                     Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                         self.listener_connections.remove(i);
                     }
                     Err(e) => {
-                        warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
-                        connection, e)
+                        node_log!(
+                            warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
+                                connection, e)
+                        )
                     }
                     Ok(_) => {
                         number_of_triggered_listeners += 1;
@@ -356,6 +502,58 @@ impl<Service: service::Service> Notifier<Service> {
             }
         }
 
+        let dynamic_config = self
+            .listener_connections
+            .service_state
+            .dynamic_storage
+            .get();
+        if let Some(deadline) = self
+            .listener_connections
+            .service_state
+            .static_config
+            .event()
+            .deadline
+        {
+            dynamic_config.event().record_notification(deadline);
+        }
+        dynamic_config.statistics().add_sent_notification();
+
         Ok(number_of_triggered_listeners)
     }
 }
+
+pub(crate) unsafe fn remove_event_payload_queues_of_notifier<Service: service::Service>(
+    notifier_id: &UniqueNotifierId,
+    config: &Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_event_payload_queues_of_notifier::<{}>({:?})",
+        core::any::type_name::<Service>(),
+        notifier_id
+    );
+    let msg = "Unable to remove the event payload queues of the notifier";
+    let payload_config = event_payload_config::<Service>(config);
+
+    let payload_queues =
+        match <Service::EventPayloadStorage as NamedConceptMgmt>::list_cfg(&payload_config) {
+            Ok(list) => list,
+            Err(NamedConceptListError::InsufficientPermissions) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                    "{} due to insufficient permissions to list all event payload queues.", msg);
+            }
+            Err(NamedConceptListError::InternalError) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} due to an internal error while listing all event payload queues.", msg);
+            }
+        };
+
+    for payload_queue in payload_queues {
+        if extract_notifier_id_from_event_payload(&payload_queue) == *notifier_id {
+            fail!(from origin,
+                when <Service::EventPayloadStorage as NamedConceptMgmt>::remove_cfg(&payload_queue, &payload_config),
+                "{} since the event payload queue {} could not be removed.", msg, payload_queue);
+        }
+    }
+
+    Ok(())
+}
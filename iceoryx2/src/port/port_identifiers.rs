@@ -55,6 +55,14 @@ generate_id! {
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     UniqueListenerId
 }
+generate_id! {
+    /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
+    UniqueWriterId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Reader`](crate::port::reader::Reader).
+    UniqueReaderId
+}
 
 /// Enum that contains the unique port id
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,4 +75,8 @@ pub enum UniquePortId {
     Notifier(UniqueNotifierId),
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     Listener(UniqueListenerId),
+    /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
+    Writer(UniqueWriterId),
+    /// The system-wide unique id of a [`Reader`](crate::port::reader::Reader).
+    Reader(UniqueReaderId),
 }
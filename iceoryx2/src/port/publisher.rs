@@ -103,19 +103,28 @@
 
 use super::port_identifiers::UniquePublisherId;
 use super::UniqueSubscriberId;
+use crate::node::node_log;
+use crate::port::data_segment_growth::MAX_NUMBER_OF_GROWTH_SEGMENTS;
+use crate::port::details::data_segment::{memory_pool_layouts, DataSegmentMemory, GrowthLayout};
 use crate::port::details::subscriber_connections::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
-use crate::port::DegrationAction;
+use crate::port::{DegrationAction, SubscriberConnectionEvent};
 use crate::raw_sample::RawSampleMut;
 use crate::sample_mut_uninit::SampleMutUninit;
-use crate::service::builder::publish_subscribe::CustomPayloadMarker;
-use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+use crate::service::config_scheme::{
+    connection_config, data_segment_config, data_segment_growth_config,
+};
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::{
-    data_segment_name, extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
+    data_segment_growth_storage_name, data_segment_name, extract_publisher_id_from_connection,
+    extract_subscriber_id_from_connection, is_data_segment_growth_of,
+};
+use crate::service::port_factory::publisher::{
+    AllocationStrategy, LocalPublisherConfig, PublisherPriority, UnableToDeliverStrategy,
 };
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
+use crate::service::port_factory::subscriber::SubscriberOverflowStrategy;
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe::{self};
 use crate::service::{self, ServiceState};
@@ -124,29 +133,31 @@ use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::context::LogContextGuard;
 use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::nanosleep;
+use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::NamedConceptMgmt;
-use iceoryx2_cal::named_concept::{
-    NamedConceptBuilder, NamedConceptListError, NamedConceptRemoveError,
-};
-use iceoryx2_cal::shared_memory::{
-    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
-};
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
-use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
+    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySendError,
+    ZeroCopySender,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 use std::any::TypeId;
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell, UnsafeCell};
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 
+const DRAIN_POLLING_INTERVAL: Duration = Duration::from_millis(1);
+
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -181,7 +192,8 @@ pub enum PublisherLoanError {
     /// The provided slice size exceeds the configured max slice size of the [`Publisher`].
     /// To send a [`SampleMut`] with this size a new [`Publisher`] has to be created with
     /// a [`crate::service::port_factory::publisher::PortFactoryPublisher::max_slice_len()`]
-    /// greater or equal to the required len.
+    /// greater or equal to the required len or with an
+    /// [`AllocationStrategy`] that allows the [`Publisher`] to grow.
     ExceedsMaxLoanSize,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
@@ -209,6 +221,9 @@ pub enum PublisherSendError {
     /// A failure occurred while establishing a connection to a
     /// [`Subscriber`](crate::port::subscriber::Subscriber)
     ConnectionError(ConnectionFailure),
+    /// The user header or payload bytes provided to [`Publisher::send_raw_copy()`] do not match
+    /// the type details of the [`Service`](crate::service::Service).
+    IncompatibleRawSampleSize,
 }
 
 impl From<PublisherLoanError> for PublisherSendError {
@@ -231,6 +246,220 @@ impl std::fmt::Display for PublisherSendError {
 
 impl std::error::Error for PublisherSendError {}
 
+/// Defines a failure that can occur when the maximum number of loaned samples of a
+/// [`Publisher`] is changed with [`Publisher::set_max_loaned_samples()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum PublisherSetMaxLoanedSamplesError {
+    /// The value exceeds the ceiling the data segment of the [`Publisher`] was sized for, see
+    /// [`crate::service::builder::publish_subscribe::Builder::publisher_max_loaned_samples_ceiling()`].
+    ExceedsMaxLoanedSamplesCeiling,
+    /// The value is zero, a [`Publisher`] must be able to loan at least one sample.
+    MaxLoanedSamplesIsZero,
+}
+
+impl std::fmt::Display for PublisherSetMaxLoanedSamplesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "PublisherSetMaxLoanedSamplesError::{:?}", self)
+    }
+}
+
+impl std::error::Error for PublisherSetMaxLoanedSamplesError {}
+
+/// Defines how [`Publisher::close()`] handles the [`crate::sample::Sample`]s that were delivered
+/// to the [`Subscriber`](crate::port::subscriber::Subscriber)s but not yet received.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum DrainMode {
+    /// The [`Publisher`] is closed immediately. The
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s can still receive the delivered
+    /// [`crate::sample::Sample`]s until they are discarded with
+    /// [`Subscriber::close()`](crate::port::subscriber::Subscriber::close()) or the
+    /// `subscriber-expired-connection-buffer` is exceeded.
+    Detach,
+    /// The [`Publisher`] waits up to the provided timeout until every
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) has received all delivered
+    /// [`crate::sample::Sample`]s before it is closed.
+    WaitForDelivery(Duration),
+}
+
+/// Defines a failure that can occur in [`Publisher::close()`]. The [`Publisher`] is closed in
+/// any case.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum PublisherCloseError {
+    /// Not all delivered [`crate::sample::Sample`]s were received within the timeout of
+    /// [`DrainMode::WaitForDelivery`].
+    DeliveryTimeout,
+    /// A termination signal was received while waiting for the delivery.
+    InterruptSignal,
+}
+
+impl std::fmt::Display for PublisherCloseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "PublisherCloseError::{:?}", self)
+    }
+}
+
+impl std::error::Error for PublisherCloseError {}
+
+/// Runtime statistics of the data segment of a [`Publisher`], acquired with
+/// [`Publisher::loan_statistics()`]. They can be used to dimension
+/// [`crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples()`]
+/// and the buffer sizes of the [`Service`](crate::service::Service) based on the actual
+/// usage instead of guessing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublisherLoanStatistics {
+    number_of_loaned_samples: usize,
+    max_loaned_samples: usize,
+    loaned_samples_high_watermark: usize,
+    number_of_chunks: usize,
+    number_of_used_chunks: usize,
+    used_chunks_high_watermark: usize,
+    number_of_allocation_failures: u64,
+}
+
+impl PublisherLoanStatistics {
+    /// Returns the number of [`SampleMut`]s that are currently loaned by the user.
+    pub fn number_of_loaned_samples(&self) -> usize {
+        self.number_of_loaned_samples
+    }
+
+    /// Returns the maximum number of [`SampleMut`]s that can be loaned in parallel.
+    pub fn max_loaned_samples(&self) -> usize {
+        self.max_loaned_samples
+    }
+
+    /// Returns the maximum number of [`SampleMut`]s that were loaned in parallel since the
+    /// [`Publisher`] was created.
+    pub fn loaned_samples_high_watermark(&self) -> usize {
+        self.loaned_samples_high_watermark
+    }
+
+    /// Returns the total number of chunks in all memory pools of the data segment.
+    pub fn number_of_chunks(&self) -> usize {
+        self.number_of_chunks
+    }
+
+    /// Returns the number of chunks that are currently in use, either loaned by the user,
+    /// stored in the history or held by a [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn number_of_used_chunks(&self) -> usize {
+        self.number_of_used_chunks
+    }
+
+    /// Returns the number of chunks that are currently free.
+    pub fn number_of_free_chunks(&self) -> usize {
+        self.number_of_chunks - self.number_of_used_chunks
+    }
+
+    /// Returns the maximum number of chunks that were in use at the same time since the
+    /// [`Publisher`] was created.
+    pub fn used_chunks_high_watermark(&self) -> usize {
+        self.used_chunks_high_watermark
+    }
+
+    /// Returns how often a loan failed since the [`Publisher`] was created, either since
+    /// [`PublisherLoanError::ExceedsMaxLoanedSamples`] or since the data segment was out of
+    /// memory.
+    pub fn number_of_allocation_failures(&self) -> u64 {
+        self.number_of_allocation_failures
+    }
+}
+
+/// The usage of a single memory pool of the data segment of a [`Publisher`], see
+/// [`DataSegmentStatistics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryPoolStatistics {
+    pub(crate) bucket_size: usize,
+    pub(crate) number_of_buckets: usize,
+    pub(crate) number_of_used_buckets: usize,
+    pub(crate) uses_huge_pages: bool,
+}
+
+impl MemoryPoolStatistics {
+    /// Returns the size in bytes of a bucket, the largest sample including its headers that
+    /// can be stored in the memory pool.
+    pub fn bucket_size(&self) -> usize {
+        self.bucket_size
+    }
+
+    /// Returns the total number of buckets of the memory pool.
+    pub fn number_of_buckets(&self) -> usize {
+        self.number_of_buckets
+    }
+
+    /// Returns the number of buckets that are currently in use.
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.number_of_used_buckets
+    }
+
+    /// Returns the number of buckets that are currently free.
+    pub fn number_of_free_buckets(&self) -> usize {
+        self.number_of_buckets - self.number_of_used_buckets
+    }
+
+    /// Returns true when the memory pool is currently mapped with huge pages, see
+    /// [`crate::service::builder::publish_subscribe::Builder::huge_pages()`].
+    pub fn uses_huge_pages(&self) -> bool {
+        self.uses_huge_pages
+    }
+}
+
+/// The allocator statistics of the data segment of a [`Publisher`], acquired with
+/// [`Publisher::data_segment_statistics()`]. They show which memory pools run out of buckets
+/// and how much of the data segment is actually used, so that the memory pools and the
+/// [`AllocationStrategy`] can be tuned before a loan fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSegmentStatistics {
+    memory_pools: Vec<MemoryPoolStatistics>,
+}
+
+impl DataSegmentStatistics {
+    /// Returns the [`MemoryPoolStatistics`] of all memory pools, ordered from the smallest to
+    /// the largest bucket, followed by the memory pools that were added when the data segment
+    /// grew.
+    pub fn memory_pools(&self) -> &[MemoryPoolStatistics] {
+        &self.memory_pools
+    }
+
+    /// Returns the number of free buckets in all memory pools.
+    pub fn number_of_free_buckets(&self) -> usize {
+        self.memory_pools
+            .iter()
+            .map(|pool| pool.number_of_free_buckets())
+            .sum()
+    }
+
+    /// Returns the size of the largest free bucket and therefore the size of the largest
+    /// sample that can currently be loaned without growing the data segment. Returns 0 when
+    /// all buckets are in use.
+    pub fn largest_free_bucket_size(&self) -> usize {
+        self.memory_pools
+            .iter()
+            .filter(|pool| pool.number_of_free_buckets() != 0)
+            .map(|pool| pool.bucket_size())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the ratio of the bytes in used buckets to the bytes of all buckets, a value
+    /// between 0.0 and 1.0.
+    pub fn utilization(&self) -> f64 {
+        let (used, total) = self
+            .memory_pools
+            .iter()
+            .fold((0, 0), |(used, total), pool| {
+                (
+                    used + pool.number_of_used_buckets() * pool.bucket_size(),
+                    total + pool.number_of_buckets() * pool.bucket_size(),
+                )
+            });
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        used as f64 / total as f64
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub(crate) enum RemovePubSubPortFromAllConnectionsError {
     InsufficientPermissions,
@@ -240,9 +469,9 @@ pub(crate) enum RemovePubSubPortFromAllConnectionsError {
 #[derive(Debug)]
 pub(crate) struct DataSegment<Service: service::Service> {
     sample_reference_counter: Vec<IoxAtomicU64>,
-    memory: Service::SharedMemory,
-    payload_size: usize,
+    memory: DataSegmentMemory<Service>,
     payload_type_layout: Layout,
+    max_slice_len: IoxAtomicUsize,
     port_id: UniquePublisherId,
     config: LocalPublisherConfig,
     service_state: Arc<ServiceState<Service>>,
@@ -252,12 +481,31 @@ pub(crate) struct DataSegment<Service: service::Service> {
     history: Option<UnsafeCell<Queue<usize>>>,
     static_config: crate::service::static_config::StaticConfig,
     loan_counter: IoxAtomicUsize,
+    max_loaned_samples: IoxAtomicUsize,
+    max_loaned_samples_ceiling: usize,
+    loan_high_watermark: IoxAtomicUsize,
+    used_chunks: IoxAtomicUsize,
+    used_chunks_high_watermark: IoxAtomicUsize,
+    allocation_failures: IoxAtomicU64,
+    sequence_number: IoxAtomicU64,
     is_active: IoxAtomicBool,
 }
 
 impl<Service: service::Service> DataSegment<Service> {
+    /// Attaches the [`NodeName`](crate::node::NodeName) and [`NodeId`](crate::node::NodeId)
+    /// to the log messages of the current thread. Sending and loaning are on the hot path,
+    /// therefore it is entered only when a message is logged.
+    pub(crate) fn enter_log_context(&self) -> LogContextGuard {
+        self.service_state.shared_node.enter_log_context()
+    }
+
     fn sample_index(&self, distance_to_chunk: usize) -> usize {
-        distance_to_chunk / self.payload_size
+        distance_to_chunk / self.memory.chunk_size()
+    }
+
+    /// Returns the sequence number of the next sample that is sent.
+    pub(crate) fn next_sequence_number(&self) -> u64 {
+        self.sequence_number.fetch_add(1, Ordering::Relaxed)
     }
 
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
@@ -269,13 +517,64 @@ impl<Service: service::Service> DataSegment<Service> {
             .fetch_add(1, Ordering::Relaxed)
             != 0
         {
-            fatal_panic!(from self,
-                "{} since the allocated sample is already in use! This should never happen!", msg);
+            node_log!(fatal_panic!(from self,
+                "{} since the allocated sample is already in use! This should never happen!", msg));
         }
 
+        let used_chunks = self.used_chunks.fetch_add(1, Ordering::Relaxed) + 1;
+        self.used_chunks_high_watermark
+            .fetch_max(used_chunks, Ordering::Relaxed);
+
         Ok(ptr)
     }
 
+    fn grow(&self, slice_len: usize) -> Result<(), PublisherLoanError> {
+        let msg = "Unable to loan slice";
+        // releases the added data segments that became empty so that their slots can be reused
+        self.retrieve_returned_samples();
+        let max_slice_len = self.max_slice_len.load(Ordering::Relaxed);
+        let new_max_slice_len = match self.config.allocation_strategy {
+            AllocationStrategy::Static => {
+                node_log!(
+                    fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                        "{} with {} elements since it would exceed the max supported slice length of {}.",
+                        msg, slice_len, max_slice_len)
+                );
+            }
+            AllocationStrategy::BestFit => slice_len,
+            AllocationStrategy::PowerOfTwo => slice_len.next_power_of_two(),
+        };
+
+        let layout = self
+            .subscriber_connections
+            .static_config
+            .message_type_details
+            .sample_layout(new_max_slice_len);
+        match self.memory.grow(layout) {
+            Ok(true) => {
+                node_log!(
+                    debug!(from self, "Increased the max supported slice length from {} to {}.",
+                        max_slice_len, new_max_slice_len)
+                );
+                self.max_slice_len
+                    .store(new_max_slice_len, Ordering::Relaxed);
+                Ok(())
+            }
+            Ok(false) => {
+                node_log!(
+                    fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                        "{} with {} elements since it would exceed the max supported slice length of {} and the data segment cannot grow any further.",
+                        msg, slice_len, max_slice_len)
+                );
+            }
+            Err(e) => {
+                node_log!(fail!(from self, with PublisherLoanError::InternalFailure,
+                    "{} with {} elements since the data segment could not grow ({:?}).",
+                    msg, slice_len, e));
+            }
+        }
+    }
+
     fn borrow_sample(&self, distance_to_chunk: usize) {
         self.sample_reference_counter[self.sample_index(distance_to_chunk)]
             .fetch_add(1, Ordering::Relaxed);
@@ -286,10 +585,16 @@ impl<Service: service::Service> DataSegment<Service> {
             .fetch_sub(1, Ordering::Relaxed)
             == 1
         {
-            unsafe {
+            if let Err(e) = unsafe {
                 self.memory
-                    .deallocate(distance_to_chunk, self.payload_type_layout);
+                    .deallocate(distance_to_chunk, self.payload_type_layout)
+            } {
+                node_log!(
+                    warn!(from self, "Unable to return the sample with the offset {} to its data segment ({:?}). The sample is lost.",
+                        distance_to_chunk.value(), e)
+                );
             }
+            self.used_chunks.fetch_sub(1, Ordering::Relaxed);
         }
     }
 
@@ -303,7 +608,9 @@ impl<Service: service::Service> DataSegment<Service> {
                         }
                         Ok(None) => break,
                         Err(e) => {
-                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                            node_log!(
+                                warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                            )
                         }
                     }
                 }
@@ -311,6 +618,13 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    fn number_of_undelivered_samples(&self) -> usize {
+        (0..self.subscriber_connections.len())
+            .filter_map(|i| self.subscriber_connections.get(i))
+            .map(|connection| connection.sender.number_of_buffered_samples())
+            .sum()
+    }
+
     fn remove_connection(&self, i: usize) {
         if let Some(connection) = self.subscriber_connections.get(i) {
             // # SAFETY: the receiver no longer exist, therefore we can
@@ -321,7 +635,12 @@ impl<Service: service::Service> DataSegment<Service> {
                     .acquire_used_offsets(|offset| self.release_sample(offset))
             };
 
+            let subscriber_id = connection.subscriber_id;
             self.subscriber_connections.remove(i);
+
+            if let Some(callback) = &self.config.subscriber_connection_callback {
+                callback.call(SubscriberConnectionEvent::Disconnected(subscriber_id));
+            }
         }
     }
 
@@ -347,7 +666,7 @@ impl<Service: service::Service> DataSegment<Service> {
     fn deliver_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
         self.retrieve_returned_samples();
 
-        let deliver_call = match self.config.unable_to_deliver_strategy {
+        let default_deliver_call = match self.config.unable_to_deliver_strategy {
             UnableToDeliverStrategy::Block => {
                 <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
             }
@@ -359,6 +678,18 @@ impl<Service: service::Service> DataSegment<Service> {
         let mut number_of_recipients = 0;
         for i in 0..self.subscriber_connections.len() {
             if let Some(ref connection) = self.subscriber_connections.get(i) {
+                // the subscriber can override the overflow behavior of the service
+                let deliver_call = match connection.overflow_strategy {
+                    Some(SubscriberOverflowStrategy::BlockPublisher) => {
+                        <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
+                    }
+                    Some(SubscriberOverflowStrategy::DiscardOldest)
+                    | Some(SubscriberOverflowStrategy::DiscardNewest) => {
+                        <Service::Connection as ZeroCopyConnection>::Sender::try_send
+                    }
+                    None => default_deliver_call,
+                };
+
                 match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
                     Err(ZeroCopySendError::ReceiveBufferFull)
                     | Err(ZeroCopySendError::UsedChunkListFull) => {
@@ -366,6 +697,7 @@ impl<Service: service::Service> DataSegment<Service> {
                          *   blocking_send => can never happen
                          *   try_send => we tried and expect that the buffer is full
                          * */
+                        self.add_dropped_sample_to_statistics();
                     }
                     Err(ZeroCopySendError::ConnectionCorrupted) => {
                         match &self.config.degration_callback {
@@ -376,29 +708,33 @@ impl<Service: service::Service> DataSegment<Service> {
                             ) {
                                 DegrationAction::Ignore => (),
                                 DegrationAction::Warn => {
-                                    error!(from self,
+                                    node_log!(error!(from self,
                                         "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        address_to_chunk, connection.subscriber_id);
+                                        address_to_chunk, connection.subscriber_id));
                                 }
                                 DegrationAction::Fail => {
-                                    fail!(from self, with PublisherSendError::ConnectionCorrupted,
-                                        "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        address_to_chunk, connection.subscriber_id);
+                                    node_log!(
+                                        fail!(from self, with PublisherSendError::ConnectionCorrupted,
+                                            "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                            address_to_chunk, connection.subscriber_id)
+                                    );
                                 }
                             },
                             None => {
-                                error!(from self,
+                                node_log!(error!(from self,
                                     "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                    address_to_chunk, connection.subscriber_id);
+                                    address_to_chunk, connection.subscriber_id));
                             }
                         }
                     }
                     Ok(overflow) => {
                         self.borrow_sample(address_to_chunk);
                         number_of_recipients += 1;
+                        connection.notify_delivery();
 
                         if let Some(old) = overflow {
-                            self.release_sample(old)
+                            self.release_sample(old);
+                            self.add_dropped_sample_to_statistics();
                         }
                     }
                 }
@@ -434,15 +770,22 @@ impl<Service: service::Service> DataSegment<Service> {
                     };
 
                     if create_connection {
-                        match self.subscriber_connections.create(
-                            i,
-                            *subscriber_details,
-                            self.config.max_slice_len,
-                        ) {
+                        match self.subscriber_connections.create(i, *subscriber_details) {
                             Ok(()) => match &self.subscriber_connections.get(i) {
-                                Some(connection) => self.deliver_sample_history(connection),
+                                Some(connection) => {
+                                    if let Some(callback) =
+                                        &self.config.subscriber_connection_callback
+                                    {
+                                        callback.call(SubscriberConnectionEvent::Connected(
+                                            subscriber_details.subscriber_id,
+                                        ));
+                                    }
+                                    self.deliver_sample_history(connection)
+                                }
                                 None => {
-                                    fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
+                                    node_log!(
+                                        fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
+                                    )
                                 }
                             },
                             Err(e) => match &self.config.degration_callback {
@@ -453,20 +796,20 @@ impl<Service: service::Service> DataSegment<Service> {
                                 ) {
                                     DegrationAction::Ignore => (),
                                     DegrationAction::Warn => {
-                                        warn!(from self,
+                                        node_log!(warn!(from self,
                                             "Unable to establish connection to new subscriber {:?}.",
-                                            subscriber_details.subscriber_id )
+                                            subscriber_details.subscriber_id ))
                                     }
                                     DegrationAction::Fail => {
-                                        fail!(from self, with e,
+                                        node_log!(fail!(from self, with e,
                                            "Unable to establish connection to new subscriber {:?}.",
-                                           subscriber_details.subscriber_id );
+                                           subscriber_details.subscriber_id ));
                                     }
                                 },
                                 None => {
-                                    warn!(from self,
+                                    node_log!(warn!(from self,
                                         "Unable to establish connection to new subscriber {:?}.",
-                                        subscriber_details.subscriber_id )
+                                        subscriber_details.subscriber_id ))
                                 }
                             },
                         }
@@ -488,8 +831,10 @@ impl<Service: service::Service> DataSegment<Service> {
                 .subscribers
                 .update_state(&mut *self.subscriber_list_state.get())
         } {
-            fail!(from self, when self.populate_subscriber_channels(),
-                "Connections were updated only partially since at least one connection to a Subscriber port failed.");
+            if let Err(e) = self.populate_subscriber_channels() {
+                node_log!(fail!(from self, with e.into(),
+                    "Connections were updated only partially since at least one connection to a Subscriber port failed."));
+            }
         }
 
         Ok(())
@@ -504,9 +849,14 @@ impl<Service: service::Service> DataSegment<Service> {
                     let ptr_distance = unsafe { history.get_unchecked(i) };
 
                     match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => self.borrow_sample(ptr_distance),
+                        Ok(_) => {
+                            self.borrow_sample(ptr_distance);
+                            connection.notify_delivery();
+                        }
                         Err(e) => {
-                            warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
+                            node_log!(
+                                warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e)
+                            );
                         }
                     }
                 }
@@ -514,18 +864,52 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
-    pub(crate) fn send_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
+    pub(crate) fn send_sample(
+        &self,
+        address_to_chunk: usize,
+        number_of_elements: usize,
+    ) -> Result<usize, PublisherSendError> {
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
-            fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
-                "{} since the connections could not be updated.", msg);
+            node_log!(
+                fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
+                    "{} since the connections could not be updated.", msg)
+            );
         }
 
-        fail!(from self, when self.update_connections(),
-            "{} since the connections could not be updated.", msg);
+        if let Err(e) = self.update_connections() {
+            node_log!(fail!(from self, with e.into(),
+                "{} since the connections could not be updated.", msg));
+        }
 
         self.add_sample_to_history(address_to_chunk);
-        self.deliver_sample(address_to_chunk)
+        let number_of_recipients = self.deliver_sample(address_to_chunk)?;
+
+        if self.config.statistics {
+            let number_of_bytes = self
+                .subscriber_connections
+                .static_config
+                .message_type_details
+                .payload_layout(number_of_elements)
+                .size();
+            self.service_state
+                .dynamic_storage
+                .get()
+                .statistics()
+                .add_published_sample(number_of_bytes as u64);
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    fn add_dropped_sample_to_statistics(&self) {
+        if self.config.statistics {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .statistics()
+                .add_dropped_sample();
+        }
     }
 }
 
@@ -539,6 +923,8 @@ pub struct Publisher<
     pub(crate) data_segment: Arc<DataSegment<Service>>,
     dynamic_publisher_handle: Option<ContainerHandle>,
     payload_size: usize,
+    last_sent_payload: RefCell<Option<Box<Payload>>>,
+    last_sent_hash: Cell<Option<u64>>,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -548,12 +934,11 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
 {
     fn drop(&mut self) {
         if let Some(handle) = self.dynamic_publisher_handle {
-            self.data_segment
-                .service_state
-                .dynamic_storage
-                .get()
+            let dynamic_config = self.data_segment.service_state.dynamic_storage.get();
+            dynamic_config
                 .publish_subscribe()
-                .release_publisher_handle(handle)
+                .release_publisher_handle(handle);
+            dynamic_config.statistics().add_disconnected_port();
         }
     }
 }
@@ -568,6 +953,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
+        let _log_context = service.__internal_state().shared_node.enter_log_context();
         let port_id = UniquePublisherId::new();
         let subscriber_list = &service
             .__internal_state()
@@ -576,31 +962,60 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .publish_subscribe()
             .subscribers;
 
+        let max_loaned_samples = config.max_loaned_samples;
+        let max_loaned_samples_ceiling =
+            max_loaned_samples.max(static_config.publisher_max_loaned_samples_ceiling);
         let number_of_samples = service
             .__internal_state()
             .static_config
             .messaging_pattern
-            .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+            .required_amount_of_samples_per_data_segment(max_loaned_samples_ceiling);
+
+        let memory_pools = memory_pool_layouts(
+            static_config.message_type_details(),
+            config.max_slice_len,
+            number_of_samples,
+            &config.memory_pools,
+        );
+
+        let growth = match config.allocation_strategy {
+            AllocationStrategy::Static => GrowthLayout::default(),
+            AllocationStrategy::BestFit | AllocationStrategy::PowerOfTwo => GrowthLayout {
+                max_number_of_segments: MAX_NUMBER_OF_GROWTH_SEGMENTS,
+                number_of_samples,
+            },
+        };
+
+        let global_config = &service.__internal_state().shared_node.config();
+        let memory_lock = config.memory_lock
+            || global_config
+                .defaults
+                .publish_subscribe
+                .publisher_memory_lock;
+        let prefault_memory = config.prefault_memory
+            || global_config
+                .defaults
+                .publish_subscribe
+                .publisher_prefault_memory;
 
         let data_segment = fail!(from origin,
-                when Self::create_data_segment(&port_id, service.__internal_state().shared_node.config(), number_of_samples, static_config, &config),
+                when DataSegmentMemory::create(&port_id, global_config, &memory_pools, growth, memory_lock, prefault_memory, static_config.huge_pages),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
-        let max_slice_len = config.max_slice_len;
+        let chunk_size = data_segment.chunk_size();
+        let number_of_chunks = data_segment.number_of_chunks();
+        let number_of_data_segments = data_segment.number_of_segments();
         let data_segment = Arc::new(DataSegment {
             is_active: IoxAtomicBool::new(true),
             memory: data_segment,
-            payload_size: static_config
-                .message_type_details()
-                .sample_layout(config.max_slice_len)
-                .size(),
             payload_type_layout: static_config
                 .message_type_details()
                 .payload_layout(config.max_slice_len),
+            max_slice_len: IoxAtomicUsize::new(config.max_slice_len),
             sample_reference_counter: {
-                let mut v = Vec::with_capacity(number_of_samples);
-                for _ in 0..number_of_samples {
+                let mut v = Vec::with_capacity(number_of_chunks);
+                for _ in 0..number_of_chunks {
                     v.push(IoxAtomicU64::new(0));
                 }
                 v
@@ -612,7 +1027,8 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 service.__internal_state().shared_node.clone(),
                 port_id,
                 static_config,
-                number_of_samples,
+                number_of_chunks,
+                chunk_size,
             ),
             config,
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
@@ -622,6 +1038,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             },
             static_config: service.__internal_state().static_config.clone(),
             loan_counter: IoxAtomicUsize::new(0),
+            max_loaned_samples: IoxAtomicUsize::new(max_loaned_samples),
+            max_loaned_samples_ceiling,
+            loan_high_watermark: IoxAtomicUsize::new(0),
+            used_chunks: IoxAtomicUsize::new(0),
+            used_chunks_high_watermark: IoxAtomicUsize::new(0),
+            allocation_failures: IoxAtomicU64::new(0),
+            sequence_number: IoxAtomicU64::new(0),
         });
 
         let payload_size = data_segment
@@ -635,6 +1058,8 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             data_segment,
             dynamic_publisher_handle: None,
             payload_size,
+            last_sent_payload: RefCell::new(None),
+            last_sent_hash: Cell::new(None),
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -654,45 +1079,32 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .publish_subscribe()
             .add_publisher_id(PublisherDetails {
                 publisher_id: port_id,
-                number_of_samples,
-                max_slice_len,
+                number_of_samples: number_of_chunks,
+                chunk_size,
+                number_of_data_segments,
+                growth,
                 node_id: *service.__internal_state().shared_node.id(),
+                priority: new_self.data_segment.config.priority,
             }) {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
                             "{} since it would exceed the maximum supported amount of publishers of {}.",
-                            msg, service.__internal_state().static_config.publish_subscribe().max_publishers);
+                            msg, service.__internal_state().dynamic_storage.get().publish_subscribe().max_publishers());
             }
         };
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .add_connected_port();
 
         Ok(new_self)
     }
 
-    fn create_data_segment(
-        port_id: &UniquePublisherId,
-        global_config: &config::Config,
-        number_of_samples: usize,
-        static_config: &publish_subscribe::StaticConfig,
-        config: &LocalPublisherConfig,
-    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
-        let l = static_config
-            .message_type_details
-            .sample_layout(config.max_slice_len);
-        let allocator_config = shm_allocator::pool_allocator::Config { bucket_layout: l };
-
-        Ok(fail!(from "Publisher::create_data_segment()",
-            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-            Service::SharedMemory,
-                >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
-                .size(l.size() * number_of_samples + l.align() - 1)
-                .create(&allocator_config),
-            "Unable to create the data segment."))
-    }
-
     /// Returns the [`UniquePublisherId`] of the [`Publisher`]
     pub fn id(&self) -> UniquePublisherId {
         self.data_segment.port_id
@@ -704,40 +1116,229 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.data_segment.config.unable_to_deliver_strategy
     }
 
+    /// Returns the [`PublisherPriority`] of the [`Publisher`].
+    pub fn priority(&self) -> PublisherPriority {
+        self.data_segment.config.priority
+    }
+
     /// Returns the maximum slice length configured for this [`Publisher`].
     pub fn max_slice_len(&self) -> usize {
-        self.data_segment.config.max_slice_len
+        self.data_segment.max_slice_len.load(Ordering::Relaxed)
+    }
+
+    /// Returns the maximum number of [`SampleMut`]s that can be loaned in parallel.
+    pub fn max_loaned_samples(&self) -> usize {
+        self.data_segment.max_loaned_samples.load(Ordering::Relaxed)
+    }
+
+    /// Changes the maximum number of [`SampleMut`]s that can be loaned in parallel without
+    /// recreating the [`Publisher`]. The value can be raised up to the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::publisher_max_loaned_samples_ceiling()`]
+    /// of the [`Service`](crate::service::Service) or the initially configured
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples()`],
+    /// whichever is greater. Lowering the value below the number of currently loaned samples
+    /// does not affect them, only new loans fail until enough samples were released. A value
+    /// of zero is rejected with [`PublisherSetMaxLoanedSamplesError::MaxLoanedSamplesIsZero`].
+    pub fn set_max_loaned_samples(
+        &self,
+        value: usize,
+    ) -> Result<(), PublisherSetMaxLoanedSamplesError> {
+        if value == 0 {
+            node_log!(
+                fail!(from self, with PublisherSetMaxLoanedSamplesError::MaxLoanedSamplesIsZero,
+                    "Unable to set the maximum number of loaned samples to 0 since at least one sample must be loanable.")
+            );
+        }
+
+        let ceiling = self.data_segment.max_loaned_samples_ceiling;
+        if ceiling < value {
+            node_log!(
+                fail!(from self, with PublisherSetMaxLoanedSamplesError::ExceedsMaxLoanedSamplesCeiling,
+                    "Unable to set the maximum number of loaned samples to {} since it exceeds the ceiling of {}.",
+                    value, ceiling)
+            );
+        }
+
+        self.data_segment
+            .max_loaned_samples
+            .store(value, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Returns the [`PublisherLoanStatistics`] of the [`Publisher`]s data segment. Samples
+    /// that were already released by the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s are reclaimed before the
+    /// statistics are acquired.
+    pub fn loan_statistics(&self) -> PublisherLoanStatistics {
+        let data_segment = &self.data_segment;
+        data_segment.retrieve_returned_samples();
+
+        PublisherLoanStatistics {
+            number_of_loaned_samples: data_segment.loan_counter.load(Ordering::Relaxed),
+            max_loaned_samples: data_segment.max_loaned_samples.load(Ordering::Relaxed),
+            loaned_samples_high_watermark: data_segment.loan_high_watermark.load(Ordering::Relaxed),
+            number_of_chunks: data_segment.memory.number_of_samples(),
+            number_of_used_chunks: data_segment.used_chunks.load(Ordering::Relaxed),
+            used_chunks_high_watermark: data_segment
+                .used_chunks_high_watermark
+                .load(Ordering::Relaxed),
+            number_of_allocation_failures: data_segment.allocation_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the [`DataSegmentStatistics`] of the [`Publisher`]s data segment. Samples that
+    /// were already released by the [`Subscriber`](crate::port::subscriber::Subscriber)s are
+    /// reclaimed before the statistics are acquired.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().max_slice_len(128).create()?;
+    ///
+    /// let statistics = publisher.data_segment_statistics();
+    /// for pool in statistics.memory_pools() {
+    ///     println!("bucket size: {}, free buckets: {}",
+    ///         pool.bucket_size(), pool.number_of_free_buckets());
+    /// }
+    /// println!("utilization: {:.2}", statistics.utilization());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn data_segment_statistics(&self) -> DataSegmentStatistics {
+        self.data_segment.retrieve_returned_samples();
+
+        DataSegmentStatistics {
+            memory_pools: self.data_segment.memory.memory_pool_statistics(),
+        }
+    }
+
+    /// Closes the [`Publisher`] explicitly instead of relying on [`Drop`], so that the shutdown
+    /// sequence defines what happens to the [`crate::sample::Sample`]s that were not yet
+    /// received, see [`DrainMode`]. Afterwards, the loaned samples that are still held can
+    /// no longer be sent.
+    ///
+    /// With [`DrainMode::WaitForDelivery`], the buffers of all connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s are polled until they are empty.
+    /// A [`Subscriber`](crate::port::subscriber::Subscriber) that disconnects while the
+    /// [`Publisher`] waits is no longer considered. When the timeout passes or a termination
+    /// signal is received, the [`Publisher`] is closed nevertheless and a
+    /// [`PublisherCloseError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::DrainMode;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    /// publisher.send_copy(1234)?;
+    ///
+    /// publisher.close(DrainMode::WaitForDelivery(Duration::from_millis(100)))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close(self, mode: DrainMode) -> Result<(), PublisherCloseError> {
+        let msg = "Unable to close the publisher gracefully";
+        let data_segment = &self.data_segment;
+        data_segment.is_active.store(false, Ordering::Relaxed);
+
+        let timeout = match mode {
+            DrainMode::Detach => return Ok(()),
+            DrainMode::WaitForDelivery(timeout) => timeout,
+        };
+
+        let start = Instant::now();
+        loop {
+            if let Err(e) = data_segment.update_connections() {
+                node_log!(
+                    warn!(from self, "Unable to update the connections while waiting for the delivery ({:?}).", e)
+                );
+            }
+
+            let undelivered_samples = data_segment.number_of_undelivered_samples();
+            if undelivered_samples == 0 {
+                return Ok(());
+            }
+
+            if SignalHandler::termination_requested() {
+                node_log!(fail!(from self, with PublisherCloseError::InterruptSignal,
+                    "{} since a termination signal was received while {} samples were not yet received.",
+                    msg, undelivered_samples));
+            }
+
+            let elapsed = start.elapsed();
+            if timeout <= elapsed {
+                node_log!(fail!(from self, with PublisherCloseError::DeliveryTimeout,
+                    "{} since {} samples were not received within {:?}.",
+                    msg, undelivered_samples, timeout));
+            }
+
+            // an interrupted sleep only shortens the polling interval
+            let _ = nanosleep((timeout - elapsed).min(DRAIN_POLLING_INTERVAL));
+        }
+    }
+
+    /// Enters the log context of the owning node, see [`DataSegment::enter_log_context()`].
+    fn enter_log_context(&self) -> LogContextGuard {
+        self.data_segment.enter_log_context()
     }
 
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, PublisherLoanError> {
         let msg = "Unable to allocate Sample with";
 
-        if self.data_segment.loan_counter.load(Ordering::Relaxed)
-            >= self.data_segment.config.max_loaned_samples
-        {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedSamples,
-                "{} {:?} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
-                msg, layout, self.data_segment.loan_counter.load(Ordering::Relaxed), self.data_segment.config.max_loaned_samples);
+        let max_loaned_samples = self.data_segment.max_loaned_samples.load(Ordering::Relaxed);
+        if self.data_segment.loan_counter.load(Ordering::Relaxed) >= max_loaned_samples {
+            self.data_segment
+                .allocation_failures
+                .fetch_add(1, Ordering::Relaxed);
+            node_log!(
+                fail!(from self, with PublisherLoanError::ExceedsMaxLoanedSamples,
+                    "{} {:?} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
+                    msg, layout, self.data_segment.loan_counter.load(Ordering::Relaxed), max_loaned_samples)
+            );
         }
 
         match self.data_segment.allocate(layout) {
             Ok(chunk) => {
-                self.data_segment
+                let loaned_samples = self
+                    .data_segment
                     .loan_counter
-                    .fetch_add(1, Ordering::Relaxed);
+                    .fetch_add(1, Ordering::Relaxed)
+                    + 1;
+                self.data_segment
+                    .loan_high_watermark
+                    .fetch_max(loaned_samples, Ordering::Relaxed);
                 Ok(chunk)
             }
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
-                fail!(from self, with PublisherLoanError::OutOfMemory,
-                    "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
+                self.data_segment
+                    .allocation_failures
+                    .fetch_add(1, Ordering::Relaxed);
+                node_log!(fail!(from self, with PublisherLoanError::OutOfMemory,
+                    "{} {:?} since the underlying shared memory is out of memory.", msg, layout));
             }
             Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
             | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
-                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+                node_log!(
+                    fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout)
+                );
             }
             Err(v) => {
-                fail!(from self, with PublisherLoanError::InternalFailure,
-                    "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v);
+                node_log!(fail!(from self, with PublisherLoanError::InternalFailure,
+                    "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v));
             }
         }
     }
@@ -808,12 +1409,63 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     /// ```
     pub fn send_copy(&self, value: Payload) -> Result<usize, PublisherSendError> {
         let msg = "Unable to send copy of payload";
-        let sample = fail!(from self, when self.loan_uninit(),
-                                    "{} since the loan of a sample failed.", msg);
+        let sample = match self.loan_uninit() {
+            Ok(sample) => sample,
+            Err(e) => {
+                node_log!(
+                    fail!(from self, with e.into(), "{} since the loan of a sample failed.", msg)
+                );
+            }
+        };
 
         sample.write_payload(value).send()
     }
 
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it when
+    /// the provided `hash` differs from the `hash` of the last payload that was sent with this
+    /// method. It is intended for state topics that are recomputed cyclically but rarely
+    /// change, since [`crate::port::subscriber::Subscriber`]s are only woken up on an actual
+    /// change. The hash is provided by the user, see
+    /// [`Publisher::send_copy_if_changed()`] for a comparison with [`PartialEq`].
+    ///
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data or [`None`] when the payload was not sent since the hash is unchanged, otherwise a
+    /// [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .create()?;
+    ///
+    /// let state = 1234;
+    /// assert!(publisher.send_copy_if_hash_changed(state, state)?.is_some());
+    /// assert!(publisher.send_copy_if_hash_changed(state, state)?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy_if_hash_changed(
+        &self,
+        value: Payload,
+        hash: u64,
+    ) -> Result<Option<usize>, PublisherSendError> {
+        if self.last_sent_hash.get() == Some(hash) {
+            return Ok(None);
+        }
+
+        let number_of_recipients = self.send_copy(value)?;
+        self.last_sent_hash.set(Some(hash));
+        Ok(Some(number_of_recipients))
+    }
+
     /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -864,6 +1516,65 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     }
 }
 
+impl<Service: service::Service, Payload: Debug + Clone + PartialEq + Sized, UserHeader: Debug>
+    Publisher<Service, Payload, UserHeader>
+{
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it when it
+    /// differs from the last payload that was sent with this method. It is intended for state
+    /// topics that are recomputed cyclically but rarely change, since
+    /// [`crate::port::subscriber::Subscriber`]s are only woken up on an actual change. The
+    /// [`Publisher`] keeps a copy of the last sent payload to compare with, see
+    /// [`Publisher::send_copy_if_hash_changed()`] for a comparison with a user provided hash.
+    ///
+    /// A [`crate::port::subscriber::Subscriber`] that connects after the last change receives
+    /// the current state only when the service has a history, see
+    /// [`crate::service::builder::publish_subscribe::Builder::history_size()`].
+    ///
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data or [`None`] when the payload was not sent since it is unchanged, otherwise a
+    /// [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .create()?;
+    ///
+    /// assert!(publisher.send_copy_if_changed(1234)?.is_some());
+    /// // the state did not change, nothing is sent
+    /// assert!(publisher.send_copy_if_changed(1234)?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy_if_changed(
+        &self,
+        value: Payload,
+    ) -> Result<Option<usize>, PublisherSendError> {
+        // the last payload is not borrowed while sending since a degradation or connection
+        // callback that is called during the delivery may use this publisher again
+        if self.last_sent_payload.borrow().as_deref() == Some(&value) {
+            return Ok(None);
+        }
+
+        let number_of_recipients = self.send_copy(value.clone())?;
+
+        let mut last_sent_payload = self.last_sent_payload.borrow_mut();
+        match last_sent_payload.as_mut() {
+            Some(last_sent_payload) => **last_sent_payload = value,
+            None => *last_sent_payload = Some(Box::new(value)),
+        }
+        Ok(Some(number_of_recipients))
+    }
+}
+
 impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: Debug>
     Publisher<Service, Payload, UserHeader>
 {
@@ -948,6 +1659,48 @@ impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
     }
 }
 
+impl<Service: service::Service, Payload: Debug + Copy, UserHeader: Debug>
+    Publisher<Service, [Payload], UserHeader>
+{
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] with exactly
+    /// `value.len()` elements and delivers it. On success it returns the number of
+    /// [`crate::port::subscriber::Subscriber`]s that received the data, otherwise a
+    /// [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .max_slice_len(120)
+    ///                          .create()?;
+    ///
+    /// let data = vec![1, 2, 3, 4];
+    /// publisher.send_slice_copy(&data)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_slice_copy(&self, value: &[Payload]) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send copy of slice";
+        let sample = match self.loan_slice_uninit(value.len()) {
+            Ok(sample) => sample,
+            Err(e) => {
+                node_log!(fail!(from self, with e.into(),
+                    "{} since the loan of a sample with {} elements failed.", msg, value.len()));
+            }
+        };
+
+        sample.write_from_slice(value).send()
+    }
+}
+
 impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     Publisher<Service, [Payload], UserHeader>
 {
@@ -995,11 +1748,8 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         underlying_number_of_slice_elements: usize,
     ) -> Result<SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>, PublisherLoanError>
     {
-        let max_slice_len = self.data_segment.config.max_slice_len;
-        if max_slice_len < slice_len {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
-                "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
-                slice_len, max_slice_len);
+        if self.data_segment.max_slice_len.load(Ordering::Relaxed) < slice_len {
+            self.data_segment.grow(slice_len)?;
         }
 
         let sample_layout = self.sample_layout(slice_len);
@@ -1052,6 +1802,101 @@ impl<Service: service::Service, UserHeader: Debug>
         self.loan_slice_uninit_impl(slice_len, self.payload_size * slice_len)
     }
 }
+
+impl<Service: service::Service> Publisher<Service, [CustomPayloadMarker], CustomHeaderMarker> {
+    /// Copies the bytes of the `user_header` and the `payload` into a
+    /// [`crate::sample_mut::SampleMut`] and delivers it. It is used by [`Publisher`]s of
+    /// [`Service`](crate::service::Service)s that were opened with
+    /// [`crate::service::builder::Builder::publish_subscribe_raw()`]. The `user_header` must have
+    /// the size of the user header type and the `payload` must consist of complete payload
+    /// elements, otherwise [`PublisherSendError::IncompatibleRawSampleSize`] is returned.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service_name: ServiceName = "My/Funk/ServiceName".try_into()?;
+    /// let typed_service = node.service_builder(&service_name)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let raw_service = node.service_builder(&service_name)
+    ///     .publish_subscribe_raw()
+    ///     .open()?;
+    /// let publisher = raw_service.publisher_builder().create()?;
+    ///
+    /// publisher.send_raw_copy(&[], &1234u64.to_ne_bytes())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_raw_copy(
+        &self,
+        user_header: &[u8],
+        payload: &[u8],
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send raw copy of payload";
+        let user_header_size = self
+            .data_segment
+            .subscriber_connections
+            .static_config
+            .message_type_details
+            .user_header
+            .size;
+
+        if user_header.len() != user_header_size {
+            node_log!(
+                fail!(from self, with PublisherSendError::IncompatibleRawSampleSize,
+                    "{} since the user header has a size of {} bytes but the service requires {} bytes.",
+                    msg, user_header.len(), user_header_size)
+            );
+        }
+
+        let number_of_elements = match self.payload_type_variant() {
+            TypeVariant::FixedSize if payload.len() == self.payload_size => 1,
+            TypeVariant::Dynamic
+                if self.payload_size != 0 && payload.len() % self.payload_size == 0 =>
+            {
+                payload.len() / self.payload_size
+            }
+            _ => {
+                node_log!(
+                    fail!(from self, with PublisherSendError::IncompatibleRawSampleSize,
+                        "{} since the payload with a size of {} bytes does not consist of complete elements with a size of {} bytes.",
+                        msg, payload.len(), self.payload_size)
+                );
+            }
+        };
+
+        let mut sample = match unsafe { self.loan_custom_payload(number_of_elements) } {
+            Ok(sample) => sample,
+            Err(e) => {
+                node_log!(
+                    fail!(from self, with e.into(), "{} since the loan of a sample failed.", msg)
+                );
+            }
+        };
+
+        // SAFETY: the sizes of the user header and the payload were verified above
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                user_header.as_ptr(),
+                (sample.user_header_mut() as *mut CustomHeaderMarker).cast::<u8>(),
+                user_header.len(),
+            );
+            core::ptr::copy_nonoverlapping(
+                payload.as_ptr(),
+                sample.payload_mut().as_mut_ptr().cast::<u8>(),
+                payload.len(),
+            );
+            sample.assume_init()
+        }
+        .send()
+    }
+}
 ////////////////////////
 // END: sliced API
 ////////////////////////
@@ -1074,11 +1919,45 @@ pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>
         port_id
     );
 
-    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
-            &data_segment_name(port_id),
-            &data_segment_config::<Service>(config),
-        ), "Unable to remove the publishers data segment."
-    );
+    let growth_config = data_segment_growth_config::<Service>(config);
+    let config = data_segment_config::<Service>(config);
+
+    // the data segments of the memory pools are created in ascending order, the first one that
+    // does not exist marks the end
+    let mut segment_index = 0;
+    while fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &data_segment_name(port_id, segment_index),
+            &config,
+        ), "Unable to remove the publishers data segment {}.", segment_index
+    ) {
+        segment_index += 1;
+    }
+
+    fail!(from origin, when <Service::GrowthStorage as NamedConceptMgmt>::remove_cfg(
+            &data_segment_growth_storage_name(port_id),
+            &growth_config,
+        ), "Unable to remove the publishers data segment growth storage.");
+
+    // the added data segments are named after their slot and generation
+    let data_segments = match <Service::SharedMemory as NamedConceptMgmt>::list_cfg(&config) {
+        Ok(list) => list,
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                "Unable to list the data segments due to insufficient permissions.");
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with NamedConceptRemoveError::InternalError,
+                "Unable to list the data segments due to an internal error.");
+        }
+    };
+
+    for data_segment in data_segments
+        .iter()
+        .filter(|data_segment| is_data_segment_growth_of(data_segment, port_id))
+    {
+        fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(data_segment, &config),
+            "Unable to remove the publishers added data segment {}.", data_segment);
+    }
 
     Ok(())
 }
@@ -0,0 +1,214 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64, f32>()
+//!     .add(0, 0.0)
+//!     .open_or_create()?;
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//!
+//! if let Some(value) = reader.get(&0) {
+//!     println!("current value: {}", *value);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::port::port_identifiers::UniqueReaderId;
+use crate::service::dynamic_config::blackboard::{Entries, ReaderDetails, ValueSlot};
+use crate::service::{self, ServiceState};
+
+/// Failures that can occur when a new [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderCreateError {
+    /// The maximum amount of [`Reader`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Reader`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedReaders,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ReaderCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// The latest value of a key of the blackboard, returned by [`Reader::get()`]. It references
+/// the value directly in shared memory and the [`Writer`](crate::port::writer::Writer) does
+/// not modify it as long as the [`EntryValue`] exists. Updates of the
+/// [`Writer`](crate::port::writer::Writer) are stored in another slot, so an [`EntryValue`]
+/// should only be held briefly to receive the latest value with the next [`Reader::get()`].
+pub struct EntryValue<'reader, ValueType: Copy + Debug + 'static> {
+    slot: &'reader ValueSlot<ValueType>,
+}
+
+impl<ValueType: Copy + Debug + 'static> Debug for EntryValue<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EntryValue {{ value: {:?} }}", self.slot.value())
+    }
+}
+
+impl<ValueType: Copy + Debug + 'static> Deref for EntryValue<'_, ValueType> {
+    type Target = ValueType;
+
+    fn deref(&self) -> &Self::Target {
+        self.slot.value()
+    }
+}
+
+impl<ValueType: Copy + Debug + 'static> Drop for EntryValue<'_, ValueType> {
+    fn drop(&mut self) {
+        self.slot.release();
+    }
+}
+
+/// Acquires the latest values of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based [`Service`](crate::service::Service) directly from shared memory.
+#[derive(Debug)]
+pub struct Reader<
+    Service: service::Service,
+    KeyType: Copy + Eq + Debug + 'static,
+    ValueType: Copy + Debug + 'static,
+> {
+    service_state: Arc<ServiceState<Service>>,
+    dynamic_reader_handle: Option<ContainerHandle>,
+    reader_id: UniqueReaderId,
+    _key: PhantomData<KeyType>,
+    _value: PhantomData<ValueType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Drop for Reader<Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_reader_handle {
+            let dynamic_config = self.service_state.dynamic_storage.get();
+            dynamic_config.blackboard().release_reader_handle(handle);
+            dynamic_config.statistics().add_disconnected_port();
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Reader<Service, KeyType, ValueType>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Unable to create Reader port";
+        let origin = "Reader::new()";
+        let _log_context = service.__internal_state().shared_node.enter_log_context();
+        let reader_id = UniqueReaderId::new();
+
+        let mut new_self = Self {
+            service_state: service.__internal_state().clone(),
+            dynamic_reader_handle: None,
+            reader_id,
+            _key: PhantomData,
+            _value: PhantomData,
+        };
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a reader is added to the dynamic config without
+        // the creation of all required resources
+        let dynamic_reader_handle = match new_self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_reader_id(ReaderDetails {
+                reader_id,
+                node_id: *service.__internal_state().shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                    "{} since it would exceed the maximum supported amount of readers of {}.",
+                    msg, service.__internal_state().static_config.blackboard().max_readers());
+            }
+        };
+        new_self.dynamic_reader_handle = Some(dynamic_reader_handle);
+        service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .add_connected_port();
+
+        Ok(new_self)
+    }
+
+    fn entries(&self) -> Entries<'_, KeyType, ValueType> {
+        unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .entries::<KeyType, ValueType>()
+        }
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.reader_id
+    }
+
+    /// Returns the latest value of the provided key without copying it out of the shared
+    /// memory. It never waits on the [`Writer`](crate::port::writer::Writer). If the key was
+    /// not added when the [`Service`](crate::service::Service) was created it returns [`None`].
+    pub fn get(&self, key: &KeyType) -> Option<EntryValue<'_, ValueType>> {
+        self.entries()
+            .find(|entry| entry.key() == key)
+            .map(|entry| EntryValue {
+                slot: entry.acquire(),
+            })
+    }
+
+    /// Calls the provided callback for every key of the blackboard until the callback
+    /// returns [`CallbackProgression::Stop`].
+    pub fn list_keys<F: FnMut(&KeyType) -> CallbackProgression>(&self, mut callback: F) {
+        for entry in self.entries() {
+            if callback(entry.key()) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+}
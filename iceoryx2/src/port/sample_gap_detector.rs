@@ -0,0 +1,145 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Detects lost [`Sample`](crate::sample::Sample)s on the
+//! [`Subscriber`](crate::port::subscriber::Subscriber) side. Every
+//! [`Publisher`](crate::port::publisher::Publisher) numbers its samples consecutively, see
+//! [`Header::sequence_number()`]. The [`SampleGapDetector`] tracks the last sequence number of
+//! every [`Publisher`](crate::port::publisher::Publisher) and reports a [`SampleGap`] when
+//! samples were skipped between two received ones, for instance since the buffer of the
+//! [`Subscriber`](crate::port::subscriber::Subscriber) overflowed.
+//!
+//! The first sample of a [`Publisher`](crate::port::publisher::Publisher) never reports a gap,
+//! so that samples which were sent before the
+//! [`Subscriber`](crate::port::subscriber::Subscriber) was connected are not reported as lost.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::sample_gap_detector::SampleGapDetector;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//! let mut gap_detector = SampleGapDetector::new();
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     if let Some(gap) = gap_detector.update(sample.header()) {
+//!         println!("lost {} samples of {:?}", gap.number_of_lost_samples(), gap.publisher_id());
+//!     }
+//! }
+//!
+//! println!("lost {} samples in total", gap_detector.number_of_lost_samples());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::service::header::publish_subscribe::Header;
+
+/// Samples of a [`Publisher`](crate::port::publisher::Publisher) that were not received, reported
+/// by [`SampleGapDetector::update()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SampleGap {
+    publisher_id: UniquePublisherId,
+    first_lost_sequence_number: u64,
+    received_sequence_number: u64,
+}
+
+impl SampleGap {
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    /// that sent the lost samples.
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the sequence number of the first lost sample.
+    pub fn first_lost_sequence_number(&self) -> u64 {
+        self.first_lost_sequence_number
+    }
+
+    /// Returns the sequence number of the sample that was received after the lost samples.
+    pub fn received_sequence_number(&self) -> u64 {
+        self.received_sequence_number
+    }
+
+    /// Returns the number of consecutive samples that were lost.
+    pub fn number_of_lost_samples(&self) -> u64 {
+        self.received_sequence_number - self.first_lost_sequence_number
+    }
+}
+
+/// Tracks the [`Header::sequence_number()`] of every
+/// [`Publisher`](crate::port::publisher::Publisher) and reports the [`SampleGap`]s between
+/// the received samples.
+#[derive(Debug, Default)]
+pub struct SampleGapDetector {
+    next_sequence_numbers: HashMap<UniquePublisherId, u64>,
+    number_of_lost_samples: u64,
+}
+
+impl SampleGapDetector {
+    /// Creates a new [`SampleGapDetector`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the detector with the [`Header`] of a received sample. Returns the [`SampleGap`]
+    /// when samples of the same [`Publisher`](crate::port::publisher::Publisher) were lost since
+    /// the previously received sample. Samples with a sequence number that was already seen,
+    /// like the history that is delivered again, are ignored.
+    pub fn update(&mut self, header: &Header) -> Option<SampleGap> {
+        let publisher_id = header.publisher_id();
+        let sequence_number = header.sequence_number();
+
+        let expected_sequence_number = self
+            .next_sequence_numbers
+            .insert(publisher_id, sequence_number.saturating_add(1))?;
+
+        if sequence_number < expected_sequence_number {
+            self.next_sequence_numbers
+                .insert(publisher_id, expected_sequence_number);
+            return None;
+        }
+
+        if sequence_number == expected_sequence_number {
+            return None;
+        }
+
+        let gap = SampleGap {
+            publisher_id,
+            first_lost_sequence_number: expected_sequence_number,
+            received_sequence_number: sequence_number,
+        };
+        self.number_of_lost_samples += gap.number_of_lost_samples();
+
+        Some(gap)
+    }
+
+    /// Returns the number of lost samples of all [`SampleGap`]s that were reported so far.
+    pub fn number_of_lost_samples(&self) -> u64 {
+        self.number_of_lost_samples
+    }
+
+    /// Stops tracking the provided [`Publisher`](crate::port::publisher::Publisher), for
+    /// instance after it was disconnected. Its next sample does not report a gap.
+    pub fn remove_publisher(&mut self, publisher_id: &UniquePublisherId) {
+        self.next_sequence_numbers.remove(publisher_id);
+    }
+}
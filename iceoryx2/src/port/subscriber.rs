@@ -35,27 +35,43 @@ use std::any::TypeId;
 use std::cell::UnsafeCell;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
-use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_log::context::LogContextGuard;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
+use iceoryx2_cal::event::{
+    Event, Listener, ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId,
+};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
+use iceoryx2_cal::zero_copy_connection::*;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
-use crate::port::DegrationAction;
+use crate::config::Config;
+use crate::node::{node_log, NodeState};
+use crate::port::{DegrationAction, ExpiredSampleCallback, PublisherDisconnectCallback};
 use crate::sample::SampleDetails;
-use crate::service::builder::publish_subscribe::CustomPayloadMarker;
+use crate::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+use crate::service::config_scheme::event_config;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
-use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::naming_scheme::subscriber_event_concept_name;
+use crate::service::port_factory::subscriber::{
+    SampleOrdering, SubscriberConfig, SubscriberOverflowStrategy,
+};
+use crate::service::service_name::ServiceName;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
 use super::details::publisher_connections::{Connection, PublisherConnections};
-use super::port_identifiers::UniqueSubscriberId;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
 use super::DegrationCallback;
 
@@ -70,6 +86,15 @@ pub enum SubscriberReceiveError {
     /// Occurs when a [`Subscriber`] is unable to connect to a corresponding
     /// [`Publisher`](crate::port::publisher::Publisher).
     ConnectionFailure(ConnectionFailure),
+
+    /// [`Subscriber::receive_blocking()`] was called on a [`Subscriber`] that was created
+    /// without
+    /// [`PortFactorySubscriber::enable_blocking_receive()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_blocking_receive()).
+    BlockingReceiveNotEnabled,
+
+    /// [`Subscriber::receive_blocking()`] was unable to wait for the delivery of a
+    /// [`Sample`].
+    WaitFailure,
 }
 
 impl std::fmt::Display for SubscriberReceiveError {
@@ -92,6 +117,8 @@ pub enum SubscriberCreateError {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`](crate::service::Service) offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// The event that is required for [`Subscriber::receive_blocking()`] could not be created.
+    UnableToCreateBlockingReceiveEvent,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
@@ -102,6 +129,40 @@ impl std::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+/// The state of the connections of a [`Subscriber`] to the
+/// [`Publisher`](crate::port::publisher::Publisher)s of the service, see
+/// [`Subscriber::publisher_connection_state()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PublisherConnectionState {
+    /// At least one [`Publisher`](crate::port::publisher::Publisher) is connected and its
+    /// [`Node`](crate::node::Node) is alive.
+    Alive,
+    /// [`Publisher`](crate::port::publisher::Publisher)s are connected but all of their
+    /// [`Node`](crate::node::Node)s are either dead or missed their liveness deadline. The
+    /// [`Publisher`](crate::port::publisher::Publisher)s of dead
+    /// [`Node`](crate::node::Node)s disconnect when the stale resources are cleaned up.
+    NotAlive,
+    /// No [`Publisher`](crate::port::publisher::Publisher) is connected.
+    Disconnected,
+}
+
+/// The statistics of the connection of a [`Subscriber`] to a single
+/// [`Publisher`](crate::port::publisher::Publisher), see
+/// [`Subscriber::publisher_connection_statistics()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct PublisherConnectionStatistics {
+    /// The [`UniquePublisherId`] of the connected
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub publisher_id: UniquePublisherId,
+    /// The number of [`Sample`]s that are currently stored in the buffer of the connection.
+    pub number_of_buffered_samples: usize,
+    /// The capacity of the buffer of the connection.
+    pub buffer_size: usize,
+    /// The number of [`Sample`]s that were dropped since the connection was established,
+    /// either because the buffer was full or because they were overwritten on overflow.
+    pub number_of_dropped_samples: u64,
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
 pub struct Subscriber<
@@ -112,8 +173,21 @@ pub struct Subscriber<
     dynamic_subscriber_handle: Option<ContainerHandle>,
     publisher_connections: PublisherConnections<Service>,
     to_be_removed_connections: UnsafeCell<Queue<Arc<Connection<Service>>>>,
+    // one slot per publisher connection and one for the expired connections, only used with
+    // SampleOrdering::Timestamp
+    lookahead: UnsafeCell<Vec<Option<(SampleDetails<Service>, usize)>>>,
+    // the sample of the last peek() or peek_last(), it is still stored in the receive buffer of
+    // its connection and therefore never released
+    peeked_sample: Option<ManuallyDrop<Sample<Service, Payload, UserHeader>>>,
+    sample_ordering: SampleOrdering,
+    max_sample_age: Option<Duration>,
+    expired_samples: IoxAtomicU64,
+    receive_batch_buffer: UnsafeCell<Vec<PointerOffset>>,
     static_config: crate::service::static_config::StaticConfig,
     degration_callback: Option<DegrationCallback<'static>>,
+    publisher_disconnect_callback: Option<PublisherDisconnectCallback<'static>>,
+    expired_sample_callback: Option<ExpiredSampleCallback<'static>>,
+    delivery_listener: Option<<Service::Event as Event>::Listener>,
 
     publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
     _payload: PhantomData<Payload>,
@@ -124,13 +198,33 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Subscriber<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
+        self.discard_peeked_sample();
+
+        let lookahead = core::mem::take(self.lookahead.get_mut());
+        for (details, _) in lookahead.into_iter().flatten() {
+            if details
+                .publisher_connection
+                .receiver
+                .release(details.offset)
+                .is_err()
+            {
+                node_log!(
+                    warn!(from self, "Unable to return the sample {:?} to the publisher {:?}.",
+                        details.offset, details.origin)
+                );
+            }
+        }
+
         if let Some(handle) = self.dynamic_subscriber_handle {
-            self.publisher_connections
+            let dynamic_config = self
+                .publisher_connections
                 .service_state
                 .dynamic_storage
-                .get()
+                .get();
+            dynamic_config
                 .publish_subscribe()
-                .release_subscriber_handle(handle)
+                .release_subscriber_handle(handle);
+            dynamic_config.statistics().add_disconnected_port();
         }
     }
 }
@@ -145,6 +239,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
         let origin = "Subscriber::new()";
+        let _log_context = service.__internal_state().shared_node.enter_log_context();
         let subscriber_id = UniqueSubscriberId::new();
 
         let publisher_list = &service
@@ -172,8 +267,25 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             service.__internal_state().clone(),
             static_config,
             buffer_size,
+            SubscriberOverflowStrategy::enable_safe_overflow(
+                config.overflow_strategy,
+                static_config.enable_safe_overflow,
+            ),
         );
 
+        let delivery_listener = if config.blocking_receive {
+            let event_name = subscriber_event_concept_name(&subscriber_id);
+            Some(fail!(from origin,
+                when <Service::Event as Event>::ListenerBuilder::new(&event_name)
+                    .config(&event_config::<Service>(&service.__internal_state().shared_node.config()))
+                    .trigger_id_max(TriggerId::new(0))
+                    .create(),
+                with SubscriberCreateError::UnableToCreateBlockingReceiveEvent,
+                "{} since the event \"{}\" for blocking receive could not be created.", msg, event_name))
+        } else {
+            None
+        };
+
         let mut new_self = Self {
             to_be_removed_connections: UnsafeCell::new(Queue::new(
                 service
@@ -184,7 +296,25 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                     .publish_subscribe
                     .subscriber_expired_connection_buffer,
             )),
+            lookahead: UnsafeCell::new(match config.sample_ordering {
+                SampleOrdering::PerPublisherFifo => vec![],
+                SampleOrdering::Timestamp => {
+                    (0..publisher_list.capacity() + 1).map(|_| None).collect()
+                }
+            }),
+            peeked_sample: None,
+            sample_ordering: config.sample_ordering,
+            max_sample_age: config.max_sample_age,
+            expired_samples: IoxAtomicU64::new(0),
+            receive_batch_buffer: UnsafeCell::new(vec![
+                PointerOffset::new(0);
+                static_config
+                    .subscriber_max_borrowed_samples
+            ]),
             degration_callback: config.degration_callback,
+            publisher_disconnect_callback: config.publisher_disconnect_callback,
+            expired_sample_callback: config.expired_sample_callback,
+            delivery_listener,
             publisher_connections,
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_subscriber_handle: None,
@@ -209,17 +339,25 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .add_subscriber_id(SubscriberDetails {
                 subscriber_id,
                 buffer_size,
+                overflow_strategy: config.overflow_strategy,
+                blocking_receive: config.blocking_receive,
                 node_id: *service.__internal_state().shared_node.id(),
             }) {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from new_self, with SubscriberCreateError::ExceedsMaxSupportedSubscribers,
                                 "{} since it would exceed the maximum supported amount of subscribers of {}.",
-                                msg, service.__internal_state().static_config.publish_subscribe().max_subscribers);
+                                msg, service.__internal_state().dynamic_storage.get().publish_subscribe().max_subscribers());
             }
         };
 
         new_self.dynamic_subscriber_handle = Some(dynamic_subscriber_handle);
+        service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .add_connected_port();
 
         Ok(new_self)
     }
@@ -241,11 +379,17 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                     && !unsafe { &mut *self.to_be_removed_connections.get() }
                         .push(connection.clone())
                 {
-                    warn!(from self, "Expired connection buffer exceeded. A publisher disconnected with undelivered samples that will be discarded. Increase the config entry `defaults.publish-subscribe.subscriber-expired-connection-buffer` to mitigate the problem.");
+                    node_log!(
+                        warn!(from self, "Expired connection buffer exceeded. A publisher disconnected with undelivered samples that will be discarded. Increase the config entry `defaults.publish-subscribe.subscriber-expired-connection-buffer` to mitigate the problem.")
+                    );
                 }
             }
         };
 
+        let had_publishers = (0..self.publisher_connections.len())
+            .any(|i| self.publisher_connections.get(i).is_some());
+        let mut last_disconnected_publisher = None;
+
         // update all connections
         for (i, index) in visited_indices.iter().enumerate() {
             match index {
@@ -262,7 +406,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                             Ok(()) => (),
                             Err(e) => match &self.degration_callback {
                                 None => {
-                                    warn!(from self, "Unable to establish connection to new publisher {:?}.", details.publisher_id)
+                                    node_log!(
+                                        warn!(from self, "Unable to establish connection to new publisher {:?}.", details.publisher_id)
+                                    )
                                 }
                                 Some(c) => {
                                     match c.call(
@@ -272,12 +418,16 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                                     ) {
                                         DegrationAction::Ignore => (),
                                         DegrationAction::Warn => {
-                                            warn!(from self, "Unable to establish connection to new publisher {:?}.",
-                                        details.publisher_id)
+                                            node_log!(
+                                                warn!(from self, "Unable to establish connection to new publisher {:?}.",
+                                                    details.publisher_id)
+                                            )
                                         }
                                         DegrationAction::Fail => {
-                                            fail!(from self, with e, "Unable to establish connection to new publisher {:?}.",
-                                        details.publisher_id);
+                                            node_log!(
+                                                fail!(from self, with e, "Unable to establish connection to new publisher {:?}.",
+                                                    details.publisher_id)
+                                            );
                                         }
                                     }
                                 }
@@ -286,6 +436,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                     }
                 }
                 None => {
+                    if let Some(connection) = self.publisher_connections.get(i) {
+                        last_disconnected_publisher = Some(connection.publisher_id);
+                    }
+
                     prepare_connection_removal(i);
 
                     self.publisher_connections.remove(i)
@@ -293,34 +447,170 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         }
 
+        if let (Some(callback), Some(publisher_id)) = (
+            &self.publisher_disconnect_callback,
+            last_disconnected_publisher,
+        ) {
+            if had_publishers && visited_indices.iter().all(|details| details.is_none()) {
+                callback.call(publisher_id);
+            }
+        }
+
         Ok(())
     }
 
+    // The log context of the owning node is entered right before a message is logged and not
+    // for every receive call.
+    fn enter_log_context(&self) -> LogContextGuard {
+        self.publisher_connections
+            .service_state
+            .shared_node
+            .enter_log_context()
+    }
+
     fn receive_from_connection(
         &self,
         connection: &Arc<Connection<Service>>,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
         let msg = "Unable to receive another sample";
-        match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(offset) => {
+        loop {
+            match connection.receiver.receive() {
+                Ok(data) => match data {
+                    None => return Ok(None),
+                    Some(offset) => {
+                        let absolute_address = self.payload_address(connection, &[offset])?;
+
+                        if let Some(age) = self.expired_sample_age(absolute_address) {
+                            self.discard_expired_sample(connection, offset, age);
+                            continue;
+                        }
+
+                        let details = SampleDetails {
+                            publisher_connection: connection.clone(),
+                            offset,
+                            origin: connection.publisher_id,
+                        };
+
+                        return Ok(Some((details, absolute_address)));
+                    }
+                },
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    node_log!(
+                        fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                            "{} since it would exceed the maximum {} of borrowed samples.",
+                            msg, connection.receiver.max_borrowed_samples())
+                    );
+                }
+            }
+        }
+    }
+
+    // Returns the age of the sample when it exceeds the max sample age, otherwise None.
+    fn expired_sample_age(&self, absolute_address: usize) -> Option<Duration> {
+        let max_sample_age = self.max_sample_age?;
+        let now = match Time::now() {
+            Ok(now) => now,
+            Err(e) => {
+                node_log!(fatal_panic!(from self,
+                    "This should never happen! Unable to acquire the current time ({:?}).", e));
+            }
+        };
+        let header = unsafe { &*(absolute_address as *const Header) };
+        let age = now.as_duration().saturating_sub(header.send_timestamp());
+
+        match max_sample_age < age {
+            true => Some(age),
+            false => None,
+        }
+    }
+
+    fn discard_expired_sample(
+        &self,
+        connection: &Arc<Connection<Service>>,
+        offset: PointerOffset,
+        age: Duration,
+    ) {
+        if connection.receiver.release(offset).is_err() {
+            node_log!(
+                warn!(from self, "Unable to return the expired sample {:?} to the publisher {:?}.",
+                    offset, connection.publisher_id)
+            );
+        }
+
+        self.expired_samples.fetch_add(1, Ordering::Relaxed);
+        if let Some(callback) = &self.expired_sample_callback {
+            callback.call(connection.publisher_id, age);
+        }
+    }
+
+    // Acquires the address of the first offset. When the publishers data segment that contains
+    // it cannot be mapped, all provided offsets are returned to the publisher since they will
+    // never be handed out to the user.
+    fn payload_address(
+        &self,
+        connection: &Arc<Connection<Service>>,
+        offsets: &[PointerOffset],
+    ) -> Result<usize, SubscriberReceiveError> {
+        match connection.data_segment.payload_address(offsets[0]) {
+            Ok(absolute_address) => Ok(absolute_address),
+            Err(e) => {
+                for offset in offsets {
+                    if connection.receiver.release(*offset).is_err() {
+                        node_log!(
+                            warn!(from self, "Unable to return the sample {:?} to the publisher {:?}.",
+                                offset, connection.publisher_id)
+                        );
+                    }
+                }
+
+                node_log!(
+                    fail!(from self, with SubscriberReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                        "Unable to receive a sample since the data segment of the publisher {:?} that contains it could not be mapped.",
+                        connection.publisher_id)
+                );
+            }
+        }
+    }
+
+    fn receive_batch_from_connection(
+        &self,
+        connection: &Arc<Connection<Service>>,
+        max: usize,
+        samples: &mut Vec<(SampleDetails<Service>, usize)>,
+    ) -> Result<usize, SubscriberReceiveError> {
+        let msg = "Unable to receive another batch of samples";
+        let buffer = unsafe { &mut *self.receive_batch_buffer.get() };
+        let len = buffer.len().min(max);
+
+        match connection.receiver.receive_batch(&mut buffer[..len]) {
+            Ok(number_of_samples) => {
+                for (n, offset) in buffer.iter().take(number_of_samples).enumerate() {
                     let absolute_address =
-                        offset.value() + connection.data_segment.payload_start_address();
+                        self.payload_address(connection, &buffer[n..number_of_samples])?;
 
-                    let details = SampleDetails {
-                        publisher_connection: connection.clone(),
-                        offset,
-                        origin: connection.publisher_id,
-                    };
+                    if let Some(age) = self.expired_sample_age(absolute_address) {
+                        self.discard_expired_sample(connection, *offset, age);
+                        continue;
+                    }
 
-                    Ok(Some((details, absolute_address)))
+                    samples.push((
+                        SampleDetails {
+                            publisher_connection: connection.clone(),
+                            offset: *offset,
+                            origin: connection.publisher_id,
+                        },
+                        absolute_address,
+                    ));
                 }
-            },
+
+                Ok(number_of_samples)
+            }
             Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
-                fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
-                    "{} since it would exceed the maximum {} of borrowed samples.",
-                    msg, connection.receiver.max_borrowed_samples());
+                node_log!(
+                    fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                        "{} since it would exceed the maximum {} of borrowed samples.",
+                        msg, connection.receiver.max_borrowed_samples())
+                );
             }
         }
     }
@@ -330,6 +620,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.publisher_connections.subscriber_id()
     }
 
+    pub(crate) fn service_name(&self) -> &ServiceName {
+        self.static_config.name()
+    }
+
     /// Returns the internal buffer size of the [`Subscriber`].
     pub fn buffer_size(&self) -> usize {
         self.publisher_connections.buffer_size
@@ -337,8 +631,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
-        fail!(from self, when self.update_connections(),
-                "Some samples are not being received since not all connections to publishers could be established.");
+        if let Err(e) = self.update_connections() {
+            node_log!(fail!(from self, with e,
+                "Some samples are not being received since not all connections to publishers could be established."));
+        }
 
         for id in 0..self.publisher_connections.len() {
             if let Some(ref connection) = &self.publisher_connections.get(id) {
@@ -348,16 +644,262 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         }
 
-        Ok(false)
+        let lookahead = unsafe { &*self.lookahead.get() };
+        Ok(lookahead.iter().any(|sample| sample.is_some()))
+    }
+
+    /// Returns the [`PublisherConnectionState`] of the [`Subscriber`]. It tells whether any
+    /// [`Publisher`](crate::port::publisher::Publisher) of the service is still connected
+    /// and alive.
+    pub fn publisher_connection_state(
+        &self,
+    ) -> Result<PublisherConnectionState, ConnectionFailure> {
+        if let Err(e) = self.update_connections() {
+            node_log!(fail!(from self, with e,
+                "Unable to acquire the publisher connection state since not all connections to publishers could be established."));
+        }
+
+        let config = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .config();
+        let mut state = PublisherConnectionState::Disconnected;
+        unsafe {
+            (*self.publisher_list_state.get()).for_each(|_, details| {
+                state = PublisherConnectionState::NotAlive;
+                match NodeState::<Service>::new(&details.node_id, config) {
+                    Ok(Some(NodeState::Dead(_)))
+                    | Ok(Some(NodeState::Unresponsive(_)))
+                    | Ok(None) => CallbackProgression::Continue,
+                    // a node whose state cannot be acquired is not known to be dead
+                    _ => {
+                        state = PublisherConnectionState::Alive;
+                        CallbackProgression::Stop
+                    }
+                }
+            })
+        };
+
+        Ok(state)
+    }
+
+    /// Returns the [`PublisherConnectionStatistics`] of every connected
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub fn publisher_connection_statistics(
+        &self,
+    ) -> Result<Vec<PublisherConnectionStatistics>, ConnectionFailure> {
+        if let Err(e) = self.update_connections() {
+            node_log!(fail!(from self, with e,
+                "Unable to acquire the publisher connection statistics since not all connections to publishers could be established."));
+        }
+
+        let mut statistics = vec![];
+        for id in 0..self.publisher_connections.len() {
+            if let Some(ref connection) = &self.publisher_connections.get(id) {
+                statistics.push(PublisherConnectionStatistics {
+                    publisher_id: connection.publisher_id,
+                    number_of_buffered_samples: connection.receiver.number_of_buffered_samples(),
+                    buffer_size: connection.receiver.buffer_size(),
+                    number_of_dropped_samples: connection.receiver.number_of_dropped_samples(),
+                });
+            }
+        }
+
+        Ok(statistics)
+    }
+
+    /// Returns the number of [`Sample`]s that were dropped on all connections to the
+    /// currently connected [`Publisher`](crate::port::publisher::Publisher)s because the
+    /// [`Subscriber`] did not receive them in time. The dropped samples of all
+    /// [`Subscriber`]s of the [`Service`](crate::service::Service) are available without
+    /// participating in the communication via
+    /// [`ServiceStatistics::samples_dropped()`](crate::service::statistics::ServiceStatistics::samples_dropped()).
+    pub fn dropped_samples(&self) -> Result<u64, ConnectionFailure> {
+        Ok(self
+            .publisher_connection_statistics()?
+            .iter()
+            .map(|s| s.number_of_dropped_samples)
+            .sum())
+    }
+
+    /// Returns the number of [`Sample`]s that were discarded since they exceeded the
+    /// [`PortFactorySubscriber::max_sample_age()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_sample_age())
+    /// at receive time.
+    pub fn expired_samples(&self) -> u64 {
+        self.expired_samples.load(Ordering::Relaxed)
+    }
+
+    /// Closes the [`Subscriber`] explicitly instead of relying on [`Drop`]. All [`Sample`]s
+    /// that were delivered but not yet received are discarded and returned to their
+    /// [`Publisher`](crate::port::publisher::Publisher)s before the [`Subscriber`] is removed
+    /// from the service, so that the shutdown sequence knows how many of them were never
+    /// consumed. [`Sample`]s that are still borrowed stay valid until they are dropped.
+    ///
+    /// On success it returns the number of discarded [`Sample`]s. When the samples could not
+    /// be received, the [`Subscriber`] is closed nevertheless and a [`SubscriberReceiveError`]
+    /// describing the failure is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let number_of_discarded_samples = subscriber.close()?;
+    /// println!("{} samples were never received", number_of_discarded_samples);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close(self) -> Result<usize, SubscriberReceiveError> {
+        let mut number_of_discarded_samples = 0;
+        while let Some((details, _)) = self.receive_impl()? {
+            if details
+                .publisher_connection
+                .receiver
+                .release(details.offset)
+                .is_err()
+            {
+                node_log!(
+                    warn!(from self, "Unable to return the discarded sample {:?} to the publisher {:?}.",
+                        details.offset, details.origin)
+                );
+            }
+            number_of_discarded_samples += 1;
+        }
+
+        Ok(number_of_discarded_samples)
+    }
+
+    fn discard_peeked_sample(&mut self) {
+        if let Some(mut sample) = self.peeked_sample.take() {
+            // the sample is owned by the receive buffer, only the connection must be dropped
+            unsafe { core::ptr::drop_in_place(&mut sample.details) };
+        }
+    }
+
+    fn peek_impl<
+        F: Fn(&Self, SampleDetails<Service>, usize) -> Sample<Service, Payload, UserHeader>,
+    >(
+        &mut self,
+        peek_last: bool,
+        create_sample: F,
+    ) -> Result<Option<&Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.discard_peeked_sample();
+
+        if let Err(e) = self.update_connections() {
+            node_log!(fail!(from self,
+                with SubscriberReceiveError::ConnectionFailure(e),
+                "Unable to peek a sample since not all connections to publishers could be established."));
+        }
+
+        let candidates = self.peek_candidates(peek_last)?;
+        let send_timestamp = |sample: &(SampleDetails<Service>, usize)| {
+            unsafe { &*(sample.1 as *const Header) }.send_timestamp()
+        };
+
+        let sample = match (peek_last, self.sample_ordering) {
+            (true, _) => candidates
+                .into_iter()
+                .max_by_key(|sample| (send_timestamp(sample), sample.0.origin)),
+            (false, SampleOrdering::PerPublisherFifo) => candidates.into_iter().next(),
+            (false, SampleOrdering::Timestamp) => candidates.into_iter().min_by_key(|sample| {
+                (
+                    core::cmp::Reverse(sample.0.publisher_connection.priority),
+                    send_timestamp(sample),
+                    sample.0.origin,
+                )
+            }),
+        };
+
+        self.peeked_sample = sample.map(|(details, absolute_address)| {
+            ManuallyDrop::new(create_sample(&*self, details, absolute_address))
+        });
+        Ok(self.peeked_sample.as_deref())
+    }
+
+    // Collects, in receive order, the oldest or newest sample of every connection without
+    // removing it from the receive buffer. A sample that is held back in the lookahead is older
+    // than all samples in the receive buffer of its connection.
+    fn peek_candidates(
+        &self,
+        peek_last: bool,
+    ) -> Result<Vec<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let lookahead = unsafe { &*self.lookahead.get() };
+        let to_be_removed_connections = unsafe { &*self.to_be_removed_connections.get() };
+        let expired_slot = self.publisher_connections.len();
+
+        let connections = to_be_removed_connections
+            .peek()
+            .map(|connection| (expired_slot, connection))
+            .into_iter()
+            .chain(
+                self.publisher_connections
+                    .receive_order()
+                    .iter()
+                    .filter_map(|id| {
+                        self.publisher_connections
+                            .get(*id)
+                            .as_ref()
+                            .map(|connection| (*id, connection))
+                    }),
+            );
+
+        let mut candidates = vec![];
+        for (slot, connection) in connections {
+            let held_back = lookahead
+                .get(slot)
+                .and_then(|sample| sample.as_ref())
+                .map(|(details, _)| details.offset);
+            let offset = match peek_last {
+                true => connection.receiver.peek_last().or(held_back),
+                false => held_back.or_else(|| connection.receiver.peek()),
+            };
+
+            if let Some(offset) = offset {
+                let absolute_address = match connection.data_segment.payload_address(offset) {
+                    Ok(absolute_address) => absolute_address,
+                    Err(e) => {
+                        node_log!(
+                            fail!(from self, with SubscriberReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                                "Unable to peek a sample since the data segment of the publisher {:?} that contains it could not be mapped.",
+                                connection.publisher_id)
+                        );
+                    }
+                };
+
+                candidates.push((
+                    SampleDetails {
+                        publisher_connection: connection.clone(),
+                        offset,
+                        origin: connection.publisher_id,
+                    },
+                    absolute_address,
+                ));
+            }
+        }
+
+        Ok(candidates)
     }
 
     fn receive_impl(
         &self,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
         if let Err(e) = self.update_connections() {
-            fail!(from self,
+            node_log!(fail!(from self,
                 with SubscriberReceiveError::ConnectionFailure(e),
-                "Some samples are not being received since not all connections to publishers could be established.");
+                "Some samples are not being received since not all connections to publishers could be established."));
+        }
+
+        if self.sample_ordering == SampleOrdering::Timestamp {
+            return self.receive_oldest_sample();
         }
 
         let to_be_removed_connections = unsafe { &mut *self.to_be_removed_connections.get() };
@@ -370,8 +912,8 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         }
 
-        for id in 0..self.publisher_connections.len() {
-            if let Some(ref mut connection) = &mut self.publisher_connections.get_mut(id) {
+        for id in self.publisher_connections.receive_order() {
+            if let Some(ref connection) = &self.publisher_connections.get(*id) {
                 if let Some((details, absolute_address)) =
                     self.receive_from_connection(connection)?
                 {
@@ -383,6 +925,190 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(None)
     }
 
+    // Fills every empty lookahead slot with the next sample of its connection and returns the
+    // sample of the publisher with the highest priority and the oldest send timestamp. Only a
+    // connection that has data but cannot be received from due to the borrow limit fails, since
+    // otherwise the order would be violated. Samples that expired while they were held back are
+    // discarded and the lookahead is refilled until a valid sample is found.
+    fn receive_oldest_sample(
+        &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let lookahead = unsafe { &mut *self.lookahead.get() };
+        let to_be_removed_connections = unsafe { &mut *self.to_be_removed_connections.get() };
+        let expired_slot = self.publisher_connections.len();
+
+        let send_order = |sample: &(SampleDetails<Service>, usize)| {
+            let header = unsafe { &*(sample.1 as *const Header) };
+            (
+                core::cmp::Reverse(sample.0.publisher_connection.priority),
+                header.send_timestamp(),
+                sample.0.origin,
+            )
+        };
+
+        loop {
+            while lookahead[expired_slot].is_none() {
+                match to_be_removed_connections.peek().cloned() {
+                    None => break,
+                    Some(connection) => match connection.receiver.has_data() {
+                        true => {
+                            lookahead[expired_slot] = self.receive_from_connection(&connection)?
+                        }
+                        false => {
+                            to_be_removed_connections.pop();
+                        }
+                    },
+                }
+            }
+
+            for (id, slot) in lookahead.iter_mut().enumerate().take(expired_slot) {
+                if slot.is_none() {
+                    if let Some(ref connection) = &self.publisher_connections.get(id) {
+                        if connection.receiver.has_data() {
+                            *slot = self.receive_from_connection(connection)?;
+                        }
+                    }
+                }
+            }
+
+            let oldest = lookahead
+                .iter()
+                .enumerate()
+                .filter_map(|(n, slot)| slot.as_ref().map(|sample| (send_order(sample), n)))
+                .min()
+                .map(|(_, n)| n);
+
+            match oldest.and_then(|n| lookahead[n].take()) {
+                // the sample may have expired while it was held back in the lookahead
+                Some((details, absolute_address)) => {
+                    match self.expired_sample_age(absolute_address) {
+                        Some(age) => self.discard_expired_sample(
+                            &details.publisher_connection,
+                            details.offset,
+                            age,
+                        ),
+                        None => return Ok(Some((details, absolute_address))),
+                    }
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+
+    fn receive_batch_impl(
+        &self,
+        max: usize,
+    ) -> Result<Vec<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        if let Err(e) = self.update_connections() {
+            node_log!(fail!(from self,
+                with SubscriberReceiveError::ConnectionFailure(e),
+                "Some samples are not being received since not all connections to publishers could be established."));
+        }
+
+        let mut samples = vec![];
+        if self.sample_ordering == SampleOrdering::Timestamp {
+            while samples.len() < max {
+                match self.receive_oldest_sample() {
+                    Ok(Some(sample)) => samples.push(sample),
+                    Ok(None) => break,
+                    Err(e) if samples.is_empty() => return Err(e),
+                    Err(_) => break,
+                }
+            }
+
+            return Ok(samples);
+        }
+
+        // when the borrow limit is reached the samples that were already received are returned,
+        // the failure is only reported when not a single sample could be received
+        let receive = |connection: &Arc<Connection<Service>>,
+                       samples: &mut Vec<(SampleDetails<Service>, usize)>|
+         -> Result<Option<usize>, SubscriberReceiveError> {
+            match self.receive_batch_from_connection(connection, max - samples.len(), samples) {
+                Ok(number_of_samples) => Ok(Some(number_of_samples)),
+                Err(e) if samples.is_empty() => Err(e),
+                Err(_) => Ok(None),
+            }
+        };
+
+        let to_be_removed_connections = unsafe { &mut *self.to_be_removed_connections.get() };
+        while let Some(connection) = to_be_removed_connections.peek() {
+            if samples.len() == max {
+                return Ok(samples);
+            }
+
+            match receive(connection, &mut samples)? {
+                None => return Ok(samples),
+                Some(0) => {
+                    to_be_removed_connections.pop();
+                }
+                Some(_) => (),
+            }
+        }
+
+        for id in self.publisher_connections.receive_order() {
+            if let Some(ref connection) = &self.publisher_connections.get(*id) {
+                while samples.len() < max {
+                    match receive(connection, &mut samples)? {
+                        None => return Ok(samples),
+                        Some(0) => break,
+                        Some(_) => (),
+                    }
+                }
+            }
+        }
+
+        Ok(samples)
+    }
+
+    pub(crate) fn delivery_listener(&self) -> Option<&<Service::Event as Event>::Listener> {
+        self.delivery_listener.as_ref()
+    }
+
+    fn receive_blocking_impl<T, F: Fn(&Self) -> Result<Option<T>, SubscriberReceiveError>>(
+        &self,
+        timeout: Duration,
+        receive: F,
+    ) -> Result<Option<T>, SubscriberReceiveError> {
+        let msg = "Unable to receive sample with blocking receive";
+        let listener = match &self.delivery_listener {
+            Some(listener) => listener,
+            None => {
+                node_log!(
+                    fail!(from self, with SubscriberReceiveError::BlockingReceiveNotEnabled,
+                        "{} since the subscriber was created without blocking receive support.", msg)
+                );
+            }
+        };
+
+        let start = Instant::now();
+        loop {
+            // notifications of samples that are received in the next step are obsolete
+            if let Err(e) = listener.try_wait_all(|_| {}) {
+                node_log!(fail!(from self, with SubscriberReceiveError::WaitFailure,
+                    "{} since the pending notifications could not be acquired ({:?}).", msg, e));
+            }
+
+            if let Some(sample) = receive(self)? {
+                return Ok(Some(sample));
+            }
+
+            let elapsed = start.elapsed();
+            if timeout <= elapsed {
+                return Ok(None);
+            }
+
+            match listener.timed_wait_one(timeout - elapsed) {
+                Ok(_) => (),
+                Err(ListenerWaitError::InterruptSignal) => return receive(self),
+                Err(e) => {
+                    node_log!(fail!(from self, with SubscriberReceiveError::WaitFailure,
+                        "{} since the wait for the next delivery failed ({:?}).", msg, e));
+                }
+            }
+        }
+    }
+
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.publisher_connections
             .static_config
@@ -413,8 +1139,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Upda
                 .publishers
                 .update_state(&mut *self.publisher_list_state.get())
         } {
-            fail!(from self, when self.populate_publisher_channels(),
-                "Connections were updated only partially since at least one connection to a publisher failed.");
+            if let Err(e) = self.populate_publisher_channels() {
+                node_log!(fail!(from self, with e,
+                    "Connections were updated only partially since at least one connection to a publisher failed."));
+            }
         }
 
         Ok(())
@@ -429,15 +1157,142 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     pub fn receive(
         &self,
     ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
-            let header_ptr = absolute_address as *const Header;
-            let user_header_ptr = self.user_header_ptr(header_ptr).cast();
-            let payload_ptr = self.payload_ptr(header_ptr).cast();
-            Sample {
-                details,
-                ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
-            }
-        }))
+        Ok(self
+            .receive_impl()?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Returns a reference to the next [`crate::sample::Sample`] without consuming it. The
+    /// next call of [`Subscriber::receive()`] returns the same [`crate::sample::Sample`]. The
+    /// peeked [`crate::sample::Sample`] stays in the receive buffer, it does not count as
+    /// borrowed and is not discarded when it exceeds the
+    /// [`PortFactorySubscriber::max_sample_age()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_sample_age()).
+    /// When the [`crate::port::publisher::Publisher`] overflows the receive buffer, it may
+    /// recycle the peeked [`crate::sample::Sample`] while it is still referenced, therefore
+    /// it is meant for diagnostic purposes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let mut subscriber = service.subscriber_builder().create()?;
+    ///
+    /// if let Some(sample) = subscriber.peek()? {
+    ///     println!("next sample: {:?}", **sample);
+    /// }
+    ///
+    /// // receives the peeked sample
+    /// let sample = subscriber.receive()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.peek_impl(false, Self::create_sample)
+    }
+
+    /// Returns a reference to the newest available [`crate::sample::Sample`] without consuming
+    /// any [`crate::sample::Sample`]. All [`crate::sample::Sample`]s are returned by
+    /// [`Subscriber::receive()`] in their original order. Like with [`Subscriber::peek()`]
+    /// the peeked [`crate::sample::Sample`] stays in the receive buffer and does not count as
+    /// borrowed.
+    pub fn peek_last(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.peek_impl(true, Self::create_sample)
+    }
+
+    /// Receives up to `max` [`crate::sample::Sample`]s from all connected
+    /// [`crate::port::publisher::Publisher`]s. The samples of one
+    /// [`crate::port::publisher::Publisher`] are acquired with a single synchronization of the
+    /// underlying queue, which reduces the overhead compared to repeated calls of
+    /// [`Subscriber::receive()`]. Returns an empty [`Vec`] when no sample is available.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// for sample in subscriber.receive_batch(16)? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_batch(
+        &self,
+        max: usize,
+    ) -> Result<Vec<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .receive_batch_impl(max)?
+            .into_iter()
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address))
+            .collect())
+    }
+
+    fn create_sample(
+        &self,
+        details: SampleDetails<Service>,
+        absolute_address: usize,
+    ) -> Sample<Service, Payload, UserHeader> {
+        let header_ptr = absolute_address as *const Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+        let payload_ptr = self.payload_ptr(header_ptr).cast();
+        Sample {
+            details,
+            ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+        }
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] but waits at most
+    /// `timeout` for its delivery when none is available. Returns [`None`] when the timeout
+    /// passed without a delivered [`crate::sample::Sample`]. The [`Subscriber`] must be created
+    /// with
+    /// [`PortFactorySubscriber::enable_blocking_receive()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_blocking_receive()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder()
+    ///     .enable_blocking_receive(true)
+    ///     .create()?;
+    ///
+    /// if let Some(sample) = subscriber.receive_blocking(Duration::from_millis(10))? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_blocking(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.receive_blocking_impl(timeout, |this| this.receive())
     }
 }
 
@@ -451,23 +1306,80 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
-            let header_ptr = absolute_address as *const Header;
-            let user_header_ptr = self.user_header_ptr(header_ptr).cast();
-            let payload_ptr = self.payload_ptr(header_ptr).cast();
-            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+        Ok(self
+            .receive_impl()?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
 
-            Sample {
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
-                        header_ptr,
-                        user_header_ptr,
-                        core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
-                    )
-                },
-            }
-        }))
+    /// Returns a reference to the next [`crate::sample::Sample`] without consuming it, see
+    /// [`Subscriber::peek()`].
+    pub fn peek(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        self.peek_impl(false, Self::create_sample)
+    }
+
+    /// Returns a reference to the newest available [`crate::sample::Sample`] without consuming
+    /// any [`crate::sample::Sample`], see [`Subscriber::peek_last()`].
+    pub fn peek_last(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        self.peek_impl(true, Self::create_sample)
+    }
+
+    /// Receives up to `max` [`crate::sample::Sample`]s from all connected
+    /// [`crate::port::publisher::Publisher`]s. The samples of one
+    /// [`crate::port::publisher::Publisher`] are acquired with a single synchronization of the
+    /// underlying queue. Returns an empty [`Vec`] when no sample is available.
+    pub fn receive_batch(
+        &self,
+        max: usize,
+    ) -> Result<Vec<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        Ok(self
+            .receive_batch_impl(max)?
+            .into_iter()
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address))
+            .collect())
+    }
+
+    fn create_sample(
+        &self,
+        details: SampleDetails<Service>,
+        absolute_address: usize,
+    ) -> Sample<Service, [Payload], UserHeader> {
+        let header_ptr = absolute_address as *const Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+        let payload_ptr = self.payload_ptr(header_ptr).cast();
+        let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+        Sample {
+            details,
+            ptr: unsafe {
+                RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
+                )
+            },
+        }
+    }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] but waits at most
+    /// `timeout` for its delivery when none is available. Returns [`None`] when the timeout
+    /// passed without a delivered [`crate::sample::Sample`]. The [`Subscriber`] must be created
+    /// with
+    /// [`PortFactorySubscriber::enable_blocking_receive()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_blocking_receive()).
+    pub fn receive_blocking(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.receive_blocking_impl(timeout, |this| this.receive())
     }
 }
 
@@ -514,3 +1426,65 @@ impl<Service: service::Service, UserHeader: Debug>
         }))
     }
 }
+
+impl<Service: service::Service> Subscriber<Service, [CustomPayloadMarker], CustomHeaderMarker> {
+    /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`] of a
+    /// [`Service`](crate::service::Service) that was opened with
+    /// [`crate::service::builder::Builder::publish_subscribe_raw()`]. The payload and the user
+    /// header can be accessed as bytes with [`Sample::payload_bytes()`] and
+    /// [`Sample::user_header_bytes()`]. If no sample could be received [`None`] is returned.
+    /// If a failure occurs [`SubscriberReceiveError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service_name: ServiceName = "My/Funk/ServiceName".try_into()?;
+    /// let typed_service = node.service_builder(&service_name)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let raw_service = node.service_builder(&service_name)
+    ///     .publish_subscribe_raw()
+    ///     .open()?;
+    /// let subscriber = raw_service.subscriber_builder().create()?;
+    ///
+    /// while let Some(sample) = subscriber.receive_raw()? {
+    ///     println!("received: {:?}", sample.payload_bytes());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_raw(
+        &self,
+    ) -> Result<
+        Option<Sample<Service, [CustomPayloadMarker], CustomHeaderMarker>>,
+        SubscriberReceiveError,
+    > {
+        // SAFETY: the number of elements in the header was set by the publisher and refers to the
+        // payload type details of the service which are used to calculate the number of bytes
+        unsafe { self.receive_custom_payload() }
+    }
+}
+
+pub(crate) unsafe fn remove_blocking_receive_event_of_subscriber<Service: service::Service>(
+    subscriber_id: &UniqueSubscriberId,
+    config: &Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_blocking_receive_event_of_subscriber::<{}>({:?})",
+        core::any::type_name::<Service>(),
+        subscriber_id
+    );
+    let msg = "Unable to remove the blocking receive event of the subscriber";
+    let event_name = subscriber_event_concept_name(subscriber_id);
+    let event_config = event_config::<Service>(config);
+
+    fail!(from origin,
+            when <Service::Event as NamedConceptMgmt>::remove_cfg(&event_name, &event_config),
+            "{} since the underlying concept could not be removed.", msg);
+    Ok(())
+}
@@ -771,10 +771,62 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     /// # }
     /// ```
     pub fn wait_and_process_once<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        fn_call: F,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process_once_impl(fn_call, None)
+    }
+
+    /// Behaves like [`WaitSet::wait_and_process_once()`] but waits at most for the provided
+    /// `timeout`. If no event arrives and no deadline or interval is hit before the timeout
+    /// has passed, it returns [`WaitSetRunResult::AllEventsHandled`] without calling the
+    /// provided callback.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    /// # let listener = event.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let _guard = waitset.attach_notification(&listener)?;
+    ///
+    /// let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+    ///     // do some event processing
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// loop {
+    ///     // blocks at most 100ms until an event arrives
+    ///     waitset.wait_and_process_once_with_timeout(on_event, Duration::from_millis(100))?;
+    ///     // do some cyclic work
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_and_process_once_with_timeout<
+        F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression,
+    >(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process_once_impl(fn_call, Some(timeout))
+    }
+
+    fn wait_and_process_once_impl<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
         &self,
         mut fn_call: F,
+        timeout: Option<Duration>,
     ) -> Result<WaitSetRunResult, WaitSetRunError> {
-        let msg = "Unable to call WaitSet::try_wait_and_process()";
+        let msg = "Unable to call WaitSet::wait_and_process_once()";
 
         if SignalHandler::termination_requested() {
             return Ok(WaitSetRunResult::TerminationRequest);
@@ -799,10 +851,13 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         // Collect all triggered file descriptors. We need to collect them first, then reset
         // the deadline and then call the callback, otherwise a long callback may destroy the
         // deadline contract.
-        let reactor_wait_result = if self.deadline_queue.is_empty() {
-            self.reactor.blocking_wait(collect_triggered_fds)
-        } else {
-            self.reactor.timed_wait(collect_triggered_fds, next_timeout)
+        let reactor_wait_result = match (self.deadline_queue.is_empty(), timeout) {
+            (true, None) => self.reactor.blocking_wait(collect_triggered_fds),
+            (true, Some(timeout)) => self.reactor.timed_wait(collect_triggered_fds, timeout),
+            (false, None) => self.reactor.timed_wait(collect_triggered_fds, next_timeout),
+            (false, Some(timeout)) => self
+                .reactor
+                .timed_wait(collect_triggered_fds, next_timeout.min(timeout)),
         };
 
         match reactor_wait_result {
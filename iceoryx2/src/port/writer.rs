@@ -0,0 +1,220 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64, f32>()
+//!     .add(0, 0.0)
+//!     .add(1, 0.0)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//!
+//! writer.update(&0, 3.14)?;
+//! writer.update(&1, 2.71)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::node_log;
+use crate::port::port_identifiers::UniqueWriterId;
+use crate::service::dynamic_config::blackboard::WriterDetails;
+use crate::service::{self, ServiceState};
+
+/// Failures that can occur when a new [`Writer`] is created with the
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterCreateError {
+    /// Only one [`Writer`] can be connected to a [`Service`](crate::service::Service) at a
+    /// time. When another [`Writer`] already exists no more [`Writer`]s can be created.
+    ExceedsMaxSupportedWriters,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WriterCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Defines the failures that can occur while a [`Writer::update()`] call.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterUpdateError {
+    /// The provided key was not added to the blackboard when the
+    /// [`Service`](crate::service::Service) was created.
+    KeyDoesNotExist,
+    /// Every value of the key is still referenced by an
+    /// [`EntryValue`](crate::port::reader::EntryValue) of a [`crate::port::reader::Reader`].
+    ValueIsStillInUse,
+}
+
+impl std::fmt::Display for WriterUpdateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WriterUpdateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WriterUpdateError {}
+
+/// Updates the values of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based [`Service`](crate::service::Service) directly in shared memory.
+#[derive(Debug)]
+pub struct Writer<
+    Service: service::Service,
+    KeyType: Copy + Eq + Debug + 'static,
+    ValueType: Copy + Debug + 'static,
+> {
+    service_state: Arc<ServiceState<Service>>,
+    dynamic_writer_handle: Option<ContainerHandle>,
+    writer_id: UniqueWriterId,
+    _key: PhantomData<KeyType>,
+    _value: PhantomData<ValueType>,
+    // updates of an entry must never happen concurrently, therefore the writer must not be
+    // shared between threads
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Drop for Writer<Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_writer_handle {
+            let dynamic_config = self.service_state.dynamic_storage.get();
+            dynamic_config.blackboard().release_writer_handle(handle);
+            dynamic_config.statistics().add_disconnected_port();
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Writer<Service, KeyType, ValueType>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, WriterCreateError> {
+        let msg = "Unable to create Writer port";
+        let origin = "Writer::new()";
+        let _log_context = service.__internal_state().shared_node.enter_log_context();
+        let writer_id = UniqueWriterId::new();
+
+        let mut new_self = Self {
+            service_state: service.__internal_state().clone(),
+            dynamic_writer_handle: None,
+            writer_id,
+            _key: PhantomData,
+            _value: PhantomData,
+            _not_sync: PhantomData,
+        };
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a writer is added to the dynamic config without
+        // the creation of all required resources
+        let dynamic_writer_handle = match new_self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_writer_id(WriterDetails {
+                writer_id,
+                node_id: *service.__internal_state().shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                    "{} since it would exceed the maximum supported amount of writers of {}.",
+                    msg, service.__internal_state().static_config.blackboard().max_writers());
+            }
+        };
+        new_self.dynamic_writer_handle = Some(dynamic_writer_handle);
+
+        // SAFETY: only one writer can exist at a time
+        unsafe {
+            new_self
+                .service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .entries::<KeyType, ValueType>()
+                .for_each(|entry| entry.release_interrupted_updates())
+        };
+
+        service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .add_connected_port();
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.writer_id
+    }
+
+    /// Updates the value of the provided key. Every
+    /// [`crate::port::reader::Reader`] acquires the new value with its next read. If the key
+    /// was not added when the [`Service`](crate::service::Service) was created it returns
+    /// [`WriterUpdateError::KeyDoesNotExist`]. When the [`crate::port::reader::Reader`]s hold
+    /// more [`EntryValue`](crate::port::reader::EntryValue)s of the key than there are
+    /// readers, it returns [`WriterUpdateError::ValueIsStillInUse`].
+    pub fn update(&self, key: &KeyType, value: ValueType) -> Result<(), WriterUpdateError> {
+        let mut entries = unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .entries::<KeyType, ValueType>()
+        };
+
+        match entries.find(|entry| entry.key() == key) {
+            // SAFETY: there exists only one writer and it is not Sync, therefore updates never
+            //         happen concurrently
+            Some(entry) => {
+                if !unsafe { entry.store(value) } {
+                    node_log!(in self.service_state.shared_node, fail!(from self, with WriterUpdateError::ValueIsStillInUse,
+                        "Unable to update the value of {:?} since every value slot is still in use by a reader.", key));
+                }
+            }
+            None => {
+                node_log!(in self.service_state.shared_node, fail!(from self, with WriterUpdateError::KeyDoesNotExist,
+                    "Unable to update the value of {:?} since the key does not exist.", key));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -12,7 +12,8 @@
 
 pub use crate::config::Config;
 pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeState};
-pub use crate::port::event_id::EventId;
+pub use crate::port::dispatcher::{Dispatcher, DispatcherBuilder};
+pub use crate::port::event_id::{EventId, EventIdEnum, EventIdFilter};
 pub use crate::port::waitset::{WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGuard};
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
@@ -20,9 +21,14 @@ pub use crate::service::{
     local, port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
     service_name::ServiceName, Service, ServiceDetails,
 };
-pub use iceoryx2_bb_derive_macros::PlacementDefault;
+pub use iceoryx2_bb_container::{
+    byte_string::FixedSizeByteString, map::FixedSizeMap, vec::FixedSizeVec,
+};
+pub use iceoryx2_bb_derive_macros::{EventIdEnum, PlacementDefault, TypeLayoutHash, ZeroCopySend};
 pub use iceoryx2_bb_elementary::alignment::Alignment;
 pub use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+pub use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+pub use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 pub use iceoryx2_bb_elementary::CallbackProgression;
 pub use iceoryx2_bb_log::set_log_level;
 pub use iceoryx2_bb_log::LogLevel;
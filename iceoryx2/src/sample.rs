@@ -39,6 +39,7 @@ use iceoryx2_cal::zero_copy_connection::{PointerOffset, ZeroCopyReceiver, ZeroCo
 use crate::port::details::publisher_connections::Connection;
 use crate::port::port_identifiers::UniquePublisherId;
 use crate::raw_sample::RawSample;
+use crate::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
 use crate::service::header::publish_subscribe::Header;
 
 #[derive(Debug)]
@@ -121,3 +122,26 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
         self.details.origin
     }
 }
+
+impl<Service: crate::service::Service> Sample<Service, [CustomPayloadMarker], CustomHeaderMarker> {
+    /// Returns the payload of a [`Sample`] received with
+    /// [`Subscriber::receive_raw()`](crate::port::subscriber::Subscriber::receive_raw()) as bytes.
+    pub fn payload_bytes(&self) -> &[u8] {
+        let payload = self.ptr.as_payload_ref();
+        // SAFETY: CustomPayloadMarker is a repr(C) wrapper of an u8
+        unsafe { core::slice::from_raw_parts(payload.as_ptr().cast(), payload.len()) }
+    }
+
+    /// Returns the user header of a [`Sample`] received with
+    /// [`Subscriber::receive_raw()`](crate::port::subscriber::Subscriber::receive_raw()) as
+    /// bytes.
+    pub fn user_header_bytes(&self) -> &[u8] {
+        // SAFETY: the user header is stored with the size of the services user header type
+        unsafe {
+            core::slice::from_raw_parts(
+                (self.ptr.as_user_header_ref() as *const CustomHeaderMarker).cast(),
+                self.details.publisher_connection.user_header_size,
+            )
+        }
+    }
+}
@@ -64,10 +64,13 @@
 //! ```
 
 use crate::{
+    node::node_log,
     port::publisher::{DataSegment, PublisherSendError},
     raw_sample::RawSampleMut,
-    service::header::publish_subscribe::Header,
+    service::header::publish_subscribe::{Header, TracingContext},
 };
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::shared_memory::*;
 use std::{
     fmt::{Debug, Formatter},
@@ -199,6 +202,34 @@ impl<
         self.ptr.as_user_header_mut()
     }
 
+    /// Sets the [`TracingContext`] that is delivered with the sample and can be acquired by the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) with
+    /// [`Header::tracing_context()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::header::publish_subscribe::TracingContext;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.set_tracing_context(TracingContext::new([0xab; 16], [0x01; 8]));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_tracing_context(&mut self, value: TracingContext) {
+        self.ptr.as_header_mut().set_tracing_context(value)
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -287,7 +318,22 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, PublisherSendError> {
-        self.data_segment.send_sample(self.offset_to_chunk.value())
+    pub fn send(mut self) -> Result<usize, PublisherSendError> {
+        let now = match Time::now() {
+            Ok(now) => now,
+            Err(e) => {
+                node_log!(in self.data_segment, fatal_panic!(from "SampleMut::send()",
+                    "This should never happen! Unable to acquire the current time ({:?}).", e));
+            }
+        };
+        self.ptr
+            .as_header_mut()
+            .set_send_timestamp(now.as_duration());
+        self.ptr
+            .as_header_mut()
+            .set_sequence_number(self.data_segment.next_sequence_number());
+        let number_of_elements = self.ptr.as_header_ref().number_of_elements() as usize;
+        self.data_segment
+            .send_sample(self.offset_to_chunk.value(), number_of_elements)
     }
 }
@@ -90,13 +90,16 @@
 //! # }
 //! ```
 
-use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+use std::{fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering, sync::Arc};
 
 use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::{
-    port::publisher::DataSegment, raw_sample::RawSampleMut, sample_mut::SampleMut,
-    service::header::publish_subscribe::Header,
+    port::publisher::DataSegment,
+    raw_sample::RawSampleMut,
+    sample_mut::SampleMut,
+    service::header::publish_subscribe::{Header, TracingContext},
 };
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
@@ -197,6 +200,12 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
         self.sample.user_header_mut()
     }
 
+    /// Sets the [`TracingContext`] that is delivered with the sample, see
+    /// [`SampleMut::set_tracing_context()`].
+    pub fn set_tracing_context(&mut self, value: TracingContext) {
+        self.sample.set_tracing_context(value)
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -430,6 +439,119 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
     }
+
+    /// Splits the payload into up to `number_of_parts` disjoint [`SlicePart`]s of equal length,
+    /// only the last part can be shorter, and hands them to the `writer`. The
+    /// [`SlicePart`]s implement [`Send`] when the payload does, so that the `writer` can
+    /// distribute them to scoped worker threads. When the `writer` returns and every
+    /// [`SlicePart`] was written, the sample is labeled as initialized. Otherwise, it returns
+    /// [`None`] and the loaned memory is released.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().max_slice_len(1024).create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit(1024)?;
+    /// let sample = sample
+    ///     .write_from_parts(4, |parts| {
+    ///         std::thread::scope(|s| {
+    ///             for part in parts {
+    ///                 s.spawn(move || part.write_from_fn(|n| n * 2));
+    ///             }
+    ///         })
+    ///     })
+    ///     .expect("all parts are written");
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_parts<F: for<'part> FnOnce(Vec<SlicePart<'part, Payload>>)>(
+        mut self,
+        number_of_parts: usize,
+        writer: F,
+    ) -> Option<SampleMut<Service, [Payload], UserHeader>> {
+        let written_parts = IoxAtomicUsize::new(0);
+        let payload = self.payload_mut();
+        let part_len = payload.len().div_ceil(number_of_parts.max(1)).max(1);
+        let parts: Vec<SlicePart<'_, Payload>> = payload
+            .chunks_mut(part_len)
+            .enumerate()
+            .map(|(n, elements)| SlicePart {
+                offset: n * part_len,
+                elements,
+                written_parts: &written_parts,
+            })
+            .collect();
+        let number_of_parts = parts.len();
+
+        writer(parts);
+
+        if written_parts.load(Ordering::Relaxed) != number_of_parts {
+            return None;
+        }
+
+        // SAFETY: every part wrote all of its elements and the parts cover the whole payload
+        Some(unsafe { self.assume_init() })
+    }
+}
+
+/// A disjoint part of the payload of a [`SampleMutUninit`] that is created with
+/// [`SampleMutUninit::write_from_parts()`]. Every part must be written exactly once.
+#[derive(Debug)]
+pub struct SlicePart<'part, Payload> {
+    offset: usize,
+    elements: &'part mut [MaybeUninit<Payload>],
+    written_parts: &'part IoxAtomicUsize,
+}
+
+impl<Payload> SlicePart<'_, Payload> {
+    /// Returns the index of the first element of the [`SlicePart`] in the payload.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of elements of the [`SlicePart`].
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true when the [`SlicePart`] has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Writes every element of the [`SlicePart`] with the value returned by the `initializer`
+    /// that is called with the index of the element in the payload.
+    pub fn write_from_fn<F: FnMut(usize) -> Payload>(self, mut initializer: F) {
+        for (i, element) in self.elements.iter_mut().enumerate() {
+            element.write(initializer(self.offset + i));
+        }
+
+        self.written_parts.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl<Payload: Copy> SlicePart<'_, Payload> {
+    /// Writes the elements of the [`SlicePart`] by mem copying the provided slice, which must
+    /// have the same length as the [`SlicePart`].
+    pub fn write_from_slice(self, value: &[Payload]) {
+        self.elements.copy_from_slice(unsafe {
+            core::mem::transmute::<&[Payload], &[MaybeUninit<Payload>]>(value)
+        });
+
+        self.written_parts.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 impl<Service: crate::service::Service, Payload: Debug + Copy, UserHeader>
@@ -15,11 +15,14 @@
 //! See [`crate::service`]
 //!
 pub use crate::port::event_id::EventId;
+use crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
 use crate::service::builder::OpenDynamicStorageFailure;
+use crate::service::discovery::ServiceDiscoveryEventKind;
 use crate::service::port_factory::event;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use crate::service::{self, dynamic_config::event::DynamicConfigSettings};
+use core::time::Duration;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 
@@ -39,6 +42,10 @@ pub enum EventOpenError {
     ServiceInCorruptedState,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
+    /// The [`Service`] was created on a platform with a different endianness, pointer width or
+    /// alignment of the primitive types, see
+    /// [`PlatformDetails`](crate::service::static_config::platform_details::PlatformDetails).
+    IncompatiblePlatform,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -56,6 +63,10 @@ pub enum EventOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
     ExceedsMaxNumberOfNodes,
+    /// The notifications of the [`Service`] carry a payload of a different size than requested.
+    IncompatiblePayloadSize,
+    /// The [`Service`] expects the notifications with a different deadline than requested.
+    IncompatibleDeadline,
     /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
@@ -76,6 +87,7 @@ impl From<ServiceState> for EventOpenError {
             ServiceState::IncompatibleMessagingPattern => {
                 EventOpenError::IncompatibleMessagingPattern
             }
+            ServiceState::IncompatiblePlatform => EventOpenError::IncompatiblePlatform,
             ServiceState::InsufficientPermissions => EventOpenError::InsufficientPermissions,
             ServiceState::HangsInCreation => EventOpenError::HangsInCreation,
             ServiceState::Corrupted => EventOpenError::ServiceInCorruptedState,
@@ -112,7 +124,9 @@ impl std::error::Error for EventCreateError {}
 impl From<ServiceState> for EventCreateError {
     fn from(value: ServiceState) -> Self {
         match value {
-            ServiceState::IncompatibleMessagingPattern => EventCreateError::AlreadyExists,
+            ServiceState::IncompatibleMessagingPattern | ServiceState::IncompatiblePlatform => {
+                EventCreateError::AlreadyExists
+            }
             ServiceState::InsufficientPermissions => EventCreateError::InsufficientPermissions,
             ServiceState::HangsInCreation => EventCreateError::HangsInCreation,
             ServiceState::Corrupted => EventCreateError::ServiceInCorruptedState,
@@ -168,6 +182,8 @@ pub struct Builder<ServiceType: service::Service> {
     verify_max_listeners: bool,
     verify_max_nodes: bool,
     verify_event_id_max_value: bool,
+    verify_payload_size: bool,
+    verify_deadline: bool,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -178,10 +194,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_max_listeners: false,
             verify_max_nodes: false,
             verify_event_id_max_value: false,
+            verify_payload_size: false,
+            verify_deadline: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
-            static_config::event::StaticConfig::new(new_self.base.shared_node.config()),
+            static_config::event::StaticConfig::new(&new_self.base.shared_node.config()),
         );
 
         new_self
@@ -214,6 +232,38 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the size in bytes of the payload every
+    /// notification carries, see [`crate::port::notifier::Notifier::notify_with_payload()`].
+    /// It is at most [`MAX_EVENT_PAYLOAD_SIZE`](crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE).
+    /// If an existing [`Service`] is opened it requires the service to have the same payload
+    /// size.
+    pub fn payload_size(mut self, value: usize) -> Self {
+        self.config_details().payload_size = value;
+        self.verify_payload_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the period in which a notification is expected,
+    /// e.g. the cycle time of a cyclic trigger. Every period without a notification is
+    /// counted as missed deadline, see
+    /// [`Listener::missed_deadline_count()`](crate::port::listener::Listener::missed_deadline_count()).
+    /// If an existing [`Service`] is opened it requires the service to have the same deadline.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config_details().deadline = Some(value);
+        self.verify_deadline = true;
+        self
+    }
+
+    /// If the [`Service`] is created it assigns a human-readable name to the [`EventId`] that
+    /// [`crate::port::listener::Listener`]s and introspection tools can query with
+    /// [`static_config::event::StaticConfig::event_id_name()`]. Names of [`EventId`]s greater
+    /// than [`Builder::event_id_max_value()`] are discarded. The names are not verified when
+    /// an existing [`Service`] is opened.
+    pub fn event_id_name(mut self, id: EventId, name: &str) -> Self {
+        self.config_details().set_event_id_name(id, name);
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::port::notifier::Notifier`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::notifier::Notifier`] must be at least supported.
@@ -243,10 +293,34 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
     /// does not exist the required attributes will be defined in the [`Service`].
     pub fn open_or_create_with_attributes(
+        self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+        self.open_or_create_with_attributes_and_hook(required_attributes, |_| {})
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. When the [`Service`] is created, `on_create` is called with the new
+    /// [`event::PortFactory`] before it is returned, e.g. to announce the new [`Service`].
+    /// Since only one process can create the [`Service`], `on_create` is called exactly once
+    /// across all processes that race for the [`Service`]. Other processes may already open
+    /// the [`Service`] while `on_create` is running.
+    pub fn open_or_create_with_hook<F: FnOnce(&event::PortFactory<ServiceType>)>(
+        self,
+        on_create: F,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+        self.open_or_create_with_attributes_and_hook(&AttributeVerifier::new(), on_create)
+    }
+
+    /// Combines [`Builder::open_or_create_with_attributes()`] and
+    /// [`Builder::open_or_create_with_hook()`].
+    pub fn open_or_create_with_attributes_and_hook<F: FnOnce(&event::PortFactory<ServiceType>)>(
         mut self,
         required_attributes: &AttributeVerifier,
+        on_create: F,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
         let msg = "Unable to open or create event service";
+        let _log_context = self.base.shared_node.enter_log_context();
 
         loop {
             match self.base.is_service_available(msg)? {
@@ -255,7 +329,10 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     match self.create_impl(&AttributeSpecifier(
                         required_attributes.attributes().clone(),
                     )) {
-                        Ok(factory) => return Ok(factory),
+                        Ok(factory) => {
+                            on_create(&factory);
+                            return Ok(factory);
+                        }
                         Err(EventCreateError::AlreadyExists)
                         | Err(EventCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
@@ -280,6 +357,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
         const OPEN_RETRY_LIMIT: usize = 5;
         let msg = "Unable to open event service";
+        let _log_context = self.base.shared_node.enter_log_context();
 
         let mut service_open_retry_count = 0;
         loop {
@@ -344,6 +422,36 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         }
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until it
+    /// is created by another process or until the timeout has passed. When the timeout has
+    /// passed or a termination request was received, it fails with
+    /// [`EventOpenError::DoesNotExist`].
+    pub fn open_or_wait(
+        self,
+        timeout: Duration,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
+        self.open_with_attributes_or_wait(&AttributeVerifier::new(), timeout)
+    }
+
+    /// Same as [`Builder::open_or_wait()`] but with attribute requirements. If the defined
+    /// attribute requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes_or_wait(
+        self,
+        required_attributes: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
+        let msg = "Unable to open event service";
+        {
+            let _log_context = self.base.shared_node.enter_log_context();
+            if !self.base.wait_until_service_is_available(msg, timeout)? {
+                fail!(from self, with EventOpenError::DoesNotExist,
+                    "{} since the event was not created within the timeout of {:?}.", msg, timeout);
+            }
+        }
+
+        self.open_with_attributes(required_attributes)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(mut self) -> Result<event::PortFactory<ServiceType>, EventCreateError> {
         self.create_impl(&AttributeSpecifier::new())
@@ -364,6 +472,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self.adjust_attributes_to_meaningful_values();
 
         let msg = "Unable to create event service";
+        let _log_context = self.base.shared_node.enter_log_context();
 
         match self.base.is_service_available(msg)? {
             None => {
@@ -432,6 +541,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     service_tag.release_ownership();
                 }
 
+                discovery::publish_event(
+                    &self.base.shared_node,
+                    ServiceDiscoveryEventKind::Created,
+                    &self.base.service_config,
+                );
+
                 Ok(event::PortFactory::new(ServiceType::__internal_from_state(
                     service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -466,6 +581,25 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             warn!(from origin, "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
+
+        if settings.payload_size > MAX_EVENT_PAYLOAD_SIZE {
+            warn!(from origin, "Setting the payload size to {} is not supported. Adjust it to {}, the largest supported value.",
+                settings.payload_size, MAX_EVENT_PAYLOAD_SIZE);
+            settings.payload_size = MAX_EVENT_PAYLOAD_SIZE;
+        }
+
+        let event_id_max_value = settings.event_id_max_value;
+        if settings
+            .event_id_names
+            .iter()
+            .any(|entry| entry.id > event_id_max_value)
+        {
+            warn!(from origin, "Names of event ids greater than the event id max value of {} are discarded.",
+                event_id_max_value);
+            settings
+                .event_id_names
+                .retain(|entry| entry.id <= event_id_max_value);
+        }
     }
 
     fn verify_service_attributes(
@@ -522,6 +656,20 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
 
-        Ok(*existing_settings)
+        if self.verify_payload_size
+            && existing_settings.payload_size != required_settings.payload_size
+        {
+            fail!(from self, with EventOpenError::IncompatiblePayloadSize,
+                "{} since the event has a payload size of {} bytes but a payload size of {} bytes is required.",
+                msg, existing_settings.payload_size, required_settings.payload_size);
+        }
+
+        if self.verify_deadline && existing_settings.deadline != required_settings.deadline {
+            fail!(from self, with EventOpenError::IncompatibleDeadline,
+                "{} since the event has a deadline of {:?} but a deadline of {:?} is required.",
+                msg, existing_settings.deadline, required_settings.deadline);
+        }
+
+        Ok(existing_settings.clone())
     }
 }
@@ -14,6 +14,9 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
@@ -26,9 +29,13 @@ use crate::service::dynamic_config::DynamicConfig;
 use crate::service::dynamic_config::RegisterNodeResult;
 use crate::service::static_config::*;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::warn;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::nanosleep;
+use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
@@ -40,6 +47,7 @@ use iceoryx2_cal::static_storage::*;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::service_tag_config;
@@ -47,9 +55,12 @@ use super::config_scheme::static_config_storage_config;
 use super::service_name::ServiceName;
 use super::Service;
 
+const SERVICE_AVAILABILITY_POLLING_INTERVAL: Duration = Duration::from_millis(10);
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceState {
     IncompatibleMessagingPattern,
+    IncompatiblePlatform,
     InsufficientPermissions,
     HangsInCreation,
     Corrupted,
@@ -112,28 +123,85 @@ impl<S: Service> Builder<S> {
 
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
-    pub fn publish_subscribe<PayloadType: Debug + ?Sized>(
+    /// The payload is shared via shared memory and must therefore implement [`ZeroCopySend`],
+    /// which can be derived with `#[derive(ZeroCopySend)]`.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct MyPayload {
+    ///     value: u64,
+    ///     name: FixedSizeByteString<32>,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<MyPayload>()
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publish_subscribe<PayloadType: Debug + ?Sized + ZeroCopySend>(
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
         BuilderWithServiceType::new(
             StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
                 &self.name,
-                self.shared_node.config(),
+                &self.shared_node.config(),
             ),
             self.shared_node,
         )
         .publish_subscribe()
     }
 
+    /// Create a new builder to open or create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// without compile-time knowledge of the payload and user header type. When an existing
+    /// [`Service`] is opened, its stored
+    /// [`MessageTypeDetails`](crate::service::static_config::message_type_details::MessageTypeDetails)
+    /// are adopted and the payload and user header are accessible as bytes.
+    pub fn publish_subscribe_raw(
+        self,
+    ) -> publish_subscribe::Builder<
+        [publish_subscribe::CustomPayloadMarker],
+        publish_subscribe::CustomHeaderMarker,
+        S,
+    > {
+        let mut builder = self
+            .publish_subscribe::<[publish_subscribe::CustomPayloadMarker]>()
+            .user_header::<publish_subscribe::CustomHeaderMarker>();
+        builder.adopt_type_details = true;
+        builder
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
+            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, &self.shared_node.config()),
             self.shared_node,
         )
         .event()
     }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard<KeyType: Copy + Eq + Debug + 'static, ValueType: Copy + Debug + 'static>(
+        self,
+    ) -> blackboard::Builder<KeyType, ValueType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+                &self.name,
+                &self.shared_node.config(),
+            ),
+            self.shared_node,
+        )
+        .blackboard()
+    }
 }
 
 #[doc(hidden)]
@@ -163,12 +231,18 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn blackboard<KeyType: Copy + Eq + Debug + 'static, ValueType: Copy + Debug + 'static>(
+        self,
+    ) -> blackboard::Builder<KeyType, ValueType, ServiceType> {
+        blackboard::Builder::new(self)
+    }
+
     fn is_service_available(
         &self,
         msg: &str,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
         let static_storage_config =
-            static_config_storage_config::<ServiceType>(self.shared_node.config());
+            static_config_storage_config::<ServiceType>(&self.shared_node.config());
         let file_name_uuid = self.service_config.service_id().0.into();
         let creation_timeout = self.shared_node.config().global.service.creation_timeout;
 
@@ -225,6 +299,23 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         msg, service_config.messaging_pattern(), self.service_config.messaging_pattern());
                 }
 
+                if !service_config
+                    .platform()
+                    .is_compatible_to(self.service_config.platform())
+                {
+                    fail!(from self, with ServiceState::IncompatiblePlatform,
+                        "{} since it was created on the platform \"{:?}\" with a different memory layout than the current platform \"{:?}\".",
+                        msg, service_config.platform(), self.service_config.platform());
+                }
+
+                if service_config.platform().architecture()
+                    != self.service_config.platform().architecture()
+                {
+                    warn!(from self,
+                        "The service was created on the architecture \"{}\" but is used on \"{}\". The memory layout is compatible but the synchronization primitives may behave differently.",
+                        service_config.platform().architecture(), self.service_config.platform().architecture());
+                }
+
                 Ok(Some((service_config, storage)))
             }
             Err(v) => {
@@ -234,6 +325,29 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    // polls until the service described in base exists or the timeout has passed, returns
+    // false when the timeout has passed or a termination request was received.
+    fn wait_until_service_is_available(
+        &self,
+        msg: &str,
+        timeout: Duration,
+    ) -> Result<bool, ServiceState> {
+        let start = Instant::now();
+        loop {
+            if self.is_service_available(msg)?.is_some() {
+                return Ok(true);
+            }
+
+            let elapsed = start.elapsed();
+            if timeout <= elapsed || SignalHandler::termination_requested() {
+                return Ok(false);
+            }
+
+            // an interrupted sleep only shortens the polling interval
+            let _ = nanosleep((timeout - elapsed).min(SERVICE_AVAILABILITY_POLLING_INTERVAL));
+        }
+    }
+
     fn config_init_call(config: &mut DynamicConfig, allocator: &mut BumpAllocator) -> bool {
         unsafe { config.init(allocator) };
         true
@@ -252,7 +366,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         >>::Builder<'_> as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&self.service_config.service_id().0.into())
-            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+            .config(&dynamic_config_storage_config::<ServiceType>(&self.shared_node.config()))
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
             .initializer(Self::config_init_call)
@@ -282,7 +396,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                     ServiceType::DynamicStorage,
                 >>::new(&self.service_config.service_id().0.into())
                     .timeout(self.shared_node.config().global.service.creation_timeout)
-                    .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+                    .config(&dynamic_config_storage_config::<ServiceType>(&self.shared_node.config()))
                 .has_ownership(false)
                 .open(),
             "{} since the dynamic storage could not be opened.", msg);
@@ -316,7 +430,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             ServiceType::StaticStorage,
         >>::new(&self.service_config.service_id().0.into())
         .config(&service_tag_config::<ServiceType>(
-            self.shared_node.config(),
+            &self.shared_node.config(),
             self.shared_node.id(),
         ))
         .has_ownership(true)
@@ -340,7 +454,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         ServiceType::StaticStorage,
                     >>::new(&self.service_config.service_id().0.into())
                     .config(&static_config_storage_config::<ServiceType>(
-                        self.shared_node.config(),
+                        &self.shared_node.config(),
                     ))
                     .has_ownership(true)
                     .create_locked(),
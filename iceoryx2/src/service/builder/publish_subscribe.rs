@@ -14,16 +14,21 @@
 //!
 //! See [`crate::service`]
 //!
+use core::time::Duration;
 use std::marker::PhantomData;
 
 use crate::service;
+use crate::service::discovery::ServiceDiscoveryEventKind;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publish_subscribe;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use iceoryx2_bb_elementary::alignment::Alignment;
+use iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
@@ -35,16 +40,24 @@ use self::{
 
 use super::{OpenDynamicStorageFailure, ServiceState};
 
+/// Placeholder for a user header whose type is not known at compile time. Used by
+/// [`crate::service::builder::Builder::publish_subscribe_raw()`], the user header can be
+/// accessed as bytes.
 #[repr(C)]
 #[derive(Debug)]
-#[doc(hidden)]
 pub struct CustomHeaderMarker {}
 
+unsafe impl ZeroCopySend for CustomHeaderMarker {}
+
+/// Placeholder for a payload whose type is not known at compile time. Used by
+/// [`crate::service::builder::Builder::publish_subscribe_raw()`], a slice of
+/// [`CustomPayloadMarker`] is the payload as bytes.
 #[repr(C)]
 #[derive(Debug)]
-#[doc(hidden)]
 pub struct CustomPayloadMarker(u8);
 
+unsafe impl ZeroCopySend for CustomPayloadMarker {}
+
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum PublishSubscribeOpenError {
@@ -56,6 +69,10 @@ pub enum PublishSubscribeOpenError {
     IncompatibleTypes,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
+    /// The [`Service`] was created on a platform with a different endianness, pointer width or
+    /// alignment of the primitive types, see
+    /// [`PlatformDetails`](crate::service::static_config::platform_details::PlatformDetails).
+    IncompatiblePlatform,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// The [`Service`] has a lower minimum buffer size than requested.
@@ -104,6 +121,9 @@ impl From<ServiceAvailabilityState> for PublishSubscribeOpenError {
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatiblePlatform) => {
+                PublishSubscribeOpenError::IncompatiblePlatform
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
                 PublishSubscribeOpenError::InsufficientPermissions
             }
@@ -137,6 +157,9 @@ pub enum PublishSubscribeCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The payload alignment is greater than the page size. The alignment of the payload can
+    /// then not be guaranteed in every process that maps the data segment.
+    UnsupportedPayloadAlignment,
 }
 
 impl std::fmt::Display for PublishSubscribeCreateError {
@@ -151,7 +174,8 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
         match value {
             ServiceAvailabilityState::IncompatibleTypes
-            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern)
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatiblePlatform) => {
                 PublishSubscribeCreateError::AlreadyExists
             }
             ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
@@ -220,6 +244,8 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    payload_layout_hash: Option<u64>,
+    user_header_layout_hash: Option<u64>,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
@@ -227,6 +253,7 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
     verify_max_nodes: bool,
+    pub(crate) adopt_type_details: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -244,15 +271,20 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
             verify_max_nodes: false,
+            adopt_type_details: false,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            payload_layout_hash: None,
+            user_header_layout_hash: None,
             _data: PhantomData,
             _user_header: PhantomData,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
-            static_config::publish_subscribe::StaticConfig::new(new_self.base.shared_node.config()),
+            static_config::publish_subscribe::StaticConfig::new(
+                &new_self.base.shared_node.config(),
+            ),
         );
 
         new_self
@@ -283,10 +315,11 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !self
-                    .config_details()
-                    .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
+                if !self.adopt_type_details
+                    && !self
+                        .config_details()
+                        .message_type_details
+                        .is_compatible_to(&config.publish_subscribe().message_type_details)
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
@@ -300,8 +333,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         }
     }
 
-    /// Sets the user header type of the [`Service`].
-    pub fn user_header<M: Debug>(self) -> Builder<Payload, M, ServiceType> {
+    /// Sets the user header type of the [`Service`]. It must implement [`ZeroCopySend`], see
+    /// [`crate::service::builder::Builder::publish_subscribe()`].
+    pub fn user_header<M: Debug + ZeroCopySend>(mut self) -> Builder<Payload, M, ServiceType> {
+        self.user_header_layout_hash = None;
         unsafe { core::mem::transmute::<Self, Builder<Payload, M, ServiceType>>(self) }
     }
 
@@ -309,6 +344,12 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     /// an existing [`Service`] is opened it requires the service to have at least the defined
     /// [`Alignment`]. If the Payload [`Alignment`] is greater than the provided [`Alignment`]
     /// then the Payload [`Alignment`] is used.
+    ///
+    /// The [`Alignment`] applies to every loan of every
+    /// [`Publisher`](crate::port::publisher::Publisher), so that for instance page-aligned
+    /// payloads can be handed to DMA or GPU uploads. It can be at most the page size, otherwise
+    /// the creation fails with [`PublishSubscribeCreateError::UnsupportedPayloadAlignment`].
+    /// Every sample reserves up to `alignment - 1` additional bytes.
     pub fn payload_alignment(mut self, alignment: Alignment) -> Self {
         self.override_alignment = Some(alignment.value());
         self
@@ -367,6 +408,27 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created it defines up to which value the maximum amount of
+    /// [`crate::port::subscriber::Subscriber`] ports can be raised at runtime with
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::set_max_subscribers()`].
+    /// The dynamic service storage and the data segment of every
+    /// [`crate::port::publisher::Publisher`] are sized for this ceiling. It is not verified when
+    /// an existing [`Service`] is opened.
+    pub fn max_subscribers_ceiling(mut self, value: usize) -> Self {
+        self.config_details_mut().max_subscribers_ceiling = value;
+        self
+    }
+
+    /// If the [`Service`] is created it defines up to which value the maximum amount of
+    /// [`crate::port::publisher::Publisher`] ports can be raised at runtime with
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::set_max_publishers()`].
+    /// The dynamic service storage is sized for this ceiling. It is not verified when an
+    /// existing [`Service`] is opened.
+    pub fn max_publishers_ceiling(mut self, value: usize) -> Self {
+        self.config_details_mut().max_publishers_ceiling = value;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
     /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
     /// [`Node`](crate::node::Node)s must be at least supported.
@@ -376,6 +438,27 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created it defines if the data segments of its
+    /// [`crate::port::publisher::Publisher`]s are backed by huge pages. When huge pages are
+    /// unavailable, [`crate::config::PublishSubscribe::huge_pages_fallback`] decides whether
+    /// regular pages are used instead. It is not verified when an existing [`Service`] is
+    /// opened.
+    pub fn huge_pages(mut self, value: bool) -> Self {
+        self.config_details_mut().huge_pages = value;
+        self
+    }
+
+    /// If the [`Service`] is created it defines up to which value a
+    /// [`crate::port::publisher::Publisher`] can raise its maximum number of loaned samples at
+    /// runtime with [`crate::port::publisher::Publisher::set_max_loaned_samples()`]. The data
+    /// segment of every [`crate::port::publisher::Publisher`] is sized for this ceiling. It is
+    /// not verified when an existing [`Service`] is opened.
+    pub fn publisher_max_loaned_samples_ceiling(mut self, value: usize) -> Self {
+        self.config_details_mut()
+            .publisher_max_loaned_samples_ceiling = value;
+        self
+    }
+
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_attributes_to_meaningful_values(&mut self) {
         let origin = format!("{:?}", self);
@@ -505,6 +588,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self.adjust_attributes_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
+        let _log_context = self.base.shared_node.enter_log_context();
 
         if !self.config_details().enable_safe_overflow
             && (self.config_details().subscriber_max_buffer_size
@@ -514,6 +598,14 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
+        let payload_alignment = self.config_details().message_type_details.payload.alignment;
+        let page_size = SystemInfo::PageSize.value();
+        if page_size < payload_alignment {
+            fail!(from self, with PublishSubscribeCreateError::UnsupportedPayloadAlignment,
+                "{} since the payload alignment of {} is greater than the page size of {}.",
+                msg, payload_alignment, page_size);
+        }
+
         match self.is_service_available(msg)? {
             None => {
                 let service_tag = self
@@ -545,8 +637,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
 
                 // create dynamic config
                 let dynamic_config_setting = DynamicConfigSettings {
-                    number_of_publishers: pubsub_config.max_publishers,
-                    number_of_subscribers: pubsub_config.max_subscribers,
+                    number_of_publishers: pubsub_config.max_publishers_ceiling(),
+                    number_of_subscribers: pubsub_config.max_subscribers_ceiling(),
+                    max_publishers: pubsub_config.max_publishers,
+                    max_subscribers: pubsub_config.max_subscribers,
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -587,6 +681,12 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     service_tag.release_ownership();
                 }
 
+                discovery::publish_event(
+                    &self.base.shared_node,
+                    ServiceDiscoveryEventKind::Created,
+                    &self.base.service_config,
+                );
+
                 Ok(publish_subscribe::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -612,6 +712,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     > {
         const OPEN_RETRY_LIMIT: usize = 5;
         let msg = "Unable to open publish subscribe service";
+        let _log_context = self.base.shared_node.enter_log_context();
 
         let mut service_open_retry_count = 0;
         loop {
@@ -676,14 +777,42 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         }
     }
 
-    fn open_or_create_impl(
+    fn open_or_wait_impl(
+        &mut self,
+        attributes: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        let msg = "Unable to open publish subscribe service";
+        {
+            let _log_context = self.base.shared_node.enter_log_context();
+            let is_available = self
+                .base
+                .wait_until_service_is_available(msg, timeout)
+                .map_err(ServiceAvailabilityState::ServiceState)?;
+            if !is_available {
+                fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                    "{} since the service was not created within the timeout of {:?}.", msg, timeout);
+            }
+        }
+
+        self.open_impl(attributes)
+    }
+
+    fn open_or_create_impl<
+        F: FnOnce(&publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>),
+    >(
         mut self,
         attributes: &AttributeVerifier,
+        on_create: F,
     ) -> Result<
         publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
+        let _log_context = self.base.shared_node.enter_log_context();
 
         loop {
             match self.is_service_available(msg)? {
@@ -694,7 +823,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                 },
                 None => {
                     match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
-                        Ok(factory) => return Ok(factory),
+                        Ok(factory) => {
+                            on_create(&factory);
+                            return Ok(factory);
+                        }
                         Err(PublishSubscribeCreateError::AlreadyExists)
                         | Err(PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
@@ -719,6 +851,62 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                 .max(alignment);
         }
     }
+
+    fn apply_layout_hashes(&mut self) {
+        if let Some(hash) = self.payload_layout_hash {
+            self.config_details_mut()
+                .message_type_details
+                .payload
+                .layout_hash = Some(hash);
+        }
+
+        if let Some(hash) = self.user_header_layout_hash {
+            self.config_details_mut()
+                .message_type_details
+                .user_header
+                .layout_hash = Some(hash);
+        }
+    }
+}
+
+impl<
+        Payload: Debug + ?Sized + TypeLayoutHash,
+        UserHeader: Debug + TypeLayoutHash,
+        ServiceType: service::Service,
+    > Builder<Payload, UserHeader, ServiceType>
+{
+    /// Stores the [`TypeLayoutHash`] of the payload and the user header in the [`Service`] when
+    /// it is created. When an existing [`Service`] is opened, that stores a
+    /// [`TypeLayoutHash`] as well, the hashes must be equal, otherwise the open process fails
+    /// with [`PublishSubscribeOpenError::IncompatibleTypes`]. This detects processes that use
+    /// types with the same name but a different definition.
+    ///
+    /// Must be called after [`Builder::user_header()`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, TypeLayoutHash, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct MyPayload {
+    ///     value: u64,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<MyPayload>()
+    ///     .verify_type_layout()
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_type_layout(mut self) -> Self {
+        self.payload_layout_hash = Some(Payload::TYPE_LAYOUT_HASH);
+        self.user_header_layout_hash = Some(UserHeader::TYPE_LAYOUT_HASH);
+        self
+    }
 }
 
 impl<UserHeader: Debug, ServiceType: service::Service>
@@ -741,6 +929,22 @@ impl<Payload: Debug + ?Sized, ServiceType: service::Service>
     }
 }
 
+impl<ServiceType: service::Service>
+    Builder<[CustomPayloadMarker], CustomHeaderMarker, ServiceType>
+{
+    /// Defines the [`MessageTypeDetails`] of the payload and the user header. If the [`Service`]
+    /// is created, they are stored in the [`Service`]. If an existing [`Service`] is opened, it
+    /// must have compatible [`MessageTypeDetails`]. Without this call, an opened [`Service`]
+    /// adopts the stored details and a created [`Service`] transmits plain bytes without user
+    /// header.
+    pub fn message_type_details(mut self, value: &MessageTypeDetails) -> Self {
+        self.override_payload_type = Some(value.payload.clone());
+        self.override_user_header_type = Some(value.user_header.clone());
+        self.adopt_type_details = false;
+        self
+    }
+}
+
 impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     Builder<Payload, UserHeader, ServiceType>
 {
@@ -756,6 +960,7 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.apply_layout_hashes();
         self.adjust_payload_alignment();
     }
 
@@ -784,7 +989,57 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
         PublishSubscribeOpenOrCreateError,
     > {
         self.prepare_config_details();
-        self.open_or_create_impl(required_attributes)
+        self.open_or_create_impl(required_attributes, |_| {})
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. When the [`Service`] is created, `on_create` is called with the new
+    /// [`publish_subscribe::PortFactory`] before it is returned, e.g. to publish an initial
+    /// state. Since only one process can create the [`Service`], `on_create` is called exactly
+    /// once across all processes that race for the [`Service`]. Other processes may already
+    /// open the [`Service`] while `on_create` is running.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create_with_hook(|service| {
+    ///         println!("this process created the service {}", service.name());
+    ///     })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_or_create_with_hook<
+        F: FnOnce(&publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>),
+    >(
+        self,
+        on_create: F,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes_and_hook(&AttributeVerifier::new(), on_create)
+    }
+
+    /// Combines [`Builder::open_or_create_with_attributes()`] and
+    /// [`Builder::open_or_create_with_hook()`].
+    pub fn open_or_create_with_attributes_and_hook<
+        F: FnOnce(&publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>),
+    >(
+        mut self,
+        required_attributes: &AttributeVerifier,
+        on_create: F,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(required_attributes, on_create)
     }
 
     /// Opens an existing [`Service`].
@@ -810,6 +1065,34 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
         self.open_impl(required_attributes)
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until it
+    /// is created by another process or until the timeout has passed. When the timeout has
+    /// passed or a termination request was received, it fails with
+    /// [`PublishSubscribeOpenError::DoesNotExist`].
+    pub fn open_or_wait(
+        self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes_or_wait(&AttributeVerifier::new(), timeout)
+    }
+
+    /// Same as [`Builder::open_or_wait()`] but with attribute requirements. If the defined
+    /// attribute requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes_or_wait(
+        mut self,
+        required_attributes: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_or_wait_impl(required_attributes, timeout)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -848,6 +1131,7 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.apply_layout_hashes();
         self.adjust_payload_alignment();
     }
 
@@ -874,7 +1158,39 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
         PublishSubscribeOpenOrCreateError,
     > {
         self.prepare_config_details();
-        self.open_or_create_impl(attributes)
+        self.open_or_create_impl(attributes, |_| {})
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. When the [`Service`] is created, `on_create` is called exactly once with the
+    /// new [`publish_subscribe::PortFactory`] before it is returned. Other processes may
+    /// already open the [`Service`] while `on_create` is running.
+    pub fn open_or_create_with_hook<
+        F: FnOnce(&publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>),
+    >(
+        self,
+        on_create: F,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes_and_hook(&AttributeVerifier::new(), on_create)
+    }
+
+    /// Combines [`Builder::open_or_create_with_attributes()`] and
+    /// [`Builder::open_or_create_with_hook()`].
+    pub fn open_or_create_with_attributes_and_hook<
+        F: FnOnce(&publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>),
+    >(
+        mut self,
+        attributes: &AttributeVerifier,
+        on_create: F,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(attributes, on_create)
     }
 
     /// Opens an existing [`Service`].
@@ -900,6 +1216,34 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
         self.open_impl(attributes)
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until it
+    /// is created by another process or until the timeout has passed. When the timeout has
+    /// passed or a termination request was received, it fails with
+    /// [`PublishSubscribeOpenError::DoesNotExist`].
+    pub fn open_or_wait(
+        self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes_or_wait(&AttributeVerifier::new(), timeout)
+    }
+
+    /// Same as [`Builder::open_or_wait()`] but with attribute requirements. If the defined
+    /// attribute requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes_or_wait(
+        mut self,
+        attributes: &AttributeVerifier,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_or_wait_impl(attributes, timeout)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -10,15 +10,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::{config, node::NodeId};
+use crate::{config, node::NodeId, service::internal::ServiceInternal};
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
 
+fn service_prefix<Service: crate::service::Service>(global_config: &config::Config) -> FileName {
+    <Service as ServiceInternal<Service>>::__internal_resource_prefix(&global_config.global.prefix)
+}
+
 pub(crate) fn dynamic_config_storage_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::DynamicStorage as NamedConceptMgmt>::Configuration {
     <<Service::DynamicStorage as NamedConceptMgmt>::Configuration>::default()
-        .prefix(&global_config.global.prefix)
+        .prefix(&service_prefix::<Service>(global_config))
         .suffix(&global_config.global.service.dynamic_config_storage_suffix)
         .path_hint(global_config.global.root_path())
 }
@@ -34,7 +39,7 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
             msg, path_hint, global_config.global.service.directory);
 
     <<Service::StaticStorage as NamedConceptMgmt>::Configuration>::default()
-        .prefix(&global_config.global.prefix)
+        .prefix(&service_prefix::<Service>(global_config))
         .suffix(&global_config.global.service.static_config_storage_suffix)
         .path_hint(&path_hint)
 }
@@ -43,7 +48,7 @@ pub(crate) fn connection_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
     <<Service::Connection as NamedConceptMgmt>::Configuration>::default()
-        .prefix(&global_config.global.prefix)
+        .prefix(&service_prefix::<Service>(global_config))
         .suffix(&global_config.global.service.connection_suffix)
         .path_hint(global_config.global.root_path())
 }
@@ -52,7 +57,7 @@ pub(crate) fn event_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Event as NamedConceptMgmt>::Configuration {
     <<Service::Event as NamedConceptMgmt>::Configuration>::default()
-        .prefix(&global_config.global.prefix)
+        .prefix(&service_prefix::<Service>(global_config))
         .suffix(&global_config.global.service.event_connection_suffix)
         .path_hint(global_config.global.root_path())
 }
@@ -61,11 +66,29 @@ pub(crate) fn data_segment_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
     <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
-        .prefix(&global_config.global.prefix)
+        .prefix(&service_prefix::<Service>(global_config))
         .suffix(&global_config.global.service.publisher_data_segment_suffix)
         .path_hint(global_config.global.root_path())
 }
 
+pub(crate) fn data_segment_growth_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::GrowthStorage as NamedConceptMgmt>::Configuration {
+    <<Service::GrowthStorage as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&service_prefix::<Service>(global_config))
+        .suffix(&global_config.global.service.publisher_data_segment_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
+pub(crate) fn event_payload_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::EventPayloadStorage as NamedConceptMgmt>::Configuration {
+    <<Service::EventPayloadStorage as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&service_prefix::<Service>(global_config))
+        .suffix(&global_config.global.service.event_payload_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Monitoring as NamedConceptMgmt>::Configuration {
@@ -96,6 +119,33 @@ pub(crate) fn node_details_config<Service: crate::service::Service>(
         .path_hint(&node_details_path(global_config, node_id))
 }
 
+pub(crate) fn node_heartbeat_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::HeartbeatStorage as NamedConceptMgmt>::Configuration {
+    <<Service::HeartbeatStorage as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.heartbeat_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
+pub(crate) fn instance_marker_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::InstanceStorage as NamedConceptMgmt>::Configuration {
+    <<Service::InstanceStorage as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.instance_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
+pub(crate) fn instance_identity_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    <<Service::StaticStorage as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.instance_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn service_tag_config<Service: crate::service::Service>(
     global_config: &config::Config,
     node_id: &NodeId,
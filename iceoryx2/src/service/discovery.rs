@@ -0,0 +1,299 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::discovery::ServiceDiscoveryEventKind;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let discovery = node.service_discovery()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .event()
+//!     .create()?;
+//!
+//! while let Some(event) = discovery.receive()? {
+//!     match event.kind() {
+//!         ServiceDiscoveryEventKind::Created => println!("created: {:?}", event.service_id()),
+//!         ServiceDiscoveryEventKind::Removed => println!("removed: {:?}", event.service_id()),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::warn;
+
+use crate::config::Config;
+use crate::node::{NodeBuilder, SharedNode};
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::SubscriberCreateError;
+use crate::service::builder::publish_subscribe::{
+    PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+};
+use crate::service::builder::Builder;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::messaging_pattern::MessagingPattern as MessagingPatternConfig;
+use crate::service::static_config::StaticConfig;
+
+const SERVICE_DISCOVERY_SERVICE_NAME: &str = "iox2://service_discovery";
+const SERVICE_DISCOVERY_SUBSCRIBER_MAX_BUFFER_SIZE: usize = 64;
+
+/// Defines what happened to the [`Service`](crate::service::Service) a
+/// [`ServiceDiscoveryEvent`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ServiceDiscoveryEventKind {
+    /// The [`Service`](crate::service::Service) was created.
+    Created,
+    /// The last [`Node`](crate::node::Node) that owned the
+    /// [`Service`](crate::service::Service) released it and the
+    /// [`Service`](crate::service::Service) was removed.
+    Removed,
+}
+
+/// Emitted by the built-in service discovery whenever a [`Service`](crate::service::Service)
+/// is created or removed. It can be received with the subscriber returned by
+/// [`Node::service_discovery()`](crate::node::Node::service_discovery()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct ServiceDiscoveryEvent {
+    kind: ServiceDiscoveryEventKind,
+    service_id: ServiceId,
+    messaging_pattern: MessagingPattern,
+}
+
+// the ServiceId is stored in a fixed size string and the MessagingPattern is a plain enum,
+// therefore the event does not refer to any memory outside of itself
+unsafe impl ZeroCopySend for ServiceDiscoveryEvent {}
+
+impl ServiceDiscoveryEvent {
+    fn new(kind: ServiceDiscoveryEventKind, static_config: &StaticConfig) -> Self {
+        Self {
+            kind,
+            service_id: static_config.service_id().clone(),
+            messaging_pattern: messaging_pattern_of(static_config),
+        }
+    }
+
+    /// Returns the [`ServiceDiscoveryEventKind`].
+    pub fn kind(&self) -> ServiceDiscoveryEventKind {
+        self.kind
+    }
+
+    /// Returns the [`ServiceId`] of the [`Service`](crate::service::Service).
+    pub fn service_id(&self) -> &ServiceId {
+        &self.service_id
+    }
+
+    /// Returns the [`MessagingPattern`] of the [`Service`](crate::service::Service).
+    pub fn messaging_pattern(&self) -> MessagingPattern {
+        self.messaging_pattern
+    }
+}
+
+/// Failures that can occur when the service discovery subscriber is created with
+/// [`Node::service_discovery()`](crate::node::Node::service_discovery()).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ServiceDiscoveryCreateError {
+    /// The built-in service discovery service could not be opened or created.
+    ServiceUnavailable(PublishSubscribeOpenOrCreateError),
+    /// The subscriber of the built-in service discovery service could not be created.
+    SubscriberCreateError(SubscriberCreateError),
+}
+
+impl From<PublishSubscribeOpenOrCreateError> for ServiceDiscoveryCreateError {
+    fn from(value: PublishSubscribeOpenOrCreateError) -> Self {
+        Self::ServiceUnavailable(value)
+    }
+}
+
+impl From<SubscriberCreateError> for ServiceDiscoveryCreateError {
+    fn from(value: SubscriberCreateError) -> Self {
+        Self::SubscriberCreateError(value)
+    }
+}
+
+impl std::fmt::Display for ServiceDiscoveryCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServiceDiscoveryCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServiceDiscoveryCreateError {}
+
+fn messaging_pattern_of(static_config: &StaticConfig) -> MessagingPattern {
+    match static_config.messaging_pattern() {
+        MessagingPatternConfig::PublishSubscribe(_) => MessagingPattern::PublishSubscribe,
+        MessagingPatternConfig::Event(_) => MessagingPattern::Event,
+        MessagingPatternConfig::Blackboard(_) => MessagingPattern::Blackboard,
+    }
+}
+
+fn service_name() -> ServiceName {
+    ServiceName::new(SERVICE_DISCOVERY_SERVICE_NAME)
+        .expect("The service discovery service name is always valid.")
+}
+
+fn open_service<Service: crate::service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+) -> Result<PortFactory<Service, ServiceDiscoveryEvent, ()>, PublishSubscribeOpenError> {
+    Builder::new(&service_name(), shared_node.clone())
+        .publish_subscribe::<ServiceDiscoveryEvent>()
+        .open()
+}
+
+pub(crate) fn open_or_create_service<Service: crate::service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+) -> Result<PortFactory<Service, ServiceDiscoveryEvent, ()>, PublishSubscribeOpenOrCreateError> {
+    let max_nodes = shared_node.config().defaults.publish_subscribe.max_nodes;
+
+    Builder::new(&service_name(), shared_node.clone())
+        .publish_subscribe::<ServiceDiscoveryEvent>()
+        .max_nodes(max_nodes)
+        .max_publishers(max_nodes)
+        .history_size(0)
+        .subscriber_max_buffer_size(SERVICE_DISCOVERY_SUBSCRIBER_MAX_BUFFER_SIZE)
+        .open_or_create()
+}
+
+#[derive(Debug)]
+enum State<Service: crate::service::Service> {
+    Uninitialized,
+    Active(Publisher<Service, ServiceDiscoveryEvent, ()>),
+    Closed,
+}
+
+/// Every [`Node`](crate::node::Node) lazily creates one publisher for the built-in service
+/// discovery service when it creates or removes a service while the service discovery service
+/// exists.
+#[derive(Debug)]
+pub(crate) struct ServiceDiscoveryPublisher<Service: crate::service::Service> {
+    state: Mutex<State<Service>>,
+}
+
+// The publisher is only accessed while holding the mutex, therefore it is never used
+// concurrently from multiple threads.
+unsafe impl<Service: crate::service::Service> Send for ServiceDiscoveryPublisher<Service> {}
+unsafe impl<Service: crate::service::Service> Sync for ServiceDiscoveryPublisher<Service> {}
+
+impl<Service: crate::service::Service> ServiceDiscoveryPublisher<Service> {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(State::Uninitialized),
+        }
+    }
+
+    /// The publisher owns the [`SharedNode`] indirectly via its service. Therefore it has to be
+    /// released explicitly when the [`Node`](crate::node::Node) goes out of scope.
+    pub(crate) fn close(&self) {
+        let state = std::mem::replace(&mut *self.state.lock().unwrap(), State::Closed);
+        drop(state);
+    }
+}
+
+pub(crate) fn publish_event<Service: crate::service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+    kind: ServiceDiscoveryEventKind,
+    static_config: &StaticConfig,
+) {
+    let origin = "service::discovery::publish_event()";
+    if static_config.name().as_str() == SERVICE_DISCOVERY_SERVICE_NAME
+        && messaging_pattern_of(static_config) == MessagingPattern::PublishSubscribe
+    {
+        return;
+    }
+
+    let mut state = shared_node
+        .service_discovery_publisher()
+        .state
+        .lock()
+        .unwrap();
+    if let State::Uninitialized = *state {
+        // nobody is interested in service discovery events as long as the service discovery
+        // service was not created by a subscriber, see Node::service_discovery()
+        let service = match open_service(shared_node) {
+            Ok(service) => service,
+            Err(PublishSubscribeOpenError::DoesNotExist) => return,
+            Err(e) => {
+                warn!(from origin,
+                    "Unable to publish the service discovery event {:?} for service {:?} since the service discovery service could not be opened ({:?}). Increase the config entry 'defaults.publish-subscribe.max-nodes' when the number of nodes is exceeded.",
+                    kind, static_config.service_id(), e);
+                return;
+            }
+        };
+
+        match service.publisher_builder().create() {
+            Ok(publisher) => *state = State::Active(publisher),
+            Err(e) => {
+                warn!(from origin,
+                    "Unable to publish the service discovery event {:?} for service {:?} since the service discovery publisher could not be created ({:?}). Increase the config entry 'defaults.publish-subscribe.max-nodes' when the number of publishers is exceeded.",
+                    kind, static_config.service_id(), e);
+                return;
+            }
+        }
+    }
+
+    if let State::Active(ref publisher) = *state {
+        if let Err(e) = publisher.send_copy(ServiceDiscoveryEvent::new(kind, static_config)) {
+            warn!(from origin, "Unable to send service discovery event {:?} for service {:?} ({:?}).",
+                kind, static_config.service_id(), e);
+        }
+    }
+}
+
+/// Publishes the [`ServiceDiscoveryEventKind::Removed`] event of a service that was removed
+/// by the cleanup of a dead [`Node`](crate::node::Node). The cleanup does not own a node,
+/// therefore a short-lived node is created to publish the event.
+pub(crate) fn publish_removed_event_of_dead_node_service<Service: crate::service::Service>(
+    config: &Config,
+    static_config: &StaticConfig,
+) {
+    let origin = "service::discovery::publish_removed_event_of_dead_node_service()";
+    match Service::does_exist(&service_name(), config, MessagingPattern::PublishSubscribe) {
+        Ok(true) => (),
+        Ok(false) => return,
+        Err(e) => {
+            warn!(from origin,
+                "Unable to publish the service discovery event {:?} for service {:?} since the existence of the service discovery service could not be verified ({:?}).",
+                ServiceDiscoveryEventKind::Removed, static_config.service_id(), e);
+            return;
+        }
+    }
+
+    // the node is created during a dead node cleanup, it must not start another one
+    let mut node_config = config.clone();
+    node_config.global.node.cleanup_dead_nodes_on_creation = false;
+    node_config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+    match NodeBuilder::new().config(&node_config).create::<Service>() {
+        Ok(node) => publish_event(
+            node.shared_node(),
+            ServiceDiscoveryEventKind::Removed,
+            static_config,
+        ),
+        Err(e) => {
+            warn!(from origin,
+                "Unable to publish the service discovery event {:?} for service {:?} since the publishing node could not be created ({:?}).",
+                ServiceDiscoveryEventKind::Removed, static_config.service_id(), e);
+        }
+    }
+}
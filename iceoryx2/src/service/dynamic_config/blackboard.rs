@@ -0,0 +1,475 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"MyBlackboardName".try_into()?)
+//!     .blackboard::<u64, u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+
+use crate::{
+    node::NodeId,
+    port::port_identifiers::{UniquePortId, UniqueReaderId, UniqueWriterId},
+};
+
+use super::PortCleanupAction;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_writers: usize,
+    pub number_of_entries: usize,
+    pub number_of_value_slots: usize,
+    pub entry_layout: Layout,
+}
+
+const SLOT_IS_UPDATED: u64 = 1 << 63;
+
+/// A value of an [`Entry`] together with the number of [`crate::port::reader::Reader`]s that
+/// currently reference it. The [`crate::port::writer::Writer`] only updates a slot that is
+/// neither referenced nor the current value of the [`Entry`].
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct ValueSlot<ValueType: Copy> {
+    state: IoxAtomicU64,
+    value: UnsafeCell<ValueType>,
+}
+
+impl<ValueType: Copy> ValueSlot<ValueType> {
+    pub(crate) fn value(&self) -> &ValueType {
+        unsafe { &*self.value.get() }
+    }
+
+    /// Releases a slot that was acquired with [`Entry::acquire()`].
+    pub(crate) fn release(&self) {
+        self.state.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// A single key-value pair of the blackboard. It is followed in memory by the
+/// [`ValueSlot`]s of the entry, the current one contains the latest value. A
+/// [`crate::port::reader::Reader`] references the current slot as long as it
+/// reads the value, the [`crate::port::writer::Writer`] writes an update into
+/// another slot and makes it the current one afterwards. Therefore, readers never
+/// wait on the writer, even when it died during an update.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct Entry<KeyType: Copy, ValueType: Copy> {
+    key: KeyType,
+    current_slot: IoxAtomicUsize,
+    number_of_value_slots: usize,
+    _value: PhantomData<ValueType>,
+}
+
+impl<KeyType: Copy, ValueType: Copy> Entry<KeyType, ValueType> {
+    /// Returns the layout of an entry followed by its `number_of_value_slots` [`ValueSlot`]s.
+    pub(crate) fn layout(number_of_value_slots: usize) -> Layout {
+        let slots = fatal_panic!(from "blackboard::Entry::layout",
+            when Layout::array::<ValueSlot<ValueType>>(number_of_value_slots),
+            "This should never happen! The value slots of a blackboard entry exceed the maximum layout size.");
+        let (layout, _) = fatal_panic!(from "blackboard::Entry::layout",
+            when Layout::new::<Self>().extend(slots),
+            "This should never happen! A blackboard entry exceeds the maximum layout size.");
+        layout.pad_to_align()
+    }
+
+    fn value_slots_offset() -> usize {
+        Layout::new::<Self>()
+            .extend(Layout::new::<ValueSlot<ValueType>>())
+            .map_or(0, |(_, offset)| offset)
+    }
+
+    /// Writes a new entry with `number_of_value_slots` [`ValueSlot`]s to `ptr`.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must point to memory with the layout of [`Entry::layout()`]
+    unsafe fn init(ptr: *mut Self, key: KeyType, value: ValueType, number_of_value_slots: usize) {
+        ptr.write(Self {
+            key,
+            current_slot: IoxAtomicUsize::new(0),
+            number_of_value_slots,
+            _value: PhantomData,
+        });
+
+        let slots = (ptr as *mut u8).add(Self::value_slots_offset()) as *mut ValueSlot<ValueType>;
+        for n in 0..number_of_value_slots {
+            slots.add(n).write(ValueSlot {
+                state: IoxAtomicU64::new(0),
+                value: UnsafeCell::new(value),
+            });
+        }
+    }
+
+    fn value_slot(&self, index: usize) -> &ValueSlot<ValueType> {
+        debug_assert!(index < self.number_of_value_slots);
+        unsafe {
+            &*((self as *const Self as *const u8).add(Self::value_slots_offset())
+                as *const ValueSlot<ValueType>)
+                .add(index)
+        }
+    }
+
+    pub(crate) fn key(&self) -> &KeyType {
+        &self.key
+    }
+
+    /// Acquires the [`ValueSlot`] with the latest value. It is not updated until it is released
+    /// with [`ValueSlot::release()`]. It only repeats when the writer replaced the current slot
+    /// concurrently.
+    pub(crate) fn acquire(&self) -> &ValueSlot<ValueType> {
+        loop {
+            let slot = self.value_slot(self.current_slot.load(Ordering::Acquire));
+            let state = slot.state.load(Ordering::Relaxed);
+            if state & SLOT_IS_UPDATED != 0 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            if slot
+                .state
+                .compare_exchange_weak(state, state + 1, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return slot;
+            }
+        }
+    }
+
+    /// Stores a new value in a slot that is not referenced by any reader and makes it the
+    /// current one. Returns false when every other slot is still referenced.
+    ///
+    /// # Safety
+    ///
+    ///  * must not be called concurrently, only the single [`crate::port::writer::Writer`]
+    ///    is allowed to update the entry
+    pub(crate) unsafe fn store(&self, value: ValueType) -> bool {
+        let current_slot = self.current_slot.load(Ordering::Relaxed);
+        for index in (0..self.number_of_value_slots).filter(|index| *index != current_slot) {
+            let slot = self.value_slot(index);
+            if slot
+                .state
+                .compare_exchange(0, SLOT_IS_UPDATED, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                continue;
+            }
+
+            slot.value.get().write(value);
+            slot.state.store(0, Ordering::Release);
+            self.current_slot.store(index, Ordering::Release);
+            return true;
+        }
+
+        false
+    }
+
+    /// Releases the slots of an update that was interrupted since the previous
+    /// [`crate::port::writer::Writer`] died.
+    ///
+    /// # Safety
+    ///
+    ///  * must only be called when no other [`crate::port::writer::Writer`] exists
+    pub(crate) unsafe fn release_interrupted_updates(&self) {
+        for index in 0..self.number_of_value_slots {
+            let _ = self.value_slot(index).state.compare_exchange(
+                SLOT_IS_UPDATED,
+                0,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
+}
+
+/// Iterates over all [`Entry`]s of the blackboard, see [`DynamicConfig::entries()`].
+pub(crate) struct Entries<'a, KeyType: Copy, ValueType: Copy> {
+    ptr: *const u8,
+    entry_size: usize,
+    remaining: usize,
+    _entry: PhantomData<&'a Entry<KeyType, ValueType>>,
+}
+
+impl<'a, KeyType: Copy, ValueType: Copy> Iterator for Entries<'a, KeyType, ValueType> {
+    type Item = &'a Entry<KeyType, ValueType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let entry = unsafe { &*(self.ptr as *const Entry<KeyType, ValueType>) };
+        self.ptr = unsafe { self.ptr.add(self.entry_size) };
+        self.remaining -= 1;
+        Some(entry)
+    }
+}
+
+/// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<ReaderDetails>,
+    pub(crate) writers: Container<WriterDetails>,
+    entries: RelocatablePointer<u8>,
+    number_of_entries: usize,
+    number_of_value_slots: usize,
+    entry_layout: Layout,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReaderDetails {
+    pub(crate) reader_id: UniqueReaderId,
+    pub(crate) node_id: NodeId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WriterDetails {
+    pub(crate) writer_id: UniqueWriterId,
+    pub(crate) node_id: NodeId,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+            entries: unsafe { RelocatablePointer::new_uninit() },
+            number_of_entries: config.number_of_entries,
+            number_of_value_slots: config.number_of_value_slots,
+            entry_layout: config.entry_layout,
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+        self.entries.init(fatal_panic!(from "blackboard::DynamicConfig::init",
+            when allocator.allocate(Self::entries_layout(self.entry_layout, self.number_of_entries)),
+            "This should never happen! Unable to allocate the memory for the blackboard entries."));
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ReaderDetails>::memory_size(config.number_of_readers)
+            + Container::<WriterDetails>::memory_size(config.number_of_writers)
+            + config.entry_layout.size() * config.number_of_entries
+            + config.entry_layout.align()
+            - 1
+    }
+
+    fn entries_layout(entry_layout: Layout, number_of_entries: usize) -> Layout {
+        unsafe {
+            Layout::from_size_align_unchecked(
+                entry_layout.size() * number_of_entries,
+                entry_layout.align(),
+            )
+        }
+    }
+
+    /// Writes the initial key-value pairs into the blackboard.
+    ///
+    /// # Safety
+    ///
+    ///  * must be called exactly once by the creator of the service before the service
+    ///    becomes accessible to other instances
+    ///  * `Entry<KeyType, ValueType>` must be the entry type the [`DynamicConfig`] was created
+    ///    with
+    ///  * `entries.len()` must be equal to the number of entries
+    pub(crate) unsafe fn init_entries<KeyType: Copy, ValueType: Copy>(
+        &self,
+        entries: &[(KeyType, ValueType)],
+    ) {
+        debug_assert!(entries.len() == self.number_of_entries);
+        debug_assert!(
+            Entry::<KeyType, ValueType>::layout(self.number_of_value_slots) == self.entry_layout
+        );
+
+        let ptr = self.entries.as_ptr() as *mut u8;
+        for (i, (key, value)) in entries.iter().enumerate() {
+            Entry::init(
+                ptr.add(i * self.entry_layout.size()) as *mut Entry<KeyType, ValueType>,
+                *key,
+                *value,
+                self.number_of_value_slots,
+            );
+        }
+    }
+
+    /// Returns all entries of the blackboard.
+    ///
+    /// # Safety
+    ///
+    ///  * the entries must have been initialized with [`DynamicConfig::init_entries()`]
+    ///  * `KeyType` and `ValueType` must be the types the entries were initialized with
+    pub(crate) unsafe fn entries<KeyType: Copy, ValueType: Copy>(
+        &self,
+    ) -> Entries<'_, KeyType, ValueType> {
+        debug_assert!(
+            Entry::<KeyType, ValueType>::layout(self.number_of_value_slots) == self.entry_layout
+        );
+
+        Entries {
+            ptr: self.entries.as_ptr(),
+            entry_size: self.entry_layout.size(),
+            remaining: self.number_of_entries,
+            _entry: PhantomData,
+        }
+    }
+
+    /// Returns the how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns the how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_reader_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.readers.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_writer_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.writers.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.readers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_reader| {
+                if registered_reader.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Reader(registered_reader.reader_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_reader_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.writers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_writer| {
+                if registered_writer.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Writer(registered_writer.writer_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_writer_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) fn add_reader_id(&self, id: ReaderDetails) -> Option<ContainerHandle> {
+        unsafe { self.readers.add(id).ok() }
+    }
+
+    pub(crate) fn release_reader_handle(&self, handle: ContainerHandle) {
+        unsafe { self.readers.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_writer_id(&self, id: WriterDetails) -> Option<ContainerHandle> {
+        unsafe { self.writers.add(id).ok() }
+    }
+
+    pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.writers.remove(handle, ReleaseMode::Default) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::*;
+
+    #[test]
+    fn entry_can_be_read_while_an_update_was_interrupted() {
+        const NUMBER_OF_VALUE_SLOTS: usize = 3;
+        let layout = Entry::<u64, u64>::layout(NUMBER_OF_VALUE_SLOTS);
+        let memory = unsafe { std::alloc::alloc(layout) } as *mut Entry<u64, u64>;
+        unsafe { Entry::init(memory, 0, 12, NUMBER_OF_VALUE_SLOTS) };
+        let sut = unsafe { &*memory };
+
+        // a writer that died during an update leaves the slot marked as updated
+        assert_that!(
+            sut.value_slot(1).state.compare_exchange(
+                0,
+                SLOT_IS_UPDATED,
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            is_ok
+        );
+
+        let slot = sut.acquire();
+        assert_that!(*slot.value(), eq 12);
+        slot.release();
+
+        unsafe { sut.release_interrupted_updates() };
+        assert_that!(unsafe { sut.store(34) }, eq true);
+        assert_that!(unsafe { sut.store(56) }, eq true);
+
+        let slot = sut.acquire();
+        assert_that!(*slot.value(), eq 56);
+        slot.release();
+
+        unsafe { std::alloc::dealloc(memory as *mut u8, layout) };
+    }
+}
@@ -26,13 +26,19 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::time::Duration;
+use std::sync::atomic::Ordering;
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
     node::NodeId,
+    port::event_id::EventIdFilter,
     port::port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
 };
 
@@ -50,12 +56,18 @@ pub(crate) struct DynamicConfigSettings {
 pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
+    // the time of the last notification in nanoseconds, initialized with the creation time of
+    // the service
+    last_notification: IoxAtomicU64,
+    // the missed deadlines up to the last notification
+    missed_deadlines: IoxAtomicU64,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ListenerDetails {
     pub(crate) listener_id: UniqueListenerId,
     pub(crate) node_id: NodeId,
+    pub(crate) event_id_filter: EventIdFilter,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -69,9 +81,38 @@ impl DynamicConfig {
         Self {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
+            last_notification: IoxAtomicU64::new(Self::now()),
+            missed_deadlines: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn now() -> u64 {
+        let now = fatal_panic!(from "event::DynamicConfig::now()",
+                    when Time::now(),
+                    "This should never happen! Unable to acquire the current time.");
+        now.as_duration().as_nanos() as u64
+    }
+
+    /// Records a notification and counts every deadline period that has passed since the
+    /// previous notification as missed.
+    pub(crate) fn record_notification(&self, deadline: Duration) {
+        let now = Self::now();
+        let previous = self.last_notification.swap(now, Ordering::Relaxed);
+        let missed = now.saturating_sub(previous) / (deadline.as_nanos() as u64).max(1);
+        if missed != 0 {
+            self.missed_deadlines.fetch_add(missed, Ordering::Relaxed);
         }
     }
 
+    /// Returns the number of missed deadlines since the creation of the service, including the
+    /// ones that passed since the last notification.
+    pub(crate) fn missed_deadlines(&self, deadline: Duration) -> u64 {
+        let now = Self::now();
+        let last_notification = self.last_notification.load(Ordering::Relaxed);
+        self.missed_deadlines.load(Ordering::Relaxed)
+            + now.saturating_sub(last_notification) / (deadline.as_nanos() as u64).max(1)
+    }
+
     pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.listeners.init(allocator),
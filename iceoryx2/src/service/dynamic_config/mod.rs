@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service.
@@ -32,6 +37,8 @@ use std::fmt::Display;
 
 use crate::{node::NodeId, port::port_identifiers::UniquePortId};
 
+use super::statistics::StatisticsCounters;
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum PortCleanupAction {
     RemovePort,
@@ -58,6 +65,7 @@ pub(crate) enum RemoveDeadNodeResult {
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
 }
 
 #[doc(hidden)]
@@ -65,6 +73,7 @@ pub(crate) enum MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     nodes: Container<NodeId>,
+    statistics: StatisticsCounters,
 }
 
 impl Display for DynamicConfig {
@@ -85,6 +94,7 @@ impl DynamicConfig {
         Self {
             messaging_pattern,
             nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
+            statistics: StatisticsCounters::new(),
         }
     }
 
@@ -98,6 +108,7 @@ impl DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
         }
     }
 
@@ -106,13 +117,24 @@ impl DynamicConfig {
     >(
         &self,
         node_id: &NodeId,
-        port_cleanup_callback: PortCleanup,
+        mut port_cleanup_callback: PortCleanup,
     ) -> Result<DeregisterNodeState, RemoveDeadNodeResult> {
+        let port_cleanup_callback = |port_id: UniquePortId| {
+            let action = port_cleanup_callback(port_id);
+            if action == PortCleanupAction::RemovePort {
+                self.statistics.add_disconnected_port();
+            }
+            action
+        };
+
         match self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => {
                 v.remove_dead_node_id(node_id, port_cleanup_callback)
             }
             MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
+            MessagingPattern::Blackboard(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
         };
 
         let mut ret_val = Err(RemoveDeadNodeResult::NodeNotRegistered);
@@ -167,6 +189,10 @@ impl DynamicConfig {
         }
     }
 
+    pub(crate) fn statistics(&self) -> &StatisticsCounters {
+        &self.statistics
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -184,4 +210,13 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
 }
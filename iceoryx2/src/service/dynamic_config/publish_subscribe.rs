@@ -26,14 +26,20 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::Ordering;
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::{
     node::NodeId,
+    port::details::data_segment::GrowthLayout,
     port::port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
+    service::port_factory::publisher::PublisherPriority,
+    service::port_factory::subscriber::SubscriberOverflowStrategy,
 };
 
 use super::PortCleanupAction;
@@ -42,6 +48,8 @@ use super::PortCleanupAction;
 pub(crate) struct DynamicConfigSettings {
     pub number_of_subscribers: usize,
     pub number_of_publishers: usize,
+    pub max_subscribers: usize,
+    pub max_publishers: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -49,7 +57,10 @@ pub(crate) struct PublisherDetails {
     pub(crate) publisher_id: UniquePublisherId,
     pub(crate) node_id: NodeId,
     pub(crate) number_of_samples: usize,
-    pub(crate) max_slice_len: usize,
+    pub(crate) chunk_size: usize,
+    pub(crate) number_of_data_segments: usize,
+    pub(crate) growth: GrowthLayout,
+    pub(crate) priority: PublisherPriority,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -57,6 +68,8 @@ pub(crate) struct SubscriberDetails {
     pub(crate) subscriber_id: UniqueSubscriberId,
     pub(crate) node_id: NodeId,
     pub(crate) buffer_size: usize,
+    pub(crate) overflow_strategy: Option<SubscriberOverflowStrategy>,
+    pub(crate) blocking_receive: bool,
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -65,6 +78,10 @@ pub(crate) struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    max_subscribers: IoxAtomicUsize,
+    max_publishers: IoxAtomicUsize,
+    reserved_subscribers: IoxAtomicUsize,
+    reserved_publishers: IoxAtomicUsize,
 }
 
 impl DynamicConfig {
@@ -72,6 +89,10 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            max_subscribers: IoxAtomicUsize::new(config.max_subscribers),
+            max_publishers: IoxAtomicUsize::new(config.max_publishers),
+            reserved_subscribers: IoxAtomicUsize::new(0),
+            reserved_publishers: IoxAtomicUsize::new(0),
         }
     }
 
@@ -133,6 +154,28 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Returns the current maximum amount of [`crate::port::publisher::Publisher`] ports.
+    pub fn max_publishers(&self) -> usize {
+        self.max_publishers.load(Ordering::Relaxed)
+    }
+
+    /// Returns the current maximum amount of [`crate::port::subscriber::Subscriber`] ports.
+    pub fn max_subscribers(&self) -> usize {
+        self.max_subscribers.load(Ordering::Relaxed)
+    }
+
+    /// Raises the maximum amount of [`crate::port::publisher::Publisher`] ports to `value` and
+    /// returns the previous maximum. The value is never decreased.
+    pub(crate) fn raise_max_publishers(&self, value: usize) -> usize {
+        self.max_publishers.fetch_max(value, Ordering::Relaxed)
+    }
+
+    /// Raises the maximum amount of [`crate::port::subscriber::Subscriber`] ports to `value` and
+    /// returns the previous maximum. The value is never decreased.
+    pub(crate) fn raise_max_subscribers(&self, value: usize) -> usize {
+        self.max_subscribers.fetch_max(value, Ordering::Relaxed)
+    }
+
     #[doc(hidden)]
     pub fn __internal_subscriber_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
         let state = unsafe { self.subscribers.get_state() };
@@ -153,19 +196,66 @@ impl DynamicConfig {
         });
     }
 
+    #[doc(hidden)]
+    pub fn __internal_publishers<F: FnMut(&UniquePublisherId, &NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.publishers.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.publisher_id, &details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    fn reserve(reserved: &IoxAtomicUsize, max: &IoxAtomicUsize) -> bool {
+        let mut current = reserved.load(Ordering::Relaxed);
+        loop {
+            if current >= max.load(Ordering::Relaxed) {
+                return false;
+            }
+
+            match reserved.compare_exchange_weak(
+                current,
+                current + 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(v) => current = v,
+            }
+        }
+    }
+
     pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
-        unsafe { self.subscribers.add(details).ok() }
+        if !Self::reserve(&self.reserved_subscribers, &self.max_subscribers) {
+            return None;
+        }
+
+        let handle = unsafe { self.subscribers.add(details).ok() };
+        if handle.is_none() {
+            self.reserved_subscribers.fetch_sub(1, Ordering::Relaxed);
+        }
+        handle
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
         unsafe { self.subscribers.remove(handle, ReleaseMode::Default) };
+        self.reserved_subscribers.fetch_sub(1, Ordering::Relaxed);
     }
 
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
-        unsafe { self.publishers.add(details).ok() }
+        if !Self::reserve(&self.reserved_publishers, &self.max_publishers) {
+            return None;
+        }
+
+        let handle = unsafe { self.publishers.add(details).ok() };
+        if handle.is_none() {
+            self.reserved_publishers.fetch_sub(1, Ordering::Relaxed);
+        }
+        handle
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
         unsafe { self.publishers.remove(handle, ReleaseMode::Default) };
+        self.reserved_publishers.fetch_sub(1, Ordering::Relaxed);
     }
 }
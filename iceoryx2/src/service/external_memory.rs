@@ -0,0 +1,134 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::external_memory;
+//! use iceoryx2_cal::shared_memory::posix::Memory;
+//! use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+//!
+//! // replace the posix shared memory with the memory provider of the accelerator
+//! type GpuService = external_memory::Service<Memory<PoolAllocator>>;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<GpuService>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().max_slice_len(4096).create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The `external_memory` variant uses the same inter-process mechanisms as
+//! [`ipc::Service`](crate::service::ipc::Service) but allocates the payload of the
+//! [`Publisher`](crate::port::publisher::Publisher)s data segments from the
+//! [`ExternalMemoryProvider`] provided as generic argument. It is the extension point to
+//! allocate the payload from CUDA pinned memory, dmabuf or ion buffers, so that samples can be
+//! handed to an accelerator without an additional copy.
+//!
+//! The [`ExternalMemoryProvider`] is responsible for mapping the memory that a
+//! [`Publisher`](crate::port::publisher::Publisher) created into the address space of every
+//! [`Subscriber`](crate::port::subscriber::Subscriber), identified by the name the memory was
+//! created with. All processes that communicate must use the same [`ExternalMemoryProvider`].
+//!
+//! The resources of an `external_memory` service are named with the global prefix
+//! prepended by `extmem_`. Therefore, an `external_memory` and an
+//! [`ipc::Service`](crate::service::ipc::Service) with the same
+//! [`ServiceName`](crate::service::service_name::ServiceName) are two separate services that
+//! neither list, open nor remove each other.
+//!
+//! See [`Service`](crate::service) for more detailed examples.
+
+use std::sync::Arc;
+
+use crate::node::heartbeat::NodeHeartbeat;
+use crate::node::instance::InstanceMarker;
+use crate::port::data_segment_growth::DataSegmentGrowth;
+use crate::port::event_payload::EventPayloadQueue;
+use crate::service::dynamic_config::DynamicConfig;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Is prepended to the global prefix of all resources so that they are distinguishable from
+/// the resources of an [`ipc::Service`](crate::service::ipc::Service).
+pub(crate) const RESOURCE_PREFIX: &[u8] = b"extmem_";
+
+/// Provides the memory in which the payload of the samples is stored. Every
+/// [`SharedMemory`] that manages its memory with the [`PoolAllocator`] is an
+/// [`ExternalMemoryProvider`], like
+/// [`shared_memory::posix::Memory`](iceoryx2_cal::shared_memory::posix::Memory) or a
+/// user-provided implementation based on CUDA pinned memory, dmabuf or ion.
+pub trait ExternalMemoryProvider: SharedMemory<PoolAllocator> {}
+
+impl<T: SharedMemory<PoolAllocator>> ExternalMemoryProvider for T {}
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms where the
+/// payload is stored in the memory of the [`ExternalMemoryProvider`].
+#[derive(Debug)]
+pub struct Service<Memory: ExternalMemoryProvider> {
+    state: Arc<ServiceState<Self>>,
+}
+
+impl<Memory: ExternalMemoryProvider> crate::service::Service for Service<Memory> {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type HeartbeatStorage = dynamic_storage::posix_shared_memory::Storage<NodeHeartbeat>;
+    type InstanceStorage = dynamic_storage::posix_shared_memory::Storage<InstanceMarker>;
+    type EventPayloadStorage = dynamic_storage::posix_shared_memory::Storage<EventPayloadQueue>;
+    type GrowthStorage = dynamic_storage::posix_shared_memory::Storage<DataSegmentGrowth>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = Memory;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::unix_datagram_socket::EventImpl;
+    #[cfg(not(target_os = "windows"))]
+    type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    // file locks of dead processes are not reliably released on windows
+    #[cfg(target_os = "windows")]
+    type Monitoring = monitoring::process_handle::ProcessHandleMonitoring;
+    type Reactor = reactor::posix_select::Reactor;
+}
+
+impl<Memory: ExternalMemoryProvider> crate::service::internal::ServiceInternal<Service<Memory>>
+    for Service<Memory>
+{
+    fn __internal_from_state(state: ServiceState<Self>) -> Self {
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    fn __internal_state(&self) -> &Arc<ServiceState<Self>> {
+        &self.state
+    }
+
+    fn __internal_resource_prefix(global_prefix: &FileName) -> FileName {
+        let mut prefix = FileName::new(RESOURCE_PREFIX).unwrap();
+        fatal_panic!(from "external_memory::Service::resource_prefix()",
+            when prefix.push_bytes(global_prefix.as_bytes()),
+            "The global prefix \"{}\" is too long to be prepended with \"extmem_\".", global_prefix);
+        prefix
+    }
+}
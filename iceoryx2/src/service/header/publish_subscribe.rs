@@ -31,8 +31,104 @@
 //! # }
 //! ```
 
+use core::fmt::Display;
+use core::time::Duration;
+
 use crate::port::port_identifiers::UniquePublisherId;
 
+/// The tracing context of a sample, consisting of a trace id and a span id as defined by the
+/// [W3C Trace Context](https://www.w3.org/TR/trace-context/). It is set by the
+/// [`Publisher`](crate::port::publisher::Publisher) with
+/// [`SampleMut::set_tracing_context()`](crate::sample_mut::SampleMut::set_tracing_context())
+/// and delivered with the sample, so that a sample can be correlated across multiple
+/// processing stages.
+///
+/// ```
+/// use iceoryx2::service::header::publish_subscribe::TracingContext;
+///
+/// let context = TracingContext::new([0xab; 16], [0x01; 8]);
+///
+/// // the next processing stage continues the trace with its own span
+/// let next_stage = context.with_span_id([0x02; 8]);
+/// assert_eq!(next_stage.trace_id(), context.trace_id());
+///
+/// // the W3C traceparent representation
+/// println!("{}", next_stage);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct TracingContext {
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+}
+
+impl TracingContext {
+    /// Creates a new [`TracingContext`]. A context where the trace id or the span id contains
+    /// only zeros is invalid and is not delivered with the sample.
+    pub fn new(trace_id: [u8; 16], span_id: [u8; 8]) -> Self {
+        Self { trace_id, span_id }
+    }
+
+    /// Returns a [`TracingContext`] with the same trace id and the provided span id.
+    pub fn with_span_id(&self, span_id: [u8; 8]) -> Self {
+        Self {
+            trace_id: self.trace_id,
+            span_id,
+        }
+    }
+
+    /// Returns the trace id.
+    pub fn trace_id(&self) -> [u8; 16] {
+        self.trace_id
+    }
+
+    /// Returns the span id.
+    pub fn span_id(&self) -> [u8; 8] {
+        self.span_id
+    }
+
+    /// Returns true when neither the trace id nor the span id contains only zeros.
+    pub fn is_valid(&self) -> bool {
+        self.trace_id != [0; 16] && self.span_id != [0; 8]
+    }
+
+    /// Creates a [`tracing::Span`] that carries the trace id and the span id as fields, so
+    /// that all events that are recorded while the span is entered can be correlated with
+    /// the sample.
+    #[cfg(feature = "tracing")]
+    pub fn span(&self) -> tracing::Span {
+        tracing::info_span!(
+            "iceoryx2_sample",
+            trace_id = %HexBytes(&self.trace_id),
+            span_id = %HexBytes(&self.span_id)
+        )
+    }
+}
+
+impl Display for TracingContext {
+    /// Writes the context in the W3C `traceparent` format, e.g.
+    /// `00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "00-{}-{}-01",
+            HexBytes(&self.trace_id),
+            HexBytes(&self.span_id)
+        )
+    }
+}
+
+struct HexBytes<'a>(&'a [u8]);
+
+impl Display for HexBytes<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 /// Sample header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 #[derive(Debug, Copy, Clone)]
@@ -40,6 +136,9 @@ use crate::port::port_identifiers::UniquePublisherId;
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    send_timestamp: u64,
+    sequence_number: u64,
+    tracing_context: TracingContext,
 }
 
 impl Header {
@@ -47,6 +146,30 @@ impl Header {
         Self {
             publisher_port_id,
             number_of_elements,
+            send_timestamp: 0,
+            sequence_number: 0,
+            tracing_context: TracingContext::new([0; 16], [0; 8]),
+        }
+    }
+
+    pub(crate) fn set_send_timestamp(&mut self, value: Duration) {
+        self.send_timestamp = value.as_nanos() as u64;
+    }
+
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn set_tracing_context(&mut self, value: TracingContext) {
+        self.tracing_context = value;
+    }
+
+    /// Returns the [`TracingContext`] the sample was sent with or [`None`] when the
+    /// [`crate::port::publisher::Publisher`] did not set a valid one.
+    pub fn tracing_context(&self) -> Option<TracingContext> {
+        match self.tracing_context.is_valid() {
+            true => Some(self.tracing_context),
+            false => None,
         }
     }
 
@@ -55,6 +178,22 @@ impl Header {
         self.publisher_port_id
     }
 
+    /// Returns the time at which the sample was sent by the
+    /// [`crate::port::publisher::Publisher`]. It is acquired from the default clock of
+    /// [`iceoryx2_bb_posix::clock::Time::now()`] and therefore comparable between the samples of
+    /// different processes on the same machine.
+    pub fn send_timestamp(&self) -> Duration {
+        Duration::from_nanos(self.send_timestamp)
+    }
+
+    /// Returns the sequence number of the sample. Every [`crate::port::publisher::Publisher`]
+    /// numbers its sent samples consecutively, starting with `0`, so that a
+    /// [`crate::port::subscriber::Subscriber`] can detect lost samples with a
+    /// [`SampleGapDetector`](crate::port::sample_gap_detector::SampleGapDetector).
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
     /// Returns how many elements are stored inside the sample's payload.
     ///
     /// # Details when using
@@ -34,6 +34,10 @@
 
 use std::sync::Arc;
 
+use crate::node::heartbeat::NodeHeartbeat;
+use crate::node::instance::InstanceMarker;
+use crate::port::data_segment_growth::DataSegmentGrowth;
+use crate::port::event_payload::EventPayloadQueue;
 use crate::service::dynamic_config::DynamicConfig;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::*;
@@ -50,11 +54,19 @@ impl crate::service::Service for Service {
     type StaticStorage = static_storage::file::Storage;
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type HeartbeatStorage = dynamic_storage::posix_shared_memory::Storage<NodeHeartbeat>;
+    type InstanceStorage = dynamic_storage::posix_shared_memory::Storage<InstanceMarker>;
+    type EventPayloadStorage = dynamic_storage::posix_shared_memory::Storage<EventPayloadQueue>;
+    type GrowthStorage = dynamic_storage::posix_shared_memory::Storage<DataSegmentGrowth>;
     type ServiceNameHasher = hash::sha1::Sha1;
     type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
     type Event = event::unix_datagram_socket::EventImpl;
+    #[cfg(not(target_os = "windows"))]
     type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    // file locks of dead processes are not reliably released on windows
+    #[cfg(target_os = "windows")]
+    type Monitoring = monitoring::process_handle::ProcessHandleMonitoring;
     type Reactor = reactor::posix_select::Reactor;
 }
 
@@ -0,0 +1,194 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//! use iceoryx2::service::list_filter::ServiceListFilter;
+//! use iceoryx2::service::messaging_pattern::MessagingPattern;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let filter = ServiceListFilter::new()
+//!     .name_pattern("My/Funk/*")
+//!     .messaging_pattern(MessagingPattern::PublishSubscribe)
+//!     .attributes(AttributeVerifier::new().require_key("camera"));
+//!
+//! let mut cursor = None;
+//! loop {
+//!     let page = ipc::Service::list_page(Config::global_config(), &filter, cursor.as_ref(), 32)?;
+//!     for service in &page.services {
+//!         println!("{:?}", service.static_details.name());
+//!     }
+//!
+//!     match page.next {
+//!         Some(next) => cursor = Some(next),
+//!         None => break,
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_cal::named_concept::FileName;
+
+use super::attribute::AttributeVerifier;
+use super::messaging_pattern::MessagingPattern;
+use super::service_id::ServiceId;
+use super::service_name::ServiceName;
+use super::static_config::StaticConfig;
+use super::{Service, ServiceDetails};
+
+const MESSAGING_PATTERNS: [MessagingPattern; 3] = [
+    MessagingPattern::PublishSubscribe,
+    MessagingPattern::Event,
+    MessagingPattern::Blackboard,
+];
+
+/// Restricts the [`Service`]s that are listed with [`Service::list_filtered()`] or
+/// [`Service::list_page()`]. The filter is applied to the static details of a [`Service`]
+/// before its dynamic details are acquired, so that the expensive part of the listing is only
+/// performed for the matching [`Service`]s. An empty filter matches every [`Service`].
+#[derive(Debug, Default)]
+pub struct ServiceListFilter {
+    name_pattern: Option<Vec<char>>,
+    messaging_pattern: Option<MessagingPattern>,
+    attributes: Option<AttributeVerifier>,
+}
+
+impl ServiceListFilter {
+    /// Creates a new [`ServiceListFilter`] that matches every [`Service`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches only [`Service`]s whose name matches the pattern. `*` matches any sequence of
+    /// characters and `?` matches a single character.
+    pub fn name_pattern(mut self, pattern: &str) -> Self {
+        self.name_pattern = Some(pattern.chars().collect());
+        self
+    }
+
+    /// Matches only [`Service`]s of the provided [`MessagingPattern`].
+    pub fn messaging_pattern(mut self, messaging_pattern: MessagingPattern) -> Self {
+        self.messaging_pattern = Some(messaging_pattern);
+        self
+    }
+
+    /// Matches only [`Service`]s whose attributes satisfy the requirements of the
+    /// [`AttributeVerifier`].
+    pub fn attributes(mut self, attributes: AttributeVerifier) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Returns true when the [`Service`] with the provided [`StaticConfig`] matches the
+    /// filter.
+    pub fn matches(&self, static_config: &StaticConfig) -> bool {
+        if let Some(messaging_pattern) = self.messaging_pattern {
+            if static_config.messaging_pattern().kind() != messaging_pattern {
+                return false;
+            }
+        }
+
+        if let Some(pattern) = &self.name_pattern {
+            let name: Vec<char> = static_config.name().as_str().chars().collect();
+            if !matches_wildcard_pattern(pattern, &name) {
+                return false;
+            }
+        }
+
+        if let Some(attributes) = &self.attributes {
+            if attributes
+                .verify_requirements(static_config.attributes())
+                .is_err()
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // When the name pattern contains no wildcard, the uuids of the only services that can
+    // match are derived from the name, so that the static configs of all other services do
+    // not have to be read and deserialized.
+    pub(crate) fn candidate_uuids<S: Service>(&self) -> Option<Vec<FileName>> {
+        let pattern = self.name_pattern.as_ref()?;
+        if pattern.iter().any(|c| *c == '*' || *c == '?') {
+            return None;
+        }
+
+        let name: String = pattern.iter().collect();
+        let service_name = match ServiceName::new(&name) {
+            Ok(service_name) => service_name,
+            // no service can have an invalid name
+            Err(_) => return Some(vec![]),
+        };
+
+        Some(
+            MESSAGING_PATTERNS
+                .iter()
+                .filter(|messaging_pattern| {
+                    self.messaging_pattern
+                        .map_or(true, |v| v == **messaging_pattern)
+                })
+                .map(|messaging_pattern| {
+                    ServiceId::new::<S::ServiceNameHasher>(&service_name, *messaging_pattern)
+                        .0
+                        .into()
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The position from where [`Service::list_page()`] continues the listing. It is stable
+/// when [`Service`]s are created or removed between two calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceListCursor(pub(crate) FileName);
+
+/// A page of [`Service`]s that were acquired with [`Service::list_page()`].
+#[derive(Debug)]
+pub struct ServiceListPage<S: Service> {
+    /// The [`ServiceDetails`] of all matching [`Service`]s of the page.
+    pub services: Vec<ServiceDetails<S>>,
+    /// The [`ServiceListCursor`] to acquire the next page. It is [`None`] when the listing
+    /// is complete.
+    pub next: Option<ServiceListCursor>,
+}
+
+fn matches_wildcard_pattern(pattern: &[char], value: &[char]) -> bool {
+    let mut p = 0;
+    let mut v = 0;
+    // the position of the last '*' in the pattern and of the value when it was encountered
+    let mut backtrack = None;
+
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if let Some((star_p, star_v)) = backtrack {
+            p = star_p + 1;
+            v = star_v + 1;
+            backtrack = Some((star_p, star_v + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -30,10 +30,22 @@
 //! # }
 //! ```
 //!
+//! The `local` variant has the same API as [`ipc::Service`](crate::service::ipc::Service) but
+//! keeps the service storage, the data segments, the connections and the node monitoring in
+//! the heap of the process. Therefore, it is suited for single process deployments and unit
+//! tests where the services shall neither be visible to nor interfere with other processes.
+//! Only the [`Listener`](crate::port::listener::Listener) is based on a unix datagram socket,
+//! since it must provide a file descriptor to be attachable to a
+//! [`WaitSet`](crate::port::waitset::WaitSet).
+//!
 //! See [`Service`](crate::service) for more detailed examples.
 
 use std::sync::Arc;
 
+use crate::node::heartbeat::NodeHeartbeat;
+use crate::node::instance::InstanceMarker;
+use crate::port::data_segment_growth::DataSegmentGrowth;
+use crate::port::event_payload::EventPayloadQueue;
 use crate::service::dynamic_config::DynamicConfig;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::*;
@@ -50,6 +62,10 @@ impl crate::service::Service for Service {
     type StaticStorage = static_storage::process_local::Storage;
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::process_local::Storage<DynamicConfig>;
+    type HeartbeatStorage = dynamic_storage::process_local::Storage<NodeHeartbeat>;
+    type InstanceStorage = dynamic_storage::process_local::Storage<InstanceMarker>;
+    type EventPayloadStorage = dynamic_storage::process_local::Storage<EventPayloadQueue>;
+    type GrowthStorage = dynamic_storage::process_local::Storage<DataSegmentGrowth>;
     type ServiceNameHasher = hash::sha1::Sha1;
     type SharedMemory = shared_memory::process_local::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::process_local::Connection;
@@ -69,4 +85,8 @@ impl crate::service::internal::ServiceInternal<Service> for Service {
     fn __internal_state(&self) -> &Arc<ServiceState<Self>> {
         &self.state
     }
+
+    fn __internal_is_inter_process() -> bool {
+        false
+    }
 }
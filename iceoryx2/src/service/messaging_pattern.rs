@@ -31,6 +31,13 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Blackboard
+//!
+//! A key-value store in shared memory. The [`Writer`](crate::port::writer::Writer) updates
+//! the values of a fixed set of keys that is defined when the
+//! [`Service`](crate::service::Service) is created and `m`
+//! [`Reader`](crate::port::reader::Reader)s acquire the latest value of a key.
 
 /// Identifies the kind of messaging pattern the [`Service`](crate::service::Service) will use.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -46,4 +53,10 @@ pub enum MessagingPattern {
     /// ability to sleep until a signal/event arrives.
     /// Building block to realize push-notifications.
     Event,
+
+    /// Key-value based communication pattern where the
+    /// [`Writer`](crate::port::writer::Writer) updates the values of a fixed set of keys in
+    /// shared memory and the [`Reader`](crate::port::reader::Reader) acquires the latest value of
+    /// a key.
+    Blackboard,
 }
@@ -61,6 +61,29 @@
 //! # }
 //! ```
 //!
+//! ## Blackboard
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let blackboard = node.service_builder(&"MyBlackboardName".try_into()?)
+//!     // define the messaging pattern with the key and value type
+//!     .blackboard::<u64, f32>()
+//!     // the keys with their initial values, they are fixed when the service is created
+//!     .add(0, 0.0)
+//!     .add(1, 1.0)
+//!     // various QoS
+//!     .max_readers(4)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Service With Custom Configuration
 //!
 //! ```
@@ -126,6 +149,9 @@ pub mod builder;
 /// The dynamic configuration of a [`Service`]
 pub mod dynamic_config;
 
+/// The built-in service discovery that informs about created and removed [`Service`]s
+pub mod discovery;
+
 /// Defines the sample headers for various
 /// [`MessagingPattern`]s
 pub mod header;
@@ -155,12 +181,23 @@ pub mod static_config;
 /// Represents static features of a service that can be set when a [`Service`] is created.
 pub mod attribute;
 
+/// Filters and paginates the listing of [`Service`]s
+pub mod list_filter;
+
+/// The aggregated counters of a [`Service`] that are stored in its dynamic configuration.
+pub mod statistics;
+
 /// A configuration when communicating within a single process or single address space.
 pub mod local;
 
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod ipc;
 
+/// A configuration when communicating between different processes where the payload is stored
+/// in the memory of a user-provided
+/// [`ExternalMemoryProvider`](crate::service::external_memory::ExternalMemoryProvider).
+pub mod external_memory;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -169,10 +206,17 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::config;
-use crate::node::{NodeId, NodeListFailure, NodeState, SharedNode};
+use crate::node::heartbeat::NodeHeartbeat;
+use crate::node::instance::InstanceMarker;
+use crate::node::{
+    remove_vanished_node_from_service, NodeId, NodeListFailure, NodeState, SharedNode,
+};
+use crate::port::data_segment_growth::DataSegmentGrowth;
+use crate::port::event_payload::EventPayloadQueue;
 use crate::service::config_scheme::dynamic_config_storage_config;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
+use crate::service::statistics::ServiceStatistics;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
@@ -194,6 +238,7 @@ use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 use service_id::ServiceId;
 
 use self::dynamic_config::DeregisterNodeState;
+use self::list_filter::{ServiceListCursor, ServiceListFilter, ServiceListPage};
 use self::messaging_pattern::MessagingPattern;
 use self::service_name::ServiceName;
 
@@ -255,12 +300,29 @@ impl std::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
+/// Returned by [`Service::remove_stale_resources()`]. Contains how many stale [`Service`]s and
+/// stale [`Node`](crate::node::Node) registrations were removed and how many stale
+/// [`Service`]s could not be cleaned up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StaleServiceCleanupState {
+    /// The number of [`Service`]s whose resources were removed since no living
+    /// [`Node`](crate::node::Node) owns them anymore.
+    pub removed_services: usize,
+    /// The number of dead or no longer existing [`Node`](crate::node::Node)s that were removed
+    /// from a [`Service`].
+    pub removed_nodes: usize,
+    /// The number of stale [`Service`]s whose resources could not be removed.
+    pub failed_cleanups: usize,
+}
+
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`]
 /// when the [`Service`] is accessible by the current process.
 #[derive(Debug)]
 pub struct ServiceDynamicDetails<S: Service> {
     /// A list of all [`Node`](crate::node::Node)s that a registered at the [`Service`]
     pub nodes: Vec<NodeState<S>>,
+    /// The aggregated counters of the [`Service`]
+    pub statistics: ServiceStatistics,
 }
 
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`].
@@ -305,8 +367,9 @@ impl<S: Service> Drop for ServiceState<S> {
     fn drop(&mut self) {
         let origin = "ServiceState::drop()";
         let id = self.static_config.service_id();
+        let mut is_removed = false;
         self.shared_node.registered_services().remove(id, |handle| {
-            if let Err(e) = remove_service_tag::<S>(self.shared_node.id(), id, self.shared_node.config())
+            if let Err(e) = remove_service_tag::<S>(self.shared_node.id(), id, &self.shared_node.config())
             {
                 debug!(from origin, "The service tag could not be removed from the node {:?} ({:?}).",
                         self.shared_node.id(), e);
@@ -322,9 +385,20 @@ impl<S: Service> Drop for ServiceState<S> {
                     self.dynamic_storage.acquire_ownership();
                     trace!(from origin, "close and remove service: {} ({:?})",
                             self.static_config.name(), id);
+                    is_removed = true;
                 }
             }
         });
+
+        // must be called after the registered services are released since the service discovery
+        // may create its own service on first use
+        if is_removed {
+            discovery::publish_event(
+                &self.shared_node,
+                discovery::ServiceDiscoveryEventKind::Removed,
+                &self.static_config,
+            );
+        }
     }
 }
 
@@ -336,11 +410,13 @@ pub(crate) mod internal {
         node::NodeId,
         port::{
             listener::remove_connection_of_listener,
+            notifier::remove_event_payload_queues_of_notifier,
             port_identifiers::UniquePortId,
             publisher::{
                 remove_data_segment_of_publisher, remove_publisher_from_all_connections,
                 remove_subscriber_from_all_connections,
             },
+            subscriber::remove_blocking_receive_event_of_subscriber,
         },
     };
 
@@ -351,6 +427,18 @@ pub(crate) mod internal {
 
         fn __internal_state(&self) -> &Arc<ServiceState<S>>;
 
+        /// Returns true when the resources of the service are shared between processes.
+        fn __internal_is_inter_process() -> bool {
+            true
+        }
+
+        /// Returns the prefix of all resources of the service. Variants that share the
+        /// resource types with another variant must adjust the global prefix so that they
+        /// do not list, open or remove the resources of the other variant.
+        fn __internal_resource_prefix(global_prefix: &FileName) -> FileName {
+            *global_prefix
+        }
+
         fn __internal_remove_node_from_service(
             node_id: &NodeId,
             service_id: &ServiceId,
@@ -398,8 +486,23 @@ pub(crate) mod internal {
                             debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
+
+                        if let Err(e) =
+                            unsafe { remove_blocking_receive_event_of_subscriber::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the subscribers ({:?}) blocking receive event ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Notifier(ref id) => {
+                        if let Err(e) =
+                            unsafe { remove_event_payload_queues_of_notifier::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the notifiers ({:?}) event payload queues ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
                     }
-                    UniquePortId::Notifier(_) => (),
+                    UniquePortId::Writer(_) | UniquePortId::Reader(_) => (),
                     UniquePortId::Listener(ref id) => {
                         if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config) } {
                             debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
@@ -425,15 +528,34 @@ pub(crate) mod internal {
             };
 
             if remove_service {
+                // the static config is required for the service discovery event and must be
+                // read before the static storage is removed
+                let static_config = match static_details::<S>(config, &service_id.0.into()) {
+                    Ok(static_config) => static_config,
+                    Err(e) => {
+                        warn!(from origin, "Unable to read the static config of the unused service, no service discovery event will be published ({:?}).",
+                            e);
+                        None
+                    }
+                };
+
                 match unsafe {
                     <S::StaticStorage as NamedConceptMgmt>::remove_cfg(
                         &service_id.0.into(),
                         &static_config_storage_config::<S>(config),
                     )
                 } {
-                    Ok(_) => {
+                    Ok(is_removed) => {
                         debug!(from origin, "Remove unused service.");
-                        dynamic_config.acquire_ownership()
+                        dynamic_config.acquire_ownership();
+                        // when the static storage was already removed, the other cleanup
+                        // publishes the event
+                        if let (true, Some(static_config)) = (is_removed, static_config) {
+                            discovery::publish_removed_event_of_dead_node_service::<S>(
+                                config,
+                                &static_config,
+                            );
+                        }
                     }
                     Err(e) => {
                         warn!(from origin, "Unable to remove static config of unused service ({:?}).",
@@ -452,7 +574,7 @@ pub(crate) mod internal {
 /// Contains the building blocks a [`Service`] requires to create the underlying resources and
 /// establish communication.
 #[allow(private_bounds)]
-pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
+pub trait Service: Debug + Sized + 'static + internal::ServiceInternal<Self> {
     /// Every service name will be hashed, to allow arbitrary [`ServiceName`]s with as less
     /// restrictions as possible. The hash of the [`ServiceName`] is the [`Service`]s uuid.
     type ServiceNameHasher: Hash;
@@ -467,6 +589,24 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// contains for instance all endpoints and other dynamic details.
     type DynamicStorage: DynamicStorage<DynamicConfig>;
 
+    /// Defines the construct used to store the [`NodeHeartbeat`] of a
+    /// [`Node`](crate::node::Node) that was created with a liveness deadline.
+    type HeartbeatStorage: DynamicStorage<NodeHeartbeat>;
+
+    /// Defines the construct used to store the [`InstanceMarker`] that identifies the
+    /// iceoryx2 instance that owns the [`Global::prefix`](crate::config::Global::prefix).
+    type InstanceStorage: DynamicStorage<InstanceMarker>;
+
+    /// Defines the construct used to transfer the
+    /// [`EventPayload`](crate::port::event_payload::EventPayload)s of a
+    /// [`Notifier`](crate::port::notifier::Notifier) to a
+    /// [`Listener`](crate::port::listener::Listener).
+    type EventPayloadStorage: DynamicStorage<EventPayloadQueue>;
+
+    /// Defines the construct used to store the [`DataSegmentGrowth`] of a
+    /// [`Publisher`](crate::port::publisher::Publisher) that can add data segments.
+    type GrowthStorage: DynamicStorage<DataSegmentGrowth>;
+
     /// The memory used to store the payload.
     type SharedMemory: SharedMemory<PoolAllocator>;
 
@@ -557,20 +697,37 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// ```
     fn list<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
         config: &config::Config,
+        callback: F,
+    ) -> Result<(), ServiceListError> {
+        Self::list_filtered(config, &ServiceListFilter::new(), callback)
+    }
+
+    /// Returns a list of all services created under a given [`config::Config`] that match the
+    /// [`ServiceListFilter`]. The dynamic details are only acquired for matching services.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::list_filter::ServiceListFilter;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = ServiceListFilter::new().name_pattern("My/Funk/*");
+    /// ipc::Service::list_filtered(Config::global_config(), &filter, |service| {
+    ///     println!("\n{:#?}", &service);
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_filtered<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+        config: &config::Config,
+        filter: &ServiceListFilter,
         mut callback: F,
     ) -> Result<(), ServiceListError> {
-        let msg = "Unable to list all services";
-        let origin = "Service::list_from_config()";
-        let static_storage_config = config_scheme::static_config_storage_config::<Self>(config);
-
-        let service_uuids = fail!(from origin,
-                when <Self::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
-                map NamedConceptListError::InsufficientPermissions => ServiceListError::InsufficientPermissions,
-                unmatched ServiceListError::InternalError,
-                "{} due to a failure while collecting all active services for config: {:?}", msg, config);
-
-        for uuid in &service_uuids {
-            if let Ok(Some(service_details)) = details::<Self>(config, uuid) {
+        for uuid in &filtered_service_uuids::<Self>(config, filter)? {
+            if let Ok(Some(service_details)) = filtered_details::<Self>(config, uuid, filter) {
                 if callback(service_details) == CallbackProgression::Stop {
                     break;
                 }
@@ -579,12 +736,237 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
 
         Ok(())
     }
+
+    /// Returns at most `max_services` services created under a given [`config::Config`] that
+    /// match the [`ServiceListFilter`]. The listing continues at the provided
+    /// [`ServiceListCursor`] or starts at the beginning when it is [`None`]. The
+    /// [`ServiceListPage::next`] cursor continues the listing with the next call. When
+    /// `max_services` is `0` the page contains all remaining services.
+    ///
+    /// See [`list_filter`] for an example.
+    fn list_page(
+        config: &config::Config,
+        filter: &ServiceListFilter,
+        cursor: Option<&ServiceListCursor>,
+        max_services: usize,
+    ) -> Result<ServiceListPage<Self>, ServiceListError> {
+        let max_services = match max_services {
+            0 => usize::MAX,
+            v => v,
+        };
+        let mut service_uuids = filtered_service_uuids::<Self>(config, filter)?;
+        service_uuids.sort_by(|lhs, rhs| lhs.as_bytes().cmp(rhs.as_bytes()));
+
+        let start = match cursor {
+            Some(cursor) => {
+                service_uuids.partition_point(|uuid| uuid.as_bytes() < cursor.0.as_bytes())
+            }
+            None => 0,
+        };
+
+        let mut page = ServiceListPage {
+            services: vec![],
+            next: None,
+        };
+
+        for uuid in service_uuids.iter().skip(start) {
+            if page.services.len() == max_services {
+                // the next page starts with the first uuid that was not examined
+                page.next = Some(ServiceListCursor(uuid.clone()));
+                break;
+            }
+
+            if let Ok(Some(service_details)) = filtered_details::<Self>(config, uuid, filter) {
+                page.services.push(service_details);
+            }
+        }
+
+        Ok(page)
+    }
+
+    /// Removes the stale resources of all [`Service`]s created under a given
+    /// [`config::Config`]. A [`Service`] is stale when one of the
+    /// [`Node`](crate::node::Node)s registered at it is dead or no longer exists, when its
+    /// dynamic configuration is gone or when no [`Node`](crate::node::Node) is registered
+    /// anymore. The stale
+    /// [`Node`](crate::node::Node)s are removed together with their ports and publisher data
+    /// segments and when no [`Node`](crate::node::Node) owns the [`Service`] anymore, the
+    /// static and dynamic storage of the [`Service`] is removed as well.
+    ///
+    /// [`Service`]s that cannot be accessed by the current process are counted in
+    /// [`StaleServiceCleanupState::failed_cleanups`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let state = ipc::Service::remove_stale_resources(Config::global_config())?;
+    /// println!("removed {} stale services", state.removed_services);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove_stale_resources(
+        config: &config::Config,
+    ) -> Result<StaleServiceCleanupState, ServiceListError> {
+        let mut state = StaleServiceCleanupState::default();
+        for uuid in &list_service_uuids::<Self>(config)? {
+            remove_stale_resources_of_service::<Self>(config, uuid, &mut state);
+        }
+
+        Ok(state)
+    }
+}
+
+fn remove_stale_resources_of_service<S: Service>(
+    config: &config::Config,
+    uuid: &FileName,
+    state: &mut StaleServiceCleanupState,
+) {
+    let origin = format!(
+        "Service::<{}>::remove_stale_resources({})",
+        core::any::type_name::<S>(),
+        uuid
+    );
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config);
+    let remove_static_storage = |state: &mut StaleServiceCleanupState| match unsafe {
+        <S::StaticStorage as NamedConceptMgmt>::remove_cfg(uuid, &static_storage_config)
+    } {
+        Ok(true) => state.removed_services += 1,
+        Ok(false) => (),
+        Err(e) => {
+            debug!(from origin, "Unable to remove the static storage of the stale service ({:?}).", e);
+            state.failed_cleanups += 1;
+        }
+    };
+
+    let static_config = match static_details::<S>(config, uuid) {
+        Ok(Some(static_config)) => static_config,
+        // the service is either removed or still being created
+        Ok(None) => return,
+        Err(e) => {
+            debug!(from origin, "Unable to acquire the static details of the service ({:?}).", e);
+            state.failed_cleanups += 1;
+            return;
+        }
+    };
+    let service_id = static_config.service_id();
+
+    let dynamic_config = match open_dynamic_config::<S>(config, service_id) {
+        Ok(Some(dynamic_config)) => dynamic_config,
+        Ok(None) => {
+            debug!(from origin, "Remove service {} without dynamic config.", static_config.name());
+            remove_static_storage(state);
+            return;
+        }
+        Err(e) => {
+            debug!(from origin, "Unable to open the dynamic config of the service ({:?}).", e);
+            state.failed_cleanups += 1;
+            return;
+        }
+    };
+
+    let mut node_ids = vec![];
+    dynamic_config.get().list_node_ids(|node_id| {
+        node_ids.push(*node_id);
+        CallbackProgression::Continue
+    });
+
+    if node_ids.is_empty() {
+        debug!(from origin, "Remove service {} without nodes.", static_config.name());
+        remove_static_storage(state);
+        dynamic_config.acquire_ownership();
+        return;
+    }
+
+    let mut has_removed_nodes = false;
+    for node_id in &node_ids {
+        let is_removed = match NodeState::<S>::new(node_id, config) {
+            Ok(Some(NodeState::Dead(dead_node))) => dead_node.remove_stale_resources(),
+            Ok(None) => Ok(remove_vanished_node_from_service::<S>(
+                node_id, service_id, config,
+            )),
+            _ => Ok(false),
+        };
+
+        match is_removed {
+            Ok(true) => {
+                state.removed_nodes += 1;
+                has_removed_nodes = true;
+            }
+            Ok(false) => (),
+            Err(e) => {
+                debug!(from origin, "Unable to remove the stale node {:?} from the service ({:?}).",
+                    node_id, e);
+                state.failed_cleanups += 1;
+            }
+        }
+    }
+
+    // the last removed node removes the service
+    if has_removed_nodes && matches!(static_details::<S>(config, uuid), Ok(None)) {
+        debug!(from origin, "Removed stale service {}.", static_config.name());
+        state.removed_services += 1;
+    }
+}
+
+fn list_service_uuids<S: Service>(
+    config: &config::Config,
+) -> Result<Vec<FileName>, ServiceListError> {
+    let msg = "Unable to list all services";
+    let origin = "Service::list_from_config()";
+    let static_storage_config = config_scheme::static_config_storage_config::<S>(config);
+
+    Ok(fail!(from origin,
+            when <S::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
+            map NamedConceptListError::InsufficientPermissions => ServiceListError::InsufficientPermissions,
+            unmatched ServiceListError::InternalError,
+            "{} due to a failure while collecting all active services for config: {:?}", msg, config))
+}
+
+fn filtered_service_uuids<S: Service>(
+    config: &config::Config,
+    filter: &ServiceListFilter,
+) -> Result<Vec<FileName>, ServiceListError> {
+    match filter.candidate_uuids::<S>() {
+        Some(uuids) => Ok(uuids),
+        None => list_service_uuids::<S>(config),
+    }
+}
+
+fn filtered_details<S: Service>(
+    config: &config::Config,
+    uuid: &FileName,
+    filter: &ServiceListFilter,
+) -> Result<Option<ServiceDetails<S>>, ServiceDetailsError> {
+    match static_details::<S>(config, uuid)? {
+        Some(static_details) if filter.matches(&static_details) => Ok(Some(ServiceDetails {
+            dynamic_details: dynamic_details::<S>(config, &static_details)?,
+            static_details,
+        })),
+        _ => Ok(None),
+    }
 }
 
 fn details<S: Service>(
     config: &config::Config,
     uuid: &FileName,
 ) -> Result<Option<ServiceDetails<S>>, ServiceDetailsError> {
+    match static_details::<S>(config, uuid)? {
+        Some(static_details) => Ok(Some(ServiceDetails {
+            dynamic_details: dynamic_details::<S>(config, &static_details)?,
+            static_details,
+        })),
+        None => Ok(None),
+    }
+}
+
+fn static_details<S: Service>(
+    config: &config::Config,
+    uuid: &FileName,
+) -> Result<Option<StaticConfig>, ServiceDetailsError> {
     let msg = "Unable to acquire servic details";
     let origin = "Service::details()";
     let static_storage_config = config_scheme::static_config_storage_config::<S>(config);
@@ -629,8 +1011,16 @@ fn details<S: Service>(
                 msg, service_config, uuid, config);
     }
 
+    Ok(Some(service_config))
+}
+
+fn dynamic_details<S: Service>(
+    config: &config::Config,
+    service_config: &StaticConfig,
+) -> Result<Option<ServiceDynamicDetails<S>>, ServiceDetailsError> {
+    let origin = "Service::details()";
     let dynamic_config = open_dynamic_config::<S>(config, service_config.service_id())?;
-    let dynamic_details = if let Some(d) = dynamic_config {
+    Ok(if let Some(d) = dynamic_config {
         let mut nodes = vec![];
         d.get().list_node_ids(|node_id| {
             match NodeState::new(node_id, config) {
@@ -639,23 +1029,21 @@ fn details<S: Service>(
                 | Err(NodeListFailure::InsufficientPermissions)
                 | Err(NodeListFailure::Interrupt) => (),
                 Err(NodeListFailure::InternalError) => {
-                    debug!(from origin, "Unable to acquire NodeState for service \"{:?}\"", uuid);
+                    debug!(from origin, "Unable to acquire NodeState for service \"{:?}\"", service_config.service_id());
                 }
             };
             CallbackProgression::Continue
         });
-        Some(ServiceDynamicDetails { nodes })
+        Some(ServiceDynamicDetails {
+            nodes,
+            statistics: d.get().statistics().snapshot(),
+        })
     } else {
         None
-    };
-
-    Ok(Some(ServiceDetails {
-        static_details: service_config,
-        dynamic_details,
-    }))
+    })
 }
 
-fn open_dynamic_config<S: Service>(
+pub(crate) fn open_dynamic_config<S: Service>(
     config: &config::Config,
     service_id: &ServiceId,
 ) -> Result<Option<S::DynamicStorage>, ServiceDetailsError> {
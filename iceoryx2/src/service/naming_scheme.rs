@@ -10,7 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::port::port_identifiers::{
+    UniqueListenerId, UniqueNotifierId, UniquePublisherId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
@@ -24,6 +26,15 @@ pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
     file
 }
 
+pub(crate) fn subscriber_event_concept_name(subscriber_id: &UniqueSubscriberId) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers event concept name.";
+    let origin = "subscriber_event_concept_name()";
+    let mut file = fatal_panic!(from origin, when FileName::new(subscriber_id.0.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(subscriber_id.0.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
 pub(crate) fn connection_name(
     publisher_id: UniquePublisherId,
     subscriber_id: UniqueSubscriberId,
@@ -51,12 +62,75 @@ pub(crate) fn extract_subscriber_id_from_connection(connection: &FileName) -> Un
     unsafe { core::mem::transmute::<u128, UniqueSubscriberId>(value) }
 }
 
-pub(crate) fn data_segment_name(publisher_id: &UniquePublisherId) -> FileName {
+pub(crate) fn event_payload_name(
+    notifier_id: &UniqueNotifierId,
+    listener_id: &UniqueListenerId,
+) -> FileName {
+    let mut file = FileName::new(notifier_id.0.value().to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(listener_id.0.value().to_string().as_bytes())
+        .unwrap();
+    file
+}
+
+pub(crate) fn extract_notifier_id_from_event_payload(event_payload: &FileName) -> UniqueNotifierId {
+    let name = core::str::from_utf8(event_payload.as_bytes()).unwrap();
+    let notifier_id = &name[..name.find('_').unwrap()];
+    let value: u128 = notifier_id.parse::<u128>().unwrap();
+
+    unsafe { core::mem::transmute::<u128, UniqueNotifierId>(value) }
+}
+
+pub(crate) fn data_segment_name(
+    publisher_id: &UniquePublisherId,
+    segment_index: usize,
+) -> FileName {
     let msg = "The system does not support the required file name length for the publishers data segment.";
     let origin = "data_segment_name()";
 
     let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
     fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    if segment_index != 0 {
+        fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+        fatal_panic!(from origin, when file.push_bytes(segment_index.to_string().as_bytes()), "{}", msg);
+    }
+    file
+}
+
+/// The name of a data segment that a publisher added when it grew. The generation is part of
+/// the name so that a replaced data segment never shares its name with its predecessor.
+pub(crate) fn data_segment_growth_name(
+    publisher_id: &UniquePublisherId,
+    slot: usize,
+    generation: u64,
+) -> FileName {
+    let msg = "The system does not support the required file name length for the publishers added data segment.";
+    let origin = "data_segment_growth_name()";
+
+    let mut file = data_segment_name(publisher_id, 0);
+    fatal_panic!(from origin, when file.push_bytes(format!("_g{}_{}", slot, generation).as_bytes()), "{}", msg);
     file
 }
+
+/// The name of the storage that contains the generations of the data segments a publisher
+/// added when it grew.
+pub(crate) fn data_segment_growth_storage_name(publisher_id: &UniquePublisherId) -> FileName {
+    let msg = "The system does not support the required file name length for the publishers data segment growth storage.";
+    let origin = "data_segment_growth_storage_name()";
+
+    let mut file = data_segment_name(publisher_id, 0);
+    fatal_panic!(from origin, when file.push_bytes(b"_growth"), "{}", msg);
+    file
+}
+
+/// Returns true when the data segment was added by the publisher when it grew.
+pub(crate) fn is_data_segment_growth_of(
+    data_segment: &FileName,
+    publisher_id: &UniquePublisherId,
+) -> bool {
+    let mut prefix = data_segment_name(publisher_id, 0).as_bytes().to_vec();
+    prefix.extend_from_slice(b"_g");
+    data_segment.as_bytes().starts_with(&prefix)
+        && *data_segment != data_segment_growth_storage_name(publisher_id)
+}
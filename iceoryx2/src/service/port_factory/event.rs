@@ -39,6 +39,7 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
 use crate::service::service_id::ServiceId;
+use crate::service::statistics::ServiceStatistics;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
 
@@ -93,10 +94,19 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
     ) -> Result<(), NodeListFailure> {
         nodes(
             self.service.__internal_state().dynamic_storage.get(),
-            self.service.__internal_state().shared_node.config(),
+            &self.service.__internal_state().shared_node.config(),
             callback,
         )
     }
+
+    fn statistics(&self) -> ServiceStatistics {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .snapshot()
+    }
 }
 
 impl<Service: service::Service> PortFactory<Service> {
@@ -143,6 +153,6 @@ impl<Service: service::Service> PortFactory<Service> {
     /// # }
     /// ```
     pub fn listener_builder(&self) -> PortFactoryListener<Service> {
-        PortFactoryListener { factory: self }
+        PortFactoryListener::new(self)
     }
 }
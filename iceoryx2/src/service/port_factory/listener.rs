@@ -29,7 +29,7 @@ use std::fmt::Debug;
 
 use iceoryx2_bb_log::fail;
 
-use crate::port::{listener::Listener, listener::ListenerCreateError};
+use crate::port::{event_id::EventIdFilter, listener::Listener, listener::ListenerCreateError};
 use crate::service;
 
 use super::event::PortFactory;
@@ -40,12 +40,31 @@ use super::event::PortFactory;
 #[derive(Debug)]
 pub struct PortFactoryListener<'factory, Service: service::Service> {
     pub(crate) factory: &'factory PortFactory<Service>,
+    event_id_filter: EventIdFilter,
 }
 
 impl<'factory, Service: service::Service> PortFactoryListener<'factory, Service> {
+    pub(crate) fn new(factory: &'factory PortFactory<Service>) -> Self {
+        Self {
+            factory,
+            event_id_filter: EventIdFilter::default(),
+        }
+    }
+
+    /// Defines the [`EventId`](crate::port::event_id::EventId)s the [`Listener`] receives.
+    /// Notifications with any other [`EventId`](crate::port::event_id::EventId) are discarded
+    /// by the [`Notifier`](crate::port::notifier::Notifier) and do not wake up the [`Listener`].
+    /// By default, every [`EventId`](crate::port::event_id::EventId) is received.
+    pub fn event_id_filter(mut self, value: EventIdFilter) -> Self {
+        self.event_id_filter = value;
+        self
+    }
+
     /// Creates the [`Listener`] port or returns a [`ListenerCreateError`] on failure.
     pub fn create(self) -> Result<Listener<Service>, ListenerCreateError> {
-        Ok(fail!(from self, when Listener::new(&self.factory.service),
-                    "Failed to create new Listener port."))
+        Ok(
+            fail!(from self, when Listener::new(&self.factory.service, self.event_id_filter),
+                    "Failed to create new Listener port."),
+        )
     }
 }
@@ -17,8 +17,14 @@ use crate::node::{NodeListFailure, NodeState};
 
 use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
+use super::statistics::ServiceStatistics;
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication and to acquire static and dynamic service information
+pub mod blackboard;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
@@ -38,9 +44,15 @@ pub mod publish_subscribe;
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create a [`Reader`](crate::port::reader::Reader)
+pub mod reader;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
+/// Factory to create a [`Writer`](crate::port::writer::Writer)
+pub mod writer;
+
 /// The trait that contains the interface of all port factories for any kind of
 /// [`crate::service::messaging_pattern::MessagingPattern`].
 pub trait PortFactory {
@@ -80,6 +92,9 @@ pub trait PortFactory {
         &self,
         callback: F,
     ) -> Result<(), NodeListFailure>;
+
+    /// Returns a snapshot of the aggregated counters of the [`crate::service::Service`].
+    fn statistics(&self) -> ServiceStatistics;
 }
 
 pub(crate) fn nodes<
@@ -43,17 +43,41 @@
 use std::{fmt::Debug, marker::PhantomData};
 
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
+use crate::service::statistics::ServiceStatistics;
 use crate::service::{self, dynamic_config, static_config};
 
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
+/// Failures that can occur when the maximum amount of ports of a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based service is raised with [`PortFactory::set_max_publishers()`] or
+/// [`PortFactory::set_max_subscribers()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum MaxPortsIncreaseError {
+    /// The requested value exceeds the ceiling the service was created with, see
+    /// [`crate::service::builder::publish_subscribe::Builder::max_publishers_ceiling()`] and
+    /// [`crate::service::builder::publish_subscribe::Builder::max_subscribers_ceiling()`].
+    ExceedsCeiling,
+    /// The requested value is smaller than the current maximum. The maximum can only be raised.
+    SmallerThanCurrentMaximum,
+}
+
+impl std::fmt::Display for MaxPortsIncreaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "MaxPortsIncreaseError::{:?}", self)
+    }
+}
+
+impl std::error::Error for MaxPortsIncreaseError {}
+
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
 /// It can acquire dynamic and static service informations and create
@@ -115,10 +139,19 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     ) -> Result<(), NodeListFailure> {
         nodes(
             self.service.__internal_state().dynamic_storage.get(),
-            self.service.__internal_state().shared_node.config(),
+            &self.service.__internal_state().shared_node.config(),
             callback,
         )
     }
+
+    fn statistics(&self) -> ServiceStatistics {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .statistics()
+            .snapshot()
+    }
 }
 
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
@@ -181,4 +214,110 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, Payload, UserHeader> {
         PortFactoryPublisher::new(self)
     }
+
+    /// Raises the maximum amount of [`crate::port::publisher::Publisher`] ports of the
+    /// [`Service`](crate::service::Service) up to the
+    /// [`static_config::publish_subscribe::StaticConfig::max_publishers_ceiling()`]. The new
+    /// maximum is visible to all processes that have opened the
+    /// [`Service`](crate::service::Service).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .max_publishers(2)
+    ///     .max_publishers_ceiling(8)
+    ///     .create()?;
+    ///
+    /// pubsub.set_max_publishers(4)?;
+    /// assert_eq!(pubsub.dynamic_config().max_publishers(), 4);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_max_publishers(&self, value: usize) -> Result<(), MaxPortsIncreaseError> {
+        let msg = "Unable to raise the maximum amount of publishers";
+        let ceiling = self
+            .service
+            .__internal_state()
+            .static_config
+            .publish_subscribe()
+            .max_publishers_ceiling();
+        if ceiling < value {
+            fail!(from self, with MaxPortsIncreaseError::ExceedsCeiling,
+                "{} to {} since it exceeds the ceiling of {}.", msg, value, ceiling);
+        }
+
+        let previous = self
+            .service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .raise_max_publishers(value);
+        if value < previous {
+            fail!(from self, with MaxPortsIncreaseError::SmallerThanCurrentMaximum,
+                "{} to {} since it is smaller than the current maximum of {}.", msg, value, previous);
+        }
+
+        Ok(())
+    }
+
+    /// Raises the maximum amount of [`crate::port::subscriber::Subscriber`] ports of the
+    /// [`Service`](crate::service::Service) up to the
+    /// [`static_config::publish_subscribe::StaticConfig::max_subscribers_ceiling()`]. The new
+    /// maximum is visible to all processes that have opened the
+    /// [`Service`](crate::service::Service).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .max_subscribers(2)
+    ///     .max_subscribers_ceiling(8)
+    ///     .create()?;
+    ///
+    /// pubsub.set_max_subscribers(4)?;
+    /// assert_eq!(pubsub.dynamic_config().max_subscribers(), 4);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_max_subscribers(&self, value: usize) -> Result<(), MaxPortsIncreaseError> {
+        let msg = "Unable to raise the maximum amount of subscribers";
+        let ceiling = self
+            .service
+            .__internal_state()
+            .static_config
+            .publish_subscribe()
+            .max_subscribers_ceiling();
+        if ceiling < value {
+            fail!(from self, with MaxPortsIncreaseError::ExceedsCeiling,
+                "{} to {} since it exceeds the ceiling of {}.", msg, value, ceiling);
+        }
+
+        let previous = self
+            .service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .raise_max_subscribers(value);
+        if value < previous {
+            fail!(from self, with MaxPortsIncreaseError::SmallerThanCurrentMaximum,
+                "{} to {} since it is smaller than the current maximum of {}.", msg, value, previous);
+        }
+
+        Ok(())
+    }
 }
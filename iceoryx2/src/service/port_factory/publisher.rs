@@ -53,6 +53,31 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Priority
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::port_factory::publisher::PublisherPriority;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let telemetry_publisher = pubsub.publisher_builder()
+//!                     .priority(PublisherPriority::Low)
+//!                     .create()?;
+//!
+//! // the subscriber receives the commands before the buffered telemetry data
+//! let command_publisher = pubsub.publisher_builder()
+//!                     .priority(PublisherPriority::High)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use std::fmt::Debug;
 
@@ -61,11 +86,13 @@ use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::publish_subscribe::PortFactory;
 use crate::{
+    config::MemoryPool,
     port::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         publisher::Publisher,
         publisher::PublisherCreateError,
-        DegrationAction, DegrationCallback,
+        DegrationAction, DegrationCallback, SubscriberConnectionCallback,
+        SubscriberConnectionEvent,
     },
     service,
 };
@@ -125,12 +152,55 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
     }
 }
 
+/// Defines how a slice [`Publisher`] reacts when a sample is loaned with more elements than
+/// [`Publisher::max_slice_len()`]. When the [`Publisher`] grows, it adds a new data segment
+/// that can hold the larger samples and the new slice length becomes its
+/// [`Publisher::max_slice_len()`]. A [`Publisher`] can grow only a limited number of times.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum AllocationStrategy {
+    /// The [`Publisher`] never grows, loaning a larger sample fails.
+    #[default]
+    Static,
+    /// The [`Publisher`] grows to exactly the requested slice length.
+    BestFit,
+    /// The [`Publisher`] grows to the next power of two of the requested slice length, so that
+    /// a slowly increasing slice length requires only a few new data segments.
+    PowerOfTwo,
+}
+
+/// Defines the priority class of a [`Publisher`]. A [`crate::port::subscriber::Subscriber`]
+/// receives the [`crate::sample::Sample`]s of a [`Publisher`] with a higher priority before
+/// the ones of a [`Publisher`] with a lower priority. Samples that must not wait behind a
+/// burst of other samples, like commands that share a service with telemetry data, shall be
+/// sent with a dedicated [`Publisher`] of a higher priority.
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash)]
+#[repr(u8)]
+pub enum PublisherPriority {
+    /// Received after the samples of all other priority classes.
+    Low,
+    /// The priority of a [`Publisher`] when nothing else is defined.
+    #[default]
+    Normal,
+    /// Received before the samples of [`PublisherPriority::Normal`] and
+    /// [`PublisherPriority::Low`].
+    High,
+    /// Received before the samples of all other priority classes.
+    Critical,
+}
+
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) subscriber_connection_callback: Option<SubscriberConnectionCallback<'static>>,
     pub(crate) max_slice_len: usize,
+    pub(crate) memory_pools: Vec<MemoryPool>,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) memory_lock: bool,
+    pub(crate) prefault_memory: bool,
+    pub(crate) priority: PublisherPriority,
+    pub(crate) statistics: bool,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -154,6 +224,7 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         Self {
             config: LocalPublisherConfig {
                 degration_callback: None,
+                subscriber_connection_callback: None,
                 max_slice_len: 1,
                 max_loaned_samples: factory
                     .service
@@ -171,6 +242,34 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
                     .defaults
                     .publish_subscribe
                     .unable_to_deliver_strategy,
+                memory_pools: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_memory_pools
+                    .clone(),
+                allocation_strategy: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_allocation_strategy,
+                memory_lock: false,
+                prefault_memory: false,
+                priority: PublisherPriority::default(),
+                statistics: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_statistics,
             },
             factory,
         }
@@ -178,18 +277,52 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
 
     /// Defines how many [`crate::sample_mut::SampleMut`] the [`Publisher`] can loan with
     /// [`Publisher::loan()`] or
-    /// [`Publisher::loan_uninit()`] in parallel.
+    /// [`Publisher::loan_uninit()`] in parallel. The default is
+    /// [`crate::config::PublishSubscribe::publisher_max_loaned_samples`].
     pub fn max_loaned_samples(mut self, value: usize) -> Self {
         self.config.max_loaned_samples = value;
         self
     }
 
-    /// Sets the [`UnableToDeliverStrategy`].
+    /// Sets the [`UnableToDeliverStrategy`]. The default is
+    /// [`crate::config::PublishSubscribe::unable_to_deliver_strategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
         self
     }
 
+    /// Sets the [`PublisherPriority`] of the [`Publisher`].
+    pub fn priority(mut self, value: PublisherPriority) -> Self {
+        self.config.priority = value;
+        self
+    }
+
+    /// Locks the data segment of the [`Publisher`] into the RAM at creation time so that it is
+    /// never swapped out. When it is enabled in [`crate::config::PublishSubscribe`] it is
+    /// enforced for every [`Publisher`] and cannot be disabled.
+    pub fn memory_lock(mut self, value: bool) -> Self {
+        self.config.memory_lock = value;
+        self
+    }
+
+    /// Touches every page of the data segment of the [`Publisher`] at creation time so that
+    /// no page fault occurs in the real-time path. When it is enabled in
+    /// [`crate::config::PublishSubscribe`] it is enforced for every [`Publisher`] and cannot be
+    /// disabled.
+    pub fn prefault_memory(mut self, value: bool) -> Self {
+        self.config.prefault_memory = value;
+        self
+    }
+
+    /// Defines if the [`Publisher`] counts its published and dropped samples in the
+    /// [`ServiceStatistics`](crate::service::statistics::ServiceStatistics). The counters are
+    /// shared by all processes that use the service, so every send accesses the same cache
+    /// lines. The default is [`crate::config::PublishSubscribe::publisher_statistics`].
+    pub fn statistics(mut self, value: bool) -> Self {
+        self.config.statistics = value;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -212,6 +345,26 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Sets a callback that is called whenever a [`crate::port::subscriber::Subscriber`]
+    /// connected to or disconnected from the [`Publisher`], for instance to start an expensive
+    /// data production only when someone is listening. The
+    /// [`crate::port::subscriber::Subscriber`]s that are already connected when the
+    /// [`Publisher`] is created are reported during [`PortFactoryPublisher::create()`].
+    /// Afterwards, the callback is called from within the [`Publisher`] calls that update the
+    /// connections, like [`crate::sample_mut::SampleMut::send()`],
+    /// [`Publisher::send_copy()`] or
+    /// [`UpdateConnections::update_connections()`](crate::port::update_connections::UpdateConnections::update_connections()),
+    /// therefore a [`Publisher`] that does not send has to update its connections
+    /// periodically.
+    pub fn on_subscriber_connection_change<F: Fn(SubscriberConnectionEvent) + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.config.subscriber_connection_callback =
+            Some(SubscriberConnectionCallback::new(callback));
+        self
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
         let origin = format!("{:?}", self);
@@ -231,4 +384,24 @@ impl<'factory, Service: service::Service, Payload: Debug, UserHeader: Debug>
         self.config.max_slice_len = value;
         self
     }
+
+    /// Sets the [`MemoryPool`]s of the [`Publisher`]. Every sample is loaned from the smallest
+    /// [`MemoryPool`] that can hold it, so that small and large samples do not occupy the same
+    /// amount of memory. If no [`MemoryPool`] can hold a sample with
+    /// [`PortFactoryPublisher::max_slice_len()`] elements an additional one is added.
+    /// Every [`MemoryPool`] provides at least as many samples as the [`Publisher`] can have in
+    /// flight, loaned, delivered and in the history.
+    /// When not set, the pools defined in [`crate::config::PublishSubscribe`] are used.
+    pub fn memory_pools(mut self, value: &[MemoryPool]) -> Self {
+        self.config.memory_pools = value.to_vec();
+        self
+    }
+
+    /// Sets the [`AllocationStrategy`] that defines whether and how the [`Publisher`] grows
+    /// when a sample with more than [`PortFactoryPublisher::max_slice_len()`] elements is
+    /// loaned. The default is [`crate::config::PublishSubscribe::publisher_allocation_strategy`].
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.config.allocation_strategy = value;
+        self
+    }
 }
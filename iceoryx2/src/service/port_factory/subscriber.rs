@@ -30,24 +30,89 @@
 //! ```
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use iceoryx2_bb_log::fail;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     port::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         subscriber::{Subscriber, SubscriberCreateError},
-        DegrationAction, DegrationCallback,
+        DegrationAction, DegrationCallback, ExpiredSampleCallback, PublisherDisconnectCallback,
     },
     service,
 };
 
 use super::publish_subscribe::PortFactory;
 
+/// Defines what happens when a [`crate::port::publisher::Publisher`] delivers a
+/// [`crate::sample::Sample`] to a [`Subscriber`] whose buffer is full. When it is not set with
+/// [`PortFactorySubscriber::overflow_strategy()`], the safe overflow setting of the service and
+/// the [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy)
+/// of the [`crate::port::publisher::Publisher`] decide.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub enum SubscriberOverflowStrategy {
+    /// The [`crate::port::publisher::Publisher`] blocks until the [`Subscriber`] has consumed a
+    /// [`crate::sample::Sample`] from the buffer and there is space again.
+    BlockPublisher,
+    /// The oldest [`crate::sample::Sample`] in the buffer is discarded to make room for the new
+    /// one.
+    DiscardOldest,
+    /// The new [`crate::sample::Sample`] is not delivered to the [`Subscriber`].
+    DiscardNewest,
+}
+
+impl SubscriberOverflowStrategy {
+    /// Returns true when the connection to the [`Subscriber`] overwrites the oldest sample on
+    /// overflow. Publisher and subscriber must agree on it when they create the connection.
+    pub(crate) fn enable_safe_overflow(
+        strategy: Option<SubscriberOverflowStrategy>,
+        service_default: bool,
+    ) -> bool {
+        match strategy {
+            Some(SubscriberOverflowStrategy::DiscardOldest) => true,
+            Some(_) => false,
+            None => service_default,
+        }
+    }
+}
+
+/// Defines in which order a [`Subscriber`] receives the [`crate::sample::Sample`]s when
+/// multiple [`crate::port::publisher::Publisher`]s are connected. The
+/// [`crate::sample::Sample`]s of a single [`crate::port::publisher::Publisher`] are always
+/// received in the order they were sent.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub enum SampleOrdering {
+    /// The buffers of the [`crate::port::publisher::Publisher`]s are drained one after another,
+    /// the ones with the highest
+    /// [`PublisherPriority`](crate::service::port_factory::publisher::PublisherPriority) first.
+    /// It is the cheapest option but the order between the
+    /// [`crate::port::publisher::Publisher`]s of the same priority is unspecified.
+    #[default]
+    PerPublisherFifo,
+    /// The [`crate::sample::Sample`]s of all [`crate::port::publisher::Publisher`]s are merged
+    /// by their
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()),
+    /// the oldest first. The
+    /// [`PublisherPriority`](crate::service::port_factory::publisher::PublisherPriority)
+    /// takes precedence over the timestamp. [`crate::sample::Sample`]s with the same timestamp
+    /// are ordered by the [`UniquePublisherId`]. To compare the timestamps the [`Subscriber`] holds back up to one
+    /// received [`crate::sample::Sample`] per [`crate::port::publisher::Publisher`] which
+    /// counts to the borrowed samples of the connection.
+    Timestamp,
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
+    pub(crate) overflow_strategy: Option<SubscriberOverflowStrategy>,
+    pub(crate) sample_ordering: SampleOrdering,
+    pub(crate) max_sample_age: Option<Duration>,
+    pub(crate) blocking_receive: bool,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) publisher_disconnect_callback: Option<PublisherDisconnectCallback<'static>>,
+    pub(crate) expired_sample_callback: Option<ExpiredSampleCallback<'static>>,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -68,21 +133,88 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
     PortFactorySubscriber<'factory, Service, PayloadType, UserHeader>
 {
     pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType, UserHeader>) -> Self {
+        let defaults = &factory
+            .service
+            .__internal_state()
+            .shared_node
+            .config()
+            .defaults
+            .publish_subscribe;
+
         Self {
             config: SubscriberConfig {
-                buffer_size: None,
+                buffer_size: defaults.subscriber_buffer_size.map(|v| v.max(1)),
+                overflow_strategy: defaults.subscriber_overflow_strategy,
+                sample_ordering: defaults.subscriber_sample_ordering,
+                max_sample_age: None,
+                blocking_receive: false,
                 degration_callback: None,
+                publisher_disconnect_callback: None,
+                expired_sample_callback: None,
             },
             factory,
         }
     }
 
     /// Defines the required buffer size of the [`Subscriber`]. Smallest possible value is `1`.
+    /// The default is [`crate::config::PublishSubscribe::subscriber_buffer_size`] and, when it
+    /// is not set, the maximum buffer size of the service.
     pub fn buffer_size(mut self, value: usize) -> Self {
         self.config.buffer_size = Some(value.max(1));
         self
     }
 
+    /// Defines the [`SubscriberOverflowStrategy`] of the [`Subscriber`] independent of the
+    /// safe overflow setting of the service and the
+    /// [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy)
+    /// of the [`crate::port::publisher::Publisher`]s. The default is
+    /// [`crate::config::PublishSubscribe::subscriber_overflow_strategy`].
+    pub fn overflow_strategy(mut self, value: SubscriberOverflowStrategy) -> Self {
+        self.config.overflow_strategy = Some(value);
+        self
+    }
+
+    /// Defines the [`SampleOrdering`] in which the [`Subscriber`] receives the
+    /// [`crate::sample::Sample`]s of multiple [`crate::port::publisher::Publisher`]s. The
+    /// default is [`crate::config::PublishSubscribe::subscriber_sample_ordering`].
+    pub fn sample_ordering(mut self, value: SampleOrdering) -> Self {
+        self.config.sample_ordering = value;
+        self
+    }
+
+    /// Defines the maximum age of a [`crate::sample::Sample`] at receive time. The age is
+    /// measured from the
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp())
+    /// on. Older [`crate::sample::Sample`]s are discarded by the receive calls of the
+    /// [`Subscriber`] instead of being handed out and are counted in
+    /// [`Subscriber::expired_samples()`]. By default, the age is not limited.
+    pub fn max_sample_age(mut self, value: Duration) -> Self {
+        self.config.max_sample_age = Some(value);
+        self
+    }
+
+    /// Sets a callback that is called whenever the [`Subscriber`] discards a
+    /// [`crate::sample::Sample`] that exceeded the
+    /// [`PortFactorySubscriber::max_sample_age()`]. It is called from within the receive calls
+    /// of the [`Subscriber`] with the [`UniquePublisherId`] of the sender and the age of the
+    /// [`crate::sample::Sample`].
+    pub fn on_expired_sample<F: Fn(UniquePublisherId, Duration) + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.config.expired_sample_callback = Some(ExpiredSampleCallback::new(callback));
+        self
+    }
+
+    /// Enables [`Subscriber::receive_blocking()`]. The [`Subscriber`] creates an internal event
+    /// and every [`crate::port::publisher::Publisher`] notifies it whenever it delivers a
+    /// [`crate::sample::Sample`], so that the [`Subscriber`] can wait without polling. Since
+    /// this adds a notification to every delivery, it is disabled by default.
+    pub fn enable_blocking_receive(mut self, value: bool) -> Self {
+        self.config.blocking_receive = value;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -105,6 +237,21 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Sets a callback that is called when the last [`crate::port::publisher::Publisher`] of
+    /// the service disconnected. A [`crate::port::publisher::Publisher`] of a dead
+    /// [`crate::node::Node`] disconnects as soon as the resources of the dead
+    /// [`crate::node::Node`] are cleaned up. The callback is called from within the
+    /// [`Subscriber`] calls that update the connections, like [`Subscriber::receive()`],
+    /// [`Subscriber::has_samples()`] or [`Subscriber::publisher_connection_state()`].
+    pub fn on_publisher_disconnect<F: Fn(UniquePublisherId) + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.config.publisher_disconnect_callback =
+            Some(PublisherDisconnectCallback::new(callback));
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         self,
@@ -0,0 +1,63 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64, f32>()
+//!     .add(0, 0.0)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::{writer::Writer, writer::WriterCreateError};
+use crate::service;
+
+use super::blackboard::PortFactory;
+
+/// Factory to create a new [`Writer`] port/endpoint for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based communication.
+#[derive(Debug)]
+pub struct PortFactoryWriter<
+    'factory,
+    Service: service::Service,
+    KeyType: Copy + Eq + Debug + 'static,
+    ValueType: Copy + Debug + 'static,
+> {
+    pub(crate) factory: &'factory PortFactory<Service, KeyType, ValueType>,
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        KeyType: Copy + Eq + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > PortFactoryWriter<'factory, Service, KeyType, ValueType>
+{
+    /// Creates the [`Writer`] port or returns a [`WriterCreateError`] on failure.
+    pub fn create(self) -> Result<Writer<Service, KeyType, ValueType>, WriterCreateError> {
+        Ok(fail!(from self, when Writer::new(&self.factory.service),
+                    "Failed to create new Writer port."))
+    }
+}
@@ -24,22 +24,43 @@
 //! println!("max listeners:                {:?}", event.static_config().max_listeners());
 //! println!("max notifiers:                {:?}", event.static_config().max_notifiers());
 //! println!("event id max value:           {:?}", event.static_config().event_id_max_value());
+//! println!("payload size:                 {:?}", event.static_config().payload_size());
+//! println!("deadline:                     {:?}", event.static_config().deadline());
+//!
+//! for (event_id, name) in event.static_config().event_id_names() {
+//!     println!("event id {:?}:                {}", event_id, name);
+//! }
 //!
 //! # Ok(())
 //! # }
 //! ```
+use core::time::Duration;
+
 use crate::config;
+use crate::port::event_id::EventId;
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EventIdName {
+    pub(crate) id: usize,
+    pub(crate) name: String,
+}
+
 /// The static configuration of an [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service. Contains all parameters that do not change during the lifetime of a
 /// [`Service`](crate::service::Service).
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct StaticConfig {
     pub(crate) max_notifiers: usize,
     pub(crate) max_listeners: usize,
     pub(crate) max_nodes: usize,
     pub(crate) event_id_max_value: usize,
+    #[serde(default)]
+    pub(crate) payload_size: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deadline: Option<Duration>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) event_id_names: Vec<EventIdName>,
 }
 
 impl StaticConfig {
@@ -49,6 +70,9 @@ impl StaticConfig {
             max_listeners: config.defaults.event.max_listeners,
             max_nodes: config.defaults.event.max_nodes,
             event_id_max_value: config.defaults.event.event_id_max_value,
+            payload_size: 0,
+            deadline: None,
+            event_id_names: Vec::new(),
         }
     }
 
@@ -72,4 +96,50 @@ impl StaticConfig {
     pub fn event_id_max_value(&self) -> usize {
         self.event_id_max_value
     }
+
+    /// Returns the size in bytes of the payload every notification carries. When it is 0,
+    /// notifications carry only an [`EventId`](crate::port::event_id::EventId).
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns the period in which a notification is expected. When no notification is sent
+    /// within the period, the deadline is missed, see
+    /// [`Listener::missed_deadline_count()`](crate::port::listener::Listener::missed_deadline_count()).
+    /// When it is [`None`], no deadline is monitored.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns the human-readable name of the [`EventId`] when one was registered with
+    /// [`crate::service::builder::event::Builder::event_id_name()`], otherwise [`None`].
+    pub fn event_id_name(&self, id: EventId) -> Option<&str> {
+        self.event_id_names
+            .binary_search_by_key(&id.as_value(), |entry| entry.id)
+            .ok()
+            .map(|index| self.event_id_names[index].name.as_str())
+    }
+
+    /// Returns all registered [`EventId`]s with their human-readable name in ascending order.
+    pub fn event_id_names(&self) -> impl Iterator<Item = (EventId, &str)> {
+        self.event_id_names
+            .iter()
+            .map(|entry| (EventId::new(entry.id), entry.name.as_str()))
+    }
+
+    pub(crate) fn set_event_id_name(&mut self, id: EventId, name: &str) {
+        match self
+            .event_id_names
+            .binary_search_by_key(&id.as_value(), |entry| entry.id)
+        {
+            Ok(index) => self.event_id_names[index].name = name.to_string(),
+            Err(index) => self.event_id_names.insert(
+                index,
+                EventIdName {
+                    id: id.as_value(),
+                    name: name.to_string(),
+                },
+            ),
+        }
+    }
 }
@@ -53,6 +53,15 @@ pub struct TypeDetail {
     /// The ABI-required minimum alignment of the underlying type calculated by [`core::mem::align_of`].
     /// It may be set by users with a larger alignment, e.g. the memory provided by allocator used by SIMD.
     pub alignment: usize,
+    /// The [`TypeLayoutHash::TYPE_LAYOUT_HASH`](iceoryx2_bb_elementary::type_layout_hash::TypeLayoutHash)
+    /// of the underlying type. It is only set when the layout verification was requested
+    /// and is then compared to the one of the opening side.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "layout_hash_format"
+    )]
+    pub layout_hash: Option<u64>,
 }
 
 impl TypeDetail {
@@ -63,6 +72,40 @@ impl TypeDetail {
             type_name: core::any::type_name::<T>().to_string(),
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            layout_hash: None,
+        }
+    }
+
+    fn has_compatible_layout_hash(&self, rhs: &Self) -> bool {
+        match (self.layout_hash, rhs.layout_hash) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => true,
+        }
+    }
+}
+
+// TOML supports only signed 64-bit integers, therefore the hash is stored as hex string.
+mod layout_hash_format {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_str(&format!("{:016x}", value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => u64::from_str_radix(&value, 16)
+                .map(Some)
+                .map_err(D::Error::custom),
+            None => Ok(None),
         }
     }
 }
@@ -131,10 +174,14 @@ impl MessageTypeDetails {
             && self.user_header.variant == rhs.user_header.variant
             && self.user_header.size == rhs.user_header.size
             && self.user_header.alignment <= rhs.user_header.alignment
+            && self
+                .user_header
+                .has_compatible_layout_hash(&rhs.user_header)
             && self.payload.type_name == rhs.payload.type_name
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
+            && self.payload.has_compatible_layout_hash(&rhs.payload)
     }
 }
 
@@ -170,12 +217,14 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
             payload: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "iceoryx2::service::static_config::message_type_details::tests::test_from::MyPayload".to_string(),
                 size: 16,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
         };
         assert_that!(sut, eq expected);
@@ -187,18 +236,21 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4,
+                layout_hash: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "bool".to_string(),
                 size: 1,
                 alignment: 1,
+                layout_hash: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::Dynamic,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
         };
         assert_that!(sut, eq expected);
@@ -375,18 +427,21 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
         };
         // smaller to bigger is allowed.
@@ -410,18 +465,21 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
         };
         // bigger to smaller is invalid.
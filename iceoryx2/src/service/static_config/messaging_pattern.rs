@@ -13,6 +13,7 @@
 //! Stores the [`Service`](crate::service::Service) messaging pattern specific static configuration.
 use std::fmt::Display;
 
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
 use crate::service::static_config::publish_subscribe;
 use serde::{Deserialize, Serialize};
@@ -30,6 +31,10 @@ pub enum MessagingPattern {
     /// Stores the static config of the
     /// [`service::MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
     Event(event::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+    Blackboard(blackboard::StaticConfig),
 }
 
 impl Display for MessagingPattern {
@@ -37,6 +42,7 @@ impl Display for MessagingPattern {
         match self {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
+            MessagingPattern::Blackboard(_) => write!(f, "Blackboard"),
         }
     }
 }
@@ -47,13 +53,27 @@ impl MessagingPattern {
         std::mem::discriminant(self) == std::mem::discriminant(rhs)
     }
 
+    pub(crate) fn kind(&self) -> crate::service::messaging_pattern::MessagingPattern {
+        match self {
+            MessagingPattern::PublishSubscribe(_) => {
+                crate::service::messaging_pattern::MessagingPattern::PublishSubscribe
+            }
+            MessagingPattern::Event(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Event
+            }
+            MessagingPattern::Blackboard(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Blackboard
+            }
+        }
+    }
+
     pub(crate) fn required_amount_of_samples_per_data_segment(
         &self,
         publisher_max_loaned_samples: usize,
     ) -> usize {
         match self {
             MessagingPattern::PublishSubscribe(v) => {
-                v.max_subscribers
+                v.max_subscribers_ceiling()
                     * (v.subscriber_max_buffer_size + v.subscriber_max_borrowed_samples)
                     + v.history_size
                     + publisher_max_loaned_samples
@@ -83,6 +103,7 @@ mod tests {
         let mut new_defaults = config::Defaults {
             publish_subscribe: cfg.defaults.publish_subscribe.clone(),
             event: cfg.defaults.event.clone(),
+            blackboard: cfg.defaults.blackboard.clone(),
         };
         new_defaults.event.event_id_max_value -= 1;
         new_defaults.publish_subscribe.max_nodes -= 1;
@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The static service configuration of an
+/// [`MessagingPattern::Blackboard`]
+/// based service.
+pub mod blackboard;
+
 /// The static service configuration of an
 /// [`MessagingPattern::Event`]
 /// based service.
@@ -26,6 +31,10 @@ pub mod message_type_details;
 
 pub mod messaging_pattern;
 
+/// Contains the endianness, pointer width and alignments of the platform that created the
+/// service
+pub mod platform_details;
+
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
@@ -33,6 +42,7 @@ use serde::{Deserialize, Serialize};
 use crate::config;
 
 use self::messaging_pattern::MessagingPattern;
+use self::platform_details::PlatformDetails;
 
 use super::{attribute::AttributeSet, service_id::ServiceId, service_name::ServiceName};
 
@@ -43,6 +53,7 @@ pub struct StaticConfig {
     service_name: ServiceName,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
+    platform: PlatformDetails,
 }
 
 impl StaticConfig {
@@ -59,6 +70,7 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            platform: PlatformDetails::current(),
         }
     }
 
@@ -76,6 +88,24 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            platform: PlatformDetails::current(),
+        }
+    }
+
+    pub(crate) fn new_blackboard<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(config));
+        Self {
+            service_id: ServiceId::new::<Hasher>(
+                service_name,
+                crate::service::messaging_pattern::MessagingPattern::Blackboard,
+            ),
+            service_name: service_name.clone(),
+            messaging_pattern,
+            attributes: AttributeSet::new(),
+            platform: PlatformDetails::current(),
         }
     }
 
@@ -99,6 +129,12 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns the [`PlatformDetails`] of the process that created the
+    /// [`crate::service::Service`]
+    pub fn platform(&self) -> &PlatformDetails {
+        &self.platform
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -123,6 +159,25 @@ impl StaticConfig {
         }
     }
 
+    pub(crate) fn blackboard(&self) -> &blackboard::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::StaticConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
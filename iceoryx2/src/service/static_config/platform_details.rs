@@ -0,0 +1,87 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use serde::{Deserialize, Serialize};
+
+/// The byte order of a platform.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Endianness {
+    /// The least significant byte is stored first.
+    Little,
+    /// The most significant byte is stored first.
+    Big,
+}
+
+/// Contains the properties of the platform that created a [`crate::service::Service`]. The
+/// data that is shared between the processes of a [`crate::service::Service`], like the
+/// payload, the headers and the management structures, is only interpreted in the same way
+/// when the endianness, the pointer width and the alignment of the primitive types are equal.
+/// The alignment of `u128` for instance differs between compiler versions on some
+/// architectures.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PlatformDetails {
+    endianness: Endianness,
+    pointer_width: usize,
+    u64_alignment: usize,
+    u128_alignment: usize,
+    architecture: String,
+}
+
+impl PlatformDetails {
+    pub(crate) fn current() -> Self {
+        Self {
+            endianness: if cfg!(target_endian = "big") {
+                Endianness::Big
+            } else {
+                Endianness::Little
+            },
+            pointer_width: core::mem::size_of::<usize>() * 8,
+            u64_alignment: core::mem::align_of::<u64>(),
+            u128_alignment: core::mem::align_of::<u128>(),
+            architecture: std::env::consts::ARCH.to_string(),
+        }
+    }
+
+    /// Returns the [`Endianness`] of the platform.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Returns the width of a pointer in bits.
+    pub fn pointer_width(&self) -> usize {
+        self.pointer_width
+    }
+
+    /// Returns the alignment of `u64` in bytes.
+    pub fn u64_alignment(&self) -> usize {
+        self.u64_alignment
+    }
+
+    /// Returns the alignment of `u128` in bytes.
+    pub fn u128_alignment(&self) -> usize {
+        self.u128_alignment
+    }
+
+    /// Returns the CPU architecture, see [`std::env::consts::ARCH`]. It is informational only,
+    /// different architectures with the same memory layout can share a
+    /// [`crate::service::Service`].
+    pub fn architecture(&self) -> &str {
+        &self.architecture
+    }
+
+    pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+        self.endianness == rhs.endianness
+            && self.pointer_width == rhs.pointer_width
+            && self.u64_alignment == rhs.u64_alignment
+            && self.u128_alignment == rhs.u128_alignment
+    }
+}
@@ -28,6 +28,8 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("huge pages:                       {:?}", pubsub.static_config().requests_huge_pages());
+//! println!("publisher max loaned ceiling:     {:?}", pubsub.static_config().publisher_max_loaned_samples_ceiling());
 //!
 //! # Ok(())
 //! # }
@@ -45,11 +47,19 @@ use serde::{Deserialize, Serialize};
 pub struct StaticConfig {
     pub(crate) max_subscribers: usize,
     pub(crate) max_publishers: usize,
+    #[serde(default)]
+    pub(crate) max_subscribers_ceiling: usize,
+    #[serde(default)]
+    pub(crate) max_publishers_ceiling: usize,
     pub(crate) max_nodes: usize,
     pub(crate) history_size: usize,
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    #[serde(default)]
+    pub(crate) huge_pages: bool,
+    #[serde(default)]
+    pub(crate) publisher_max_loaned_samples_ceiling: usize,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
@@ -58,6 +68,8 @@ impl StaticConfig {
         Self {
             max_subscribers: config.defaults.publish_subscribe.max_subscribers,
             max_publishers: config.defaults.publish_subscribe.max_publishers,
+            max_subscribers_ceiling: 0,
+            max_publishers_ceiling: 0,
             max_nodes: config.defaults.publish_subscribe.max_nodes,
             history_size: config.defaults.publish_subscribe.publisher_history_size,
             subscriber_max_buffer_size: config
@@ -69,6 +81,11 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            huge_pages: config.defaults.publish_subscribe.huge_pages,
+            publisher_max_loaned_samples_ceiling: config
+                .defaults
+                .publish_subscribe
+                .publisher_max_loaned_samples_ceiling,
             message_type_details: MessageTypeDetails::default(),
         }
     }
@@ -79,16 +96,34 @@ impl StaticConfig {
         self.max_nodes
     }
 
-    /// Returns the maximum supported amount of [`crate::port::publisher::Publisher`] ports
+    /// Returns the maximum supported amount of [`crate::port::publisher::Publisher`] ports the
+    /// [`crate::service::Service`] was created with. The current limit is provided by
+    /// [`crate::service::dynamic_config::publish_subscribe::DynamicConfig::max_publishers()`].
     pub fn max_publishers(&self) -> usize {
         self.max_publishers
     }
 
-    /// Returns the maximum supported amount of [`crate::port::subscriber::Subscriber`] ports
+    /// Returns the maximum supported amount of [`crate::port::subscriber::Subscriber`] ports the
+    /// [`crate::service::Service`] was created with. The current limit is provided by
+    /// [`crate::service::dynamic_config::publish_subscribe::DynamicConfig::max_subscribers()`].
     pub fn max_subscribers(&self) -> usize {
         self.max_subscribers
     }
 
+    /// Returns the value up to which the maximum amount of
+    /// [`crate::port::publisher::Publisher`] ports can be raised at runtime with
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::set_max_publishers()`].
+    pub fn max_publishers_ceiling(&self) -> usize {
+        self.max_publishers.max(self.max_publishers_ceiling)
+    }
+
+    /// Returns the value up to which the maximum amount of
+    /// [`crate::port::subscriber::Subscriber`] ports can be raised at runtime with
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::set_max_subscribers()`].
+    pub fn max_subscribers_ceiling(&self) -> usize {
+        self.max_subscribers.max(self.max_subscribers_ceiling)
+    }
+
     /// Returns the maximum history size that can be requested on connect.
     pub fn history_size(&self) -> usize {
         self.history_size
@@ -113,6 +148,20 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if the data segments of the [`crate::port::publisher::Publisher`]s shall be
+    /// backed by huge pages, otherwise false. Whether a data segment is actually backed by huge
+    /// pages is reported by [`crate::port::publisher::MemoryPoolStatistics::uses_huge_pages()`].
+    pub fn requests_huge_pages(&self) -> bool {
+        self.huge_pages
+    }
+
+    /// Returns the value up to which a [`crate::port::publisher::Publisher`] can raise its
+    /// maximum number of loaned samples with
+    /// [`crate::port::publisher::Publisher::set_max_loaned_samples()`].
+    pub fn publisher_max_loaned_samples_ceiling(&self) -> usize {
+        self.publisher_max_loaned_samples_ceiling
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
@@ -0,0 +1,159 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // the publishers must enable the statistics, e.g. with
+//! // `service.publisher_builder().statistics(true)`, to count the published samples
+//!
+//! // a monitoring agent acquires the statistics without opening the service
+//! ipc::Service::list(Config::global_config(), |service| {
+//!     if let Some(dynamic_details) = &service.dynamic_details {
+//!         let statistics = dynamic_details.statistics;
+//!         println!("{}: {} samples published, {} bytes published",
+//!             service.static_details.name(),
+//!             statistics.samples_published(),
+//!             statistics.bytes_published());
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::Ordering;
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+/// A snapshot of the aggregated counters of a [`Service`](crate::service::Service). The
+/// counters are stored in the dynamic configuration of the
+/// [`Service`](crate::service::Service) and accumulate over its whole lifetime. They can be
+/// acquired without participating in the communication with
+/// [`Service::list()`](crate::service::Service::list()) or
+/// [`Service::details()`](crate::service::Service::details()), or with
+/// [`PortFactory::statistics()`](crate::service::port_factory::PortFactory::statistics()).
+///
+/// The published and dropped samples are only counted by the
+/// [`Publisher`](crate::port::publisher::Publisher)s that enabled it with
+/// [`PortFactoryPublisher::statistics()`](crate::service::port_factory::publisher::PortFactoryPublisher::statistics()),
+/// since every count is an atomic operation on memory that is shared by all processes.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ServiceStatistics {
+    samples_published: u64,
+    bytes_published: u64,
+    samples_dropped: u64,
+    notifications_sent: u64,
+    connected_ports: u64,
+    disconnected_ports: u64,
+}
+
+impl ServiceStatistics {
+    /// Returns the number of samples that were sent by all
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn samples_published(&self) -> u64 {
+        self.samples_published
+    }
+
+    /// Returns the number of payload bytes that were sent by all
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn bytes_published(&self) -> u64 {
+        self.bytes_published
+    }
+
+    /// Returns the number of samples that were not delivered to a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) since its buffer was full or that
+    /// were overwritten in the buffer of a [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// on overflow.
+    pub fn samples_dropped(&self) -> u64 {
+        self.samples_dropped
+    }
+
+    /// Returns the number of notifications that were sent by all
+    /// [`Notifier`](crate::port::notifier::Notifier)s.
+    pub fn notifications_sent(&self) -> u64 {
+        self.notifications_sent
+    }
+
+    /// Returns the number of ports that were created for the
+    /// [`Service`](crate::service::Service).
+    pub fn connected_ports(&self) -> u64 {
+        self.connected_ports
+    }
+
+    /// Returns the number of ports that were removed from the
+    /// [`Service`](crate::service::Service), either because they went out of scope or because
+    /// their [`Node`](crate::node::Node) died.
+    pub fn disconnected_ports(&self) -> u64 {
+        self.disconnected_ports
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct StatisticsCounters {
+    samples_published: IoxAtomicU64,
+    bytes_published: IoxAtomicU64,
+    samples_dropped: IoxAtomicU64,
+    notifications_sent: IoxAtomicU64,
+    connected_ports: IoxAtomicU64,
+    disconnected_ports: IoxAtomicU64,
+}
+
+impl StatisticsCounters {
+    pub(crate) fn new() -> Self {
+        Self {
+            samples_published: IoxAtomicU64::new(0),
+            bytes_published: IoxAtomicU64::new(0),
+            samples_dropped: IoxAtomicU64::new(0),
+            notifications_sent: IoxAtomicU64::new(0),
+            connected_ports: IoxAtomicU64::new(0),
+            disconnected_ports: IoxAtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn add_published_sample(&self, number_of_bytes: u64) {
+        self.samples_published.fetch_add(1, Ordering::Relaxed);
+        self.bytes_published
+            .fetch_add(number_of_bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_dropped_sample(&self) {
+        self.samples_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_sent_notification(&self) {
+        self.notifications_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_connected_port(&self) {
+        self.connected_ports.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_disconnected_port(&self) {
+        self.disconnected_ports.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ServiceStatistics {
+        ServiceStatistics {
+            samples_published: self.samples_published.load(Ordering::Relaxed),
+            bytes_published: self.bytes_published.load(Ordering::Relaxed),
+            samples_dropped: self.samples_dropped.load(Ordering::Relaxed),
+            notifications_sent: self.notifications_sent.load(Ordering::Relaxed),
+            connected_ports: self.connected_ports.load(Ordering::Relaxed),
+            disconnected_ports: self.disconnected_ports.load(Ordering::Relaxed),
+        }
+    }
+}
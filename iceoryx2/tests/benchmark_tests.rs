@@ -0,0 +1,110 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod benchmark {
+    use iceoryx2::benchmark::{Benchmark, BenchmarkError};
+    use iceoryx2::node::NodeBuilder;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    const ITERATIONS: u64 = 100;
+    const PAYLOAD_SIZE: usize = 128;
+
+    fn create_benchmark() -> Benchmark {
+        Benchmark::new()
+            .iterations(ITERATIONS)
+            .warmup_iterations(10)
+            .payload_size(PAYLOAD_SIZE)
+    }
+
+    #[test]
+    fn latency_measures_every_iteration<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let report = create_benchmark().latency(&node).unwrap();
+
+        assert_that!(report.iterations(), eq ITERATIONS);
+        assert_that!(report.payload_size(), eq PAYLOAD_SIZE);
+        assert_that!(report.min(), le report.percentile(50.0));
+        assert_that!(report.percentile(50.0), le report.percentile(99.0));
+        assert_that!(report.percentile(99.0), le report.max());
+        assert_that!(report.mean(), ge report.min());
+        assert_that!(report.mean(), le report.max());
+        assert_that!(report.percentile(0.0), eq report.min());
+        assert_that!(report.percentile(100.0), eq report.max());
+    }
+
+    #[test]
+    fn latency_with_send_copy_works<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let report = create_benchmark().send_copy(true).latency(&node).unwrap();
+
+        assert_that!(report.iterations(), eq ITERATIONS);
+    }
+
+    #[test]
+    fn throughput_receives_every_sample<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let report = create_benchmark().throughput(&node).unwrap();
+
+        assert_that!(report.iterations(), eq ITERATIONS);
+        assert_that!(report.payload_size(), eq PAYLOAD_SIZE);
+        assert_that!(report.samples_per_second(), gt 0.0);
+        assert_that!(report.bytes_per_second(), eq report.samples_per_second() * PAYLOAD_SIZE as f64);
+    }
+
+    #[test]
+    fn throughput_without_iterations_reports_no_samples_per_second<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let report = create_benchmark()
+            .iterations(0)
+            .warmup_iterations(0)
+            .throughput(&node)
+            .unwrap();
+
+        assert_that!(report.iterations(), eq 0);
+        assert_that!(report.samples_per_second(), eq 0.0);
+        assert_that!(report.bytes_per_second(), eq 0.0);
+    }
+
+    #[test]
+    fn benchmark_can_be_repeated_with_the_same_node<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let benchmark = create_benchmark();
+
+        assert_that!(benchmark.latency(&node), is_ok);
+        assert_that!(benchmark.latency(&node), is_ok);
+        assert_that!(benchmark.throughput(&node), is_ok);
+    }
+
+    #[test]
+    fn benchmark_error_display_works<Sut: Service>() {
+        assert_that!(
+            format!("{}", BenchmarkError::CommunicationFailure), eq "BenchmarkError::CommunicationFailure");
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -11,7 +11,16 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 mod node_name {
+    use iceoryx2::config::{
+        self, ConfigDiagnosticKind, ConfigDiagnosticSeverity, LOG_LEVEL_ENV_VAR,
+    };
     use iceoryx2::{config::DEFAULT_CONFIG_FILE, prelude::*};
+    use iceoryx2_bb_log::get_log_level;
+    use iceoryx2_bb_posix::config::test_directory;
+    use iceoryx2_bb_posix::directory::Directory;
+    use iceoryx2_bb_posix::file::{CreationMode, File, FileBuilder, Permission};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_system_types::file_path::*;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::{assert_that, test_requires};
@@ -41,4 +50,215 @@ mod node_name {
 
         assert_that!(default_config, eq file_config);
     }
+
+    #[test]
+    fn log_level_is_applied_and_can_be_overridden_with_env_var() {
+        let mut config = Config::default();
+        config.log.level = Some(config::LogLevel::Error);
+        config.log.apply();
+        assert_that!(get_log_level(), eq LogLevel::Error as u8);
+
+        std::env::set_var(LOG_LEVEL_ENV_VAR, "fatal");
+        config.log.apply();
+        std::env::remove_var(LOG_LEVEL_ENV_VAR);
+        assert_that!(get_log_level(), eq LogLevel::Fatal as u8);
+
+        std::env::set_var(LOG_LEVEL_ENV_VAR, "not_a_log_level");
+        config.log.apply();
+        std::env::remove_var(LOG_LEVEL_ENV_VAR);
+        assert_that!(get_log_level(), eq LogLevel::Error as u8);
+    }
+
+    #[test]
+    fn saved_config_can_be_loaded_again() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.max_publishers = 13;
+        config.global.prefix = FileName::new(b"saved_config_").unwrap();
+
+        let directory = test_directory();
+        Directory::create(&directory, Permission::OWNER_ALL).unwrap();
+        let file_name = FileName::new(
+            format!(
+                "config_tests_{}.toml",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        let file_path = FilePath::from_path_and_file(&directory, &file_name).unwrap();
+
+        assert_that!(config.save(&file_path), is_ok);
+        let loaded_config = Config::from_file(&file_path);
+        File::remove(&file_path).unwrap();
+
+        assert_that!(loaded_config, is_ok);
+        assert_that!(loaded_config.unwrap(), eq config);
+    }
+
+    #[test]
+    fn config_file_without_settings_of_newer_releases_can_be_loaded() {
+        // the config file of the first release, every setting that was added afterwards
+        // must be optional
+        const CONFIG_FILE_CONTENTS: &str = r#"
+[global]
+root-path-unix                              = '/tmp/iceoryx2/'
+root-path-windows                           = 'c:\Temp\iceoryx2\'
+prefix                                      = 'iox2_'
+
+[global.node]
+directory                                   = 'nodes'
+monitor-suffix                              = '.node_monitor'
+static-config-suffix                        = '.details'
+service-tag-suffix                          = '.service_tag'
+cleanup-dead-nodes-on-creation              = true
+cleanup-dead-nodes-on-destruction           = true
+
+[global.service]
+directory                                   = 'services'
+publisher-data-segment-suffix               = '.publisher_data'
+static-config-storage-suffix                = '.service'
+dynamic-config-storage-suffix               = '.dynamic'
+event-connection-suffix                     = '.event'
+connection-suffix                           = '.connection'
+creation-timeout.secs                       = 0
+creation-timeout.nanos                      = 500000000
+
+[defaults.publish-subscribe]
+max-subscribers                             = 8
+max-publishers                              = 2
+max-nodes                                   = 20
+publisher-history-size                      = 1
+subscriber-max-buffer-size                  = 2
+subscriber-max-borrowed-samples             = 2
+publisher-max-loaned-samples                = 2
+enable-safe-overflow                        = true
+unable-to-deliver-strategy                  = 'Block'
+subscriber-expired-connection-buffer        = 128
+
+[defaults.event]
+max-listeners                               = 16
+max-notifiers                               = 16
+max-nodes                                   = 36
+event-id-max-value                          = 4294967295
+"#;
+
+        let directory = test_directory();
+        Directory::create(&directory, Permission::OWNER_ALL).unwrap();
+        let file_name = FileName::new(
+            format!(
+                "config_tests_{}.toml",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        let file_path = FilePath::from_path_and_file(&directory, &file_name).unwrap();
+
+        let mut file = FileBuilder::new(&file_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+            .unwrap();
+        file.write(CONFIG_FILE_CONTENTS.as_bytes()).unwrap();
+        drop(file);
+
+        let loaded_config = Config::from_file(&file_path);
+        File::remove(&file_path).unwrap();
+
+        assert_that!(loaded_config, is_ok);
+        assert_that!(loaded_config.unwrap(), eq Config::default());
+    }
+
+    #[test]
+    fn env_vars_override_config_settings() {
+        let mut config = Config::default();
+        config.defaults.event.max_listeners = 3;
+
+        std::env::set_var("IOX2_GLOBAL_PREFIX", "env_override_");
+        std::env::set_var("IOX2_DEFAULTS_EVENT_MAX_LISTENERS", "21");
+        std::env::set_var(
+            "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_ENABLE_SAFE_OVERFLOW",
+            "false",
+        );
+        std::env::set_var("IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS", "7");
+        std::env::set_var("IOX2_DEFAULTS_EVENT_MAX_NOTIFIERS", "not_a_number");
+        std::env::set_var("IOX2_DEFAULTS_NOT_A_SETTING", "1");
+        config.apply_env_overrides();
+        std::env::remove_var("IOX2_GLOBAL_PREFIX");
+        std::env::remove_var("IOX2_DEFAULTS_EVENT_MAX_LISTENERS");
+        std::env::remove_var("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_ENABLE_SAFE_OVERFLOW");
+        std::env::remove_var("IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS");
+        std::env::remove_var("IOX2_DEFAULTS_EVENT_MAX_NOTIFIERS");
+        std::env::remove_var("IOX2_DEFAULTS_NOT_A_SETTING");
+
+        assert_that!(config.global.prefix, eq FileName::new(b"env_override_").unwrap());
+        assert_that!(config.defaults.event.max_listeners, eq 21);
+        assert_that!(config.defaults.publish_subscribe.enable_safe_overflow, eq false);
+        assert_that!(config.global.service.creation_timeout.as_secs(), eq 7);
+        assert_that!(config.defaults.event.max_notifiers, eq Config::default().defaults.event.max_notifiers);
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_that!(Config::default().validate(), is_empty);
+    }
+
+    #[test]
+    fn validate_reports_unsupported_zero_values_as_warnings() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.max_publishers = 0;
+        config.defaults.event.max_listeners = 0;
+
+        let sut = config.validate();
+
+        assert_that!(sut, len 2);
+        for diagnostic in &sut {
+            assert_that!(diagnostic.severity(), eq ConfigDiagnosticSeverity::Warning);
+            assert_that!(diagnostic.kind(), eq ConfigDiagnosticKind::OutOfRange);
+        }
+        assert_that!(sut[0].setting(), eq "defaults.publish-subscribe.max-publishers");
+        assert_that!(sut[1].setting(), eq "defaults.event.max-listeners");
+    }
+
+    #[test]
+    fn validate_reports_subscriber_buffer_size_exceeding_max_buffer_size() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.subscriber_max_buffer_size = 4;
+        config.defaults.publish_subscribe.subscriber_buffer_size = Some(5);
+
+        let sut = config.validate();
+
+        assert_that!(sut, len 1);
+        assert_that!(sut[0].severity(), eq ConfigDiagnosticSeverity::Error);
+        assert_that!(sut[0].kind(), eq ConfigDiagnosticKind::ConflictingSettings);
+        assert_that!(sut[0].setting(), eq "defaults.publish-subscribe.subscriber-buffer-size");
+    }
+
+    #[test]
+    fn validate_reports_history_exceeding_buffer_size_without_safe_overflow() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.subscriber_max_buffer_size = 2;
+        config.defaults.publish_subscribe.publisher_history_size = 3;
+        assert_that!(config.validate(), is_empty);
+
+        config.defaults.publish_subscribe.enable_safe_overflow = false;
+        let sut = config.validate();
+
+        assert_that!(sut, len 1);
+        assert_that!(sut[0].severity(), eq ConfigDiagnosticSeverity::Error);
+        assert_that!(sut[0].kind(), eq ConfigDiagnosticKind::ConflictingSettings);
+        assert_that!(sut[0].setting(), eq "defaults.publish-subscribe.publisher-history-size");
+    }
+
+    #[test]
+    fn validate_reports_too_long_prefix() {
+        let mut config = Config::default();
+        config.global.prefix = FileName::new(&[b'a'; 200]).unwrap();
+
+        let sut = config.validate();
+
+        assert_that!(sut, len 1);
+        assert_that!(sut[0].severity(), eq ConfigDiagnosticSeverity::Error);
+        assert_that!(sut[0].kind(), eq ConfigDiagnosticKind::InvalidName);
+        assert_that!(sut[0].setting(), eq "global.prefix");
+    }
 }
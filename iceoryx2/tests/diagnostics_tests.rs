@@ -0,0 +1,64 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod diagnostics {
+    use iceoryx2::diagnostics::{self_test, SelfTestConcept, SelfTestOutcome};
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn self_test_passes_every_concept<S: Service>() {
+        let config = generate_isolated_config();
+
+        let report = self_test::<S>(&config);
+
+        assert_that!(report.has_passed(), eq true);
+        assert_that!(report.results(), len SelfTestConcept::ALL.len());
+        for (result, concept) in report.results().iter().zip(SelfTestConcept::ALL) {
+            assert_that!(result.concept(), eq concept);
+            assert_that!(*result.outcome(), eq SelfTestOutcome::Passed);
+            assert_that!(*report.outcome(concept), eq SelfTestOutcome::Passed);
+        }
+    }
+
+    #[test]
+    fn self_test_leaves_no_services_or_nodes_behind<S: Service>() {
+        let config = generate_isolated_config();
+
+        let report = self_test::<S>(&config);
+        assert_that!(report.has_passed(), eq true);
+
+        let mut number_of_services = 0;
+        S::list(&config, |_| {
+            number_of_services += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_services, eq 0);
+
+        let mut number_of_nodes = 0;
+        Node::<S>::list(&config, |_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod dispatcher {
+    use iceoryx2::port::dispatcher::{
+        DispatcherAttachmentError, DispatcherCreateError, DispatcherRunError,
+    };
+    use iceoryx2::port::waitset::WaitSetRunResult;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "dispatcher_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn error_display_works<S: Service>() {
+        assert_that!(format!("{}", DispatcherAttachmentError::BlockingReceiveNotEnabled), eq
+            "DispatcherAttachmentError::BlockingReceiveNotEnabled");
+        assert_that!(format!("{}", DispatcherRunError::ReceiveFailure), eq
+            "DispatcherRunError::ReceiveFailure");
+        assert_that!(format!("{}", DispatcherCreateError::InternalError), eq
+            "DispatcherCreateError::InternalError");
+    }
+
+    #[test]
+    fn subscriber_without_blocking_receive_cannot_be_attached<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+        let result = sut.on_sample(&subscriber, |_| CallbackProgression::Continue);
+
+        assert_that!(result.err(), eq Some(DispatcherAttachmentError::BlockingReceiveNotEnabled));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn dispatch_loop_calls_event_callback_for_every_event<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const NUMBER_OF_EVENTS: usize = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_EVENTS {
+            notifier
+                .notify_with_custom_event_id(EventId::new(i + 1))
+                .unwrap();
+        }
+
+        let mut received_events = vec![];
+        let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+        sut.on_event(&listener, |event_id| {
+            received_events.push(event_id);
+            if received_events.len() == NUMBER_OF_EVENTS {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+        .unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        let result = sut.dispatch_loop();
+        drop(sut);
+
+        assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(received_events, len NUMBER_OF_EVENTS);
+        for i in 0..NUMBER_OF_EVENTS {
+            assert_that!(received_events, contains EventId::new(i + 1));
+        }
+    }
+
+    #[test]
+    fn dispatch_loop_calls_sample_callback_for_every_sample<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const NUMBER_OF_SAMPLES: u64 = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .enable_blocking_receive(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(i).unwrap();
+        }
+
+        let mut received_samples = vec![];
+        let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+        sut.on_sample(&subscriber, |sample| {
+            received_samples.push(*sample);
+            if received_samples.len() == NUMBER_OF_SAMPLES as usize {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+        .unwrap();
+
+        let result = sut.dispatch_loop();
+        drop(sut);
+
+        assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(
+            received_samples,
+            eq(0..NUMBER_OF_SAMPLES).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn pending_samples_remain_in_subscriber_after_stop<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .enable_blocking_receive(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        publisher.send_copy(7).unwrap();
+        publisher.send_copy(8).unwrap();
+
+        let mut sut = DispatcherBuilder::new().create::<S>().unwrap();
+        sut.on_sample(&subscriber, |sample| {
+            assert_that!(*sample, eq 7);
+            CallbackProgression::Stop
+        })
+        .unwrap();
+
+        assert_that!(sut.dispatch_loop(), eq Ok(WaitSetRunResult::StopRequest));
+        drop(sut);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -13,10 +13,17 @@
 #[generic_tests::define]
 mod listener {
     use std::collections::HashSet;
+    use std::time::Duration;
 
+    use iceoryx2::port::event_id::EventId;
     use iceoryx2::testing::*;
     use iceoryx2::{node::NodeBuilder, port::listener::ListenerCreateError, service::Service};
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_posix::file_descriptor_set::{
+        FileDescriptorSet, FileEvent, SynchronousMultiplexing,
+    };
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
 
     #[test]
     fn create_error_display_works<S: Service>() {
@@ -50,6 +57,201 @@ mod listener {
         }
     }
 
+    #[test]
+    fn batch_contains_every_event_id_once_with_number_of_notifications<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        for id in [5, 1, 5, 3, 5] {
+            notifier
+                .notify_with_custom_event_id(EventId::new(id))
+                .unwrap();
+        }
+
+        let batch = sut.timed_wait_batch(Duration::from_millis(100)).unwrap();
+        assert_that!(batch.len(), eq 3);
+        assert_that!(batch.count(EventId::new(1)), eq 1);
+        assert_that!(batch.count(EventId::new(3)), eq 1);
+        assert_that!(batch.count(EventId::new(5)), eq 3);
+        assert_that!(batch.contains(EventId::new(2)), eq false);
+        assert_that!(batch.iter().map(|(id, _)| id.as_value()).collect::<Vec<_>>(), eq vec![1, 3, 5]);
+
+        let batch = sut.try_wait_batch().unwrap();
+        assert_that!(batch.is_empty(), eq true);
+    }
+
+    #[test]
+    fn timed_wait_batch_returns_empty_batch_after_timeout<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+
+        let start = std::time::Instant::now();
+        let batch = sut.timed_wait_batch(TIMEOUT).unwrap();
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(batch.is_empty(), eq true);
+    }
+
+    #[test]
+    fn file_descriptor_becomes_readable_when_notified<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::fd::AsRawFd;
+            assert_that!(sut.as_raw_fd(), eq unsafe { sut.file_descriptor().native_handle() });
+        }
+
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fd_set.add(&sut).unwrap();
+
+        let mut number_of_triggers = 0;
+        fd_set
+            .timed_wait(Duration::ZERO, FileEvent::Read, |_| number_of_triggers += 1)
+            .unwrap();
+        assert_that!(number_of_triggers, eq 0);
+
+        notifier.notify().unwrap();
+
+        fd_set
+            .timed_wait(Duration::from_millis(100), FileEvent::Read, |fd| {
+                assert_that!(unsafe { fd.native_handle() }, eq unsafe { sut.file_descriptor().native_handle() });
+                number_of_triggers += 1;
+            })
+            .unwrap();
+        assert_that!(number_of_triggers, eq 1);
+
+        let mut received_ids = vec![];
+        sut.try_wait_all(|id| received_ids.push(id)).unwrap();
+        assert_that!(received_ids, len 1);
+    }
+
+    #[cfg(feature = "async")]
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => return result,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_async_returns_already_received_event<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(12))
+            .unwrap();
+
+        assert_that!(block_on(sut.wait_async()), eq Ok(EventId::new(12)));
+        assert_that!(sut.try_wait_one().unwrap(), is_none);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_async_becomes_ready_when_notified<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: FileDescriptorBased,
+    {
+        use std::sync::Barrier;
+        use std::time::Instant;
+
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut = service.listener_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node.service_builder(&service_name).event().open().unwrap();
+                let notifier = service.notifier_builder().create().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                notifier
+                    .notify_with_custom_event_id(EventId::new(3))
+                    .unwrap();
+            });
+
+            barrier.wait();
+            let start = Instant::now();
+            assert_that!(block_on(sut.wait_async()), eq Ok(EventId::new(3)));
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        });
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
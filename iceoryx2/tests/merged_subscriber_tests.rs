@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod merged_subscriber {
+    use iceoryx2::port::merged_subscriber::MergedSubscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "merged_subscriber_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn empty_merged_subscriber_receives_nothing<S: Service>() {
+        let sut = MergedSubscriber::<S, u64, ()>::new();
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.has_samples(), eq Ok(false));
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receives_samples_of_all_services_with_source<S: Service>() {
+        const NUMBER_OF_SERVICES: usize = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut services = vec![];
+        let mut publishers = vec![];
+        let mut sut = MergedSubscriber::new();
+        let mut sources = vec![];
+        for _ in 0..NUMBER_OF_SERVICES {
+            let service = node
+                .service_builder(&generate_name())
+                .publish_subscribe::<u64>()
+                .subscriber_max_buffer_size(2)
+                .create()
+                .unwrap();
+            sources.push(sut.add(service.subscriber_builder().create().unwrap()));
+            publishers.push(service.publisher_builder().create().unwrap());
+            services.push(service);
+        }
+        assert_that!(sut.len(), eq NUMBER_OF_SERVICES);
+
+        for (n, publisher) in publishers.iter().enumerate() {
+            publisher.send_copy(n as u64).unwrap();
+        }
+        assert_that!(sut.has_samples(), eq Ok(true));
+
+        for (n, (expected_source, service)) in sources.iter().zip(services.iter()).enumerate() {
+            let (source, sample) = sut.receive().unwrap().unwrap();
+            assert_that!(source, eq * expected_source);
+            assert_that!(source.index(), eq n);
+            assert_that!(*sample, eq n as u64);
+            assert_that!(sut.service_name(source), eq Some(service.name()));
+        }
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn busy_service_does_not_starve_other_services<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service_1 = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+        let service_2 = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut sut = MergedSubscriber::new();
+        let source_1 = sut.add(service_1.subscriber_builder().create().unwrap());
+        let source_2 = sut.add(service_2.subscriber_builder().create().unwrap());
+
+        let publisher_1 = service_1.publisher_builder().create().unwrap();
+        let publisher_2 = service_2.publisher_builder().create().unwrap();
+        for n in 0..4 {
+            publisher_1.send_copy(n).unwrap();
+        }
+        publisher_2.send_copy(42).unwrap();
+
+        let (source, _) = sut.receive().unwrap().unwrap();
+        assert_that!(source, eq source_1);
+        let (source, sample) = sut.receive().unwrap().unwrap();
+        assert_that!(source, eq source_2);
+        assert_that!(*sample, eq 42);
+
+        for n in 1..4 {
+            let (source, sample) = sut.receive().unwrap().unwrap();
+            assert_that!(source, eq source_1);
+            assert_that!(*sample, eq n);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,190 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "metrics")]
+#[generic_tests::define]
+mod metrics {
+    use iceoryx2::metrics::{Metric, MetricFamily, Metrics};
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "metrics_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn find(metrics: &Metrics, family: MetricFamily, label: &str) -> Option<Metric> {
+        let mut result = None;
+        metrics.list(|metric| {
+            if metric.family() == family && metric.labels().iter().any(|(_, v)| v == label) {
+                result = Some(metric.clone());
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+        result
+    }
+
+    #[test]
+    fn scrape_counts_alive_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let _node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = Metrics::scrape::<S>(&config).unwrap();
+
+        let alive = find(&sut, MetricFamily::Nodes, "alive");
+        assert_that!(alive, is_some);
+        assert_that!(alive.unwrap().value(), eq 2);
+
+        let dead = find(&sut, MetricFamily::Nodes, "dead");
+        assert_that!(dead, is_some);
+        assert_that!(dead.unwrap().value(), eq 0);
+    }
+
+    #[test]
+    fn scrape_counts_ports_of_services<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _publisher = service.publisher_builder().create().unwrap();
+        let _subscriber_1 = service.subscriber_builder().create().unwrap();
+        let _subscriber_2 = service.subscriber_builder().create().unwrap();
+
+        let sut = Metrics::scrape::<S>(&config).unwrap();
+
+        let services = find(&sut, MetricFamily::Services, "PublishSubscribe");
+        assert_that!(services, is_some);
+        assert_that!(services.unwrap().value(), eq 1);
+
+        let nodes = find(&sut, MetricFamily::ServiceNodes, service_name.as_str());
+        assert_that!(nodes, is_some);
+        assert_that!(nodes.unwrap().value(), eq 1);
+
+        let publishers = find(&sut, MetricFamily::ServicePublishers, service_name.as_str());
+        assert_that!(publishers, is_some);
+        assert_that!(publishers.unwrap().value(), eq 1);
+
+        let subscribers = find(
+            &sut,
+            MetricFamily::ServiceSubscribers,
+            service_name.as_str(),
+        );
+        assert_that!(subscribers, is_some);
+        assert_that!(subscribers.unwrap().value(), eq 2);
+    }
+
+    #[test]
+    fn scrape_reports_subscriber_buffers_and_dropped_samples<S: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .statistics(true)
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE as u64 + 2 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        let sut = Metrics::scrape::<S>(&config).unwrap();
+
+        let buffered = find(
+            &sut,
+            MetricFamily::SubscriberBufferedSamples,
+            service_name.as_str(),
+        );
+        assert_that!(buffered, is_some);
+        assert_that!(buffered.unwrap().value(), eq BUFFER_SIZE as u64);
+
+        let buffer_size = find(
+            &sut,
+            MetricFamily::SubscriberBufferSize,
+            service_name.as_str(),
+        );
+        assert_that!(buffer_size, is_some);
+        assert_that!(buffer_size.unwrap().value(), eq BUFFER_SIZE as u64);
+
+        let dropped = find(
+            &sut,
+            MetricFamily::ServiceDroppedSamples,
+            service_name.as_str(),
+        );
+        assert_that!(dropped, is_some);
+        assert_that!(dropped.unwrap().value(), eq 2);
+
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        let sut = Metrics::scrape::<S>(&config).unwrap();
+        let buffered = find(
+            &sut,
+            MetricFamily::SubscriberBufferedSamples,
+            service_name.as_str(),
+        );
+        assert_that!(buffered.unwrap().value(), eq BUFFER_SIZE as u64 - 1);
+    }
+
+    #[test]
+    fn exposition_groups_metrics_by_family<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = Metrics::scrape::<S>(&config).unwrap().to_string();
+
+        assert_that!(sut.matches("# TYPE iceoryx2_nodes gauge").count(), eq 1);
+        assert_that!(sut.matches("# TYPE iceoryx2_service_listeners gauge").count(), eq 1);
+        assert_that!(sut.contains("iceoryx2_nodes{state=\"alive\"} 1\n"), eq true);
+        let notifiers = format!(
+            "iceoryx2_service_notifiers{{service_name=\"{}\",messaging_pattern=\"Event\"}} 0\n",
+            service_name.as_str()
+        );
+        assert_that!(sut.contains(&notifiers), eq true);
+        assert_that!(sut.ends_with("# EOF\n"), eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -12,13 +12,15 @@
 
 #[generic_tests::define]
 mod node_death_tests {
+    use core::time::Duration;
     use std::sync::atomic::{AtomicU32, Ordering};
 
     use iceoryx2::config::Config;
     use iceoryx2::node::testing::__internal_node_staged_death;
     use iceoryx2::node::{CleanupState, NodeState};
     use iceoryx2::prelude::*;
-    use iceoryx2::service::Service;
+    use iceoryx2::service::discovery::ServiceDiscoveryEventKind;
+    use iceoryx2::service::{Service, StaleServiceCleanupState};
     use iceoryx2::testing::*;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -37,6 +39,13 @@ mod node_death_tests {
         }
 
         fn create_test_node(config: &Config) -> TestDetails<Self::Service> {
+            Self::create_test_node_with_builder(NodeBuilder::new(), config)
+        }
+
+        fn create_test_node_with_builder(
+            builder: NodeBuilder,
+            config: &Config,
+        ) -> TestDetails<Self::Service> {
             static COUNTER: AtomicU32 = AtomicU32::new(0);
             let node_name = Self::generate_node_name(0, "toby or no toby");
             let fake_node_id = ((u32::MAX - COUNTER.fetch_add(1, Ordering::Relaxed)) as u128) << 96;
@@ -44,7 +53,7 @@ mod node_death_tests {
                 unsafe { core::mem::transmute::<u128, UniqueSystemId>(fake_node_id) };
 
             let node = unsafe {
-                NodeBuilder::new()
+                builder
                     .name(&node_name)
                     .config(config)
                     .__internal_create_with_custom_node_id::<Self::Service>(fake_node_id)
@@ -305,6 +314,38 @@ mod node_death_tests {
         );
     }
 
+    #[test]
+    fn removing_service_of_dead_node_emits_removed_discovery_event<S: Test>() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let observer = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let discovery = observer.service_discovery().unwrap();
+
+        let mut sut = S::create_test_node(&config).node;
+        let service = sut.service_builder(&service_name).event().create().unwrap();
+        let service_id = service.service_id().clone();
+        core::mem::forget(service);
+        S::staged_death(&mut sut);
+        core::mem::forget(sut);
+
+        let event = discovery.receive().unwrap().unwrap();
+        assert_that!(event.kind(), eq ServiceDiscoveryEventKind::Created);
+        assert_that!(*event.service_id(), eq service_id);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        let event = discovery.receive().unwrap().unwrap();
+        assert_that!(event.kind(), eq ServiceDiscoveryEventKind::Removed);
+        assert_that!(*event.service_id(), eq service_id);
+        assert_that!(event.messaging_pattern(), eq MessagingPattern::Event);
+        assert_that!(discovery.receive().unwrap(), is_none);
+    }
+
     #[test]
     fn pubsub_service_is_removed_when_last_node_dies<S: Test>() {
         let service_name = generate_service_name();
@@ -338,6 +379,88 @@ mod node_death_tests {
         );
     }
 
+    #[test]
+    fn stale_service_resources_are_removed_when_owning_node_died<S: Test>() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut sut = S::create_test_node(&config).node;
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        core::mem::forget(service.publisher_builder().create().unwrap());
+        core::mem::forget(service.subscriber_builder().create().unwrap());
+        core::mem::forget(service);
+        S::staged_death(&mut sut);
+
+        assert_that!(
+            S::Service::remove_stale_resources(&config),
+            eq Ok(StaleServiceCleanupState {
+                removed_services: 1,
+                removed_nodes: 1,
+                failed_cleanups: 0
+            })
+        );
+
+        assert_that!(
+            S::Service::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe),
+            eq Ok(false)
+        );
+        assert_that!(
+            S::Service::remove_stale_resources(&config),
+            eq Ok(StaleServiceCleanupState::default())
+        );
+    }
+
+    #[test]
+    fn stale_service_resources_cleanup_keeps_services_of_alive_nodes<S: Test>() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let mut bad_node = S::create_test_node(&config).node;
+
+        let good_service = good_node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        core::mem::forget(
+            bad_node
+                .service_builder(&service_name)
+                .event()
+                .open_or_create()
+                .unwrap(),
+        );
+        S::staged_death(&mut bad_node);
+
+        assert_that!(
+            S::Service::remove_stale_resources(&config),
+            eq Ok(StaleServiceCleanupState {
+                removed_services: 0,
+                removed_nodes: 1,
+                failed_cleanups: 0
+            })
+        );
+
+        assert_that!(
+            S::Service::does_exist(&service_name, &config, MessagingPattern::Event),
+            eq Ok(true)
+        );
+        let details = S::Service::details(&service_name, &config, MessagingPattern::Event)
+            .unwrap()
+            .unwrap();
+        assert_that!(details.dynamic_details.unwrap().nodes, len 1);
+        drop(good_service);
+    }
+
     #[test]
     fn node_cleanup_option_works_on_node_creation<S: Test>() {
         let mut config = generate_isolated_config();
@@ -422,6 +545,179 @@ mod node_death_tests {
         assert_that!(number_of_nodes(), eq 0);
     }
 
+    #[test]
+    fn node_cleanup_option_works_on_wait<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+        let node_without_cleanup = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let node_with_cleanup = NodeBuilder::new()
+            .config(&config)
+            .cleanup_dead_nodes_on_wait(true)
+            .create::<S::Service>()
+            .unwrap();
+
+        let mut sut = S::create_test_node(&config);
+        S::staged_death(&mut sut.node);
+        core::mem::forget(sut.node);
+
+        let number_of_nodes = || {
+            let mut counter = 0;
+            Node::<S::Service>::list(&config, |_| {
+                counter += 1;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            counter
+        };
+
+        assert_that!(number_of_nodes(), eq 3);
+
+        assert_that!(node_without_cleanup.wait(Duration::ZERO), is_ok);
+        assert_that!(number_of_nodes(), eq 3);
+
+        assert_that!(node_with_cleanup.wait(Duration::ZERO), is_ok);
+        assert_that!(number_of_nodes(), eq 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn node_cleanup_option_works_on_wait_async<S: Test>() {
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+        let node_with_cleanup = NodeBuilder::new()
+            .config(&config)
+            .cleanup_dead_nodes_on_wait(true)
+            .create::<S::Service>()
+            .unwrap();
+
+        let mut sut = S::create_test_node(&config);
+        S::staged_death(&mut sut.node);
+        core::mem::forget(sut.node);
+
+        let number_of_nodes = || {
+            let mut counter = 0;
+            Node::<S::Service>::list(&config, |_| {
+                counter += 1;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            counter
+        };
+
+        assert_that!(number_of_nodes(), eq 2);
+
+        // the dead nodes are removed with the first poll, before the future waits
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(node_with_cleanup.wait_async(Duration::from_secs(3600)));
+        assert_that!(future.as_mut().poll(&mut cx), eq Poll::Pending);
+
+        assert_that!(number_of_nodes(), eq 1);
+    }
+
+    #[test]
+    fn node_cleanup_on_wait_is_rate_limited<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .cleanup_dead_nodes_on_wait(true)
+            .dead_node_cleanup_interval(Duration::from_secs(3600))
+            .create::<S::Service>()
+            .unwrap();
+
+        let number_of_nodes = || {
+            let mut counter = 0;
+            Node::<S::Service>::list(&config, |_| {
+                counter += 1;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            counter
+        };
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.wait(Duration::ZERO), is_ok);
+        assert_that!(number_of_nodes(), eq 1);
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.wait(Duration::ZERO), is_ok);
+        assert_that!(number_of_nodes(), eq 2);
+    }
+
+    #[test]
+    fn node_that_marks_itself_dead_on_panic_keeps_its_resources<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+        let sut =
+            S::create_test_node_with_builder(NodeBuilder::new().mark_dead_on_panic(true), &config);
+
+        let result = std::thread::spawn(|| panic!("the node shall be marked as dead")).join();
+        assert_that!(result, is_err);
+        drop(sut);
+
+        let mut node_list = vec![];
+        Node::<S::Service>::list(&config, |node_state| {
+            node_list.push(node_state);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(node_list, len 1);
+
+        if let Some(NodeState::Dead(state)) = node_list.pop() {
+            assert_that!(state.remove_stale_resources(), eq Ok(true));
+        } else {
+            test_fail!("the node shall be dead");
+        }
+    }
+
+    #[test]
+    fn node_that_does_not_mark_itself_dead_on_panic_removes_its_resources<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+        let sut = S::create_test_node(&config);
+
+        let result = std::thread::spawn(|| panic!("the node shall stay alive")).join();
+        assert_that!(result, is_err);
+        drop(sut);
+
+        let mut counter = 0;
+        Node::<S::Service>::list(&config, |_| {
+            counter += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(counter, eq 0);
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod ipc {}
 }
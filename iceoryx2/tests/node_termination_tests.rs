@@ -0,0 +1,154 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod node_termination {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::node::NodeWaitFailure;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::signal::{Signal, SignalHandler};
+    use iceoryx2_bb_testing::assert_that;
+
+    // a termination request is consumed by the first wait that observes it, therefore the
+    // tests that raise one must not run concurrently
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    struct TestFixture {
+        _guard: MutexGuard<'static, ()>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl TestFixture {
+        fn new() -> Self {
+            let guard = match LOCK.lock() {
+                Ok(guard) => guard,
+                Err(e) => e.into_inner(),
+            };
+
+            // installs the signal handler so that the process is not terminated by the
+            // raised signal and discards all previous signals
+            SignalHandler::termination_requested();
+
+            Self {
+                _guard: guard,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        fn create_node<S: Service>(&self) -> Node<S> {
+            let config = generate_isolated_config();
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            let calls = self.calls.clone();
+            node.on_termination(move || {
+                calls.fetch_add(1, Ordering::Relaxed);
+            });
+
+            node
+        }
+
+        fn number_of_termination_callback_calls(&self) -> usize {
+            self.calls.load(Ordering::Relaxed)
+        }
+    }
+
+    fn request_termination() {
+        Process::from_self().send_signal(Signal::Terminate).unwrap();
+    }
+
+    #[cfg(feature = "async")]
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => return result,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn wait_calls_termination_callbacks_on_termination_request<S: Service>() {
+        const CYCLE_TIME: Duration = Duration::from_secs(10);
+        let test = TestFixture::new();
+        let node = test.create_node::<S>();
+
+        request_termination();
+
+        let start = Instant::now();
+        let result = node.wait(CYCLE_TIME);
+
+        assert_that!(result, eq Err(NodeWaitFailure::TerminationRequest));
+        assert_that!(start.elapsed(), lt CYCLE_TIME);
+        assert_that!(test.number_of_termination_callback_calls(), eq 1);
+
+        drop(node);
+        assert_that!(test.number_of_termination_callback_calls(), eq 1);
+    }
+
+    #[test]
+    fn wait_does_not_call_termination_callbacks_without_termination_request<S: Service>() {
+        let test = TestFixture::new();
+        let node = test.create_node::<S>();
+
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(test.number_of_termination_callback_calls(), eq 0);
+
+        drop(node);
+        assert_that!(test.number_of_termination_callback_calls(), eq 1);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_async_calls_termination_callbacks_on_termination_request<S: Service>() {
+        const CYCLE_TIME: Duration = Duration::from_secs(10);
+        let test = TestFixture::new();
+        let node = test.create_node::<S>();
+
+        let start = Instant::now();
+        let signal_thread = std::thread::spawn(|| {
+            std::thread::sleep(Duration::from_millis(50));
+            request_termination();
+        });
+
+        let result = block_on(node.wait_async(CYCLE_TIME));
+        signal_thread.join().unwrap();
+
+        assert_that!(result, eq Err(NodeWaitFailure::TerminationRequest));
+        assert_that!(start.elapsed(), lt CYCLE_TIME);
+        assert_that!(test.number_of_termination_callback_calls(), eq 1);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -13,20 +13,29 @@
 #[generic_tests::define]
 mod node {
     use std::collections::{HashSet, VecDeque};
+    use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::Barrier;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
-    use iceoryx2::config::Config;
+    use iceoryx2::config::{Config, LogTarget};
+    use iceoryx2::node::heartbeat::MAX_NUMBER_OF_WATCHDOGS;
+    use iceoryx2::node::watchdog::NodeWatchdogCreateError;
     use iceoryx2::node::{
-        NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure, NodeState, NodeView,
+        NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure, NodeRenameFailure,
+        NodeState, NodeUpdateConfigFailure, NodeView,
     };
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_log::get_log_level;
+    use iceoryx2_bb_posix::file_descriptor_set::{FileDescriptorSet, FileEvent};
+    use iceoryx2_bb_posix::scheduler::Scheduler;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::watchdog::Watchdog;
-    use iceoryx2_bb_testing::{assert_that, test_fail};
+    use iceoryx2_bb_testing::{assert_that, test_fail, test_requires};
 
     #[derive(Debug, Eq, PartialEq)]
     struct Details {
@@ -62,7 +71,9 @@ mod node {
             let view = match node {
                 NodeState::<S>::Alive(ref view) => view as &dyn NodeView,
                 NodeState::<S>::Dead(ref view) => view as &dyn NodeView,
-                NodeState::<S>::Inaccessible(_) | NodeState::<S>::Undefined(_) => {
+                NodeState::<S>::Inaccessible(_)
+                | NodeState::<S>::Undefined(_)
+                | NodeState::<S>::Unresponsive(_) => {
                     assert_that!(true, eq false);
                     panic!();
                 }
@@ -82,6 +93,108 @@ mod node {
         NodeName::new(&(prefix.to_string() + &i.to_string())).unwrap()
     }
 
+    #[test]
+    fn node_attributes_are_stored_in_node_details<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .attributes(
+                &AttributeSpecifier::new()
+                    .define("team", "perception")
+                    .define("version", "1.4.2"),
+            )
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut.attributes().get("team"), eq vec!["perception"]);
+
+        let mut nodes = vec![];
+        Node::<S>::list(&config, |node_state| {
+            nodes.push(node_state);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(nodes, len 1);
+        if let NodeState::Alive(node_view) = &nodes[0] {
+            let details = node_view.details().as_ref().unwrap();
+            assert_that!(details.attributes().get("team"), eq vec!["perception"]);
+            assert_that!(details.attributes().get("version"), eq vec!["1.4.2"]);
+        } else {
+            test_fail!("Process internal nodes shall be always detected as alive.");
+        }
+    }
+
+    #[test]
+    fn list_iter_returns_same_nodes_as_list<S: Service>() {
+        const NUMBER_OF_NODES: usize = 5;
+        let config = generate_isolated_config();
+        let mut nodes = vec![];
+        for i in 0..NUMBER_OF_NODES {
+            nodes.push(
+                NodeBuilder::new()
+                    .name(&generate_node_name(i, "list_iter"))
+                    .config(&config)
+                    .create::<S>()
+                    .unwrap(),
+            );
+        }
+
+        let mut listed_ids = vec![];
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                listed_ids.push(view.id().value());
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        let mut iterated_ids: Vec<u128> = Node::<S>::list_iter(&config)
+            .unwrap()
+            .filter_map(|node_state| match node_state {
+                NodeState::Alive(view) => Some(view.id().value()),
+                _ => None,
+            })
+            .collect();
+
+        let mut expected_ids: Vec<u128> = nodes.iter().map(|node| node.id().value()).collect();
+        listed_ids.sort();
+        iterated_ids.sort();
+        expected_ids.sort();
+
+        assert_that!(iterated_ids, eq expected_ids);
+        assert_that!(listed_ids, eq expected_ids);
+
+        drop(nodes);
+        assert_that!(Node::<S>::list_iter(&config).unwrap().count(), eq 0);
+    }
+
+    #[test]
+    fn node_without_attributes_has_empty_attribute_set<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.attributes().is_empty(), eq true);
+    }
+
+    #[test]
+    fn termination_callbacks_are_called_in_order_when_node_goes_out_of_scope<S: Service>() {
+        use std::sync::{Arc, Mutex};
+
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let calls = Arc::new(Mutex::new(vec![]));
+
+        for i in 0..3 {
+            let calls = calls.clone();
+            sut.on_termination(move || calls.lock().unwrap().push(i));
+        }
+
+        assert_that!(*calls.lock().unwrap(), len 0);
+        drop(sut);
+        assert_that!(*calls.lock().unwrap(), eq vec![0, 1, 2]);
+    }
+
     #[test]
     fn node_without_name_can_be_created<S: Service>() {
         let config = generate_isolated_config();
@@ -247,12 +360,347 @@ mod node {
         }
     }
 
+    #[test]
+    fn renamed_node_is_listed_with_new_name<S: Service>() {
+        let config = generate_isolated_config();
+        let attributes = AttributeSpecifier::new().define("role", "controller");
+        let mut sut = NodeBuilder::new()
+            .name(&NodeName::new("follower").unwrap())
+            .config(&config)
+            .attributes(&attributes)
+            .create::<S>()
+            .unwrap();
+
+        let new_name = NodeName::new("follower_leader").unwrap();
+        assert_that!(sut.rename(&new_name), is_ok);
+        assert_that!(*sut.name(), eq new_name);
+
+        let mut nodes = vec![];
+        Node::<S>::list(&config, |node_state| {
+            nodes.push(node_state);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(nodes, len 1);
+        if let NodeState::Alive(node_view) = &nodes[0] {
+            let details = node_view.details().as_ref().unwrap();
+            assert_that!(*details.name(), eq new_name);
+            assert_that!(details.attributes().get("role"), eq vec!["controller"]);
+        } else {
+            test_fail!("Process internal nodes shall be always detected as alive.");
+        }
+    }
+
+    #[test]
+    fn renamed_node_can_be_renamed_again_and_cleans_up<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.rename(&NodeName::new("first").unwrap()), is_ok);
+        assert_that!(sut.rename(&NodeName::new("second").unwrap()), is_ok);
+        assert_that!(sut.name().as_str(), eq "second");
+
+        drop(sut);
+
+        let mut number_of_nodes = 0;
+        Node::<S>::list(&config, |_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 0);
+    }
+
+    #[test]
+    fn renamed_node_is_listed_with_details_during_rename<S: Service>() {
+        const NUMBER_OF_RENAMES: usize = 100;
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new()
+            .name(&NodeName::new("rename_0").unwrap())
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let keep_listing = AtomicBool::new(true);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                while keep_listing.load(Ordering::Relaxed) {
+                    let mut nodes = vec![];
+                    Node::<S>::list(&config, |node_state| {
+                        nodes.push(node_state);
+                        CallbackProgression::Continue
+                    })
+                    .unwrap();
+
+                    assert_that!(nodes, len 1);
+                    if let NodeState::Alive(node_view) = &nodes[0] {
+                        assert_that!(node_view.details(), is_some);
+                    } else {
+                        test_fail!("Process internal nodes shall be always detected as alive.");
+                    }
+                }
+            });
+
+            for n in 1..=NUMBER_OF_RENAMES {
+                let new_name = NodeName::new(&format!("rename_{n}")).unwrap();
+                assert_that!(sut.rename(&new_name), is_ok);
+            }
+            keep_listing.store(false, Ordering::Relaxed);
+        });
+
+        assert_that!(sut.name().as_str(), eq format!("rename_{NUMBER_OF_RENAMES}"));
+    }
+
+    #[test]
+    fn updated_config_is_used_by_services_that_are_created_afterwards<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let existing_service = sut
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut new_config = config.clone();
+        new_config.defaults.publish_subscribe.max_publishers =
+            config.defaults.publish_subscribe.max_publishers + 3;
+        assert_that!(sut.update_config(&new_config), is_ok);
+        assert_that!(*sut.config(), eq new_config);
+
+        let new_service = sut
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(existing_service.static_config().max_publishers(), eq config.defaults.publish_subscribe.max_publishers);
+        assert_that!(new_service.static_config().max_publishers(), eq new_config.defaults.publish_subscribe.max_publishers);
+    }
+
+    #[test]
+    fn updated_config_is_listed_and_keeps_the_node_name<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let new_name = NodeName::new("reconfigured").unwrap();
+        assert_that!(sut.rename(&new_name), is_ok);
+
+        let mut new_config = config.clone();
+        new_config.defaults.event.max_listeners = config.defaults.event.max_listeners + 1;
+        assert_that!(sut.update_config(&new_config), is_ok);
+
+        let mut nodes = vec![];
+        Node::<S>::list(&config, |node_state| {
+            nodes.push(node_state);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(nodes, len 1);
+        if let NodeState::Alive(node_view) = &nodes[0] {
+            let details = node_view.details().as_ref().unwrap();
+            assert_that!(*details.name(), eq new_name);
+            assert_that!(*details.config(), eq new_config);
+        } else {
+            test_fail!("Process internal nodes shall be always detected as alive.");
+        }
+    }
+
+    #[test]
+    fn update_config_applies_changed_log_level<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut new_config = config.clone();
+        new_config.log.level = Some(iceoryx2::config::LogLevel::Trace);
+        assert_that!(sut.update_config(&new_config), is_ok);
+        assert_that!(*sut.config(), eq new_config);
+        assert_that!(get_log_level(), eq LogLevel::Trace as u8);
+    }
+
+    #[test]
+    fn update_config_with_different_log_target_fails<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut new_config = config.clone();
+        new_config.log.target = match config.log.target {
+            LogTarget::Stderr => LogTarget::Syslog,
+            _ => LogTarget::Stderr,
+        };
+        assert_that!(sut.update_config(&new_config), eq Err(NodeUpdateConfigFailure::LogSettingsChanged));
+        assert_that!(*sut.config(), eq config);
+    }
+
+    #[test]
+    fn update_config_with_different_global_settings_fails<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut new_config = config.clone();
+        new_config.global.service.creation_timeout += Duration::from_millis(1);
+        assert_that!(sut.update_config(&new_config), eq Err(NodeUpdateConfigFailure::GlobalSettingsChanged));
+        assert_that!(*sut.config(), eq config);
+    }
+
+    #[test]
+    fn nodes_and_services_of_different_domains_are_isolated<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("domain_service").unwrap();
+
+        let node_1 = NodeBuilder::new()
+            .config(&config)
+            .domain(&"robot_7".try_into().unwrap())
+            .create::<S>()
+            .unwrap();
+        let node_2 = NodeBuilder::new()
+            .config(&config)
+            .domain(&"robot_8".try_into().unwrap())
+            .create::<S>()
+            .unwrap();
+        let _node_3 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let _service = node_1
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut = node_2.service_builder(&service_name).event().open();
+        assert_that!(sut, is_err);
+        let sut = node_2.service_builder(&service_name).event().create();
+        assert_that!(sut, is_ok);
+
+        let mut number_of_nodes = 0;
+        Node::<S>::list(&config, |_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 1);
+
+        let mut number_of_nodes = 0;
+        Node::<S>::list(node_1.config(), |_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 1);
+    }
+
+    #[test]
+    fn list_domains_returns_all_domains_with_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        assert_that!(Node::<S>::list_domains(&config).unwrap(), len 0);
+
+        let node_1 = NodeBuilder::new()
+            .config(&config)
+            .domain(&"robot_1".try_into().unwrap())
+            .create::<S>()
+            .unwrap();
+        let _node_2 = NodeBuilder::new()
+            .config(&config)
+            .domain(&"robot_2".try_into().unwrap())
+            .create::<S>()
+            .unwrap();
+
+        let domains = Node::<S>::list_domains(&config).unwrap();
+        assert_that!(domains, len 2);
+        assert_that!(domains[0], eq FileName::try_from("robot_1").unwrap());
+        assert_that!(domains[1], eq FileName::try_from("robot_2").unwrap());
+
+        drop(node_1);
+        let domains = Node::<S>::list_domains(&config).unwrap();
+        assert_that!(domains, len 1);
+        assert_that!(domains[0], eq FileName::try_from("robot_2").unwrap());
+    }
+
+    #[test]
+    fn restarted_node_has_higher_generation<S: Service>() {
+        let config = generate_isolated_config();
+        let name = NodeName::new("phoenix").unwrap();
+
+        let sut = NodeBuilder::new()
+            .name(&name)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let first_generation = sut.generation();
+        drop(sut);
+
+        let sut = NodeBuilder::new()
+            .name(&name)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        assert_that!(sut.generation(), eq first_generation + 1);
+
+        let mut nodes = vec![];
+        Node::<S>::list(&config, |node_state| {
+            nodes.push(node_state);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(nodes, len 1);
+        if let NodeState::Alive(node_view) = &nodes[0] {
+            assert_that!(node_view.generation(), eq Some(first_generation + 1));
+            assert_that!(node_view.creation_time(), eq sut.id().creation_time());
+        } else {
+            test_fail!("Process internal nodes shall be always detected as alive.");
+        }
+    }
+
+    #[test]
+    fn nodes_with_different_names_share_the_generation_counter<S: Service>() {
+        let config = generate_isolated_config();
+
+        let sut_1 = NodeBuilder::new()
+            .name(&NodeName::new("castor").unwrap())
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut_2 = NodeBuilder::new()
+            .name(&NodeName::new("castor").unwrap())
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut_3 = NodeBuilder::new()
+            .name(&NodeName::new("pollux").unwrap())
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut_1.generation(), eq 1);
+        assert_that!(sut_2.generation(), eq 2);
+        assert_that!(sut_3.generation(), eq 3);
+    }
+
+    #[test]
+    fn node_rename_failure_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", NodeRenameFailure::InsufficientPermissions), eq "NodeRenameFailure::InsufficientPermissions");
+        assert_that!(
+            format!("{}", NodeRenameFailure::InternalError), eq "NodeRenameFailure::InternalError");
+    }
+
+    #[test]
+    fn node_update_config_failure_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", NodeUpdateConfigFailure::GlobalSettingsChanged), eq "NodeUpdateConfigFailure::GlobalSettingsChanged");
+        assert_that!(
+            format!("{}", NodeUpdateConfigFailure::InternalError), eq "NodeUpdateConfigFailure::InternalError");
+    }
+
     #[test]
     fn node_creation_failure_display_works<S: Service>() {
         assert_that!(
             format!("{}", NodeCreationFailure::InsufficientPermissions), eq "NodeCreationFailure::InsufficientPermissions");
         assert_that!(
             format!("{}", NodeCreationFailure::InternalError), eq "NodeCreationFailure::InternalError");
+        assert_that!(
+            format!("{}", NodeCreationFailure::PrefixCollision), eq "NodeCreationFailure::PrefixCollision");
     }
 
     #[test]
@@ -311,7 +759,7 @@ mod node {
                                         found_self = true;
                                     }
                                 }
-                                NodeState::Undefined(_) => {
+                                NodeState::Undefined(_) | NodeState::Unresponsive(_) => {
                                     assert_that!(true, eq false);
                                 }
                             };
@@ -368,9 +816,588 @@ mod node {
         }
     }
 
+    fn list_node_states<S: Service>(config: &Config) -> Vec<NodeState<S>> {
+        let mut nodes = vec![];
+        let result = Node::<S>::list(config, |node_state| {
+            nodes.push(node_state);
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+
+        nodes
+    }
+
+    #[test]
+    fn node_that_missed_liveness_deadline_is_unresponsive<S: Service>() {
+        const LIVENESS_DEADLINE: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(LIVENESS_DEADLINE)
+            .create::<S>()
+            .unwrap();
+
+        std::thread::sleep(LIVENESS_DEADLINE * 2);
+
+        let nodes = list_node_states::<S>(node.config());
+        assert_that!(nodes, len 1);
+        if let NodeState::Unresponsive(node_view) = &nodes[0] {
+            assert_that!(node_view.id(), eq node.id());
+        } else {
+            test_fail!("A node that missed its liveness deadline shall be unresponsive.");
+        }
+
+        node.heartbeat();
+
+        let nodes = list_node_states::<S>(node.config());
+        assert_that!(nodes, len 1);
+        assert_that!(matches!(nodes[0], NodeState::Alive(_)), eq true);
+    }
+
+    #[test]
+    fn node_wait_sends_heartbeats_within_liveness_deadline<S: Service>() {
+        const LIVENESS_DEADLINE: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(LIVENESS_DEADLINE)
+            .heartbeat_interval(LIVENESS_DEADLINE / 10)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(node.wait(LIVENESS_DEADLINE * 3), is_ok);
+
+        let nodes = list_node_states::<S>(node.config());
+        assert_that!(nodes, len 1);
+        assert_that!(matches!(nodes[0], NodeState::Alive(_)), eq true);
+    }
+
+    #[test]
+    fn starving_watchdog_makes_node_unresponsive<S: Service>() {
+        const LIVENESS_DEADLINE: Duration = Duration::from_secs(3600);
+        const WATCHDOG_DEADLINE: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(LIVENESS_DEADLINE)
+            .create::<S>()
+            .unwrap();
+
+        let sut = node.watchdog("worker", WATCHDOG_DEADLINE).unwrap();
+        assert_that!(sut.name(), eq "worker");
+        assert_that!(sut.deadline(), eq WATCHDOG_DEADLINE);
+
+        std::thread::sleep(WATCHDOG_DEADLINE * 2);
+        node.heartbeat();
+
+        assert_that!(sut.has_missed_deadline(), eq true);
+        assert_that!(node.starving_watchdogs(), eq vec!["worker".to_string()]);
+        let nodes = list_node_states::<S>(node.config());
+        assert_that!(nodes, len 1);
+        assert_that!(matches!(nodes[0], NodeState::Unresponsive(_)), eq true);
+
+        sut.feed();
+
+        assert_that!(sut.has_missed_deadline(), eq false);
+        assert_that!(node.starving_watchdogs(), is_empty);
+        let nodes = list_node_states::<S>(node.config());
+        assert_that!(nodes, len 1);
+        assert_that!(matches!(nodes[0], NodeState::Alive(_)), eq true);
+    }
+
+    #[test]
+    fn released_watchdog_does_not_make_node_unresponsive<S: Service>() {
+        const WATCHDOG_DEADLINE: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(Duration::from_secs(3600))
+            .create::<S>()
+            .unwrap();
+
+        let sut = node.watchdog("worker", WATCHDOG_DEADLINE).unwrap();
+        std::thread::sleep(WATCHDOG_DEADLINE * 2);
+        drop(sut);
+
+        assert_that!(node.starving_watchdogs(), is_empty);
+        let nodes = list_node_states::<S>(node.config());
+        assert_that!(nodes, len 1);
+        assert_that!(matches!(nodes[0], NodeState::Alive(_)), eq true);
+    }
+
+    #[test]
+    fn watchdog_can_be_fed_from_another_thread<S: Service>() {
+        const WATCHDOG_DEADLINE: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(Duration::from_secs(3600))
+            .create::<S>()
+            .unwrap();
+
+        let sut = node.watchdog("worker", WATCHDOG_DEADLINE).unwrap();
+        std::thread::sleep(WATCHDOG_DEADLINE * 2);
+        assert_that!(node.starving_watchdogs(), len 1);
+
+        std::thread::spawn(move || sut.feed()).join().unwrap();
+
+        assert_that!(node.starving_watchdogs(), is_empty);
+    }
+
+    #[test]
+    fn watchdog_creation_fails_without_liveness_deadline<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node.watchdog("worker", Duration::from_millis(50));
+        assert_that!(sut.err(), eq Some(NodeWatchdogCreateError::LivenessMonitoringDisabled));
+    }
+
+    #[test]
+    fn watchdog_creation_fails_when_max_number_of_watchdogs_is_exceeded<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(Duration::from_secs(3600))
+            .create::<S>()
+            .unwrap();
+
+        let mut watchdogs = vec![];
+        for i in 0..MAX_NUMBER_OF_WATCHDOGS {
+            watchdogs.push(
+                node.watchdog(&format!("worker_{i}"), Duration::from_secs(1))
+                    .unwrap(),
+            );
+        }
+
+        let sut = node.watchdog("one_too_many", Duration::from_secs(1));
+        assert_that!(sut.err(), eq Some(NodeWatchdogCreateError::ExceedsMaxNumberOfWatchdogs));
+
+        watchdogs.pop();
+        let sut = node.watchdog("one_too_many", Duration::from_secs(1));
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn wait_with_cyclic_deadline_does_not_accumulate_the_time_between_calls<S: Service>() {
+        const PERIOD: Duration = Duration::from_millis(100);
+        const WORK_TIME: Duration = Duration::from_millis(60);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .cyclic_deadline(PERIOD)
+            .create::<S>()
+            .unwrap();
+
+        let start = Instant::now();
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(start.elapsed(), ge PERIOD);
+
+        let start = Instant::now();
+        std::thread::sleep(WORK_TIME);
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(start.elapsed(), lt PERIOD + WORK_TIME);
+    }
+
+    #[test]
+    fn wait_with_cyclic_deadline_skips_missed_deadlines<S: Service>() {
+        const PERIOD: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .cyclic_deadline(PERIOD)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        std::thread::sleep(PERIOD * 3 + PERIOD / 2);
+
+        // the deadline was missed, therefore it returns immediately
+        let start = Instant::now();
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(start.elapsed(), lt PERIOD / 2);
+
+        // the next deadline is aligned to the schedule and not a full period away
+        let start = Instant::now();
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+        assert_that!(start.elapsed(), lt PERIOD);
+    }
+
+    #[cfg(feature = "async")]
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => return result,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_async_returns_after_cycle_time<S: Service>() {
+        const CYCLE_TIME: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let start = Instant::now();
+        assert_that!(block_on(node.wait_async(CYCLE_TIME)), is_ok);
+        assert_that!(start.elapsed(), ge CYCLE_TIME);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_async_sends_heartbeats_within_liveness_deadline<S: Service>() {
+        const LIVENESS_DEADLINE: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .liveness_deadline(LIVENESS_DEADLINE)
+            .heartbeat_interval(LIVENESS_DEADLINE / 10)
+            .create::<S>()
+            .unwrap();
+
+        // the node states are acquired while the future is pending, without heartbeats the
+        // node would miss its liveness deadline
+        let observer = {
+            let config = config.clone();
+            std::thread::spawn(move || {
+                let mut states = vec![];
+                for _ in 0..3 {
+                    std::thread::sleep(LIVENESS_DEADLINE);
+                    let nodes = list_node_states::<S>(&config);
+                    states.push(nodes.len() == 1 && matches!(nodes[0], NodeState::Alive(_)));
+                }
+                states
+            })
+        };
+
+        assert_that!(block_on(node.wait_async(LIVENESS_DEADLINE * 4)), is_ok);
+        assert_that!(observer.join().unwrap(), eq vec![true; 3]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_async_with_cyclic_deadline_does_not_accumulate_the_time_between_calls<S: Service>() {
+        const PERIOD: Duration = Duration::from_millis(100);
+        const WORK_TIME: Duration = Duration::from_millis(60);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .cyclic_deadline(PERIOD)
+            .create::<S>()
+            .unwrap();
+
+        let start = Instant::now();
+        assert_that!(block_on(node.wait_async(Duration::ZERO)), is_ok);
+        assert_that!(start.elapsed(), ge PERIOD);
+
+        let start = Instant::now();
+        std::thread::sleep(WORK_TIME);
+        assert_that!(block_on(node.wait_async(Duration::ZERO)), is_ok);
+        assert_that!(start.elapsed(), lt PERIOD + WORK_TIME);
+    }
+
+    #[test]
+    fn thread_settings_are_inherited_by_default<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.thread_settings().is_inherited(), eq true);
+        assert_that!(node.thread_settings().cpu_affinity(), eq None);
+        assert_that!(node.thread_settings().scheduler(), eq None);
+        assert_that!(node.thread_settings().priority(), eq None);
+    }
+
+    #[test]
+    fn thread_settings_are_applied_to_node<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .thread_cpu_affinity(&[0])
+            .thread_scheduler(Scheduler::Other)
+            .thread_priority(0)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(node.thread_settings().is_inherited(), eq false);
+        assert_that!(node.thread_settings().cpu_affinity(), eq Some(&[0][..]));
+        assert_that!(node.thread_settings().scheduler(), eq Some(Scheduler::Other));
+        assert_that!(node.thread_settings().priority(), eq Some(0));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn dropped_wait_async_with_thread_settings_does_not_block_for_cycle_time<S: Service>() {
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        const CYCLE_TIME: Duration = Duration::from_secs(10);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .thread_cpu_affinity(&[0])
+            .create::<S>()
+            .unwrap();
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(node.wait_async(CYCLE_TIME));
+        assert_that!(future.as_mut().poll(&mut cx), eq Poll::Pending);
+
+        let start = Instant::now();
+        drop(future);
+        assert_that!(start.elapsed(), lt CYCLE_TIME);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn dropped_wait_async_is_not_woken_up<S: Service>() {
+        use std::future::Future;
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct CountingWaker(AtomicUsize);
+
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        const CYCLE_TIME: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(node.wait_async(CYCLE_TIME));
+        assert_that!(future.as_mut().poll(&mut cx), eq Poll::Pending);
+        drop(future);
+
+        std::thread::sleep(CYCLE_TIME * 2);
+        assert_that!(counter.0.load(Ordering::Relaxed), eq 0);
+    }
+
+    #[test]
+    fn log_messages_of_node_entities_contain_node_name_and_id<S: Service>() {
+        static LOGGER: iceoryx2_bb_log::logger::buffer::Logger =
+            iceoryx2_bb_log::logger::buffer::Logger::new();
+
+        let config = generate_isolated_config();
+        let node_name = NodeName::new(&format!(
+            "node_with_log_context_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let node = NodeBuilder::new()
+            .name(&node_name)
+            .config(&config)
+            .logger(&LOGGER)
+            .create::<S>()
+            .unwrap();
+        let node_id = ("node_id", node.id().value().to_string());
+
+        let service_name = ServiceName::new("does_not_exist").unwrap();
+        let sut = node.service_builder(&service_name).event().open();
+        assert_that!(sut, is_err);
+
+        let entries: Vec<_> = LOGGER
+            .content()
+            .into_iter()
+            .filter(|entry| {
+                entry
+                    .context
+                    .contains(&("node_name", node_name.as_str().to_string()))
+            })
+            .collect();
+
+        assert_that!(entries, is_not_empty);
+        for entry in entries {
+            assert_that!(entry.context, contains node_id);
+        }
+    }
+
+    #[test]
+    fn failures_on_the_hot_path_are_logged_with_node_name_and_id<S: Service>() {
+        static LOGGER: iceoryx2_bb_log::logger::buffer::Logger =
+            iceoryx2_bb_log::logger::buffer::Logger::new();
+
+        let config = generate_isolated_config();
+        let node_name = NodeName::new(&format!(
+            "node_with_hot_path_log_context_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let node = NodeBuilder::new()
+            .name(&node_name)
+            .config(&config)
+            .logger(&LOGGER)
+            .create::<S>()
+            .unwrap();
+        let node_name = ("node_name", node_name.as_str().to_string());
+        let node_id = ("node_id", node.id().value().to_string());
+
+        let service = node
+            .service_builder(&ServiceName::new("hot_path_service").unwrap())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+
+        let _sample = publisher.loan().unwrap();
+        LOGGER.clear();
+        assert_that!(publisher.loan(), is_err);
+
+        let entries = LOGGER.content();
+        assert_that!(entries, is_not_empty);
+        for entry in entries {
+            assert_that!(entry.context, contains node_name);
+            assert_that!(entry.context, contains node_id);
+        }
+    }
+
+    #[test]
+    fn termination_wakeup_is_created_once_and_not_readable_without_termination_request<
+        S: Service,
+    >() {
+        // the termination wakeup is based on pipes which are not available on windows
+        test_requires!(cfg!(not(target_os = "windows")));
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node.termination_wakeup().unwrap();
+        assert_that!(core::ptr::eq(sut, node.termination_wakeup().unwrap()), eq true);
+
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fd_set.add(sut).unwrap();
+
+        let number_of_triggers = fd_set
+            .timed_wait(Duration::from_millis(10), FileEvent::Read, |_| {})
+            .unwrap();
+        assert_that!(number_of_triggers, eq 0);
+        assert_that!(node.wait(Duration::ZERO), is_ok);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
 }
+
+// resources without a path are only shared between instances with posix shared memory
+#[generic_tests::define]
+mod node_prefix {
+    use iceoryx2::config::Config;
+    use iceoryx2::node::NodeCreationFailure;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn config_of_other_instance(config: &Config) -> Config {
+        let mut root_path = *config.global.root_path();
+        root_path
+            .add_path_entry(&"other_instance".try_into().unwrap())
+            .unwrap();
+
+        let mut other_config = config.clone();
+        other_config.global.set_root_path(&root_path);
+        other_config
+    }
+
+    #[test]
+    fn instances_with_same_prefix_and_different_root_paths_collide<S: Service>() {
+        let config = generate_isolated_config();
+        let other_config = config_of_other_instance(&config);
+
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = NodeBuilder::new().config(&other_config).create::<S>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::PrefixCollision));
+
+        let sut = NodeBuilder::new().config(&config).create::<S>();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn instances_with_different_prefixes_do_not_collide<S: Service>() {
+        let config = generate_isolated_config();
+        let mut other_config = config_of_other_instance(&config);
+        other_config.global.prefix = generate_isolated_config().global.prefix;
+
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = NodeBuilder::new().config(&other_config).create::<S>();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn prefix_of_instance_without_shared_resources_is_not_taken_over<S: Service>() {
+        let config = generate_isolated_config();
+        let other_config = config_of_other_instance(&config);
+
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        drop(node);
+
+        let sut = NodeBuilder::new().config(&other_config).create::<S>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::PrefixCollision));
+    }
+
+    #[test]
+    fn nodes_of_a_verified_instance_acquire_increasing_generations<S: Service>() {
+        let config = generate_isolated_config();
+
+        let first_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let second_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        assert_that!(second_node.generation(), gt first_node.generation());
+
+        let sut = NodeBuilder::new()
+            .config(&config_of_other_instance(&config))
+            .create::<S>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::PrefixCollision));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+}
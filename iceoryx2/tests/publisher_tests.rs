@@ -13,13 +13,19 @@
 #[generic_tests::define]
 mod publisher {
     use std::collections::HashSet;
-    use std::sync::Mutex;
+    use std::sync::{Arc, Mutex};
     use std::time::{Duration, Instant};
 
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
+    use iceoryx2::config::MemoryPool;
+    use iceoryx2::port::publisher::{
+        DrainMode, PublisherCloseError, PublisherCreateError, PublisherLoanError,
+        PublisherSendError, PublisherSetMaxLoanedSamplesError,
+    };
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::port::SubscriberConnectionEvent;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::publisher::{AllocationStrategy, UnableToDeliverStrategy};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2::testing::*;
@@ -41,7 +47,7 @@ mod publisher {
 
     const COMPLEX_TYPE_DEFAULT_VALUE: u64 = 872379237;
 
-    #[derive(Debug)]
+    #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
     struct ComplexType {
         data: u64,
@@ -163,6 +169,39 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_send_slice_copy_delivers_slice_with_same_length<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 32;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for n in 0..=MAX_ELEMENTS {
+            let data: Vec<u64> = (0..n as u64).map(|i| i * 3).collect();
+            assert_that!(publisher.send_slice_copy(&data)?, eq 1);
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), eq data.as_slice());
+        }
+
+        let data = vec![0u64; MAX_ELEMENTS + 1];
+        let sut = publisher.send_slice_copy(&data);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublisherSendError::LoanError(PublisherLoanError::ExceedsMaxLoanSize));
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -215,6 +254,176 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_send_copy_if_changed_sends_only_changed_payload<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy_if_changed(1)?, eq Some(1));
+        assert_that!(sut.send_copy_if_changed(1)?, eq None);
+        assert_that!(sut.send_copy_if_changed(2)?, eq Some(1));
+        assert_that!(sut.send_copy_if_changed(2)?, eq None);
+        assert_that!(sut.send_copy_if_changed(1)?, eq Some(1));
+
+        for value in [1, 2, 1] {
+            let r = subscriber.receive()?;
+            assert_that!(r, is_some);
+            assert_that!(*r.unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_copy_if_hash_changed_sends_only_when_hash_changed<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy_if_hash_changed(10, 1)?, eq Some(1));
+        assert_that!(sut.send_copy_if_hash_changed(11, 1)?, eq None);
+        assert_that!(sut.send_copy_if_hash_changed(12, 2)?, eq Some(1));
+
+        for value in [10, 12] {
+            let r = subscriber.receive()?;
+            assert_that!(r, is_some);
+            assert_that!(*r.unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_close_succeeds_when_all_samples_are_received<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+
+        for value in [1, 2] {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq value);
+        }
+
+        assert_that!(sut.close(DrainMode::WaitForDelivery(TIMEOUT)), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_close_fails_when_samples_are_not_received_within_timeout<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(7)?;
+
+        let start = Instant::now();
+        assert_that!(sut.close(DrainMode::WaitForDelivery(TIMEOUT)), eq Err(PublisherCloseError::DeliveryTimeout));
+        assert_that!(start.elapsed(), ge TIMEOUT);
+
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_close_prevents_sending_of_loaned_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let sample = sut.loan()?;
+
+        assert_that!(sut.close(DrainMode::Detach), is_ok);
+        assert_that!(sample.send().err(), eq Some(PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_reports_subscriber_connection_changes<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let subscriber_1 = service.subscriber_builder().create()?;
+
+        let events_clone = events.clone();
+        let sut = service
+            .publisher_builder()
+            .on_subscriber_connection_change(move |event| events_clone.lock().unwrap().push(event))
+            .create()?;
+        assert_that!(*events.lock().unwrap(), eq vec![SubscriberConnectionEvent::Connected(subscriber_1.id())]);
+
+        let subscriber_2 = service.subscriber_builder().create()?;
+        let subscriber_2_id = subscriber_2.id();
+        sut.update_connections()?;
+        assert_that!(*events.lock().unwrap(), len 2);
+        assert_that!(events.lock().unwrap()[1], eq SubscriberConnectionEvent::Connected(subscriber_2_id));
+
+        drop(subscriber_2);
+        sut.update_connections()?;
+        assert_that!(*events.lock().unwrap(), len 3);
+        assert_that!(events.lock().unwrap()[2], eq SubscriberConnectionEvent::Disconnected(subscriber_2_id));
+
+        sut.update_connections()?;
+        assert_that!(*events.lock().unwrap(), len 3);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_max_loaned_samples_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -287,6 +496,640 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_max_loaned_samples_can_be_raised_up_to_ceiling<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publisher_max_loaned_samples_ceiling(4)
+            .create()?;
+
+        assert_that!(service.static_config().publisher_max_loaned_samples_ceiling(), eq 4);
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+        assert_that!(sut.max_loaned_samples(), eq 1);
+
+        let _sample1 = sut.loan_uninit()?;
+        assert_that!(sut.loan_uninit().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        assert_that!(sut.set_max_loaned_samples(4), is_ok);
+        assert_that!(sut.max_loaned_samples(), eq 4);
+        assert_that!(sut.loan_statistics().max_loaned_samples(), eq 4);
+
+        let _sample2 = sut.loan_uninit()?;
+        let _sample3 = sut.loan_uninit()?;
+        let _sample4 = sut.loan_uninit()?;
+        assert_that!(sut.loan_uninit().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_max_loaned_samples_cannot_exceed_ceiling<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publisher_max_loaned_samples_ceiling(3)
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        assert_that!(sut.set_max_loaned_samples(4).err(), eq Some(PublisherSetMaxLoanedSamplesError::ExceedsMaxLoanedSamplesCeiling));
+        assert_that!(sut.max_loaned_samples(), eq 2);
+
+        let sut = service.publisher_builder().max_loaned_samples(5).create()?;
+        assert_that!(sut.set_max_loaned_samples(5), is_ok);
+        assert_that!(sut.set_max_loaned_samples(6).err(), eq Some(PublisherSetMaxLoanedSamplesError::ExceedsMaxLoanedSamplesCeiling));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_max_loaned_samples_cannot_be_set_to_zero<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        assert_that!(sut.set_max_loaned_samples(0).err(), eq Some(PublisherSetMaxLoanedSamplesError::MaxLoanedSamplesIsZero));
+        assert_that!(sut.max_loaned_samples(), eq 2);
+        let _sample = sut.loan_uninit()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_lowering_max_loaned_samples_keeps_loaned_samples<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let sample1 = sut.loan_uninit()?;
+        let _sample2 = sut.loan_uninit()?;
+
+        assert_that!(sut.set_max_loaned_samples(1), is_ok);
+        drop(sample1);
+        assert_that!(sut.loan_uninit().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_statistics_track_loaned_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(3).create()?;
+
+        let stats = sut.loan_statistics();
+        assert_that!(stats.number_of_loaned_samples(), eq 0);
+        assert_that!(stats.max_loaned_samples(), eq 3);
+        assert_that!(stats.loaned_samples_high_watermark(), eq 0);
+        assert_that!(stats.number_of_chunks(), eq 5);
+        assert_that!(stats.number_of_free_chunks(), eq 5);
+        assert_that!(stats.number_of_allocation_failures(), eq 0);
+
+        let sample1 = sut.loan_uninit()?;
+        let sample2 = sut.loan_uninit()?;
+
+        let stats = sut.loan_statistics();
+        assert_that!(stats.number_of_loaned_samples(), eq 2);
+        assert_that!(stats.loaned_samples_high_watermark(), eq 2);
+        assert_that!(stats.number_of_used_chunks(), eq 2);
+        assert_that!(stats.number_of_free_chunks(), eq 3);
+
+        drop(sample1);
+        drop(sample2);
+
+        let stats = sut.loan_statistics();
+        assert_that!(stats.number_of_loaned_samples(), eq 0);
+        assert_that!(stats.loaned_samples_high_watermark(), eq 2);
+        assert_that!(stats.number_of_used_chunks(), eq 0);
+        assert_that!(stats.used_chunks_high_watermark(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_statistics_count_allocation_failures<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+
+        let _sample = sut.loan_uninit()?;
+        assert_that!(sut.loan_uninit(), is_err);
+        assert_that!(sut.loan_uninit(), is_err);
+
+        assert_that!(sut.loan_statistics().number_of_allocation_failures(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_statistics_count_chunks_held_by_subscribers<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+
+        let stats = sut.loan_statistics();
+        assert_that!(stats.number_of_loaned_samples(), eq 0);
+        assert_that!(stats.number_of_used_chunks(), eq 2);
+
+        let sample = subscriber.receive()?.unwrap();
+        drop(sample);
+
+        assert_that!(sut.loan_statistics().number_of_used_chunks(), eq 1);
+        assert_that!(sut.loan_statistics().used_chunks_high_watermark(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_memory_pools_delivers_samples_of_every_pool<Sut: Service>() -> TestResult<()>
+    {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(256)
+            .memory_pools(&[MemoryPool::new(32, 2), MemoryPool::new(256, 2)])
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for slice_len in [3, 20, 200, 4] {
+            let sample = sut.loan_slice_uninit(slice_len)?;
+            let sample = sample.write_from_fn(|n| (n * slice_len) as u64);
+            sample.send()?;
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), len slice_len);
+            for (n, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(n * slice_len) as u64);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_can_loan_max_number_of_samples_of_every_size_class<Sut: Service>() -> TestResult<()>
+    {
+        const BUFFER_SIZE: usize = 2;
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        const MAX_LOANED_SAMPLES: usize = 2;
+        const MAX_SAMPLES_IN_FLIGHT: usize =
+            BUFFER_SIZE + MAX_BORROWED_SAMPLES + MAX_LOANED_SAMPLES;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(8)
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .memory_pools(&[MemoryPool::new(16, 1), MemoryPool::new(64, 1)])
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for (pool, slice_len) in [(0, 1), (1, 8)] {
+            let mut borrowed_samples = vec![];
+            let mut loaned_samples = vec![];
+
+            for _ in 0..BUFFER_SIZE {
+                sut.loan_slice_uninit(slice_len)?
+                    .write_from_fn(|n| n as u64)
+                    .send()?;
+            }
+            for _ in 0..MAX_BORROWED_SAMPLES {
+                borrowed_samples.push(subscriber.receive()?.unwrap());
+            }
+            for _ in 0..BUFFER_SIZE {
+                sut.loan_slice_uninit(slice_len)?
+                    .write_from_fn(|n| n as u64)
+                    .send()?;
+            }
+            for _ in 0..MAX_LOANED_SAMPLES {
+                loaned_samples.push(sut.loan_slice_uninit(slice_len)?);
+            }
+
+            let stats = sut.data_segment_statistics();
+            assert_that!(stats.memory_pools()[pool].number_of_used_buckets(), eq MAX_SAMPLES_IN_FLIGHT);
+
+            drop(loaned_samples);
+            drop(borrowed_samples);
+            while subscriber.receive()?.is_some() {}
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_data_segment_statistics_track_memory_pools<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(8)
+            .max_loaned_samples(3)
+            .memory_pools(&[MemoryPool::new(16, 2), MemoryPool::new(64, 1)])
+            .create()?;
+
+        let stats = sut.data_segment_statistics();
+        assert_that!(stats.memory_pools(), len 2);
+        let small_bucket_size = stats.memory_pools()[0].bucket_size();
+        let large_bucket_size = stats.memory_pools()[1].bucket_size();
+        let number_of_small_buckets = stats.memory_pools()[0].number_of_buckets();
+        let number_of_large_buckets = stats.memory_pools()[1].number_of_buckets();
+        let number_of_buckets = number_of_small_buckets + number_of_large_buckets;
+        assert_that!(small_bucket_size, lt large_bucket_size);
+        assert_that!(number_of_small_buckets, ge 3);
+        assert_that!(number_of_large_buckets, ge 3);
+        assert_that!(stats.number_of_free_buckets(), eq number_of_buckets);
+        assert_that!(stats.largest_free_bucket_size(), eq large_bucket_size);
+        assert_that!(stats.utilization(), eq 0.0);
+
+        let large_sample = sut.loan_slice_uninit(8)?;
+        let small_sample_1 = sut.loan_slice_uninit(1)?;
+        let small_sample_2 = sut.loan_slice_uninit(1)?;
+
+        let stats = sut.data_segment_statistics();
+        assert_that!(stats.memory_pools()[0].number_of_used_buckets(), eq 2);
+        assert_that!(stats.memory_pools()[1].number_of_used_buckets(), eq 1);
+        assert_that!(stats.number_of_free_buckets(), eq number_of_buckets - 3);
+        assert_that!(stats.utilization(), gt 0.0);
+
+        drop(large_sample);
+        drop(small_sample_1);
+        drop(small_sample_2);
+
+        let stats = sut.data_segment_statistics();
+        assert_that!(stats.number_of_free_buckets(), eq number_of_buckets);
+        assert_that!(stats.utilization(), eq 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_static_allocation_strategy_does_not_grow<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(8)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()?;
+
+        let sample = sut.loan_slice_uninit(9);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
+        assert_that!(sut.max_slice_len(), eq 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_best_fit_allocation_strategy_grows_to_slice_len<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(8)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for slice_len in [4, 9, 300, 1000, 2] {
+            let sample = sut.loan_slice_uninit(slice_len)?;
+            let sample = sample.write_from_fn(|n| (n * slice_len) as u64);
+            sample.send()?;
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), len slice_len);
+            for (n, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(n * slice_len) as u64);
+            }
+        }
+
+        assert_that!(sut.max_slice_len(), eq 1000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_power_of_two_allocation_strategy_grows_to_next_power_of_two<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(8)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let sample = sut.loan_slice_uninit(100)?;
+        assert_that!(sut.max_slice_len(), eq 128);
+        let sample = sample.write_from_fn(|n| n as u64 * 3);
+        sample.send()?;
+
+        // fits into the already added data segment
+        let sample = sut.loan_slice(128)?;
+        assert_that!(sut.max_slice_len(), eq 128);
+        drop(sample);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), len 100);
+        for (n, value) in sample.payload().iter().enumerate() {
+            assert_that!(*value, eq n as u64 * 3);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_fails_to_loan_when_it_cannot_grow_any_further<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(1)
+            .max_loaned_samples(64)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+
+        // the loaned samples keep the added data segments in use
+        let mut samples = vec![];
+        let mut slice_len = 1;
+        let result = loop {
+            slice_len += 1;
+            match sut.loan_slice(slice_len) {
+                Ok(sample) => samples.push(sample),
+                Err(e) => break e,
+            }
+        };
+
+        assert_that!(result, eq PublisherLoanError::ExceedsMaxLoanSize);
+        assert_that!(sut.max_slice_len(), eq slice_len - 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_releases_empty_added_data_segments<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_GROWTHS: usize = 32;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for slice_len in 2..NUMBER_OF_GROWTHS + 2 {
+            let sample = sut.loan_slice_uninit(slice_len)?;
+            assert_that!(sut.max_slice_len(), eq slice_len);
+            let sample = sample.write_from_fn(|n| (n * slice_len) as u64);
+            sample.send()?;
+
+            // the subscriber reopens the slots that are reused by the publisher
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), len slice_len);
+            for (n, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(n * slice_len) as u64);
+            }
+        }
+
+        let number_of_pools = sut.data_segment_statistics().memory_pools().len();
+        assert_that!(number_of_pools, le 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_uses_memory_pools_from_config<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.publisher_memory_pools = vec![MemoryPool::new(16, 1024)];
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_slice_len(2).create()?;
+
+        assert_that!(sut.loan_statistics().number_of_chunks(), eq 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_prefaulted_memory_delivers_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().prefault_memory(true).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(8192)?;
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 8192);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_locked_memory_from_config_delivers_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.publisher_memory_lock = true;
+        config.defaults.publish_subscribe.publisher_prefault_memory = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(4096)?;
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 4096);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_of_huge_page_service_delivers_samples_with_fallback<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.huge_pages_fallback = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .huge_pages(true)
+            .create()?;
+        assert_that!(service.static_config().requests_huge_pages(), eq true);
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(2048)?;
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 2048);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_of_huge_page_service_without_fallback_uses_huge_pages<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.huge_pages_fallback = false;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .huge_pages(true)
+            .create()?;
+
+        let sut = match service.publisher_builder().create() {
+            Ok(sut) => sut,
+            Err(e) => {
+                assert_that!(e, eq PublisherCreateError::UnableToCreateDataSegment);
+                return Ok(());
+            }
+        };
+
+        for pool in sut.data_segment_statistics().memory_pools() {
+            assert_that!(pool.uses_huge_pages(), eq true);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn service_uses_huge_pages_from_config<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.huge_pages = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        assert_that!(service.static_config().requests_huge_pages(), eq true);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
         let _watchdog = Watchdog::new();
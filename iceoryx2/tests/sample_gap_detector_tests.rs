@@ -0,0 +1,158 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod sample_gap_detector {
+    use iceoryx2::port::sample_gap_detector::SampleGapDetector;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "sample_gap_detector_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_service<S: Service>(node: &Node<S>, buffer_size: usize) -> PortFactory<S, u64, ()> {
+        node.service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .history_size(0)
+            .subscriber_max_buffer_size(buffer_size)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap()
+    }
+
+    #[test]
+    fn sequence_numbers_of_a_publisher_are_consecutive<S: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = create_service(&node, NUMBER_OF_SAMPLES as usize);
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().sequence_number(), eq n);
+        }
+    }
+
+    #[test]
+    fn no_gap_is_reported_when_every_sample_is_received<S: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = create_service(&node, 1);
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let mut sut = SampleGapDetector::new();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            for publisher in [&publisher_1, &publisher_2] {
+                assert_that!(publisher.send_copy(n), eq Ok(1));
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(sut.update(sample.header()), eq None);
+            }
+        }
+
+        assert_that!(sut.number_of_lost_samples(), eq 0);
+    }
+
+    #[test]
+    fn gap_is_reported_when_samples_are_lost<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = create_service(&node, 1);
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let mut sut = SampleGapDetector::new();
+
+        assert_that!(publisher.send_copy(0), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sut.update(sample.header()), eq None);
+        drop(sample);
+
+        // the buffer holds one sample, the older ones are overridden
+        for n in 1..4 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let gap = sut.update(sample.header()).unwrap();
+        assert_that!(gap.publisher_id(), eq publisher.id());
+        assert_that!(gap.first_lost_sequence_number(), eq 1);
+        assert_that!(gap.received_sequence_number(), eq 3);
+        assert_that!(gap.number_of_lost_samples(), eq 2);
+        assert_that!(sut.number_of_lost_samples(), eq 2);
+    }
+
+    #[test]
+    fn first_sample_of_a_publisher_does_not_report_a_gap<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = create_service(&node, 1);
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let mut sut = SampleGapDetector::new();
+
+        for n in 0..3 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 2);
+        assert_that!(sut.update(sample.header()), eq None);
+        assert_that!(sut.number_of_lost_samples(), eq 0);
+    }
+
+    #[test]
+    fn removed_publisher_does_not_report_a_gap<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = create_service(&node, 1);
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let mut sut = SampleGapDetector::new();
+
+        assert_that!(publisher.send_copy(0), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sut.update(sample.header()), eq None);
+        drop(sample);
+
+        sut.remove_publisher(&publisher.id());
+        for n in 1..4 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sut.update(sample.header()), eq None);
+        assert_that!(sut.number_of_lost_samples(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
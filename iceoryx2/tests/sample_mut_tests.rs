@@ -178,6 +178,69 @@ mod sample_mut {
         assert_that!(service.publisher_builder().create(), is_ok);
     }
 
+    #[test]
+    fn write_from_parts_initializes_whole_slice<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 19;
+        const NUMBER_OF_PARTS: usize = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(NUMBER_OF_ELEMENTS).unwrap();
+        let sample = sample.write_from_parts(NUMBER_OF_PARTS, |parts| {
+            assert_that!(parts, len NUMBER_OF_PARTS);
+            std::thread::scope(|s| {
+                for part in parts {
+                    s.spawn(move || part.write_from_fn(|n| n as u64 * 3));
+                }
+            });
+        });
+        assert_that!(sample, is_some);
+        assert_that!(sample.unwrap().send(), eq Ok(1));
+
+        let received_sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(received_sample.payload(), len NUMBER_OF_ELEMENTS);
+        for (n, element) in received_sample.payload().iter().enumerate() {
+            assert_that!(*element, eq n as u64 * 3);
+        }
+    }
+
+    #[test]
+    fn write_from_parts_fails_when_a_part_is_not_written<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 8;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
+            .unwrap();
+
+        let sample = publisher.loan_slice_uninit(NUMBER_OF_ELEMENTS).unwrap();
+        let sample = sample.write_from_parts(2, |mut parts| {
+            parts.pop();
+            for part in parts {
+                part.write_from_slice(&[1, 2, 3, 4]);
+            }
+        });
+        assert_that!(sample, is_none);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
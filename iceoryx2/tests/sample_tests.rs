@@ -15,6 +15,7 @@ mod sample {
     use iceoryx2::port::publisher::Publisher;
     use iceoryx2::port::subscriber::Subscriber;
     use iceoryx2::prelude::*;
+    use iceoryx2::service::header::publish_subscribe::TracingContext;
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
@@ -173,6 +174,28 @@ mod sample {
         assert_that!(*sample_2, eq PAYLOAD_2);
     }
 
+    #[test]
+    fn sample_delivers_tracing_context<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        let tracing_context = TracingContext::new([0x4b; 16], [0x7f; 8]);
+
+        let mut sample = test_context.publisher_1.loan().unwrap();
+        sample.set_tracing_context(tracing_context);
+        sample.send().unwrap();
+
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().tracing_context(), eq Some(tracing_context));
+        assert_that!(
+            tracing_context.to_string(),
+            eq "00-4b4b4b4b4b4b4b4b4b4b4b4b4b4b4b4b-7f7f7f7f7f7f7f7f-01"
+        );
+
+        assert_that!(test_context.publisher_1.send_copy(12), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().tracing_context(), eq None);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
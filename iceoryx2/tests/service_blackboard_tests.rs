@@ -0,0 +1,431 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_blackboard {
+    use iceoryx2::port::reader::ReaderCreateError;
+    use iceoryx2::port::writer::{WriterCreateError, WriterUpdateError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_elementary::CallbackProgression;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+        assert_that!(sut.static_config().number_of_entries(), eq 1);
+    }
+
+    #[test]
+    fn creating_service_without_entries_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardCreateError::NoEntriesProvided);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn open_non_existing_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .add(1, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().number_of_entries(), eq 2);
+    }
+
+    #[test]
+    fn open_with_incompatible_key_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u32, u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::IncompatibleKeyType);
+    }
+
+    #[test]
+    fn open_with_incompatible_value_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, i16>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::IncompatibleValueType);
+    }
+
+    #[test]
+    fn event_and_blackboard_with_same_name_are_different_services<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_readers_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .max_readers(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .max_readers(1)
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn reader_acquires_initial_values<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 12)
+            .add(1, 34)
+            .add(0, 56)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(sut.static_config().number_of_entries(), eq 2);
+        assert_that!(reader.get(&0).map(|v| *v), eq Some(56));
+        assert_that!(reader.get(&1).map(|v| *v), eq Some(34));
+        assert_that!(reader.get(&2).map(|v| *v), is_none);
+    }
+
+    #[test]
+    fn reader_acquires_updated_values<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .add(1, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .open()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut2.reader_builder().create().unwrap();
+
+        assert_that!(writer.update(&1, 789), is_ok);
+        assert_that!(reader.get(&0).map(|v| *v), eq Some(0));
+        assert_that!(reader.get(&1).map(|v| *v), eq Some(789));
+
+        assert_that!(writer.update(&0, 123), is_ok);
+        assert_that!(reader.get(&0).map(|v| *v), eq Some(123));
+    }
+
+    #[test]
+    fn entry_value_is_not_modified_by_writer_update<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 12)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let value = reader.get(&0).unwrap();
+        assert_that!(writer.update(&0, 34), is_ok);
+        assert_that!(*value, eq 12);
+        assert_that!(reader.get(&0).map(|v| *v), eq Some(34));
+        drop(value);
+
+        assert_that!(writer.update(&0, 56), is_ok);
+        assert_that!(reader.get(&0).map(|v| *v), eq Some(56));
+    }
+
+    #[test]
+    fn writer_update_fails_when_every_value_is_in_use<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .max_readers(1)
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let mut values = vec![];
+        for n in 1..3 {
+            values.push(reader.get(&0).unwrap());
+            assert_that!(writer.update(&0, n), is_ok);
+        }
+        values.push(reader.get(&0).unwrap());
+
+        let result = writer.update(&0, 3);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq WriterUpdateError::ValueIsStillInUse);
+
+        for (n, value) in values.iter().enumerate() {
+            assert_that!(**value, eq n as u64);
+        }
+
+        values.remove(0);
+        assert_that!(writer.update(&0, 3), is_ok);
+        assert_that!(reader.get(&0).map(|v| *v), eq Some(3));
+    }
+
+    #[test]
+    fn writer_update_of_non_existing_key_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let result = writer.update(&1, 1);
+
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq WriterUpdateError::KeyDoesNotExist);
+    }
+
+    #[test]
+    fn only_one_writer_can_exist_at_a_time<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create();
+        assert_that!(writer, is_ok);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+        let writer2 = sut.writer_builder().create();
+        assert_that!(writer2, is_err);
+        assert_that!(writer2.err().unwrap(), eq WriterCreateError::ExceedsMaxSupportedWriters);
+
+        drop(writer);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+
+        let writer3 = sut.writer_builder().create();
+        assert_that!(writer3, is_ok);
+    }
+
+    #[test]
+    fn number_of_readers_is_limited<Sut: Service>() {
+        const MAX_READERS: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(0, 0)
+            .max_readers(MAX_READERS)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for i in 0..MAX_READERS {
+            readers.push(sut.reader_builder().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_readers(), eq i + 1);
+        }
+
+        let reader = sut.reader_builder().create();
+        assert_that!(reader, is_err);
+        assert_that!(reader.err().unwrap(), eq ReaderCreateError::ExceedsMaxSupportedReaders);
+
+        readers.pop();
+        assert_that!(sut.reader_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn reader_lists_all_keys<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64, u64>()
+            .add(5, 0)
+            .add(7, 0)
+            .add(9, 0)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let mut keys = vec![];
+        reader.list_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(keys, eq vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn create_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", BlackboardCreateError::NoEntriesProvided), eq "BlackboardCreateError::NoEntriesProvided");
+        assert_that!(
+            format!("{}", BlackboardOpenError::IncompatibleKeyType), eq "BlackboardOpenError::IncompatibleKeyType");
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_discovery {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::discovery::ServiceDiscoveryEventKind;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_discovery_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_a_service_emits_created_event<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let discovery = node.service_discovery().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let event = discovery.receive().unwrap().unwrap();
+        assert_that!(event.kind(), eq ServiceDiscoveryEventKind::Created);
+        assert_that!(event.service_id(), eq sut.service_id());
+        assert_that!(event.messaging_pattern(), eq MessagingPattern::PublishSubscribe);
+        assert_that!(discovery.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn removing_a_service_emits_removed_event<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let discovery = node.service_discovery().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let service_id = sut.service_id().clone();
+        drop(sut);
+
+        let event = discovery.receive().unwrap().unwrap();
+        assert_that!(event.kind(), eq ServiceDiscoveryEventKind::Created);
+        assert_that!(*event.service_id(), eq service_id);
+        assert_that!(event.messaging_pattern(), eq MessagingPattern::Event);
+
+        let event = discovery.receive().unwrap().unwrap();
+        assert_that!(event.kind(), eq ServiceDiscoveryEventKind::Removed);
+        assert_that!(*event.service_id(), eq service_id);
+        assert_that!(event.messaging_pattern(), eq MessagingPattern::Event);
+    }
+
+    #[test]
+    fn removing_a_service_from_a_node_that_did_not_create_it_emits_removed_event<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let creator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let opener = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = creator
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut2 = opener
+            .service_builder(&service_name)
+            .event()
+            .open()
+            .unwrap();
+        let service_id = sut.service_id().clone();
+
+        let discovery = creator.service_discovery().unwrap();
+        while discovery.receive().unwrap().is_some() {}
+
+        drop(sut);
+        drop(sut2);
+
+        let event = discovery.receive().unwrap().unwrap();
+        assert_that!(event.kind(), eq ServiceDiscoveryEventKind::Removed);
+        assert_that!(*event.service_id(), eq service_id);
+    }
+
+    #[test]
+    fn opening_a_service_does_not_emit_an_event<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let discovery = node.service_discovery().unwrap();
+        while discovery.receive().unwrap().is_some() {}
+
+        let _sut2 = node.service_builder(&service_name).event().open().unwrap();
+
+        assert_that!(discovery.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn events_before_subscriber_creation_are_not_received<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let late_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let discovery = late_node.service_discovery().unwrap();
+
+        assert_that!(discovery.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn service_discovery_service_does_not_exist_without_subscriber<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        drop(sut);
+
+        let mut number_of_services = 0;
+        assert_that!(
+            S::list(&config, |_| {
+                number_of_services += 1;
+                CallbackProgression::Continue
+            }),
+            is_ok
+        );
+        assert_that!(number_of_services, eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -17,6 +17,7 @@ mod service_event {
     use std::sync::Barrier;
     use std::time::{Duration, Instant};
 
+    use iceoryx2::port::event_id::{EventIdFilterError, MAX_EVENT_ID_FILTER_RANGES};
     use iceoryx2::port::listener::{Listener, ListenerCreateError};
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
@@ -25,6 +26,7 @@ mod service_event {
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::ListenerWaitError;
 
     const TIMEOUT: Duration = Duration::from_millis(50);
 
@@ -88,6 +90,95 @@ mod service_event {
         assert_that!(sut.err().unwrap(), eq EventOpenError::DoesNotExist);
     }
 
+    #[test]
+    fn open_or_wait_fails_when_service_is_not_created_within_timeout<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let now = Instant::now();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_wait(TIMEOUT);
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq EventOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_or_wait_opens_existing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_wait(Duration::ZERO);
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn open_or_wait_blocks_until_service_is_created<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let _service = node
+                    .service_builder(&service_name)
+                    .event()
+                    .create()
+                    .unwrap();
+                barrier.wait();
+            });
+
+            barrier.wait();
+            let now = Instant::now();
+            let sut = node
+                .service_builder(&service_name)
+                .event()
+                .open_or_wait(TIMEOUT * 1000);
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+            assert_that!(sut, is_ok);
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn open_or_create_with_hook_calls_hook_only_when_service_is_created<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut number_of_hook_calls = 0;
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create_with_hook(|_| number_of_hook_calls += 1);
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create_with_hook(|_| number_of_hook_calls += 1);
+        assert_that!(sut2, is_ok);
+
+        assert_that!(number_of_hook_calls, eq 1);
+    }
+
     #[test]
     fn open_succeeds_when_service_does_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -1162,6 +1253,344 @@ mod service_event {
         });
     }
 
+    #[test]
+    fn notification_with_payload_is_received<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .payload_size(4)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(5))
+            .create()
+            .unwrap();
+
+        assert_that!(notifier.notify_with_payload(&42u32.to_ne_bytes()), eq Ok(1));
+        assert_that!(notifier.notify_with_custom_event_id_and_payload(EventId::new(7), &73u32.to_ne_bytes()), eq Ok(1));
+
+        let payload = listener
+            .timed_wait_one_with_payload(TIMEOUT)
+            .unwrap()
+            .unwrap();
+        assert_that!(payload.event_id(), eq EventId::new(5));
+        assert_that!(payload.data(), eq 42u32.to_ne_bytes());
+
+        let payload = listener.try_wait_one_with_payload().unwrap().unwrap();
+        assert_that!(payload.event_id(), eq EventId::new(7));
+        assert_that!(payload.data(), eq 73u32.to_ne_bytes());
+
+        assert_that!(listener.try_wait_one_with_payload().unwrap(), is_none);
+    }
+
+    #[test]
+    fn listener_receives_only_event_ids_of_its_filter<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let filter = EventIdFilter::new()
+            .with_range(EventId::new(10)..=EventId::new(19))
+            .unwrap()
+            .with_id(EventId::new(42))
+            .unwrap();
+        let filtered_listener = sut
+            .listener_builder()
+            .event_id_filter(filter)
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(9)), eq Ok(1));
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(10)), eq Ok(2));
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(19)), eq Ok(2));
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(20)), eq Ok(1));
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(42)), eq Ok(2));
+
+        let batch = filtered_listener.try_wait_batch().unwrap();
+        assert_that!(batch.len(), eq 3);
+        assert_that!(batch.contains(EventId::new(10)), eq true);
+        assert_that!(batch.contains(EventId::new(19)), eq true);
+        assert_that!(batch.contains(EventId::new(42)), eq true);
+
+        let batch = listener.try_wait_batch().unwrap();
+        assert_that!(batch.len(), eq 5);
+    }
+
+    #[test]
+    fn event_id_filter_merges_overlapping_and_adjacent_ranges<Sut: Service>() {
+        let sut = EventIdFilter::new()
+            .with_range(EventId::new(5)..=EventId::new(9))
+            .unwrap()
+            .with_range(EventId::new(20)..=EventId::new(30))
+            .unwrap()
+            .with_range(EventId::new(8)..=EventId::new(12))
+            .unwrap()
+            .with_id(EventId::new(13))
+            .unwrap();
+
+        let ranges: Vec<_> = sut.ranges().collect();
+        assert_that!(ranges, eq vec![
+            EventId::new(5)..=EventId::new(13),
+            EventId::new(20)..=EventId::new(30)
+        ]);
+        assert_that!(sut.contains(EventId::new(4)), eq false);
+        assert_that!(sut.contains(EventId::new(13)), eq true);
+        assert_that!(sut.contains(EventId::new(14)), eq false);
+        assert_that!(sut.accepts_all(), eq false);
+        assert_that!(EventIdFilter::default().contains(EventId::new(1234)), eq true);
+    }
+
+    #[test]
+    fn event_id_filter_with_too_many_ranges_fails<Sut: Service>() {
+        let mut sut = EventIdFilter::new();
+        for i in 0..MAX_EVENT_ID_FILTER_RANGES {
+            sut = sut.with_id(EventId::new(2 * i)).unwrap();
+        }
+
+        assert_that!(sut.with_id(EventId::new(2 * MAX_EVENT_ID_FILTER_RANGES)).err(),
+            eq Some(EventIdFilterError::ExceedsMaxSupportedRanges));
+        // merges into an existing range and does not require a new one
+        assert_that!(sut.with_id(EventId::new(1)), is_ok);
+    }
+
+    #[test]
+    fn notification_with_payload_of_wrong_size_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .payload_size(4)
+            .create()
+            .unwrap();
+
+        let _listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_payload(&[1, 2]), eq Err(NotifierNotifyError::IncompatiblePayloadSize));
+    }
+
+    #[test]
+    fn open_with_different_payload_size_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .payload_size(4)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .payload_size(8)
+            .open();
+        assert_that!(sut2.err(), eq Some(EventOpenError::IncompatiblePayloadSize));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .payload_size(4)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_with_different_deadline_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(TIMEOUT)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().deadline(), eq Some(TIMEOUT));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(TIMEOUT * 2)
+            .open();
+        assert_that!(sut2.err(), eq Some(EventOpenError::IncompatibleDeadline));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(TIMEOUT)
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
+        assert_that!(sut2.static_config().deadline(), eq Some(TIMEOUT));
+    }
+
+    #[test]
+    fn listener_counts_missed_deadlines<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(TIMEOUT)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.deadline(), eq Some(TIMEOUT));
+
+        assert_that!(notifier.notify(), is_ok);
+        assert_that!(listener.missed_deadline_count(), eq 0);
+
+        // the deadline is missed even when the listener does not wait in between
+        std::thread::sleep(TIMEOUT * 3);
+        assert_that!(listener.missed_deadline_count(), ge 2);
+        assert_that!(notifier.notify(), is_ok);
+        let missed_deadlines = listener.missed_deadline_count();
+        assert_that!(missed_deadlines, ge 2);
+
+        assert_that!(notifier.notify(), is_ok);
+        assert_that!(listener.missed_deadline_count(), eq missed_deadlines);
+    }
+
+    #[test]
+    fn event_id_names_are_available_to_all_participants<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(10)
+            .event_id_name(EventId::new(7), "shutdown")
+            .event_id_name(EventId::new(2), "start")
+            .event_id_name(EventId::new(7), "stop")
+            .event_id_name(EventId::new(11), "out of range")
+            .create()
+            .unwrap();
+
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
+        let listener = sut2.listener_builder().create().unwrap();
+
+        assert_that!(sut2.static_config().event_id_name(EventId::new(2)), eq Some("start"));
+        assert_that!(sut2.static_config().event_id_name(EventId::new(7)), eq Some("stop"));
+        assert_that!(
+            sut2.static_config().event_id_name(EventId::new(11)),
+            is_none
+        );
+        assert_that!(listener.event_id_name(EventId::new(2)), eq Some("start"));
+        assert_that!(listener.event_id_name(EventId::new(3)), is_none);
+        assert_that!(
+            sut.static_config().event_id_names().collect::<Vec<_>>(),
+            eq vec![(EventId::new(2), "start"), (EventId::new(7), "stop")]
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EventIdEnum)]
+    enum TestEvents {
+        Start,
+        Stop,
+        Error = 12,
+        Restart = 5,
+    }
+
+    #[test]
+    fn event_id_enum_maps_variants_to_event_ids<Sut: Service>() {
+        assert_that!(TestEvents::MAX_EVENT_ID, eq EventId::new(12));
+        assert_that!(TestEvents::Start.event_id(), eq EventId::new(0));
+        assert_that!(EventId::from(TestEvents::Stop), eq EventId::new(1));
+        assert_that!(EventId::from(TestEvents::Restart), eq EventId::new(5));
+        assert_that!(TestEvents::from_event_id(EventId::new(12)), eq Some(TestEvents::Error));
+        assert_that!(TestEvents::from_event_id(EventId::new(2)), is_none);
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(TestEvents::MAX_EVENT_ID.as_value())
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut
+            .notifier_builder()
+            .default_event_id(TestEvents::Start.into())
+            .create()
+            .unwrap();
+
+        notifier
+            .notify_with_custom_event_id(TestEvents::Error.into())
+            .unwrap();
+
+        let event_id = listener.try_wait_one().unwrap().unwrap();
+        assert_that!(TestEvents::from_event_id(event_id), eq Some(TestEvents::Error));
+    }
+
+    #[test]
+    fn listener_without_deadline_does_not_count_missed_deadlines<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        assert_that!(listener.deadline(), is_none);
+
+        std::thread::sleep(TIMEOUT);
+        assert_that!(listener.missed_deadline_count(), eq 0);
+    }
+
+    #[test]
+    fn wait_with_payload_on_service_without_payload_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(listener.try_wait_one_with_payload().err(), eq Some(ListenerWaitError::ContractViolation));
+    }
+
     #[test]
     fn open_error_display_works<S: Service>() {
         assert_that!(
@@ -1172,6 +1601,8 @@ mod service_event {
             format!("{}", EventOpenError::ServiceInCorruptedState), eq "EventOpenError::ServiceInCorruptedState");
         assert_that!(
             format!("{}", EventOpenError::IncompatibleMessagingPattern), eq "EventOpenError::IncompatibleMessagingPattern");
+        assert_that!(
+            format!("{}", EventOpenError::IncompatiblePlatform), eq "EventOpenError::IncompatiblePlatform");
         assert_that!(
             format!("{}", EventOpenError::IncompatibleAttributes), eq "EventOpenError::IncompatibleAttributes");
         assert_that!(
@@ -1184,6 +1615,10 @@ mod service_event {
             format!("{}", EventOpenError::DoesNotSupportRequestedAmountOfListeners), eq "EventOpenError::DoesNotSupportRequestedAmountOfListeners");
         assert_that!(
             format!("{}", EventOpenError::DoesNotSupportRequestedMaxEventId), eq "EventOpenError::DoesNotSupportRequestedMaxEventId");
+        assert_that!(
+            format!("{}", EventOpenError::IncompatiblePayloadSize), eq "EventOpenError::IncompatiblePayloadSize");
+        assert_that!(
+            format!("{}", EventOpenError::IncompatibleDeadline), eq "EventOpenError::IncompatibleDeadline");
     }
 
     #[test]
@@ -1198,6 +1633,33 @@ mod service_event {
             format!("{}", EventCreateError::IsBeingCreatedByAnotherInstance), eq "EventCreateError::IsBeingCreatedByAnotherInstance");
     }
 
+    #[test]
+    fn statistics_count_sent_notifications_and_ports<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        for _ in 0..3 {
+            notifier.notify().unwrap();
+        }
+        drop(listener);
+
+        let statistics = sut.statistics();
+        assert_that!(statistics.notifications_sent(), eq 3);
+        assert_that!(statistics.connected_ports(), eq 2);
+        assert_that!(statistics.disconnected_ports(), eq 1);
+        assert_that!(statistics.samples_published(), eq 0);
+        assert_that!(statistics.bytes_published(), eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,231 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_external_memory {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::external_memory;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::path::Path;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::shared_memory::*;
+    use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+
+    type Provider = iceoryx2_cal::shared_memory::posix::Memory<PoolAllocator>;
+
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    // a user-provided memory that counts the allocations, all other operations are
+    // forwarded to the posix shared memory
+    #[derive(Debug)]
+    struct TrackedMemory(Provider);
+
+    struct TrackedMemoryBuilder(<Provider as SharedMemory<PoolAllocator>>::Builder);
+
+    type Sut = external_memory::Service<TrackedMemory>;
+
+    impl NamedConcept for TrackedMemory {
+        fn name(&self) -> &FileName {
+            self.0.name()
+        }
+    }
+
+    impl NamedConceptMgmt for TrackedMemory {
+        type Configuration = <Provider as NamedConceptMgmt>::Configuration;
+
+        unsafe fn remove_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Result<bool, NamedConceptRemoveError> {
+            Provider::remove_cfg(name, cfg)
+        }
+
+        fn does_exist_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Result<bool, NamedConceptDoesExistError> {
+            Provider::does_exist_cfg(name, cfg)
+        }
+
+        fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+            Provider::list_cfg(cfg)
+        }
+
+        fn remove_path_hint(value: &Path) -> Result<(), NamedConceptPathHintRemoveError> {
+            Provider::remove_path_hint(value)
+        }
+    }
+
+    impl NamedConceptBuilder<TrackedMemory> for TrackedMemoryBuilder {
+        fn new(name: &FileName) -> Self {
+            Self(<Provider as SharedMemory<PoolAllocator>>::Builder::new(
+                name,
+            ))
+        }
+
+        fn config(self, config: &<TrackedMemory as NamedConceptMgmt>::Configuration) -> Self {
+            Self(self.0.config(config))
+        }
+    }
+
+    impl SharedMemoryBuilder<PoolAllocator, TrackedMemory> for TrackedMemoryBuilder {
+        fn has_ownership(self, value: bool) -> Self {
+            Self(self.0.has_ownership(value))
+        }
+
+        fn size(self, value: usize) -> Self {
+            Self(self.0.size(value))
+        }
+
+        fn timeout(self, value: Duration) -> Self {
+            Self(self.0.timeout(value))
+        }
+
+        fn is_memory_locked(self, value: bool) -> Self {
+            Self(self.0.is_memory_locked(value))
+        }
+
+        fn prefault_memory(self, value: bool) -> Self {
+            Self(self.0.prefault_memory(value))
+        }
+
+        fn use_huge_pages(self, value: bool) -> Self {
+            Self(self.0.use_huge_pages(value))
+        }
+
+        fn create(
+            self,
+            allocator_config: &<PoolAllocator as ShmAllocator>::Configuration,
+        ) -> Result<TrackedMemory, SharedMemoryCreateError> {
+            Ok(TrackedMemory(self.0.create(allocator_config)?))
+        }
+
+        fn open(self) -> Result<TrackedMemory, SharedMemoryOpenError> {
+            Ok(TrackedMemory(self.0.open()?))
+        }
+    }
+
+    impl SharedMemory<PoolAllocator> for TrackedMemory {
+        type Builder = TrackedMemoryBuilder;
+
+        fn size(&self) -> usize {
+            self.0.size()
+        }
+
+        fn max_alignment(&self) -> usize {
+            self.0.max_alignment()
+        }
+
+        fn payload_start_address(&self) -> usize {
+            self.0.payload_start_address()
+        }
+
+        fn uses_huge_pages(&self) -> bool {
+            self.0.uses_huge_pages()
+        }
+
+        fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
+            let ptr = self.0.allocate(layout)?;
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            Ok(ptr)
+        }
+
+        unsafe fn deallocate(&self, offset: PointerOffset, layout: std::alloc::Layout) {
+            self.0.deallocate(offset, layout)
+        }
+
+        fn does_support_persistency() -> bool {
+            Provider::does_support_persistency()
+        }
+
+        fn has_ownership(&self) -> bool {
+            self.0.has_ownership()
+        }
+
+        fn acquire_ownership(&self) {
+            self.0.acquire_ownership()
+        }
+
+        fn release_ownership(&self) {
+            self.0.release_ownership()
+        }
+    }
+
+    #[test]
+    fn payload_is_allocated_from_user_provided_memory() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        let sample = publisher.loan_uninit().unwrap();
+        assert_that!(ALLOCATIONS.load(Ordering::Relaxed), gt allocations_before);
+
+        sample.write_payload(8127).send().unwrap();
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8127);
+    }
+
+    #[test]
+    fn does_not_collide_with_ipc_service_with_same_name() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let ipc_node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(ipc::Service::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe), eq Ok(false));
+
+        let mut ipc_services = 0;
+        ipc::Service::list(&config, |_| {
+            ipc_services += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ipc_services, eq 0);
+
+        let ipc_sut = ipc_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(ipc_sut, is_ok);
+        let ipc_publisher = ipc_sut.unwrap().publisher_builder().create();
+        assert_that!(ipc_publisher, is_ok);
+
+        assert_that!(Sut::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe), eq Ok(true));
+        assert_that!(ipc::Service::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe), eq Ok(true));
+    }
+}
@@ -15,9 +15,10 @@ mod service_publish_subscribe {
     use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::{Barrier, Mutex};
     use std::thread;
+    use std::time::{Duration, Instant};
 
     use iceoryx2::config::Config;
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
+    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError, PublisherSendError};
     use iceoryx2::port::subscriber::SubscriberCreateError;
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
@@ -25,22 +26,35 @@ mod service_publish_subscribe {
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
     use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::messaging_pattern::MessagingPattern;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::publish_subscribe::MaxPortsIncreaseError;
+    use iceoryx2::service::port_factory::publisher::{PublisherPriority, UnableToDeliverStrategy};
+    use iceoryx2::service::port_factory::subscriber::{SampleOrdering, SubscriberOverflowStrategy};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{Service, ServiceDetails};
     use iceoryx2::testing::*;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
 
-    #[derive(Debug)]
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
     struct SomeUserHeader {
         value: [u64; 1024],
     }
 
+    #[derive(Debug, TypeLayoutHash, ZeroCopySend)]
+    #[repr(C)]
+    struct LayoutCheckedPayload {
+        value: u64,
+        data: [u8; 8],
+    }
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "service_tests_{}",
@@ -201,6 +215,129 @@ mod service_publish_subscribe {
         assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
     }
 
+    #[test]
+    fn open_or_wait_fails_when_service_is_not_created_within_timeout<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let now = Instant::now();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_wait(TIMEOUT);
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_or_wait_opens_existing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_wait(Duration::ZERO);
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn open_or_wait_blocks_until_service_is_created<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let _service = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .create()
+                    .unwrap();
+                barrier.wait();
+            });
+
+            barrier.wait();
+            let now = Instant::now();
+            let sut = node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_wait(TIMEOUT * 1000);
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+            assert_that!(sut, is_ok);
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn open_or_create_with_hook_calls_hook_only_in_the_creating_instance<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let number_of_threads = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 1024);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let number_of_hook_calls = AtomicUsize::new(0);
+        let barrier_enter = Barrier::new(number_of_threads);
+        let barrier_exit = Barrier::new(number_of_threads);
+
+        std::thread::scope(|s| {
+            let mut threads = vec![];
+            for _ in 0..number_of_threads {
+                threads.push(s.spawn(|| {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    barrier_enter.wait();
+
+                    let sut = node
+                        .service_builder(&service_name)
+                        .publish_subscribe::<u64>()
+                        .open_or_create_with_hook(|_| {
+                            number_of_hook_calls.fetch_add(1, Ordering::Relaxed);
+                        });
+                    assert_that!(sut, is_ok);
+
+                    barrier_exit.wait();
+                }));
+            }
+
+            for thread in threads {
+                thread.join().unwrap();
+            }
+        });
+
+        assert_that!(number_of_hook_calls.load(Ordering::Relaxed), eq 1);
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create_with_hook(|service| {
+                number_of_hook_calls.fetch_add(1, Ordering::Relaxed);
+                assert_that!(service.dynamic_config().number_of_publishers(), eq 0);
+            });
+        assert_that!(sut, is_ok);
+        let _sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create_with_hook(|_| {
+                number_of_hook_calls.fetch_add(1, Ordering::Relaxed);
+            })
+            .unwrap();
+
+        assert_that!(number_of_hook_calls.load(Ordering::Relaxed), eq 2);
+    }
+
     #[test]
     fn open_succeeds_when_service_does_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -1515,6 +1652,187 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn subscribers_with_different_overflow_strategies_can_be_mixed<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let lossy_subscriber = sut.subscriber_builder().create().unwrap();
+        let discard_newest_subscriber = sut
+            .subscriber_builder()
+            .overflow_strategy(SubscriberOverflowStrategy::DiscardNewest)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(2 * i + 25), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            let sample = lossy_subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 2 * i + 25);
+
+            let sample = discard_newest_subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+        }
+    }
+
+    #[test]
+    fn subscriber_can_discard_oldest_samples_in_non_overflowing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 3;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .overflow_strategy(SubscriberOverflowStrategy::DiscardOldest)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(3 * i + 7), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 3 * i + 7);
+        }
+    }
+
+    #[test]
+    fn subscriber_can_block_publisher_in_overflowing_service<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        const BUFFER_SIZE: usize = 2;
+
+        let sut = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let sut = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<usize>()
+                    .open()
+                    .unwrap();
+                let subscriber = sut
+                    .subscriber_builder()
+                    .overflow_strategy(SubscriberOverflowStrategy::BlockPublisher)
+                    .create()
+                    .unwrap();
+                let receive_sample = || loop {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        return *sample;
+                    }
+                };
+
+                barrier.wait();
+                thread::sleep(TIMEOUT);
+                for i in 0..BUFFER_SIZE + 1 {
+                    assert_that!(receive_sample(), eq i);
+                }
+            });
+
+            barrier.wait();
+            let start = Instant::now();
+            for i in 0..BUFFER_SIZE {
+                assert_that!(publisher.send_copy(i), eq Ok(1));
+            }
+
+            // the buffer of the subscriber is full, the publisher must wait until the
+            // subscriber consumed a sample instead of discarding the oldest one
+            assert_that!(publisher.send_copy(BUFFER_SIZE), eq Ok(1));
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        });
+    }
+
+    #[test]
+    fn subscriber_overflow_strategy_from_config_can_be_overridden<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config
+            .defaults
+            .publish_subscribe
+            .subscriber_overflow_strategy = Some(SubscriberOverflowStrategy::DiscardNewest);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let discard_newest_subscriber = sut.subscriber_builder().create().unwrap();
+        let lossy_subscriber = sut
+            .subscriber_builder()
+            .overflow_strategy(SubscriberOverflowStrategy::DiscardOldest)
+            .create()
+            .unwrap();
+
+        for i in 0..2 * BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        for i in 0..BUFFER_SIZE {
+            let sample = discard_newest_subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+
+            let sample = lossy_subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq BUFFER_SIZE + i);
+        }
+    }
+
     #[test]
     fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
         let service_name = generate_name();
@@ -1566,6 +1884,43 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn publish_history_is_delivered_before_new_samples<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE + 1)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        for i in 0..HISTORY_SIZE + 2 {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
+        }
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(1000), is_ok);
+
+        for i in 2..HISTORY_SIZE + 2 {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq i);
+        }
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq 1000);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_none);
+    }
+
     #[test]
     fn publish_history_of_zero_works<Sut: Service>() {
         const BUFFER_SIZE: usize = 2;
@@ -2040,29 +2395,132 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+    fn raised_max_subscribers_allow_additional_subscribers<Sut: Service>() {
+        const MAX_SUBSCRIBERS: usize = 2;
+        const MAX_SUBSCRIBERS_CEILING: usize = 4;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(0)
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .max_subscribers_ceiling(MAX_SUBSCRIBERS_CEILING)
             .create()
             .unwrap();
-
-        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
-    }
-
-    #[test]
-    fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut = node
+        let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(0)
+            .open()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let mut subscribers = vec![];
+        for _ in 0..MAX_SUBSCRIBERS {
+            subscribers.push(sut.subscriber_builder().create().unwrap());
+        }
+        let result = sut2.subscriber_builder().create();
+        assert_that!(result.err(), eq Some(SubscriberCreateError::ExceedsMaxSupportedSubscribers));
+
+        assert_that!(sut.set_max_subscribers(MAX_SUBSCRIBERS_CEILING), is_ok);
+        assert_that!(sut2.dynamic_config().max_subscribers(), eq MAX_SUBSCRIBERS_CEILING);
+        assert_that!(sut2.static_config().max_subscribers(), eq MAX_SUBSCRIBERS);
+
+        for _ in MAX_SUBSCRIBERS..MAX_SUBSCRIBERS_CEILING {
+            subscribers.push(sut2.subscriber_builder().create().unwrap());
+        }
+        let result = sut2.subscriber_builder().create();
+        assert_that!(result.err(), eq Some(SubscriberCreateError::ExceedsMaxSupportedSubscribers));
+
+        assert_that!(publisher.send_copy(1234), eq Ok(MAX_SUBSCRIBERS_CEILING));
+        for subscriber in &subscribers {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 1234);
+        }
+    }
+
+    #[test]
+    fn raised_max_publishers_allow_additional_publishers<Sut: Service>() {
+        const MAX_PUBLISHERS: usize = 1;
+        const MAX_PUBLISHERS_CEILING: usize = 3;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .max_publishers_ceiling(MAX_PUBLISHERS_CEILING)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![sut.publisher_builder().create().unwrap()];
+        let result = sut.publisher_builder().create();
+        assert_that!(result.err(), eq Some(PublisherCreateError::ExceedsMaxSupportedPublishers));
+
+        assert_that!(sut.set_max_publishers(MAX_PUBLISHERS_CEILING), is_ok);
+        for _ in MAX_PUBLISHERS..MAX_PUBLISHERS_CEILING {
+            publishers.push(sut.publisher_builder().create().unwrap());
+        }
+        let result = sut.publisher_builder().create();
+        assert_that!(result.err(), eq Some(PublisherCreateError::ExceedsMaxSupportedPublishers));
+
+        publishers.clear();
+        for _ in 0..MAX_PUBLISHERS_CEILING {
+            publishers.push(sut.publisher_builder().create().unwrap());
+        }
+    }
+
+    #[test]
+    fn max_ports_can_only_be_raised_up_to_the_ceiling<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .max_subscribers(2)
+            .max_publishers_ceiling(4)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_publishers_ceiling(), eq 4);
+        assert_that!(sut.static_config().max_subscribers_ceiling(), eq 2);
+
+        assert_that!(sut.set_max_publishers(5).err(), eq Some(MaxPortsIncreaseError::ExceedsCeiling));
+        assert_that!(sut.set_max_subscribers(3).err(), eq Some(MaxPortsIncreaseError::ExceedsCeiling));
+        assert_that!(sut.set_max_publishers(3), is_ok);
+        assert_that!(sut.set_max_publishers(2).err(), eq Some(MaxPortsIncreaseError::SmallerThanCurrentMaximum));
+        assert_that!(sut.dynamic_config().max_publishers(), eq 3);
+        assert_that!(sut.dynamic_config().max_subscribers(), eq 2);
+    }
+
+    #[test]
+    fn set_subscriber_max_borrowed_samples_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().subscriber_max_borrowed_samples(), eq 1);
+    }
+
+    #[test]
+    fn set_buffer_size_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(0)
             .create()
             .unwrap();
 
@@ -2460,6 +2918,28 @@ mod service_publish_subscribe {
         assert_that!(subscriber.buffer_size(), eq 1);
     }
 
+    #[test]
+    fn subscriber_buffer_size_from_config_can_be_overridden<Sut: Service>() {
+        const BUFFER_SIZE: usize = 8;
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.subscriber_buffer_size = Some(3);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 3);
+
+        let subscriber = sut.subscriber_builder().buffer_size(5).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 5);
+    }
+
     #[test]
     fn sliced_service_works<Sut: Service>() {
         const MAX_ELEMENTS: usize = 91;
@@ -2539,6 +3019,229 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn page_aligned_payload_works<Sut: Service>() {
+        const BUFFER_SIZE: usize = 4;
+        let page_size = SystemInfo::PageSize.value();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_pub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(BUFFER_SIZE)
+            .payload_alignment(Alignment::new(page_size).unwrap())
+            .create()
+            .unwrap();
+
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open()
+            .unwrap();
+
+        let publisher = service_pub
+            .publisher_builder()
+            .max_slice_len(page_size)
+            .create()
+            .unwrap();
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+
+        for n in 0..BUFFER_SIZE {
+            let sample = publisher.loan_slice_uninit(page_size).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % page_size, eq 0);
+            sample.write_from_fn(|i| (i + n) as u8).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+            assert_that!((recv_sample.payload().as_ptr() as usize) % page_size, eq 0);
+            assert_that!(recv_sample.payload()[0], eq n as u8);
+        }
+    }
+
+    #[test]
+    fn payload_alignment_greater_than_page_size_fails<Sut: Service>() {
+        let page_size = SystemInfo::PageSize.value();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(page_size * 2).unwrap())
+            .create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::UnsupportedPayloadAlignment));
+    }
+
+    #[test]
+    fn statistics_count_published_samples_and_ports<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(8)
+            .statistics(true)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_slice_copy(&[1, 2, 3]).unwrap();
+        publisher.send_slice_copy(&[4]).unwrap();
+        drop(subscriber);
+
+        let statistics = sut.statistics();
+        assert_that!(statistics.samples_published(), eq 2);
+        assert_that!(statistics.bytes_published(), eq 4 * core::mem::size_of::<u64>() as u64);
+        assert_that!(statistics.connected_ports(), eq 2);
+        assert_that!(statistics.disconnected_ports(), eq 1);
+        assert_that!(statistics.notifications_sent(), eq 0);
+    }
+
+    #[test]
+    fn statistics_count_dropped_samples<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().statistics(true).create().unwrap();
+        let _subscriber = sut
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE as u64 + 3 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        assert_that!(sut.statistics().samples_dropped(), eq 3);
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        let statistics = details.dynamic_details.unwrap().statistics;
+        assert_that!(statistics.samples_dropped(), eq 3);
+    }
+
+    #[test]
+    fn statistics_count_discarded_samples_as_dropped<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .statistics(true)
+            .create()
+            .unwrap();
+        let _subscriber = sut
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE as u64 + 2 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        assert_that!(sut.statistics().samples_dropped(), eq 2);
+    }
+
+    #[test]
+    fn statistics_do_not_count_samples_by_default<Sut: Service>() {
+        const BUFFER_SIZE: usize = 1;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..BUFFER_SIZE as u64 + 2 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        let statistics = sut.statistics();
+        assert_that!(statistics.samples_published(), eq 0);
+        assert_that!(statistics.bytes_published(), eq 0);
+        assert_that!(statistics.samples_dropped(), eq 0);
+        assert_that!(statistics.connected_ports(), eq 2);
+    }
+
+    #[test]
+    fn statistics_can_be_enabled_in_the_config<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.publisher_statistics = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        assert_that!(sut.statistics().samples_published(), eq 1);
+    }
+
+    #[test]
+    fn statistics_are_available_without_opening_the_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().statistics(true).create().unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        let statistics = details.dynamic_details.unwrap().statistics;
+        assert_that!(statistics.samples_published(), eq 1);
+        assert_that!(statistics.bytes_published(), eq core::mem::size_of::<u64>() as u64);
+        assert_that!(statistics, eq sut.statistics());
+    }
+
     #[test]
     fn simple_communication_with_user_header_works<Sut: Service>() {
         let service_name = generate_name();
@@ -2636,18 +3339,79 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn create_with_custom_user_header_type_works<Sut: Service>() {
+    fn open_with_verified_type_layout_fails_when_layout_hash_differs<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const HEADER_SIZE: usize = 1024;
 
-        let sut_pub = unsafe {
+        // emulates a process with a different definition of the same type
+        let mut payload_details =
+            TypeDetail::__internal_new::<LayoutCheckedPayload>(TypeVariant::FixedSize);
+        payload_details.layout_hash = Some(!LayoutCheckedPayload::TYPE_LAYOUT_HASH);
+
+        let _sut = unsafe {
             node.service_builder(&service_name)
-                .publish_subscribe::<[u8]>()
-                .user_header::<CustomHeaderMarker>()
-                .__internal_set_user_header_type_details(&TypeDetail::__internal_new::<
-                    [u64; HEADER_SIZE],
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&payload_details)
+                .create()
+                .unwrap()
+        };
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<LayoutCheckedPayload>()
+            .verify_type_layout()
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_with_verified_type_layout_works_when_layout_hash_is_equal<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<LayoutCheckedPayload>()
+            .verify_type_layout()
+            .create()
+            .unwrap();
+
+        let details = sut.static_config().message_type_details();
+        assert_that!(details.payload.layout_hash, eq Some(LayoutCheckedPayload::TYPE_LAYOUT_HASH));
+        assert_that!(details.user_header.layout_hash, eq Some(<()>::TYPE_LAYOUT_HASH));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<LayoutCheckedPayload>()
+            .verify_type_layout()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        // without verification the layout hash is not considered
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<LayoutCheckedPayload>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn create_with_custom_user_header_type_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const HEADER_SIZE: usize = 1024;
+
+        let sut_pub = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_user_header_type_details(&TypeDetail::__internal_new::<
+                    [u64; HEADER_SIZE],
                 >(TypeVariant::FixedSize))
                 .create()
                 .unwrap()
@@ -2736,6 +3500,8 @@ mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::IncompatibleTypes");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
                                   "PublishSubscribeOpenError::IncompatibleMessagingPattern");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatiblePlatform), eq
+                                  "PublishSubscribeOpenError::IncompatiblePlatform");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
                                   "PublishSubscribeOpenError::IncompatibleAttributes");
         assert_that!(format!("{}", PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize), eq
@@ -2902,6 +3668,219 @@ mod service_publish_subscribe {
         assert_that!(*sample, eq 456);
     }
 
+    #[test]
+    fn timestamp_ordering_merges_samples_of_multiple_publishers<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_PUBLISHERS: usize = 3;
+        const SEND_ORDER: [usize; 9] = [2, 0, 1, 1, 0, 2, 2, 1, 0];
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(SEND_ORDER.len())
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let publishers: Vec<_> = (0..NUMBER_OF_PUBLISHERS)
+            .map(|_| sut.publisher_builder().create().unwrap())
+            .collect();
+        let subscriber = sut
+            .subscriber_builder()
+            .sample_ordering(SampleOrdering::Timestamp)
+            .create()
+            .unwrap();
+
+        for (n, publisher) in SEND_ORDER.iter().enumerate() {
+            assert_that!(publishers[*publisher].send_copy(n), is_ok);
+        }
+
+        let mut last_timestamp = core::time::Duration::ZERO;
+        for (n, publisher) in SEND_ORDER.iter().enumerate() {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(sample.origin(), eq publishers[*publisher].id());
+            assert_that!(sample.header().send_timestamp(), ge last_timestamp);
+            last_timestamp = sample.header().send_timestamp();
+        }
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn timestamp_ordering_batch_receive_merges_samples_of_multiple_publishers<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const SEND_ORDER: [usize; 6] = [1, 0, 0, 1, 1, 0];
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(SEND_ORDER.len())
+            .subscriber_max_borrowed_samples(SEND_ORDER.len())
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let publishers = [
+            sut.publisher_builder().create().unwrap(),
+            sut.publisher_builder().create().unwrap(),
+        ];
+        let subscriber = sut
+            .subscriber_builder()
+            .sample_ordering(SampleOrdering::Timestamp)
+            .create()
+            .unwrap();
+
+        for (n, publisher) in SEND_ORDER.iter().enumerate() {
+            assert_that!(publishers[*publisher].send_copy(n), is_ok);
+        }
+
+        let samples = subscriber.receive_batch(SEND_ORDER.len()).unwrap();
+        assert_that!(samples, len SEND_ORDER.len());
+        for (n, sample) in samples.iter().enumerate() {
+            assert_that!(**sample, eq n);
+        }
+    }
+
+    #[test]
+    fn timestamp_ordering_receives_samples_of_disconnected_publisher<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(2)
+            .max_publishers(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .sample_ordering(SampleOrdering::Timestamp)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send_copy(123), is_ok);
+        drop(publisher);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(456), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 123);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 456);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn samples_of_publisher_with_higher_priority_are_received_first<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const NUMBER_OF_TELEMETRY_SAMPLES: usize = 4;
+        const NUMBER_OF_COMMANDS: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(NUMBER_OF_TELEMETRY_SAMPLES)
+            .subscriber_max_borrowed_samples(NUMBER_OF_TELEMETRY_SAMPLES + NUMBER_OF_COMMANDS)
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let telemetry_publisher = sut
+            .publisher_builder()
+            .priority(PublisherPriority::Low)
+            .create()
+            .unwrap();
+        let command_publisher = sut
+            .publisher_builder()
+            .priority(PublisherPriority::High)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(telemetry_publisher.priority(), eq PublisherPriority::Low);
+        assert_that!(command_publisher.priority(), eq PublisherPriority::High);
+
+        for n in 0..NUMBER_OF_TELEMETRY_SAMPLES {
+            assert_that!(telemetry_publisher.send_copy(n), is_ok);
+        }
+        for n in 0..NUMBER_OF_COMMANDS {
+            assert_that!(command_publisher.send_copy(100 + n), is_ok);
+        }
+
+        let mut samples = vec![];
+        for n in 0..NUMBER_OF_COMMANDS {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 100 + n);
+            assert_that!(sample.origin(), eq command_publisher.id());
+            samples.push(sample);
+        }
+
+        for n in 0..NUMBER_OF_TELEMETRY_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(sample.origin(), eq telemetry_publisher.id());
+            samples.push(sample);
+        }
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publisher_priority_takes_precedence_over_timestamp_ordering<Sut: Service>() {
+        set_log_level(LogLevel::Error);
+        const SEND_ORDER: [usize; 6] = [0, 0, 1, 0, 1, 1];
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(SEND_ORDER.len())
+            .subscriber_max_borrowed_samples(SEND_ORDER.len())
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let publishers = [
+            sut.publisher_builder().create().unwrap(),
+            sut.publisher_builder()
+                .priority(PublisherPriority::Critical)
+                .create()
+                .unwrap(),
+        ];
+        let subscriber = sut
+            .subscriber_builder()
+            .sample_ordering(SampleOrdering::Timestamp)
+            .create()
+            .unwrap();
+
+        for (n, publisher) in SEND_ORDER.iter().enumerate() {
+            assert_that!(publishers[*publisher].send_copy(n), is_ok);
+        }
+
+        let samples = subscriber.receive_batch(SEND_ORDER.len()).unwrap();
+        let received: Vec<usize> = samples.iter().map(|sample| **sample).collect();
+        assert_that!(received, eq vec![2, 4, 5, 0, 1, 3]);
+    }
+
     #[test]
     fn communication_with_custom_payload_works<Sut: Service>() {
         set_log_level(LogLevel::Error);
@@ -2976,9 +3955,172 @@ mod service_publish_subscribe {
         assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
+    #[test]
+    fn raw_service_adopts_type_details_of_existing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .open();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        assert_that!(sut.static_config().message_type_details(), eq typed_service.static_config().message_type_details());
+    }
+
+    #[test]
+    fn raw_subscriber_receives_bytes_of_typed_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+        let raw_service = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .open()
+            .unwrap();
+
+        let publisher = typed_service.publisher_builder().create().unwrap();
+        let sut = raw_service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        *sample.user_header_mut() = 0xaffe;
+        *sample.payload_mut() = 0x1234_5678_9abc_def0;
+        sample.send().unwrap();
+
+        let sample = sut.receive_raw().unwrap().unwrap();
+        assert_that!(sample.user_header_bytes(), eq 0xaffeu32.to_ne_bytes());
+        assert_that!(sample.payload_bytes(), eq 0x1234_5678_9abc_def0u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn raw_publisher_sends_bytes_to_typed_subscriber<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 3;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u16]>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+        let raw_service = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .open()
+            .unwrap();
+
+        let sut = raw_service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = typed_service.subscriber_builder().create().unwrap();
+
+        let payload: Vec<u8> = [7u16, 8, 9].iter().flat_map(|v| v.to_ne_bytes()).collect();
+        assert_that!(sut.send_raw_copy(&42u64.to_ne_bytes(), &payload), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq 42);
+        assert_that!(sample.payload(), eq [7, 8, 9]);
+        assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
+    }
+
+    #[test]
+    fn raw_publisher_rejects_bytes_that_do_not_match_the_type_details<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+        let raw_service = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .open()
+            .unwrap();
+        let sut = raw_service.publisher_builder().create().unwrap();
+
+        assert_that!(sut.send_raw_copy(&[0u8; 2], &[0u8; 8]), eq Err(PublisherSendError::IncompatibleRawSampleSize));
+        assert_that!(sut.send_raw_copy(&[0u8; 4], &[0u8; 7]), eq Err(PublisherSendError::IncompatibleRawSampleSize));
+        assert_that!(sut.send_raw_copy(&[0u8; 4], &[0u8; 8]), eq Ok(0));
+    }
+
+    #[test]
+    fn raw_service_with_type_details_fails_to_open_incompatible_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let mut type_details = typed_service.static_config().message_type_details().clone();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .message_type_details(&type_details)
+            .open();
+        assert_that!(sut, is_ok);
+
+        type_details.payload.size = 4;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .message_type_details(&type_details)
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn raw_service_without_type_details_is_created_as_byte_slice_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_raw()
+            .create()
+            .unwrap();
+
+        let type_details = sut.static_config().message_type_details();
+        assert_that!(type_details.payload.variant, eq TypeVariant::Dynamic);
+        assert_that!(type_details.payload.size, eq 1);
+        assert_that!(type_details.user_header.size, eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::external_memory::Service<iceoryx2_cal::shared_memory::posix::Memory<iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator>>>)]
+    mod external_memory {}
 }
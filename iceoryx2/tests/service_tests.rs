@@ -23,8 +23,10 @@ mod service {
     use iceoryx2::service::builder::publish_subscribe::{
         PublishSubscribeCreateError, PublishSubscribeOpenError,
     };
+    use iceoryx2::service::list_filter::ServiceListFilter;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::{event, publish_subscribe};
+    use iceoryx2::service::static_config::platform_details::Endianness;
     use iceoryx2::service::{ServiceDetailsError, ServiceListError};
     use iceoryx2::testing::*;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
@@ -648,6 +650,187 @@ mod service {
         }
     }
 
+    #[test]
+    fn list_filtered_services_matches_name_pattern_and_messaging_pattern<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        const NUMBER_OF_SERVICES: usize = 4;
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let base_name = generate_name();
+
+        let mut services = vec![];
+        let mut matching_service_ids = vec![];
+        for i in 0..NUMBER_OF_SERVICES {
+            let kind = if i % 2 == 0 { "even" } else { "odd" };
+            let service_name =
+                ServiceName::new(&format!("{}/{}/{}", base_name.as_str(), kind, i)).unwrap();
+            let sut = test
+                .create(&node, &service_name, &AttributeSpecifier::new())
+                .unwrap();
+
+            if i % 2 == 0 {
+                matching_service_ids.push(sut.service_id().clone());
+            }
+            services.push(sut);
+        }
+
+        let filter = ServiceListFilter::new()
+            .name_pattern(&format!("{}/even/?", base_name.as_str()))
+            .messaging_pattern(Factory::messaging_pattern());
+        let mut listed_services = vec![];
+        let result = Sut::list_filtered(&config, &filter, |service| {
+            listed_services.push(service.static_details.service_id().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, len matching_service_ids.len());
+        for s in listed_services {
+            assert_that!(matching_service_ids, contains s);
+        }
+
+        let other_messaging_pattern = match Factory::messaging_pattern() {
+            MessagingPattern::PublishSubscribe => MessagingPattern::Event,
+            _ => MessagingPattern::PublishSubscribe,
+        };
+        let filter = ServiceListFilter::new()
+            .name_pattern(&format!("{}/*", base_name.as_str()))
+            .messaging_pattern(other_messaging_pattern);
+        let mut service_counter = 0;
+        let result = Sut::list_filtered(&config, &filter, |_| {
+            service_counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(service_counter, eq 0);
+    }
+
+    #[test]
+    fn list_filtered_services_matches_attributes<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut_with_attribute = test
+            .create(
+                &node,
+                &generate_name(),
+                &AttributeSpecifier::new().define("camera", "front"),
+            )
+            .unwrap();
+        let _sut_without_attribute = test
+            .create(&node, &generate_name(), &AttributeSpecifier::new())
+            .unwrap();
+
+        let filter = ServiceListFilter::new()
+            .attributes(AttributeVerifier::new().require("camera", "front"));
+        let mut listed_services = vec![];
+        let result = Sut::list_filtered(&config, &filter, |service| {
+            listed_services.push(service.static_details.service_id().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, eq vec![sut_with_attribute.service_id().clone()]);
+    }
+
+    #[test]
+    fn list_page_iterates_over_all_services<Sut: Service, Factory: SutFactory<Sut>>() {
+        const NUMBER_OF_SERVICES: usize = 10;
+        const PAGE_SIZE: usize = 3;
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let mut services = vec![];
+        let mut service_ids = vec![];
+        for _ in 0..NUMBER_OF_SERVICES {
+            let sut = test
+                .create(&node, &generate_name(), &AttributeSpecifier::new())
+                .unwrap();
+            service_ids.push(sut.service_id().clone());
+            services.push(sut);
+        }
+
+        let filter = ServiceListFilter::new().messaging_pattern(Factory::messaging_pattern());
+        let mut listed_services = vec![];
+        let mut cursor = None;
+        loop {
+            let page = Sut::list_page(&config, &filter, cursor.as_ref(), PAGE_SIZE).unwrap();
+            assert_that!(page.services.len(), le PAGE_SIZE);
+            for service in page.services {
+                listed_services.push(service.static_details.service_id().clone());
+            }
+
+            match page.next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_that!(listed_services, len NUMBER_OF_SERVICES);
+        for s in service_ids {
+            assert_that!(listed_services, contains s);
+        }
+    }
+
+    #[test]
+    fn list_page_with_zero_max_services_returns_all_services<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        const NUMBER_OF_SERVICES: usize = 5;
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let mut services = vec![];
+        for _ in 0..NUMBER_OF_SERVICES {
+            let sut = test
+                .create(&node, &generate_name(), &AttributeSpecifier::new())
+                .unwrap();
+            services.push(sut);
+        }
+
+        let filter = ServiceListFilter::new().messaging_pattern(Factory::messaging_pattern());
+        let page = Sut::list_page(&config, &filter, None, 0).unwrap();
+
+        assert_that!(page.services, len NUMBER_OF_SERVICES);
+        assert_that!(page.next, eq None);
+    }
+
+    #[test]
+    fn list_filtered_services_with_exact_name_pattern_lists_only_the_named_service<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_name = generate_name();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let _other_sut = test
+            .create(&node, &generate_name(), &AttributeSpecifier::new())
+            .unwrap();
+
+        let filter = ServiceListFilter::new().name_pattern(service_name.as_str());
+        let mut listed_services = vec![];
+        let result = Sut::list_filtered(&config, &filter, |service| {
+            listed_services.push(service.static_details.service_id().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, eq vec![sut.service_id().clone()]);
+
+        let page = Sut::list_page(&config, &filter, None, 0).unwrap();
+        assert_that!(page.services, len 1);
+        assert_that!(page.next, eq None);
+    }
+
     #[test]
     fn list_services_stops_when_callback_progression_states_stop<
         Sut: Service,
@@ -764,7 +947,7 @@ mod service {
                                         found_me = true;
                                     }
                                 }
-                                NodeState::Undefined(_) => {
+                                NodeState::Undefined(_) | NodeState::Unresponsive(_) => {
                                     assert_that!(true, eq false);
                                 }
                             }
@@ -836,7 +1019,7 @@ mod service {
                                         found_me = true;
                                     }
                                 }
-                                NodeState::Undefined(_) => {
+                                NodeState::Undefined(_) | NodeState::Unresponsive(_) => {
                                     assert_that!(true, eq false);
                                 }
                             }
@@ -877,7 +1060,9 @@ mod service {
                     match node_state {
                         NodeState::Alive(view) => registered_node_ids.push(view.id().clone()),
                         NodeState::Dead(view) => registered_node_ids.push(view.id().clone()),
-                        NodeState::Inaccessible(_) | NodeState::Undefined(_) => {
+                        NodeState::Inaccessible(_)
+                        | NodeState::Undefined(_)
+                        | NodeState::Unresponsive(_) => {
                             assert_that!(true, eq false)
                         }
                     }
@@ -954,6 +1139,34 @@ mod service {
         assert_that!(sut.service_id(), eq sut2.service_id());
     }
 
+    #[test]
+    fn service_details_contain_platform_of_creator<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let service_details = Sut::details(&service_name, &config, Factory::messaging_pattern())
+            .unwrap()
+            .unwrap();
+        let platform = service_details.static_details.platform();
+
+        let endianness = if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        };
+        assert_that!(platform.endianness(), eq endianness);
+        assert_that!(platform.pointer_width(), eq core::mem::size_of::<usize>() * 8);
+        assert_that!(platform.u64_alignment(), eq core::mem::align_of::<u64>());
+        assert_that!(platform.u128_alignment(), eq core::mem::align_of::<u128>());
+        assert_that!(platform.architecture(), eq std::env::consts::ARCH);
+    }
+
     mod ipc {
         use iceoryx2::service::ipc::Service;
 
@@ -15,11 +15,16 @@ mod subscriber {
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
 
     use iceoryx2::{
         node::NodeBuilder,
-        port::subscriber::{SubscriberCreateError, SubscriberReceiveError},
-        service::{service_name::ServiceName, Service},
+        port::subscriber::{
+            PublisherConnectionState, PublisherConnectionStatistics, SubscriberCreateError,
+            SubscriberReceiveError,
+        },
+        service::{port_factory::subscriber::SampleOrdering, service_name::ServiceName, Service},
         testing::*,
     };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -45,6 +50,140 @@ mod subscriber {
             format!("{}", SubscriberCreateError::ExceedsMaxSupportedSubscribers), eq "SubscriberCreateError::ExceedsMaxSupportedSubscribers");
         assert_that!(
             format!("{}", SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService), eq "SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService");
+        assert_that!(
+            format!("{}", SubscriberCreateError::UnableToCreateBlockingReceiveEvent), eq "SubscriberCreateError::UnableToCreateBlockingReceiveEvent");
+    }
+
+    #[test]
+    fn close_discards_and_counts_samples_that_were_not_received<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for i in 0..3 {
+            publisher.send_copy(i).unwrap();
+        }
+        let sample = sut.receive().unwrap();
+        assert_that!(sample, is_some);
+        drop(sample);
+
+        assert_that!(sut.close(), eq Ok(2));
+    }
+
+    #[test]
+    fn peek_does_not_consume_the_next_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.peek().unwrap(), is_none);
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        assert_that!(**sut.peek().unwrap().unwrap(), eq 1);
+        assert_that!(**sut.peek().unwrap().unwrap(), eq 1);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peek_last_returns_newest_sample_and_keeps_the_order<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        for i in 0..3 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        assert_that!(**sut.peek_last().unwrap().unwrap(), eq 2);
+        assert_that!(**sut.peek().unwrap().unwrap(), eq 0);
+
+        publisher.send_copy(3).unwrap();
+        assert_that!(**sut.peek_last().unwrap().unwrap(), eq 3);
+
+        let samples = sut.receive_batch(2).unwrap();
+        assert_that!(samples.iter().map(|s| **s).collect::<Vec<_>>(), eq vec![0, 1]);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        drop(samples);
+
+        assert_that!(sut.close(), eq Ok(1));
+    }
+
+    #[test]
+    fn peek_does_not_borrow_samples_when_more_are_queued_than_can_be_borrowed<Sut: Service>() {
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 6;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(i).unwrap();
+        }
+
+        assert_that!(**sut.peek_last().unwrap().unwrap(), eq NUMBER_OF_SAMPLES - 1);
+        assert_that!(**sut.peek().unwrap().unwrap(), eq 0);
+        assert_that!(**sut.peek_last().unwrap().unwrap(), eq NUMBER_OF_SAMPLES - 1);
+
+        let mut borrowed_samples = vec![];
+        for i in 0..MAX_BORROWED_SAMPLES as u64 {
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+            borrowed_samples.push(sample);
+        }
+
+        assert_that!(**sut.peek().unwrap().unwrap(), eq MAX_BORROWED_SAMPLES as u64);
+        assert_that!(**sut.peek_last().unwrap().unwrap(), eq NUMBER_OF_SAMPLES - 1);
+        drop(borrowed_samples);
+
+        for i in MAX_BORROWED_SAMPLES as u64..NUMBER_OF_SAMPLES {
+            assert_that!(*sut.receive().unwrap().unwrap(), eq i);
+        }
+        assert_that!(sut.peek().unwrap(), is_none);
+        assert_that!(sut.peek_last().unwrap(), is_none);
     }
 
     #[test]
@@ -71,6 +210,412 @@ mod subscriber {
         }
     }
 
+    #[test]
+    fn receive_blocking_fails_when_it_is_not_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let result = subscriber.receive_blocking(Duration::ZERO);
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::BlockingReceiveNotEnabled));
+    }
+
+    #[test]
+    fn receive_blocking_returns_none_after_timeout<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .enable_blocking_receive(true)
+            .create()
+            .unwrap();
+        let _publisher = sut.publisher_builder().create().unwrap();
+
+        let start = Instant::now();
+        let result = subscriber.receive_blocking(TIMEOUT).unwrap();
+        assert_that!(result, is_none);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn receive_blocking_wakes_up_on_delivery<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .enable_blocking_receive(true)
+            .create()
+            .unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let sut = node
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+                let publisher = sut.publisher_builder().create().unwrap();
+
+                std::thread::sleep(Duration::from_millis(10));
+                assert_that!(publisher.send_copy(8912), eq Ok(1));
+            });
+
+            let start = Instant::now();
+            let sample = subscriber.receive_blocking(TIMEOUT).unwrap();
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 8912);
+        });
+    }
+
+    #[test]
+    fn publisher_connection_state_follows_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.publisher_connection_state(), eq Ok(PublisherConnectionState::Disconnected));
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(subscriber.publisher_connection_state(), eq Ok(PublisherConnectionState::Alive));
+
+        drop(publisher);
+        assert_that!(subscriber.publisher_connection_state(), eq Ok(PublisherConnectionState::Disconnected));
+    }
+
+    #[test]
+    fn dropped_samples_are_counted_per_publisher_connection<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(subscriber.dropped_samples(), eq Ok(0));
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+
+        let statistics = subscriber.publisher_connection_statistics().unwrap();
+        assert_that!(statistics, len 1);
+        assert_that!(statistics[0], eq PublisherConnectionStatistics {
+            publisher_id: publisher.id(),
+            number_of_buffered_samples: BUFFER_SIZE,
+            buffer_size: BUFFER_SIZE,
+            number_of_dropped_samples: NUMBER_OF_SAMPLES - BUFFER_SIZE as u64,
+        });
+        assert_that!(subscriber.dropped_samples(), eq Ok(NUMBER_OF_SAMPLES - BUFFER_SIZE as u64));
+
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        let statistics = subscriber.publisher_connection_statistics().unwrap();
+        assert_that!(statistics[0].number_of_buffered_samples, eq BUFFER_SIZE - 1);
+    }
+
+    #[test]
+    fn publisher_disconnect_callback_is_called_when_last_publisher_disconnects<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let disconnected_publishers = Arc::new(Mutex::new(vec![]));
+        let disconnected_publishers_clone = disconnected_publishers.clone();
+        let subscriber = sut
+            .subscriber_builder()
+            .on_publisher_disconnect(move |id| {
+                disconnected_publishers_clone.lock().unwrap().push(id)
+            })
+            .create()
+            .unwrap();
+
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+        let last_publisher_id = publisher_2.id();
+        assert_that!(subscriber.receive(), is_ok);
+
+        drop(publisher_1);
+        assert_that!(subscriber.receive(), is_ok);
+        assert_that!(disconnected_publishers.lock().unwrap(), len 0);
+
+        drop(publisher_2);
+        assert_that!(subscriber.receive(), is_ok);
+        assert_that!(*disconnected_publishers.lock().unwrap(), eq vec![last_publisher_id]);
+
+        assert_that!(subscriber.receive(), is_ok);
+        assert_that!(disconnected_publishers.lock().unwrap(), len 1);
+    }
+
+    #[test]
+    fn receive_batch_returns_samples_in_order<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const NUMBER_OF_SAMPLES: u64 = 6;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(10)
+            .subscriber_max_borrowed_samples(8)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.receive_batch(4).unwrap(), len 0);
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        let first_batch = subscriber.receive_batch(4).unwrap();
+        assert_that!(first_batch, len 4);
+        let second_batch = subscriber.receive_batch(10).unwrap();
+        assert_that!(second_batch, len 2);
+
+        for (i, sample) in first_batch.iter().chain(second_batch.iter()).enumerate() {
+            assert_that!(**sample, eq i as u64);
+        }
+
+        assert_that!(subscriber.receive_batch(10).unwrap(), len 0);
+    }
+
+    #[test]
+    fn receive_batch_is_limited_by_max_borrowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_BORROWED_SAMPLES: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..4 {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        let samples = subscriber.receive_batch(4).unwrap();
+        assert_that!(samples, len MAX_BORROWED_SAMPLES);
+
+        let result = subscriber.receive_batch(4);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq SubscriberReceiveError::ExceedsMaxBorrowedSamples);
+
+        drop(samples);
+        let samples = subscriber.receive_batch(4).unwrap();
+        assert_that!(samples, len MAX_BORROWED_SAMPLES);
+        assert_that!(*samples[0], eq 2);
+        assert_that!(*samples[1], eq 3);
+    }
+
+    #[test]
+    fn samples_exceeding_max_sample_age_are_discarded<Sut: Service>() {
+        const MAX_SAMPLE_AGE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let expired_samples = Arc::new(Mutex::new(vec![]));
+        let expired_samples_clone = expired_samples.clone();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .max_sample_age(MAX_SAMPLE_AGE)
+            .on_expired_sample(move |id, age| expired_samples_clone.lock().unwrap().push((id, age)))
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(publisher.send_copy(2), is_ok);
+        std::thread::sleep(MAX_SAMPLE_AGE * 2);
+        assert_that!(publisher.send_copy(3), is_ok);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(subscriber.expired_samples(), eq 2);
+        let expired_samples = expired_samples.lock().unwrap();
+        assert_that!(*expired_samples, len 2);
+        for (id, age) in expired_samples.iter() {
+            assert_that!(*id, eq publisher.id());
+            assert_that!(*age, gt MAX_SAMPLE_AGE);
+        }
+    }
+
+    #[test]
+    fn timestamp_ordering_discards_many_samples_exceeding_max_sample_age<Sut: Service>() {
+        const MAX_SAMPLE_AGE: Duration = Duration::from_millis(50);
+        const NUMBER_OF_PUBLISHERS: usize = 3;
+        const NUMBER_OF_EXPIRED_SAMPLES: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_EXPIRED_SAMPLES + 1)
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let publishers: Vec<_> = (0..NUMBER_OF_PUBLISHERS)
+            .map(|_| sut.publisher_builder().create().unwrap())
+            .collect();
+        let subscriber = sut
+            .subscriber_builder()
+            .sample_ordering(SampleOrdering::Timestamp)
+            .max_sample_age(MAX_SAMPLE_AGE)
+            .create()
+            .unwrap();
+
+        for publisher in &publishers {
+            for n in 0..NUMBER_OF_EXPIRED_SAMPLES {
+                assert_that!(publisher.send_copy(n as u64), is_ok);
+            }
+        }
+        std::thread::sleep(MAX_SAMPLE_AGE * 2);
+        assert_that!(publishers[1].send_copy(1234), is_ok);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        let sample = sample.unwrap();
+        assert_that!(*sample, eq 1234);
+        assert_that!(sample.origin(), eq publishers[1].id());
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        let expired_samples = NUMBER_OF_PUBLISHERS * NUMBER_OF_EXPIRED_SAMPLES;
+        assert_that!(subscriber.expired_samples(), eq expired_samples as u64);
+    }
+
+    #[test]
+    fn receive_batch_discards_samples_exceeding_max_sample_age<Sut: Service>() {
+        const MAX_SAMPLE_AGE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .max_sample_age(MAX_SAMPLE_AGE)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        std::thread::sleep(MAX_SAMPLE_AGE * 2);
+        assert_that!(publisher.send_copy(2), is_ok);
+        assert_that!(publisher.send_copy(3), is_ok);
+
+        let samples = subscriber.receive_batch(4).unwrap();
+        assert_that!(samples, len 2);
+        assert_that!(*samples[0], eq 2);
+        assert_that!(*samples[1], eq 3);
+        assert_that!(subscriber.expired_samples(), eq 1);
+    }
+
+    #[test]
+    fn samples_are_not_discarded_without_max_sample_age<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        std::thread::sleep(Duration::from_millis(50));
+
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        assert_that!(subscriber.expired_samples(), eq 0);
+    }
+
     #[test]
     #[should_panic]
     #[cfg(debug_assertions)]
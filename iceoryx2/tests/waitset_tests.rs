@@ -16,7 +16,7 @@ mod waitset {
 
     use iceoryx2::port::listener::Listener;
     use iceoryx2::port::notifier::Notifier;
-    use iceoryx2::port::waitset::{WaitSetAttachmentError, WaitSetRunError};
+    use iceoryx2::port::waitset::{WaitSetAttachmentError, WaitSetRunError, WaitSetRunResult};
     use iceoryx2::prelude::{WaitSetBuilder, *};
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::config::test_directory;
@@ -251,6 +251,64 @@ mod waitset {
         assert_that!(start.elapsed(), time_at_least TIMEOUT);
     }
 
+    #[test]
+    fn run_with_timeout_blocks_for_at_least_timeout<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let _guard = sut.attach_notification(&listener).unwrap();
+
+        let mut callback_called = false;
+        let start = Instant::now();
+        let result = sut
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    callback_called = true;
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(callback_called, eq false);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+
+    #[test]
+    fn run_with_timeout_reports_notification_before_timeout<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let guard = sut.attach_notification(&listener).unwrap();
+        notifier.notify().unwrap();
+
+        let mut callback_called = false;
+        sut.wait_and_process_once_with_timeout(
+            |id| {
+                callback_called = true;
+                assert_that!(id.has_event_from(&guard), eq true);
+                CallbackProgression::Continue
+            },
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+
+        assert_that!(callback_called, eq true);
+    }
+
     #[test]
     fn run_with_deadline_blocks_for_at_least_timeout<S: Service>()
     where